@@ -21,6 +21,7 @@
 
 #include "memzero.h"
 #include "py/obj.h"
+#include "py/objexcept.h"
 #include "py/objint.h"
 #include "py/objstr.h"
 #include "py/runtime.h"
@@ -92,3 +93,8 @@ mp_obj_t trezor_obj_str_from_rom_text(const char *str) {
 #endif
   return MP_OBJ_FROM_PTR(o_str);
 }
+
+// Raised by Rust layout constructors for arguments failing validation.
+MP_DEFINE_EXCEPTION(ValidationError, ValueError)
+MP_DEFINE_EXCEPTION(MissingFieldError, ValidationError)
+MP_DEFINE_EXCEPTION(InvalidFieldError, ValidationError)
@@ -105,4 +105,8 @@ mp_obj_t trezor_obj_call_protected(void (*func)(void *), void *arg);
 
 mp_obj_t trezor_obj_str_from_rom_text(const char *str);
 
+extern const mp_obj_type_t mp_type_ValidationError;
+extern const mp_obj_type_t mp_type_MissingFieldError;
+extern const mp_obj_type_t mp_type_InvalidFieldError;
+
 #endif
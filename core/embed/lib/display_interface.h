@@ -60,6 +60,11 @@ void display_sync(void);
 void display_refresh(void);
 const char *display_save(const char *prefix);
 void display_clear_save(void);
+#ifdef TREZOR_EMULATOR
+// Copies the top-left `width` x `height` pixels of the screen as RGB565 into
+// `dest`, row by row.
+void display_copy_rgb565(uint16_t *dest, uint16_t width, uint16_t height);
+#endif
 
 void display_efficient_clear(void);
 uint32_t *display_get_fb_addr(void);
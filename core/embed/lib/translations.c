@@ -0,0 +1,44 @@
+#include "translations.h"
+#include "common.h"
+#include "flash.h"
+#include "model.h"
+
+bool translations_write(const uint8_t* data, uint32_t offset, uint32_t len) {
+  uint32_t size = translations_area_bytesize();
+  if (offset > size || size - offset < len) {
+    return false;
+  }
+
+  ensure(flash_unlock_write(), "translations write");
+  for (uint32_t i = 0; i < len; i++) {
+    ensure(flash_area_write_byte(&TRANSLATIONS_AREA, offset + i, data[i]),
+           "translations write");
+  }
+  ensure(flash_lock_write(), "translations write");
+  return true;
+}
+
+const uint8_t* translations_read(uint32_t* len, uint32_t offset) {
+  uint32_t size = translations_area_bytesize();
+  if (offset > size) {
+    *len = 0;
+    return NULL;
+  }
+
+  // The area is one run of adjacent sectors, so it is contiguous in memory.
+  const uint8_t* start = flash_area_get_address(&TRANSLATIONS_AREA, 0, 0);
+  if (start == NULL) {
+    *len = 0;
+    return NULL;
+  }
+  *len = size - offset;
+  return start + offset;
+}
+
+void translations_erase(void) {
+  ensure(flash_area_erase(&TRANSLATIONS_AREA, NULL), "translations erase");
+}
+
+uint32_t translations_area_bytesize(void) {
+  return flash_area_get_size(&TRANSLATIONS_AREA);
+}
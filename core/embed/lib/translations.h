@@ -0,0 +1,19 @@
+#ifndef _TRANSLATIONS_H
+#define _TRANSLATIONS_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+// Write `len` bytes of `data` at `offset` of the translations area, which
+// has to be erased there. Returns false if they do not fit in the area.
+bool translations_write(const uint8_t* data, uint32_t offset, uint32_t len);
+
+// Content of the translations area from `offset` to its end, `len` is set to
+// its length. NULL in case `offset` is past the end of the area.
+const uint8_t* translations_read(uint32_t* len, uint32_t offset);
+
+void translations_erase(void);
+
+uint32_t translations_area_bytesize(void);
+
+#endif  //_TRANSLATIONS_H
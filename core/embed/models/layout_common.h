@@ -19,5 +19,6 @@ extern const flash_area_t BOOTLOADER_AREA;
 extern const flash_area_t FIRMWARE_AREA;
 extern const flash_area_t WIPE_AREA;
 extern const flash_area_t ALL_WIPE_AREA;
+extern const flash_area_t TRANSLATIONS_AREA;
 
 #endif
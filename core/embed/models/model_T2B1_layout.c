@@ -85,3 +85,12 @@ const flash_area_t WIPE_AREA = {
             .num_sectors = 8,
         },
 };
+
+const flash_area_t TRANSLATIONS_AREA = {
+    .num_subareas = 1,
+    .subarea[0] =
+        {
+            .first_sector = 13,
+            .num_sectors = 2,
+        },
+};
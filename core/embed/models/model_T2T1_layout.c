@@ -80,3 +80,12 @@ const flash_area_t ALL_WIPE_AREA = {
             .num_sectors = 21,
         },
 };
+
+const flash_area_t TRANSLATIONS_AREA = {
+    .num_subareas = 1,
+    .subarea[0] =
+        {
+            .first_sector = 13,
+            .num_sectors = 2,
+        },
+};
@@ -225,6 +225,9 @@ fn generate_micropython_bindings() {
         .allowlist_var("mp_type_OverflowError")
         .allowlist_var("mp_type_ValueError")
         .allowlist_var("mp_type_TypeError")
+        .allowlist_var("mp_type_ValidationError")
+        .allowlist_var("mp_type_MissingFieldError")
+        .allowlist_var("mp_type_InvalidFieldError")
         // time
         .allowlist_function("mp_hal_ticks_ms")
         .allowlist_function("mp_hal_delay_ms")
@@ -289,11 +292,18 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("storage_delete")
         .allowlist_function("storage_set_counter")
         .allowlist_function("storage_next_counter")
+        // translations
+        .allowlist_function("translations_write")
+        .allowlist_function("translations_read")
+        .allowlist_function("translations_erase")
+        .allowlist_function("translations_area_bytesize")
         // display
         .allowlist_function("display_clear")
+        .allowlist_function("display_copy_rgb565")
         .allowlist_function("display_offset")
         .allowlist_function("display_refresh")
         .allowlist_function("display_backlight")
+        .allowlist_function("display_get_orientation")
         .allowlist_function("display_text")
         .allowlist_function("display_text_render_buffer")
         .allowlist_function("display_pixeldata")
@@ -319,7 +329,6 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("uzlib_uncompress_init")
         .allowlist_function("uzlib_uncompress")
         // bip39
-        .allowlist_function("mnemonic_word_completion_mask")
         .allowlist_var("BIP39_WORDLIST_ENGLISH")
         .allowlist_var("BIP39_WORD_COUNT")
         // slip39
@@ -331,6 +340,8 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("random_uniform")
         // rgb led
         .allowlist_function("rgb_led_set_color")
+        // audio
+        .allowlist_function("audio_play_tone")
         // time
         .allowlist_function("hal_delay")
         .allowlist_function("hal_ticks_ms")
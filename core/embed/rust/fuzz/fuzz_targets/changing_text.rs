@@ -0,0 +1,40 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trezor_lib::ui::{
+    component::Component,
+    constant::screen,
+    geometry::{Offset, Point, Rect},
+    model_tr::component::ChangingTextLine,
+};
+
+fuzz_target!(|data: &[u8]| {
+    // Render off-screen.
+    std::env::set_var("SDL_VIDEODRIVER", "dummy");
+
+    let (width, rest) = match data.split_first() {
+        Some((width, rest)) => (*width, rest),
+        None => return,
+    };
+    let text = match std::str::from_utf8(rest) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    let screen = screen();
+    let bounds =
+        Rect::new(Point::zero(), Point::zero() + Offset::new(width as i16, 20)).clamp(screen);
+
+    let mut line = ChangingTextLine::center_mono(String::new());
+    line.place(bounds);
+    line.paint();
+    // Grow the text one line at a time, the way the input components do.
+    let mut shown = String::new();
+    for part in text.split_inclusive('\n') {
+        shown.push_str(part);
+        line.update_text(shown.clone());
+        line.paint();
+    }
+    let mut line = ChangingTextLine::center_bold(shown).without_ellipsis();
+    line.place(bounds);
+    line.paint();
+});
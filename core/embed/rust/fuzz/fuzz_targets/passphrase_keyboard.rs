@@ -0,0 +1,43 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trezor_lib::ui::{
+    component::{Component, Event, EventCtx},
+    constant::screen,
+    event::TouchEvent,
+    geometry::Point,
+    model_tt::component::PassphraseKeyboard,
+};
+
+fuzz_target!(|data: &[u8]| {
+    // Render off-screen.
+    std::env::set_var("SDL_VIDEODRIVER", "dummy");
+
+    let mut ctx = EventCtx::new();
+    let mut keyboard = PassphraseKeyboard::new();
+    keyboard.place(screen());
+    keyboard.event(&mut ctx, Event::Attach);
+
+    // Each event is encoded in three bytes, the kind and the coordinates.
+    for chunk in data.chunks_exact(3) {
+        let point = Point::new(chunk[1] as i16, chunk[2] as i16);
+        let event = match chunk[0] % 4 {
+            0 => TouchEvent::TouchStart(point),
+            1 => TouchEvent::TouchMove(point),
+            2 => TouchEvent::TouchEnd(point),
+            _ => {
+                // Deliver the timers requested so far, e.g. the multi-tap timeout.
+                while let Some((token, _)) = ctx.pop_timer() {
+                    keyboard.event(&mut ctx, Event::Timer(token));
+                }
+                continue;
+            }
+        };
+        keyboard.event(&mut ctx, Event::Touch(event));
+        if ctx.needs_place_before_next_event_or_paint() {
+            keyboard.place(screen());
+        }
+        keyboard.paint();
+        ctx.clear();
+    }
+});
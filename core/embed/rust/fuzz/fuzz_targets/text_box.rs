@@ -0,0 +1,47 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trezor_lib::ui::component::{
+    text::common::{TextBox, TextEdit},
+    EventCtx,
+};
+
+/// Deliberately small, so that the edits overflow it.
+const CAPACITY: usize = 16;
+
+fuzz_target!(|data: &[u8]| {
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    let mut ctx = EventCtx::new();
+    let mut textbox = TextBox::<CAPACITY>::empty();
+    let mut chars = text.chars();
+
+    // Every character is preceded by the operation it is used in.
+    while let Some(op) = chars.next() {
+        let ch = chars.next().unwrap_or('a');
+        let before = String::from(textbox.content());
+        let result = match op as u32 % 7 {
+            0 => textbox.apply(&mut ctx, TextEdit::Append(ch)),
+            1 => textbox.apply(&mut ctx, TextEdit::ReplaceLast(ch)),
+            2 => textbox.append_slice(&mut ctx, chars.as_str()),
+            3 => textbox.replace(&mut ctx, chars.as_str()),
+            4 => {
+                textbox.delete_last(&mut ctx);
+                Ok(())
+            }
+            5 => {
+                textbox.clear(&mut ctx);
+                Ok(())
+            }
+            _ => textbox.replace(&mut ctx, &ch.to_string()),
+        };
+        // Failed edits leave the content untouched.
+        if result.is_err() {
+            assert_eq!(textbox.content(), before);
+        }
+        assert!(textbox.len() <= CAPACITY);
+        ctx.clear();
+    }
+});
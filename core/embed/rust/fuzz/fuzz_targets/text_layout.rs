@@ -0,0 +1,126 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trezor_lib::{
+    strutil::SkipPrefix,
+    ui::{
+        component::{
+            text::{
+                layout::{LayoutFit, TextLayout, TextStyle},
+                paragraphs::{Paragraph, ParagraphVecShort, Paragraphs},
+            },
+            Component, Paginate,
+        },
+        constant::screen,
+        display::{Color, Font},
+        geometry::{Alignment, Offset, Point, Rect},
+        util::long_line_content_with_ellipsis,
+    },
+};
+
+static STYLES: [TextStyle; 5] = {
+    let fg = Color::white();
+    let bg = Color::black();
+    [
+        TextStyle::new(Font::NORMAL, fg, bg, fg, fg),
+        TextStyle::new(Font::BOLD, fg, bg, fg, fg),
+        TextStyle::new(Font::MONO, fg, bg, fg, fg),
+        TextStyle::new(Font::BIG, fg, bg, fg, fg),
+        TextStyle::new(Font::DEMIBOLD, fg, bg, fg, fg),
+    ]
+};
+
+/// `&str` is only a `StringType` in the bootloader and tests.
+struct Str(String);
+
+impl AsRef<str> for Str {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for Str {
+    fn from(s: &'static str) -> Self {
+        Self(s.to_owned())
+    }
+}
+
+impl SkipPrefix for Str {
+    fn skip_prefix(&self, bytes: usize) -> Self {
+        Self(self.0[bytes..].to_owned())
+    }
+}
+
+fn alignment(b: u8) -> Alignment {
+    match b % 3 {
+        0 => Alignment::Start,
+        1 => Alignment::Center,
+        _ => Alignment::End,
+    }
+}
+
+/// Lay the text out page by page until all of it is processed.
+fn fit_pages(layout: &TextLayout, mut text: &str) {
+    loop {
+        let processed = match layout.fit_text(text) {
+            LayoutFit::Fitting { .. } => return,
+            LayoutFit::OutOfBounds {
+                processed_chars, ..
+            } => processed_chars,
+        };
+        // The bounds are too small for even a single character.
+        if processed == 0 || !text.is_char_boundary(processed) {
+            return;
+        }
+        text = &text[processed..];
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Render off-screen.
+    std::env::set_var("SDL_VIDEODRIVER", "dummy");
+
+    let (header, rest) = match data {
+        [a, b, c, d, e, f, rest @ ..] => ([*a, *b, *c, *d, *e, *f], rest),
+        _ => return,
+    };
+    let text = match std::str::from_utf8(rest) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+
+    let [style, align, x0, y0, width, height] = header;
+    let style = STYLES[style as usize % STYLES.len()];
+    let screen = screen();
+    let origin = Point::new(x0 as i16, y0 as i16);
+    let bounds = Rect::new(origin, origin + Offset::new(width as i16, height as i16)).clamp(screen);
+    let layout = TextLayout::new(style)
+        .with_bounds(bounds)
+        .with_align(alignment(align));
+    fit_pages(&layout, text);
+    layout.render_text(text);
+
+    let font = style.text_font;
+    let suffix = font.longest_suffix(width as i16, text);
+    assert!(text.is_char_boundary(text.len() - suffix));
+    let line = long_line_content_with_ellipsis(text, "...", font, width as i16);
+    assert!(text.ends_with(line.trim_start_matches("...")));
+
+    let mut paragraphs = ParagraphVecShort::new();
+    for (i, chunk) in text.split('\n').enumerate() {
+        let style = &STYLES[(i + header[0] as usize) % STYLES.len()];
+        if paragraphs
+            .push(Paragraph::new(style, Str(chunk.to_owned())))
+            .is_err()
+        {
+            break;
+        }
+    }
+    let mut paragraphs = Paragraphs::new(paragraphs);
+    paragraphs.place(bounds);
+    let pages = paragraphs.page_count();
+    for page in 0..pages.min(16) {
+        paragraphs.change_page(page);
+        paragraphs.paint();
+    }
+});
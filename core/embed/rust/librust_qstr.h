@@ -8,11 +8,17 @@ static void _librust_qstrs(void) {
   MP_QSTR_CANCELLED;
   MP_QSTR_CONFIRMED;
   MP_QSTR_INFO;
+  MP_QSTR_InvalidFieldError;
   MP_QSTR_Layout;
   MP_QSTR_MESSAGE_NAME;
   MP_QSTR_MESSAGE_WIRE_TYPE;
+  MP_QSTR_MORE;
+  MP_QSTR_MissingFieldError;
   MP_QSTR_Msg;
   MP_QSTR_MsgDef;
+  MP_QSTR_SELECTED;
+  MP_QSTR_ValidationError;
+  MP_QSTR_ValueResult;
   MP_QSTR___dict__;
   MP_QSTR___name__;
   MP_QSTR_account;
@@ -20,6 +26,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_accounts;
   MP_QSTR_action;
   MP_QSTR_active;
+  MP_QSTR_active_page;
   MP_QSTR_address;
   MP_QSTR_address_label;
   MP_QSTR_address_title;
@@ -32,19 +39,36 @@ static void _librust_qstrs(void) {
   MP_QSTR_amount_value;
   MP_QSTR_app_name;
   MP_QSTR_attach_timer_fn;
+  MP_QSTR_attempts;
+  MP_QSTR_audio_feedback;
+  MP_QSTR_available;
+  MP_QSTR_base64;
   MP_QSTR_bootscreen;
+  MP_QSTR_bounce;
   MP_QSTR_bounds;
   MP_QSTR_button;
   MP_QSTR_button_event;
   MP_QSTR_cancel_arrow;
   MP_QSTR_cancel_cross;
+  MP_QSTR_cancellable;
+  MP_QSTR_carry_in;
+  MP_QSTR_carry_out;
   MP_QSTR_case_sensitive;
+  MP_QSTR_changes;
   MP_QSTR_check_homescreen_format;
+  MP_QSTR_check_share_words;
+  MP_QSTR_checks;
+  MP_QSTR_choose_account;
+  MP_QSTR_choose_from_list;
   MP_QSTR_chunkify;
+  MP_QSTR_clear_resume_point;
+  MP_QSTR_coin;
   MP_QSTR_coinjoin_authorized;
+  MP_QSTR_commit_translations;
   MP_QSTR_confirm_action;
   MP_QSTR_confirm_address;
   MP_QSTR_confirm_backup;
+  MP_QSTR_confirm_blind_signing;
   MP_QSTR_confirm_blob;
   MP_QSTR_confirm_coinjoin;
   MP_QSTR_confirm_emphasized;
@@ -52,26 +76,41 @@ static void _librust_qstrs(void) {
   MP_QSTR_confirm_firmware_update;
   MP_QSTR_confirm_homescreen;
   MP_QSTR_confirm_joint_total;
+  MP_QSTR_confirm_language;
   MP_QSTR_confirm_modify_fee;
   MP_QSTR_confirm_modify_output;
   MP_QSTR_confirm_more;
   MP_QSTR_confirm_output_address;
   MP_QSTR_confirm_output_amount;
+  MP_QSTR_confirm_payment_uri;
   MP_QSTR_confirm_properties;
   MP_QSTR_confirm_recovery;
   MP_QSTR_confirm_reset_device;
+  MP_QSTR_confirm_settings_change;
+  MP_QSTR_confirm_streamed;
+  MP_QSTR_confirm_template;
+  MP_QSTR_confirm_terms;
   MP_QSTR_confirm_total;
   MP_QSTR_confirm_value;
   MP_QSTR_confirm_with_info;
   MP_QSTR_count;
   MP_QSTR_data;
+  MP_QSTR_debug_tree;
   MP_QSTR_decode;
+  MP_QSTR_delay_ms;
   MP_QSTR_description;
+  MP_QSTR_destructive;
+  MP_QSTR_detail;
   MP_QSTR_details_title;
   MP_QSTR_disable_animation;
+  MP_QSTR_disable_transitions;
+  MP_QSTR_dropped;
   MP_QSTR_dry_run;
+  MP_QSTR_edit_list;
+  MP_QSTR_enable_profiler;
   MP_QSTR_encode;
   MP_QSTR_encoded_length;
+  MP_QSTR_erase_translations;
   MP_QSTR_extra;
   MP_QSTR_fee_amount;
   MP_QSTR_fee_label;
@@ -79,6 +118,8 @@ static void _librust_qstrs(void) {
   MP_QSTR_fee_title;
   MP_QSTR_fee_value;
   MP_QSTR_fingerprint;
+  MP_QSTR_flow;
+  MP_QSTR_high_contrast;
   MP_QSTR_hold;
   MP_QSTR_hold_danger;
   MP_QSTR_horizontal;
@@ -87,37 +128,79 @@ static void _librust_qstrs(void) {
   MP_QSTR_indeterminate;
   MP_QSTR_info_button;
   MP_QSTR_is_type_of;
+  MP_QSTR_item;
   MP_QSTR_items;
+  MP_QSTR_kind;
   MP_QSTR_label;
+  MP_QSTR_language_changed;
+  MP_QSTR_larger_fonts;
+  MP_QSTR_level;
   MP_QSTR_lines;
+  MP_QSTR_load_translations;
+  MP_QSTR_longer_timeouts;
   MP_QSTR_max_count;
   MP_QSTR_max_feerate;
   MP_QSTR_max_len;
   MP_QSTR_max_rounds;
   MP_QSTR_min_count;
+  MP_QSTR_missed_release;
   MP_QSTR_multiple_pages_texts;
+  MP_QSTR_name;
   MP_QSTR_notification;
   MP_QSTR_notification_level;
+  MP_QSTR_page;
   MP_QSTR_page_count;
   MP_QSTR_pages;
   MP_QSTR_paint;
   MP_QSTR_path;
+  MP_QSTR_path_unusual;
+  MP_QSTR_pop_overlay;
+  MP_QSTR_progress;
   MP_QSTR_progress_event;
   MP_QSTR_prompt;
+  MP_QSTR_pubkey;
+  MP_QSTR_push_overlay;
+  MP_QSTR_qr;
+  MP_QSTR_qr_data;
   MP_QSTR_qr_title;
+  MP_QSTR_reduce_motion;
+  MP_QSTR_rejected;
   MP_QSTR_request_bip39;
   MP_QSTR_request_complete_repaint;
+  MP_QSTR_request_label;
   MP_QSTR_request_number;
   MP_QSTR_request_passphrase;
   MP_QSTR_request_pin;
   MP_QSTR_request_slip39;
+  MP_QSTR_resume_point;
   MP_QSTR_reverse;
+  MP_QSTR_rows;
+  MP_QSTR_save_resume_point;
+  MP_QSTR_scheme;
+  MP_QSTR_seconds;
+  MP_QSTR_secrets_equal;
+  MP_QSTR_seed;
   MP_QSTR_select_word;
   MP_QSTR_select_word_count;
+  MP_QSTR_set_accessibility;
+  MP_QSTR_set_autodim;
+  MP_QSTR_set_battery_level;
+  MP_QSTR_set_button_quirks;
+  MP_QSTR_set_density;
+  MP_QSTR_set_keyboard_layout;
+  MP_QSTR_set_replay_seed;
+  MP_QSTR_set_theme_inverted;
+  MP_QSTR_set_wallet_badge;
+  MP_QSTR_settings;
+  MP_QSTR_share_groups;
   MP_QSTR_share_words;
   MP_QSTR_show_address_details;
+  MP_QSTR_show_blob;
+  MP_QSTR_show_check_result;
   MP_QSTR_show_checklist;
+  MP_QSTR_show_entropy_mixing;
   MP_QSTR_show_error;
+  MP_QSTR_show_eta;
   MP_QSTR_show_group_share_success;
   MP_QSTR_show_homescreen;
   MP_QSTR_show_info;
@@ -125,38 +208,59 @@ static void _librust_qstrs(void) {
   MP_QSTR_show_lockscreen;
   MP_QSTR_show_mismatch;
   MP_QSTR_show_passphrase;
+  MP_QSTR_show_pin_lockout;
   MP_QSTR_show_progress;
   MP_QSTR_show_progress_coinjoin;
+  MP_QSTR_show_pubkey_details;
+  MP_QSTR_show_receipt;
   MP_QSTR_show_remaining_shares;
+  MP_QSTR_show_result;
+  MP_QSTR_show_settings_menu;
   MP_QSTR_show_share_words;
   MP_QSTR_show_simple;
   MP_QSTR_show_success;
   MP_QSTR_show_warning;
+  MP_QSTR_shuffle;
+  MP_QSTR_shuffle_on_keypress;
   MP_QSTR_sign;
+  MP_QSTR_signed;
   MP_QSTR_skip_first_paint;
   MP_QSTR_spending_amount;
+  MP_QSTR_step;
   MP_QSTR_subprompt;
   MP_QSTR_subtitle;
+  MP_QSTR_success;
+  MP_QSTR_text;
   MP_QSTR_time_ms;
   MP_QSTR_timer;
   MP_QSTR_title;
+  MP_QSTR_toast;
+  MP_QSTR_total;
   MP_QSTR_total_amount;
   MP_QSTR_total_fee_new;
   MP_QSTR_total_label;
   MP_QSTR_touch_event;
   MP_QSTR_trace;
+  MP_QSTR_trace_binary;
+  MP_QSTR_trace_paint_counters;
+  MP_QSTR_translations_max_length;
   MP_QSTR_trezorproto;
   MP_QSTR_trezorui2;
   MP_QSTR_tutorial;
   MP_QSTR_type_for_name;
   MP_QSTR_type_for_wire;
+  MP_QSTR_update;
+  MP_QSTR_uri;
   MP_QSTR_usb_event;
   MP_QSTR_user_fee_change;
   MP_QSTR_value;
   MP_QSTR_verb;
   MP_QSTR_verb_cancel;
+  MP_QSTR_version;
   MP_QSTR_warning;
+  MP_QSTR_wipe_carry;
   MP_QSTR_words;
+  MP_QSTR_write_translations;
   MP_QSTR_wrong_pin;
   MP_QSTR_xpubs;
 }
@@ -18,6 +18,9 @@ uint32_t screen_menu(secbool firmware_present);
 void screen_connect(bool initial_setup);
 void screen_fatal_error_rust(const char* title, const char* msg,
                              const char* footer);
+// diagnostics are shown as a QR code, up to 200 ASCII characters
+void screen_fatal_error_qr_rust(const char* title, const char* msg,
+                                const char* footer, const char* diagnostics);
 void screen_wipe_success(void);
 void screen_wipe_fail(void);
 uint32_t screen_install_success(uint8_t restart_seconds, bool initial_setup,
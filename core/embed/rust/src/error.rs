@@ -24,6 +24,12 @@ pub enum Error {
     ValueError(&'static CStr),
     #[cfg(feature = "micropython")]
     ValueErrorParam(&'static CStr, Obj),
+    /// Required argument of a layout was not passed.
+    #[cfg(feature = "micropython")]
+    MissingField(Qstr),
+    /// Argument of a layout has a wrong type or value.
+    #[cfg(feature = "micropython")]
+    InvalidField(Qstr, &'static CStr),
 }
 
 #[macro_export]
@@ -33,6 +39,13 @@ macro_rules! value_error {
     };
 }
 
+#[macro_export]
+macro_rules! field_error {
+    ($field:expr, $msg:expr) => {
+        Error::InvalidField($field, cstr_core::cstr!($msg))
+    };
+}
+
 #[cfg(feature = "micropython")]
 impl Error {
     /// Create an exception instance matching the error code. The result of this
@@ -71,6 +84,27 @@ impl Error {
                 Error::AttributeError(attr) => {
                     ffi::mp_obj_new_exception_args(&ffi::mp_type_AttributeError, 1, &attr.into())
                 }
+                Error::MissingField(field) => ffi::mp_obj_new_exception_args(
+                    &ffi::mp_type_MissingFieldError,
+                    1,
+                    &field.into(),
+                ),
+                Error::InvalidField(field, msg) => {
+                    if let Ok(msg) = msg.try_into() {
+                        let args = [field.into(), msg];
+                        ffi::mp_obj_new_exception_args(
+                            &ffi::mp_type_InvalidFieldError,
+                            2,
+                            args.as_ptr(),
+                        )
+                    } else {
+                        ffi::mp_obj_new_exception_args(
+                            &ffi::mp_type_InvalidFieldError,
+                            1,
+                            &field.into(),
+                        )
+                    }
+                }
             }
         }
     }
@@ -35,6 +35,7 @@ pub mod strutil;
 
 #[cfg(feature = "debug")]
 #[cfg(not(test))]
+#[cfg(any(not(feature = "test"), feature = "clippy"))]
 #[panic_handler]
 /// More detailed panic handling. The difference against
 /// default `panic` below is that this "debug" version
@@ -171,6 +171,117 @@ impl From<&'static str> for StrBuffer {
     }
 }
 
+/// Immutable UTF-8 string borrowed from the data of a MicroPython `str` or
+/// `bytes` object, without copying it.
+///
+/// Unlike `StrBuffer`, the data pointer does not need to be a GC head pointer,
+/// e.g. it can point into the middle of a `bytes` buffer. The owning object is
+/// pinned instead: it is kept in the `owner` field, so that GC sees a reference
+/// to it for as long as the `BorrowedStr` lives in GC-scanned memory, i.e. on
+/// the stack or inside of a GC-allocated object such as a layout.
+///
+/// # Safety
+///
+/// Same assumptions as for `StrBuffer` apply -- the data of `str` and `bytes`
+/// objects is immutable.
+#[derive(Clone)]
+pub struct BorrowedStr {
+    owner: Obj,
+    ptr: *const u8,
+    len: u16,
+    off: u16,
+}
+
+impl BorrowedStr {
+    pub fn empty() -> Self {
+        Self::from("")
+    }
+
+    /// Object owning the data, `None` for static strings.
+    pub fn owner(&self) -> Option<Obj> {
+        (!self.owner.is_null()).then_some(self.owner)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        if self.ptr.is_null() {
+            &[]
+        } else {
+            // SAFETY: Data is pinned by `owner`, or static.
+            unsafe { slice::from_raw_parts(self.ptr.add(self.off.into()), self.len.into()) }
+        }
+    }
+}
+
+impl TryFrom<Obj> for BorrowedStr {
+    type Error = Error;
+
+    fn try_from(obj: Obj) -> Result<Self, Self::Error> {
+        if !obj.is_str() && !obj.is_bytes() {
+            return Err(Error::TypeError);
+        }
+        let bufinfo = get_buffer_info(obj, ffi::MP_BUFFER_READ)?;
+        let new = Self {
+            owner: obj,
+            ptr: bufinfo.buf as _,
+            len: bufinfo.len.try_into()?,
+            off: 0,
+        };
+        // Unlike `str`, `bytes` are not guaranteed to be UTF-8.
+        if str::from_utf8(new.as_bytes()).is_err() {
+            return Err(Error::TypeError);
+        }
+        Ok(new)
+    }
+}
+
+impl SkipPrefix for BorrowedStr {
+    fn skip_prefix(&self, skip_bytes: usize) -> Self {
+        let off: u16 = unwrap!(skip_bytes.try_into());
+        assert!(off <= self.len);
+        assert!(self.as_ref().is_char_boundary(skip_bytes));
+        Self {
+            owner: self.owner,
+            ptr: self.ptr,
+            len: self.len - off,
+            off: self.off + off,
+        }
+    }
+}
+
+impl Default for BorrowedStr {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl Deref for BorrowedStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_ref()
+    }
+}
+
+impl AsRef<str> for BorrowedStr {
+    fn as_ref(&self) -> &str {
+        // SAFETY: UTF-8 validity is checked at construction time, `skip_prefix()`
+        // splits the string at character boundary.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+impl From<&'static str> for BorrowedStr {
+    fn from(val: &'static str) -> Self {
+        // Static data needs no owner.
+        Self {
+            owner: Obj::const_null(),
+            ptr: val.as_ptr(),
+            len: unwrap!(val.len().try_into()),
+            off: 0,
+        }
+    }
+}
+
 fn get_buffer_info(obj: Obj, flags: u32) -> Result<ffi::mp_buffer_info_t, Error> {
     let mut bufinfo = ffi::mp_buffer_info_t {
         buf: ptr::null_mut(),
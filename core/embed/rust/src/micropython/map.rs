@@ -112,6 +112,34 @@ impl Map {
         }
     }
 
+    /// Get the value of a required keyword argument of a layout, converted to
+    /// `T`. Failures are reported as validation errors naming the argument.
+    pub fn get_field<T>(&self, field: Qstr) -> Result<T, Error>
+    where
+        T: TryFrom<Obj>,
+        <T as TryFrom<Obj>>::Error: Into<Error>,
+    {
+        let obj = self.get(field).map_err(|_| Error::MissingField(field))?;
+        obj.try_into().map_err(|err| match err.into() {
+            Error::TypeError => field_error!(field, "Invalid type."),
+            Error::OutOfRange => field_error!(field, "Value out of range."),
+            err => err,
+        })
+    }
+
+    /// Same as `get_field()`, but `None` is accepted as the value.
+    pub fn get_optional_field<T>(&self, field: Qstr) -> Result<Option<T>, Error>
+    where
+        T: TryFrom<Obj>,
+        <T as TryFrom<Obj>>::Error: Into<Error>,
+    {
+        if self.get(field).map_err(|_| Error::MissingField(field))? == Obj::const_none() {
+            Ok(None)
+        } else {
+            self.get_field(field).map(Some)
+        }
+    }
+
     pub fn set(&mut self, index: impl Into<Obj>, value: impl Into<Obj>) -> Result<(), Error> {
         self.set_obj(index.into(), value.into())
     }
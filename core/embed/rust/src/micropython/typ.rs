@@ -17,6 +17,14 @@ impl Type {
         ObjBase { type_: self }
     }
 
+    /// Convert the type to a MicroPython object, e.g. to export it in a module.
+    pub const fn as_obj(&'static self) -> Obj {
+        // SAFETY:
+        //  - Types are objects with a base.
+        //  - 'static lifetime holds us in place.
+        unsafe { Obj::from_ptr(self as *const _ as *mut _) }
+    }
+
     #[cfg(feature = "debug")]
     pub fn name(&self) -> &'static str {
         use super::qstr::Qstr;
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::trezorhal::storage::{get, get_length, StorageResult};
+use crate::trezorhal::storage::{delete, get, get_length, set, StorageResult};
 
 pub const HOMESCREEN_MAX_SIZE: usize = 16384;
 
@@ -35,6 +35,7 @@ const SD_SALT_AUTH_KEY: u16 = FLAG_PUBLIC | APP_DEVICE | 0x0012;
 const INITIALIZED: u16 = FLAG_PUBLIC | APP_DEVICE | 0x0013;
 const SAFETY_CHECK_LEVEL: u16 = APP_DEVICE | 0x0014;
 const EXPERIMENTAL_FEATURES: u16 = APP_DEVICE | 0x0015;
+const RESUME_POINT: u16 = APP_DEVICE | 0x001F;
 
 pub fn get_avatar_len() -> StorageResult<usize> {
     get_length(HOMESCREEN)
@@ -46,3 +47,15 @@ pub fn load_avatar(dest: &mut [u8]) -> StorageResult<()> {
     ensure!(dest_len == result.len(), "Internal error in load_avatar");
     Ok(())
 }
+
+pub fn load_resume_point(dest: &mut [u8]) -> StorageResult<&[u8]> {
+    get(RESUME_POINT, dest)
+}
+
+pub fn save_resume_point(data: &[u8]) -> StorageResult<()> {
+    set(RESUME_POINT, data)
+}
+
+pub fn delete_resume_point() -> StorageResult<()> {
+    delete(RESUME_POINT)
+}
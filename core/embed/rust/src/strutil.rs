@@ -1,4 +1,9 @@
-use heapless::String;
+use core::hint::black_box;
+
+use heapless::{String, Vec};
+
+#[cfg(feature = "ui")]
+use crate::ui::translations::TranslatedString;
 
 /// Trait for slicing off string prefix by a specified number of bytes.
 /// See `StringType` for deeper explanation.
@@ -6,10 +11,10 @@ pub trait SkipPrefix {
     fn skip_prefix(&self, bytes: usize) -> Self;
 }
 
-// XXX only implemented in bootloader, as we don't want &str to satisfy
-// StringType in the main firmware. This is because we want to avoid duplication
-// of every StringType-parametrized component.
-#[cfg(feature = "bootloader")]
+// XXX only implemented in bootloader and tests, as we don't want &str to
+// satisfy StringType in the main firmware. This is because we want to avoid
+// duplication of every StringType-parametrized component.
+#[cfg(any(feature = "bootloader", test))]
 impl SkipPrefix for &str {
     fn skip_prefix(&self, chars: usize) -> Self {
         &self[chars..]
@@ -23,9 +28,18 @@ impl SkipPrefix for &str {
 /// - create a new string by skipping some number of bytes (SkipPrefix) - used
 ///   when rendering continuations of long strings
 /// - create a new string from a string literal (From<&'static str>)
-pub trait StringType: AsRef<str> + From<&'static str> + SkipPrefix {}
+/// - create a new string in the current language (From<TranslatedString>)
+#[cfg(feature = "ui")]
+pub trait StringType:
+    AsRef<str> + From<&'static str> + From<TranslatedString> + SkipPrefix
+{
+}
 
-impl<T> StringType for T where T: AsRef<str> + From<&'static str> + SkipPrefix {}
+#[cfg(feature = "ui")]
+impl<T> StringType for T where
+    T: AsRef<str> + From<&'static str> + From<TranslatedString> + SkipPrefix
+{
+}
 
 /// Unified-length String type, long enough for most simple use-cases.
 pub type ShortString = String<50>;
@@ -72,3 +86,325 @@ pub fn format_i64(num: i64, buffer: &mut [u8]) -> Option<&str> {
         }
     }
 }
+
+/// Whether `ch` continues the grapheme cluster of the preceding character:
+/// combining marks, variation selectors, emoji modifiers and the zero-width
+/// joiner.
+fn extends_grapheme(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{200D}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+    )
+}
+
+/// Byte offsets of the ends of the grapheme clusters in `text`. A cluster is
+/// approximated by a character followed by the characters extending it, see
+/// `extends_grapheme`, with the zero-width joiner also gluing the next
+/// character to it.
+fn grapheme_ends(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut chars = text.char_indices().peekable();
+    core::iter::from_fn(move || {
+        let (_, mut prev) = chars.next()?;
+        while let Some(&(idx, ch)) = chars.peek() {
+            if !extends_grapheme(ch) && prev != '\u{200D}' {
+                return Some(idx);
+            }
+            prev = ch;
+            chars.next();
+        }
+        Some(text.len())
+    })
+}
+
+/// Number of grapheme clusters, i.e. user-perceived characters, in `text`.
+pub fn count_graphemes(text: &str) -> usize {
+    grapheme_ends(text).count()
+}
+
+/// The first `count` grapheme clusters of `text`, or the whole `text` if it
+/// is shorter.
+pub fn truncate_graphemes(text: &str, count: usize) -> &str {
+    match count.checked_sub(1) {
+        None => "",
+        Some(last) => &text[..grapheme_ends(text).nth(last).unwrap_or(text.len())],
+    }
+}
+
+/// `String` for secrets, e.g. PINs, passphrases and recovery words, that
+/// zeroizes the bytes it stops using, be it by shortening the content or by
+/// being dropped. Layouts are not always dropped when they go away, so inputs
+/// also `clear()` their secrets once they are no longer needed.
+pub struct SecretString<const L: usize> {
+    text: String<L>,
+}
+
+// Errors are the same as those of `heapless::String`.
+#[allow(clippy::result_unit_err)]
+impl<const L: usize> SecretString<L> {
+    pub const fn new() -> Self {
+        Self {
+            text: String::new(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.text.capacity()
+    }
+
+    pub fn push(&mut self, ch: char) -> Result<(), ()> {
+        self.text.push(ch)
+    }
+
+    pub fn push_str(&mut self, text: &str) -> Result<(), ()> {
+        self.text.push_str(text)
+    }
+
+    /// Shorten the content to `len` bytes, zeroizing the rest. Panics if `len`
+    /// is not on a char boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.text.len() {
+            return;
+        }
+        assert!(self.text.is_char_boundary(len));
+        // SAFETY: Zero bytes are valid UTF-8 on their own and they are cut off
+        // right away, at a char boundary.
+        let bytes = unsafe { self.text.as_mut_vec() };
+        for byte in bytes[len..].iter_mut() {
+            // SAFETY: `byte` is a valid reference. Volatile so that the
+            // zeroing is not optimized away.
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        self.text.truncate(len);
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.text.chars().next_back()?;
+        self.truncate(self.text.len() - ch.len_utf8());
+        Some(ch)
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Compare with `other` in time that does not depend on where the two
+    /// differ, see `ct_eq`.
+    pub fn ct_eq(&self, other: &str) -> bool {
+        ct_eq(self.as_str().as_bytes(), other.as_bytes())
+    }
+}
+
+impl<const L: usize, const M: usize> PartialEq<SecretString<M>> for SecretString<L> {
+    fn eq(&self, other: &SecretString<M>) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<const L: usize> Eq for SecretString<L> {}
+
+impl<const L: usize> core::ops::Deref for SecretString<L> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const L: usize> AsRef<str> for SecretString<L> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const L: usize> Drop for SecretString<L> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Equality of secrets, e.g. of a PIN and its re-entry or of a wipe code and
+/// the PIN, that goes through all the bytes instead of stopping at the first
+/// mismatch, so that the time it takes does not tell how much of the secret
+/// was guessed right. Only the lengths may leak.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    let mut diff = (a.len() != b.len()) as u8;
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        // Hidden from the optimizer after every byte, so that it cannot tell
+        // when `diff` stops changing and leave the loop early.
+        diff = black_box(diff | (x ^ y));
+    }
+    black_box(diff) == 0
+}
+
+/// Deepest derivation path `parse_path` accepts.
+pub const MAX_PATH_DEPTH: usize = 12;
+
+/// Long enough for a path of `MAX_PATH_DEPTH` hardened levels.
+pub type PathString = String<{ 2 + MAX_PATH_DEPTH * 12 }>;
+
+const HARDENED: u32 = 0x8000_0000;
+
+/// One level of a derivation path.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PathLevel {
+    pub index: u32,
+    pub hardened: bool,
+}
+
+impl PathLevel {
+    /// Level given as a number with an optional hardened marker, e.g. `44'`,
+    /// `44h` or `0`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (digits, hardened) = match text.strip_suffix(&['\'', 'h', 'H'][..]) {
+            Some(digits) => (digits, true),
+            None => (text, false),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let index: u32 = digits.parse().ok()?;
+        (index < HARDENED).then_some(Self { index, hardened })
+    }
+}
+
+/// How hardened levels are marked when a path is turned into text.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum HardenedMarker {
+    /// `m/44'/0'/0'`, as in BIP-32.
+    Apostrophe,
+    /// `m/44h/0h/0h`, as in output descriptors.
+    Letter,
+}
+
+impl HardenedMarker {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Apostrophe => "'",
+            Self::Letter => "h",
+        }
+    }
+}
+
+/// Levels of a path such as `m/44'/0'/0'/0/1`. Either marker is accepted,
+/// the leading `m` is optional; `None` if it is not a valid path.
+pub fn parse_path(path: &str) -> Option<Vec<PathLevel, MAX_PATH_DEPTH>> {
+    let path = path.strip_prefix('m').unwrap_or(path);
+    let path = path.strip_prefix('/').unwrap_or(path);
+    let mut levels = Vec::new();
+    if path.is_empty() {
+        return Some(levels);
+    }
+    for level in path.split('/') {
+        levels.push(PathLevel::parse(level)?).ok()?;
+    }
+    Some(levels)
+}
+
+/// `levels` as text with hardened levels marked with `marker`.
+pub fn format_path(levels: &[PathLevel], marker: HardenedMarker) -> PathString {
+    let mut result = PathString::new();
+    unwrap!(result.push('m'));
+    for level in levels {
+        let mut buffer = [0; 10];
+        unwrap!(result.push('/'));
+        unwrap!(result.push_str(unwrap!(format_i64(level.index as i64, &mut buffer))));
+        if level.hardened {
+            unwrap!(result.push_str(marker.as_str()));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphemes() {
+        // "e" with a combining acute accent, thumbs up with a skin tone and a
+        // family glued with zero-width joiners.
+        let text = "ae\u{301}\u{1F44D}\u{1F3FD}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}z";
+        assert_eq!(count_graphemes(text), 5);
+        assert_eq!(count_graphemes(""), 0);
+        assert_eq!(truncate_graphemes(text, 0), "");
+        assert_eq!(truncate_graphemes(text, 2), "ae\u{301}");
+        assert_eq!(truncate_graphemes(text, 4), &text[..text.len() - 1]);
+        assert_eq!(truncate_graphemes(text, 9), text);
+        assert_eq!(truncate_graphemes("ěšč", 2), "ěš");
+    }
+
+    #[test]
+    fn secret_string() {
+        let mut secret = SecretString::<8>::new();
+        assert!(secret.push_str("1234").is_ok());
+        assert_eq!(secret.pop(), Some('4'));
+        secret.truncate(1);
+        assert_eq!(&*secret, "1");
+        assert!(secret.push_str("2345678").is_ok());
+        assert!(secret.push('9').is_err());
+        secret.clear();
+        assert!(secret.is_empty());
+    }
+
+    #[test]
+    fn constant_time_eq() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"1234", b"1234"));
+        assert!(!ct_eq(b"1234", b"1235"));
+        assert!(!ct_eq(b"1234", b"12340"));
+        assert!(!ct_eq(b"", b"\0"));
+
+        let mut pin = SecretString::<8>::new();
+        let mut repeated = SecretString::<16>::new();
+        assert!(pin.push_str("1234").is_ok());
+        assert!(repeated.push_str("1234").is_ok());
+        assert!(pin == repeated);
+        assert!(repeated.pop().is_some());
+        assert!(pin != repeated);
+        assert!(!pin.ct_eq("123"));
+    }
+
+    #[test]
+    fn constant_time_eq_every_position() {
+        let secret = b"0123456789";
+        for i in 0..secret.len() {
+            let mut other = *secret;
+            other[i] ^= 0x80;
+            assert!(!ct_eq(secret, &other));
+            assert!(!ct_eq(&secret[..i], secret));
+            assert!(ct_eq(&secret[..i], &other[..i]));
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let levels = unwrap!(parse_path("m/84h/0'/0'/1/7"));
+        assert_eq!(levels.len(), 5);
+        assert!(levels[0].index == 84 && levels[0].hardened);
+        assert!(levels[3].index == 1 && !levels[3].hardened);
+        assert_eq!(
+            format_path(&levels, HardenedMarker::Apostrophe).as_str(),
+            "m/84'/0'/0'/1/7"
+        );
+        assert_eq!(
+            format_path(&levels, HardenedMarker::Letter).as_str(),
+            "m/84h/0h/0h/1/7"
+        );
+        assert!(unwrap!(parse_path("m")).is_empty());
+        assert!(parse_path("m/44'/x").is_none());
+        assert!(parse_path("m/2147483648").is_none());
+    }
+}
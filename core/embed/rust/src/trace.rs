@@ -1,4 +1,4 @@
-use crate::strutil::format_i64;
+use crate::{strutil::format_i64, ui::geometry::Rect};
 
 pub trait Tracer {
     fn child(&mut self, key: &str, value: &dyn Trace);
@@ -13,6 +13,16 @@ pub trait Tracer {
     fn component(&mut self, name: &str) {
         self.string("component", name);
     }
+
+    /// Screen area of the component as `[x0, y0, x1, y1]`.
+    fn bounds(&mut self, r: Rect) {
+        self.in_list("bounds", &|t| {
+            t.int(r.x0.into());
+            t.int(r.y0.into());
+            t.int(r.x1.into());
+            t.int(r.y1.into());
+        });
+    }
 }
 
 pub trait ListTracer {
@@ -25,21 +35,139 @@ pub trait ListTracer {
     fn in_list(&mut self, block: &dyn Fn(&mut dyn ListTracer));
 }
 
-/// Generic tracer based on a TraceWriter.
-pub struct JsonTracer<F: FnMut(&str)> {
+/// Piece of the trace of a component tree. Objects and lists are delimited by
+/// their start and end tokens, every value inside an object is preceded by its
+/// `Key`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Token<'a> {
+    ObjectStart,
+    ObjectEnd,
+    ListStart,
+    ListEnd,
+    Key(&'a str),
+    Int(i64),
+    String(&'a str),
+    Bool(bool),
+    Null,
+}
+
+/// Serialization of the trace tokens, e.g. into JSON text.
+pub trait TokenSink {
+    fn token(&mut self, token: Token);
+}
+
+/// Tracer turning the tracing calls of components into tokens for `S`.
+pub struct TokenTracer<S: TokenSink> {
+    sink: S,
+}
+
+impl<S: TokenSink> TokenTracer<S> {
+    pub fn new(sink: S) -> Self {
+        Self { sink }
+    }
+
+    pub fn root(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
+        self.sink.token(Token::ObjectStart);
+        block(self);
+        self.sink.token(Token::ObjectEnd);
+    }
+}
+
+impl<S: TokenSink> ListTracer for TokenTracer<S> {
+    fn child(&mut self, value: &dyn Trace) {
+        ListTracer::in_child(self, &|t| value.trace(t));
+    }
+
+    fn int(&mut self, i: i64) {
+        self.sink.token(Token::Int(i));
+    }
+
+    fn string(&mut self, s: &str) {
+        self.sink.token(Token::String(s));
+    }
+
+    fn bool(&mut self, b: bool) {
+        self.sink.token(Token::Bool(b));
+    }
+
+    fn in_child(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
+        self.sink.token(Token::ObjectStart);
+        block(self);
+        self.sink.token(Token::ObjectEnd);
+    }
+
+    fn in_list(&mut self, block: &dyn Fn(&mut dyn ListTracer)) {
+        self.sink.token(Token::ListStart);
+        block(self);
+        self.sink.token(Token::ListEnd);
+    }
+}
+
+impl<S: TokenSink> Tracer for TokenTracer<S> {
+    fn child(&mut self, key: &str, value: &dyn Trace) {
+        Tracer::in_child(self, key, &|t| value.trace(t));
+    }
+
+    fn int(&mut self, key: &str, i: i64) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::Int(i));
+    }
+
+    fn string(&mut self, key: &str, s: &str) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::String(s));
+    }
+
+    fn bool(&mut self, key: &str, b: bool) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::Bool(b));
+    }
+
+    fn null(&mut self, key: &str) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::Null);
+    }
+
+    fn in_child(&mut self, key: &str, block: &dyn Fn(&mut dyn Tracer)) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::ObjectStart);
+        block(self);
+        self.sink.token(Token::ObjectEnd);
+    }
+
+    fn in_list(&mut self, key: &str, block: &dyn Fn(&mut dyn ListTracer)) {
+        self.sink.token(Token::Key(key));
+        self.sink.token(Token::ListStart);
+        block(self);
+        self.sink.token(Token::ListEnd);
+    }
+}
+
+/// Tracer writing JSON text.
+pub type JsonTracer<F> = TokenTracer<JsonWriter<F>>;
+
+/// Tracer writing the compact binary encoding of `BinaryWriter`.
+pub type BinaryTracer<F> = TokenTracer<BinaryWriter<F>>;
+
+/// Writes the tokens as JSON text, in pieces passed to `write_fn`.
+pub struct JsonWriter<F: FnMut(&str)> {
     write_fn: F,
     write_buf: [u8; 32],
     buf_pos: usize,
+    /// No value has been written into the current object or list yet.
     first: bool,
+    /// The value of the key just written comes next.
+    after_key: bool,
 }
 
-impl<F: FnMut(&str)> JsonTracer<F> {
+impl<F: FnMut(&str)> JsonWriter<F> {
     pub fn new(write_fn: F) -> Self {
         Self {
             write_fn,
             write_buf: [0; 32],
             buf_pos: 0,
             first: true,
+            after_key: false,
         }
     }
 
@@ -98,99 +226,122 @@ impl<F: FnMut(&str)> JsonTracer<F> {
         }
     }
 
-    fn key(&mut self, key: &str) {
-        self.maybe_comma();
-        self.write_str_quoted(key);
-        (self.write_fn)(": ");
-    }
-
-    pub fn root(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
-        (self.write_fn)("{");
-        block(self);
-        (self.write_fn)("}");
+    /// Separate a value from the previous one, unless it belongs to a key.
+    fn value(&mut self) {
+        if !core::mem::replace(&mut self.after_key, false) {
+            self.maybe_comma();
+        }
     }
 }
 
-impl<F: FnMut(&str)> ListTracer for JsonTracer<F> {
-    fn child(&mut self, value: &dyn Trace) {
-        ListTracer::in_child(self, &|t| value.trace(t));
-    }
-
-    fn int(&mut self, i: i64) {
-        self.maybe_comma();
-        self.write_int(i);
-    }
-
-    fn string(&mut self, s: &str) {
-        self.maybe_comma();
-        self.write_str_quoted(s);
-    }
-
-    fn bool(&mut self, b: bool) {
-        self.maybe_comma();
-        (self.write_fn)(if b { "true" } else { "false" });
-    }
-
-    fn in_child(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
-        self.maybe_comma();
-        self.first = true;
-        (self.write_fn)("{");
-        block(self);
-        (self.write_fn)("}");
-        self.first = false;
-    }
-
-    fn in_list(&mut self, block: &dyn Fn(&mut dyn ListTracer)) {
-        self.maybe_comma();
-        self.first = true;
-        (self.write_fn)("[");
-        block(self);
-        (self.write_fn)("]");
-        self.first = false;
+impl<F: FnMut(&str)> TokenSink for JsonWriter<F> {
+    fn token(&mut self, token: Token) {
+        match token {
+            Token::ObjectStart | Token::ListStart => {
+                self.value();
+                (self.write_fn)(if token == Token::ObjectStart {
+                    "{"
+                } else {
+                    "["
+                });
+                self.first = true;
+            }
+            Token::ObjectEnd | Token::ListEnd => {
+                (self.write_fn)(if token == Token::ObjectEnd { "}" } else { "]" });
+                self.first = false;
+            }
+            Token::Key(key) => {
+                self.maybe_comma();
+                self.write_str_quoted(key);
+                (self.write_fn)(": ");
+                self.after_key = true;
+            }
+            Token::Int(i) => {
+                self.value();
+                self.write_int(i);
+            }
+            Token::String(s) => {
+                self.value();
+                self.write_str_quoted(s);
+            }
+            Token::Bool(b) => {
+                self.value();
+                (self.write_fn)(if b { "true" } else { "false" });
+            }
+            Token::Null => {
+                self.value();
+                (self.write_fn)("null");
+            }
+        }
     }
 }
 
-impl<F: FnMut(&str)> Tracer for JsonTracer<F> {
-    fn child(&mut self, key: &str, value: &dyn Trace) {
-        Tracer::in_child(self, key, &|t| value.trace(t));
-    }
+/// Tag bytes of the tokens in the binary encoding.
+const TAG_OBJECT_START: u8 = 0x01;
+const TAG_OBJECT_END: u8 = 0x02;
+const TAG_LIST_START: u8 = 0x03;
+const TAG_LIST_END: u8 = 0x04;
+const TAG_KEY: u8 = 0x05;
+const TAG_INT: u8 = 0x06;
+const TAG_STRING: u8 = 0x07;
+const TAG_FALSE: u8 = 0x08;
+const TAG_TRUE: u8 = 0x09;
+const TAG_NULL: u8 = 0x0a;
 
-    fn int(&mut self, key: &str, i: i64) {
-        self.key(key);
-        self.write_int(i);
-    }
-
-    fn string(&mut self, key: &str, s: &str) {
-        self.key(key);
-        self.write_str_quoted(s);
-    }
+/// Writes the tokens in a compact binary encoding, in pieces passed to
+/// `write_fn`. Every token is a tag byte, `Int` followed by the zigzag-encoded
+/// number and `Key` and `String` by the length of the UTF-8 text and the text,
+/// numbers and lengths as LEB128 varints.
+pub struct BinaryWriter<F: FnMut(&[u8])> {
+    write_fn: F,
+}
 
-    fn bool(&mut self, key: &str, b: bool) {
-        self.key(key);
-        (self.write_fn)(if b { "true" } else { "false" });
+impl<F: FnMut(&[u8])> BinaryWriter<F> {
+    pub fn new(write_fn: F) -> Self {
+        Self { write_fn }
     }
 
-    fn null(&mut self, key: &str) {
-        self.key(key);
-        (self.write_fn)("null");
+    fn write_varint(&mut self, mut n: u64) {
+        let mut buf = [0; 10];
+        let mut len = 0;
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                buf[len] = byte;
+                len += 1;
+                break;
+            }
+            buf[len] = byte | 0x80;
+            len += 1;
+        }
+        (self.write_fn)(&buf[..len]);
     }
 
-    fn in_child(&mut self, key: &str, block: &dyn Fn(&mut dyn Tracer)) {
-        self.key(key);
-        (self.write_fn)("{");
-        self.first = true;
-        block(self);
-        (self.write_fn)("}");
-        self.first = false;
+    fn write_tagged_str(&mut self, tag: u8, s: &str) {
+        (self.write_fn)(&[tag]);
+        self.write_varint(s.len() as u64);
+        (self.write_fn)(s.as_bytes());
     }
+}
 
-    fn in_list(&mut self, key: &str, block: &dyn Fn(&mut dyn ListTracer)) {
-        self.key(key);
-        (self.write_fn)("[");
-        self.first = true;
-        block(self);
-        (self.write_fn)("]");
-        self.first = false;
+impl<F: FnMut(&[u8])> TokenSink for BinaryWriter<F> {
+    fn token(&mut self, token: Token) {
+        match token {
+            Token::ObjectStart => (self.write_fn)(&[TAG_OBJECT_START]),
+            Token::ObjectEnd => (self.write_fn)(&[TAG_OBJECT_END]),
+            Token::ListStart => (self.write_fn)(&[TAG_LIST_START]),
+            Token::ListEnd => (self.write_fn)(&[TAG_LIST_END]),
+            Token::Key(key) => self.write_tagged_str(TAG_KEY, key),
+            Token::Int(i) => {
+                (self.write_fn)(&[TAG_INT]);
+                self.write_varint(((i << 1) ^ (i >> 63)) as u64);
+            }
+            Token::String(s) => self.write_tagged_str(TAG_STRING, s),
+            Token::Bool(false) => (self.write_fn)(&[TAG_FALSE]),
+            Token::Bool(true) => (self.write_fn)(&[TAG_TRUE]),
+            Token::Null => (self.write_fn)(&[TAG_NULL]),
+        }
     }
 }
 
@@ -208,10 +359,61 @@ pub mod tests {
 
     pub fn trace(val: &impl Trace) -> Value {
         let mut buf = Vec::new();
-        let mut tracer = JsonTracer::new(|text| buf.extend_from_slice(text.as_bytes()));
+        let mut tracer = JsonTracer::new(JsonWriter::new(|text| {
+            buf.extend_from_slice(text.as_bytes())
+        }));
         tracer.root(&|t| val.trace(t));
         let s = String::from_utf8(buf).unwrap();
         //crate::micropython::print::print(s.as_str());
         s.parse().unwrap()
     }
+
+    struct Sample;
+
+    impl Trace for Sample {
+        fn trace(&self, t: &mut dyn Tracer) {
+            t.component("Sample");
+            t.int("count", -2);
+            t.in_list("items", &|l| {
+                l.bool(true);
+                l.in_child(&|c| c.null("none"));
+            });
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let mut buf = Vec::new();
+        let mut tracer = JsonTracer::new(JsonWriter::new(|text| {
+            buf.extend_from_slice(text.as_bytes())
+        }));
+        tracer.root(&|t| Sample.trace(t));
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r#"{"component": "Sample", "count": -2, "items": [true, {"none": null}]}"#
+        );
+    }
+
+    #[test]
+    fn test_binary() {
+        let mut buf = Vec::new();
+        let mut tracer = BinaryTracer::new(BinaryWriter::new(|bytes| buf.extend_from_slice(bytes)));
+        tracer.root(&|t| Sample.trace(t));
+        let mut expected = vec![TAG_OBJECT_START, TAG_KEY, 9];
+        expected.extend_from_slice(b"component");
+        expected.extend_from_slice(&[TAG_STRING, 6]);
+        expected.extend_from_slice(b"Sample");
+        expected.extend_from_slice(&[TAG_KEY, 5]);
+        expected.extend_from_slice(b"count");
+        expected.extend_from_slice(&[TAG_INT, 3, TAG_KEY, 5]);
+        expected.extend_from_slice(b"items");
+        expected.extend_from_slice(&[TAG_LIST_START, TAG_TRUE, TAG_OBJECT_START, TAG_KEY, 4]);
+        expected.extend_from_slice(b"none");
+        expected.extend_from_slice(&[TAG_NULL, TAG_OBJECT_END, TAG_LIST_END, TAG_OBJECT_END]);
+        assert_eq!(buf, expected);
+
+        let mut buf = Vec::new();
+        BinaryWriter::new(|bytes: &[u8]| buf.extend_from_slice(bytes)).token(Token::Int(300));
+        assert_eq!(buf, [TAG_INT, 0xd8, 0x04]);
+    }
 }
@@ -0,0 +1,8 @@
+use super::ffi;
+
+/// Play a tone of `freq_hz`, 0 for silence, blocking for `duration_ms`.
+pub fn play_tone(freq_hz: u16, duration_ms: u16) {
+    unsafe {
+        ffi::audio_play_tone(freq_hz, duration_ms);
+    }
+}
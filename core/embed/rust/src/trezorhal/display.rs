@@ -23,6 +23,10 @@ pub fn backlight(val: i32) -> i32 {
     unsafe { ffi::display_backlight(val) }
 }
 
+pub fn get_orientation() -> i32 {
+    unsafe { ffi::display_get_orientation() }
+}
+
 pub fn text(baseline_x: i16, baseline_y: i16, text: &str, font: i32, fgcolor: u16, bgcolor: u16) {
     unsafe {
         ffi::display_text(
@@ -154,6 +158,15 @@ pub fn get_offset() -> (i16, i16) {
     }
 }
 
+pub fn set_offset(x: i16, y: i16) {
+    unsafe {
+        let mut set_xy: [c_int; 2] = [x.into(), y.into()];
+        let mut get_x: c_int = 0;
+        let mut get_y: c_int = 0;
+        ffi::display_offset(set_xy.as_mut_ptr(), &mut get_x, &mut get_y);
+    }
+}
+
 pub fn sync() {
     unsafe {
         ffi::display_sync();
@@ -171,3 +184,13 @@ pub fn clear() {
         ffi::display_clear();
     }
 }
+
+/// Read back the top-left `width` x `height` pixels of the emulator screen,
+/// row by row.
+#[cfg(test)]
+pub fn copy_rgb565(dest: &mut [u16], width: u16, height: u16) {
+    assert!(dest.len() >= width as usize * height as usize);
+    unsafe {
+        ffi::display_copy_rgb565(dest.as_mut_ptr(), width, height);
+    }
+}
@@ -1,4 +1,5 @@
-pub mod bip39;
+#[cfg(feature = "audio")]
+pub mod audio;
 #[macro_use]
 #[allow(unused_macros)]
 pub mod fatal_error;
@@ -14,6 +15,8 @@ pub mod random;
 pub mod rgb_led;
 pub mod slip39;
 pub mod storage;
+#[cfg(feature = "translations")]
+pub mod translations;
 pub mod usb;
 pub mod uzlib;
 pub mod wordlist;
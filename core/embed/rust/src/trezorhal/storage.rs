@@ -241,24 +241,27 @@ pub fn delete(appkey: u16) -> StorageResult<()> {
     }
 }
 
+/// Start from wiped storage on the emulated flash, unlocked with the empty PIN
+/// if `unlock` is set. For the tests of code keeping its data in the storage.
+#[cfg(test)]
+pub fn init_storage(unlock: bool) {
+    unsafe {
+        ffi::flash_init();
+    }
+    init();
+    wipe();
+    lock();
+    if unlock {
+        self::unlock("", None);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     const APPKEY: u16 = 0x0101;
 
-    fn init_storage(unlock: bool) {
-        unsafe {
-            ffi::flash_init();
-        }
-        init();
-        wipe();
-        lock();
-        if unlock {
-            super::unlock("", None);
-        }
-    }
-
     #[test]
     fn test_init() {
         init();
@@ -0,0 +1,33 @@
+use super::ffi;
+use core::slice;
+
+/// Content of the translations area in flash, all of it. Erased bytes read as
+/// `0xFF`.
+pub fn read() -> &'static [u8] {
+    let mut len: u32 = 0;
+    // SAFETY: The area is mapped for the whole run of the firmware, writes to
+    // it have to be ordered with the uses of the returned slice by the caller.
+    unsafe {
+        let ptr = ffi::translations_read(&mut len, 0);
+        if ptr.is_null() {
+            return &[];
+        }
+        slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+/// Write `data` at `offset` of the area, `false` if it does not fit. The area
+/// has to be erased there and nothing may be reading it at the time.
+pub fn write(data: &[u8], offset: usize) -> bool {
+    // SAFETY: The C side checks the bounds of the area.
+    unsafe { ffi::translations_write(data.as_ptr(), offset as u32, data.len() as u32) }
+}
+
+/// Erase the whole area. Nothing may be reading it at the time.
+pub fn erase() {
+    unsafe { ffi::translations_erase() }
+}
+
+pub fn area_len() -> usize {
+    unsafe { ffi::translations_area_bytesize() as usize }
+}
@@ -1,29 +1,84 @@
 use super::ffi;
-use core::cmp::Ordering;
+use core::{cmp::Ordering, ops::Range};
 use cstr_core::CStr;
 
+/// Alphabetically sorted list of words a mnemonic is made of. The input
+/// methods only see the words through this trait, so that any list can back
+/// them, not just the ones compiled into the firmware.
+pub trait WordList {
+    /// How many words are in the list.
+    fn count(&self) -> usize;
+
+    /// Word at the position `index`, `None` past the end of the list.
+    fn word(&self, index: usize) -> Option<&'static str>;
+}
+
+/// English BIP39 wordlist of the C part of the firmware.
+struct Bip39English;
+
+impl WordList for Bip39English {
+    fn count(&self) -> usize {
+        ffi::BIP39_WORD_COUNT as usize
+    }
+
+    fn word(&self, index: usize) -> Option<&'static str> {
+        // SAFETY: The wordlist is never modified.
+        c_word(unsafe { &ffi::BIP39_WORDLIST_ENGLISH }, index)
+    }
+}
+
+/// SLIP39 wordlist of the C part of the firmware.
+struct Slip39;
+
+impl WordList for Slip39 {
+    fn count(&self) -> usize {
+        ffi::SLIP39_WORD_COUNT as usize
+    }
+
+    fn word(&self, index: usize) -> Option<&'static str> {
+        // SAFETY: The wordlist is never modified.
+        c_word(unsafe { &ffi::SLIP39_WORDLIST }, index)
+    }
+}
+
+fn c_word(words: &'static [*const cty::c_char], index: usize) -> Option<&'static str> {
+    // SAFETY: we assume every word in the wordlist is a valid 0-terminated UTF-8
+    // string.
+    words
+        .get(index)
+        .map(|word| unsafe { from_utf8_unchecked(*word) })
+}
+
 /// Holds all the possible words with the possibility to interact
 /// with the "list" - filtering it further, getting their count, etc.
+#[derive(Clone)]
 pub struct Wordlist {
-    words: &'static [*const cty::c_char],
+    list: &'static dyn WordList,
+    /// Positions of the current words in `list`.
+    range: Range<usize>,
     /// Holds the length of prefix which was used to filter the list
     /// (how many beginning characters are common for all words).
     prefix_len: usize,
 }
 
 impl Wordlist {
-    pub fn new(words: &'static [*const cty::c_char], prefix_len: usize) -> Self {
-        Self { words, prefix_len }
+    /// All words of `list`.
+    pub fn new(list: &'static dyn WordList) -> Self {
+        Self {
+            list,
+            range: 0..list.count(),
+            prefix_len: 0,
+        }
     }
 
     /// Initialize BIP39 wordlist.
     pub fn bip39() -> Self {
-        Self::new(unsafe { &ffi::BIP39_WORDLIST_ENGLISH }, 0)
+        Self::new(&Bip39English)
     }
 
     /// Initialize SLIP39 wordlist.
     pub fn slip39() -> Self {
-        Self::new(unsafe { &ffi::SLIP39_WORDLIST }, 0)
+        Self::new(&Slip39)
     }
 
     /// Returns all possible letters from current wordlist that form a valid
@@ -45,37 +100,60 @@ impl Wordlist {
         })
     }
 
+    /// Bitmask of the letters returned by `get_available_letters`, the lowest
+    /// bit is 'a', the second lowest 'b', etc.
+    pub fn letter_mask(&self) -> u32 {
+        self.get_available_letters()
+            .filter(char::is_ascii_lowercase)
+            .fold(0, |mask, ch| mask | (1 << (ch as u8 - b'a')))
+    }
+
     /// Only leaves words that have a specified prefix. Throw away others.
     pub fn filter_prefix(&self, prefix: &str) -> Self {
-        // SAFETY: We assume our slice is an array of 0-terminated strings.
-        let start = self
-            .words
-            .partition_point(|&word| matches!(unsafe { prefix_cmp(prefix, word) }, Ordering::Less));
-        let end = self.words.partition_point(|&word| {
-            !matches!(unsafe { prefix_cmp(prefix, word) }, Ordering::Greater)
-        });
-        Self::new(&self.words[start..end], prefix.len())
+        let start = self.partition_point(|word| prefix_cmp(prefix, word) == Ordering::Less);
+        let end = self.partition_point(|word| prefix_cmp(prefix, word) != Ordering::Greater);
+        Self {
+            list: self.list,
+            range: start..end,
+            prefix_len: prefix.len(),
+        }
+    }
+
+    /// Position in `list` of the first current word not satisfying `pred`,
+    /// which has to be true for all the words before it and false after.
+    fn partition_point(&self, pred: impl Fn(&str) -> bool) -> usize {
+        let (mut low, mut high) = (self.range.start, self.range.end);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if matches!(self.list.word(mid), Some(word) if pred(word)) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
     }
 
     /// Get a word at the certain position.
     pub fn get(&self, index: usize) -> Option<&'static str> {
-        // SAFETY: we assume every word in the wordlist is a valid 0-terminated UTF-8
-        // string.
-        self.words
-            .get(index)
-            .map(|word| unsafe { from_utf8_unchecked(*word) })
+        if index < self.len() {
+            self.list.word(self.range.start + index)
+        } else {
+            None
+        }
     }
 
     /// How many words are currently in the list.
-    pub const fn len(&self) -> usize {
-        self.words.len()
+    pub fn len(&self) -> usize {
+        self.range.len()
     }
 
     /// Iterator of all current words.
     pub fn iter(&self) -> impl Iterator<Item = &'static str> {
-        self.words
-            .iter()
-            .map(|word| unsafe { from_utf8_unchecked(*word) })
+        let list = self.list;
+        self.range
+            .clone()
+            .map(move |index| list.word(index).unwrap_or_default())
     }
 }
 
@@ -84,18 +162,13 @@ impl Wordlist {
 /// The comparison returns Less if the word comes lexicographically before all
 /// possible words starting with `prefix`, and Greater if it comes after.
 /// Equal is returned if the word starts with `prefix`.
-unsafe fn prefix_cmp(prefix: &str, word: *const cty::c_char) -> Ordering {
-    // SAFETY: we assume `word` is a pointer to a 0-terminated string.
-    for (i, prefix_char) in prefix.as_bytes().iter().enumerate() {
-        let word_char = unsafe { *(word.add(i)) } as u8;
-        if word_char == 0 {
-            // Prefix is longer than word.
-            return Ordering::Less;
-        } else if *prefix_char != word_char {
-            return word_char.cmp(prefix_char);
-        }
+fn prefix_cmp(prefix: &str, word: &str) -> Ordering {
+    let common = prefix.len().min(word.len());
+    match word.as_bytes()[..common].cmp(&prefix.as_bytes()[..common]) {
+        // Prefix is longer than word.
+        Ordering::Equal if word.len() < prefix.len() => Ordering::Less,
+        ordering => ordering,
     }
-    Ordering::Equal
 }
 
 unsafe fn from_utf8_unchecked<'a>(word: *const cty::c_char) -> &'a str {
@@ -210,4 +283,32 @@ mod tests {
         ];
         assert_eq!(result, expected_result);
     }
+
+    struct Colors;
+
+    impl WordList for Colors {
+        fn count(&self) -> usize {
+            4
+        }
+
+        fn word(&self, index: usize) -> Option<&'static str> {
+            ["black", "blue", "brown", "green"].get(index).copied()
+        }
+    }
+
+    #[test]
+    fn test_custom_wordlist() {
+        let words = Wordlist::new(&Colors);
+        assert_eq!(words.len(), 4);
+        let result = words.filter_prefix("bl").iter().collect::<Vec<_>>();
+        assert_eq!(result, vec!["black", "blue"]);
+        assert_eq!(words.filter_prefix("bl").get(1), Some("blue"));
+        assert_eq!(words.filter_prefix("bl").get(2), None);
+        assert_eq!(words.filter_prefix("blacks").len(), 0);
+        assert_eq!(words.letter_mask(), (1 << 1) | (1 << 6));
+        assert_eq!(
+            words.filter_prefix("b").letter_mask(),
+            (1 << 11) | (1 << 17)
+        );
+    }
 }
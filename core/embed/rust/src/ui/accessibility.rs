@@ -0,0 +1,50 @@
+//! Accessibility preferences of the user. The firmware sets them with
+//! `trezorui2.set_accessibility()` from the storage, components read them from
+//! `EventCtx::accessibility()`, see `context::UiSettings`.
+
+use crate::time::Duration;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Accessibility {
+    /// Skip decorative movement, e.g. screen transitions or sliding items.
+    pub reduce_motion: bool,
+    /// Give the user more time, see `timeout` and `hold`.
+    pub longer_timeouts: bool,
+    /// Use bigger fonts on screens that have them.
+    pub larger_fonts: bool,
+    /// Paint grey text in white or black, see `Color::with_full_contrast` and
+    /// `Theme::fg_secondary`.
+    pub high_contrast: bool,
+    /// Play the outcome of a flow on the buzzer, see `sound::Feedback`.
+    pub audio_feedback: bool,
+}
+
+impl Accessibility {
+    pub const DEFAULT: Self = Self {
+        reduce_motion: false,
+        longer_timeouts: false,
+        larger_fonts: false,
+        high_contrast: false,
+        audio_feedback: false,
+    };
+
+    /// How long to wait before something goes away on its own, e.g. a warning
+    /// replaced by the prompt.
+    pub fn timeout(self, duration: Duration) -> Duration {
+        if self.longer_timeouts {
+            duration * 2.0
+        } else {
+            duration
+        }
+    }
+
+    /// How long a press has to be held. Holds take longer with longer
+    /// timeouts too, so that a slow release does not confirm by accident.
+    pub fn hold(self, duration: Duration) -> Duration {
+        if self.longer_timeouts {
+            duration * 1.5
+        } else {
+            duration
+        }
+    }
+}
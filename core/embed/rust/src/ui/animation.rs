@@ -1,8 +1,40 @@
 use crate::{
     time::{Duration, Instant},
-    ui::lerp::{InvLerp, Lerp},
+    ui::{
+        context::settings,
+        lerp::{InvLerp, Lerp},
+        util::animation_disabled,
+    },
 };
 
+/// Charge in percent below which animations are throttled on battery.
+pub const LOW_BATTERY_LEVEL: u8 = 20;
+
+/// How many times longer animation frames take when saving power.
+const POWER_SAVING_FRAME_FACTOR: f32 = 3.0;
+
+/// Whether to animate less to save the battery, see
+/// `UiSettings::battery_level`.
+pub fn power_saving() -> bool {
+    matches!(settings().battery_level, Some(level) if level < LOW_BATTERY_LEVEL)
+}
+
+/// Whether to skip purely decorative motion, e.g. marquees and screen
+/// transitions. Animations showing progress keep running, only with fewer
+/// frames, see `frame_interval`.
+pub fn effects_disabled() -> bool {
+    animation_disabled() || power_saving()
+}
+
+/// Time until the next animation frame, given the usual `interval`.
+pub fn frame_interval(interval: Duration) -> Duration {
+    if power_saving() {
+        interval * POWER_SAVING_FRAME_FACTOR
+    } else {
+        interval
+    }
+}
+
 /// Running, time-based linear progression of a value.
 pub struct Animation<T> {
     /// Starting value.
@@ -0,0 +1,60 @@
+//! Dimming of the display after a while without input, before the firmware
+//! locks the device or shows the screensaver. The firmware sets it up with
+//! `trezorui2.set_autodim()`, the `Root` of the layout on screen dims and
+//! restores the backlight. The input restoring it is not passed on, so that
+//! the press waking the display up does not select anything.
+
+use crate::{
+    time::Duration,
+    ui::{
+        context::{settings, update_settings, with_state},
+        display,
+    },
+};
+
+#[derive(Copy, Clone)]
+pub struct AutoDim {
+    /// How long without input before the display dims.
+    pub delay: Duration,
+    /// Backlight while dimmed.
+    pub level: u16,
+}
+
+/// `None` turns the dimming off, and restores the backlight if dimmed.
+pub fn set_autodim(autodim: Option<AutoDim>) {
+    if autodim.is_none() {
+        wake();
+    }
+    update_settings(|s| s.autodim = autodim);
+}
+
+pub fn is_dimmed() -> bool {
+    with_state(|s| s.autodim_restore.is_some())
+}
+
+/// Dim the display, unless it is dimmed already or dimming is off.
+pub fn dim() {
+    let Some(autodim) = settings().autodim else {
+        return;
+    };
+    if is_dimmed() {
+        return;
+    }
+    let current = display::backlight();
+    if current <= autodim.level {
+        return;
+    }
+    with_state(|s| s.autodim_restore = Some(current));
+    display::fade_backlight(autodim.level);
+}
+
+/// Restore the backlight. Returns false if the display was not dimmed.
+pub fn wake() -> bool {
+    match with_state(|s| s.autodim_restore.take()) {
+        Some(backlight) => {
+            display::set_backlight(backlight);
+            true
+        }
+        None => false,
+    }
+}
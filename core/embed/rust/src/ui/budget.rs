@@ -0,0 +1,63 @@
+//! Time budget of the event and paint passes of layouts. A pass taking longer
+//! than `SLOW_PASS` is reported on the debug console. Components that can do
+//! their work in parts, e.g. painting a long text, check `exhausted()` and
+//! leave the rest for the next paint with `defer()`, so that a pathological
+//! screen does not keep the device busy for too long at once.
+
+use crate::{
+    time::{Duration, Instant},
+    ui::context::with_state,
+};
+
+/// Time a paint has before `exhausted()` starts returning true.
+const FRAME_BUDGET: Duration = Duration::from_millis(50);
+/// Passes taking longer than this are reported in debug builds.
+#[cfg(feature = "ui_debug")]
+const SLOW_PASS: Duration = Duration::from_millis(100);
+
+/// Run a pass of a layout, `label` names it in the report if it is slow.
+pub fn measure<U>(#[allow(unused)] label: &str, func: impl FnOnce() -> U) -> U {
+    let start = Instant::now();
+    let outer = with_state(|s| s.pass_start.replace(start));
+    let result = func();
+    with_state(|s| s.pass_start = outer);
+    #[cfg(feature = "ui_debug")]
+    {
+        let elapsed = Instant::now().saturating_duration_since(start);
+        if elapsed > SLOW_PASS {
+            print!("slow ", label, ": ");
+            println!(inttostr!(elapsed.to_millis()), " ms");
+        }
+    }
+    result
+}
+
+/// Whether a pass of a layout is running, i.e. whether deferred painting is
+/// going to be picked up.
+pub fn in_pass() -> bool {
+    with_state(|s| s.pass_start.is_some())
+}
+
+/// Whether the current pass has used up its budget.
+pub fn exhausted() -> bool {
+    match with_state(|s| s.pass_start) {
+        Some(start) => Instant::now().saturating_duration_since(start) > FRAME_BUDGET,
+        None => false,
+    }
+}
+
+/// Leave the rest of the painting for the next paint. `Child` wrappers of the
+/// component stay marked for painting and the layout asks for an animation
+/// frame to get painted again.
+pub fn defer() {
+    with_state(|s| s.paint_deferred = true);
+}
+
+pub fn is_deferred() -> bool {
+    with_state(|s| s.paint_deferred)
+}
+
+/// Whether any painting has been deferred since the last call.
+pub fn take_deferred() -> bool {
+    with_state(|s| core::mem::replace(&mut s.paint_deferred, false))
+}
@@ -0,0 +1,84 @@
+//! Spinner painted over a part of the screen while a long computation, e.g.
+//! encoding a large QR code or checking an image, keeps the device from
+//! painting anything else. The computation runs in `run()` and calls `tick()`
+//! as it makes progress. Nothing is painted unless it takes longer than
+//! `SHOW_AFTER`, the spinner is left on screen for whatever the result of the
+//! computation is painted over it.
+
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        context::with_state,
+        display::{self, Color},
+        geometry::Rect,
+    },
+};
+
+/// Computations shorter than this do not show the spinner.
+const SHOW_AFTER: Duration = Duration::from_millis(200);
+/// Shortest time between two paints of the spinner.
+const FRAME: Duration = Duration::from_millis(60);
+/// Time of one turn of the spinner.
+const TURN: u32 = 800;
+
+/// Computation in `run()`, kept in `UiState::busy`.
+#[derive(Copy, Clone)]
+pub(crate) struct Busy {
+    start: Instant,
+    last_frame: Option<Instant>,
+    area: Rect,
+    fg_color: Color,
+    bg_color: Color,
+}
+
+/// Computation in progress, `None` outside of `run()`.
+/// Run a long computation, the spinner is centered in `area`. Nested calls
+/// keep the spinner of the outermost one.
+pub fn run<U>(area: Rect, fg_color: Color, bg_color: Color, func: impl FnOnce() -> U) -> U {
+    if with_state(|s| s.busy.is_some()) {
+        return func();
+    }
+    let busy = Busy {
+        start: Instant::now(),
+        last_frame: None,
+        area,
+        fg_color,
+        bg_color,
+    };
+    with_state(|s| s.busy = Some(busy));
+    let result = func();
+    with_state(|s| s.busy = None);
+    result
+}
+
+/// Report progress of the computation, paints the next frame of the spinner
+/// when it is due. Does nothing outside of `run()`, so it is fine to call from
+/// code that is not always slow.
+pub fn tick() {
+    let now = Instant::now();
+    let frame = with_state(|s| {
+        let busy = s.busy.as_mut()?;
+        let elapsed = now.saturating_duration_since(busy.start);
+        if elapsed < SHOW_AFTER {
+            return None;
+        }
+        if let Some(last) = busy.last_frame {
+            if now.saturating_duration_since(last) < FRAME {
+                return None;
+            }
+        }
+        busy.last_frame = Some(now);
+        Some((*busy, elapsed))
+    });
+    let Some((busy, elapsed)) = frame else {
+        return;
+    };
+    let progress = (elapsed.to_millis() % TURN) * display::LOADER_MAX as u32 / TURN;
+    display::loader_small_indeterminate_at(
+        busy.area.center(),
+        progress as u16,
+        busy.fg_color,
+        busy.bg_color,
+    );
+    display::refresh();
+}
@@ -0,0 +1,154 @@
+//! Quirks of physical buttons simulated in the emulator: contacts bouncing,
+//! releases that never arrive and events lost on the way. The emulator turns
+//! them on when `TREZOR_BUTTON_QUIRKS` is set, button events then pass through
+//! `apply()` before they reach the layout, so that tests exercise the layouts
+//! with the kind of input the hardware produces. Debug builds only.
+
+use crate::ui::event::ButtonEvent;
+
+#[cfg(feature = "ui_debug")]
+use crate::ui::context::with_state;
+
+/// How often each of the quirks happens, in percent of the button events. The
+/// same `seed` and the same input always produce the same quirks.
+#[derive(Copy, Clone)]
+pub struct ButtonQuirks {
+    pub seed: u32,
+    /// Event followed by the opposite one and by itself again.
+    pub bounce: u8,
+    /// Release that is lost, the button stays pressed until the next press.
+    pub missed_release: u8,
+    /// Event of any kind that is lost.
+    pub dropped: u8,
+}
+
+/// Turn the quirks on, `None` turns them off. They are kept together with the
+/// state of their xorshift32 PRNG in `UiState::button_quirks`.
+#[cfg(feature = "ui_debug")]
+pub fn set_button_quirks(quirks: Option<ButtonQuirks>) {
+    // Xorshift would get stuck on zero.
+    let state = quirks.map(|q| (q, if q.seed == 0 { 1 } else { q.seed }));
+    with_state(|s| s.button_quirks = state);
+}
+
+#[cfg(not(feature = "ui_debug"))]
+pub fn set_button_quirks(_quirks: Option<ButtonQuirks>) {}
+
+#[cfg(feature = "ui_debug")]
+enum Quirk {
+    Lost,
+    /// The event is followed by this one and by itself again.
+    Bounce(ButtonEvent),
+}
+
+/// Whether a quirk happening in `percent` of the events happens now.
+#[cfg(feature = "ui_debug")]
+fn happens(state: &mut u32, percent: u8) -> bool {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *state = x;
+    x % 100 < percent as u32
+}
+
+#[cfg(feature = "ui_debug")]
+fn opposite(event: ButtonEvent) -> Option<ButtonEvent> {
+    match event {
+        ButtonEvent::ButtonPressed(b) => Some(ButtonEvent::ButtonReleased(b)),
+        ButtonEvent::ButtonReleased(b) => Some(ButtonEvent::ButtonPressed(b)),
+        _ => None,
+    }
+}
+
+/// Pass `event` to `sink` the way the simulated buttons would, i.e. once, not
+/// at all, or several times with bounces in between. `sink` returns false if
+/// it does not take any more events, e.g. because the layout is done.
+pub fn apply(event: ButtonEvent, sink: &mut dyn FnMut(ButtonEvent) -> bool) {
+    #[cfg(feature = "ui_debug")]
+    {
+        // Decided before passing anything on, `sink` runs the layout, which
+        // needs the state too.
+        let quirk = with_state(|s| {
+            let (quirks, state) = s.button_quirks.as_mut()?;
+            if happens(state, quirks.dropped) {
+                return Some(Quirk::Lost);
+            }
+            if matches!(event, ButtonEvent::ButtonReleased(_))
+                && happens(state, quirks.missed_release)
+            {
+                return Some(Quirk::Lost);
+            }
+            let bounce = opposite(event).filter(|_| happens(state, quirks.bounce))?;
+            Some(Quirk::Bounce(bounce))
+        });
+        match quirk {
+            Some(Quirk::Lost) => return,
+            Some(Quirk::Bounce(bounce)) => {
+                let _ = sink(event) && sink(bounce) && sink(event);
+                return;
+            }
+            None => {}
+        }
+    }
+    sink(event);
+}
+
+#[cfg(all(test, feature = "ui_debug"))]
+mod tests {
+    use super::*;
+    use crate::ui::event::PhysicalButton;
+
+    /// Events the layout gets for `count` clicks of the left button.
+    fn clicks(quirks: ButtonQuirks, count: usize) -> Vec<ButtonEvent> {
+        let mut events = Vec::new();
+        set_button_quirks(Some(quirks));
+        for _ in 0..count {
+            for event in [
+                ButtonEvent::ButtonPressed(PhysicalButton::Left),
+                ButtonEvent::ButtonReleased(PhysicalButton::Left),
+            ] {
+                apply(event, &mut |e| {
+                    events.push(e);
+                    true
+                });
+            }
+        }
+        set_button_quirks(None);
+        events
+    }
+
+    #[test]
+    fn quirks() {
+        let none = ButtonQuirks {
+            seed: 7,
+            bounce: 0,
+            missed_release: 0,
+            dropped: 0,
+        };
+        assert_eq!(clicks(none, 10).len(), 20);
+
+        let bouncy = ButtonQuirks {
+            bounce: 100,
+            ..none
+        };
+        assert_eq!(clicks(bouncy, 10).len(), 60);
+
+        let sticky = ButtonQuirks {
+            missed_release: 100,
+            ..none
+        };
+        assert!(clicks(sticky, 10)
+            .iter()
+            .all(|e| matches!(e, ButtonEvent::ButtonPressed(_))));
+
+        let lossy = ButtonQuirks {
+            bounce: 30,
+            missed_release: 20,
+            dropped: 20,
+            ..none
+        };
+        assert!(clicks(lossy, 50) == clicks(lossy, 50));
+        assert!(clicks(lossy, 50) != clicks(ButtonQuirks { seed: 8, ..lossy }, 50));
+    }
+}
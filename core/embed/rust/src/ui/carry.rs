@@ -0,0 +1,38 @@
+//! Text carried over from one input screen of a flow to the next one, e.g. the
+//! passphrase just confirmed prefilled into the screen that checks it. The
+//! buffer, `UiState::carry`, is zeroized as soon as the next screen takes the
+//! text, or by `wipe()` when the flow ends without that.
+
+use crate::{
+    strutil::SecretString,
+    ui::{component::text::common::MAX_PASSPHRASE_LENGTH, context::with_state},
+};
+
+pub const MAX_CARRY_LENGTH: usize = MAX_PASSPHRASE_LENGTH;
+
+/// Replace the carried text with `text`. Text that does not fit is not carried
+/// at all.
+pub fn put(text: &str) {
+    with_state(|s| {
+        s.carry.clear();
+        if s.carry.push_str(text).is_err() {
+            s.carry.clear();
+        }
+    });
+}
+
+/// Move the carried text out, `None` if there is none or it does not fit into
+/// `L` bytes. The buffer is zeroized either way.
+pub fn take<const L: usize>() -> Option<SecretString<L>> {
+    with_state(|s| {
+        let mut text = SecretString::new();
+        let fits = !s.carry.is_empty() && text.push_str(&s.carry).is_ok();
+        s.carry.clear();
+        fits.then_some(text)
+    })
+}
+
+/// Zeroize the carried text.
+pub fn wipe() {
+    with_state(|s| s.carry.clear());
+}
@@ -0,0 +1,165 @@
+//! Small 1-bit icons of the major coins, painted together with the name of the
+//! coin into the header of the confirm screens, so that it is clear at a
+//! glance which coin is being spent when several coin apps are in use. The
+//! layouts look the icons up by the shortcut of the coin, e.g. `"BTC"`, which
+//! the firmware passes in the `coin` argument of the layout.
+
+use crate::ui::{
+    constant,
+    display::{self, Color},
+    geometry::{Offset, Point, Rect},
+};
+
+/// Width and height of the icons in pixels, before scaling.
+pub const SIZE: i16 = 8;
+
+pub struct CoinIcon {
+    pub shortcut: &'static str,
+    pub name: &'static str,
+    /// One byte per row, the most significant bit is the leftmost pixel.
+    rows: [u8; SIZE as usize],
+}
+
+#[rustfmt::skip]
+const BITCOIN: [u8; 8] = [
+    0b00101000,
+    0b01111100,
+    0b01100110,
+    0b01111100,
+    0b01100110,
+    0b01100110,
+    0b01111100,
+    0b00101000,
+];
+#[rustfmt::skip]
+const LITECOIN: [u8; 8] = [
+    0b01100000,
+    0b01100000,
+    0b01101100,
+    0b01111000,
+    0b11100000,
+    0b01100000,
+    0b01111110,
+    0b00000000,
+];
+#[rustfmt::skip]
+const DOGECOIN: [u8; 8] = [
+    0b01111000,
+    0b01101100,
+    0b01100110,
+    0b11110110,
+    0b01100110,
+    0b01101100,
+    0b01111000,
+    0b00000000,
+];
+#[rustfmt::skip]
+const ETHEREUM: [u8; 8] = [
+    0b00011000,
+    0b00111100,
+    0b01111110,
+    0b00000000,
+    0b01111110,
+    0b00111100,
+    0b00011000,
+    0b00000000,
+];
+#[rustfmt::skip]
+const CARDANO: [u8; 8] = [
+    0b01011010,
+    0b00000000,
+    0b10011001,
+    0b00100100,
+    0b00100100,
+    0b10011001,
+    0b00000000,
+    0b01011010,
+];
+#[rustfmt::skip]
+const RIPPLE: [u8; 8] = [
+    0b11000011,
+    0b01100110,
+    0b00111100,
+    0b00000000,
+    0b00000000,
+    0b00111100,
+    0b01100110,
+    0b11000011,
+];
+#[rustfmt::skip]
+const MONERO: [u8; 8] = [
+    0b00000000,
+    0b11000011,
+    0b11100111,
+    0b11011011,
+    0b11000011,
+    0b11000011,
+    0b11000011,
+    0b00000000,
+];
+
+const ICONS: &[CoinIcon] = &[
+    CoinIcon::new("BTC", "Bitcoin", BITCOIN),
+    CoinIcon::new("TEST", "Testnet", BITCOIN),
+    CoinIcon::new("REGTEST", "Regtest", BITCOIN),
+    CoinIcon::new("LTC", "Litecoin", LITECOIN),
+    CoinIcon::new("DOGE", "Dogecoin", DOGECOIN),
+    CoinIcon::new("ETH", "Ethereum", ETHEREUM),
+    CoinIcon::new("ADA", "Cardano", CARDANO),
+    CoinIcon::new("XRP", "Ripple", RIPPLE),
+    CoinIcon::new("XMR", "Monero", MONERO),
+];
+
+/// Icon of the coin with `shortcut`, ignoring the case. `None` for the coins
+/// without an icon, their screens keep the plain header.
+pub fn find(shortcut: &str) -> Option<&'static CoinIcon> {
+    ICONS
+        .iter()
+        .find(|icon| icon.shortcut.eq_ignore_ascii_case(shortcut))
+}
+
+impl CoinIcon {
+    const fn new(shortcut: &'static str, name: &'static str, rows: [u8; SIZE as usize]) -> Self {
+        Self {
+            shortcut,
+            name,
+            rows,
+        }
+    }
+
+    /// Paint the icon with its top left corner at `top_left`, every pixel of
+    /// it `scale` pixels wide and high.
+    pub fn paint(&self, top_left: Point, scale: i16, fg_color: Color, bg_color: Color) {
+        let r = Rect::from_top_left_and_size(top_left, Offset::uniform(SIZE * scale))
+            .translate(display::get_offset());
+        let clamped = r.clamp(constant::screen());
+        let (fg_color, bg_color) = (display::themed(fg_color), display::themed(bg_color));
+        display::set_window(clamped);
+        for y in clamped.y0..clamped.y1 {
+            let row = self.rows[((y - r.y0) / scale) as usize];
+            for x in clamped.x0..clamped.x1 {
+                let set = row & (0x80 >> ((x - r.x0) / scale)) != 0;
+                display::pixeldata(if set { fg_color } else { bg_color });
+            }
+        }
+        display::pixeldata_dirty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(find("BTC").map(|icon| icon.name), Some("Bitcoin"));
+        assert_eq!(find("doge").map(|icon| icon.name), Some("Dogecoin"));
+        assert!(find("").is_none());
+        assert!(find("NOPE").is_none());
+        for (i, icon) in ICONS.iter().enumerate() {
+            assert!(ICONS[..i]
+                .iter()
+                .all(|other| !other.shortcut.eq_ignore_ascii_case(icon.shortcut)));
+        }
+    }
+}
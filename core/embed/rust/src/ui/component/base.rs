@@ -3,11 +3,18 @@ use core::mem;
 use heapless::Vec;
 
 use crate::{
-    time::Duration,
+    time::{Duration, Instant},
     ui::{
+        accessibility::Accessibility,
+        animation, autodim, budget,
         component::{maybe::PaintOverlapping, MsgMap},
+        context::{self, UiSettings},
         display::{self, Color},
-        geometry::{Offset, Rect},
+        geometry::{Insets, Offset, Rect},
+        help::{self, HelpAction, HelpGesture},
+        stats,
+        translations::TranslatedString,
+        wallet_badge::{self, Wallet},
     },
 };
 
@@ -63,6 +70,22 @@ pub trait Component {
     #[cfg(feature = "ui_bounds")]
     /// Report current paint bounds of this component. Used for debugging.
     fn bounds(&self, _sink: &mut dyn FnMut(Rect)) {}
+
+    /// Change an attribute of the component while it is shown, without the
+    /// need to construct it again. Returns `true` if the attribute is
+    /// supported by the component or any of its children.
+    ///
+    /// Component should call `EventCtx::request_paint` in case its look has
+    /// changed. Components wrapping others should forward the call to them.
+    fn update_attr(&mut self, _ctx: &mut EventCtx, _attr: AttrUpdate) -> bool {
+        false
+    }
+
+    /// Short help about the component, shown over the screen on a long press,
+    /// see `ui::help`. Components wrapping others should forward the call to them.
+    fn help(&self) -> Option<TranslatedString> {
+        None
+    }
 }
 
 /// Components should always avoid unnecessary overpaint to prevent obvious
@@ -73,6 +96,12 @@ pub trait Component {
 pub struct Child<T> {
     component: T,
     marked_for_paint: bool,
+    /// Paints of the component, see `UiSettings::paint_counters`.
+    #[cfg(feature = "ui_debug")]
+    paints: u32,
+    /// Events after which the component asked to be painted.
+    #[cfg(feature = "ui_debug")]
+    paint_requests: u32,
 }
 
 impl<T> Child<T> {
@@ -80,6 +109,10 @@ impl<T> Child<T> {
         Self {
             component,
             marked_for_paint: true,
+            #[cfg(feature = "ui_debug")]
+            paints: 0,
+            #[cfg(feature = "ui_debug")]
+            paint_requests: 0,
         }
     }
 
@@ -104,6 +137,10 @@ impl<T> Child<T> {
             // mark ourselves for paint as well, and keep the `ctx` flag so it can
             // propagate upwards.
             self.marked_for_paint = true;
+            #[cfg(feature = "ui_debug")]
+            {
+                self.paint_requests += 1;
+            }
         } else {
             // Paint has not been requested in the *inner* component, so there's no need to
             // paint it, but we need to preserve the previous flag carried in `ctx` so it
@@ -135,21 +172,35 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        stats::sample_stack();
         self.mutate(ctx, |ctx, c| {
             // Handle the internal invalidation event here, so components don't have to. We
             // still pass it inside, so the event propagates correctly to all components in
             // the sub-tree.
-            if let Event::RequestPaint = event {
-                ctx.request_paint();
+            match event {
+                Event::RequestPaint => ctx.request_paint(),
+                Event::LanguageChanged => {
+                    ctx.request_place();
+                    ctx.request_paint();
+                }
+                _ => {}
             }
             c.event(ctx, event)
         })
     }
 
     fn paint(&mut self) {
+        stats::sample_stack();
         if self.marked_for_paint {
             self.marked_for_paint = false;
+            #[cfg(feature = "ui_debug")]
+            {
+                self.paints += 1;
+            }
+            let deferred = budget::is_deferred();
             self.component.paint();
+            // Stay marked if the component left some of its painting for later.
+            self.marked_for_paint = !deferred && budget::is_deferred();
         }
     }
 
@@ -157,6 +208,14 @@ where
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         self.component.bounds(sink)
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.mutate(ctx, |ctx, c| c.update_attr(ctx, attr))
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.component.help()
+    }
 }
 
 impl<T: Paginate> Paginate for Child<T> {
@@ -191,22 +250,48 @@ where
     T: crate::trace::Trace,
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
-        self.component.trace(t)
+        self.component.trace(t);
+        if context::settings().paint_counters {
+            t.int("paints", self.paints.into());
+            t.int("paint_requests", self.paint_requests.into());
+        }
     }
 }
 
 /// Same as `Child` but also handles screen clearing when layout is first
-/// painted.
+/// painted, dims the display after a while without input, see `autodim`,
+/// paints the badge of the wallet in use above the component, see
+/// `wallet_badge`, and shows the help of the component on a long press, see
+/// `ui::help`.
 pub struct Root<T> {
     inner: Child<T>,
+    bounds: Rect,
     marked_for_clear: bool,
+    /// Time of the last input, or of the attach if there has not been any.
+    last_input: Option<Instant>,
+    dim_timer: Option<TimerToken>,
+    /// The display has been woken up and the rest of the waking touch or
+    /// press is not passed on either.
+    waking: bool,
+    help: HelpGesture,
+    /// The help is shown and still has to be painted.
+    help_needs_paint: bool,
+    /// Wallet of the badge the component was placed below.
+    wallet: Option<Wallet>,
 }
 
 impl<T> Root<T> {
     pub fn new(component: T) -> Self {
         Self {
             inner: Child::new(component),
+            bounds: Rect::zero(),
             marked_for_clear: true,
+            last_input: None,
+            dim_timer: None,
+            waking: false,
+            help: HelpGesture::new(),
+            help_needs_paint: false,
+            wallet: None,
         }
     }
 
@@ -214,13 +299,84 @@ impl<T> Root<T> {
         &self.inner
     }
 
+    pub fn will_paint(&self) -> bool {
+        if self.help.is_shown() {
+            self.help_needs_paint
+        } else {
+            self.inner.will_paint()
+        }
+    }
+
     pub fn skip_paint(&mut self) {
+        self.help_needs_paint = false;
         self.inner.skip_paint()
     }
 
     pub fn clear_screen(&mut self) {
         self.marked_for_clear = true;
     }
+
+    /// Returns true if `event` is not to be passed on, being the dimming timer
+    /// or part of the input that woke the display up.
+    fn autodim_event(&mut self, ctx: &mut EventCtx, event: Event) -> bool {
+        let Some(config) = ctx.settings().autodim else {
+            self.dim_timer = None;
+            return false;
+        };
+        let now = Instant::now();
+        match event {
+            Event::Attach => {
+                // A new screen is worth looking at.
+                autodim::wake();
+                self.last_input = Some(now);
+                self.dim_timer = Some(ctx.request_timer(config.delay));
+                false
+            }
+            Event::Timer(token) if self.dim_timer == Some(token) => {
+                let idle = self
+                    .last_input
+                    .map_or(config.delay, |t| now.saturating_duration_since(t));
+                match config.delay.checked_sub(idle) {
+                    Some(left) if left > Duration::ZERO => {
+                        self.dim_timer = Some(ctx.request_timer(left));
+                    }
+                    _ => {
+                        self.dim_timer = None;
+                        autodim::dim();
+                    }
+                }
+                true
+            }
+            _ => {
+                let Some(end) = input_end(event) else {
+                    return false;
+                };
+                self.last_input = Some(now);
+                if self.dim_timer.is_none() {
+                    self.dim_timer = Some(ctx.request_timer(config.delay));
+                }
+                if autodim::wake() {
+                    self.waking = true;
+                }
+                let swallowed = self.waking;
+                if end {
+                    self.waking = false;
+                }
+                swallowed
+            }
+        }
+    }
+}
+
+/// `Some` for input events, true if the event ends a touch or a press.
+pub(crate) fn input_end(event: Event) -> Option<bool> {
+    match event {
+        #[cfg(feature = "button")]
+        Event::Button(e) => Some(matches!(e, ButtonEvent::ButtonReleased(_))),
+        #[cfg(feature = "touch")]
+        Event::Touch(e) => Some(matches!(e, TouchEvent::TouchEnd(_))),
+        _ => None,
+    }
 }
 
 impl<T> Component for Root<T>
@@ -230,11 +386,34 @@ where
     type Msg = T::Msg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        self.inner.place(bounds)
+        self.bounds = bounds;
+        self.wallet = context::settings().wallet;
+        let badge_height = wallet_badge::height(self.wallet);
+        self.inner.place(bounds.inset(Insets::top(badge_height)));
+        bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let msg = self.inner.event(ctx, event);
+        if self.autodim_event(ctx, event) {
+            return None;
+        }
+        let has_help = self.inner.help().is_some();
+        let msg = match self.help.event(ctx, event, self.bounds, has_help) {
+            HelpAction::Pass => self.inner.event(ctx, event),
+            HelpAction::Swallow => return None,
+            HelpAction::Show(cancel) => {
+                self.help_needs_paint = true;
+                ctx.request_paint();
+                cancel.and_then(|cancel| self.inner.event(ctx, cancel))
+            }
+            HelpAction::Dismiss => {
+                // Forget the gesture, e.g. both buttons held down.
+                self.marked_for_clear = true;
+                let msg = self.inner.event(ctx, Event::Attach);
+                self.inner.request_complete_repaint(ctx);
+                msg
+            }
+        };
         if ctx.needs_repaint_root() {
             self.marked_for_clear = true;
             let mut dummy_ctx = EventCtx::new();
@@ -246,17 +425,37 @@ where
     }
 
     fn paint(&mut self) {
-        if self.marked_for_clear && self.inner.will_paint() {
+        if self.help.is_shown() {
+            if mem::replace(&mut self.help_needs_paint, false) {
+                if let Some(text) = self.inner.help() {
+                    help::paint(self.bounds, text);
+                }
+            }
+            return;
+        }
+        let will_paint = self.inner.will_paint();
+        if self.marked_for_clear && will_paint {
             self.marked_for_clear = false;
             display::clear()
         }
         self.inner.paint();
+        if will_paint {
+            wallet_badge::paint(self.bounds, self.wallet);
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         self.inner.bounds(sink)
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.inner.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.inner.help()
+    }
 }
 
 #[cfg(feature = "ui_debug")]
@@ -348,6 +547,22 @@ where
     }
 }
 
+#[cfg(feature = "ui_debug")]
+impl<T, U, V> crate::trace::Trace for (T, U, V)
+where
+    T: crate::trace::Trace,
+    U: crate::trace::Trace,
+    V: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.in_list("children", &|l| {
+            l.child(&self.0);
+            l.child(&self.1);
+            l.child(&self.2);
+        });
+    }
+}
+
 impl<T> Component for Option<T>
 where
     T: Component,
@@ -427,11 +642,36 @@ pub enum Event<'a> {
     /// Advance progress bar. Progress screens only.
     Progress(u16, &'a str),
     /// Component has been attached to component tree. This event is sent once
-    /// before any other events.
+    /// before any other events, and again when the help shown over the
+    /// component is closed, see `ui::help`.
     Attach,
     /// Internally-handled event to inform all `Child` wrappers in a sub-tree to
     /// get scheduled for painting.
     RequestPaint,
+    /// Language of the UI has changed. Components holding translated strings
+    /// should resolve them again. The whole sub-tree gets placed again and
+    /// scheduled for painting, as the new strings can be of different length.
+    LanguageChanged,
+}
+
+/// Typed value of an attribute that can be changed on a live component, see
+/// `Component::update_attr`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum AttrUpdate<'a> {
+    /// Progress in the `0..=1000` range, same as in `Event::Progress`.
+    Progress(u16),
+    /// Number of remaining attempts, e.g. of PIN entry.
+    Attempts(u16),
+    /// Text of the main label or description.
+    Text(&'a str),
+    /// Next item of streamed content, its title and value.
+    Item(&'a str, &'a str),
+    /// The streamed content is complete.
+    End,
+    /// Page to show, e.g. when resuming a flow.
+    Page(u16),
+    /// Short notification to show over the component.
+    Toast(&'a str),
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -450,14 +690,33 @@ impl TimerToken {
     }
 }
 
+/// Timers of a layout that are started and cancelled by name, see
+/// `EventCtx::start_timer`. Only one timer of each kind runs at a time, so a
+/// kind is meant for a component there is one of on the screen.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TimerKind {
+    /// Hide the PIN digits shown in the clear.
+    AutoHidePin,
+    /// Replace the wrong PIN warning with the prompt.
+    PinWarning,
+}
+
+impl TimerKind {
+    const COUNT: usize = 2;
+}
+
 pub struct EventCtx {
     timers: Vec<(TimerToken, Duration), { Self::MAX_TIMERS }>,
+    /// Token of the running timer of each `TimerKind`.
+    named_timers: [Option<TimerToken>; TimerKind::COUNT],
     next_token: u32,
     place_requested: bool,
     paint_requested: bool,
     anim_frame_scheduled: bool,
     page_count: Option<usize>,
+    active_page: Option<usize>,
     root_repaint_requested: bool,
+    settings: UiSettings,
 }
 
 impl EventCtx {
@@ -477,13 +736,16 @@ impl EventCtx {
     pub fn new() -> Self {
         Self {
             timers: Vec::new(),
+            named_timers: [None; TimerKind::COUNT],
             next_token: Self::STARTING_TIMER_TOKEN,
             place_requested: true, // We need to perform a place pass in the beginning.
             paint_requested: false, /* We also need to paint, but this is supplemented by
                                     * `Child::marked_for_paint` being true. */
             anim_frame_scheduled: false,
             page_count: None,
+            active_page: None,
             root_repaint_requested: false,
+            settings: context::settings(),
         }
     }
 
@@ -514,11 +776,34 @@ impl EventCtx {
         token
     }
 
-    /// Request an animation frame timer to fire as soon as possible.
+    /// Start the timer of `kind`, restarting it if it is running already.
+    pub fn start_timer(&mut self, kind: TimerKind, deadline: Duration) {
+        self.named_timers[kind as usize] = Some(self.request_timer(deadline));
+    }
+
+    /// Stop the timer of `kind`, it does not fire even if it is due already.
+    pub fn cancel(&mut self, kind: TimerKind) {
+        self.named_timers[kind as usize] = None;
+    }
+
+    /// Whether `event` is the timer of `kind` firing. The timer is over then,
+    /// it has to be started again to fire again.
+    pub fn timer_fired(&mut self, kind: TimerKind, event: Event) -> bool {
+        let running = self.named_timers[kind as usize];
+        if matches!(event, Event::Timer(token) if Some(token) == running) {
+            self.named_timers[kind as usize] = None;
+            return true;
+        }
+        false
+    }
+
+    /// Request an animation frame timer to fire as soon as possible, or later
+    /// when saving the battery, see `animation::frame_interval`.
     pub fn request_anim_frame(&mut self) {
         if !self.anim_frame_scheduled {
             self.anim_frame_scheduled = true;
-            self.register_timer(Self::ANIM_FRAME_TIMER, Self::ANIM_FRAME_DEADLINE);
+            let deadline = animation::frame_interval(Self::ANIM_FRAME_DEADLINE);
+            self.register_timer(Self::ANIM_FRAME_TIMER, deadline);
         }
     }
 
@@ -540,6 +825,25 @@ impl EventCtx {
         self.page_count
     }
 
+    /// Report the page shown, so that the firmware can return to it.
+    pub fn set_active_page(&mut self, page: usize) {
+        self.active_page = Some(page);
+    }
+
+    pub fn active_page(&self) -> Option<usize> {
+        self.active_page
+    }
+
+    /// Settings of the UI as of the start of this event tick.
+    pub fn settings(&self) -> UiSettings {
+        self.settings
+    }
+
+    /// Accessibility preferences of the user, see `settings`.
+    pub fn accessibility(&self) -> Accessibility {
+        self.settings.accessibility
+    }
+
     pub fn pop_timer(&mut self) -> Option<(TimerToken, Duration)> {
         self.timers.pop()
     }
@@ -549,11 +853,15 @@ impl EventCtx {
         self.paint_requested = false;
         self.anim_frame_scheduled = false;
         self.page_count = None;
+        self.active_page = None;
         self.root_repaint_requested = false;
+        self.settings = context::settings();
     }
 
     fn register_timer(&mut self, token: TimerToken, deadline: Duration) {
-        if self.timers.push((token, deadline)).is_err() {
+        let pushed = self.timers.push((token, deadline));
+        stats::record_buffer("EventCtx::timers", self.timers.len(), Self::MAX_TIMERS);
+        if pushed.is_err() {
             // The timer queue is full, this would be a development error in the layout
             // layer. Let's panic in the debug env.
             #[cfg(feature = "ui_debug")]
@@ -573,3 +881,129 @@ impl EventCtx {
         token
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        autodim::AutoDim,
+        context::{update_settings, with_state},
+        geometry::Point,
+        testing::{touch_end, touch_start, Harness},
+    };
+
+    use super::*;
+
+    /// Emits a message for every finished touch it gets.
+    struct Taps;
+
+    impl Component for Taps {
+        type Msg = ();
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            matches!(event, Event::Touch(TouchEvent::TouchEnd(_))).then_some(())
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    #[cfg(feature = "ui_debug")]
+    impl crate::trace::Trace for Taps {
+        fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+            t.component("Taps");
+        }
+    }
+
+    const DELAY: Duration = Duration::from_millis(1000);
+
+    fn root() -> Harness<Root<Taps>> {
+        update_settings(|s| {
+            s.autodim = Some(AutoDim {
+                delay: DELAY,
+                level: 10,
+            })
+        });
+        Harness::new(Root::new(Taps))
+    }
+
+    #[test]
+    fn dims_after_the_delay() {
+        let mut h = root();
+        assert!(h.timers().len() == 1 && h.timers()[0].1 == DELAY);
+
+        // Fired before the delay is over, it waits for the rest of it.
+        h.fire_timers();
+        assert!(h.inner().dim_timer.is_some());
+        assert!(h.timers().len() == 1 && h.timers()[0].1 <= DELAY);
+
+        h.mutate(|_, root| root.last_input = Instant::now().checked_sub(DELAY));
+        h.fire_timers();
+        assert!(h.inner().dim_timer.is_none());
+
+        autodim::set_autodim(None);
+        assert!(!autodim::is_dimmed());
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[test]
+    fn waking_touch_is_not_passed_on() {
+        let mut h = root();
+        with_state(|s| s.autodim_restore = Some(100));
+
+        assert!(h.event(touch_start(10, 10)).is_none());
+        assert!(h.event(touch_end(10, 10)).is_none());
+        assert!(!autodim::is_dimmed());
+        assert!(h.tap(Point::new(10, 10)) == Some(()));
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[test]
+    fn nothing_without_autodim() {
+        let mut h = Harness::new(Root::new(Taps));
+        assert!(h.timers().is_empty());
+        assert!(h.tap(Point::new(10, 10)) == Some(()));
+    }
+
+    #[test]
+    fn frames_further_apart_on_low_battery() {
+        let frame = |battery_level| {
+            update_settings(|s| s.battery_level = battery_level);
+            let mut h = Harness::new(Taps);
+            h.mutate(|ctx, _| ctx.request_anim_frame());
+            h.timers().to_vec()
+        };
+        let normal = [(EventCtx::ANIM_FRAME_TIMER, EventCtx::ANIM_FRAME_DEADLINE)];
+        assert!(frame(None) == normal);
+        assert!(frame(Some(animation::LOW_BATTERY_LEVEL)) == normal);
+        assert!(
+            frame(Some(animation::LOW_BATTERY_LEVEL - 1))
+                == [(EventCtx::ANIM_FRAME_TIMER, Duration::from_millis(54))]
+        );
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[cfg(feature = "ui_debug")]
+    #[test]
+    fn paint_counters_are_traced() {
+        use crate::trace::tests::trace;
+
+        let mut h = Harness::new(Child::new(Taps));
+        assert!(trace(h.inner()).get("paints").is_none());
+
+        update_settings(|s| s.paint_counters = true);
+        h.event(Event::RequestPaint);
+        h.mutate(|_, child| child.paint());
+        // Painted only once, nothing asked for it again.
+        h.mutate(|_, child| child.paint());
+        let traced = trace(h.inner());
+        assert_eq!(traced["paints"], 1);
+        assert_eq!(traced["paint_requests"], 1);
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+}
@@ -0,0 +1,300 @@
+use core::fmt::Write;
+
+use heapless::String;
+
+use crate::ui::{
+    component::{text::TextStyle, Component, Event, EventCtx, Never, Paginate},
+    display,
+    geometry::{Offset, Rect},
+};
+
+/// Most characters of a line, the rest of a wide screen stays empty.
+const MAX_CHARS: usize = 56;
+/// Room for the longest line, even with a long offset.
+const MAX_LINE_LEN: usize = 64;
+/// Digits of the offset in front of every line, more for large blobs.
+const OFFSET_LEN: usize = 4;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum BlobFormat {
+    Hex,
+    Base64,
+}
+
+impl BlobFormat {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Hex => "hex",
+            Self::Base64 => "base64",
+        }
+    }
+
+    /// Most bytes encoded into `chars` characters, in whole groups so that
+    /// the lines together are the encoding of the whole blob.
+    fn bytes_per_line(self, chars: usize) -> usize {
+        match self {
+            Self::Hex => (chars / 4) * 2,
+            Self::Base64 => (chars / 4) * 3,
+        }
+    }
+}
+
+/// Arbitrary binary data, e.g. an OP_RETURN payload or contract call data,
+/// shown in full on as many pages as needed. The first line of every page
+/// tells the total length, the other lines start with the offset of their
+/// first byte. Needs a monospace font in `style`.
+pub struct BlobView<T> {
+    data: T,
+    /// Length of the whole blob, of which `data` may only be the start.
+    total: usize,
+    format: BlobFormat,
+    style: TextStyle,
+    area: Rect,
+    /// Lines of data on one page, below the length.
+    lines_per_page: usize,
+    bytes_per_line: usize,
+    page: usize,
+}
+
+impl<T> BlobView<T>
+where
+    T: AsRef<[u8]>,
+{
+    pub fn new(data: T, format: BlobFormat, style: TextStyle) -> Self {
+        Self {
+            total: data.as_ref().len(),
+            data,
+            format,
+            style,
+            area: Rect::zero(),
+            lines_per_page: 1,
+            bytes_per_line: 1,
+            page: 0,
+        }
+    }
+
+    /// `data` is only the first part of a blob `total` bytes long, the rest is
+    /// not shown but counted in the length.
+    pub fn with_total(mut self, total: usize) -> Self {
+        self.total = total.max(self.data.as_ref().len());
+        self
+    }
+
+    fn line_count(&self) -> usize {
+        let len = self.data.as_ref().len();
+        ((len + self.bytes_per_line - 1) / self.bytes_per_line).max(1)
+    }
+
+    fn header(&self) -> String<MAX_LINE_LEN> {
+        let mut line = String::new();
+        let len = self.data.as_ref().len();
+        let unit = if self.total == 1 { "byte" } else { "bytes" };
+        if self.total > len {
+            unwrap!(write!(line, "{}/", len));
+        }
+        unwrap!(write!(
+            line,
+            "{} {}, {}",
+            self.total,
+            unit,
+            self.format.name()
+        ));
+        line
+    }
+
+    /// Line `index` of the blob, the offset and the encoded bytes.
+    fn line(&self, index: usize) -> String<MAX_LINE_LEN> {
+        let mut line = String::new();
+        let offset = index * self.bytes_per_line;
+        let data = self.data.as_ref();
+        let chunk = &data[offset.min(data.len())..(offset + self.bytes_per_line).min(data.len())];
+        unwrap!(write!(line, "{:0width$x} ", offset, width = OFFSET_LEN));
+        match self.format {
+            BlobFormat::Hex => {
+                for byte in chunk {
+                    unwrap!(write!(line, "{:02x}", byte));
+                }
+            }
+            BlobFormat::Base64 => {
+                for group in chunk.chunks(3) {
+                    let mut bytes = [0; 3];
+                    bytes[..group.len()].copy_from_slice(group);
+                    let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+                    for i in 0..4 {
+                        let c = if i <= group.len() {
+                            BASE64_ALPHABET[((bits >> (18 - 6 * i)) & 0x3f) as usize] as char
+                        } else {
+                            '='
+                        };
+                        unwrap!(line.push(c));
+                    }
+                }
+            }
+        }
+        line
+    }
+}
+
+impl<T> Paginate for BlobView<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn page_count(&mut self) -> usize {
+        (self.line_count() + self.lines_per_page - 1) / self.lines_per_page
+    }
+
+    fn change_page(&mut self, to_page: usize) {
+        self.page = to_page;
+    }
+}
+
+impl<T> Component for BlobView<T>
+where
+    T: AsRef<[u8]>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let font = self.style.text_font;
+        let char_width = font.text_width("0").max(1);
+        let chars = (bounds.width() / char_width) as usize;
+        let data_chars = chars.min(MAX_CHARS).saturating_sub(OFFSET_LEN + 1);
+        self.bytes_per_line = self.format.bytes_per_line(data_chars).max(1);
+        let lines = (bounds.height() / font.line_height()) as usize;
+        self.lines_per_page = lines.saturating_sub(1).max(1);
+        self.area = bounds;
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        let font = self.style.text_font;
+        let fg = self.style.text_color;
+        let bg = self.style.background_color;
+        display::rect_fill(self.area, bg);
+
+        let baseline = |row: usize| {
+            self.area.top_left() + Offset::y(font.text_height() + row as i16 * font.line_height())
+        };
+        display::text_left(baseline(0), &self.header(), font, fg, bg);
+        let first = self.page * self.lines_per_page;
+        let last = (first + self.lines_per_page).min(self.line_count());
+        for (row, index) in (first..last).enumerate() {
+            let text = self.line(index);
+            display::text_left(baseline(row + 1), &text, font, fg, bg);
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for BlobView<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("BlobView");
+        t.string("header", &self.header());
+        if !self.data.as_ref().is_empty() {
+            let first = self.page * self.lines_per_page;
+            let last = (first + self.lines_per_page).min(self.line_count());
+            t.in_list("lines", &|list| {
+                for index in first..last {
+                    list.string(&self.line(index));
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        constant::screen,
+        display::{Color, Font},
+        testing::Harness,
+    };
+
+    fn view(data: &[u8], format: BlobFormat, bytes_per_line: usize) -> BlobView<&[u8]> {
+        let style = TextStyle::new(
+            Font::MONO,
+            Color::white(),
+            Color::black(),
+            Color::white(),
+            Color::white(),
+        );
+        let mut view = BlobView::new(data, format, style);
+        view.bytes_per_line = bytes_per_line;
+        view
+    }
+
+    #[test]
+    fn lines() {
+        let data = b"\x00\x01\xfe\xffhello";
+        let hex = view(data, BlobFormat::Hex, 4);
+        assert_eq!(hex.header().as_str(), "9 bytes, hex");
+        assert_eq!(hex.line_count(), 3);
+        assert_eq!(hex.line(0).as_str(), "0000 0001feff");
+        assert_eq!(hex.line(2).as_str(), "0008 6f");
+        let start = view(data, BlobFormat::Hex, 4).with_total(1024);
+        assert_eq!(start.header().as_str(), "9/1024 bytes, hex");
+        assert_eq!(start.line_count(), 3);
+
+        let base64 = view(data, BlobFormat::Base64, 6);
+        assert_eq!(base64.line(0).as_str(), "0000 AAH+/2hl");
+        assert_eq!(base64.line(1).as_str(), "0006 bGxv");
+        let short = view(b"ab", BlobFormat::Base64, 6);
+        assert_eq!(short.line(0).as_str(), "0000 YWI=");
+        assert_eq!(
+            view(b"a", BlobFormat::Base64, 6).line(0).as_str(),
+            "0000 YQ=="
+        );
+    }
+
+    #[test]
+    fn pages_fill_the_area() {
+        let data = [0xab; 100];
+        let mut h = Harness::new(view(&data, BlobFormat::Hex, 1));
+        let per_line = h.inner().bytes_per_line;
+        let per_page = h.inner().lines_per_page;
+
+        // Whole bytes on the lines, which fit the width below the length.
+        assert!(per_line > 1 && per_line % 2 == 0);
+        assert!(Font::MONO.text_width(&h.inner().line(0)) <= screen().width());
+        assert!((per_page as i16 + 1) * Font::MONO.line_height() <= screen().height());
+
+        let lines = (data.len() + per_line - 1) / per_line;
+        assert!(h.inner().line_count() == lines);
+        let pages = h.mutate(|_, view| view.page_count());
+        assert!(pages == (lines + per_page - 1) / per_page);
+    }
+
+    #[cfg(feature = "ui_debug")]
+    #[test]
+    fn last_page_traces_the_rest() {
+        use crate::trace::tests::trace;
+
+        let data = [0x5a; 100];
+        let mut h = Harness::new(view(&data, BlobFormat::Hex, 1));
+        let pages = h.mutate(|_, view| {
+            let pages = view.page_count();
+            view.change_page(pages - 1);
+            pages
+        });
+        let rest = h.inner().line_count() - (pages - 1) * h.inner().lines_per_page;
+        let traced = trace(h.inner());
+        assert_eq!(traced["header"], "100 bytes, hex");
+        assert_eq!(traced["lines"].as_array().unwrap().len(), rest);
+    }
+}
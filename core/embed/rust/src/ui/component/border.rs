@@ -1,9 +1,18 @@
 use super::{Component, Event, EventCtx};
-use crate::ui::geometry::{Insets, Rect};
+use crate::ui::{
+    display::{self, Color},
+    geometry::{Insets, Point, Rect},
+};
+
+/// Width of the stripes, measured horizontally.
+const STRIPE_WIDTH: i16 = 6;
 
 pub struct Border<T> {
     border: Insets,
     inner: T,
+    area: Rect,
+    /// Colors of the diagonal stripes painted in the border, if any.
+    stripes: Option<(Color, Color)>,
 }
 
 impl<T> Border<T>
@@ -11,7 +20,19 @@ where
     T: Component,
 {
     pub fn new(border: Insets, inner: T) -> Self {
-        Self { border, inner }
+        Self {
+            border,
+            inner,
+            area: Rect::zero(),
+            stripes: None,
+        }
+    }
+
+    /// Fill the border with diagonal stripes of `fg` and `bg`, to make a
+    /// screen stand out from the regular ones.
+    pub fn with_stripes(mut self, fg: Color, bg: Color) -> Self {
+        self.stripes = Some((fg, bg));
+        self
     }
 
     pub fn inner(&self) -> &T {
@@ -26,6 +47,7 @@ where
     type Msg = T::Msg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
         let inside = bounds.inset(self.border);
         self.inner.place(inside);
         inside
@@ -36,6 +58,15 @@ where
     }
 
     fn paint(&mut self) {
+        if let Some((fg, bg)) = self.stripes {
+            let (top, rest) = self.area.split_top(self.border.top);
+            let (rest, bottom) = rest.split_bottom(self.border.bottom);
+            let (left, rest) = rest.split_left(self.border.left);
+            let (_, right) = rest.split_right(self.border.right);
+            for r in [top, bottom, left, right] {
+                paint_stripes(r, fg, bg);
+            }
+        }
         self.inner.paint()
     }
 
@@ -45,6 +76,25 @@ where
     }
 }
 
+/// Fill `r` with stripes going from the bottom left to the top right. The
+/// pattern is anchored to the screen so that adjacent areas line up.
+fn paint_stripes(r: Rect, fg: Color, bg: Color) {
+    display::rect_fill(r, bg);
+    let period = 2 * STRIPE_WIDTH;
+    for y in r.y0..r.y1 {
+        // Start of the first stripe reaching into the row.
+        let mut x = r.x0 - (r.x0 + y).rem_euclid(period);
+        while x < r.x1 {
+            let x0 = x.max(r.x0);
+            let x1 = (x + STRIPE_WIDTH).min(r.x1);
+            if x0 < x1 {
+                display::rect_fill(Rect::new(Point::new(x0, y), Point::new(x1, y + 1)), fg);
+            }
+            x += period;
+        }
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl<T> crate::trace::Trace for Border<T>
 where
@@ -54,3 +104,21 @@ where
         self.inner.trace(t)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{component::Timeout, constant::screen, testing::Harness};
+
+    #[test]
+    fn inner_gets_the_inside() {
+        let border = Insets::new(3, 4, 5, 6);
+        let mut h = Harness::new(
+            Border::new(border, Timeout::new(100)).with_stripes(Color::white(), Color::black()),
+        );
+        assert!(h.inner().area == screen());
+        // Events go through the stripes to the inner component.
+        assert_eq!(h.timers().len(), 1);
+        assert!(h.fire_timers().is_some());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::ui::{
+    coin_icons::{self, CoinIcon},
+    component::{text::TextStyle, Component, Event, EventCtx, Never},
+    display,
+    geometry::{Offset, Point, Rect},
+};
+
+/// Space between the icon and the name.
+const ICON_SPACE: i16 = 2;
+
+/// Icon and name of a coin, shown in the header of the confirm screens next to
+/// the title.
+pub struct CoinLabel {
+    icon: &'static CoinIcon,
+    style: TextStyle,
+    /// Size of the icon pixels, to match the size of the font.
+    scale: i16,
+    area: Rect,
+}
+
+impl CoinLabel {
+    pub fn new(icon: &'static CoinIcon, style: TextStyle, scale: i16) -> Self {
+        Self {
+            icon,
+            style,
+            scale,
+            area: Rect::zero(),
+        }
+    }
+
+    fn icon_size(&self) -> i16 {
+        coin_icons::SIZE * self.scale
+    }
+
+    pub fn width(&self) -> i16 {
+        self.icon_size() + ICON_SPACE + self.style.text_font.text_width(self.icon.name)
+    }
+}
+
+impl Component for CoinLabel {
+    type Msg = Never;
+
+    /// Takes the top left corner of `bounds`, just as large as the label.
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let height = self
+            .icon_size()
+            .max(self.style.text_font.line_height())
+            .min(bounds.height());
+        self.area = Rect::from_top_left_and_size(
+            bounds.top_left(),
+            Offset::new(self.width().min(bounds.width()), height),
+        );
+        self.area
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        let fg = self.style.text_color;
+        let bg = self.style.background_color;
+        let center_y = self.area.center().y;
+        let icon_size = self.icon_size();
+        self.icon.paint(
+            Point::new(self.area.x0, center_y - icon_size / 2),
+            self.scale,
+            fg,
+            bg,
+        );
+        let font = self.style.text_font;
+        let baseline = Point::new(
+            self.area.x0 + icon_size + ICON_SPACE,
+            center_y + font.text_height() / 2,
+        );
+        display::text_left(baseline, self.icon.name, font, fg, bg);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for CoinLabel {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("CoinLabel");
+        t.string("coin", self.icon.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        constant::screen,
+        display::{Color, Font},
+        testing::Harness,
+    };
+
+    use super::*;
+
+    const STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    fn bitcoin() -> CoinLabel {
+        CoinLabel::new(coin_icons::find("BTC").unwrap(), STYLE, 2)
+    }
+
+    #[test]
+    fn just_as_large_as_the_label() {
+        let h = Harness::new(bitcoin());
+        let label = h.inner();
+        assert!(label.area.top_left() == screen().top_left());
+        assert!(label.area.width() == label.width());
+        assert!(label.area.height() == (2 * coin_icons::SIZE).max(Font::NORMAL.line_height()));
+        assert!(label.width() > Font::NORMAL.text_width("Bitcoin") + 2 * coin_icons::SIZE);
+    }
+
+    #[test]
+    fn cut_to_narrow_bounds() {
+        let mut label = bitcoin();
+        let bounds = Rect::from_top_left_and_size(Point::new(10, 20), Offset::new(30, 8));
+        assert!(label.place(bounds) == bounds);
+    }
+}
@@ -0,0 +1,228 @@
+use heapless::String;
+
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        component::{text::TextStyle, Child, Component, Event, EventCtx, TimerToken},
+        display,
+        geometry::{Offset, Rect},
+    },
+};
+
+/// How often the bar shrinks.
+const TICK_MS: u32 = 100;
+const BAR_HEIGHT: i16 = 3;
+/// Space between the number and the bar.
+const BAR_SPACE: i16 = 2;
+
+pub enum CountdownMsg<T> {
+    Inner(T),
+    /// The time ran out, the inner component does not receive any more
+    /// events.
+    TimedOut,
+}
+
+/// Limits the time the inner component is available for, e.g. a user
+/// presence window or a pairing code. The seconds left and a bar shrinking
+/// towards the end are shown in a strip at the bottom, the inner component
+/// gets the rest of the area.
+pub struct Countdown<T> {
+    inner: Child<T>,
+    style: TextStyle,
+    duration: Duration,
+    area: Rect,
+    started: Option<Instant>,
+    elapsed: Duration,
+    timer: Option<TimerToken>,
+}
+
+impl<T> Countdown<T>
+where
+    T: Component,
+{
+    pub fn new(inner: T, duration: Duration, style: TextStyle) -> Self {
+        Self {
+            inner: Child::new(inner),
+            style,
+            duration,
+            area: Rect::zero(),
+            started: None,
+            elapsed: Duration::from_millis(0),
+            timer: None,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        self.inner.inner()
+    }
+
+    /// Time left before the countdown expires.
+    pub fn remaining(&self) -> Duration {
+        self.duration
+            .checked_sub(self.elapsed)
+            .unwrap_or(Duration::from_millis(0))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.started.is_some() && self.timer.is_none()
+    }
+
+    /// Start counting down from the full duration again.
+    pub fn restart(&mut self, ctx: &mut EventCtx) {
+        self.started = Some(Instant::now());
+        self.elapsed = Duration::from_millis(0);
+        self.timer = Some(ctx.request_timer(Duration::from_millis(TICK_MS)));
+        ctx.request_paint();
+    }
+
+    fn seconds_left(&self) -> u32 {
+        // Rounded up so that the last second is shown as 1, not 0.
+        (self.remaining().to_millis() + 999) / 1000
+    }
+
+    fn bar_width(&self) -> i16 {
+        let total = self.duration.to_millis().max(1);
+        let left = self.remaining().to_millis();
+        (self.area.width() as u32 * left / total) as i16
+    }
+}
+
+impl<T> Component for Countdown<T>
+where
+    T: Component,
+{
+    type Msg = CountdownMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let height = self.style.text_font.line_height() + BAR_SPACE + BAR_HEIGHT;
+        let (content, area) = bounds.split_bottom(height);
+        self.area = area;
+        self.inner.place(content);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Attach => self.restart(ctx),
+            Event::Timer(token) if Some(token) == self.timer => {
+                if let Some(started) = self.started {
+                    self.elapsed = Instant::now().saturating_duration_since(started);
+                }
+                if self.remaining().to_millis() == 0 {
+                    self.timer = None;
+                    return Some(CountdownMsg::TimedOut);
+                }
+                self.timer = Some(ctx.request_timer(Duration::from_millis(TICK_MS)));
+                ctx.request_paint();
+                return None;
+            }
+            _ => {}
+        }
+        if self.is_expired() {
+            return None;
+        }
+        self.inner.event(ctx, event).map(CountdownMsg::Inner)
+    }
+
+    fn paint(&mut self) {
+        self.inner.paint();
+
+        let font = self.style.text_font;
+        let fg = self.style.text_color;
+        let bg = self.style.background_color;
+        display::rect_fill(self.area, bg);
+
+        let seconds: String<10> = self.seconds_left().into();
+        let text: String<12> = build_string!(12, seconds.as_str(), "s");
+        let baseline = self.area.top_center() + Offset::y(font.text_max_height());
+        display::text_center(baseline, text.as_str(), font, fg, bg);
+
+        let (_, bar) = self.area.split_bottom(BAR_HEIGHT);
+        let (bar, _) = bar.split_left(self.bar_width());
+        display::rect_fill(bar, fg);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.inner.bounds(sink);
+        sink(self.area);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for Countdown<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("Countdown");
+        t.int("seconds_left", self.seconds_left() as i64);
+        t.child("inner", &self.inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        component::Timeout,
+        display::{Color, Font},
+        testing::Harness,
+    };
+
+    const STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    /// Countdown over a component sending a message after `inner_ms`.
+    fn countdown(duration_ms: u32, inner_ms: u32) -> Harness<Countdown<Timeout>> {
+        Harness::new(Countdown::new(
+            Timeout::new(inner_ms),
+            Duration::from_millis(duration_ms),
+            STYLE,
+        ))
+    }
+
+    /// Deliver the tick of the countdown, leaving the timer of the inner
+    /// component pending.
+    fn tick(h: &mut Harness<Countdown<Timeout>>) -> Option<CountdownMsg<()>> {
+        let timer = h.inner().timer;
+        let index = h
+            .timers()
+            .iter()
+            .position(|(token, _)| Some(*token) == timer)
+            .unwrap();
+        h.fire_timer(index)
+    }
+
+    #[test]
+    fn ticks_while_time_is_left() {
+        let mut h = countdown(60_000, 1000);
+        assert_eq!(h.timers().len(), 2);
+        assert!(!h.inner().is_expired());
+
+        assert!(tick(&mut h).is_none());
+        assert!(h.paint_requested());
+        assert_eq!(h.inner().seconds_left(), 60);
+        // The inner component still gets its events.
+        assert!(matches!(h.fire_timers(), Some(CountdownMsg::Inner(()))));
+        assert!(h.inner().timer.is_some());
+    }
+
+    #[test]
+    fn inner_is_cut_off_when_expired() {
+        let mut h = countdown(0, 1000);
+        assert!(matches!(tick(&mut h), Some(CountdownMsg::TimedOut)));
+        assert!(h.inner().is_expired());
+        assert_eq!(h.inner().seconds_left(), 0);
+        // The timer of the inner component is not delivered to it any more.
+        assert!(h.fire_timers().is_none());
+
+        h.mutate(|ctx, countdown| countdown.restart(ctx));
+        assert!(!h.inner().is_expired());
+    }
+}
@@ -0,0 +1,281 @@
+use heapless::Vec;
+
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        component::{text::TextStyle, Component, Event, EventCtx, TimerToken},
+        display,
+        geometry::{Grid, Offset, Rect},
+    },
+};
+
+#[cfg(feature = "touch")]
+use crate::ui::event::TouchEvent;
+#[cfg(feature = "button")]
+use crate::ui::event::{ButtonEvent, PhysicalButton};
+
+/// How often the pattern is stirred.
+const FRAME_MS: u32 = 80;
+/// Input keeps the screen on until this long after the last press.
+const IDLE_MS: u32 = 2000;
+const ROWS: usize = 8;
+const COLS: usize = 16;
+/// Space between the pattern and the text.
+const TEXT_SPACE: i16 = 4;
+/// Bytes recorded for each press, the milliseconds since the screen appeared
+/// and which button or where on the screen.
+const INPUT_RECORD_LEN: usize = 5;
+/// Most presses recorded, the screen closes once there are this many.
+const MAX_PRESSES: usize = 64;
+pub const MAX_INPUT_LEN: usize = MAX_PRESSES * INPUT_RECORD_LEN;
+
+pub enum EntropyMixerMsg {
+    /// The time ran out and the user stopped pressing, the presses are
+    /// available in `EntropyMixer::input()`.
+    Done,
+}
+
+/// Abstract pattern stirred while the entropy of a new wallet is mixed,
+/// together with `text` inviting the user to press the buttons or to tap the
+/// screen at random. The timing of the presses is recorded for the firmware to
+/// mix into the entropy, and each of them visibly stirs the pattern. The
+/// pattern starts from `seed`, a hash of the entropy the firmware chose, and is
+/// not the entropy itself. Without any presses the screen closes after
+/// `duration`, otherwise once the user stops pressing.
+pub struct EntropyMixer<T> {
+    text: T,
+    style: TextStyle,
+    pattern_area: Rect,
+    text_area: Rect,
+    /// Xorshift128 state, only drives the pattern.
+    state: [u32; 4],
+    input: Vec<u8, MAX_INPUT_LEN>,
+    duration: Duration,
+    started: Option<Instant>,
+    last_press: Option<Instant>,
+    timer: Option<TimerToken>,
+}
+
+impl<T> EntropyMixer<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(seed: &[u8], text: T, duration: Duration, style: TextStyle) -> Self {
+        let mut state = [0; 4];
+        for (i, byte) in seed.iter().enumerate() {
+            state[i / 4 % 4] ^= (*byte as u32) << (i % 4 * 8);
+        }
+        if state == [0; 4] {
+            // Xorshift would get stuck on zero.
+            state[0] = 1;
+        }
+        Self {
+            text,
+            style,
+            pattern_area: Rect::zero(),
+            text_area: Rect::zero(),
+            state,
+            input: Vec::new(),
+            duration,
+            started: None,
+            last_press: None,
+            timer: None,
+        }
+    }
+
+    /// Records of the presses so far.
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    fn stir(&mut self) {
+        let [x, y, z, w] = self.state;
+        let t = x ^ (x << 11);
+        self.state = [y, z, w, w ^ (w >> 19) ^ t ^ (t >> 8)];
+    }
+
+    /// Which input `event` is, `None` for all but the start of a press.
+    fn press(event: Event) -> Option<u8> {
+        match event {
+            #[cfg(feature = "button")]
+            Event::Button(ButtonEvent::ButtonPressed(button)) => Some(match button {
+                PhysicalButton::Left => 1,
+                PhysicalButton::Right => 2,
+            }),
+            #[cfg(feature = "touch")]
+            Event::Touch(TouchEvent::TouchStart(point)) => Some((point.x ^ point.y) as u8),
+            _ => None,
+        }
+    }
+
+    fn record(&mut self, now: Instant, kind: u8) {
+        let since_start = self.started.map_or(0, |started| {
+            now.saturating_duration_since(started).to_millis()
+        });
+        self.last_press = Some(now);
+        // The state only changes the look, mixing the press into it shows that
+        // the press was taken.
+        self.state[0] ^= since_start ^ ((kind as u32) << 24);
+        self.stir();
+        let mut record = [0; INPUT_RECORD_LEN];
+        record[..4].copy_from_slice(&since_start.to_le_bytes());
+        record[4] = kind;
+        unwrap!(self.input.extend_from_slice(&record));
+    }
+
+    fn is_done(&self, now: Instant) -> bool {
+        let Some(started) = self.started else {
+            return false;
+        };
+        if self.input.is_full() {
+            return true;
+        }
+        let since = |t: Instant| now.saturating_duration_since(t);
+        match self.last_press {
+            Some(last) => since(last) >= Duration::from_millis(IDLE_MS),
+            None => since(started) >= self.duration,
+        }
+    }
+}
+
+impl<T> Component for EntropyMixer<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = EntropyMixerMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let text_height = self.style.text_font.line_height();
+        let (pattern_area, text_area) = bounds.split_bottom(text_height + TEXT_SPACE);
+        self.pattern_area = pattern_area;
+        self.text_area = text_area;
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let now = Instant::now();
+        match event {
+            Event::Attach => {
+                self.started = Some(now);
+                self.timer = Some(ctx.request_timer(Duration::from_millis(FRAME_MS)));
+            }
+            Event::Timer(token) if Some(token) == self.timer => {
+                if self.is_done(now) {
+                    self.timer = None;
+                    return Some(EntropyMixerMsg::Done);
+                }
+                self.stir();
+                self.timer = Some(ctx.request_timer(Duration::from_millis(FRAME_MS)));
+                ctx.request_paint();
+            }
+            _ => {
+                if let Some(kind) = Self::press(event) {
+                    if !self.input.is_full() {
+                        self.record(now, kind);
+                        ctx.request_paint();
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        let fg = self.style.text_color;
+        let bg = self.style.background_color;
+
+        let grid = Grid::new(self.pattern_area, ROWS, COLS);
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                let bit = row * COLS + col;
+                let set = self.state[bit / 32] & (1 << (bit % 32)) != 0;
+                display::rect_fill(grid.row_col(row, col), if set { fg } else { bg });
+            }
+        }
+
+        let font = self.style.text_font;
+        display::rect_fill(self.text_area, bg);
+        let baseline = self.text_area.bottom_center() - Offset::y(font.text_baseline());
+        display::text_center(baseline, self.text.as_ref(), font, fg, bg);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pattern_area);
+        sink(self.text_area);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for EntropyMixer<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("EntropyMixer");
+        t.string("text", self.text.as_ref());
+        t.int("presses", (self.input.len() / INPUT_RECORD_LEN) as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        display::{Color, Font},
+        geometry::Point,
+        testing::Harness,
+    };
+
+    use super::*;
+
+    const STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    fn mixer(duration_ms: u32) -> Harness<EntropyMixer<&'static str>> {
+        let duration = Duration::from_millis(duration_ms);
+        Harness::new(EntropyMixer::new(b"seed", "Tap anywhere", duration, STYLE))
+    }
+
+    fn is_done(msg: Option<EntropyMixerMsg>) -> bool {
+        matches!(msg, Some(EntropyMixerMsg::Done))
+    }
+
+    #[test]
+    fn presses_stir_and_get_recorded() {
+        let mut h = mixer(10_000);
+        let state = h.inner().state;
+        assert!(h.tap(Point::new(3, 5)).is_none());
+        assert!(h.inner().state != state);
+        assert!(h.inner().input().len() == INPUT_RECORD_LEN);
+        assert!(h.inner().input()[4] == 3 ^ 5);
+
+        // Not done while the user keeps pressing.
+        assert!(!is_done(h.fire_timers()));
+        let idle = Duration::from_millis(IDLE_MS);
+        h.mutate(|_, m| m.last_press = Instant::now().checked_sub(idle));
+        assert!(is_done(h.fire_timers()));
+    }
+
+    #[test]
+    fn done_after_the_duration_without_presses() {
+        let mut h = mixer(0);
+        assert!(h.inner().input().is_empty());
+        assert!(is_done(h.fire_timers()));
+        assert!(h.timers().is_empty());
+    }
+
+    #[test]
+    fn done_once_full() {
+        let mut h = mixer(10_000);
+        for i in 0..MAX_PRESSES + 3 {
+            h.tap(Point::new(i as i16, 0));
+        }
+        assert!(h.inner().input().len() == MAX_INPUT_LEN);
+        assert!(is_done(h.fire_timers()));
+    }
+}
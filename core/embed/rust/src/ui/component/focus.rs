@@ -0,0 +1,249 @@
+use crate::ui::{
+    component::{AttrUpdate, Component, Event, EventCtx},
+    event::{ButtonEvent, PhysicalButton},
+    geometry::Rect,
+    translations::TranslatedString,
+};
+
+/// Tuples of components that `Focus` can send the button events to one at a
+/// time.
+pub trait FocusChildren<const N: usize>: Component {
+    fn child_event(&mut self, index: usize, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg>;
+}
+
+impl<M, T, U> FocusChildren<2> for (T, U)
+where
+    T: Component<Msg = M>,
+    U: Component<Msg = M>,
+{
+    fn child_event(&mut self, index: usize, ctx: &mut EventCtx, event: Event) -> Option<M> {
+        match index {
+            0 => self.0.event(ctx, event),
+            _ => self.1.event(ctx, event),
+        }
+    }
+}
+
+impl<M, T, U, V> FocusChildren<3> for (T, U, V)
+where
+    T: Component<Msg = M>,
+    U: Component<Msg = M>,
+    V: Component<Msg = M>,
+{
+    fn child_event(&mut self, index: usize, ctx: &mut EventCtx, event: Event) -> Option<M> {
+        match index {
+            0 => self.0.event(ctx, event),
+            1 => self.1.event(ctx, event),
+            _ => self.2.event(ctx, event),
+        }
+    }
+}
+
+/// How the user moves the focus to the next child of a `Focus`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FocusGesture {
+    /// Only the screen moves the focus, see `Focus::set_focused`.
+    None,
+    /// Pressing both buttons together. A press is held back until its release
+    /// or the press of the other button, so the children get whole clicks
+    /// only and cannot react to long presses.
+    BothButtons,
+}
+
+/// Routes the button events of a composite screen to the one of the children
+/// of tuple `T` that has the focus, the other events go to all of them. The
+/// screen paints the focus itself, going by `focused()`.
+pub struct Focus<T, const N: usize> {
+    children: T,
+    focused: usize,
+    gesture: FocusGesture,
+    /// Press held back until it is clear whether it is a click or the start of
+    /// the gesture.
+    held: Option<PhysicalButton>,
+    /// Releases ending the gesture, they are not passed on.
+    gesture_releases: u8,
+}
+
+impl<T, const N: usize> Focus<T, N>
+where
+    T: FocusChildren<N>,
+{
+    pub const fn new(children: T, gesture: FocusGesture) -> Self {
+        Self {
+            children,
+            focused: 0,
+            gesture,
+            held: None,
+            gesture_releases: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.children
+    }
+
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.children
+    }
+
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    pub fn set_focused(&mut self, ctx: &mut EventCtx, index: usize) {
+        if index < N && index != self.focused {
+            self.focused = index;
+            self.held = None;
+            ctx.request_paint();
+        }
+    }
+
+    fn focused_event(&mut self, ctx: &mut EventCtx, event: ButtonEvent) -> Option<T::Msg> {
+        self.children
+            .child_event(self.focused, ctx, Event::Button(event))
+    }
+
+    fn button_event(&mut self, ctx: &mut EventCtx, event: ButtonEvent) -> Option<T::Msg> {
+        if self.gesture == FocusGesture::None {
+            return self.focused_event(ctx, event);
+        }
+        match event {
+            ButtonEvent::ButtonPressed(button) => match self.held {
+                Some(other) if other != button => {
+                    self.held = None;
+                    self.gesture_releases = 2;
+                    self.set_focused(ctx, (self.focused + 1) % N);
+                    None
+                }
+                _ => {
+                    self.held = Some(button);
+                    None
+                }
+            },
+            ButtonEvent::ButtonReleased(_) if self.gesture_releases > 0 => {
+                self.gesture_releases -= 1;
+                None
+            }
+            ButtonEvent::ButtonReleased(button) if self.held == Some(button) => {
+                self.held = None;
+                let msg = self.focused_event(ctx, ButtonEvent::ButtonPressed(button));
+                msg.or_else(|| self.focused_event(ctx, event))
+            }
+            _ => self.focused_event(ctx, event),
+        }
+    }
+}
+
+impl<T, const N: usize> Component for Focus<T, N>
+where
+    T: FocusChildren<N>,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.children.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Button(event) => self.button_event(ctx, event),
+            _ => self.children.event(ctx, event),
+        }
+    }
+
+    fn paint(&mut self) {
+        self.children.paint();
+    }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.children.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.children.help()
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.children.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, const N: usize> crate::trace::Trace for Focus<T, N>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("Focus");
+        t.int("focused", self.focused as i64);
+        self.children.trace(t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns its own number on every button release it gets.
+    struct Clicks(u8);
+
+    impl Component for Clicks {
+        type Msg = u8;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<u8> {
+            matches!(event, Event::Button(ButtonEvent::ButtonReleased(_))).then_some(self.0)
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    fn press(focus: &mut Focus<(Clicks, Clicks), 2>, event: ButtonEvent) -> Option<u8> {
+        focus.event(&mut EventCtx::new(), Event::Button(event))
+    }
+
+    #[test]
+    fn gesture() {
+        use ButtonEvent::{ButtonPressed, ButtonReleased};
+        use PhysicalButton::{Left, Right};
+
+        let mut focus = Focus::new((Clicks(0), Clicks(1)), FocusGesture::BothButtons);
+        assert_eq!(press(&mut focus, ButtonPressed(Left)), None);
+        assert_eq!(press(&mut focus, ButtonReleased(Left)), Some(0));
+
+        // Both buttons move the focus, the releases go nowhere.
+        assert_eq!(press(&mut focus, ButtonPressed(Left)), None);
+        assert_eq!(press(&mut focus, ButtonPressed(Right)), None);
+        assert_eq!(press(&mut focus, ButtonReleased(Right)), None);
+        assert_eq!(press(&mut focus, ButtonReleased(Left)), None);
+        assert_eq!(focus.focused(), 1);
+
+        assert_eq!(press(&mut focus, ButtonPressed(Right)), None);
+        assert_eq!(press(&mut focus, ButtonReleased(Right)), Some(1));
+    }
+
+    #[test]
+    fn set_by_the_screen() {
+        use ButtonEvent::ButtonReleased;
+
+        let mut focus = Focus::new((Clicks(0), Clicks(1)), FocusGesture::None);
+        assert_eq!(
+            press(&mut focus, ButtonReleased(PhysicalButton::Left)),
+            Some(0)
+        );
+
+        focus.set_focused(&mut EventCtx::new(), 1);
+        assert_eq!(
+            press(&mut focus, ButtonReleased(PhysicalButton::Left)),
+            Some(1)
+        );
+
+        // Out of range, the focus stays.
+        focus.set_focused(&mut EventCtx::new(), 2);
+        assert_eq!(focus.focused(), 1);
+    }
+}
@@ -0,0 +1,330 @@
+use heapless::Vec;
+
+#[cfg(feature = "button")]
+use crate::ui::event::{ButtonEvent, PhysicalButton};
+use crate::ui::{
+    component::{Child, Component, Event, EventCtx},
+    display::{self, Color},
+    geometry::{Alignment, Alignment2D, Grid, Offset, Rect},
+};
+
+pub enum GridMsg<T> {
+    /// Message of the child at the given index.
+    Child(usize, T),
+    /// Focus moved to the child at the given index. Only emitted on models
+    /// with buttons, where a click of the left or right button that the
+    /// focused child did not answer moves the focus.
+    Focused(usize),
+    /// Both buttons were clicked while the child at the given index had the
+    /// focus.
+    Selected(usize),
+}
+
+/// Places up to `N` components of the same type into the cells of a
+/// `rows`×`cols` grid, row by row. Children either fill their cells, or are
+/// given a fixed size and aligned within them.
+pub struct GridLayout<T, const N: usize> {
+    children: Vec<Child<T>, N>,
+    rows: usize,
+    cols: usize,
+    spacing: i16,
+    /// Size of the children, `None` to fill the whole cells.
+    content_size: Option<Offset>,
+    alignments: [Alignment2D; N],
+    focused: usize,
+    /// Outline of the focused cell and the color the other cells are
+    /// outlined with, `None` to not mark the focus.
+    focus_colors: Option<(Color, Color)>,
+    area: Rect,
+    /// Buttons held down at the moment.
+    #[cfg(feature = "button")]
+    pressed: u8,
+    /// Whether both buttons were down during the current press.
+    #[cfg(feature = "button")]
+    both: bool,
+}
+
+impl<T, const N: usize> GridLayout<T, N>
+where
+    T: Component,
+{
+    pub fn new(rows: usize, cols: usize, children: impl IntoIterator<Item = T>) -> Self {
+        let mut result = Self {
+            children: Vec::new(),
+            rows,
+            cols,
+            spacing: 0,
+            content_size: None,
+            alignments: [Alignment2D::CENTER; N],
+            focused: 0,
+            focus_colors: None,
+            area: Rect::zero(),
+            #[cfg(feature = "button")]
+            pressed: 0,
+            #[cfg(feature = "button")]
+            both: false,
+        };
+        for child in children.into_iter().take(N.min(rows * cols)) {
+            unwrap!(result.children.push(Child::new(child)));
+        }
+        result
+    }
+
+    pub fn with_spacing(mut self, spacing: i16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Give all children `size` instead of the full cell, aligned by
+    /// `alignment` unless set otherwise for the cell.
+    pub fn with_content_size(mut self, size: Offset, alignment: Alignment2D) -> Self {
+        self.content_size = Some(size);
+        self.alignments = [alignment; N];
+        self
+    }
+
+    /// Align the child at `index` within its cell by `alignment`.
+    pub fn with_cell_alignment(mut self, index: usize, alignment: Alignment2D) -> Self {
+        if let Some(a) = self.alignments.get_mut(index) {
+            *a = alignment;
+        }
+        self
+    }
+
+    /// Outline the focused cell with `color`, the other cells with
+    /// `background`.
+    pub fn with_focus_marker(mut self, color: Color, background: Color) -> Self {
+        self.focus_colors = Some((color, background));
+        self
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = &T> {
+        self.children.iter().map(Child::inner)
+    }
+
+    /// Index of the child having the focus.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    fn grid(&self) -> Grid {
+        Grid::new(self.area, self.rows, self.cols).with_spacing(self.spacing)
+    }
+
+    #[cfg(feature = "button")]
+    fn move_focus(&mut self, ctx: &mut EventCtx, forward: bool) -> Option<usize> {
+        let count = self.children.len();
+        if count == 0 {
+            return None;
+        }
+        self.focused = if forward {
+            (self.focused + 1) % count
+        } else {
+            (self.focused + count - 1) % count
+        };
+        ctx.request_paint();
+        Some(self.focused)
+    }
+
+    /// Button events go to the focused child only. Clicks it does not answer
+    /// move the focus. Pressing both buttons selects the focused cell, the
+    /// rest of such a press is not passed on.
+    #[cfg(feature = "button")]
+    fn button_event(&mut self, ctx: &mut EventCtx, event: ButtonEvent) -> Option<GridMsg<T::Msg>> {
+        if self.children.is_empty() {
+            return None;
+        }
+        let focused = self.focused;
+        match event {
+            ButtonEvent::ButtonPressed(_) => {
+                self.pressed += 1;
+                self.both |= self.pressed > 1;
+            }
+            ButtonEvent::ButtonReleased(_) => self.pressed = self.pressed.saturating_sub(1),
+        }
+        if self.both {
+            if self.pressed > 0 {
+                return None;
+            }
+            self.both = false;
+            return Some(GridMsg::Selected(focused));
+        }
+        if let Some(msg) = self.children[focused].event(ctx, Event::Button(event)) {
+            return Some(GridMsg::Child(focused, msg));
+        }
+        match event {
+            ButtonEvent::ButtonReleased(button) => {
+                let forward = matches!(button, PhysicalButton::Right);
+                self.move_focus(ctx, forward).map(GridMsg::Focused)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Rectangle of `size` placed within `cell` according to `alignment`.
+fn align_in(cell: Rect, size: Offset, alignment: Alignment2D) -> Rect {
+    let offset = |free: i16, align: Alignment| match align {
+        Alignment::Start => 0,
+        Alignment::Center => free / 2,
+        Alignment::End => free,
+    };
+    let free = cell.size() - size;
+    let top_left =
+        cell.top_left() + Offset::new(offset(free.x, alignment.0), offset(free.y, alignment.1));
+    Rect::from_top_left_and_size(top_left, size)
+}
+
+impl<T, const N: usize> Component for GridLayout<T, N>
+where
+    T: Component,
+{
+    type Msg = GridMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        let grid = self.grid();
+        for (i, child) in self.children.iter_mut().enumerate() {
+            let cell = grid.cell(i);
+            match self.content_size {
+                Some(size) => child.place(align_in(cell, size, self.alignments[i])),
+                None => child.place(cell),
+            };
+        }
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        #[cfg(feature = "button")]
+        if let Event::Button(event) = event {
+            return self.button_event(ctx, event);
+        }
+        let mut result = None;
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if let Some(msg) = child.event(ctx, event) {
+                result = result.or(Some(GridMsg::Child(i, msg)));
+            }
+        }
+        result
+    }
+
+    fn paint(&mut self) {
+        for child in self.children.iter_mut() {
+            child.paint();
+        }
+        if let Some((color, background)) = self.focus_colors {
+            let grid = self.grid();
+            for i in 0..self.children.len() {
+                let color = if i == self.focused { color } else { background };
+                display::rect_stroke(grid.cell(i), color);
+            }
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        for child in self.children.iter() {
+            child.bounds(sink);
+        }
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, const N: usize> crate::trace::Trace for GridLayout<T, N>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("GridLayout");
+        t.int("rows", self.rows as i64);
+        t.int("cols", self.cols as i64);
+        t.int("focused", self.focused as i64);
+        t.in_list("children", &|l| {
+            for child in self.children.iter() {
+                l.child(child);
+            }
+        });
+    }
+}
+
+#[cfg(all(test, feature = "button"))]
+mod tests {
+    use crate::ui::testing::{press, release, Harness};
+
+    use super::*;
+
+    /// Cell that answers a left click with its own number.
+    struct Cell(usize);
+
+    impl Component for Cell {
+        type Msg = usize;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<usize> {
+            let left = Event::Button(ButtonEvent::ButtonReleased(PhysicalButton::Left));
+            (self.0 == 5 && event == left).then_some(self.0)
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    fn pad() -> Harness<GridLayout<Cell, 12>> {
+        Harness::new(GridLayout::new(4, 3, (0..12).map(Cell)))
+    }
+
+    #[test]
+    fn clicks_move_the_focus() {
+        let mut h = pad();
+        assert!(matches!(
+            h.click(PhysicalButton::Right),
+            Some(GridMsg::Focused(1))
+        ));
+        assert!(matches!(
+            h.click(PhysicalButton::Left),
+            Some(GridMsg::Focused(0))
+        ));
+        assert!(matches!(
+            h.click(PhysicalButton::Left),
+            Some(GridMsg::Focused(11))
+        ));
+        assert!(h.paint_requested());
+
+        // Only the release moves it, the press alone does nothing.
+        h.expect_no_msg(press(PhysicalButton::Right));
+        assert!(h.inner().focused() == 11);
+        assert!(matches!(
+            h.event(release(PhysicalButton::Right)),
+            Some(GridMsg::Focused(0))
+        ));
+    }
+
+    #[test]
+    fn focused_child_gets_the_buttons() {
+        let mut h = pad();
+        h.mutate(|ctx, grid| {
+            for _ in 0..5 {
+                grid.move_focus(ctx, true);
+            }
+        });
+
+        // The child answers the click, so the focus stays.
+        assert!(matches!(
+            h.click(PhysicalButton::Left),
+            Some(GridMsg::Child(5, 5))
+        ));
+        assert!(h.inner().focused() == 5);
+
+        assert!(matches!(h.click_both(), Some(GridMsg::Selected(5))));
+        assert!(h.inner().focused() == 5);
+    }
+
+    #[test]
+    fn empty_grid() {
+        let mut h: Harness<GridLayout<Cell, 4>> =
+            Harness::new(GridLayout::new(2, 2, core::iter::empty()));
+        assert!(h.click(PhysicalButton::Right).is_none());
+    }
+}
@@ -1,5 +1,5 @@
-use super::{Component, Event, EventCtx};
-use crate::ui::geometry::Rect;
+use super::{AttrUpdate, Component, Event, EventCtx};
+use crate::ui::{geometry::Rect, translations::TranslatedString};
 
 pub struct MsgMap<T, F> {
     inner: T,
@@ -35,6 +35,14 @@ where
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         self.inner.bounds(sink);
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.inner.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.inner.help()
+    }
 }
 
 #[cfg(feature = "ui_debug")]
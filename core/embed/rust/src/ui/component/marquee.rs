@@ -1,12 +1,11 @@
 use crate::{
     time::{Duration, Instant},
     ui::{
-        animation::Animation,
+        animation::{effects_disabled, Animation},
         component::{Component, Event, EventCtx, Never, TimerToken},
         display,
         display::{Color, Font},
         geometry::Rect,
-        util::animation_disabled,
     },
 };
 
@@ -60,8 +59,8 @@ where
     }
 
     pub fn start(&mut self, ctx: &mut EventCtx, now: Instant) {
-        // Not starting if animations are disabled.
-        if animation_disabled() {
+        // Not starting if animations are disabled or the battery is low.
+        if effects_disabled() {
             return;
         }
 
@@ -145,8 +144,8 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        // Not doing anything if animations are disabled.
-        if animation_disabled() {
+        // Not doing anything if animations are disabled or the battery is low.
+        if effects_disabled() {
             return None;
         }
 
@@ -237,3 +236,41 @@ where
         t.string("text", self.text.as_ref());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        animation::LOW_BATTERY_LEVEL,
+        context::{update_settings, UiSettings},
+        testing::Harness,
+    };
+
+    use super::*;
+
+    fn started(battery_level: Option<u8>) -> Harness<Marquee<&'static str>> {
+        update_settings(|s| s.battery_level = battery_level);
+        let text = "RECEIVE ADDRESS OF THE SECOND ACCOUNT";
+        let mut h = Harness::new(Marquee::new(
+            text,
+            Font::NORMAL,
+            Color::white(),
+            Color::black(),
+        ));
+        h.mutate(|ctx, marquee| marquee.start(ctx, Instant::now()));
+        h
+    }
+
+    #[test]
+    fn still_on_low_battery() {
+        let h = started(Some(LOW_BATTERY_LEVEL - 1));
+        assert!(!h.inner().is_animating());
+        assert!(h.timers().is_empty());
+
+        // Scrolls again once charging.
+        let h = started(None);
+        assert!(h.inner().is_animating());
+        assert!(h.timers().len() == 1);
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+}
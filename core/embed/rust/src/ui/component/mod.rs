@@ -1,8 +1,15 @@
 #![forbid(unsafe_code)]
 
 pub mod base;
+pub mod blob_view;
 pub mod border;
+pub mod coin_label;
+pub mod countdown;
 pub mod empty;
+pub mod entropy;
+#[cfg(feature = "button")]
+pub mod focus;
+pub mod grid;
 pub mod image;
 pub mod label;
 pub mod map;
@@ -11,25 +18,52 @@ pub mod maybe;
 pub mod pad;
 pub mod paginated;
 pub mod painter;
+pub mod path;
+pub mod payment_uri;
 pub mod placed;
 pub mod qr_code;
+pub mod scroll;
+pub mod settings_diff;
+pub mod stream;
+pub mod template;
 pub mod text;
 pub mod timeout;
+pub mod toast;
+pub mod transition;
+pub mod wizard;
 
-pub use base::{Child, Component, ComponentExt, Event, EventCtx, Never, Root, TimerToken};
+pub use base::{
+    AttrUpdate, Child, Component, ComponentExt, Event, EventCtx, Never, Root, TimerKind, TimerToken,
+};
+pub use blob_view::{BlobFormat, BlobView};
 pub use border::Border;
+pub use coin_label::CoinLabel;
+pub use countdown::{Countdown, CountdownMsg};
 pub use empty::Empty;
+pub use entropy::{EntropyMixer, EntropyMixerMsg};
+#[cfg(feature = "button")]
+pub use focus::{Focus, FocusChildren, FocusGesture};
+pub use grid::{GridLayout, GridMsg};
 pub use label::Label;
 pub use map::MsgMap;
 pub use marquee::Marquee;
 pub use maybe::Maybe;
 pub use pad::Pad;
-pub use paginated::{PageMsg, Paginate};
+pub use paginated::{PageMsg, Paginate, PAGE_SELECTOR_MIN_PAGES};
 pub use painter::Painter;
+pub use path::{PathStyle, PathText};
+pub use payment_uri::{PaymentRequest, PaymentUri};
 pub use placed::{FixedHeightBar, Floating, GridPlaced, Split};
 pub use qr_code::Qr;
+pub use scroll::{Momentum, ScrollBarLayout, ScrollMarks};
+pub use settings_diff::{SettingChange, SettingsDiff};
+pub use stream::{StreamItem, StreamWindow};
+pub use template::{ConfirmTemplate, TemplateRow};
 pub use text::{
     formatted::FormattedText,
     layout::{LineBreaking, PageBreaking, TextLayout},
 };
 pub use timeout::Timeout;
+pub use toast::{Toast, ToastQueue};
+pub use transition::{Transition, TransitionKind};
+pub use wizard::{StepMsg, Wizard, WizardMsg, WizardSteps};
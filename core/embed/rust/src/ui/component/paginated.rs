@@ -16,6 +16,10 @@ pub enum PageMsg<T> {
     SwipeRight,
 }
 
+/// Paged components with at least this many pages open a page selector on a
+/// long press of the button going to the next page, to jump there directly.
+pub const PAGE_SELECTOR_MIN_PAGES: usize = 10;
+
 pub trait Paginate {
     /// How many pages of content are there in total?
     fn page_count(&mut self) -> usize;
@@ -0,0 +1,158 @@
+use crate::{
+    strutil::{format_i64, parse_path, HardenedMarker},
+    ui::{
+        component::{text::TextStyle, Component, Event, EventCtx, Never},
+        display::{self, Color, Font},
+        geometry::{Offset, Point, Rect},
+    },
+};
+
+const CHIP_PADDING: i16 = 3;
+const CHIP_SPACE: i16 = 4;
+const CHIP_RADIUS: u8 = 2;
+
+/// How the hardened levels of a path are told apart from the rest.
+#[derive(Copy, Clone)]
+pub struct PathStyle {
+    /// Separators and the levels that are not hardened.
+    pub text: TextStyle,
+    /// Hardened levels, usually a bolder variant of the text font.
+    pub hardened_font: Font,
+    /// Marker after the hardened levels, `None` to rely on the font only.
+    pub marker: Option<HardenedMarker>,
+    /// Background of the warning chip, its text is in the background color.
+    pub warning_color: Color,
+}
+
+/// Derivation path with the hardened levels styled differently. Levels wrap
+/// to the next line as a whole. Paths the firmware does not know the scheme
+/// of are followed by a warning chip, see `with_warning`.
+pub struct PathText<T> {
+    path: T,
+    style: PathStyle,
+    warning: Option<T>,
+    area: Rect,
+}
+
+impl<T> PathText<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(path: T, style: PathStyle) -> Self {
+        Self {
+            path,
+            style,
+            warning: None,
+            area: Rect::zero(),
+        }
+    }
+
+    /// Show a chip with `text` after the path, for paths that do not follow
+    /// any scheme known to the firmware.
+    pub fn with_warning(mut self, text: T) -> Self {
+        self.warning = Some(text);
+        self
+    }
+
+    /// Paint something `width` wide at `cursor`, moving to the next line
+    /// first if it does not fit. Returns the position after it.
+    fn paint_piece(&self, cursor: Point, width: i16, paint: impl FnOnce(Point)) -> Point {
+        let line_height = self.style.text.text_font.line_height();
+        let cursor = if cursor.x + width > self.area.x1 && cursor.x > self.area.x0 {
+            Point::new(self.area.x0, cursor.y + line_height)
+        } else {
+            cursor
+        };
+        paint(cursor);
+        cursor + Offset::x(width)
+    }
+
+    fn paint_text(&self, cursor: Point, parts: &[(&str, Font)]) -> Point {
+        let width = parts.iter().map(|(text, font)| font.text_width(text)).sum();
+        let fg = self.style.text.text_color;
+        let bg = self.style.text.background_color;
+        self.paint_piece(cursor, width, |mut position| {
+            for (text, font) in parts {
+                display::text_left(position, text, *font, fg, bg);
+                position = position + Offset::x(font.text_width(text));
+            }
+        })
+    }
+
+    fn paint_chip(&self, cursor: Point, text: &str) {
+        let font = self.style.text.text_font;
+        let fg = self.style.warning_color;
+        let bg = self.style.text.background_color;
+        let width = font.text_width(text) + 2 * CHIP_PADDING;
+        self.paint_piece(cursor + Offset::x(CHIP_SPACE), width, |position| {
+            let chip = Rect::from_top_left_and_size(
+                position - Offset::y(font.text_max_height() + CHIP_PADDING),
+                Offset::new(width, font.text_max_height() + 2 * CHIP_PADDING),
+            );
+            display::rect_fill_rounded(chip, fg, bg, CHIP_RADIUS);
+            display::text_left(position + Offset::x(CHIP_PADDING), text, font, bg, fg);
+        });
+    }
+}
+
+impl<T> Component for PathText<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        let font = self.style.text.text_font;
+        let start = self.area.top_left() + Offset::y(font.text_max_height());
+        let cursor = match parse_path(self.path.as_ref()) {
+            Some(levels) => {
+                let mut cursor = self.paint_text(start, &[("m", font)]);
+                for level in levels.iter() {
+                    let mut buffer = [0; 10];
+                    let index = unwrap!(format_i64(level.index as i64, &mut buffer));
+                    cursor = if level.hardened {
+                        let hardened = self.style.hardened_font;
+                        let marker = self.style.marker.map_or("", HardenedMarker::as_str);
+                        self.paint_text(
+                            cursor,
+                            &[("/", font), (index, hardened), (marker, hardened)],
+                        )
+                    } else {
+                        self.paint_text(cursor, &[("/", font), (index, font)])
+                    };
+                }
+                cursor
+            }
+            None => self.paint_text(start, &[(self.path.as_ref(), font)]),
+        };
+        if let Some(warning) = &self.warning {
+            self.paint_chip(cursor, warning.as_ref());
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for PathText<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("PathText");
+        t.string("path", self.path.as_ref());
+        t.bool("unusual", self.warning.is_some());
+    }
+}
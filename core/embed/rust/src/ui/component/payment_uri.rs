@@ -0,0 +1,231 @@
+use heapless::{String, Vec};
+
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::component::text::{
+        paragraphs::{Paragraph, ParagraphSource},
+        TextStyle,
+    },
+};
+
+/// Longest URI accepted, in bytes.
+const MAX_URI_LENGTH: usize = 512;
+/// Longest label and message in bytes, once decoded.
+pub const MAX_TEXT_LENGTH: usize = 100;
+/// Most digits after the decimal point of the amount.
+const MAX_DECIMALS: usize = 8;
+
+/// Fields of a BIP-21 payment URI, e.g.
+/// `bitcoin:bc1q...?amount=0.01&label=Shop&message=Order%2042`.
+pub struct PaymentUri<'a> {
+    pub address: &'a str,
+    pub amount: Option<&'a str>,
+    pub label: Option<String<MAX_TEXT_LENGTH>>,
+    pub message: Option<String<MAX_TEXT_LENGTH>>,
+}
+
+impl<'a> PaymentUri<'a> {
+    /// Parse `uri` of the `scheme`, e.g. `bitcoin`, refusing anything
+    /// malformed instead of showing a part of it. Parameters the firmware
+    /// does not know are ignored, unless they are required ones starting with
+    /// `req-`.
+    pub fn parse(uri: &'a str, scheme: &str) -> Result<Self, Error> {
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(value_error!("URI too long"));
+        }
+        let (uri_scheme, rest) = uri
+            .split_once(':')
+            .ok_or_else(|| value_error!("Not a payment URI"))?;
+        if !uri_scheme.eq_ignore_ascii_case(scheme) {
+            return Err(value_error!("Wrong URI scheme"));
+        }
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if address.is_empty() || !address.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(value_error!("Invalid address in URI"));
+        }
+
+        let mut result = Self {
+            address,
+            amount: None,
+            label: None,
+            message: None,
+        };
+        let mut seen: Vec<&str, 3> = Vec::new();
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| value_error!("Invalid URI parameter"))?;
+            if key.is_empty() || value.is_empty() || seen.contains(&key) {
+                return Err(value_error!("Invalid URI parameter"));
+            }
+            // Only the known ones are remembered, the rest has no limit.
+            match key {
+                "amount" => result.amount = Some(check_amount(value)?),
+                "label" => result.label = Some(percent_decode(value)?),
+                "message" => result.message = Some(percent_decode(value)?),
+                _ if key.starts_with("req-") => {
+                    return Err(value_error!("Unsupported URI parameter"))
+                }
+                _ => continue,
+            }
+            unwrap!(seen.push(key));
+        }
+        Ok(result)
+    }
+}
+
+/// Refuse `amount` unless it is a decimal number with up to `MAX_DECIMALS`
+/// digits after the point.
+fn check_amount(amount: &str) -> Result<&str, Error> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if !digits(whole)
+        || !digits(fraction)
+        || whole.len() + fraction.len() == 0
+        || fraction.len() > MAX_DECIMALS
+    {
+        return Err(value_error!("Invalid amount in URI"));
+    }
+    Ok(amount)
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
+
+/// Decode the `%XX` escapes of `text`. The result must be UTF-8 without
+/// control characters, so that it cannot break the lines of the screen.
+fn percent_decode(text: &str) -> Result<String<MAX_TEXT_LENGTH>, Error> {
+    let invalid = || value_error!("Invalid text in URI");
+    let mut decoded: Vec<u8, MAX_TEXT_LENGTH> = Vec::new();
+    let mut bytes = text.bytes();
+    while let Some(byte) = bytes.next() {
+        let byte = if byte == b'%' {
+            let high = bytes.next().and_then(hex_digit).ok_or_else(invalid)?;
+            let low = bytes.next().and_then(hex_digit).ok_or_else(invalid)?;
+            (high << 4) | low
+        } else {
+            byte
+        };
+        decoded
+            .push(byte)
+            .map_err(|_| value_error!("Text in URI too long"))?;
+    }
+    let decoded = core::str::from_utf8(&decoded).map_err(|_| invalid())?;
+    if decoded.chars().any(char::is_control) {
+        return Err(invalid());
+    }
+    let mut result = String::new();
+    unwrap!(result.push_str(decoded));
+    Ok(result)
+}
+
+/// Payment request as paragraphs, the name of each field over its value, and
+/// the whole URI on a page of its own after them.
+pub struct PaymentRequest<T> {
+    /// Name and value of the address, amount, label and message, the missing
+    /// ones left out.
+    fields: Vec<(&'static str, T), 4>,
+    uri: T,
+    key_style: &'static TextStyle,
+    value_style: &'static TextStyle,
+}
+
+impl<T> PaymentRequest<T>
+where
+    T: StringType,
+{
+    pub fn new(
+        address: T,
+        amount: Option<T>,
+        label: Option<T>,
+        message: Option<T>,
+        uri: T,
+        key_style: &'static TextStyle,
+        value_style: &'static TextStyle,
+    ) -> Self {
+        let mut fields = Vec::new();
+        unwrap!(fields.push(("Address", address)));
+        let optional = [("Amount", amount), ("Label", label), ("Message", message)];
+        for (name, value) in optional {
+            if let Some(value) = value {
+                unwrap!(fields.push((name, value)));
+            }
+        }
+        Self {
+            fields,
+            uri,
+            key_style,
+            value_style,
+        }
+    }
+}
+
+impl<T> ParagraphSource for PaymentRequest<T>
+where
+    T: StringType,
+{
+    type StrType = T;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        let (name, value) = match self.fields.get(index / 2) {
+            Some((name, value)) => (*name, value),
+            None => ("Payment request", &self.uri),
+        };
+        if index % 2 == 0 {
+            Paragraph::new(self.key_style, T::from(name).skip_prefix(offset)).no_break()
+        } else if index == 2 * self.fields.len() - 1 {
+            // The last field, the URI follows on the next page.
+            Paragraph::new(self.value_style, value.skip_prefix(offset)).break_after()
+        } else {
+            Paragraph::new(self.value_style, value.skip_prefix(offset))
+        }
+    }
+
+    fn size(&self) -> usize {
+        2 * self.fields.len() + 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+    #[test]
+    fn parse() {
+        let uri = "BITCOIN:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=0.015&label=Coffee%20Shop&message=Order%20%2342&foo=bar";
+        let parsed = PaymentUri::parse(uri, "bitcoin").unwrap();
+        assert_eq!(parsed.address, ADDRESS);
+        assert_eq!(parsed.amount, Some("0.015"));
+        assert_eq!(parsed.label.as_deref(), Some("Coffee Shop"));
+        assert_eq!(parsed.message.as_deref(), Some("Order #42"));
+
+        let parsed = PaymentUri::parse("bitcoin:1BoatSLRHtKNngkdXEeobR76b53LETtpyT", "bitcoin");
+        assert!(parsed.unwrap().amount.is_none());
+    }
+
+    #[test]
+    fn malformed() {
+        let invalid = [
+            "bitcoin:",
+            "litecoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            "bitcoin:bc1q ar0srrr7",
+            "bitcoin:bc1qar0srrr7?amount=1.5.0",
+            "bitcoin:bc1qar0srrr7?amount=-1",
+            "bitcoin:bc1qar0srrr7?amount=0.123456789",
+            "bitcoin:bc1qar0srrr7?amount=1&amount=2",
+            "bitcoin:bc1qar0srrr7?label=%2",
+            "bitcoin:bc1qar0srrr7?label=%zz",
+            "bitcoin:bc1qar0srrr7?message=Line%0AFake",
+            "bitcoin:bc1qar0srrr7?label=%ff",
+            "bitcoin:bc1qar0srrr7?req-somethingnew=1",
+            "bitcoin:bc1qar0srrr7?label",
+        ];
+        for uri in invalid {
+            assert!(PaymentUri::parse(uri, "bitcoin").is_err(), "{}", uri);
+        }
+    }
+}
@@ -4,10 +4,11 @@ use qrcodegen::{QrCode, QrCodeEcc, Version};
 use crate::{
     error::Error,
     ui::{
-        component::{Component, Event, EventCtx, Never},
+        budget, busy,
+        component::{Component, Event, EventCtx, Never, Paginate},
         constant,
-        display::{pixeldata, pixeldata_dirty, rect_fill_rounded, set_window, Color},
-        geometry::{Insets, Offset, Rect},
+        display::{pixeldata, pixeldata_dirty, rect_fill_rounded, set_window, themed, Color},
+        geometry::{Insets, Offset, Point, Rect},
     },
 };
 
@@ -18,41 +19,99 @@ const ALPHANUMERIC_CHARSET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./
 const MAX_DATA: usize = THRESHOLDS_ALPHANUM[THRESHOLDS_ALPHANUM.len() - 1];
 
 const QR_MAX_VERSION: Version = Version::new(NVERSIONS as u8 - 1);
+/// Side of the largest code in modules.
+const MAX_SIZE: usize = 4 * (NVERSIONS - 1) + 17;
+/// Longer texts are encoded by the paint following the one that shows the
+/// placeholder, encoding them takes a noticeable time.
+const PLACEHOLDER_THRESHOLD: usize = THRESHOLDS_BINARY[4];
 const CORNER_RADIUS: u8 = 4;
 
 const DARK: Color = Color::rgb(0, 0, 0);
 const LIGHT: Color = Color::rgb(0xff, 0xff, 0xff);
 
-pub struct Qr {
-    text: String<MAX_DATA>,
+/// Dark modules of an encoded code, one bit each.
+struct Modules {
+    size: i16,
+    bits: [u8; (MAX_SIZE * MAX_SIZE + 7) / 8],
+}
+
+impl Modules {
+    fn encode(text: &str) -> Self {
+        let mut outbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
+        let mut tempbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
+
+        let qr = unwrap!(QrCode::encode_text(
+            text,
+            &mut tempbuffer,
+            &mut outbuffer,
+            QrCodeEcc::Medium,
+            Version::MIN,
+            QR_MAX_VERSION,
+            None,
+            true,
+        ));
+        let size = qr.size() as i16;
+        let mut modules = Self {
+            size,
+            bits: [0; (MAX_SIZE * MAX_SIZE + 7) / 8],
+        };
+        for y in 0..size {
+            busy::tick();
+            for x in 0..size {
+                if qr.get_module(x.into(), y.into()) {
+                    let i = (y * size + x) as usize;
+                    modules.bits[i / 8] |= 1 << (i % 8);
+                }
+            }
+        }
+        modules
+    }
+
+    fn get(&self, x: i16, y: i16) -> bool {
+        let i = (y * self.size + x) as usize;
+        self.bits[i / 8] & (1 << (i % 8)) != 0
+    }
+}
+
+/// QR code of the given text. The text is kept as it is, only converted to
+/// uppercase when the code is painted in case it results in a smaller code.
+///
+/// The code is encoded by the first paint and kept. Long texts are encoded
+/// by the next paint, a placeholder is shown in the meantime, and a paint
+/// that runs out of time leaves the rest of the rows for the next one, see
+/// `budget::defer`.
+pub struct Qr<T> {
+    text: T,
+    uppercase: bool,
     border: i16,
     area: Rect,
+    modules: Option<Modules>,
+    placeholder_shown: bool,
+    /// Code row the paint continues with.
+    next_row: i16,
 }
 
-impl Qr {
-    pub fn new<T>(text: T, case_sensitive: bool) -> Result<Self, Error>
-    where
-        T: AsRef<str>,
-    {
+impl<T> Qr<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(text: T, case_sensitive: bool) -> Result<Self, Error> {
         let indata = text.as_ref();
-        let mut s = String::new();
-
-        if !case_sensitive
-            && Self::is_smaller_for_alphanumeric(indata.len())
-            && Self::is_alphanumeric_after_conversion(indata)
-        {
-            for c in indata.chars() {
-                s.push(c.to_ascii_uppercase())
-                    .map_err(|_| Error::OutOfRange)?;
-            }
-        } else {
-            s.push_str(indata).map_err(|_| Error::OutOfRange)?;
+        if indata.len() > MAX_DATA {
+            return Err(Error::OutOfRange);
         }
+        let uppercase = !case_sensitive
+            && Self::is_smaller_for_alphanumeric(indata.len())
+            && Self::is_alphanumeric_after_conversion(indata);
 
         Ok(Self {
-            text: s,
+            text,
+            uppercase,
             border: 0,
             area: Rect::zero(),
+            modules: None,
+            placeholder_shown: false,
+            next_row: 0,
         })
     }
 
@@ -75,24 +134,71 @@ impl Qr {
         false
     }
 
-    fn draw(qr: &QrCode, area: Rect, border: i16, scale: i16) {
+    /// Side of the code in modules, known before encoding unless the text is
+    /// numeric.
+    fn expected_size(text: &str) -> Option<i16> {
+        let thresholds = if !text.chars().all(|c| ALPHANUMERIC_CHARSET.contains(c)) {
+            &THRESHOLDS_BINARY
+        } else if !text.chars().all(|c| c.is_ascii_digit()) {
+            &THRESHOLDS_ALPHANUM
+        } else {
+            return None;
+        };
+        let version = thresholds.iter().position(|t| text.len() <= *t)?;
+        Some(4 * (version as i16 + 1) + 17)
+    }
+
+    /// Square of `size` modules the code is painted into.
+    fn code_area(&self, size: i16) -> Rect {
+        let avail_space = self.area.width().min(self.area.height());
+        let avail_space = avail_space - 2 * self.border;
+        let scale = avail_space / size;
+        assert!((1..=10).contains(&scale));
+        Rect::from_center_and_size(self.area.center(), Offset::uniform(size * scale))
+    }
+
+    /// Call `func` with the text that is encoded in the code.
+    fn with_encoded_text<R>(&self, func: impl FnOnce(&str) -> R) -> R {
+        if self.uppercase {
+            let mut s = String::<MAX_DATA>::new();
+            for c in self.text.as_ref().chars() {
+                // Length is checked in `new()`, uppercase of an alphanumeric
+                // character is of the same size.
+                unwrap!(s.push(c.to_ascii_uppercase()));
+            }
+            func(&s)
+        } else {
+            func(self.text.as_ref())
+        }
+    }
+
+    fn draw_border(area: Rect, border: i16) {
         if border > 0 {
+            // The primitives swap the greys in the inverted theme variant,
+            // swapping them once more here keeps the code dark on light in
+            // every variant, which is what the scanners expect. The modules
+            // are written to the display as they are.
             rect_fill_rounded(
                 area.inset(Insets::uniform(-border)),
-                LIGHT,
+                themed(LIGHT),
                 DARK,
                 CORNER_RADIUS,
             );
         }
+    }
 
-        let window = area.clamp(constant::screen());
+    /// Draw code row `row` into `area`, each module is `scale` pixels wide.
+    fn draw_row(modules: &Modules, area: Rect, scale: i16, row: i16) {
+        let band = Rect::new(
+            Point::new(area.x0, area.y0 + row * scale),
+            Point::new(area.x1, area.y0 + (row + 1) * scale),
+        );
+        let window = band.clamp(constant::screen());
         set_window(window);
 
-        for y in window.y0..window.y1 {
+        for _ in window.y0..window.y1 {
             for x in window.x0..window.x1 {
-                let rx = (x - window.x0) / scale;
-                let ry = (y - window.y0) / scale;
-                if qr.get_module(rx.into(), ry.into()) {
+                if modules.get((x - area.x0) / scale, row) {
                     pixeldata(DARK);
                 } else {
                     pixeldata(LIGHT);
@@ -103,11 +209,15 @@ impl Qr {
     }
 }
 
-impl Component for Qr {
+impl<T> Component for Qr<T>
+where
+    T: AsRef<str>,
+{
     type Msg = Never;
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
+        self.next_row = 0;
         bounds
     }
 
@@ -116,29 +226,42 @@ impl Component for Qr {
     }
 
     fn paint(&mut self) {
-        let mut outbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
-        let mut tempbuffer = [0u8; QR_MAX_VERSION.buffer_len()];
-
-        let qr = QrCode::encode_text(
-            self.text.as_ref(),
-            &mut tempbuffer,
-            &mut outbuffer,
-            QrCodeEcc::Medium,
-            Version::MIN,
-            QR_MAX_VERSION,
-            None,
-            true,
-        );
-        let qr = unwrap!(qr);
-        let size = qr.size() as i16;
-
-        let avail_space = self.area.width().min(self.area.height());
-        let avail_space = avail_space - 2 * self.border;
-        let scale = avail_space / size;
-        assert!((1..=10).contains(&scale));
-
-        let area = Rect::from_center_and_size(self.area.center(), Offset::uniform(size * scale));
-        Self::draw(&qr, area, self.border, scale);
+        if self.modules.is_none() {
+            let placeholder = (!self.placeholder_shown
+                && self.text.as_ref().len() > PLACEHOLDER_THRESHOLD
+                && budget::in_pass())
+            .then(|| self.with_encoded_text(Self::expected_size))
+            .flatten();
+            if let Some(size) = placeholder {
+                // Only the light background, the code is drawn over it later.
+                self.placeholder_shown = true;
+                let area = self.code_area(size).inset(Insets::uniform(-self.border));
+                rect_fill_rounded(area, themed(LIGHT), DARK, CORNER_RADIUS);
+                budget::defer();
+                return;
+            }
+            // The spinner is in the middle of the code, it gets painted over.
+            let modules = busy::run(self.area, themed(DARK), themed(LIGHT), || {
+                self.with_encoded_text(Modules::encode)
+            });
+            self.modules = Some(modules);
+        }
+        let modules = unwrap!(self.modules.as_ref());
+        let size = modules.size;
+        let area = self.code_area(size);
+        let scale = area.width() / size;
+        if self.next_row == 0 {
+            Self::draw_border(area, self.border);
+        }
+        for row in self.next_row..size {
+            Self::draw_row(modules, area, scale, row);
+            if row + 1 < size && budget::exhausted() {
+                self.next_row = row + 1;
+                budget::defer();
+                return;
+            }
+        }
+        self.next_row = 0;
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -147,10 +270,89 @@ impl Component for Qr {
     }
 }
 
+impl<T> Paginate for Qr<T>
+where
+    T: AsRef<str>,
+{
+    fn page_count(&mut self) -> usize {
+        1
+    }
+
+    fn change_page(&mut self, _to_page: usize) {}
+}
+
 #[cfg(feature = "ui_debug")]
-impl crate::trace::Trace for Qr {
+impl<T> crate::trace::Trace for Qr<T>
+where
+    T: AsRef<str>,
+{
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Qr");
-        t.string("text", self.text.as_ref());
+        self.with_encoded_text(|text| t.string("text", text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        time::{Duration, Instant},
+        ui::{context::with_state, testing::Harness},
+    };
+
+    use super::*;
+
+    /// Encoded at once, into a code of 25 modules.
+    const ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+    /// Long enough for the placeholder, short enough for a 41 module code.
+    const LONG: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq \
+                        bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+    fn paint_in_pass(h: &mut Harness<Qr<&'static str>>) -> bool {
+        budget::measure("test", || h.mutate(|_, qr| qr.paint()));
+        budget::take_deferred()
+    }
+
+    #[test]
+    fn long_text_is_encoded_by_the_next_pass() {
+        let mut h = Harness::new(unwrap!(Qr::new(LONG, true)));
+
+        assert!(paint_in_pass(&mut h));
+        assert!(h.inner().placeholder_shown);
+        assert!(h.inner().modules.is_none());
+
+        assert!(!paint_in_pass(&mut h));
+        assert!(h.inner().modules.as_ref().map(|m| m.size) == Some(41));
+    }
+
+    #[test]
+    fn encoded_at_once_outside_of_a_pass() {
+        let mut h = Harness::new(unwrap!(Qr::new(LONG, true)));
+        h.mutate(|_, qr| qr.paint());
+        assert!(!h.inner().placeholder_shown);
+        assert!(h.inner().modules.is_some());
+        assert!(!budget::take_deferred());
+    }
+
+    #[test]
+    fn rows_continue_when_out_of_time() {
+        let mut h = Harness::new(unwrap!(Qr::new(ADDRESS, true)));
+        let spent = Instant::now().checked_sub(Duration::from_millis(1000));
+        with_state(|s| s.pass_start = spent);
+
+        h.mutate(|_, qr| qr.paint());
+        assert!(h.inner().next_row == 1);
+        h.mutate(|_, qr| qr.paint());
+        assert!(h.inner().next_row == 2);
+
+        with_state(|s| s.pass_start = None);
+        h.mutate(|_, qr| qr.paint());
+        assert!(h.inner().next_row == 0);
+        assert!(budget::take_deferred());
+    }
+
+    #[test]
+    fn too_long_text() {
+        let text = Box::leak("x".repeat(MAX_DATA + 1).into_boxed_str());
+        assert!(Qr::new(&*text, true).is_err());
     }
 }
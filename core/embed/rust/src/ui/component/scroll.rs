@@ -0,0 +1,163 @@
+use crate::ui::geometry::{Axis, Offset, Rect};
+
+/// How a scrollbar indicates the position among the pages.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ScrollMarks {
+    /// Nothing, there is only one page.
+    None,
+    /// One dot for each page, possibly condensed at the ends.
+    Dots,
+    /// A track with a thumb, when there are too many pages for dots.
+    Bar,
+}
+
+/// Model-independent geometry of scrollbars, the models only differ in how
+/// the dots and the bar are painted.
+#[derive(Copy, Clone)]
+pub struct ScrollBarLayout {
+    pub axis: Axis,
+    /// Up to this many pages are shown as dots, more as a bar.
+    pub max_dotted_pages: usize,
+    /// Shortest the thumb of the bar can get.
+    pub min_thumb: i16,
+}
+
+impl ScrollBarLayout {
+    pub const fn new(axis: Axis, max_dotted_pages: usize, min_thumb: i16) -> Self {
+        Self {
+            axis,
+            max_dotted_pages,
+            min_thumb,
+        }
+    }
+
+    pub const fn marks(&self, page_count: usize) -> ScrollMarks {
+        if page_count <= 1 {
+            ScrollMarks::None
+        } else if page_count <= self.max_dotted_pages {
+            ScrollMarks::Dots
+        } else {
+            ScrollMarks::Bar
+        }
+    }
+
+    /// Thumb within the `track` of a bar. Its length is proportional to one
+    /// page out of `page_count`, its position to `active_page`.
+    pub fn thumb(&self, track: Rect, page_count: usize, active_page: usize) -> Rect {
+        let track_len = track.size().axis(self.axis);
+        let page_count = page_count.max(1);
+        let len = (track_len / page_count as i16)
+            .max(self.min_thumb)
+            .min(track_len);
+        let start = if page_count > 1 {
+            let active_page = active_page.min(page_count - 1) as i32;
+            ((track_len - len) as i32 * active_page / (page_count as i32 - 1)) as i16
+        } else {
+            0
+        };
+        let cross = track.size().axis(self.axis.cross());
+        let size = match self.axis {
+            Axis::Horizontal => Offset::new(len, cross),
+            Axis::Vertical => Offset::new(cross, len),
+        };
+        Rect::from_top_left_and_size(track.top_left() + Offset::on_axis(self.axis, start), size)
+    }
+}
+
+/// Momentum of a fling through paged content, decelerating until it stops.
+/// Positive velocities move towards the end of the content.
+pub struct Momentum {
+    /// Pages per second.
+    velocity: f32,
+    /// Pages travelled but not yet turned.
+    travelled: f32,
+}
+
+impl Momentum {
+    /// Deceleration, in pages per second squared.
+    const FRICTION: f32 = 12.0;
+    /// Slower flings are stopped.
+    const MIN_VELOCITY: f32 = 1.0;
+
+    pub const fn new() -> Self {
+        Self {
+            velocity: 0.0,
+            travelled: 0.0,
+        }
+    }
+
+    pub fn fling(&mut self, velocity: f32) {
+        self.velocity = velocity;
+        self.travelled = 0.0;
+    }
+
+    pub fn stop(&mut self) {
+        self.velocity = 0.0;
+        self.travelled = 0.0;
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.velocity >= Self::MIN_VELOCITY || self.velocity <= -Self::MIN_VELOCITY
+    }
+
+    /// Move on by `millis` milliseconds, returns the number of pages to turn.
+    pub fn step(&mut self, millis: u32) -> isize {
+        if !self.is_moving() {
+            self.stop();
+            return 0;
+        }
+        let dt = millis as f32 / 1000.0;
+        self.travelled += self.velocity * dt;
+        let decrease = Self::FRICTION * dt;
+        self.velocity = if self.velocity > 0.0 {
+            (self.velocity - decrease).max(0.0)
+        } else {
+            (self.velocity + decrease).min(0.0)
+        };
+        let pages = self.travelled as isize;
+        self.travelled -= pages as f32;
+        pages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::geometry::Point;
+
+    #[test]
+    fn thumb() {
+        let layout = ScrollBarLayout::new(Axis::Vertical, 7, 4);
+        let track = Rect::from_top_left_and_size(Point::new(10, 20), Offset::new(2, 100));
+        assert!(layout.marks(1) == ScrollMarks::None);
+        assert!(layout.marks(7) == ScrollMarks::Dots);
+        assert!(layout.marks(8) == ScrollMarks::Bar);
+
+        let first = layout.thumb(track, 10, 0);
+        assert!(first == Rect::new(Point::new(10, 20), Point::new(12, 30)));
+        let last = layout.thumb(track, 10, 9);
+        assert!(last == Rect::new(Point::new(10, 110), Point::new(12, 120)));
+
+        // The thumb does not get shorter than the minimum.
+        let thumb = layout.thumb(track, 50, 25);
+        assert_eq!(thumb.size(), Offset::new(2, 4));
+        assert_eq!(thumb.y0, 20 + 96 * 25 / 49);
+    }
+
+    #[test]
+    fn momentum() {
+        let mut momentum = Momentum::new();
+        momentum.fling(10.0);
+        let mut pages = 0;
+        while momentum.is_moving() {
+            pages += momentum.step(50);
+        }
+        // Roughly v^2 / 2a, decelerating from 10 pages per second.
+        assert!((3..=5).contains(&pages));
+
+        momentum.fling(-10.0);
+        assert!(momentum.step(200) < 0);
+        momentum.stop();
+        assert_eq!(momentum.step(200), 0);
+    }
+}
@@ -0,0 +1,227 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::{text::TextStyle, Component, Event, EventCtx, Never, Paginate},
+    display::{self, Icon},
+    geometry::{Alignment2D, Offset, Point, Rect},
+};
+
+/// Most changes shown on one screen, the ones over it are left out.
+pub const MAX_CHANGES: usize = 4;
+/// Space on both sides of the arrow.
+const ARROW_SPACE: i16 = 4;
+/// Space between two changes.
+const CHANGE_SPACE: i16 = 6;
+
+/// One setting changed from `old` to `new`. `risky` if the new value reduces
+/// security, e.g. turning off the PIN or a longer auto-lock delay.
+pub struct SettingChange<T> {
+    pub label: T,
+    pub old: T,
+    pub new: T,
+    pub risky: bool,
+}
+
+/// Changes of settings, each of them the name of the setting over the old
+/// value, an arrow and the new value. New values reducing security are painted
+/// in `risky_style`, so that the dangerous direction stands out.
+pub struct SettingsDiff<T> {
+    area: Rect,
+    changes: Vec<SettingChange<T>, MAX_CHANGES>,
+    label_style: TextStyle,
+    value_style: TextStyle,
+    risky_style: TextStyle,
+    arrow: Icon,
+}
+
+impl<T> SettingsDiff<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(
+        changes: impl IntoIterator<Item = SettingChange<T>>,
+        label_style: TextStyle,
+        value_style: TextStyle,
+        risky_style: TextStyle,
+        arrow: Icon,
+    ) -> Self {
+        Self {
+            area: Rect::zero(),
+            changes: changes.into_iter().take(MAX_CHANGES).collect(),
+            label_style,
+            value_style,
+            risky_style,
+            arrow,
+        }
+    }
+
+    /// Paint `change` from the top of `area`, returning where the next one
+    /// goes.
+    fn paint_change(&self, change: &SettingChange<T>, top: i16) -> i16 {
+        let label_font = self.label_style.text_font;
+        let value_font = self.value_style.text_font;
+        let mut y = top + label_font.text_height();
+        display::text_left(
+            Point::new(self.area.x0, y),
+            change.label.as_ref(),
+            label_font,
+            self.label_style.text_color,
+            self.label_style.background_color,
+        );
+
+        y += value_font.line_height();
+        let mut x = self.area.x0;
+        display::text_left(
+            Point::new(x, y),
+            change.old.as_ref(),
+            value_font,
+            self.value_style.text_color,
+            self.value_style.background_color,
+        );
+        x += value_font.text_width(change.old.as_ref()) + ARROW_SPACE;
+        self.arrow.draw(
+            Point::new(x, y - value_font.text_height() / 2),
+            Alignment2D::CENTER_LEFT,
+            self.value_style.text_color,
+            self.value_style.background_color,
+        );
+        x += self.arrow.toif.width() + ARROW_SPACE;
+
+        let style = if change.risky {
+            &self.risky_style
+        } else {
+            &self.value_style
+        };
+        let new_font = style.text_font;
+        let new_width = new_font.text_width(change.new.as_ref());
+        if x + new_width > self.area.x1 {
+            // Not enough room after the arrow.
+            x = self.area.x0;
+            y += new_font.line_height();
+        }
+        let baseline = Point::new(x, y);
+        display::rect_fill(
+            Rect::from_bottom_left_and_size(
+                baseline + Offset::y(new_font.text_baseline()),
+                Offset::new(new_width, new_font.line_height()),
+            ),
+            style.background_color,
+        );
+        display::text_left(
+            baseline,
+            change.new.as_ref(),
+            new_font,
+            style.text_color,
+            style.background_color,
+        );
+        y + new_font.text_baseline() + CHANGE_SPACE
+    }
+}
+
+impl<T> Component for SettingsDiff<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        let mut top = self.area.y0;
+        for change in self.changes.iter() {
+            top = self.paint_change(change, top);
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+impl<T> Paginate for SettingsDiff<T>
+where
+    T: AsRef<str>,
+{
+    fn page_count(&mut self) -> usize {
+        1
+    }
+
+    fn change_page(&mut self, _to_page: usize) {}
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for SettingsDiff<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("SettingsDiff");
+        t.in_list("changes", &|list| {
+            for change in self.changes.iter() {
+                list.string(change.label.as_ref());
+                list.string(change.old.as_ref());
+                list.string(change.new.as_ref());
+                list.bool(change.risky);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{model_tt::theme, testing::Harness};
+
+    fn change(label: &'static str, risky: bool) -> SettingChange<&'static str> {
+        SettingChange {
+            label,
+            old: "ON",
+            new: "OFF",
+            risky,
+        }
+    }
+
+    fn diff(labels: &[&'static str]) -> Harness<SettingsDiff<&'static str>> {
+        Harness::new(SettingsDiff::new(
+            labels.iter().map(|label| change(label, *label == "PIN")),
+            theme::TEXT_NORMAL,
+            theme::TEXT_DEMIBOLD,
+            theme::TEXT_SETTING_RISKY,
+            theme::ICON_LIST_CURRENT,
+        ))
+    }
+
+    #[test]
+    fn changes_over_the_limit_are_left_out() {
+        let h = diff(&[
+            "PIN",
+            "Wipe code",
+            "Auto-lock",
+            "Passphrase",
+            "Safety checks",
+        ]);
+        let labels: std::vec::Vec<_> = h.inner().changes.iter().map(|c| c.label).collect();
+        assert_eq!(labels, ["PIN", "Wipe code", "Auto-lock", "Passphrase"]);
+    }
+
+    #[cfg(feature = "ui_debug")]
+    #[test]
+    fn changes_are_traced_in_order() {
+        use crate::trace::tests::trace;
+
+        let h = diff(&["PIN", "Auto-lock"]);
+        let traced = trace(h.inner());
+        assert_eq!(
+            traced["changes"],
+            serde_json::json!(["PIN", "ON", "OFF", true, "Auto-lock", "ON", "OFF", false])
+        );
+    }
+}
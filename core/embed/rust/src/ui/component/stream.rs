@@ -0,0 +1,106 @@
+use heapless::{Deque, String};
+
+/// Longest title of a streamed item.
+pub const STREAM_TITLE_LEN: usize = 32;
+/// Longest value of a streamed item, longer values have to be split by the
+/// sender.
+pub const STREAM_VALUE_LEN: usize = 160;
+
+/// Title and value of one item of streamed content.
+pub struct StreamItem {
+    pub title: String<STREAM_TITLE_LEN>,
+    pub value: String<STREAM_VALUE_LEN>,
+}
+
+/// Content that arrives piece by piece while it is being shown, e.g. the
+/// outputs of a large transaction. Only the last `N` items are kept, older
+/// ones are dropped as new ones arrive, so the memory used does not depend on
+/// the size of the whole content. Items are addressed by their position in
+/// the whole stream.
+pub struct StreamWindow<const N: usize> {
+    items: Deque<StreamItem, N>,
+    /// Position of the oldest item kept.
+    first: usize,
+    finished: bool,
+}
+
+impl<const N: usize> StreamWindow<N> {
+    pub const fn new() -> Self {
+        Self {
+            items: Deque::new(),
+            first: 0,
+            finished: false,
+        }
+    }
+
+    /// Append an item, dropping the oldest one if the window is full. Returns
+    /// false if the item is too long or the stream has ended.
+    pub fn push(&mut self, title: &str, value: &str) -> bool {
+        if self.finished {
+            return false;
+        }
+        let mut item = StreamItem {
+            title: String::new(),
+            value: String::new(),
+        };
+        if item.title.push_str(title).is_err() || item.value.push_str(value).is_err() {
+            return false;
+        }
+        if self.items.is_full() {
+            self.items.pop_front();
+            self.first += 1;
+        }
+        unwrap!(self.items.push_back(item));
+        true
+    }
+
+    /// No more items are coming.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Position of the oldest item still available.
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Number of items received so far.
+    pub fn received(&self) -> usize {
+        self.first + self.items.len()
+    }
+
+    /// Item at position `index` of the stream, if it is still kept.
+    pub fn get(&self, index: usize) -> Option<&StreamItem> {
+        self.items.iter().nth(index.checked_sub(self.first)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window() {
+        let mut stream = StreamWindow::<2>::new();
+        assert!(stream.get(0).is_none());
+        assert!(stream.push("Output 1", "bc1q..."));
+        assert!(stream.push("Output 2", "bc1p..."));
+        assert!(stream.push("Fee", "0.0001 BTC"));
+        assert_eq!(stream.received(), 3);
+        assert_eq!(stream.first(), 1);
+        // The oldest item has been dropped.
+        assert!(stream.get(0).is_none());
+        assert_eq!(unwrap!(stream.get(2)).title.as_str(), "Fee");
+        assert!(stream.get(3).is_none());
+
+        let long = [b'x'; STREAM_VALUE_LEN + 1];
+        assert!(!stream.push("Data", unwrap!(core::str::from_utf8(&long).ok())));
+        stream.finish();
+        assert!(!stream.push("Output 3", "bc1q..."));
+        assert_eq!(stream.received(), 3);
+    }
+}
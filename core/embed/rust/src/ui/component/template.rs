@@ -0,0 +1,182 @@
+use heapless::Vec;
+
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::component::text::{
+        paragraphs::{Paragraph, ParagraphSource},
+        TextStyle,
+    },
+};
+
+/// Most rows of one confirmation.
+pub const MAX_ROWS: usize = 6;
+/// Longest title in bytes, so that it fits the title bar of both models.
+const MAX_TITLE_LENGTH: usize = 16;
+const MAX_KEY_LENGTH: usize = 20;
+const MAX_VALUE_LENGTH: usize = 100;
+/// Longest button label in bytes.
+const MAX_LABEL_LENGTH: usize = 10;
+
+/// One row of a template, the name of a property and its value.
+pub struct TemplateRow<T> {
+    pub key: T,
+    pub value: T,
+}
+
+/// Confirmation of an application-specific action described by the host,
+/// instead of free-form text that could pass for a screen of the firmware
+/// itself. Only the title, up to `MAX_ROWS` rows, the button labels and
+/// whether to warn come from the host, and each of them is checked in `new`.
+pub struct ConfirmTemplate<T> {
+    title: T,
+    rows: Vec<TemplateRow<T>, MAX_ROWS>,
+    warning: bool,
+    verb: T,
+    verb_cancel: T,
+}
+
+/// Refuse `text` unless it is printable ASCII, without surrounding spaces and
+/// at most `max_len` bytes long. Rules out line breaks and characters the
+/// fonts cannot show, which could be used to imitate other screens.
+fn check(text: &str, max_len: usize) -> Result<(), Error> {
+    if text.is_empty() {
+        return Err(value_error!("Empty template text"));
+    }
+    if text.len() > max_len {
+        return Err(value_error!("Template text too long"));
+    }
+    if !text.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+        return Err(value_error!("Invalid character in template"));
+    }
+    if text.starts_with(' ') || text.ends_with(' ') {
+        return Err(value_error!("Template text not trimmed"));
+    }
+    Ok(())
+}
+
+impl<T> ConfirmTemplate<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(
+        title: T,
+        rows: impl IntoIterator<Item = TemplateRow<T>>,
+        warning: bool,
+        verb: T,
+        verb_cancel: T,
+    ) -> Result<Self, Error> {
+        check(title.as_ref(), MAX_TITLE_LENGTH)?;
+        check(verb.as_ref(), MAX_LABEL_LENGTH)?;
+        check(verb_cancel.as_ref(), MAX_LABEL_LENGTH)?;
+        let mut checked = Vec::new();
+        for row in rows {
+            check(row.key.as_ref(), MAX_KEY_LENGTH)?;
+            check(row.value.as_ref(), MAX_VALUE_LENGTH)?;
+            checked
+                .push(row)
+                .map_err(|_| value_error!("Too many template rows"))?;
+        }
+        if checked.is_empty() {
+            return Err(value_error!("No template rows"));
+        }
+        Ok(Self {
+            title,
+            rows: checked,
+            warning,
+            verb,
+            verb_cancel,
+        })
+    }
+
+    pub fn title(&self) -> T {
+        self.title.clone()
+    }
+
+    /// Whether the action is risky, the models confirm it in a way that
+    /// stands out.
+    pub fn warning(&self) -> bool {
+        self.warning
+    }
+
+    pub fn verb(&self) -> T {
+        self.verb.clone()
+    }
+
+    pub fn verb_cancel(&self) -> T {
+        self.verb_cancel.clone()
+    }
+
+    /// The rows as paragraphs, each key in `key_style` over its value in
+    /// `value_style`.
+    pub fn rows(
+        &self,
+        key_style: &'static TextStyle,
+        value_style: &'static TextStyle,
+    ) -> TemplateRows<T> {
+        TemplateRows {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| TemplateRow {
+                    key: row.key.clone(),
+                    value: row.value.clone(),
+                })
+                .collect(),
+            key_style,
+            value_style,
+        }
+    }
+}
+
+pub struct TemplateRows<T> {
+    rows: Vec<TemplateRow<T>, MAX_ROWS>,
+    key_style: &'static TextStyle,
+    value_style: &'static TextStyle,
+}
+
+impl<T> ParagraphSource for TemplateRows<T>
+where
+    T: StringType,
+{
+    type StrType = T;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        let row = &self.rows[index / 2];
+        if index % 2 == 0 {
+            Paragraph::new(self.key_style, row.key.skip_prefix(offset)).no_break()
+        } else {
+            Paragraph::new(self.value_style, row.value.skip_prefix(offset))
+        }
+    }
+
+    fn size(&self) -> usize {
+        2 * self.rows.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(key: &'static str, value: &'static str) -> TemplateRow<&'static str> {
+        TemplateRow { key, value }
+    }
+
+    #[test]
+    fn validation() {
+        let template = |title, rows: &[TemplateRow<&'static str>]| {
+            let rows = rows.iter().map(|r| row(r.key, r.value));
+            ConfirmTemplate::new(title, rows, false, "APPROVE", "REJECT")
+        };
+        assert!(template("APPROVE VOTE", &[row("Proposal", "#12")]).is_ok());
+        assert!(template("APPROVE VOTE", &[]).is_err());
+        assert!(template("", &[row("Proposal", "#12")]).is_err());
+        assert!(template("A VERY LONG TITLE TEXT", &[row("Proposal", "#12")]).is_err());
+        assert!(template("VOTE", &[row("Proposal", "#12\nConfirmed")]).is_err());
+        assert!(template("VOTE", &[row("Proposal", " #12")]).is_err());
+        assert!(template("VOTE", &[row("Návrh", "#12")]).is_err());
+        let rows = [(); MAX_ROWS + 1].map(|_| row("Proposal", "#12"));
+        assert!(template("VOTE", &rows).is_err());
+    }
+}
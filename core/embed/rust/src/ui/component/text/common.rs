@@ -1,5 +1,7 @@
-use crate::ui::{component::EventCtx, util::ResultExt};
-use heapless::String;
+use crate::{
+    strutil::{count_graphemes, truncate_graphemes, SecretString},
+    ui::{component::EventCtx, stats},
+};
 
 /// Reified editing operations of `TextBox`.
 ///
@@ -10,22 +12,59 @@ pub enum TextEdit {
     Append(char),
 }
 
+/// Longest PIN accepted by the keyboards, in digits.
+pub const MAX_PIN_LENGTH: usize = 50;
+/// Longest passphrase accepted by the keyboards, in bytes. Keep in sync with
+/// `apps.common.passphrase._MAX_PASSPHRASE_LEN`.
+pub const MAX_PASSPHRASE_LENGTH: usize = 50;
+
+/// Compile-time check that a buffer of `L` bytes holds input of up to `N`
+/// bytes. Using `OK` fails the build if it does not, instead of the input
+/// being cut off or rejected at runtime.
+pub struct Holds<const L: usize, const N: usize>;
+
+impl<const L: usize, const N: usize> Holds<L, N> {
+    pub const OK: () = assert!(N <= L, "buffer too small for the input");
+}
+
+/// The edit would not fit into the `TextBox`, its content was left unchanged.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TextBoxFull;
+
 /// Wraps a character buffer of maximum length `L` and provides text editing
 /// operations over it. Text ops usually take a `EventCtx` to request a paint
-/// pass in case of any state modification.
+/// pass in case of any state modification. The content is kept in a
+/// `SecretString`, all the inputs hold secrets of some kind.
 pub struct TextBox<const L: usize> {
-    text: String<L>,
+    text: SecretString<L>,
+    /// Longest content accepted, in bytes, at most `L`.
+    max_len: usize,
 }
 
 impl<const L: usize> TextBox<L> {
     /// Create a new `TextBox` with content `text`.
-    pub fn new(text: String<L>) -> Self {
-        Self { text }
+    pub fn new(text: SecretString<L>) -> Self {
+        Self { text, max_len: L }
     }
 
     /// Create an empty `TextBox`.
     pub fn empty() -> Self {
-        Self::new(String::new())
+        Self::new(SecretString::new())
+    }
+
+    /// Create an empty `TextBox` for input of up to `N` bytes, the build fails
+    /// if `L` is smaller.
+    pub fn for_input<const N: usize>() -> Self {
+        let () = Holds::<L, N>::OK;
+        Self::empty()
+    }
+
+    /// Accept at most `max_len` bytes, e.g. the limit of the value being
+    /// entered when shorter than the buffer. Longer content already in the
+    /// box is kept, but nothing can be added to it.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len.min(L);
+        self
     }
 
     pub fn content(&self) -> &str {
@@ -41,62 +80,99 @@ impl<const L: usize> TextBox<L> {
     }
 
     pub fn is_full(&self) -> bool {
-        self.text.len() == self.text.capacity()
+        self.text.len() >= self.max_len
+    }
+
+    /// Whether `len` more bytes can be added after the first `start` bytes.
+    fn fits(&self, start: usize, len: usize) -> bool {
+        start + len <= self.max_len
     }
 
-    /// Delete the last character of content, if any.
+    /// Byte offset where the last grapheme cluster of the content starts.
+    fn last_grapheme_start(&self) -> usize {
+        let count = count_graphemes(&self.text);
+        truncate_graphemes(&self.text, count.saturating_sub(1)).len()
+    }
+
+    /// Delete the last character of content, if any, together with the
+    /// accents or modifiers attached to it.
     pub fn delete_last(&mut self, ctx: &mut EventCtx) {
-        let changed = self.text.pop().is_some();
+        let changed = !self.text.is_empty();
         if changed {
+            self.text.truncate(self.last_grapheme_start());
             ctx.request_paint();
         }
     }
 
-    /// Replaces the last character of the content with `ch`. If the content is
-    /// empty, `ch` is appended.
-    pub fn replace_last(&mut self, ctx: &mut EventCtx, ch: char) {
-        let previous = self.text.pop();
-        self.text
-            .push(ch)
-            .assert_if_debugging_ui("TextBox has zero capacity");
-        let changed = previous != Some(ch);
+    /// Replaces the last character of the content, including anything attached
+    /// to it, with `ch`. If the content is empty, `ch` is appended.
+    pub fn replace_last(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        let start = self.last_grapheme_start();
+        if !self.fits(start, ch.len_utf8()) {
+            // Zero capacity or a wider character than the previous one.
+            return Err(TextBoxFull);
+        }
+        let mut buf = [0; 4];
+        let changed = self.text[start..] != *ch.encode_utf8(&mut buf);
+        self.text.truncate(start);
+        unwrap!(self.text.push(ch));
         if changed {
             ctx.request_paint();
         }
+        self.record_usage();
+        Ok(())
     }
 
     /// Append `ch` at the end of the content.
-    pub fn append(&mut self, ctx: &mut EventCtx, ch: char) {
-        self.text.push(ch).assert_if_debugging_ui("TextBox is full");
+    pub fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        if !self.fits(self.text.len(), ch.len_utf8()) {
+            return Err(TextBoxFull);
+        }
+        self.text.push(ch).map_err(|_| TextBoxFull)?;
         ctx.request_paint();
+        self.record_usage();
+        Ok(())
     }
 
     /// Append `slice` at the end of the content.
-    pub fn append_slice(&mut self, ctx: &mut EventCtx, slice: &str) {
-        self.text
-            .push_str(slice)
-            .assert_if_debugging_ui("TextBox is full");
+    pub fn append_slice(&mut self, ctx: &mut EventCtx, slice: &str) -> Result<(), TextBoxFull> {
+        if !self.fits(self.text.len(), slice.len()) {
+            return Err(TextBoxFull);
+        }
+        self.text.push_str(slice).map_err(|_| TextBoxFull)?;
         ctx.request_paint();
+        self.record_usage();
+        Ok(())
     }
 
     /// Replace the textbox content with `text`.
-    pub fn replace(&mut self, ctx: &mut EventCtx, text: &str) {
-        if self.text != text {
+    pub fn replace(&mut self, ctx: &mut EventCtx, text: &str) -> Result<(), TextBoxFull> {
+        if self.text.as_str() != text {
+            if !self.fits(0, text.len()) {
+                return Err(TextBoxFull);
+            }
             self.text.clear();
-            self.text
-                .push_str(text)
-                .assert_if_debugging_ui("TextBox is full");
+            unwrap!(self.text.push_str(text));
             ctx.request_paint();
+            self.record_usage();
         }
+        Ok(())
     }
 
     /// Clear the textbox content.
     pub fn clear(&mut self, ctx: &mut EventCtx) {
-        self.replace(ctx, "");
+        if !self.text.is_empty() {
+            self.text.clear();
+            ctx.request_paint();
+        }
+    }
+
+    fn record_usage(&self) {
+        stats::record_buffer("TextBox", self.text.len(), L);
     }
 
     /// Apply a editing operation to the text buffer.
-    pub fn apply(&mut self, ctx: &mut EventCtx, edit: TextEdit) {
+    pub fn apply(&mut self, ctx: &mut EventCtx, edit: TextEdit) -> Result<(), TextBoxFull> {
         match edit {
             TextEdit::ReplaceLast(char) => self.replace_last(ctx, char),
             TextEdit::Append(char) => self.append(ctx, char),
@@ -104,6 +180,38 @@ impl<const L: usize> TextBox<L> {
     }
 }
 
+/// Text entry implemented by the keyboards of all the models, be it touch or
+/// choice based, so that flows and tests can be written once against it.
+pub trait InputMethod {
+    /// Text entered so far.
+    fn content(&self) -> &str;
+
+    /// Append `ch` as if it was picked on the keyboard. Fails if `ch` does not
+    /// fit, or if the keyboard cannot enter it at all.
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull>;
+
+    /// Delete the last character, if any.
+    fn delete_last(&mut self, ctx: &mut EventCtx);
+
+    /// Pass the words the content can be completed to to `sink`. Inputs of
+    /// free-form text have none.
+    fn completions(&self, _sink: &mut dyn FnMut(&'static str)) {}
+}
+
+impl<const L: usize> InputMethod for TextBox<L> {
+    fn content(&self) -> &str {
+        TextBox::content(self)
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        TextBox::append(self, ctx, ch)
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        TextBox::delete_last(self, ctx)
+    }
+}
+
 // DEBUG-ONLY SECTION BELOW
 
 #[cfg(feature = "ui_debug")]
@@ -113,3 +221,46 @@ impl<const L: usize> crate::trace::Trace for TextBox<L> {
         t.string("text", &self.text);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type `text` into `input`, then delete the last character.
+    fn type_and_fix(input: &mut dyn InputMethod, text: &str) -> Result<(), TextBoxFull> {
+        let mut ctx = EventCtx::new();
+        for ch in text.chars() {
+            input.append(&mut ctx, ch)?;
+        }
+        input.delete_last(&mut ctx);
+        Ok(())
+    }
+
+    #[test]
+    fn input_method() {
+        let mut textbox = TextBox::<4>::empty();
+        assert_eq!(type_and_fix(&mut textbox, "abcd"), Ok(()));
+        assert_eq!(InputMethod::content(&textbox), "abc");
+        assert_eq!(type_and_fix(&mut textbox, "de"), Err(TextBoxFull));
+
+        let mut completions = 0;
+        textbox.completions(&mut |_| completions += 1);
+        assert_eq!(completions, 0);
+    }
+
+    #[test]
+    fn max_len() {
+        let mut ctx = EventCtx::new();
+        let mut textbox = TextBox::<8>::empty().with_max_len(3);
+        assert_eq!(textbox.append_slice(&mut ctx, "ab"), Ok(()));
+        assert!(!textbox.is_full());
+        assert_eq!(textbox.append(&mut ctx, 'č'), Err(TextBoxFull));
+        assert_eq!(textbox.append(&mut ctx, 'c'), Ok(()));
+        assert!(textbox.is_full());
+        assert_eq!(textbox.append(&mut ctx, 'd'), Err(TextBoxFull));
+        // Cycling through the characters of a key still works when full.
+        assert_eq!(textbox.replace_last(&mut ctx, 'd'), Ok(()));
+        assert_eq!(textbox.content(), "abd");
+        assert_eq!(textbox.replace(&mut ctx, "abcd"), Err(TextBoxFull));
+    }
+}
@@ -1,12 +1,14 @@
 use crate::ui::{
+    context::{self, with_state},
     display,
     display::{toif::Icon, Color, Font, GlyphMetrics},
     geometry::{Alignment, Alignment2D, Dimensions, Offset, Point, Rect},
+    stats,
 };
 
 const ELLIPSIS: &str = "...";
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum LineBreaking {
     /// Break line only at whitespace, if possible. If we don't find any
     /// whitespace, break words.
@@ -53,7 +55,7 @@ pub struct TextLayout {
 }
 
 /// Configuration for chunkifying the text into smaller parts.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Chunks {
     /// How many characters will be grouped in one chunk.
     pub chunk_size: usize,
@@ -97,6 +99,14 @@ pub struct TextStyle {
     /// Optional icon to signal content continues from previous page,
     /// including text margin.
     pub prev_page_ellipsis_icon: Option<(Icon, i16)>,
+    /// Text shown at the end of the page when there is no ellipsis icon.
+    pub ellipsis_text: &'static str,
+    /// Text shown at the beginning of the next page when there is no icon for
+    /// it.
+    pub prev_page_text: &'static str,
+    /// Whether the text or icon at the beginning of the next page is on a line
+    /// of its own, as a header above the continuing text.
+    pub prev_page_header: bool,
 
     /// Specifies which line-breaking strategy to use.
     pub line_breaking: LineBreaking,
@@ -110,6 +120,10 @@ pub struct TextStyle {
     /// Optionally increase the vertical space between text lines
     /// (can be even negative, in which case it will decrease it).
     pub line_spacing: i16,
+
+    /// Fill the background of the text in the full line height, not only
+    /// behind the glyphs, see `inverse()`.
+    pub fill_background: bool,
 }
 
 impl TextStyle {
@@ -128,10 +142,14 @@ impl TextStyle {
             ellipsis_color,
             ellipsis_icon: None,
             prev_page_ellipsis_icon: None,
+            ellipsis_text: ELLIPSIS,
+            prev_page_text: ELLIPSIS,
+            prev_page_header: false,
             line_breaking: LineBreaking::BreakAtWhitespace,
             page_breaking: PageBreaking::CutAndInsertEllipsis,
             chunks: None,
             line_spacing: 0,
+            fill_background: false,
         }
     }
 
@@ -157,6 +175,27 @@ impl TextStyle {
         self
     }
 
+    /// Text shown instead of "..." at the end of the page.
+    pub const fn with_ellipsis_text(mut self, text: &'static str) -> Self {
+        self.ellipsis_text = text;
+        self
+    }
+
+    /// Text shown instead of "..." at the beginning of the next page.
+    pub const fn with_prev_page_text(mut self, text: &'static str) -> Self {
+        self.prev_page_text = text;
+        self
+    }
+
+    /// Line of its own at the beginning of the next page, e.g. "continued",
+    /// instead of "..." before the text. Needs
+    /// `PageBreaking::CutAndInsertEllipsisBoth`.
+    pub const fn with_prev_page_header(mut self, text: &'static str) -> Self {
+        self.prev_page_text = text;
+        self.prev_page_header = true;
+        self
+    }
+
     /// Adding optional chunkification to the text.
     pub const fn with_chunks(mut self, chunks: Chunks) -> Self {
         self.chunks = Some(chunks);
@@ -169,11 +208,24 @@ impl TextStyle {
         self
     }
 
+    /// Inverse video, e.g. black on white on a monochrome display. The text is
+    /// on a solid band of the text color of `self`, to emphasize a selected
+    /// item or a warning.
+    pub const fn inverse(mut self) -> Self {
+        let text_color = self.background_color;
+        self.background_color = self.text_color;
+        self.text_color = text_color;
+        self.hyphen_color = text_color;
+        self.ellipsis_color = text_color;
+        self.fill_background = true;
+        self
+    }
+
     fn ellipsis_width(&self) -> i16 {
         if let Some((icon, margin)) = self.ellipsis_icon {
             icon.toif.width() + margin
         } else {
-            self.text_font.text_width(ELLIPSIS)
+            self.text_font.text_width(self.ellipsis_text)
         }
     }
 
@@ -181,7 +233,7 @@ impl TextStyle {
         if let Some((icon, margin)) = self.prev_page_ellipsis_icon {
             icon.toif.width() + margin
         } else {
-            self.text_font.text_width(ELLIPSIS)
+            self.text_font.text_width(self.prev_page_text)
         }
     }
 
@@ -244,6 +296,7 @@ impl TextLayout {
         cursor: &mut Point,
         sink: &mut dyn LayoutSink,
     ) -> LayoutFit {
+        stats::sample_stack();
         let init_cursor = *cursor;
         let mut remaining_text = text;
         let mut num_lines = 1;
@@ -258,11 +311,21 @@ impl TextLayout {
         }
 
         // Draw the arrow icon if we are in the middle of a string
-        if matches!(
+        let continues = matches!(
             self.style.page_breaking,
             PageBreaking::CutAndInsertEllipsisBoth
-        ) && self.continues_from_prev_page
+        ) && self.continues_from_prev_page;
+        // The header only if there is space for a line of text below it.
+        let line_advance = self.style.text_font.line_height() + self.style.line_spacing;
+        if continues
+            && self.style.prev_page_header
+            && cursor.y + line_advance + self.style.text_font.line_height() <= self.bottom_y()
         {
+            sink.prev_page_ellipsis(*cursor, self);
+            cursor.y += line_advance;
+            num_lines += 1;
+            sink.line_break(*cursor);
+        } else if continues {
             // Move the cursor to the right, always the same distance
             // Special case in chunkifying text - move the cursor so that we
             // start with the second chunk.
@@ -298,7 +361,7 @@ impl TextLayout {
             };
 
             let remaining_width = self.bounds.x1 - cursor.x;
-            let mut span = Span::fit_horizontally(
+            let mut span = Span::fit_horizontally_cached(
                 remaining_text,
                 remaining_width,
                 self.style.text_font,
@@ -469,13 +532,32 @@ impl LayoutSink for TextNoOp {}
 /// `LayoutSink` for rendering the content.
 pub struct TextRenderer;
 
+/// `color` of a part of the text, in full contrast if the user asked for it,
+/// see `Accessibility::high_contrast`.
+fn ink(color: Color, layout: &TextLayout) -> Color {
+    if context::settings().accessibility.high_contrast {
+        color.with_full_contrast(layout.style.background_color)
+    } else {
+        color
+    }
+}
+
 impl LayoutSink for TextRenderer {
     fn text(&mut self, cursor: Point, layout: &TextLayout, text: &str) {
+        if layout.style.fill_background {
+            let font = layout.style.text_font;
+            let top_left = cursor - Offset::y(font.text_max_height() - font.text_baseline());
+            let size = Offset::new(font.text_width(text), font.text_max_height());
+            display::rect_fill(
+                Rect::from_top_left_and_size(top_left, size),
+                layout.style.background_color,
+            );
+        }
         display::text_left(
             cursor,
             text,
             layout.style.text_font,
-            layout.style.text_color,
+            ink(layout.style.text_color, layout),
             layout.style.background_color,
         );
     }
@@ -485,7 +567,7 @@ impl LayoutSink for TextRenderer {
             cursor,
             "-",
             layout.style.text_font,
-            layout.style.hyphen_color,
+            ink(layout.style.hyphen_color, layout),
             layout.style.background_color,
         );
     }
@@ -496,15 +578,15 @@ impl LayoutSink for TextRenderer {
             icon.draw(
                 bottom_left,
                 Alignment2D::BOTTOM_LEFT,
-                layout.style.ellipsis_color,
+                ink(layout.style.ellipsis_color, layout),
                 layout.style.background_color,
             );
         } else {
             display::text_left(
                 cursor,
-                ELLIPSIS,
+                layout.style.ellipsis_text,
                 layout.style.text_font,
-                layout.style.ellipsis_color,
+                ink(layout.style.ellipsis_color, layout),
                 layout.style.background_color,
             );
         }
@@ -515,15 +597,15 @@ impl LayoutSink for TextRenderer {
             icon.draw(
                 cursor,
                 Alignment2D::BOTTOM_LEFT,
-                layout.style.ellipsis_color,
+                ink(layout.style.ellipsis_color, layout),
                 layout.style.background_color,
             );
         } else {
             display::text_left(
                 cursor,
-                ELLIPSIS,
+                layout.style.prev_page_text,
                 layout.style.text_font,
-                layout.style.ellipsis_color,
+                ink(layout.style.ellipsis_color, layout),
                 layout.style.background_color,
             );
         }
@@ -548,12 +630,12 @@ pub mod trace {
             self.0.string("-");
         }
 
-        fn ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {
-            self.0.string(ELLIPSIS);
+        fn ellipsis(&mut self, _cursor: Point, layout: &TextLayout) {
+            self.0.string(layout.style.ellipsis_text);
         }
 
-        fn prev_page_ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {
-            self.0.string(ELLIPSIS);
+        fn prev_page_ellipsis(&mut self, _cursor: Point, layout: &TextLayout) {
+            self.0.string(layout.style.prev_page_text);
         }
 
         fn line_break(&mut self, _cursor: Point) {
@@ -564,8 +646,8 @@ pub mod trace {
 
 /// Carries info about the content that was processed
 /// on the current line.
-#[derive(Debug, PartialEq, Eq)]
-struct Span {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Span {
     /// How many characters from the input text this span is laying out.
     length: usize,
     /// How many chars from the input text should we skip before fitting the
@@ -580,6 +662,42 @@ struct Span {
 }
 
 impl Span {
+    /// Same as `fit_horizontally`, but remembers the results for the last few
+    /// lines laid out. Paragraphs are laid out again on every repaint, even if
+    /// only something else on the screen has changed, and measuring the text
+    /// is the expensive part of it.
+    pub fn fit_horizontally_cached(
+        text: &str,
+        max_width: i16,
+        text_font: Font,
+        breaking: LineBreaking,
+        line_ending_space: i16,
+        chunks: Option<Chunks>,
+    ) -> Self {
+        let key = SpanKey {
+            text_hash: SpanKey::hash(text),
+            text_len: text.len(),
+            max_width,
+            text_font,
+            breaking,
+            line_ending_space,
+            chunks,
+        };
+        if let Some(span) = with_state(|s| s.span_cache.get(&key)) {
+            return span;
+        }
+        let span = Self::fit_horizontally(
+            text,
+            max_width,
+            text_font,
+            breaking,
+            line_ending_space,
+            chunks,
+        );
+        with_state(|s| s.span_cache.insert(key, span));
+        span
+    }
+
     pub fn fit_horizontally(
         text: &str,
         max_width: i16,
@@ -635,7 +753,7 @@ impl Span {
         };
 
         let mut span_width = 0;
-        let mut found_any_whitespace = false;
+        let mut found_any_break = false;
 
         let mut char_indices_iter = text.char_indices().peekable();
         // Iterating manually because we need a reference to the iterator inside the
@@ -669,21 +787,33 @@ impl Span {
                     // End of line, break immediately.
                     return line;
                 }
-                found_any_whitespace = true;
+                found_any_break = true;
             } else if span_width + char_width > max_width {
                 // Cannot fit on this line. Return the last breakpoint.
                 return line;
             } else {
+                let (next_idx, next_ch) = match char_indices_iter.peek() {
+                    Some((idx, ch)) => (*idx, Some(*ch)),
+                    None => (text.len(), None),
+                };
+                // CJK text has no spaces, lines can break around any of its characters,
+                // but not before closing punctuation.
+                let cjk_break = (is_cjk(ch) || next_ch.map_or(false, is_cjk))
+                    && !next_ch.map_or(false, is_closing_punctuation);
                 let have_space_for_break =
                     span_width + char_width + incomplete_word_end_width <= max_width;
                 let can_break_word =
-                    !matches!(breaking, LineBreaking::BreakAtWhitespace) || !found_any_whitespace;
-                if have_space_for_break && can_break_word {
+                    !matches!(breaking, LineBreaking::BreakAtWhitespace) || !found_any_break;
+                if cjk_break && span_width + char_width + complete_word_end_width <= max_width {
+                    // Break after this character, without hyphen.
+                    line.length = next_idx;
+                    line.advance.x = span_width + char_width;
+                    line.insert_hyphen_before_line_break = false;
+                    line.skip_next_chars = 0;
+                    found_any_break = true;
+                } else if have_space_for_break && can_break_word {
                     // Break after this character, append hyphen.
-                    line.length = match char_indices_iter.peek() {
-                        Some((idx, _)) => *idx,
-                        None => text.len(),
-                    };
+                    line.length = next_idx;
                     line.advance.x = span_width + char_width;
                     line.insert_hyphen_before_line_break = use_hyphens;
                     line.skip_next_chars = 0;
@@ -703,6 +833,79 @@ impl Span {
     }
 }
 
+/// Everything the result of `Span::fit_horizontally` depends on. The text is
+/// identified by its hash and length rather than by its address, as the same
+/// memory can hold a different text later.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SpanKey {
+    text_hash: u32,
+    text_len: usize,
+    max_width: i16,
+    text_font: Font,
+    breaking: LineBreaking,
+    line_ending_space: i16,
+    chunks: Option<Chunks>,
+}
+
+impl SpanKey {
+    /// FNV-1a, cheap compared to measuring the glyphs.
+    fn hash(text: &str) -> u32 {
+        text.bytes().fold(0x811c_9dc5, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+        })
+    }
+}
+
+/// Enough for the lines of a text-heavy screen.
+const SPAN_CACHE_SIZE: usize = 16;
+
+/// Results of the last few `Span::fit_horizontally` calls, the oldest one is
+/// replaced when full.
+pub(crate) struct SpanCache {
+    entries: [Option<(SpanKey, Span)>; SPAN_CACHE_SIZE],
+    next: usize,
+}
+
+impl SpanCache {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [None; SPAN_CACHE_SIZE],
+            next: 0,
+        }
+    }
+
+    fn get(&self, key: &SpanKey) -> Option<Span> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|(k, _)| k == key)
+            .map(|(_, span)| *span)
+    }
+
+    fn insert(&mut self, key: SpanKey, span: Span) {
+        self.entries[self.next] = Some((key, span));
+        self.next = (self.next + 1) % SPAN_CACHE_SIZE;
+    }
+}
+
+/// Characters of the CJK scripts, including their punctuation and full-width
+/// forms.
+fn is_cjk(ch: char) -> bool {
+    matches!(ch,
+        '\u{1100}'..='\u{11FF}' // Hangul Jamo
+        | '\u{3000}'..='\u{30FF}' // CJK punctuation, Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+        | '\u{FF00}'..='\u{FFEF}' // Half-width and full-width forms
+    )
+}
+
+/// Punctuation that must not start a line.
+fn is_closing_punctuation(ch: char) -> bool {
+    ".,!?:;)]}、。，．：；？！）」』】〕〉》〗〙〟ー".contains(ch)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -731,6 +934,37 @@ mod tests {
         height: 1,
     };
 
+    #[test]
+    fn test_span_cache() {
+        let key = |text_hash| SpanKey {
+            text_hash,
+            text_len: 5,
+            max_width: 100,
+            text_font: Font::NORMAL,
+            breaking: LineBreaking::BreakAtWhitespace,
+            line_ending_space: 0,
+            chunks: None,
+        };
+        let span = Span::fit_horizontally(
+            "hello",
+            5,
+            FIXED_FONT,
+            LineBreaking::BreakAtWhitespace,
+            0,
+            None,
+        );
+        let mut cache = SpanCache::new();
+        assert_eq!(cache.get(&key(0)), None);
+        for i in 0..=SPAN_CACHE_SIZE as u32 {
+            cache.insert(key(i), span);
+        }
+        // The oldest entry has been replaced.
+        assert_eq!(cache.get(&key(0)), None);
+        assert_eq!(cache.get(&key(1)), Some(span));
+        assert_eq!(cache.get(&key(SPAN_CACHE_SIZE as u32)), Some(span));
+        assert!(SpanKey::hash("hello") != SpanKey::hash("hellO"));
+    }
+
     #[test]
     fn test_span() {
         assert_eq!(spans_from("hello", 5), vec![("hello", false)]);
@@ -778,6 +1012,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cjk() {
+        assert_eq!(
+            spans_from("日本語のテキスト。", 4),
+            vec![("日本語の", false), ("テキス", false), ("ト。", false)]
+        );
+        assert_eq!(
+            spans_from("ok 按钮", 4),
+            vec![("ok 按", false), ("钮", false)]
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let style = TextStyle::new(
+            Font::NORMAL,
+            Color::white(),
+            Color::black(),
+            Color::white(),
+            Color::white(),
+        );
+        let inverse = style.inverse();
+        assert!(inverse.text_color == Color::black());
+        assert!(inverse.background_color == Color::white());
+        assert!(inverse.ellipsis_color == Color::black());
+        assert!(inverse.fill_background);
+        assert!(!style.fill_background);
+    }
+
+    /// Which markers a layout reports, and on which line.
+    #[derive(PartialEq, Debug)]
+    enum Mark {
+        PrevPage(i16),
+        Text(i16),
+    }
+
+    struct Marks(Vec<Mark>);
+
+    impl LayoutSink for Marks {
+        fn text(&mut self, cursor: Point, _layout: &TextLayout, _text: &str) {
+            self.0.push(Mark::Text(cursor.y));
+        }
+
+        fn prev_page_ellipsis(&mut self, cursor: Point, _layout: &TextLayout) {
+            self.0.push(Mark::PrevPage(cursor.y));
+        }
+    }
+
+    fn continued_marks(height: i16) -> Vec<Mark> {
+        let style = TextStyle::new(
+            Font::NORMAL,
+            Color::white(),
+            Color::black(),
+            Color::white(),
+            Color::white(),
+        )
+        .with_page_breaking(PageBreaking::CutAndInsertEllipsisBoth)
+        .with_prev_page_header("continued");
+        let mut layout = TextLayout::new(style).with_bounds(Rect::from_top_left_and_size(
+            Point::zero(),
+            Offset::new(200, height),
+        ));
+        layout.continues_from_prev_page = true;
+        let mut marks = Marks(vec![]);
+        layout.layout_text("rest", &mut layout.initial_cursor(), &mut marks);
+        marks.0
+    }
+
+    #[test]
+    fn test_prev_page_header() {
+        let line = Font::NORMAL.line_height();
+        let marks = continued_marks(4 * line);
+        let Mark::PrevPage(y) = marks[0] else {
+            panic!("no header");
+        };
+        assert_eq!(marks[1..], [Mark::Text(y + line)]);
+
+        // Without the room for both lines, it goes in front of the text.
+        let marks = continued_marks(line);
+        let Mark::PrevPage(y) = marks[0] else {
+            panic!("no marker");
+        };
+        assert_eq!(marks[1..], [Mark::Text(y)]);
+    }
+
     fn spans_from(text: &str, max_width: i16) -> Vec<(&str, bool)> {
         let mut spans = vec![];
         let mut remaining_text = text;
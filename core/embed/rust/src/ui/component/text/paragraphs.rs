@@ -3,11 +3,14 @@ use heapless::Vec;
 use crate::{
     strutil::StringType,
     ui::{
+        budget,
         component::{Component, Event, EventCtx, Never, Paginate},
-        display::toif::Icon,
+        context,
+        display::{toif::Icon, Color},
         geometry::{
             Alignment, Alignment2D, Dimensions, Insets, LinearPlacement, Offset, Point, Rect,
         },
+        stats,
     },
 };
 
@@ -25,6 +28,9 @@ pub const DEFAULT_SPACING: i16 = 0;
 pub const PARAGRAPH_TOP_SPACE: i16 = -1;
 /// Offset of paragraph bounding box bottom relative to bottom of its text.
 pub const PARAGRAPH_BOTTOM_SPACE: i16 = 5;
+/// Number of page boundaries remembered after they are computed. Content with
+/// more pages is re-flowed on every page change.
+const MAX_CACHED_PAGES: usize = 16;
 
 pub type ParagraphVecLong<T> = Vec<Paragraph<T>, 32>;
 pub type ParagraphVecShort<T> = Vec<Paragraph<T>, 8>;
@@ -53,6 +59,12 @@ pub struct Paragraphs<T> {
     placement: LinearPlacement,
     offset: PageOffset,
     visible: Vec<TextLayoutProxy, MAX_LINES>,
+    /// Beginnings of the pages, `None` until computed for the current area
+    /// and content.
+    page_breaks: Option<Vec<PageOffset, MAX_CACHED_PAGES>>,
+    /// First of the visible paragraphs left unpainted when the paint ran out
+    /// of time, see `budget::defer`.
+    resume: usize,
     source: T,
 }
 
@@ -68,6 +80,8 @@ where
                 .with_spacing(DEFAULT_SPACING),
             offset: PageOffset::default(),
             visible: Vec::new(),
+            page_breaks: None,
+            resume: 0,
             source,
         }
     }
@@ -87,6 +101,8 @@ where
     }
 
     pub fn inner_mut(&mut self) -> &mut T {
+        // The content may change and with it the page boundaries.
+        self.page_breaks = None;
         &mut self.source
     }
 
@@ -95,6 +111,7 @@ where
     /// arranged according to the layout.
     fn change_offset(&mut self, offset: PageOffset) {
         self.offset = offset;
+        self.resume = 0;
         Self::dyn_change_offset(self.area, offset, &self.source, self.visible.as_mut());
         self.placement.arrange(self.area, &mut self.visible);
     }
@@ -113,7 +130,10 @@ where
         while offset.par < source.size() {
             let (next_offset, remaining_area, layout) = offset.advance(area, source, full_height);
             if let Some(layout) = layout {
-                unwrap!(visible.push(layout));
+                // Lots of tiny paragraphs could overflow the list, do not show the rest.
+                if visible.push(layout).is_err() {
+                    break;
+                }
             }
             if let Some(remaining_area) = remaining_area {
                 #[cfg(feature = "ui_debug")]
@@ -124,6 +144,7 @@ where
                 break;
             }
         }
+        stats::record_buffer("Paragraphs::visible", visible.len(), MAX_LINES);
     }
 
     fn break_pages(&self) -> PageBreakIterator<T> {
@@ -133,6 +154,21 @@ where
         }
     }
 
+    /// Compute the page boundaries unless they are known already. Leaves the
+    /// cache empty if there are too many pages.
+    fn cache_page_breaks(&mut self) {
+        if self.page_breaks.is_some() {
+            return;
+        }
+        let mut page_breaks = Vec::new();
+        for offset in self.break_pages() {
+            if page_breaks.push(offset).is_err() {
+                return;
+            }
+        }
+        self.page_breaks = Some(page_breaks);
+    }
+
     /// Iterate over visible layouts (bounding box, style) together
     /// with corresponding string content. Should not get monomorphized.
     fn foreach_visible<'a, S: StringType>(
@@ -169,6 +205,7 @@ where
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
+        self.page_breaks = None;
         self.change_offset(self.offset);
         self.area
     }
@@ -178,14 +215,30 @@ where
     }
 
     fn paint(&mut self) {
+        let resume = self.resume;
+        let mut index = 0;
+        let mut stopped_at = None;
         Self::foreach_visible(
             &self.source,
             &self.visible,
             self.offset,
             &mut |layout, content| {
-                layout.render_text(content);
+                if index >= resume && stopped_at.is_none() {
+                    layout.render_text(content);
+                    if budget::exhausted() {
+                        stopped_at = Some(index + 1);
+                    }
+                }
+                index += 1;
             },
-        )
+        );
+        match stopped_at {
+            Some(next) if next < index => {
+                self.resume = next;
+                budget::defer();
+            }
+            _ => self.resume = 0,
+        }
     }
 
     #[cfg(feature = "ui_bounds")]
@@ -202,16 +255,27 @@ where
     T: ParagraphSource,
 {
     fn page_count(&mut self) -> usize {
+        self.cache_page_breaks();
+        let count = match &self.page_breaks {
+            Some(page_breaks) => page_breaks.len(),
+            None => self.break_pages().count(),
+        };
         // There's always at least one page.
-        self.break_pages().count().max(1)
+        count.max(1)
     }
 
     fn change_page(&mut self, to_page: usize) {
-        if let Some(offset) = self.break_pages().nth(to_page) {
+        self.cache_page_breaks();
+        let offset = match &self.page_breaks {
+            Some(page_breaks) => page_breaks.get(to_page).copied(),
+            None => self.break_pages().nth(to_page),
+        };
+        if let Some(offset) = offset {
             self.change_offset(offset)
         } else {
             // Should not happen, set index to first paragraph and render empty page.
             self.offset = PageOffset::default();
+            self.resume = 0;
             self.visible.clear()
         }
     }
@@ -259,6 +323,9 @@ pub struct Paragraph<T> {
     /// Try to keep this and the next paragraph on the same page. NOTE: doesn't
     /// work if two or more subsequent paragraphs have this flag.
     no_break: bool,
+    /// Move this paragraph to the next page instead of splitting it, unless it
+    /// does not fit on a page by itself. Used for addresses and amounts.
+    keep_whole: bool,
     padding_top: i16,
     padding_bottom: i16,
 }
@@ -271,6 +338,7 @@ impl<T> Paragraph<T> {
             align: Alignment::Start,
             break_after: false,
             no_break: false,
+            keep_whole: false,
             padding_top: PARAGRAPH_TOP_SPACE,
             padding_bottom: PARAGRAPH_BOTTOM_SPACE,
         }
@@ -291,6 +359,11 @@ impl<T> Paragraph<T> {
         self
     }
 
+    pub const fn keep_whole(mut self) -> Self {
+        self.keep_whole = true;
+        self
+    }
+
     pub const fn with_top_padding(mut self, padding: i16) -> Self {
         self.padding_top = padding;
         self
@@ -317,16 +390,22 @@ impl<T> Paragraph<T> {
             align: self.align,
             break_after: self.break_after,
             no_break: self.no_break,
+            keep_whole: self.keep_whole,
             padding_top: self.padding_top,
             padding_bottom: self.padding_bottom,
         }
     }
 
     fn layout(&self, area: Rect) -> TextLayout {
+        let density = context::settings().density;
+        let style = TextStyle {
+            line_spacing: density.line_spacing(self.style.line_spacing),
+            ..*self.style
+        };
         TextLayout {
             padding_top: self.padding_top,
-            padding_bottom: self.padding_bottom,
-            ..TextLayout::new(*self.style)
+            padding_bottom: density.paragraph_space(self.padding_bottom),
+            ..TextLayout::new(style)
                 .with_align(self.align)
                 .with_bounds(area)
         }
@@ -417,6 +496,32 @@ impl PageOffset {
         let mut layout = paragraph.layout(area);
         layout.continues_from_prev_page = self.chr > 0;
         let fit = layout.fit_text(paragraph.content.as_ref());
+
+        // Do not leave the first line of a paragraph alone at the bottom of a
+        // page, move the whole paragraph to the next one.
+        if let LayoutFit::OutOfBounds {
+            processed_chars, ..
+        } = fit
+        {
+            let one_line = layout.layout_height(Point::zero(), Point::zero());
+            let orphan = processed_chars > 0 && fit.height() <= one_line;
+            if orphan && self.chr == 0 && area.height() < full_height {
+                return (self, None, None);
+            }
+            // Likewise an address or amount, if it fits on the next page.
+            if paragraph.keep_whole && self.chr == 0 && area.height() < full_height {
+                let fits_on_page = matches!(
+                    paragraph
+                        .layout(area.with_height(full_height))
+                        .fit_text(paragraph.content.as_ref()),
+                    LayoutFit::Fitting { .. }
+                );
+                if fits_on_page {
+                    return (self, None, None);
+                }
+            }
+        }
+
         let (used, remaining_area) = area.split_top(fit.height());
 
         let layout = TextLayoutProxy::new(self, used);
@@ -468,8 +573,8 @@ impl PageOffset {
             .layout(full_area)
             .fit_text(next_paragraph.content.as_ref())
             .height();
-        let screen_full_threshold = this_paragraph.style.text_font.line_height()
-            + next_paragraph.style.text_font.line_height();
+        let val_line_height = next_paragraph.style.text_font.line_height();
+        let screen_full_threshold = this_paragraph.style.text_font.line_height() + val_line_height;
 
         if key_height + val_height > remaining_height {
             return
@@ -477,8 +582,8 @@ impl PageOffset {
                 // next page.
                 (remaining_height <= screen_full_threshold)
                 // More than 2 remaining lines so try to fit something -- but won't
-                // fit at least one line of value.
-                || (val_height > 0 && key_height > remaining_height)
+                // fit at least one line of value, which would separate the key from it.
+                || (val_height > 0 && key_height + val_line_height > remaining_height)
                 // Whole property won't fit to the page, but it will fit on a page
                 // by itself.
                 || (key_height + val_height <= full_height);
@@ -540,9 +645,12 @@ impl<T: ParagraphSource> Iterator for PageBreakIterator<'_, T> {
         }
 
         let next = Self::dyn_next(self.paragraphs.area, &self.paragraphs.source, *current);
+        if next == self.current {
+            // Content that does not fit even on an empty page, end the iteration
+            // instead of looping forever.
+            return None;
+        }
         if next.is_some() {
-            // Better panic than infinite loop.
-            assert_ne!(next, self.current);
             self.current = next;
         }
         next
@@ -672,6 +780,110 @@ impl<T: ParagraphSource> crate::trace::Trace for Checklist<T> {
     }
 }
 
+/// Most paragraphs of an `IconList` that get an icon.
+const MAX_ICONS: usize = 8;
+
+/// Paragraphs with an icon of their own in the left column, e.g. the outcome
+/// of each of the checks of a backup. Paragraphs without an icon leave the
+/// column empty.
+pub struct IconList<T> {
+    area: Rect,
+    paragraphs: Paragraphs<T>,
+    icons: Vec<Option<(Icon, Color)>, MAX_ICONS>,
+    /// How wide will the left icon column be
+    icon_width: i16,
+    /// Offset of the icons from the top left corner of their paragraph
+    icon_offset: Offset,
+}
+
+impl<T> IconList<T> {
+    /// `icons` go to the paragraphs in order, the ones over `MAX_ICONS` are
+    /// left out.
+    pub fn from_paragraphs(
+        icons: impl IntoIterator<Item = Option<(Icon, Color)>>,
+        paragraphs: Paragraphs<T>,
+    ) -> Self {
+        Self {
+            area: Rect::zero(),
+            paragraphs,
+            icons: icons.into_iter().take(MAX_ICONS).collect(),
+            icon_width: 0,
+            icon_offset: Offset::zero(),
+        }
+    }
+
+    pub fn with_icon_width(mut self, icon_width: i16) -> Self {
+        self.icon_width = icon_width;
+        self
+    }
+
+    pub fn with_icon_offset(mut self, icon_offset: Offset) -> Self {
+        self.icon_offset = icon_offset;
+        self
+    }
+}
+
+impl<T> Component for IconList<T>
+where
+    T: ParagraphSource,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        let para_area = bounds.inset(Insets::left(self.icon_width));
+        self.paragraphs.place(para_area);
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.paragraphs.event(ctx, event)
+    }
+
+    fn paint(&mut self) {
+        self.paragraphs.paint();
+
+        let first = self.paragraphs.offset.par;
+        for (i, layout) in self.paragraphs.visible.iter().enumerate() {
+            let Some(Some((icon, color))) = self.icons.get(first + i) else {
+                continue;
+            };
+            let layout = layout.layout(&self.paragraphs.source);
+            icon.draw(
+                Point::new(self.area.x0, layout.bounds.y0) + self.icon_offset,
+                Alignment2D::TOP_LEFT,
+                *color,
+                layout.style.background_color,
+            );
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area);
+        self.paragraphs.bounds(sink);
+    }
+}
+
+impl<T> Paginate for IconList<T>
+where
+    T: ParagraphSource,
+{
+    fn page_count(&mut self) -> usize {
+        1
+    }
+
+    fn change_page(&mut self, _to_page: usize) {}
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T: ParagraphSource> crate::trace::Trace for IconList<T> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("IconList");
+        t.child("items", &self.paragraphs);
+    }
+}
+
 pub trait VecExt<T> {
     fn add(&mut self, paragraph: Paragraph<T>) -> &mut Self;
 }
@@ -684,7 +896,9 @@ where
         if paragraph.content.as_ref().is_empty() {
             return self;
         }
-        if self.push(paragraph).is_err() {
+        let pushed = self.push(paragraph);
+        stats::record_buffer("ParagraphVec", self.len(), N);
+        if pushed.is_err() {
             #[cfg(feature = "ui_debug")]
             panic!("paragraph list is full");
         }
@@ -730,3 +944,63 @@ impl<T: StringType> ParagraphSource for Paragraph<T> {
         1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{constant::screen, display::Font, testing::Harness};
+
+    use super::*;
+
+    const STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    #[test]
+    fn icons_get_a_column_of_their_own() {
+        let checks = [
+            Paragraph::new(&STYLE, "Share 1 is correct"),
+            Paragraph::new(&STYLE, "Share 2 is not part of the backup"),
+        ];
+        // Only the first `MAX_ICONS` are kept, paragraphs without one too.
+        let h = Harness::new(
+            IconList::from_paragraphs(core::iter::repeat(None).take(10), Paragraphs::new(checks))
+                .with_icon_width(24),
+        );
+        let list = h.inner();
+        assert!(list.icons.len() == MAX_ICONS);
+        assert!(list.area == screen());
+        assert!(list.paragraphs.area == screen().inset(Insets::left(24)));
+    }
+
+    const ADDRESS: &str =
+        "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+    /// Beginnings of the pages with the first paragraph taking `lines` and
+    /// an address after it.
+    fn page_starts(lines: usize, keep_whole: bool) -> Vec<PageOffset, MAX_CACHED_PAGES> {
+        let first: &str = Box::leak("Recipient\n".repeat(lines).into_boxed_str());
+        let mut address = Paragraph::new(&STYLE, ADDRESS);
+        if keep_whole {
+            address = address.keep_whole();
+        }
+        let mut h = Harness::new(Paragraphs::new([Paragraph::new(&STYLE, first), address]));
+        h.mutate(|_, p| {
+            p.cache_page_breaks();
+            unwrap!(p.page_breaks.clone())
+        })
+    }
+
+    #[test]
+    fn addresses_are_not_split() {
+        let split = |offset: &PageOffset| offset.par == 1 && offset.chr > 0;
+        // Somewhere the address starts low enough on the page to get split.
+        assert!((1..10).any(|lines| page_starts(lines, false).iter().any(split)));
+        for lines in 1..10 {
+            assert!(!page_starts(lines, true).iter().any(split));
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use heapless::{Deque, String};
+
+use crate::{
+    time::Duration,
+    ui::{
+        component::{
+            text::TextStyle, AttrUpdate, Component, ComponentExt, Event, EventCtx, TimerToken,
+        },
+        display::{self, Icon},
+        geometry::{Offset, Rect},
+        translations::TranslatedString,
+        util::icon_text_center,
+    },
+};
+
+/// Maximum number of notifications waiting to be shown.
+const MAX_TOASTS: usize = 4;
+/// Longer texts are cut, they would not fit on one line anyway.
+const MAX_TEXT_LEN: usize = 32;
+const DEFAULT_DURATION_MS: u32 = 2000;
+/// Space between the icon and the text.
+const ICON_SPACE: i16 = 4;
+/// Vertical padding of the toast bar.
+const PADDING: i16 = 2;
+
+/// Short notification consisting of an icon and one line of text.
+pub struct Toast {
+    icon: Icon,
+    text: String<MAX_TEXT_LEN>,
+}
+
+impl Toast {
+    pub fn new(icon: Icon, text: &str) -> Self {
+        let mut buffer = String::new();
+        for ch in text.chars() {
+            if buffer.push(ch).is_err() {
+                break;
+            }
+        }
+        Self { icon, text: buffer }
+    }
+
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+/// Shows queued toasts one after another in a bar at the bottom of the inner
+/// component, each for a fixed time. The inner component keeps receiving all
+/// the events while a toast is shown, so an ongoing input is not interrupted.
+/// The firmware adds toasts with `AttrUpdate::Toast`, they get the `icon` of the
+/// queue.
+pub struct ToastQueue<T> {
+    inner: T,
+    queue: Deque<Toast, MAX_TOASTS>,
+    style: TextStyle,
+    icon: Icon,
+    duration: Duration,
+    area: Rect,
+    timer: Option<TimerToken>,
+}
+
+impl<T> ToastQueue<T>
+where
+    T: Component,
+{
+    pub fn new(inner: T, style: TextStyle, icon: Icon) -> Self {
+        Self {
+            inner,
+            queue: Deque::new(),
+            style,
+            icon,
+            duration: Duration::from_millis(DEFAULT_DURATION_MS),
+            area: Rect::zero(),
+            timer: None,
+        }
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Enqueue a notification. It is shown right away in case no other toast
+    /// is visible. Returns `false` and discards the toast when the queue is
+    /// full.
+    pub fn push(&mut self, ctx: &mut EventCtx, toast: Toast) -> bool {
+        if self.queue.push_back(toast).is_err() {
+            return false;
+        }
+        if self.timer.is_none() {
+            self.show_next(ctx);
+        }
+        true
+    }
+
+    /// Currently shown toast, if any.
+    pub fn current(&self) -> Option<&Toast> {
+        self.queue.front()
+    }
+
+    fn show_next(&mut self, ctx: &mut EventCtx) {
+        if self.queue.is_empty() {
+            self.timer = None;
+        } else {
+            self.timer = Some(ctx.request_timer(self.duration));
+            ctx.request_paint();
+        }
+    }
+}
+
+impl<T> Component for ToastQueue<T>
+where
+    T: Component,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let height = self.style.text_font.line_height() + 2 * PADDING;
+        self.area = bounds.split_bottom(height).1;
+        self.inner.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Timer(token) = event {
+            if Some(token) == self.timer {
+                self.queue.pop_front();
+                // The toast was painted over the inner component.
+                self.inner.request_complete_repaint(ctx);
+                self.show_next(ctx);
+                return None;
+            }
+        }
+        self.inner.event(ctx, event)
+    }
+
+    fn paint(&mut self) {
+        self.inner.paint();
+        if let Some(toast) = self.queue.front() {
+            display::rect_fill(self.area, self.style.background_color);
+            icon_text_center(
+                self.area.center(),
+                toast.icon,
+                ICON_SPACE,
+                toast.text(),
+                self.style,
+                Offset::zero(),
+            );
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.inner.bounds(sink);
+        sink(self.area);
+    }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Toast(text) => {
+                // A full queue drops the toast, the attribute is still supported.
+                self.push(ctx, Toast::new(self.icon, text));
+                true
+            }
+            _ => self.inner.update_attr(ctx, attr),
+        }
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.inner.help()
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for ToastQueue<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("ToastQueue");
+        if let Some(toast) = self.queue.front() {
+            t.string("toast", toast.text());
+        }
+        t.int("queued", self.queue.len() as i64);
+        t.child("inner", &self.inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{component::Timeout, model_tt::theme, testing::Harness};
+
+    #[test]
+    fn toasts_in_order() {
+        let queue = ToastQueue::new(Timeout::new(10000), theme::TEXT_NORMAL, theme::ICON_WARN);
+        let mut queue = Harness::new(queue);
+        assert!(queue.update_attr(AttrUpdate::Toast("Settings saved")));
+        assert!(queue.update_attr(AttrUpdate::Toast("USB disconnected")));
+        assert_eq!(queue.inner().current().unwrap().text(), "Settings saved");
+        // Only the shown toast waits for its timer, besides the inner component.
+        assert_eq!(queue.timers().len(), 2);
+        // The inner component still gets its timer.
+        assert!(queue.fire_timers().is_some());
+        assert_eq!(queue.inner().current().unwrap().text(), "USB disconnected");
+        assert!(queue.fire_timers().is_none());
+        assert!(queue.inner().current().is_none());
+        assert!(queue.timers().is_empty());
+    }
+}
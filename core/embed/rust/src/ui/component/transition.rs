@@ -0,0 +1,407 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        animation::{effects_disabled, Animation},
+        component::{AttrUpdate, Component, Event, EventCtx, Paginate},
+        display::{self, Color},
+        geometry::{Offset, Rect},
+        translations::TranslatedString,
+    },
+};
+
+/// How long a slide between two pages takes.
+const SLIDE_DURATION: Duration = Duration::from_millis(80);
+/// How long the backlight takes to come back up in a fade.
+const FADE_DURATION: Duration = Duration::from_millis(100);
+/// Progress of a transition goes from zero to this value.
+const PROGRESS_END: i16 = 1000;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TransitionKind {
+    /// Old content leaves to the left, new content comes from the right.
+    SlideLeft,
+    /// Old content leaves to the right, new content comes from the left.
+    SlideRight,
+    /// Screen goes dark and the new content fades in.
+    Fade,
+}
+
+struct Running {
+    kind: TransitionKind,
+    from_page: usize,
+    progress: Animation<i16>,
+}
+
+/// Animates the change between two pages of the inner component. Changing
+/// to a higher page slides to the left, changing to a lower one slides to the
+/// right. The owner starts the animation with `play()` after changing the
+/// page, its frames are then painted on animation frame timers, so that the
+/// events keep being handled while it runs.
+///
+/// Only active with the `ui_transitions` feature, otherwise (or when disabled
+/// at runtime) it just passes everything through to the inner component.
+pub struct Transition<T> {
+    inner: T,
+    area: Rect,
+    background: Color,
+    /// Backlight level a fade ends on, the current one if not given.
+    backlight: Option<u16>,
+    active_page: usize,
+    /// Transition to play next, together with the page it starts from.
+    pending: Option<(TransitionKind, usize)>,
+    running: Option<Running>,
+    /// Whether the area needs clearing before painting the inner component.
+    clear: bool,
+}
+
+impl<T> Transition<T>
+where
+    T: Component + Paginate,
+{
+    pub fn new(inner: T, background: Color) -> Self {
+        Self {
+            inner,
+            area: Rect::zero(),
+            background,
+            backlight: None,
+            active_page: 0,
+            pending: None,
+            running: None,
+            clear: false,
+        }
+    }
+
+    /// Backlight level to end fades on.
+    pub fn with_backlight(mut self, backlight: u16) -> Self {
+        self.backlight = Some(backlight);
+        self
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Play `kind` instead of the slide the page change asked for.
+    pub fn start(&mut self, kind: TransitionKind) {
+        let from_page = self.pending.map_or(self.active_page, |(_, from)| from);
+        self.pending = Some((kind, from_page));
+    }
+
+    /// Start playing the pending transition, if there is one and transitions
+    /// are enabled.
+    pub fn play(&mut self, ctx: &mut EventCtx) {
+        let (kind, from_page) = match self.pending.take() {
+            Some(pending) if Self::enabled(ctx) => pending,
+            _ => return,
+        };
+        let duration = match kind {
+            TransitionKind::Fade => FADE_DURATION,
+            _ => SLIDE_DURATION,
+        };
+        if kind == TransitionKind::Fade && self.backlight.is_none() {
+            self.backlight = Some(display::backlight());
+        }
+        self.running = Some(Running {
+            kind,
+            from_page,
+            progress: Animation::new(0, PROGRESS_END, duration, Instant::now()),
+        });
+        ctx.request_anim_frame();
+        ctx.request_paint();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    fn enabled(ctx: &EventCtx) -> bool {
+        cfg!(feature = "ui_transitions")
+            && !ctx.settings().transitions_disabled
+            && !ctx.accessibility().reduce_motion
+            && !effects_disabled()
+    }
+
+    fn finish(&mut self) {
+        if let Some(running) = self.running.take() {
+            match running.kind {
+                TransitionKind::Fade => {
+                    display::set_backlight(self.backlight.unwrap_or_else(display::backlight))
+                }
+                _ => self.clear = true,
+            }
+        }
+    }
+
+    fn paint_page_at(&mut self, page: usize, offset: Offset) {
+        display::set_offset(offset);
+        self.inner.change_page(page);
+        self.inner.paint();
+    }
+
+    /// One frame of the slide from `from_page` to the active page. Positive
+    /// `direction` moves the content to the left.
+    fn paint_slide(&mut self, from_page: usize, direction: i16, progress: i16) {
+        let to_page = self.active_page;
+        let width = self.area.width();
+        let shift = (width as i32 * progress as i32 / PROGRESS_END as i32) as i16 * direction;
+        display::rect_fill(self.area, self.background);
+        self.paint_page_at(from_page, Offset::x(-shift));
+        self.paint_page_at(to_page, Offset::x(direction * width - shift));
+        display::set_offset(Offset::zero());
+        self.inner.change_page(to_page);
+    }
+
+    /// One frame of the fade, the backlight goes up with the progress.
+    fn paint_fade(&mut self, progress: i16) {
+        let target = self.backlight.unwrap_or_else(display::backlight);
+        display::set_backlight((target as i32 * progress as i32 / PROGRESS_END as i32) as u16);
+        self.inner.paint();
+    }
+}
+
+impl<T> Paginate for Transition<T>
+where
+    T: Component + Paginate,
+{
+    fn page_count(&mut self) -> usize {
+        self.inner.page_count()
+    }
+
+    fn change_page(&mut self, active_page: usize) {
+        if active_page != self.active_page {
+            // When the page changes more times before the transition is
+            // played, slide from the page that is still on the screen.
+            let from_page = self.pending.map_or(self.active_page, |(_, from)| from);
+            let kind = if active_page > from_page {
+                TransitionKind::SlideLeft
+            } else {
+                TransitionKind::SlideRight
+            };
+            self.pending = Some((kind, from_page));
+            self.active_page = active_page;
+        }
+        self.inner.change_page(active_page);
+    }
+}
+
+impl<T> Component for Transition<T>
+where
+    T: Component + Paginate,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = self.inner.place(bounds);
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let (Some(running), Event::Timer(EventCtx::ANIM_FRAME_TIMER)) = (&self.running, event) {
+            if running.progress.elapsed(Instant::now()) >= running.progress.duration {
+                self.finish();
+            } else {
+                ctx.request_anim_frame();
+            }
+            ctx.request_paint();
+        }
+        self.inner.event(ctx, event)
+    }
+
+    fn paint(&mut self) {
+        let now = Instant::now();
+        let frame = self
+            .running
+            .as_ref()
+            .map(|running| (running.kind, running.from_page, running.progress.value(now)));
+        match frame {
+            Some((TransitionKind::SlideLeft, from_page, progress)) => {
+                self.paint_slide(from_page, 1, progress)
+            }
+            Some((TransitionKind::SlideRight, from_page, progress)) => {
+                self.paint_slide(from_page, -1, progress)
+            }
+            Some((TransitionKind::Fade, _, progress)) => self.paint_fade(progress),
+            None => {
+                if self.clear {
+                    self.clear = false;
+                    display::rect_fill(self.area, self.background);
+                }
+                self.inner.paint();
+            }
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.inner.bounds(sink);
+    }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.inner.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.inner.help()
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for Transition<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        self.inner.trace(t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        component::{Never, TimerToken},
+        testing::Harness,
+    };
+
+    use super::*;
+
+    /// Content of three pages remembering the one it shows.
+    struct Pages {
+        page: usize,
+    }
+
+    impl Component for Pages {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            None
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    impl Paginate for Pages {
+        fn page_count(&mut self) -> usize {
+            3
+        }
+
+        fn change_page(&mut self, active_page: usize) {
+            self.page = active_page;
+        }
+    }
+
+    fn harness() -> Harness<Transition<Pages>> {
+        Harness::new(Transition::new(Pages { page: 0 }, Color::black()))
+    }
+
+    fn go_to(h: &mut Harness<Transition<Pages>>, page: usize) {
+        h.mutate(|ctx, t| {
+            t.change_page(page);
+            t.play(ctx);
+        });
+    }
+
+    fn anim_frames(h: &Harness<Transition<Pages>>) -> usize {
+        h.timers()
+            .iter()
+            .filter(|(token, _)| *token == EventCtx::ANIM_FRAME_TIMER)
+            .count()
+    }
+
+    /// Let the running transition reach its end at the next frame.
+    fn end(h: &mut Harness<Transition<Pages>>) {
+        h.mutate(|_, t| {
+            if let Some(running) = &mut t.running {
+                running.progress.duration = Duration::from_millis(0);
+            }
+        });
+    }
+
+    #[test]
+    fn slide_runs_on_frame_timers() {
+        let mut h = harness();
+        go_to(&mut h, 1);
+
+        assert!(h.inner().is_running());
+        assert!(h.inner().inner().page == 1);
+        assert!(anim_frames(&h) == 1);
+        assert!(matches!(
+            h.inner().running.as_ref().map(|r| (r.kind, r.from_page)),
+            Some((TransitionKind::SlideLeft, 0))
+        ));
+
+        // Each frame asks for the next one until the slide is over.
+        h.fire_timers();
+        assert!(h.inner().is_running());
+        assert!(anim_frames(&h) == 1);
+
+        end(&mut h);
+        h.clear_paint();
+        h.fire_timers();
+        assert!(!h.inner().is_running());
+        assert!(anim_frames(&h) == 0);
+        assert!(h.paint_requested());
+    }
+
+    #[test]
+    fn events_pass_through_while_running() {
+        let mut h = harness();
+        go_to(&mut h, 2);
+        go_to(&mut h, 1);
+
+        // The second change slides back from the page on the screen.
+        assert!(matches!(
+            h.inner().running.as_ref().map(|r| (r.kind, r.from_page)),
+            Some((TransitionKind::SlideRight, 2))
+        ));
+        h.event(Event::Timer(TimerToken::INVALID));
+        assert!(h.inner().is_running());
+    }
+
+    #[test]
+    fn fade_replaces_the_slide() {
+        let mut h =
+            Harness::new(Transition::new(Pages { page: 0 }, Color::black()).with_backlight(150));
+        h.mutate(|ctx, t| {
+            t.change_page(1);
+            t.start(TransitionKind::Fade);
+            t.play(ctx);
+        });
+        assert!(matches!(
+            h.inner().running.as_ref().map(|r| (r.kind, r.from_page)),
+            Some((TransitionKind::Fade, 0))
+        ));
+    }
+
+    #[test]
+    fn no_slide_on_low_battery() {
+        use crate::ui::{
+            animation::LOW_BATTERY_LEVEL,
+            context::{update_settings, UiSettings},
+        };
+
+        update_settings(|s| s.battery_level = Some(LOW_BATTERY_LEVEL - 1));
+        let mut h = harness();
+        go_to(&mut h, 1);
+        assert!(!h.inner().is_running());
+        assert!(h.inner().inner().page == 1);
+        assert!(anim_frames(&h) == 0);
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[test]
+    fn nothing_to_play() {
+        let mut h = harness();
+        go_to(&mut h, 0);
+        assert!(!h.inner().is_running());
+        assert!(anim_frames(&h) == 0);
+    }
+}
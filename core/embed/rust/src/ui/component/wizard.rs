@@ -0,0 +1,282 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::{Component, Event, EventCtx},
+    geometry::Rect,
+};
+
+/// Maximum number of steps of a `Wizard`.
+pub const MAX_STEPS: usize = 8;
+
+/// Message a single step of a `Wizard` sends to move the flow.
+pub enum StepMsg<R> {
+    /// Go to the previous step, cancels the flow on the first one.
+    Back,
+    /// Store the result of this step and go to the next one, completes the
+    /// flow on the last one.
+    Next(R),
+    /// Abort the whole flow.
+    Cancel,
+}
+
+pub enum WizardMsg {
+    /// All the steps were passed, results are available in
+    /// `Wizard::results()`.
+    Completed,
+    Cancelled,
+}
+
+/// Heterogeneous sequence of steps, implemented for tuples of components
+/// whose message type is `StepMsg<R>`.
+pub trait WizardSteps<R> {
+    const COUNT: usize;
+    fn place(&mut self, idx: usize, bounds: Rect) -> Rect;
+    fn event(&mut self, idx: usize, ctx: &mut EventCtx, event: Event) -> Option<StepMsg<R>>;
+    fn paint(&mut self, idx: usize);
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, idx: usize, sink: &mut dyn FnMut(Rect));
+    #[cfg(feature = "ui_debug")]
+    fn trace(&self, idx: usize, t: &mut dyn crate::trace::Tracer);
+}
+
+/// Multi-step flow. Owns all of its steps so that each of them keeps its state
+/// when the user goes back and forth, and reports a single result when the
+/// flow finishes.
+pub struct Wizard<S, R> {
+    steps: S,
+    current: usize,
+    results: Vec<Option<R>, MAX_STEPS>,
+}
+
+impl<S, R> Wizard<S, R>
+where
+    S: WizardSteps<R>,
+{
+    pub fn new(steps: S) -> Self {
+        let mut results = Vec::new();
+        for _ in 0..S::COUNT.min(MAX_STEPS) {
+            unwrap!(results.push(None));
+        }
+        Self {
+            steps,
+            current: 0,
+            results,
+        }
+    }
+
+    pub fn current_step(&self) -> usize {
+        self.current
+    }
+
+    /// Results of the individual steps, `None` for steps that were not
+    /// confirmed yet.
+    pub fn results(&self) -> &[Option<R>] {
+        &self.results
+    }
+
+    fn go_to(&mut self, ctx: &mut EventCtx, idx: usize) {
+        self.current = idx;
+        // Let the step know it is visible now, e.g. to set up its timers.
+        self.steps.event(self.current, ctx, Event::Attach);
+        self.steps.event(self.current, ctx, Event::RequestPaint);
+        ctx.request_paint();
+        ctx.request_repaint_root();
+    }
+}
+
+impl<S, R> Component for Wizard<S, R>
+where
+    S: WizardSteps<R>,
+{
+    type Msg = WizardMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        for idx in 0..S::COUNT {
+            self.steps.place(idx, bounds);
+        }
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match self.steps.event(self.current, ctx, event)? {
+            StepMsg::Back if self.current == 0 => Some(WizardMsg::Cancelled),
+            StepMsg::Back => {
+                self.go_to(ctx, self.current - 1);
+                None
+            }
+            StepMsg::Next(result) => {
+                self.results[self.current] = Some(result);
+                if self.current + 1 >= S::COUNT {
+                    Some(WizardMsg::Completed)
+                } else {
+                    self.go_to(ctx, self.current + 1);
+                    None
+                }
+            }
+            StepMsg::Cancel => Some(WizardMsg::Cancelled),
+        }
+    }
+
+    fn paint(&mut self) {
+        self.steps.paint(self.current);
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.steps.bounds(self.current, sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<S, R> crate::trace::Trace for Wizard<S, R>
+where
+    S: WizardSteps<R>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("Wizard");
+        t.int("current_step", self.current as i64);
+        t.int("step_count", S::COUNT as i64);
+        t.in_child("step", &|t| self.steps.trace(self.current, t));
+    }
+}
+
+macro_rules! impl_wizard_steps {
+    ($count:literal; $($idx:tt: $t:ident),+) => {
+        impl<R, $($t),+> WizardSteps<R> for ($($t,)+)
+        where
+            $($t: Component<Msg = StepMsg<R>> + crate::maybe_trace::MaybeTrace,)+
+        {
+            const COUNT: usize = $count;
+
+            fn place(&mut self, idx: usize, bounds: Rect) -> Rect {
+                match idx {
+                    $($idx => self.$idx.place(bounds),)+
+                    _ => Rect::zero(),
+                }
+            }
+
+            fn event(
+                &mut self,
+                idx: usize,
+                ctx: &mut EventCtx,
+                event: Event,
+            ) -> Option<StepMsg<R>> {
+                match idx {
+                    $($idx => self.$idx.event(ctx, event),)+
+                    _ => None,
+                }
+            }
+
+            fn paint(&mut self, idx: usize) {
+                match idx {
+                    $($idx => self.$idx.paint(),)+
+                    _ => {}
+                }
+            }
+
+            #[cfg(feature = "ui_bounds")]
+            fn bounds(&self, idx: usize, sink: &mut dyn FnMut(Rect)) {
+                match idx {
+                    $($idx => self.$idx.bounds(sink),)+
+                    _ => {}
+                }
+            }
+
+            #[cfg(feature = "ui_debug")]
+            fn trace(&self, idx: usize, t: &mut dyn crate::trace::Tracer) {
+                match idx {
+                    $($idx => crate::trace::Trace::trace(&self.$idx, t),)+
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+impl_wizard_steps!(2; 0: A, 1: B);
+impl_wizard_steps!(3; 0: A, 1: B, 2: C);
+impl_wizard_steps!(4; 0: A, 1: B, 2: C, 3: D);
+impl_wizard_steps!(5; 0: A, 1: B, 2: C, 3: D, 4: E);
+impl_wizard_steps!(6; 0: A, 1: B, 2: C, 3: D, 4: E, 5: F);
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{event::PhysicalButton, testing::Harness};
+
+    use super::*;
+
+    /// Step going on with the right button, back with the left one, and
+    /// cancelling the flow on any timer, which stands in for a cancel button.
+    /// Its result counts how many times it was passed.
+    struct Step {
+        id: u8,
+        passed: u8,
+    }
+
+    impl Component for Step {
+        type Msg = StepMsg<u8>;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            use crate::ui::event::ButtonEvent::ButtonReleased;
+            match event {
+                Event::Button(ButtonReleased(PhysicalButton::Left)) => Some(StepMsg::Back),
+                Event::Button(ButtonReleased(PhysicalButton::Right)) => {
+                    self.passed += 1;
+                    Some(StepMsg::Next(self.id * 10 + self.passed))
+                }
+                Event::Timer(_) => Some(StepMsg::Cancel),
+                _ => None,
+            }
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    fn step(id: u8) -> Step {
+        Step { id, passed: 0 }
+    }
+
+    fn wizard() -> Harness<Wizard<(Step, Step, Step), u8>> {
+        Harness::new(Wizard::new((step(0), step(1), step(2))))
+    }
+
+    #[test]
+    fn steps_keep_their_state() {
+        let mut h = wizard();
+        assert!(h.click(PhysicalButton::Right).is_none());
+        assert!(h.inner().current_step() == 1);
+        assert!(h.paint_requested());
+
+        assert!(h.click(PhysicalButton::Left).is_none());
+        assert!(h.inner().current_step() == 0);
+
+        assert!(h.click(PhysicalButton::Right).is_none());
+        assert!(h.click(PhysicalButton::Right).is_none());
+        assert!(matches!(
+            h.click(PhysicalButton::Right),
+            Some(WizardMsg::Completed)
+        ));
+        assert!(h.inner().results() == [Some(2), Some(11), Some(21)]);
+    }
+
+    #[test]
+    fn cancelling() {
+        let mut h = wizard();
+        assert!(matches!(
+            h.click(PhysicalButton::Left),
+            Some(WizardMsg::Cancelled)
+        ));
+
+        let mut h = wizard();
+        h.click(PhysicalButton::Right);
+        assert!(matches!(
+            h.event(Event::Timer(crate::ui::component::TimerToken::INVALID)),
+            Some(WizardMsg::Cancelled)
+        ));
+        assert!(h.inner().results() == [Some(1), None, None]);
+    }
+}
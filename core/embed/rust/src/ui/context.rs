@@ -0,0 +1,279 @@
+//! Everything the UI keeps between layouts, in one place.
+//!
+//! `UiSettings` is what the firmware configures through `trezorui2`, e.g. the
+//! accessibility preferences or the keyboard layout. `EventCtx` takes a copy
+//! at the start of every event, so components handling one read it from
+//! `EventCtx::settings()`. Code that runs without a context, i.e. painting and
+//! the constructors called from Python, reads `settings()`.
+//!
+//! `UiState` is what the UI code itself carries from one call to the next:
+//! caches, the text carried between screens, the spinner of a busy
+//! computation. Each field belongs to one module, which reaches it through
+//! `with_state()`.
+//!
+//! Tests run in parallel threads, so there each thread gets a context of its
+//! own.
+
+use core::cell::{Cell, RefCell};
+
+use crate::{
+    strutil::SecretString,
+    time::Instant,
+    ui::{
+        accessibility::Accessibility,
+        autodim::AutoDim,
+        busy::Busy,
+        carry::MAX_CARRY_LENGTH,
+        component::text::layout::SpanCache,
+        density::Density,
+        display::{glyph_cache::GlyphCache, LOADER_MAX},
+        eta::RateTracker,
+        keyboard::KeyboardLayout,
+        theme::ThemeVariant,
+        translations::Translations,
+        wallet_badge::Wallet,
+    },
+};
+
+#[cfg(all(feature = "button", feature = "ui_debug"))]
+use crate::ui::button_quirks::ButtonQuirks;
+#[cfg(feature = "ui_debug")]
+use crate::ui::profiler::Profiler;
+#[cfg(feature = "ui_stats")]
+use crate::ui::stats::Stats;
+
+#[derive(Copy, Clone)]
+pub struct UiSettings {
+    pub accessibility: Accessibility,
+    pub density: Density,
+    /// Applies to keyboards created from now on.
+    pub keyboard_layout: KeyboardLayout,
+    /// Wallet of the session, `None` hides the badge, see `wallet_badge`.
+    pub wallet: Option<Wallet>,
+    /// Charge in percent, `None` on external power or without a battery.
+    pub battery_level: Option<u8>,
+    /// `None` while the display does not dim, see `autodim`.
+    pub autodim: Option<AutoDim>,
+    /// Screen transitions turned off, see `component::Transition`.
+    pub transitions_disabled: bool,
+    /// Colors the screens are painted in, see `display::themed`.
+    pub theme_variant: ThemeVariant,
+    /// `Child` components trace their paint counts, see `trace`.
+    #[cfg(feature = "ui_debug")]
+    pub paint_counters: bool,
+}
+
+impl UiSettings {
+    pub const DEFAULT: Self = Self {
+        accessibility: Accessibility::DEFAULT,
+        density: Density::Comfortable,
+        keyboard_layout: KeyboardLayout::Abc,
+        wallet: None,
+        battery_level: None,
+        autodim: None,
+        transitions_disabled: false,
+        theme_variant: ThemeVariant::Normal,
+        #[cfg(feature = "ui_debug")]
+        paint_counters: false,
+    };
+}
+
+pub(crate) struct UiState {
+    /// Backlight from before the dimming, `None` while not dimmed.
+    pub autodim_restore: Option<u16>,
+    /// Start of the event or paint pass being measured by `budget`.
+    pub pass_start: Option<Instant>,
+    /// Some component left a part of its paint for the next pass.
+    pub paint_deferred: bool,
+    pub busy: Option<Busy>,
+    pub carry: SecretString<MAX_CARRY_LENGTH>,
+    pub glyph_cache: GlyphCache,
+    pub span_cache: SpanCache,
+    /// Estimate of the remaining time of a wipe in the bootloader.
+    pub wipe_eta: RateTracker,
+    /// Quirks of the simulated buttons and the state of their PRNG.
+    #[cfg(all(feature = "button", feature = "ui_debug"))]
+    pub button_quirks: Option<(ButtonQuirks, u32)>,
+    #[cfg(feature = "ui_debug")]
+    pub profiler: Option<Profiler>,
+    /// State of the PRNG replacing the random numbers, see `random`.
+    #[cfg(feature = "ui_replay")]
+    pub replay_state: Option<u32>,
+    /// Usage recorded outside of the layouts, see `stats`.
+    #[cfg(feature = "ui_stats")]
+    pub stats: Stats,
+    /// Stack pointer at the outermost `stats::enter`, zero outside of it.
+    #[cfg(feature = "ui_stats")]
+    pub stack_base: usize,
+}
+
+impl UiState {
+    const fn new() -> Self {
+        Self {
+            autodim_restore: None,
+            pass_start: None,
+            paint_deferred: false,
+            busy: None,
+            carry: SecretString::new(),
+            glyph_cache: GlyphCache::new(),
+            span_cache: SpanCache::new(),
+            wipe_eta: RateTracker::new(LOADER_MAX),
+            #[cfg(all(feature = "button", feature = "ui_debug"))]
+            button_quirks: None,
+            #[cfg(feature = "ui_debug")]
+            profiler: None,
+            #[cfg(feature = "ui_replay")]
+            replay_state: None,
+            #[cfg(feature = "ui_stats")]
+            stats: Stats::new(),
+            #[cfg(feature = "ui_stats")]
+            stack_base: 0,
+        }
+    }
+}
+
+struct UiContext {
+    settings: Cell<UiSettings>,
+    state: RefCell<UiState>,
+    /// Translations in use, `None` for English. Strings are lent out of them
+    /// for as long as the language does not change, so they cannot live in
+    /// the `RefCell` with the rest.
+    #[cfg(not(test))]
+    translations: core::cell::UnsafeCell<Option<Translations<'static>>>,
+    #[cfg(test)]
+    translations: Cell<Option<&'static Translations<'static>>>,
+}
+
+impl UiContext {
+    const fn new() -> Self {
+        Self {
+            settings: Cell::new(UiSettings::DEFAULT),
+            state: RefCell::new(UiState::new()),
+            #[cfg(not(test))]
+            translations: core::cell::UnsafeCell::new(None),
+            #[cfg(test)]
+            translations: Cell::new(None),
+        }
+    }
+}
+
+// SAFETY: The firmware runs the UI on a single thread, interrupt handlers do
+// not touch it.
+#[cfg(not(test))]
+unsafe impl Sync for UiContext {}
+
+#[cfg(not(test))]
+static CONTEXT: UiContext = UiContext::new();
+
+#[cfg(test)]
+std::thread_local! {
+    static CONTEXT: UiContext = const { UiContext::new() };
+}
+
+fn with_context<R>(f: impl FnOnce(&UiContext) -> R) -> R {
+    #[cfg(not(test))]
+    let result = f(&CONTEXT);
+    #[cfg(test)]
+    let result = CONTEXT.with(f);
+    result
+}
+
+/// Current settings.
+pub fn settings() -> UiSettings {
+    with_context(|context| context.settings.get())
+}
+
+pub fn update_settings(f: impl FnOnce(&mut UiSettings)) {
+    with_context(|context| {
+        let mut settings = context.settings.get();
+        f(&mut settings);
+        context.settings.set(settings);
+    })
+}
+
+/// Run `f` on the state. `f` must not get back here, e.g. by passing an event
+/// to a component, that panics. Keep it to reading and updating the fields.
+pub(crate) fn with_state<R>(f: impl FnOnce(&mut UiState) -> R) -> R {
+    with_context(|context| f(&mut context.state.borrow_mut()))
+}
+
+pub(crate) fn translations() -> Option<&'static Translations<'static>> {
+    #[cfg(not(test))]
+    // SAFETY: `set_translations` is the only writer and it is never called
+    // while strings of the previous translations are in use.
+    let current = unsafe { (*CONTEXT.translations.get()).as_ref() };
+    #[cfg(test)]
+    let current = with_context(|context| context.translations.get());
+    current
+}
+
+pub(crate) fn set_translations(translations: Option<Translations<'static>>) {
+    #[cfg(not(test))]
+    // SAFETY: see `translations`.
+    unsafe {
+        *CONTEXT.translations.get() = translations;
+    }
+    #[cfg(test)]
+    with_context(|context| {
+        context
+            .translations
+            .set(translations.map(|t| &*Box::leak(Box::new(t))))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        component::EventCtx,
+        display::{self, Color},
+    };
+
+    use super::*;
+
+    #[test]
+    fn events_see_the_settings() {
+        let mut ctx = EventCtx::new();
+        assert!(!ctx.accessibility().high_contrast);
+
+        update_settings(|s| s.accessibility.high_contrast = true);
+        // Taken at the start of the event, not in the middle of it.
+        assert!(!ctx.accessibility().high_contrast);
+        ctx.clear();
+        assert!(ctx.accessibility().high_contrast);
+        assert!(settings().accessibility.high_contrast);
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[test]
+    fn grey_text_in_full_contrast() {
+        let grey = Color::rgb(0x90, 0x90, 0x90);
+        let red = Color::rgb(0xE7, 0x0E, 0x0E);
+        assert!(grey.with_full_contrast(Color::black()) == Color::white());
+        assert!(grey.with_full_contrast(Color::white()) == Color::black());
+        assert!(red.with_full_contrast(Color::black()) == red);
+    }
+
+    #[test]
+    fn inverted_variant_swaps_greys() {
+        for bits in 0..=u16::MAX {
+            let color = Color::from_u16(bits);
+            assert!(color.with_inverted_greys().with_inverted_greys() == color);
+        }
+        let red = Color::rgb(0xE7, 0x0E, 0x0E);
+
+        update_settings(|s| s.theme_variant = ThemeVariant::Inverted);
+        assert!(display::themed(Color::black()) == Color::white());
+        assert!(display::themed(Color::rgb(0x90, 0x90, 0x90)) == Color::rgb(0x6F, 0x6F, 0x6F));
+        assert!(display::themed(red) == red);
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+        assert!(display::themed(Color::black()) == Color::black());
+    }
+
+    #[test]
+    #[should_panic]
+    fn state_is_not_reentrant() {
+        with_state(|_| with_state(|_| {}));
+    }
+}
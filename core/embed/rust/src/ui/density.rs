@@ -0,0 +1,48 @@
+//! How much the confirm screens show on each page. The firmware sets it with
+//! `trezorui2.set_density()` into `UiSettings::density`. Paragraphs read it
+//! when laid out, the builders of detail pages when choosing their styles.
+
+/// Space below a paragraph in the compact density, instead of the one the
+/// paragraph asks for.
+const COMPACT_PARAGRAPH_SPACE: i16 = 2;
+/// Change of the space between the lines of a paragraph in the compact
+/// density.
+const COMPACT_LINE_SPACING: i16 = -1;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Density {
+    /// The spacing the screens were designed with.
+    Comfortable,
+    /// Tighter spacing and narrower fonts, so that more fits on a page.
+    Compact,
+}
+
+impl Density {
+    /// Density of a value of the `ScreenDensity` protobuf enum.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Comfortable),
+            1 => Some(Self::Compact),
+            _ => None,
+        }
+    }
+
+    /// `comfortable` or `compact` depending on the density, e.g. the style of
+    /// values on a detail page.
+    pub fn choose<T>(self, comfortable: T, compact: T) -> T {
+        match self {
+            Self::Comfortable => comfortable,
+            Self::Compact => compact,
+        }
+    }
+
+    /// Space below a paragraph that asked for `space`.
+    pub fn paragraph_space(self, space: i16) -> i16 {
+        self.choose(space, space.min(COMPACT_PARAGRAPH_SPACE))
+    }
+
+    /// Space between the lines of a paragraph whose style has `spacing`.
+    pub fn line_spacing(self, spacing: i16) -> i16 {
+        self.choose(spacing, spacing + COMPACT_LINE_SPACING)
+    }
+}
@@ -79,6 +79,36 @@ impl Color {
         Self(!self.0)
     }
 
+    /// White or black, whichever stands out more against `bg`, if this is a
+    /// shade of grey. Colors carrying a meaning, e.g. the red of a warning, are
+    /// kept.
+    pub fn with_full_contrast(self, bg: Color) -> Self {
+        let (r, g, b) = (self.r(), self.g(), self.b());
+        let grey = r.abs_diff(g) <= 8 && g.abs_diff(b) <= 8 && r.abs_diff(b) <= 8;
+        if !grey {
+            self
+        } else if bg.luminance() < 128 {
+            Self::white()
+        } else {
+            Self::black()
+        }
+    }
+
+    /// Negated if this is a shade of grey, e.g. white turns black, other
+    /// colors are kept. Negating again gives the color back, the shades are
+    /// compared on the bits the display keeps.
+    pub fn with_inverted_greys(self) -> Self {
+        let r = self.0 >> 11;
+        let g = (self.0 >> 6) & 0x1F;
+        let b = self.0 & 0x1F;
+        let grey = r.abs_diff(g) <= 1 && g.abs_diff(b) <= 1 && r.abs_diff(b) <= 1;
+        if grey {
+            self.negate()
+        } else {
+            self
+        }
+    }
+
     pub const fn white() -> Self {
         Self::rgb(255, 255, 255)
     }
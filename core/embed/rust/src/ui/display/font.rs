@@ -3,11 +3,12 @@ use crate::{
     ui::{
         constant,
         geometry::{Offset, Point, Rect},
+        translations,
     },
 };
 use core::slice;
 
-use super::{get_color_table, get_offset, pixeldata, set_window, Color};
+use super::{get_color_table, get_offset, glyph_cache, pixeldata, set_window, themed, Color};
 
 /// Representation of a single glyph.
 /// We use standard typographic terms. For a nice explanation, see, e.g.,
@@ -27,6 +28,9 @@ pub struct Glyph {
 }
 
 impl Glyph {
+    /// Size of the metadata preceding the pixel data.
+    pub const HEADER_LEN: usize = 5;
+
     /// Construct a `Glyph` from a raw pointer.
     ///
     /// # Safety
@@ -41,25 +45,39 @@ impl Glyph {
             let width = *data.offset(0) as i16;
             let height = *data.offset(1) as i16;
 
-            let data_bits = constant::FONT_BPP * width * height;
-
-            let data_bytes = if data_bits % 8 == 0 {
-                data_bits / 8
-            } else {
-                (data_bits / 8) + 1
-            };
-
             Glyph {
                 width,
                 height,
                 adv: *data.offset(2) as i16,
                 bearing_x: *data.offset(3) as i16,
                 bearing_y: *data.offset(4) as i16,
-                data: slice::from_raw_parts(data.offset(5), data_bytes as usize),
+                data: slice::from_raw_parts(data.offset(5), Self::data_len(width, height)),
             }
         }
     }
 
+    /// Construct a `Glyph` from `data` in the same format as `load` expects.
+    /// Returns `None` in case `data` is too short.
+    pub fn from_slice(data: &'static [u8]) -> Option<Self> {
+        let header = data.get(..Self::HEADER_LEN)?;
+        let (width, height) = (header[0] as i16, header[1] as i16);
+        let end = Self::HEADER_LEN + Self::data_len(width, height);
+        Some(Glyph {
+            width,
+            height,
+            adv: header[2] as i16,
+            bearing_x: header[3] as i16,
+            bearing_y: header[4] as i16,
+            data: data.get(Self::HEADER_LEN..end)?,
+        })
+    }
+
+    /// Size of the pixel data of a glyph of the given dimensions.
+    pub const fn data_len(width: i16, height: i16) -> usize {
+        let data_bits = constant::FONT_BPP as usize * width as usize * height as usize;
+        (data_bits + 7) / 8
+    }
+
     /// Space between the right edge of the glyph and the left edge of the next
     /// bounding box.
     pub const fn right_side_bearing(&self) -> i16 {
@@ -69,22 +87,7 @@ impl Glyph {
     pub fn print(&self, pos: Point, colortable: [Color; 16]) -> i16 {
         let bearing = Offset::new(self.bearing_x, -self.bearing_y);
         let size = Offset::new(self.width, self.height);
-        let pos_adj = pos + bearing;
-        let r = Rect::from_top_left_and_size(pos_adj, size);
-
-        let area = r.translate(get_offset());
-        let window = area.clamp(constant::screen());
-
-        set_window(window);
-
-        for y in window.y0..window.y1 {
-            for x in window.x0..window.x1 {
-                let p = Point::new(x, y);
-                let r = p - pos_adj;
-                let c = self.get_pixel_data(r);
-                pixeldata(colortable[c as usize]);
-            }
-        }
+        print_pixels(pos + bearing, size, colortable, |p| self.get_pixel_data(p));
         self.adv
     }
 
@@ -121,6 +124,30 @@ impl Glyph {
     }
 }
 
+/// Draw a glyph `size` big with its top left corner at `pos`, `pixel` returns
+/// the index into `colortable` of a pixel at the given offset in the glyph.
+pub(super) fn print_pixels(
+    pos: Point,
+    size: Offset,
+    colortable: [Color; 16],
+    pixel: impl Fn(Offset) -> u8,
+) {
+    let r = Rect::from_top_left_and_size(pos, size);
+
+    let area = r.translate(get_offset());
+    let window = area.clamp(constant::screen());
+
+    set_window(window);
+
+    for y in window.y0..window.y1 {
+        for x in window.x0..window.x1 {
+            let p = Point::new(x, y);
+            let c = pixel(p - pos);
+            pixeldata(colortable[c as usize]);
+        }
+    }
+}
+
 /// Font constants. Keep in sync with FONT_ definitions in
 /// `extmod/modtrezorui/fonts/fonts.h`.
 #[derive(Copy, Clone, PartialEq, Eq, FromPrimitive)]
@@ -141,7 +168,13 @@ impl From<Font> for i32 {
 
 impl Font {
     pub fn text_width(self, text: &str) -> i16 {
-        display::text_width(text, self.into())
+        if text.is_ascii() {
+            return display::text_width(text, self.into());
+        }
+        text.chars()
+            .filter_map(|c| self.get_char_glyph(c))
+            .map(|g| g.adv)
+            .sum()
     }
 
     /// Width of the text that is visible.
@@ -153,10 +186,10 @@ impl Font {
         }
 
         let first_char = unwrap!(text.chars().next());
-        let first_char_glyph = unwrap!(self.get_glyph(first_char as u8));
+        let first_char_glyph = unwrap!(self.get_char_glyph(first_char));
 
         let last_char = unwrap!(text.chars().last());
-        let last_char_glyph = unwrap!(self.get_glyph(last_char as u8));
+        let last_char_glyph = unwrap!(self.get_char_glyph(last_char));
 
         // Strip leftmost and rightmost spaces/bearings/margins.
         self.text_width(text) - first_char_glyph.bearing_x - last_char_glyph.right_side_bearing()
@@ -170,12 +203,15 @@ impl Font {
         }
 
         let first_char = unwrap!(text.chars().next());
-        let first_char_glyph = unwrap!(self.get_glyph(first_char as u8));
+        let first_char_glyph = unwrap!(self.get_char_glyph(first_char));
         first_char_glyph.bearing_x
     }
 
     pub fn char_width(self, ch: char) -> i16 {
-        display::char_width(ch, self.into())
+        if ch.is_ascii() {
+            return display::char_width(ch, self.into());
+        }
+        self.get_char_glyph(ch).map_or(0, |g| g.adv)
     }
 
     pub fn text_height(self) -> i16 {
@@ -208,27 +244,42 @@ impl Font {
         unsafe { Some(Glyph::load(gl_data)) }
     }
 
+    /// Glyph of `ch`. Characters outside of ASCII come from the font of the
+    /// current translations, the built-in fonts only have a placeholder for
+    /// them. The same glyphs are used for all the font styles.
+    pub fn get_char_glyph(self, ch: char) -> Option<Glyph> {
+        if ch.is_ascii() {
+            return self.get_glyph(ch as u8);
+        }
+        translations::wide_glyph(ch).or_else(|| {
+            let mut buf = [0; 4];
+            self.get_glyph(ch.encode_utf8(&mut buf).as_bytes()[0])
+        })
+    }
+
     pub fn display_text(self, text: &str, baseline: Point, fg_color: Color, bg_color: Color) {
-        let colortable = get_color_table(fg_color, bg_color);
+        let colortable = get_color_table(themed(fg_color), themed(bg_color));
         let mut adv_total = 0;
-        for c in text.bytes() {
-            let g = self.get_glyph(c);
-            if let Some(gly) = g {
-                let adv = gly.print(baseline + Offset::new(adv_total, 0), colortable);
+        for c in text.chars() {
+            let pos = baseline + Offset::new(adv_total, 0);
+            if let Some(adv) = glyph_cache::print(self, c, pos, colortable) {
+                adv_total += adv;
+            } else if let Some(gly) = self.get_char_glyph(c) {
+                let adv = gly.print(pos, colortable);
                 adv_total += adv;
             }
         }
     }
 
-    /// Get the length of the longest suffix from a given `text`
+    /// Get the length in bytes of the longest suffix from a given `text`
     /// that will fit into the area `width` pixels wide.
     pub fn longest_suffix(self, width: i16, text: &str) -> usize {
         let mut text_width = 0;
-        for (chars_from_right, c) in text.chars().rev().enumerate() {
+        for (idx, c) in text.char_indices().rev() {
             let c_width = self.char_width(c);
             if text_width + c_width > width {
                 // Another character cannot be fitted, we're done.
-                return chars_from_right;
+                return text.len() - idx - c.len_utf8();
             }
             text_width += c_width;
         }
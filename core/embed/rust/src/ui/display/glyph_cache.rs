@@ -0,0 +1,120 @@
+use crate::ui::{
+    context::with_state,
+    geometry::{Offset, Point},
+};
+
+use super::{
+    font::{print_pixels, Glyph},
+    Color, Font,
+};
+
+/// Characters drawn over and over during rapid updates, e.g. PIN asterisks,
+/// countdowns and progress percentages.
+const CACHED_CHARS: &str = "0123456789*%:.s";
+const CACHE_SIZE: usize = 8;
+/// Largest glyph kept, in pixels. Fits the digits of all the fonts but the
+/// big one.
+const MAX_PIXELS: usize = 512;
+
+/// Glyph with its pixel data expanded to one byte per pixel, so that drawing
+/// it does not have to look the glyph up and unpack the bits again.
+struct GlyphRaster {
+    font: Font,
+    ch: char,
+    width: i16,
+    height: i16,
+    adv: i16,
+    bearing_x: i16,
+    bearing_y: i16,
+    /// Rows of pixels in the `0..=15` range.
+    pixels: [u8; MAX_PIXELS],
+}
+
+impl GlyphRaster {
+    fn expand(font: Font, ch: char, glyph: &Glyph) -> Option<Self> {
+        let len = glyph.width as usize * glyph.height as usize;
+        if len > MAX_PIXELS {
+            return None;
+        }
+        let mut pixels = [0; MAX_PIXELS];
+        for y in 0..glyph.height {
+            for x in 0..glyph.width {
+                pixels[(y * glyph.width + x) as usize] = glyph.get_pixel_data(Offset::new(x, y));
+            }
+        }
+        Some(Self {
+            font,
+            ch,
+            width: glyph.width,
+            height: glyph.height,
+            adv: glyph.adv,
+            bearing_x: glyph.bearing_x,
+            bearing_y: glyph.bearing_y,
+            pixels,
+        })
+    }
+
+    fn print(&self, pos: Point, colortable: [Color; 16]) -> i16 {
+        let bearing = Offset::new(self.bearing_x, -self.bearing_y);
+        let size = Offset::new(self.width, self.height);
+        print_pixels(pos + bearing, size, colortable, |p| {
+            self.pixels[(p.y * self.width + p.x) as usize]
+        });
+        self.adv
+    }
+}
+
+/// Rasters of the `CACHED_CHARS` drawn most recently, the oldest one is
+/// replaced when full. Kept in `UiState::glyph_cache`.
+pub(crate) struct GlyphCache {
+    entries: [Option<GlyphRaster>; CACHE_SIZE],
+    next: usize,
+}
+
+impl GlyphCache {
+    const EMPTY: Option<GlyphRaster> = None;
+
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [Self::EMPTY; CACHE_SIZE],
+            next: 0,
+        }
+    }
+
+    fn get(&mut self, font: Font, ch: char) -> Option<&GlyphRaster> {
+        let index = match self
+            .entries
+            .iter()
+            .position(|e| matches!(e, Some(r) if r.font == font && r.ch == ch))
+        {
+            Some(index) => index,
+            None => {
+                let raster = GlyphRaster::expand(font, ch, &font.get_char_glyph(ch)?)?;
+                let index = self.next;
+                self.entries[index] = Some(raster);
+                self.next = (self.next + 1) % CACHE_SIZE;
+                index
+            }
+        };
+        self.entries[index].as_ref()
+    }
+}
+
+pub fn is_cached_char(ch: char) -> bool {
+    CACHED_CHARS.contains(ch)
+}
+
+/// Draw `ch` from the cache, expanding its glyph first if it is not there.
+/// Returns the advance, `None` if `ch` is not cached or its glyph is too big
+/// to be.
+pub fn print(font: Font, ch: char, pos: Point, colortable: [Color; 16]) -> Option<i16> {
+    if !is_cached_char(ch) {
+        return None;
+    }
+    // Only puts pixels on the display, which does not need the state.
+    with_state(|s| {
+        s.glyph_cache
+            .get(font, ch)
+            .map(|raster| raster.print(pos, colortable))
+    })
+}
@@ -240,6 +240,7 @@ pub fn loader_rust(
 
     let center = r.center();
 
+    let (fg_color, bg_color) = (display::themed(fg_color), display::themed(bg_color));
     let colortable = display::get_color_table(fg_color, bg_color);
     let mut icon_colortable = colortable;
 
@@ -256,7 +257,7 @@ pub fn loader_rust(
             icon_area_clamped = icon_area.clamp(constant::screen());
             icon_data = data;
             use_icon = true;
-            icon_colortable = display::get_color_table(color, bg_color);
+            icon_colortable = display::get_color_table(display::themed(color), bg_color);
         }
     }
 
@@ -359,7 +360,11 @@ pub fn loader_rust(
     let mut ib2 = buffers::BufferLine4bpp::get_cleared();
     let mut empty_line = buffers::BufferLine4bpp::get_cleared();
 
-    dma2d_setup_4bpp_over_4bpp(fg_color.into(), bg_color.into(), icon_color.into());
+    dma2d_setup_4bpp_over_4bpp(
+        display::themed(fg_color).into(),
+        display::themed(bg_color).into(),
+        display::themed(icon_color).into(),
+    );
 
     for y_c in r.y0..r.y1 {
         let mut icon_buffer = &mut *empty_line;
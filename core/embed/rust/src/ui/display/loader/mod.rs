@@ -17,7 +17,7 @@ use crate::ui::display::loader::rectangular::loader_rectangular as determinate;
 #[cfg(not(feature = "model_tt"))]
 use crate::ui::display::loader::starry::loader_starry_indeterminate as indeterminate;
 
-pub use small::loader_small_indeterminate;
+pub use small::{loader_small_indeterminate, loader_small_indeterminate_at};
 
 pub const LOADER_MIN: u16 = 0;
 pub const LOADER_MAX: u16 = 1000;
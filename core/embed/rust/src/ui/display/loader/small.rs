@@ -16,8 +16,22 @@ fn fill_point(point: Point, color: Color) {
 }
 
 pub fn loader_small_indeterminate(progress: u16, y_offset: i16, fg_color: Color, bg_color: Color) {
-    let area =
-        Rect::from_center_and_size(screen().center(), LOADER_SIZE).translate(Offset::y(y_offset));
+    loader_small_indeterminate_at(
+        screen().center() + Offset::y(y_offset),
+        progress,
+        fg_color,
+        bg_color,
+    );
+}
+
+/// Small loader centered at `center` instead of the middle of the screen.
+pub fn loader_small_indeterminate_at(
+    center: Point,
+    progress: u16,
+    fg_color: Color,
+    bg_color: Color,
+) {
+    let area = Rect::from_center_and_size(center, LOADER_SIZE);
 
     rect_fill(area, bg_color);
 
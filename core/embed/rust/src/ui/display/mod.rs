@@ -1,5 +1,6 @@
 pub mod color;
 pub mod font;
+pub(crate) mod glyph_cache;
 pub mod loader;
 #[cfg(feature = "jpeg")]
 pub mod tjpgd;
@@ -7,7 +8,7 @@ pub mod toif;
 
 use super::{
     constant,
-    geometry::{Offset, Point, Rect},
+    geometry::{Offset, Point, Rect, Rotation},
 };
 #[cfg(feature = "dma2d")]
 use crate::trezorhal::{
@@ -28,7 +29,7 @@ use crate::{time::Duration, trezorhal::time};
 
 use crate::{
     trezorhal::{buffers, display, uzlib::UzlibContext},
-    ui::lerp::Lerp,
+    ui::{context::settings, lerp::Lerp},
 };
 
 // Reexports
@@ -37,7 +38,8 @@ pub use crate::ui::display::toif::Icon;
 pub use color::Color;
 pub use font::{Font, Glyph, GlyphMetrics};
 pub use loader::{
-    loader, loader_indeterminate, loader_small_indeterminate, LOADER_MAX, LOADER_MIN,
+    loader, loader_indeterminate, loader_small_indeterminate, loader_small_indeterminate_at,
+    LOADER_MAX, LOADER_MIN,
 };
 
 #[cfg(all(feature = "dma2d", feature = "framebuffer"))]
@@ -51,6 +53,11 @@ pub fn backlight() -> u16 {
     display::backlight(-1) as u16
 }
 
+/// Current rotation of the display.
+pub fn rotation() -> Rotation {
+    Rotation::from_degrees(display::get_orientation()).unwrap_or(Rotation::R0)
+}
+
 #[cfg(feature = "backlight")]
 pub fn set_backlight(val: u16) {
     display::backlight(val as i32);
@@ -91,6 +98,7 @@ pub fn fade_backlight_duration(_: u16, _: u32) {}
 pub fn rect_fill(r: Rect, fg_color: Color) {
     let r = r.translate(get_offset());
     let r = r.clamp(constant::screen());
+    let fg_color = themed(fg_color);
 
     set_window(r);
 
@@ -107,6 +115,7 @@ pub fn rect_fill(r: Rect, fg_color: Color) {
 pub fn rect_fill(r: Rect, fg_color: Color) {
     let r = r.translate(get_offset());
     let r = r.clamp(constant::screen());
+    let fg_color = themed(fg_color);
     set_window(r);
     dma2d_setup_const();
     unsafe {
@@ -171,7 +180,7 @@ pub fn rect_fill_rounded(r: Rect, fg_color: Color, bg_color: Color, radius: u8)
     } else {
         assert!([2, 4, 8, 16].iter().any(|allowed| radius == *allowed));
 
-        let color_table = get_color_table(fg_color, bg_color);
+        let color_table = get_color_table(themed(fg_color), themed(bg_color));
         let area = r.translate(get_offset());
         let clamped = area.clamp(constant::screen());
 
@@ -269,7 +278,7 @@ pub fn rect_fill_rounded(area: Rect, fg_color: Color, bg_color: Color, radius: u
 
         rect_fill(r, fg_color);
         let r_inv = 16 / radius;
-        let color_table = get_color_table(fg_color, bg_color);
+        let color_table = get_color_table(themed(fg_color), themed(bg_color));
 
         for y in 0..radius {
             for x in 0..radius {
@@ -341,9 +350,14 @@ pub fn rect_fill_corners(r: Rect, fg_color: Color) {
     }
 }
 
-/// Draw black rectangle over entire screen.
+/// Draw black rectangle over entire screen, or a white one in the inverted
+/// theme variant.
 pub fn clear() {
-    display::clear();
+    if themed(Color::black()) == Color::black() {
+        display::clear();
+    } else {
+        rect_fill(constant::screen().translate(-get_offset()), Color::black());
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -400,8 +414,8 @@ impl<T: AsRef<str>> TextOverlay<T> {
         for g in self
             .text
             .as_ref()
-            .bytes()
-            .filter_map(|c| self.font.get_glyph(c))
+            .chars()
+            .filter_map(|c| self.font.get_char_glyph(c))
         {
             let top = self.max_height - self.baseline - g.bearing_y;
             let char_area = Rect::new(
@@ -487,6 +501,7 @@ pub fn rect_rounded2_partial(
 
     let r = area.translate(get_offset());
     let clamped = r.clamp(constant::screen());
+    let (fg_color, bg_color) = (themed(fg_color), themed(bg_color));
 
     set_window(clamped);
 
@@ -506,7 +521,7 @@ pub fn rect_rounded2_partial(
             icon_area = Rect::from_center_and_size(center, icon_size);
             icon_area_clamped = icon_area.clamp(constant::screen());
             icon.toif.uncompress(&mut icon_data);
-            icon_colortable = get_color_table(icon_color, bg_color);
+            icon_colortable = get_color_table(themed(icon_color), bg_color);
             icon_width = icon.toif.width();
             use_icon = true;
         }
@@ -723,6 +738,7 @@ pub fn text_over_image(
     let area;
     let offset_img_final;
     if let Some((a, color)) = bg_area {
+        let color = themed(color);
         let hi = color.hi_byte();
         let lo = color.lo_byte();
         //prefill image/bg buffers with the bg color
@@ -765,7 +781,7 @@ pub fn text_over_image(
     let mut window = [0; UZLIB_WINDOW_SIZE];
     let mut ctx = image.toif.decompression_context(Some(&mut window));
 
-    dma2d_setup_4bpp_over_16bpp(text_color.into());
+    dma2d_setup_4bpp_over_16bpp(themed(text_color).into());
 
     let mut i = 0;
 
@@ -877,7 +893,11 @@ pub fn icon_over_icon(
     let mut window_fg = [0; UZLIB_WINDOW_SIZE];
     let mut ctx_fg = UzlibContext::new(icon_fg.toif.zdata(), Some(&mut window_fg));
 
-    dma2d_setup_4bpp_over_4bpp(color_icon_bg.into(), bg_color.into(), color_icon_fg.into());
+    dma2d_setup_4bpp_over_4bpp(
+        themed(color_icon_bg).into(),
+        themed(bg_color).into(),
+        themed(color_icon_fg).into(),
+    );
 
     let mut fg_i = 0;
     let mut bg_i = 0;
@@ -1005,6 +1025,7 @@ pub fn bar_with_text_and_fill<T: AsRef<str>>(
 ) {
     let r = area.translate(get_offset());
     let clamped = r.clamp(constant::screen());
+    let (fg_color, bg_color) = (themed(fg_color), themed(bg_color));
     let colortable = get_color_table(fg_color, bg_color);
 
     set_window(clamped);
@@ -1041,7 +1062,8 @@ pub fn marquee(area: Rect, text: &str, offset: i16, font: Font, fg: Color, bg: C
     set_window(clamped);
 
     display::text_into_buffer(text, font.into(), &mut buffer, offset);
-    let tbl = get_color_table(fg, bg);
+    let bg = themed(bg);
+    let tbl = get_color_table(themed(fg), bg);
 
     for y in 0..clamped.height() {
         for x in 0..clamped.width() {
@@ -1072,54 +1094,47 @@ pub fn dotted_line(start: Point, width: i16, color: Color, step: i16) {
     }
 }
 
+/// Draw `text` starting at `baseline`. Text outside of ASCII goes through the
+/// Rust renderer, which looks its glyphs up per character the same way
+/// `Font::text_width` measures them, some may come from the translations.
+/// Short texts redrawn often, e.g. PIN digits or countdowns, also go through
+/// the Rust renderer for its glyph cache.
+fn draw_text(baseline: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
+    let cached = !text.is_empty() && text.chars().all(glyph_cache::is_cached_char);
+    if !cached && text.is_ascii() {
+        display::text(
+            baseline.x,
+            baseline.y,
+            text,
+            font.into(),
+            themed(fg_color).into(),
+            themed(bg_color).into(),
+        );
+    } else {
+        font.display_text(text, baseline, fg_color, bg_color);
+    }
+}
+
 /// Display text left-aligned to a certain Point
 pub fn text_left(baseline: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
-    display::text(
-        baseline.x,
-        baseline.y,
-        text,
-        font.into(),
-        fg_color.into(),
-        bg_color.into(),
-    );
+    draw_text(baseline, text, font, fg_color, bg_color);
 }
 
 /// Display text centered around a certain Point
 pub fn text_center(baseline: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
     let w = font.text_width(text);
-    display::text(
-        baseline.x - w / 2,
-        baseline.y,
-        text,
-        font.into(),
-        fg_color.into(),
-        bg_color.into(),
-    );
+    draw_text(baseline - Offset::x(w / 2), text, font, fg_color, bg_color);
 }
 
 /// Display text right-aligned to a certain Point
 pub fn text_right(baseline: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
     let w = font.text_width(text);
-    display::text(
-        baseline.x - w,
-        baseline.y,
-        text,
-        font.into(),
-        fg_color.into(),
-        bg_color.into(),
-    );
+    draw_text(baseline - Offset::x(w), text, font, fg_color, bg_color);
 }
 
 pub fn text_top_left(position: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
     let h = font.text_height();
-    display::text(
-        position.x,
-        position.y + h,
-        text,
-        font.into(),
-        fg_color.into(),
-        bg_color.into(),
-    );
+    draw_text(position + Offset::y(h), text, font, fg_color, bg_color);
 }
 
 #[inline(always)]
@@ -1136,6 +1151,11 @@ pub fn get_offset() -> Offset {
     Offset::new(offset.0, offset.1)
 }
 
+/// Shift everything drawn from now on by `offset`.
+pub fn set_offset(offset: Offset) {
+    display::set_offset(offset.x, offset.y);
+}
+
 pub fn set_window(window: Rect) {
     display::set_window(
         window.x0 as u16,
@@ -1153,6 +1173,14 @@ pub fn refresh() {
     display::refresh();
 }
 
+/// `color` as painted in the theme variant selected in the settings. The
+/// components give the colors of the normal variant, the primitives of this
+/// module apply the variant once, where the colors reach the display. Images
+/// keep their colors.
+pub fn themed(color: Color) -> Color {
+    settings().theme_variant.paint(color)
+}
+
 pub fn get_color_table(fg_color: Color, bg_color: Color) -> [Color; 16] {
     let mut table: [Color; 16] = [Color::from_u16(0); 16];
 
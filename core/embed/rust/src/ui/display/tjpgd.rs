@@ -1,4 +1,4 @@
-use trezor_tjpgdec::{BlackHoleOutput, JpegOutput};
+use trezor_tjpgdec::JpegOutput;
 pub use trezor_tjpgdec::{BufferInput, Error, JDEC};
 
 use crate::{
@@ -7,8 +7,8 @@ use crate::{
         display::pixeldata,
     },
     ui::{
-        constant,
-        display::set_window,
+        busy, constant,
+        display::{set_window, Color},
         geometry::{Offset, Point, Rect},
     },
 };
@@ -49,18 +49,38 @@ pub fn jpeg_test(data: &[u8]) -> bool {
             return false;
         }
 
-        let mut out = BlackHoleOutput;
-        let mut res = jd.decomp(&mut out);
-        while res == Err(Error::Interrupted) {
-            res = jd.decomp(&mut out);
-        }
-        res.is_ok()
+        // Checking a large image takes a while, the screen shown next paints
+        // over the spinner.
+        busy::run(constant::screen(), Color::white(), Color::black(), || {
+            let mut out = TestOutput;
+            let mut res = jd.decomp(&mut out);
+            while res == Err(Error::Interrupted) {
+                res = jd.decomp(&mut out);
+            }
+            res.is_ok()
+        })
     } else {
         false
     };
     result
 }
 
+/// Output of `jpeg_test`, drops the pixels and keeps the busy spinner going.
+struct TestOutput;
+
+impl JpegOutput for TestOutput {
+    fn write(
+        &mut self,
+        _jd: &JDEC,
+        _rect_origin: (u32, u32),
+        _rect_size: (u32, u32),
+        _bitmap: &[u16],
+    ) -> bool {
+        busy::tick();
+        true
+    }
+}
+
 pub struct BufferOutput {
     buffer: BufferJpeg,
     buffer_width: i16,
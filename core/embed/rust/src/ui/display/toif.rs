@@ -4,7 +4,7 @@ use crate::{
     ui::{
         component::image::Image,
         constant,
-        display::{get_offset, pixeldata_dirty, set_window},
+        display::{get_offset, pixeldata_dirty, set_window, themed},
         geometry::{Alignment2D, Offset, Point, Rect},
     },
 };
@@ -47,7 +47,7 @@ pub fn render_toif(toif: &Toif, center: Point, fg_color: Color, bg_color: Color)
     let r = Rect::from_center_and_size(center, toif.size());
     let area = r.translate(get_offset());
     let clamped = area.clamp(constant::screen());
-    let colortable = get_color_table(fg_color, bg_color);
+    let colortable = get_color_table(themed(fg_color), themed(bg_color));
 
     set_window(clamped);
 
@@ -92,7 +92,7 @@ pub fn render_toif(toif: &Toif, center: Point, fg_color: Color, bg_color: Color)
     let mut window = [0; UZLIB_WINDOW_SIZE];
     let mut ctx = toif.decompression_context(Some(&mut window));
 
-    dma2d_setup_4bpp(fg_color.into(), bg_color.into());
+    dma2d_setup_4bpp(themed(fg_color).into(), themed(bg_color).into());
 
     let x_shift = max(0, clamped.x0 - area.x0);
 
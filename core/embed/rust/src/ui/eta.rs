@@ -0,0 +1,145 @@
+use core::fmt::Write;
+
+use heapless::String;
+
+use crate::{
+    time::{Duration, Instant},
+    ui::{message_format::format_translated, translations::TranslatedString},
+};
+
+/// Do not estimate anything before the operation runs at least this long.
+const MIN_ELAPSED_MS: u32 = 1000;
+
+/// Estimates the remaining time of an operation from the average rate its
+/// progress value has been increasing with so far.
+pub struct RateTracker {
+    max: u16,
+    start: Option<(Instant, u16)>,
+    last: Option<(Instant, u16)>,
+}
+
+impl RateTracker {
+    pub const fn new(max: u16) -> Self {
+        Self {
+            max,
+            start: None,
+            last: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.start = None;
+        self.last = None;
+    }
+
+    /// Record that the progress has reached `value` at time `now`.
+    pub fn update(&mut self, value: u16, now: Instant) {
+        match self.last {
+            // Progress went backwards, the operation probably restarted.
+            Some((_, last)) if value < last => self.reset(),
+            _ => {}
+        }
+        if self.start.is_none() {
+            self.start = Some((now, value));
+        }
+        self.last = Some((now, value));
+    }
+
+    /// Record that the progress has reached `value` now and describe the
+    /// remaining time, see `format_remaining`. Empty while there is not
+    /// enough data.
+    pub fn update_text(&mut self, value: u16) -> String<24> {
+        self.update(value, Instant::now());
+        self.remaining().map(format_remaining).unwrap_or_default()
+    }
+
+    /// Estimated time until the value reaches the maximum, `None` if there is
+    /// not enough data yet.
+    pub fn remaining(&self) -> Option<Duration> {
+        let (start_time, start_value) = self.start?;
+        let (last_time, last_value) = self.last?;
+        let elapsed = last_time.saturating_duration_since(start_time).to_millis();
+        let done = last_value.saturating_sub(start_value);
+        if elapsed < MIN_ELAPSED_MS || done == 0 {
+            return None;
+        }
+        let left = self.max.saturating_sub(last_value) as u64;
+        let millis = elapsed as u64 * left / done as u64;
+        Some(Duration::from_millis(millis.min(u32::MAX as u64) as u32))
+    }
+}
+
+/// Human readable form of the remaining time, e.g. "About 5 s left".
+pub fn format_remaining(remaining: Duration) -> String<24> {
+    let secs = (remaining.to_millis() + 999) / 1000;
+    if secs < 60 {
+        format_translated(TranslatedString::TextSecondsLeft, &[secs.into()])
+    } else {
+        format_translated(
+            TranslatedString::TextMinutesLeft,
+            &[((secs + 59) / 60).into()],
+        )
+    }
+}
+
+/// Clock-like form of a countdown, e.g. "4:05", with hours only when needed.
+pub fn format_countdown(secs: u32) -> String<12> {
+    let mut text = String::new();
+    let (hours, minutes, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        unwrap!(write!(text, "{}:{:02}:{:02}", hours, minutes, secs));
+    } else {
+        unwrap!(write!(text, "{}:{:02}", minutes, secs));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining() {
+        let start = Instant::now();
+        let at = |ms| unwrap!(start.checked_add(Duration::from_millis(ms)));
+        let mut eta = RateTracker::new(1000);
+        assert!(eta.remaining().is_none());
+        eta.update(100, start);
+        eta.update(200, at(500));
+        // Not running long enough yet.
+        assert!(eta.remaining().is_none());
+        eta.update(300, at(1000));
+        // 200 per second, 700 to go.
+        assert_eq!(eta.remaining(), Some(Duration::from_millis(3500)));
+        // Going backwards starts over.
+        eta.update(0, at(1500));
+        assert!(eta.remaining().is_none());
+    }
+
+    #[test]
+    fn test_format_remaining() {
+        assert_eq!(
+            format_remaining(Duration::from_millis(0)).as_str(),
+            "About 0 s left"
+        );
+        assert_eq!(
+            format_remaining(Duration::from_millis(4200)).as_str(),
+            "About 5 s left"
+        );
+        assert_eq!(
+            format_remaining(Duration::from_secs(59)).as_str(),
+            "About 59 s left"
+        );
+        assert_eq!(
+            format_remaining(Duration::from_secs(61)).as_str(),
+            "About 2 min left"
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(0).as_str(), "0:00");
+        assert_eq!(format_countdown(245).as_str(), "4:05");
+        assert_eq!(format_countdown(32767).as_str(), "9:06:07");
+    }
+}
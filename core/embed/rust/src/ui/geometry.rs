@@ -506,6 +506,51 @@ impl Insets {
     }
 }
 
+/// Clockwise rotation of the content of the display with respect to the
+/// panel, e.g. `R180` for a device mounted upside down.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Rotation {
+    R0,
+    R90,
+    R180,
+    R270,
+}
+
+impl Rotation {
+    pub const fn from_degrees(degrees: i32) -> Option<Self> {
+        match degrees {
+            0 => Some(Self::R0),
+            90 => Some(Self::R90),
+            180 => Some(Self::R180),
+            270 => Some(Self::R270),
+            _ => None,
+        }
+    }
+
+    pub const fn degrees(self) -> i32 {
+        match self {
+            Self::R0 => 0,
+            Self::R90 => 90,
+            Self::R180 => 180,
+            Self::R270 => 270,
+        }
+    }
+
+    /// Whether the width and the height of the content are swapped.
+    pub const fn swaps_axes(self) -> bool {
+        matches!(self, Self::R90 | Self::R270)
+    }
+
+    /// Size of the content of a panel of `size`.
+    pub const fn rotate_size(self, size: Offset) -> Offset {
+        if self.swaps_axes() {
+            Offset::new(size.y, size.x)
+        } else {
+            size
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Alignment {
     Start,
@@ -520,6 +565,7 @@ impl Alignment2D {
     pub const TOP_RIGHT: Alignment2D = Alignment2D(Alignment::End, Alignment::Start);
     pub const TOP_CENTER: Alignment2D = Alignment2D(Alignment::Center, Alignment::Start);
     pub const CENTER: Alignment2D = Alignment2D(Alignment::Center, Alignment::Center);
+    pub const CENTER_LEFT: Alignment2D = Alignment2D(Alignment::Start, Alignment::Center);
     pub const BOTTOM_LEFT: Alignment2D = Alignment2D(Alignment::Start, Alignment::End);
     pub const BOTTOM_RIGHT: Alignment2D = Alignment2D(Alignment::End, Alignment::End);
     pub const BOTTOM_CENTER: Alignment2D = Alignment2D(Alignment::Center, Alignment::End);
@@ -746,8 +792,174 @@ impl LinearPlacement {
     }
 }
 
+/// Size constraint of one of the parts of `LinearSplit`, along its axis.
+#[derive(Copy, Clone)]
+pub struct Slot {
+    /// Share of the space left after all the parts get their minimum.
+    pub weight: u16,
+    pub min: i16,
+    pub max: i16,
+}
+
+impl Slot {
+    /// Part of exactly `size` pixels.
+    pub const fn fixed(size: i16) -> Self {
+        Self {
+            weight: 0,
+            min: size,
+            max: size,
+        }
+    }
+
+    /// Part getting `weight` shares of the free space.
+    pub const fn weighted(weight: u16) -> Self {
+        Self {
+            weight,
+            min: 0,
+            max: i16::MAX,
+        }
+    }
+
+    pub const fn with_min(self, min: i16) -> Self {
+        Self { min, ..self }
+    }
+
+    pub const fn with_max(self, max: i16) -> Self {
+        Self { max, ..self }
+    }
+}
+
+/// Splits an area into `N` consecutive parts along `axis`, sized according to
+/// their `Slot`s.
+#[derive(Copy, Clone)]
+pub struct LinearSplit<const N: usize> {
+    pub axis: Axis,
+    pub spacing: i16,
+    pub slots: [Slot; N],
+}
+
+impl<const N: usize> LinearSplit<N> {
+    pub const fn new(axis: Axis, slots: [Slot; N]) -> Self {
+        Self {
+            axis,
+            spacing: 0,
+            slots,
+        }
+    }
+
+    /// Parts stacked from the top to the bottom.
+    pub const fn vertical(slots: [Slot; N]) -> Self {
+        Self::new(Axis::Vertical, slots)
+    }
+
+    /// Parts ordered from the left to the right.
+    pub const fn horizontal(slots: [Slot; N]) -> Self {
+        Self::new(Axis::Horizontal, slots)
+    }
+
+    pub const fn with_spacing(self, spacing: i16) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// Sizes of the parts when splitting `available` pixels. Every part gets
+    /// its minimum first, the rest is divided by the weights, without letting
+    /// any part grow over its maximum. In case the minimums do not fit, the
+    /// parts at the end overflow `available`.
+    pub fn sizes(&self, available: i16) -> [i16; N] {
+        let mut sizes = self.slots.map(|slot| slot.min);
+        let mut free = available - self.spacing * (N as i16 - 1).max(0) - sizes.iter().sum::<i16>();
+        // Parts that are still allowed to grow.
+        let mut growing = self
+            .slots
+            .map(|slot| slot.weight > 0 && slot.min < slot.max);
+        while free > 0 {
+            let total_weight: i32 = (0..N)
+                .filter(|&i| growing[i])
+                .map(|i| self.slots[i].weight as i32)
+                .sum();
+            if total_weight == 0 {
+                break;
+            }
+            let mut given = 0;
+            let mut capped = false;
+            let mut last = None;
+            for i in (0..N).filter(|&i| growing[i]) {
+                let share = (free as i32 * self.slots[i].weight as i32 / total_weight) as i16;
+                let room = self.slots[i].max - sizes[i];
+                if share >= room {
+                    sizes[i] += room;
+                    given += room;
+                    growing[i] = false;
+                    capped = true;
+                } else {
+                    sizes[i] += share;
+                    given += share;
+                    last = Some(i);
+                }
+            }
+            free -= given;
+            if !capped {
+                // Shares are rounded down, the last part gets the leftover.
+                if let Some(i) = last {
+                    sizes[i] += free;
+                }
+                break;
+            }
+        }
+        sizes
+    }
+
+    /// Split `area` into the parts.
+    pub fn split(&self, area: Rect) -> [Rect; N] {
+        let sizes = self.sizes(area.size().axis(self.axis));
+        let mut cursor = 0;
+        sizes.map(|size| {
+            let top_left = area.top_left() + Offset::on_axis(self.axis, cursor);
+            let extent = Offset::on_axis(self.axis, size)
+                + Offset::on_axis(self.axis.cross(), area.size().axis(self.axis.cross()));
+            cursor += size + self.spacing;
+            Rect::from_top_left_and_size(top_left, extent)
+        })
+    }
+}
+
 /// Types that can place themselves within area specified by `bounds`.
 pub trait Dimensions {
     fn fit(&mut self, bounds: Rect);
     fn area(&self) -> Rect;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_split() {
+        let split = LinearSplit::vertical([
+            Slot::fixed(20),
+            Slot::weighted(1),
+            Slot::weighted(2).with_max(30),
+        ])
+        .with_spacing(5);
+        assert_eq!(split.sizes(100), [20, 40, 30]);
+        assert_eq!(split.sizes(200), [20, 140, 30]);
+        assert_eq!(split.sizes(20), [20, 0, 0]);
+
+        let split = LinearSplit::horizontal([Slot::weighted(1), Slot::weighted(1)]);
+        assert_eq!(split.sizes(11), [5, 6]);
+        let [left, right] = split.split(Rect::from_top_left_and_size(
+            Point::new(10, 10),
+            Offset::new(11, 4),
+        ));
+        assert!(left == Rect::new(Point::new(10, 10), Point::new(15, 14)));
+        assert!(right == Rect::new(Point::new(15, 10), Point::new(21, 14)));
+    }
+
+    #[test]
+    fn rotation() {
+        let size = Offset::new(128, 64);
+        assert_eq!(Rotation::R90.rotate_size(size), Offset::new(64, 128));
+        assert_eq!(Rotation::R180.rotate_size(size), size);
+        assert!(Rotation::from_degrees(45).is_none());
+    }
+}
@@ -0,0 +1,317 @@
+//! Short help about the screen shown, opened by holding both buttons, or a
+//! corner of the touch screen, for a while. The component on screen provides
+//! the text through `Component::help`, the `Root` of the layout recognizes the
+//! gesture and paints the help over the whole screen until the next press of
+//! any button or touch.
+
+use crate::{
+    time::Duration,
+    ui::{
+        component::{base::input_end, text::util::text_multiline, Event, EventCtx, TimerToken},
+        display,
+        geometry::{Alignment, Insets, Rect},
+        theme::Theme,
+        translations::TranslatedString,
+    },
+};
+
+#[cfg(feature = "button")]
+use crate::ui::event::{ButtonEvent, PhysicalButton};
+#[cfg(feature = "touch")]
+use crate::ui::{event::TouchEvent, geometry::Point};
+
+#[cfg(all(feature = "model_tr", not(feature = "model_tt")))]
+use super::model_tr::theme;
+#[cfg(feature = "model_tt")]
+use super::model_tt::theme;
+
+/// How long the gesture has to be held. Longer than any hold to confirm, so
+/// that holding both buttons confirms before the help would open.
+const HOLD: Duration = Duration::from_millis(3000);
+/// Size of the corners of the touch screen that open the help.
+#[cfg(feature = "touch")]
+const CORNER_SIZE: i16 = 32;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum State {
+    Idle,
+    Holding(TimerToken),
+    /// The help is on screen. `dismissing` once a press started after it was
+    /// opened, the help closes when it ends.
+    Shown {
+        dismissing: bool,
+    },
+}
+
+/// What the `Root` should do with an event, see `HelpGesture::event`.
+pub enum HelpAction {
+    /// Deliver the event to the component.
+    Pass,
+    /// Leave the event out, it belongs to the help.
+    Swallow,
+    /// Open the help. The event is left out, the component gets the one
+    /// given instead, if any, ending the touch it has seen starting.
+    Show(Option<Event<'static>>),
+    /// Close the help. The component should forget the input it has seen
+    /// before it was opened and paint itself again.
+    Dismiss,
+}
+
+pub struct HelpGesture {
+    state: State,
+    #[cfg(feature = "button")]
+    left_down: bool,
+    #[cfg(feature = "button")]
+    right_down: bool,
+    /// Where the touch holding a corner started.
+    #[cfg(feature = "touch")]
+    touch: Option<Point>,
+}
+
+impl HelpGesture {
+    pub const fn new() -> Self {
+        Self {
+            state: State::Idle,
+            #[cfg(feature = "button")]
+            left_down: false,
+            #[cfg(feature = "button")]
+            right_down: false,
+            #[cfg(feature = "touch")]
+            touch: None,
+        }
+    }
+
+    pub fn is_shown(&self) -> bool {
+        matches!(self.state, State::Shown { .. })
+    }
+
+    /// Follow `event` on a screen of `area`. The gesture only starts when the
+    /// component `has_help`.
+    pub fn event(
+        &mut self,
+        ctx: &mut EventCtx,
+        event: Event,
+        area: Rect,
+        has_help: bool,
+    ) -> HelpAction {
+        // Followed while the help is shown too, its releases belong to it.
+        #[cfg(feature = "button")]
+        match event {
+            Event::Button(ButtonEvent::ButtonPressed(button)) => self.set_down(button, true),
+            Event::Button(ButtonEvent::ButtonReleased(button)) => self.set_down(button, false),
+            _ => {}
+        }
+        if let State::Shown { dismissing } = self.state {
+            return match input_end(event) {
+                Some(false) => {
+                    self.state = State::Shown { dismissing: true };
+                    HelpAction::Swallow
+                }
+                Some(true) if dismissing => {
+                    self.state = State::Idle;
+                    HelpAction::Dismiss
+                }
+                Some(true) => HelpAction::Swallow,
+                None => HelpAction::Pass,
+            };
+        }
+        match event {
+            Event::Timer(token) if self.state == State::Holding(token) => {
+                self.state = State::Shown { dismissing: false };
+                HelpAction::Show(self.cancel_event(area))
+            }
+            #[cfg(feature = "button")]
+            Event::Button(ButtonEvent::ButtonPressed(_)) => {
+                if has_help && self.left_down && self.right_down {
+                    self.state = State::Holding(ctx.request_timer(HOLD));
+                }
+                HelpAction::Pass
+            }
+            #[cfg(feature = "button")]
+            Event::Button(ButtonEvent::ButtonReleased(_)) => {
+                self.state = State::Idle;
+                HelpAction::Pass
+            }
+            #[cfg(feature = "touch")]
+            Event::Touch(TouchEvent::TouchStart(point)) => {
+                if has_help && in_corner(area, point) {
+                    self.touch = Some(point);
+                    self.state = State::Holding(ctx.request_timer(HOLD));
+                }
+                HelpAction::Pass
+            }
+            #[cfg(feature = "touch")]
+            Event::Touch(TouchEvent::TouchMove(point)) => {
+                if self.touch.is_some() && !in_corner(area, point) {
+                    self.touch = None;
+                    self.state = State::Idle;
+                }
+                HelpAction::Pass
+            }
+            #[cfg(feature = "touch")]
+            Event::Touch(TouchEvent::TouchEnd(_)) => {
+                self.touch = None;
+                self.state = State::Idle;
+                HelpAction::Pass
+            }
+            _ => HelpAction::Pass,
+        }
+    }
+
+    #[cfg(feature = "button")]
+    fn set_down(&mut self, button: PhysicalButton, down: bool) {
+        match button {
+            PhysicalButton::Left => self.left_down = down,
+            PhysicalButton::Right => self.right_down = down,
+        }
+    }
+
+    /// End of the touch holding a corner, outside of the screen so that
+    /// nothing gets clicked and close enough to the start not to be a swipe.
+    #[cfg(feature = "touch")]
+    fn cancel_event(&mut self, area: Rect) -> Option<Event<'static>> {
+        let start = self.touch.take()?;
+        let center = area.center();
+        let x = if start.x < center.x {
+            area.x0 - 1
+        } else {
+            area.x1
+        };
+        let y = if start.y < center.y {
+            area.y0 - 1
+        } else {
+            area.y1
+        };
+        Some(Event::Touch(TouchEvent::TouchEnd(Point::new(x, y))))
+    }
+
+    /// Buttons need nothing, the component forgets them when the help closes.
+    #[cfg(not(feature = "touch"))]
+    fn cancel_event(&mut self, _area: Rect) -> Option<Event<'static>> {
+        None
+    }
+}
+
+#[cfg(feature = "touch")]
+fn in_corner(area: Rect, point: Point) -> bool {
+    let near_edge = |p, low, high| p < low + CORNER_SIZE || p >= high - CORNER_SIZE;
+    area.contains(point)
+        && near_edge(point.x, area.x0, area.x1)
+        && near_edge(point.y, area.y0, area.y1)
+}
+
+/// Paint `text` over the whole `area`, under a title.
+pub fn paint(area: Rect, text: TranslatedString) {
+    let theme = theme::current();
+    let (fg, bg) = (theme.fg(), theme.bg());
+    display::rect_fill(area, bg);
+    let area = area.inset(Insets::uniform(theme.content_padding()));
+    let rest = TranslatedString::TitleHelp.map_translated(|title| {
+        text_multiline(area, title, theme.font_bold(), fg, bg, Alignment::Start)
+    });
+    if let Some(rest) = rest {
+        text.map_translated(|text| {
+            text_multiline(rest, text, theme.font_normal(), fg, bg, Alignment::Start)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        component::{Component, Root},
+        constant::screen,
+        testing::{press, release, touch_end, touch_start, Harness},
+    };
+
+    /// `Clicked` at the end of a press or touch, `Cancelled` if the touch
+    /// ended off the screen.
+    #[derive(Debug, PartialEq)]
+    enum Msg {
+        Clicked,
+        Cancelled,
+    }
+
+    struct Screen {
+        help: Option<TranslatedString>,
+    }
+
+    impl Component for Screen {
+        type Msg = Msg;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Msg> {
+            match event {
+                Event::Button(ButtonEvent::ButtonReleased(_)) => Some(Msg::Clicked),
+                Event::Touch(TouchEvent::TouchEnd(point)) if !screen().contains(point) => {
+                    Some(Msg::Cancelled)
+                }
+                Event::Touch(TouchEvent::TouchEnd(_)) => Some(Msg::Clicked),
+                _ => None,
+            }
+        }
+
+        fn paint(&mut self) {}
+
+        fn help(&self) -> Option<TranslatedString> {
+            self.help
+        }
+    }
+
+    fn harness(help: Option<TranslatedString>) -> Harness<Root<Screen>> {
+        Harness::new(Root::new(Screen { help }))
+    }
+
+    #[test]
+    fn buttons() {
+        let mut root = harness(Some(TranslatedString::HelpPin));
+        root.events([press(PhysicalButton::Left), press(PhysicalButton::Right)]);
+        assert!(root.fire_timers().is_none());
+        // The release of the gesture and the next press belong to the help.
+        root.expect_no_msg(release(PhysicalButton::Left));
+        root.expect_no_msg(release(PhysicalButton::Right));
+        root.expect_no_msg(press(PhysicalButton::Left));
+        root.expect_no_msg(release(PhysicalButton::Left));
+        assert_eq!(root.click(PhysicalButton::Right), Some(Msg::Clicked));
+
+        // Released before the time is up.
+        let mut root = harness(Some(TranslatedString::HelpPin));
+        root.events([press(PhysicalButton::Left), press(PhysicalButton::Right)]);
+        assert_eq!(
+            root.event(release(PhysicalButton::Left)),
+            Some(Msg::Clicked)
+        );
+        assert!(root.fire_timers().is_none());
+        assert_eq!(
+            root.event(release(PhysicalButton::Right)),
+            Some(Msg::Clicked)
+        );
+
+        // Nothing to help with.
+        let mut root = harness(None);
+        root.events([press(PhysicalButton::Left), press(PhysicalButton::Right)]);
+        assert!(root.timers().is_empty());
+    }
+
+    #[test]
+    fn touch() {
+        let mut root = harness(Some(TranslatedString::HelpPin));
+        let corner = Point::new(screen().x1 - 5, 5);
+        root.event(touch_start(corner.x, corner.y));
+        // The screen sees its touch ending off the screen.
+        assert_eq!(root.fire_timers(), Some(Msg::Cancelled));
+        root.expect_no_msg(touch_end(corner.x, corner.y));
+        root.expect_no_msg(touch_start(100, 100));
+        root.expect_no_msg(touch_end(100, 100));
+        assert_eq!(root.tap(Point::new(100, 100)), Some(Msg::Clicked));
+
+        // Not in a corner.
+        let mut root = harness(Some(TranslatedString::HelpPin));
+        root.event(touch_start(100, 5));
+        assert!(root.timers().is_empty());
+    }
+}
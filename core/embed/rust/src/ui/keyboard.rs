@@ -0,0 +1,62 @@
+//! Letter layout of the on-screen keyboards, a preference of the user. The
+//! touch keyboard groups the letters into its multi-tap keys in the order of
+//! the layout, the choice keyboard offers them in that order.
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    /// Alphabetical order.
+    Abc,
+    Qwerty,
+    Azerty,
+    Qwertz,
+}
+
+impl KeyboardLayout {
+    /// Layout of a value of the `KeyboardLayout` protobuf enum.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Abc),
+            1 => Some(Self::Qwerty),
+            2 => Some(Self::Azerty),
+            3 => Some(Self::Qwertz),
+            _ => None,
+        }
+    }
+
+    /// All the letters of the English alphabet in the order of the layout.
+    pub const fn letters(self, uppercase: bool) -> &'static str {
+        match (self, uppercase) {
+            (Self::Abc, false) => "abcdefghijklmnopqrstuvwxyz",
+            (Self::Abc, true) => "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            (Self::Qwerty, false) => "qwertyuiopasdfghjklzxcvbnm",
+            (Self::Qwerty, true) => "QWERTYUIOPASDFGHJKLZXCVBNM",
+            (Self::Azerty, false) => "azertyuiopqsdfghjklmwxcvbn",
+            (Self::Azerty, true) => "AZERTYUIOPQSDFGHJKLMWXCVBN",
+            (Self::Qwertz, false) => "qwertzuiopasdfghjklyxcvbnm",
+            (Self::Qwertz, true) => "QWERTZUIOPASDFGHJKLYXCVBNM",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters() {
+        for layout in [
+            KeyboardLayout::Abc,
+            KeyboardLayout::Qwerty,
+            KeyboardLayout::Azerty,
+            KeyboardLayout::Qwertz,
+        ] {
+            // Every layout is a permutation of the alphabet.
+            let mut lower: Vec<char> = layout.letters(false).chars().collect();
+            lower.sort_unstable();
+            assert!(lower.into_iter().eq('a'..='z'));
+            assert_eq!(layout.letters(false).to_uppercase(), layout.letters(true));
+        }
+        assert!(KeyboardLayout::from_u8(3) == Some(KeyboardLayout::Qwertz));
+        assert!(KeyboardLayout::from_u8(4).is_none());
+    }
+}
@@ -1,3 +1,6 @@
 pub mod obj;
+pub mod queue;
 pub mod result;
+#[cfg(feature = "ui_debug")]
+pub mod tree;
 pub mod util;
@@ -1,8 +1,11 @@
 use core::{
-    cell::RefCell,
+    cell::{RefCell, RefMut},
     convert::{TryFrom, TryInto},
+    ptr,
 };
 
+use heapless::Vec;
+
 use crate::{
     error::Error,
     maybe_trace::MaybeTrace,
@@ -17,18 +20,25 @@ use crate::{
     },
     time::Duration,
     ui::{
-        component::{Component, Event, EventCtx, Never, Root, TimerToken},
+        budget,
+        component::{AttrUpdate, Component, Event, EventCtx, Never, Root, TimerToken},
         constant,
         display::sync,
         geometry::Rect,
+        layout::{
+            queue::{EventQueue, TimerSchedule},
+            util::iter_into_array,
+        },
+        profiler, resume,
+        stats::{self, Recording, Stats},
     },
 };
 
-#[cfg(feature = "button")]
-use crate::ui::event::ButtonEvent;
 #[cfg(feature = "touch")]
 use crate::ui::event::TouchEvent;
 use crate::ui::event::USBEvent;
+#[cfg(feature = "button")]
+use crate::ui::{button_quirks, event::ButtonEvent};
 
 /// Conversion trait implemented by components that know how to convert their
 /// message values into MicroPython `Obj`s.
@@ -50,6 +60,9 @@ pub trait ObjComponent: MaybeTrace {
     fn obj_bounds(&self, _sink: &mut dyn FnMut(Rect)) {}
     fn obj_skip_paint(&mut self) {}
     fn obj_request_clear(&mut self) {}
+    fn obj_update_attr(&mut self, _ctx: &mut EventCtx, _attr: AttrUpdate) -> bool {
+        false
+    }
 }
 
 impl<T> ObjComponent for Root<T>
@@ -69,7 +82,7 @@ where
     }
 
     fn obj_paint(&mut self) -> bool {
-        let will_paint = self.inner().will_paint();
+        let will_paint = self.will_paint();
         self.paint();
         will_paint
     }
@@ -86,22 +99,161 @@ where
     fn obj_request_clear(&mut self) {
         self.clear_screen()
     }
+
+    fn obj_update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.update_attr(ctx, attr)
+    }
 }
 
+/// Maximum number of overlays stacked over the root component.
+const MAX_OVERLAYS: usize = 4;
+
 /// `LayoutObj` is a GC-allocated object exported to MicroPython, with type
 /// `LayoutObj::obj_type()`. It wraps a root component through the
 /// `ObjComponent` trait.
+///
+/// Other layouts can be pushed over the root as overlays. The topmost overlay
+/// receives the input events and is painted over everything below it, the
+/// underlying layers keep their state and keep receiving the non-input events
+/// (timers, USB, progress).
+///
+/// Events go through an `EventQueue` together with the timers that are due at
+/// the time, so that a burst of timers is handled in one go and painted once,
+/// with input first.
 #[repr(C)]
 pub struct LayoutObj {
     base: ObjBase,
     inner: RefCell<LayoutObjInner>,
+    stats: RefCell<Stats>,
 }
 
 struct LayoutObjInner {
     root: Gc<dyn ObjComponent>,
+    overlays: Vec<Gc<LayoutObj>, MAX_OVERLAYS>,
     event_ctx: EventCtx,
     timer_fn: Obj,
+    timers: TimerSchedule,
     page_count: u16,
+    /// Page shown, as last reported by the components.
+    active_page: u16,
+}
+
+impl LayoutObjInner {
+    /// Place the root component and all the overlays on the screen.
+    fn place(&mut self) {
+        // SAFETY: `self.root` is unique because we are borrowed mutably.
+        unsafe { Gc::as_mut(&mut self.root) }.obj_place(constant::root());
+        for overlay in &self.overlays {
+            overlay.obj_root_place();
+        }
+    }
+
+    /// Send the event to the layers that should receive it. Input events only
+    /// go to the topmost layer, other events go to all of them. Returns the
+    /// first message any of the layers raised.
+    fn dispatch_event(&mut self, event: Event) -> Result<Obj, Error> {
+        let input = is_input_event(event);
+        let mut msg = Obj::const_none();
+        if !input || self.overlays.is_empty() {
+            // SAFETY: `self.root` is unique because we are borrowed mutably.
+            msg = unsafe { Gc::as_mut(&mut self.root) }.obj_event(&mut self.event_ctx, event)?;
+        }
+        let top = self.overlays.len().saturating_sub(1);
+        for (i, overlay) in self.overlays.iter().enumerate() {
+            if input && i != top {
+                continue;
+            }
+            let overlay_msg = overlay.obj_root_event(&mut self.event_ctx, event)?;
+            if msg == Obj::const_none() {
+                msg = overlay_msg;
+            }
+        }
+        Ok(msg)
+    }
+
+    /// Run one event pass over the component tree.
+    fn event_pass(&mut self, event: Event) -> Result<Obj, Error> {
+        // Place the root component on the screen in case it was previously requested.
+        if self.event_ctx.needs_place_before_next_event_or_paint() {
+            self.place();
+        }
+
+        // Clear the leftover flags from the previous event pass.
+        self.event_ctx.clear();
+
+        // Send the event down the component tree. Bail out in case of failure.
+        let msg = self.dispatch_event(event)?;
+
+        // All concerning `Child` wrappers should have already marked themselves for
+        // painting by now, and we're prepared for a paint pass.
+
+        self.drain_timers()?;
+
+        if let Some(count) = self.event_ctx.page_count() {
+            self.page_count = count as u16;
+        }
+        self.update_active_page();
+
+        Ok(msg)
+    }
+
+    fn update_active_page(&mut self) {
+        if let Some(page) = self.event_ctx.active_page() {
+            if page as u16 != self.active_page {
+                resume::update_page(page as u16);
+            }
+            self.active_page = page as u16;
+        }
+    }
+
+    /// Drain any pending timers into the callback, except for the ones the
+    /// event loop does not need to know about.
+    fn drain_timers(&mut self) -> Result<(), Error> {
+        while let Some((token, deadline)) = self.event_ctx.pop_timer() {
+            self.schedule_timer(token, deadline)?;
+        }
+        Ok(())
+    }
+
+    /// Hand the timer over to the event loop, unless it already has it.
+    fn schedule_timer(&mut self, token: TimerToken, deadline: Duration) -> Result<(), Error> {
+        if !self.timers.schedule(token, deadline) {
+            return Ok(());
+        }
+        let token = token.try_into();
+        let deadline = deadline.try_into();
+        if let (Ok(token), Ok(deadline)) = (token, deadline) {
+            self.timer_fn.call_with_n_args(&[token, deadline])?;
+        } else {
+            // Failed to convert token or deadline into `Obj`, skip.
+        }
+        Ok(())
+    }
+
+    /// Keep the timers left in `queue` after an event produced a message, so
+    /// that they are not lost once the event loop fires them.
+    fn keep_undelivered(&mut self, queue: &mut EventQueue) -> Result<(), Error> {
+        while let Some(event) = queue.pop() {
+            let Event::Timer(token) = event else {
+                continue;
+            };
+            if !self.timers.undeliver(token) {
+                // Fired by the event loop already, it has to be asked again.
+                self.schedule_timer(token, Duration::ZERO)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_input_event(event: Event) -> bool {
+    match event {
+        #[cfg(feature = "button")]
+        Event::Button(_) => true,
+        #[cfg(feature = "touch")]
+        Event::Touch(_) => true,
+        _ => false,
+    }
 }
 
 impl LayoutObj {
@@ -110,6 +262,7 @@ impl LayoutObj {
         // Let's wrap the root component into a `Root` to maintain the top-level
         // invalidation logic.
         let wrapped_root = Root::new(root);
+        profiler::reset();
         // SAFETY: We are coercing GC-allocated sized ptr into an unsized one.
         let root =
             unsafe { Gc::from_raw(Gc::into_raw(Gc::new(wrapped_root)?) as *mut dyn ObjComponent) };
@@ -118,13 +271,24 @@ impl LayoutObj {
             base: Self::obj_type().as_base(),
             inner: RefCell::new(LayoutObjInner {
                 root,
+                overlays: Vec::new(),
                 event_ctx: EventCtx::new(),
                 timer_fn: Obj::const_none(),
+                timers: TimerSchedule::new(),
                 page_count: 1,
+                active_page: 0,
             }),
+            // Buffers filled while the components were constructed.
+            stats: RefCell::new(stats::take()),
         })
     }
 
+    /// Record the resource usage into the stats of this layout until the
+    /// returned guard is dropped.
+    fn record_stats(&self) -> Recording<RefMut<Stats>> {
+        stats::enter(self.stats.borrow_mut())
+    }
+
     pub fn skip_first_paint(&self) {
         let mut inner = self.inner.borrow_mut();
 
@@ -138,45 +302,112 @@ impl LayoutObj {
         self.inner.borrow_mut().timer_fn = timer_fn;
     }
 
-    /// Run an event pass over the component tree. After the traversal, any
+    /// Run an event pass over the component tree for the event and for each of
+    /// the timers that are due, see `EventQueue`. After each traversal, any
     /// pending timers are drained into `self.timer_callback`. Returns `Err`
     /// in case the timer callback raises or one of the components returns
-    /// an error, `Ok` with the message otherwise.
+    /// an error, `Ok` with the first message otherwise.
     fn obj_event(&self, event: Event) -> Result<Obj, Error> {
+        profiler::measure_event(|| budget::measure("event", || self.obj_event_pass(event)))
+    }
+
+    fn obj_event_pass(&self, event: Event) -> Result<Obj, Error> {
+        let _stats = self.record_stats();
         let inner = &mut *self.inner.borrow_mut();
 
-        // Place the root component on the screen in case it was previously requested.
-        if inner.event_ctx.needs_place_before_next_event_or_paint() {
-            // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
-            unsafe { Gc::as_mut(&mut inner.root) }.obj_place(constant::screen());
+        if let Event::Timer(token) = event {
+            if !inner.timers.fire(token) {
+                // Already handled together with an earlier event.
+                return Ok(Obj::const_none());
+            }
+        }
+        let mut queue = EventQueue::new();
+        queue.push(event);
+        inner.timers.take_due(&mut queue);
+
+        while let Some(event) = queue.pop() {
+            let msg = inner.event_pass(event)?;
+            if msg != Obj::const_none() {
+                inner.keep_undelivered(&mut queue)?;
+                return Ok(msg);
+            }
         }
+        Ok(Obj::const_none())
+    }
 
-        // Clear the leftover flags from the previous event pass.
+    /// Push `overlay` over the current topmost layer. The overlay layout is
+    /// owned by the stack from now on and must not be used on its own.
+    fn obj_push_overlay(&self, overlay: Gc<LayoutObj>) -> Result<(), Error> {
+        let _stats = self.record_stats();
+        if ptr::eq(self, &*overlay) {
+            return Err(value_error!("Cannot push layout over itself."));
+        }
+        let inner = &mut *self.inner.borrow_mut();
+        overlay.obj_root_place();
         inner.event_ctx.clear();
+        let msg = overlay.obj_root_event(&mut inner.event_ctx, Event::Attach)?;
+        if msg != Obj::const_none() {
+            return Err(value_error!("Overlay finished when attached."));
+        }
+        inner
+            .overlays
+            .push(overlay)
+            .map_err(|_| value_error!("Too many overlays."))?;
+        stats::record_buffer("LayoutObj::overlays", inner.overlays.len(), MAX_OVERLAYS);
+        inner.drain_timers()
+    }
 
-        // Send the event down the component tree. Bail out in case of failure.
+    /// Remove the topmost overlay and make sure the layers below get
+    /// completely repainted. Returns `false` if there was nothing to pop.
+    fn obj_pop_overlay(&self) -> Result<bool, Error> {
+        let _stats = self.record_stats();
+        let inner = &mut *self.inner.borrow_mut();
+        if inner.overlays.pop().is_none() {
+            return Ok(false);
+        }
+        inner.event_ctx.clear();
+        let msg = inner.dispatch_event(Event::RequestPaint)?;
+        if msg != Obj::const_none() {
+            return Err(value_error!("Layout finished when repainted."));
+        }
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
-        let msg = unsafe { Gc::as_mut(&mut inner.root) }.obj_event(&mut inner.event_ctx, event)?;
+        unsafe { Gc::as_mut(&mut inner.root) }.obj_request_clear();
+        inner.drain_timers()?;
+        Ok(true)
+    }
 
-        // All concerning `Child` wrappers should have already marked themselves for
-        // painting by now, and we're prepared for a paint pass.
+    /// Change an attribute of the live root component, without constructing
+    /// the layout again. Fails in case the component does not support the
+    /// attribute.
+    fn obj_update_attr(&self, attr: AttrUpdate) -> Result<(), Error> {
+        let _stats = self.record_stats();
+        let inner = &mut *self.inner.borrow_mut();
 
-        // Drain any pending timers into the callback.
-        while let Some((token, deadline)) = inner.event_ctx.pop_timer() {
-            let token = token.try_into();
-            let deadline = deadline.try_into();
-            if let (Ok(token), Ok(deadline)) = (token, deadline) {
-                inner.timer_fn.call_with_n_args(&[token, deadline])?;
-            } else {
-                // Failed to convert token or deadline into `Obj`, skip.
-            }
+        if inner.event_ctx.needs_place_before_next_event_or_paint() {
+            inner.place();
         }
+        inner.event_ctx.clear();
 
-        if let Some(count) = inner.event_ctx.page_count() {
-            inner.page_count = count as u16;
+        // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+        if !unsafe { Gc::as_mut(&mut inner.root) }.obj_update_attr(&mut inner.event_ctx, attr) {
+            return Err(value_error!("Attribute not supported by layout."));
         }
+        inner.update_active_page();
+        inner.drain_timers()
+    }
 
-        Ok(msg)
+    fn obj_root_place(&self) {
+        let mut inner = self.inner.borrow_mut();
+        // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+        unsafe { Gc::as_mut(&mut inner.root) }.obj_place(constant::root());
+    }
+
+    /// Deliver the event to the root component only, using event context of
+    /// the layout this one is stacked on.
+    fn obj_root_event(&self, ctx: &mut EventCtx, event: Event) -> Result<Obj, Error> {
+        let mut inner = self.inner.borrow_mut();
+        // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
+        unsafe { Gc::as_mut(&mut inner.root) }.obj_event(ctx, event)
     }
 
     fn obj_request_clear(&self) {
@@ -188,18 +419,43 @@ impl LayoutObj {
     /// Run a paint pass over the component tree. Returns true if any component
     /// actually requested painting since last invocation of the function.
     fn obj_paint_if_requested(&self) -> bool {
+        profiler::measure_paint(|| budget::measure("paint", || self.obj_paint_pass()))
+    }
+
+    fn obj_paint_pass(&self) -> bool {
+        let _stats = self.record_stats();
         let mut inner = self.inner.borrow_mut();
 
-        // Place the root component on the screen in case it was previously requested.
+        // Place the root component and the overlays on the screen in case it was
+        // previously requested.
         if inner.event_ctx.needs_place_before_next_event_or_paint() {
-            // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
-            unsafe { Gc::as_mut(&mut inner.root) }.obj_place(constant::screen());
+            inner.place();
         }
 
         sync();
 
         // SAFETY: `inner.root` is unique because of the `inner.borrow_mut()`.
-        unsafe { Gc::as_mut(&mut inner.root) }.obj_paint()
+        let mut painted = unsafe { Gc::as_mut(&mut inner.root) }.obj_paint();
+
+        // Whenever a layer paints, it could have overpainted the overlays above it,
+        // so repaint them as well.
+        for overlay in &inner.overlays {
+            if painted {
+                let mut dummy_ctx = EventCtx::new();
+                let msg = unwrap!(overlay.obj_root_event(&mut dummy_ctx, Event::RequestPaint));
+                assert!(msg == Obj::const_none());
+            }
+            let mut overlay_inner = overlay.inner.borrow_mut();
+            // SAFETY: `overlay_inner.root` is unique because of the `borrow_mut()`.
+            painted |= unsafe { Gc::as_mut(&mut overlay_inner.root) }.obj_paint();
+        }
+
+        // Painting ran out of time, come back for the rest with the next frame.
+        if budget::take_deferred() {
+            inner.event_ctx.request_anim_frame();
+            unwrap!(inner.drain_timers());
+        }
+        painted
     }
 
     /// Run a tracing pass over the component tree. Passed `callback` is called
@@ -207,11 +463,11 @@ impl LayoutObj {
     /// raises an exception.
     #[cfg(feature = "ui_debug")]
     fn obj_trace(&self, callback: Obj) {
-        use crate::trace::JsonTracer;
+        use crate::trace::{JsonTracer, JsonWriter};
 
-        let mut tracer = JsonTracer::new(|text: &str| {
+        let mut tracer = JsonTracer::new(JsonWriter::new(|text: &str| {
             unwrap!(callback.call_with_n_args(&[unwrap!(text.try_into())]));
-        });
+        }));
 
         // For Reasons(tm), we must pass a closure in which we call `root.trace(t)`,
         // instead of passing `root` into the tracer.
@@ -221,15 +477,67 @@ impl LayoutObj {
         // claim that it implements `Trace`, and we also can't upcast it to `&dyn Trace`
         // because trait upcasting is unstable.
         // Luckily, calling `root.trace()` works perfectly fine in spite of the above.)
-        tracer.root(&|t| {
-            self.inner.borrow().root.trace(t);
-        });
+        tracer.root(&|t| self.trace_topmost(t));
+    }
+
+    /// Run a tracing pass like `obj_trace`, in the compact binary encoding of
+    /// `BinaryWriter`. Passed `callback` is called with the encoding in pieces
+    /// of bytes. Panics in case the callback raises an exception.
+    #[cfg(feature = "ui_debug")]
+    fn obj_trace_binary(&self, callback: Obj) {
+        use crate::trace::{BinaryTracer, BinaryWriter};
+
+        fn flush(callback: Obj, chunk: &mut Vec<u8, 256>) {
+            unwrap!(callback.call_with_n_args(&[unwrap!(chunk.as_slice().try_into())]));
+            chunk.clear();
+        }
+
+        // Collect the bytes, most tokens are only a few of them long.
+        let mut chunk = Vec::new();
+        let mut tracer = BinaryTracer::new(BinaryWriter::new(|bytes: &[u8]| {
+            for &byte in bytes {
+                if chunk.push(byte).is_err() {
+                    flush(callback, &mut chunk);
+                    unwrap!(chunk.push(byte));
+                }
+            }
+        }));
+        tracer.root(&|t| self.trace_topmost(t));
+        flush(callback, &mut chunk);
+    }
+
+    /// Trace the topmost layer, that is the one the user interacts with.
+    #[cfg(feature = "ui_debug")]
+    fn trace_topmost(&self, t: &mut dyn crate::trace::Tracer) {
+        match self.inner.borrow().overlays.last() {
+            Some(overlay) => overlay.inner.borrow().root.trace(t),
+            None => self.inner.borrow().root.trace(t),
+        }
+        #[cfg(feature = "ui_stats")]
+        self.stats.borrow().trace(t);
+    }
+
+    /// Build the component tree of the topmost layer out of dicts and lists,
+    /// the same structure `obj_trace` serializes into JSON. Panics in case an
+    /// allocation fails.
+    #[cfg(feature = "ui_debug")]
+    fn obj_debug_tree(&self) -> Obj {
+        use crate::ui::layout::tree::ObjTracer;
+
+        ObjTracer::root(&|t| match self.inner.borrow().overlays.last() {
+            Some(overlay) => overlay.inner.borrow().root.trace(t),
+            None => self.inner.borrow().root.trace(t),
+        })
     }
 
     fn obj_page_count(&self) -> Obj {
         self.inner.borrow().page_count.into()
     }
 
+    fn obj_active_page(&self) -> Obj {
+        self.inner.borrow().active_page.into()
+    }
+
     #[cfg(feature = "ui_debug")]
     fn obj_bounds(&self) {
         use crate::ui::display;
@@ -260,8 +568,15 @@ impl LayoutObj {
                 Qstr::MP_QSTR_paint => obj_fn_1!(ui_layout_paint).as_obj(),
                 Qstr::MP_QSTR_request_complete_repaint => obj_fn_1!(ui_layout_request_complete_repaint).as_obj(),
                 Qstr::MP_QSTR_trace => obj_fn_2!(ui_layout_trace).as_obj(),
+                Qstr::MP_QSTR_trace_binary => obj_fn_2!(ui_layout_trace_binary).as_obj(),
                 Qstr::MP_QSTR_bounds => obj_fn_1!(ui_layout_bounds).as_obj(),
+                Qstr::MP_QSTR_debug_tree => obj_fn_1!(ui_layout_debug_tree).as_obj(),
                 Qstr::MP_QSTR_page_count => obj_fn_1!(ui_layout_page_count).as_obj(),
+                Qstr::MP_QSTR_active_page => obj_fn_1!(ui_layout_active_page).as_obj(),
+                Qstr::MP_QSTR_push_overlay => obj_fn_2!(ui_layout_push_overlay).as_obj(),
+                Qstr::MP_QSTR_pop_overlay => obj_fn_1!(ui_layout_pop_overlay).as_obj(),
+                Qstr::MP_QSTR_update => obj_fn_3!(ui_layout_update).as_obj(),
+                Qstr::MP_QSTR_language_changed => obj_fn_1!(ui_layout_language_changed).as_obj(),
             }),
         };
         &TYPE
@@ -351,6 +666,7 @@ extern "C" fn ui_layout_touch_event(n_args: usize, args: *const Obj) -> Obj {
             args[2].try_into()?,
             args[3].try_into()?,
         )?;
+        // Touches come already rotated into the coordinates of the content.
         let msg = this.obj_event(Event::Touch(event))?;
         Ok(msg)
     };
@@ -370,8 +686,12 @@ extern "C" fn ui_layout_button_event(n_args: usize, args: *const Obj) -> Obj {
         }
         let this: Gc<LayoutObj> = args[0].try_into()?;
         let event = ButtonEvent::new(args[1].try_into()?, args[2].try_into()?)?;
-        let msg = this.obj_event(Event::Button(event))?;
-        Ok(msg)
+        let mut msg = Ok(Obj::const_none());
+        button_quirks::apply(event, &mut |event| {
+            msg = this.obj_event(Event::Button(event));
+            matches!(msg, Ok(m) if m == Obj::const_none())
+        });
+        msg
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
 }
@@ -395,6 +715,37 @@ extern "C" fn ui_layout_progress_event(n_args: usize, args: *const Obj) -> Obj {
     unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
 }
 
+extern "C" fn ui_layout_update(this: Obj, attr: Obj, value: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let attr: Qstr = attr.try_into()?;
+        let text: StrBuffer;
+        let item_value: StrBuffer;
+        let update = match attr {
+            Qstr::MP_QSTR_progress => AttrUpdate::Progress(value.try_into()?),
+            Qstr::MP_QSTR_attempts => AttrUpdate::Attempts(value.try_into()?),
+            Qstr::MP_QSTR_text => {
+                text = value.try_into()?;
+                AttrUpdate::Text(text.as_ref())
+            }
+            Qstr::MP_QSTR_item if value == Obj::const_none() => AttrUpdate::End,
+            Qstr::MP_QSTR_item => {
+                [text, item_value] = iter_into_array(value)?;
+                AttrUpdate::Item(text.as_ref(), item_value.as_ref())
+            }
+            Qstr::MP_QSTR_page => AttrUpdate::Page(value.try_into()?),
+            Qstr::MP_QSTR_toast => {
+                text = value.try_into()?;
+                AttrUpdate::Toast(text.as_ref())
+            }
+            _ => return Err(Error::AttributeError(attr)),
+        };
+        this.obj_update_attr(update)?;
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 extern "C" fn ui_layout_usb_event(n_args: usize, args: *const Obj) -> Obj {
     let block = |args: &[Obj], _kwargs: &Map| {
         if args.len() != 2 {
@@ -444,6 +795,21 @@ extern "C" fn ui_layout_request_complete_repaint(this: Obj) -> Obj {
     unsafe { util::try_or_raise(block) }
 }
 
+extern "C" fn ui_layout_language_changed(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let msg = this.obj_event(Event::LanguageChanged)?;
+        if msg != Obj::const_none() {
+            // Same as with `RequestPaint`, messages are not expected here.
+            #[cfg(feature = "ui_debug")]
+            panic!("cannot raise messages during LanguageChanged");
+        };
+        this.obj_request_clear();
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 extern "C" fn ui_layout_page_count(this: Obj) -> Obj {
     let block = || {
         let this: Gc<LayoutObj> = this.try_into()?;
@@ -452,6 +818,33 @@ extern "C" fn ui_layout_page_count(this: Obj) -> Obj {
     unsafe { util::try_or_raise(block) }
 }
 
+extern "C" fn ui_layout_active_page(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        Ok(this.obj_active_page())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+extern "C" fn ui_layout_push_overlay(this: Obj, overlay: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let overlay: Gc<LayoutObj> = overlay.try_into()?;
+        this.obj_push_overlay(overlay)?;
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+extern "C" fn ui_layout_pop_overlay(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        let popped = this.obj_pop_overlay()?.into();
+        Ok(popped)
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 #[cfg(feature = "ui_debug")]
 #[no_mangle]
 pub extern "C" fn ui_debug_layout_type() -> &'static Type {
@@ -473,6 +866,35 @@ extern "C" fn ui_layout_trace(_this: Obj, _callback: Obj) -> Obj {
     Obj::const_none()
 }
 
+#[cfg(feature = "ui_debug")]
+extern "C" fn ui_layout_trace_binary(this: Obj, callback: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        this.obj_trace_binary(callback);
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(not(feature = "ui_debug"))]
+extern "C" fn ui_layout_trace_binary(_this: Obj, _callback: Obj) -> Obj {
+    Obj::const_none()
+}
+
+#[cfg(feature = "ui_debug")]
+extern "C" fn ui_layout_debug_tree(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        Ok(this.obj_debug_tree())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(not(feature = "ui_debug"))]
+extern "C" fn ui_layout_debug_tree(_this: Obj) -> Obj {
+    Obj::const_none()
+}
+
 #[cfg(feature = "ui_bounds")]
 extern "C" fn ui_layout_bounds(this: Obj) -> Obj {
     let block = || {
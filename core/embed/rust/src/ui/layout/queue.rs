@@ -0,0 +1,216 @@
+use heapless::Vec;
+
+use crate::{
+    time::{Duration, Instant},
+    ui::component::{Event, EventCtx, TimerToken},
+};
+
+/// Most events delivered to a layout in one go: the one coming from the
+/// event loop and the timers that are due along with it.
+const MAX_QUEUED: usize = 8;
+/// Most timers a layout keeps track of, more are still delivered but one
+/// event pass each.
+const MAX_SCHEDULED: usize = 8;
+
+/// Delivery order of queued events, the highest first. Input goes before
+/// everything else so that it is not held back behind a burst of timers, and
+/// the animation frame goes last because only the latest state is worth
+/// painting.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Priority {
+    AnimFrame,
+    Timer,
+    Host,
+    Input,
+}
+
+impl Priority {
+    fn of(event: &Event) -> Self {
+        match event {
+            #[cfg(feature = "button")]
+            Event::Button(_) => Self::Input,
+            #[cfg(feature = "touch")]
+            Event::Touch(_) => Self::Input,
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => Self::AnimFrame,
+            Event::Timer(_) => Self::Timer,
+            _ => Self::Host,
+        }
+    }
+}
+
+/// Events waiting to be delivered to a layout, taken out by priority and in
+/// the order of arrival within one priority. A timer that is already queued
+/// is not queued again.
+pub struct EventQueue<'a> {
+    events: Vec<Event<'a>, MAX_QUEUED>,
+}
+
+impl<'a> EventQueue<'a> {
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Returns false if the queue is full and the event has been dropped.
+    pub fn push(&mut self, event: Event<'a>) -> bool {
+        if matches!(event, Event::Timer(_)) && self.events.contains(&event) {
+            return true;
+        }
+        self.events.push(event).is_ok()
+    }
+
+    pub fn pop(&mut self) -> Option<Event<'a>> {
+        let mut next: Option<(usize, Priority)> = None;
+        for (i, event) in self.events.iter().enumerate() {
+            let priority = Priority::of(event);
+            if next.map_or(true, |(_, p)| priority > p) {
+                next = Some((i, priority));
+            }
+        }
+        let (i, _) = next?;
+        // Keep the order of the rest.
+        self.events[i..].rotate_left(1);
+        self.events.pop()
+    }
+}
+
+/// Timers a layout has handed over to the event loop, with their deadlines,
+/// so that the ones already due can be delivered in the same pass as the
+/// event that is being handled instead of one pass and one paint each.
+pub struct TimerSchedule {
+    scheduled: Vec<(TimerToken, Instant), MAX_SCHEDULED>,
+    /// Delivered early, the event loop is still going to fire them.
+    delivered: Vec<TimerToken, MAX_SCHEDULED>,
+}
+
+impl TimerSchedule {
+    pub const fn new() -> Self {
+        Self {
+            scheduled: Vec::new(),
+            delivered: Vec::new(),
+        }
+    }
+
+    /// Record a timer requested by the layout. Returns false if the event loop
+    /// does not have to be told, which is the case of an animation frame when
+    /// the event loop already has one.
+    pub fn schedule(&mut self, token: TimerToken, deadline: Duration) -> bool {
+        let Some(at) = Instant::now().checked_add(deadline) else {
+            return true;
+        };
+        if token == EventCtx::ANIM_FRAME_TIMER {
+            if self.scheduled.iter().any(|(t, _)| *t == token) {
+                return false;
+            }
+            if let Some(i) = self.delivered.iter().position(|t| *t == token) {
+                // Reuse the frame delivered early, it is due in the event loop.
+                self.delivered.swap_remove(i);
+                unwrap!(self.scheduled.push((token, at)).ok());
+                return false;
+            }
+        }
+        // Timers that do not fit are not tracked, only delivered on their own.
+        let _ = self.scheduled.push((token, at));
+        true
+    }
+
+    /// The event loop fired `token`. Returns false if it has been delivered
+    /// early already and should be ignored.
+    pub fn fire(&mut self, token: TimerToken) -> bool {
+        if let Some(i) = self.delivered.iter().position(|t| *t == token) {
+            self.delivered.swap_remove(i);
+            return false;
+        }
+        if let Some(i) = self.scheduled.iter().position(|(t, _)| *t == token) {
+            self.scheduled.swap_remove(i);
+        }
+        true
+    }
+
+    /// Put `token`, delivered early, back among the scheduled ones, e.g. when
+    /// an earlier event in the queue produced a message and the rest was not
+    /// delivered. Returns false if it has not been delivered early.
+    pub fn undeliver(&mut self, token: TimerToken) -> bool {
+        let Some(i) = self.delivered.iter().position(|t| *t == token) else {
+            return false;
+        };
+        self.delivered.swap_remove(i);
+        // Taken out of `scheduled` when delivered, so there is room.
+        unwrap!(self.scheduled.push((token, Instant::now())).ok());
+        true
+    }
+
+    /// Move the timers that are due into `queue`.
+    pub fn take_due(&mut self, queue: &mut EventQueue) {
+        let now = Instant::now();
+        let mut i = 0;
+        while i < self.scheduled.len() {
+            let (token, at) = self.scheduled[i];
+            if at <= now && !self.delivered.is_full() && queue.push(Event::Timer(token)) {
+                unwrap!(self.delivered.push(token).ok());
+                self.scheduled.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::event::ButtonEvent;
+
+    #[test]
+    fn priority() {
+        let timer = Event::Timer(TimerToken::from_raw(5));
+        let button = Event::Button(unwrap!(ButtonEvent::new(1, 0).ok()));
+
+        let mut queue = EventQueue::new();
+        assert!(queue.push(Event::Timer(EventCtx::ANIM_FRAME_TIMER)));
+        assert!(queue.push(timer));
+        assert!(queue.push(Event::Timer(EventCtx::ANIM_FRAME_TIMER)));
+        assert!(queue.push(button));
+        assert!(queue.pop() == Some(button));
+        assert!(queue.pop() == Some(timer));
+        // The second animation frame has been coalesced into the first one.
+        assert!(queue.pop() == Some(Event::Timer(EventCtx::ANIM_FRAME_TIMER)));
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn anim_frame_reused() {
+        let mut timers = TimerSchedule::new();
+        assert!(timers.schedule(EventCtx::ANIM_FRAME_TIMER, Duration::ZERO));
+        assert!(!timers.schedule(EventCtx::ANIM_FRAME_TIMER, Duration::ZERO));
+
+        let mut queue = EventQueue::new();
+        timers.take_due(&mut queue);
+        assert!(queue.pop() == Some(Event::Timer(EventCtx::ANIM_FRAME_TIMER)));
+        // Requested again before the event loop fired the one delivered early.
+        assert!(!timers.schedule(EventCtx::ANIM_FRAME_TIMER, Duration::ZERO));
+        assert!(timers.fire(EventCtx::ANIM_FRAME_TIMER));
+    }
+
+    #[test]
+    fn message_while_timer_due() {
+        let timer = TimerToken::from_raw(5);
+        let button = Event::Button(unwrap!(ButtonEvent::new(1, 0).ok()));
+        let mut timers = TimerSchedule::new();
+        assert!(timers.schedule(timer, Duration::ZERO));
+
+        let mut queue = EventQueue::new();
+        assert!(queue.push(button));
+        timers.take_due(&mut queue);
+        // The button produced a message, the timer is left undelivered.
+        assert!(queue.pop() == Some(button));
+        assert!(queue.pop() == Some(Event::Timer(timer)));
+        assert!(timers.undeliver(timer));
+        // Delivered when the event loop fires it, and due again for the next
+        // event.
+        let mut queue = EventQueue::new();
+        timers.take_due(&mut queue);
+        assert!(queue.pop() == Some(Event::Timer(timer)));
+        assert!(!timers.fire(timer));
+        assert!(!timers.undeliver(timer));
+    }
+}
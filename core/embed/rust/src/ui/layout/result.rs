@@ -1,12 +1,23 @@
-use crate::micropython::{
-    obj::{Obj, ObjBase},
-    qstr::Qstr,
-    typ::Type,
+use core::convert::TryFrom;
+
+use crate::{
+    error::Error,
+    micropython::{
+        ffi,
+        gc::Gc,
+        obj::{Obj, ObjBase},
+        qstr::Qstr,
+        typ::Type,
+        util,
+    },
 };
 
+/// Singleton object a layout returns to say how it was finished, e.g.
+/// `CONFIRMED`. Exposes its name in the `name` attribute.
 #[repr(C)]
 pub struct ResultObj {
     base: ObjBase,
+    name: Qstr,
 }
 
 impl ResultObj {
@@ -18,21 +29,164 @@ impl ResultObj {
         //  - There's nothing to mutate.
         unsafe { Obj::from_ptr(self as *const _ as *mut _) }
     }
+
+    pub fn name(&self) -> Qstr {
+        self.name
+    }
+
+    /// Find the result singleton behind `obj`, if it is one.
+    pub fn from_obj(obj: Obj) -> Option<&'static Self> {
+        RESULTS
+            .iter()
+            .copied()
+            .find(|result| result.as_obj().as_ptr() == obj.as_ptr())
+    }
+
+    /// Find the result singleton called `name`.
+    pub fn by_name(name: Qstr) -> Option<&'static Self> {
+        RESULTS.iter().copied().find(|result| result.name == name)
+    }
 }
 
 // SAFETY: We are in a single-threaded environment.
 unsafe impl Sync for ResultObj {}
 
-static CONFIRMED_TYPE: Type = obj_type! { name: Qstr::MP_QSTR_CONFIRMED, };
-static CANCELLED_TYPE: Type = obj_type! { name: Qstr::MP_QSTR_CANCELLED, };
-static INFO_TYPE: Type = obj_type! { name: Qstr::MP_QSTR_INFO, };
+unsafe extern "C" fn result_obj_attr(self_in: Obj, attr: ffi::qstr, dest: *mut Obj) {
+    let block = || {
+        let this = ResultObj::from_obj(self_in).ok_or(Error::TypeError)?;
+        let attr = Qstr::from_u16(attr as _);
 
-pub static CONFIRMED: ResultObj = ResultObj {
-    base: CONFIRMED_TYPE.as_base(),
-};
-pub static CANCELLED: ResultObj = ResultObj {
-    base: CANCELLED_TYPE.as_base(),
-};
-pub static INFO: ResultObj = ResultObj {
-    base: INFO_TYPE.as_base(),
-};
+        unsafe {
+            if dest.read().is_null() && attr == Qstr::MP_QSTR_name {
+                dest.write(this.name.into());
+                Ok(())
+            } else {
+                Err(Error::AttributeError(attr))
+            }
+        }
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+/// Define the result singletons together with their types and the table
+/// mapping their names to them.
+macro_rules! results {
+    ($($result:ident: $typ:ident = $name:expr,)+) => {
+        $(
+            static $typ: Type = obj_type! {
+                name: $name,
+                attr_fn: result_obj_attr,
+            };
+
+            pub static $result: ResultObj = ResultObj {
+                base: $typ.as_base(),
+                name: $name,
+            };
+        )+
+
+        /// All the result singletons, usable for looking them up by name.
+        pub static RESULTS: &[&ResultObj] = &[$(&$result),+];
+    };
+}
+
+results! {
+    CONFIRMED: CONFIRMED_TYPE = Qstr::MP_QSTR_CONFIRMED,
+    CANCELLED: CANCELLED_TYPE = Qstr::MP_QSTR_CANCELLED,
+    INFO: INFO_TYPE = Qstr::MP_QSTR_INFO,
+    SELECTED: SELECTED_TYPE = Qstr::MP_QSTR_SELECTED,
+    MORE: MORE_TYPE = Qstr::MP_QSTR_MORE,
+}
+
+/// Result carrying a value, e.g. the index of the item the user selected.
+/// Python reads `kind` (one of the singletons above), its `name` and the
+/// `value`.
+#[repr(C)]
+pub struct ValueResultObj {
+    base: ObjBase,
+    kind: &'static ResultObj,
+    value: Obj,
+}
+
+impl ValueResultObj {
+    pub fn new(kind: &'static ResultObj, value: Obj) -> Result<Gc<Self>, Error> {
+        Gc::new(Self {
+            base: Self::obj_type().as_base(),
+            kind,
+            value,
+        })
+    }
+
+    pub fn kind(&self) -> &'static ResultObj {
+        self.kind
+    }
+
+    pub fn value(&self) -> Obj {
+        self.value
+    }
+
+    fn obj_type() -> &'static Type {
+        static TYPE: Type = obj_type! {
+            name: Qstr::MP_QSTR_ValueResult,
+            attr_fn: value_result_obj_attr,
+        };
+        &TYPE
+    }
+
+    fn getattr(&self, attr: Qstr) -> Result<Obj, Error> {
+        match attr {
+            Qstr::MP_QSTR_kind => Ok(self.kind.as_obj()),
+            Qstr::MP_QSTR_name => Ok(self.kind.name.into()),
+            Qstr::MP_QSTR_value => Ok(self.value),
+            _ => Err(Error::AttributeError(attr)),
+        }
+    }
+}
+
+/// Wrap `value` into a result object of the given kind.
+pub fn with_value(kind: &'static ResultObj, value: impl Into<Obj>) -> Result<Obj, Error> {
+    Ok(ValueResultObj::new(kind, value.into())?.into())
+}
+
+impl From<Gc<ValueResultObj>> for Obj {
+    fn from(value: Gc<ValueResultObj>) -> Self {
+        // SAFETY:
+        //  - `value` is GC-allocated.
+        //  - `value` is `repr(C)`.
+        //  - `value` has a `base` as the first field with the correct type.
+        unsafe { Self::from_ptr(Gc::into_raw(value).cast()) }
+    }
+}
+
+impl TryFrom<Obj> for Gc<ValueResultObj> {
+    type Error = Error;
+
+    fn try_from(value: Obj) -> Result<Self, Self::Error> {
+        if ValueResultObj::obj_type().is_type_of(value) {
+            // SAFETY: We assume that if `value` is an object pointer with the correct type,
+            // it is always GC-allocated.
+            let this = unsafe { Gc::from_raw(value.as_ptr().cast()) };
+            Ok(this)
+        } else {
+            Err(Error::TypeError)
+        }
+    }
+}
+
+unsafe extern "C" fn value_result_obj_attr(self_in: Obj, attr: ffi::qstr, dest: *mut Obj) {
+    let block = || {
+        let this = Gc::<ValueResultObj>::try_from(self_in)?;
+        let attr = Qstr::from_u16(attr as _);
+
+        unsafe {
+            if dest.read().is_null() {
+                // Load attribute.
+                dest.write(this.getattr(attr)?);
+                Ok(())
+            } else {
+                // Results are immutable.
+                Err(Error::AttributeError(attr))
+            }
+        }
+    };
+    unsafe { util::try_or_raise(block) }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    micropython::{dict::Dict, gc::Gc, list::List, obj::Obj},
+    trace::{ListTracer, Trace, Tracer},
+};
+
+/// Tracer building the component tree out of MicroPython dicts and lists,
+/// so that it can be inspected from Python without parsing the JSON output
+/// of `JsonTracer`. Panics in case an allocation fails.
+pub struct ObjTracer {
+    dict: Gc<Dict>,
+}
+
+impl ObjTracer {
+    fn new() -> Self {
+        Self {
+            dict: unwrap!(Dict::alloc_with_capacity(4)),
+        }
+    }
+
+    /// Run `block` over a fresh dict and return it.
+    pub fn root(block: &dyn Fn(&mut dyn Tracer)) -> Obj {
+        let mut tracer = Self::new();
+        block(&mut tracer);
+        tracer.dict.into()
+    }
+
+    fn set(&mut self, key: &str, value: Obj) {
+        // SAFETY: The dict is not referenced from anywhere else until the tracing
+        // is done.
+        let map = unsafe { Gc::as_mut(&mut self.dict) }.map_mut();
+        unwrap!(map.set(unwrap!(Obj::try_from(key)), value));
+    }
+}
+
+impl Tracer for ObjTracer {
+    fn child(&mut self, key: &str, value: &dyn Trace) {
+        Tracer::in_child(self, key, &|t| value.trace(t));
+    }
+
+    fn int(&mut self, key: &str, i: i64) {
+        self.set(key, unwrap!(Obj::try_from(i)));
+    }
+
+    fn string(&mut self, key: &str, s: &str) {
+        self.set(key, unwrap!(Obj::try_from(s)));
+    }
+
+    fn bool(&mut self, key: &str, b: bool) {
+        self.set(key, b.into());
+    }
+
+    fn null(&mut self, key: &str) {
+        self.set(key, Obj::const_none());
+    }
+
+    fn in_child(&mut self, key: &str, block: &dyn Fn(&mut dyn Tracer)) {
+        self.set(key, ObjTracer::root(block));
+    }
+
+    fn in_list(&mut self, key: &str, block: &dyn Fn(&mut dyn ListTracer)) {
+        self.set(key, ObjListTracer::root(block));
+    }
+}
+
+/// Counterpart of `ObjTracer` for list items.
+pub struct ObjListTracer {
+    list: Gc<List>,
+}
+
+impl ObjListTracer {
+    fn root(block: &dyn Fn(&mut dyn ListTracer)) -> Obj {
+        let mut tracer = Self {
+            list: unwrap!(List::with_capacity(4)),
+        };
+        block(&mut tracer);
+        tracer.list.into()
+    }
+
+    fn append(&mut self, value: Obj) {
+        // SAFETY: The list is not referenced from anywhere else until the tracing
+        // is done.
+        unwrap!(unsafe { Gc::as_mut(&mut self.list) }.append(value));
+    }
+}
+
+impl ListTracer for ObjListTracer {
+    fn child(&mut self, value: &dyn Trace) {
+        ListTracer::in_child(self, &|t| value.trace(t));
+    }
+
+    fn int(&mut self, i: i64) {
+        self.append(unwrap!(Obj::try_from(i)));
+    }
+
+    fn string(&mut self, s: &str) {
+        self.append(unwrap!(Obj::try_from(s)));
+    }
+
+    fn bool(&mut self, b: bool) {
+        self.append(b.into());
+    }
+
+    fn in_child(&mut self, block: &dyn Fn(&mut dyn Tracer)) {
+        self.append(ObjTracer::root(block));
+    }
+
+    fn in_list(&mut self, block: &dyn Fn(&mut dyn ListTracer)) {
+        self.append(ObjListTracer::root(block));
+    }
+}
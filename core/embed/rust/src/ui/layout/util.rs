@@ -1,21 +1,43 @@
+#[cfg(feature = "button")]
+use crate::ui::button_quirks::{set_button_quirks, ButtonQuirks};
 use crate::{
     error::Error,
     micropython::{
-        buffer::{hexlify_bytes, StrBuffer},
+        buffer::{get_buffer, hexlify_bytes, StrBuffer},
         gc::Gc,
         iter::IterBuf,
         list::List,
+        map::Map,
         obj::Obj,
-        util::try_or_raise,
+        qstr::Qstr,
+        util::{try_or_raise, try_with_args_and_kwargs},
     },
     storage::{get_avatar_len, load_avatar},
-    strutil::SkipPrefix,
+    strutil::{ct_eq, SkipPrefix},
+    time::Duration,
     ui::{
-        component::text::{
-            paragraphs::{Paragraph, ParagraphSource},
-            TextStyle,
+        accessibility::Accessibility,
+        autodim::{set_autodim, AutoDim},
+        carry,
+        component::{
+            payment_uri::{PaymentRequest, PaymentUri},
+            settings_diff::{SettingChange, MAX_CHANGES},
+            template::{self, ConfirmTemplate, TemplateRow},
+            text::{
+                paragraphs::{Paragraph, ParagraphSource},
+                TextStyle,
+            },
         },
+        context::update_settings,
+        density::Density,
+        keyboard::KeyboardLayout,
+        profiler,
+        random::set_replay_seed,
+        resume::{self, ResumePoint},
+        theme::ThemeVariant,
+        translations::installed,
         util::set_animation_disabled,
+        wallet_badge::Wallet,
     },
 };
 use heapless::Vec;
@@ -44,6 +66,65 @@ where
     Ok(vec)
 }
 
+/// Changes for a `SettingsDiff` from an iterable of `(name, old value, new
+/// value, reduces security)` tuples.
+pub fn get_setting_changes(
+    iterable: Obj,
+) -> Result<Vec<SettingChange<StrBuffer>, MAX_CHANGES>, Error> {
+    let mut changes = Vec::new();
+    for item in IterBuf::new().try_iterate(iterable)? {
+        let [label, old, new, risky]: [Obj; 4] = iter_into_array(item)?;
+        let change = SettingChange {
+            label: label.try_into()?,
+            old: old.try_into()?,
+            new: new.try_into()?,
+            risky: risky.try_into()?,
+        };
+        changes
+            .push(change)
+            .map_err(|_| value_error!("Too many changes"))?;
+    }
+    Ok(changes)
+}
+
+/// Template of a confirmation from the `title`, `rows` of `(key, value)`
+/// tuples, `warning`, `verb` and `verb_cancel` keyword arguments.
+pub fn get_template(kwargs: &Map) -> Result<ConfirmTemplate<StrBuffer>, Error> {
+    let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+    let warning: bool = kwargs.get_or(Qstr::MP_QSTR_warning, false)?;
+    let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "CONFIRM".into())?;
+    let verb_cancel: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb_cancel, "CANCEL".into())?;
+    let mut rows: Vec<TemplateRow<StrBuffer>, { template::MAX_ROWS }> = Vec::new();
+    for item in IterBuf::new().try_iterate(kwargs.get(Qstr::MP_QSTR_rows)?)? {
+        let [key, value]: [StrBuffer; 2] = iter_into_array(item)?;
+        rows.push(TemplateRow { key, value })
+            .map_err(|_| value_error!("Too many template rows"))?;
+    }
+    ConfirmTemplate::new(title, rows, warning, verb, verb_cancel)
+}
+
+/// Fields of the payment URI in the `uri` keyword argument, of the `scheme` one
+/// or `bitcoin`. Raises ValueError for a malformed URI.
+pub fn get_payment_request(
+    kwargs: &Map,
+    key_style: &'static TextStyle,
+    value_style: &'static TextStyle,
+) -> Result<PaymentRequest<StrBuffer>, Error> {
+    let uri: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_uri)?;
+    let scheme: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_scheme, "bitcoin".into())?;
+    let parsed = PaymentUri::parse(uri.as_ref(), scheme.as_ref())?;
+    let optional = |text: Option<&str>| text.map(StrBuffer::alloc).transpose();
+    Ok(PaymentRequest::new(
+        StrBuffer::alloc(parsed.address)?,
+        optional(parsed.amount)?,
+        optional(parsed.label.as_deref())?,
+        optional(parsed.message.as_deref())?,
+        uri,
+        key_style,
+        value_style,
+    ))
+}
+
 /// Maximum number of characters that can be displayed on screen at once. Used
 /// for on-the-fly conversion of binary data to hexadecimal representation.
 /// NOTE: can be fine-tuned for particular model screen to decrease memory
@@ -79,6 +160,31 @@ impl TryFrom<Obj> for StrOrBytes {
     }
 }
 
+/// Immutable bytes object kept by a component, e.g. the data of a
+/// `BlobView`.
+#[derive(Clone, Copy)]
+pub struct ObjBytes(Obj);
+
+impl TryFrom<Obj> for ObjBytes {
+    type Error = Error;
+
+    fn try_from(obj: Obj) -> Result<Self, Error> {
+        if obj.is_bytes() {
+            Ok(Self(obj))
+        } else {
+            Err(Error::TypeError)
+        }
+    }
+}
+
+impl AsRef<[u8]> for ObjBytes {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: Only bytes objects are accepted, which are never modified in
+        // MicroPython, and no mutable reference is taken here.
+        unsafe { get_buffer(self.0) }.unwrap_or(&[])
+    }
+}
+
 pub struct ConfirmBlob {
     pub description: StrBuffer,
     pub extra: StrBuffer,
@@ -95,7 +201,7 @@ impl ParagraphSource for ConfirmBlob {
         match index {
             0 => Paragraph::new(self.description_font, self.description.skip_prefix(offset)),
             1 => Paragraph::new(self.extra_font, self.extra.skip_prefix(offset)),
-            2 => Paragraph::new(self.data_font, self.data.as_str_offset(offset)),
+            2 => Paragraph::new(self.data_font, self.data.as_str_offset(offset)).keep_whole(),
             _ => unreachable!(),
         }
     }
@@ -173,6 +279,9 @@ impl ParagraphSource for PropsList {
 
             if obj == key && value != Obj::const_none() {
                 Ok(para.no_break())
+            } else if index % 2 == 1 && value_is_mono {
+                // Addresses, amounts and other data are not split across pages.
+                Ok(para.keep_whole())
             } else {
                 Ok(para)
             }
@@ -196,6 +305,230 @@ pub extern "C" fn upy_disable_animation(disable: Obj) -> Obj {
     unsafe { try_or_raise(block) }
 }
 
+pub extern "C" fn upy_disable_transitions(disable: Obj) -> Obj {
+    let block = || {
+        let disable: bool = disable.try_into()?;
+        update_settings(|s| s.transitions_disabled = disable);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_accessibility(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let accessibility = Accessibility {
+            reduce_motion: kwargs.get_or(Qstr::MP_QSTR_reduce_motion, false)?,
+            longer_timeouts: kwargs.get_or(Qstr::MP_QSTR_longer_timeouts, false)?,
+            larger_fonts: kwargs.get_or(Qstr::MP_QSTR_larger_fonts, false)?,
+            high_contrast: kwargs.get_or(Qstr::MP_QSTR_high_contrast, false)?,
+            audio_feedback: kwargs.get_or(Qstr::MP_QSTR_audio_feedback, false)?,
+        };
+        update_settings(|s| s.accessibility = accessibility);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+pub extern "C" fn upy_set_autodim(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let delay_ms: Option<u32> = kwargs
+            .get_or(Qstr::MP_QSTR_delay_ms, Obj::const_none())?
+            .try_into_option()?;
+        let level: u16 = kwargs.get_or(Qstr::MP_QSTR_level, 0)?;
+        set_autodim(delay_ms.map(|ms| AutoDim {
+            delay: Duration::from_millis(ms),
+            level,
+        }));
+        Ok(Obj::const_none())
+    };
+    unsafe { try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+#[cfg(feature = "button")]
+pub extern "C" fn upy_set_button_quirks(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let seed: Option<u32> = kwargs
+            .get_or(Qstr::MP_QSTR_seed, Obj::const_none())?
+            .try_into_option()?;
+        let bounce: u8 = kwargs.get_or(Qstr::MP_QSTR_bounce, 0)?;
+        let missed_release: u8 = kwargs.get_or(Qstr::MP_QSTR_missed_release, 0)?;
+        let dropped: u8 = kwargs.get_or(Qstr::MP_QSTR_dropped, 0)?;
+        if bounce > 100 || missed_release > 100 || dropped > 100 {
+            return Err(Error::OutOfRange);
+        }
+        set_button_quirks(seed.map(|seed| ButtonQuirks {
+            seed,
+            bounce,
+            missed_release,
+            dropped,
+        }));
+        Ok(Obj::const_none())
+    };
+    unsafe { try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+pub extern "C" fn upy_trace_paint_counters(enabled: Obj) -> Obj {
+    let block = || {
+        let _enabled: bool = enabled.try_into()?;
+        // Only debug builds trace the layouts.
+        #[cfg(feature = "ui_debug")]
+        update_settings(|s| s.paint_counters = _enabled);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_wipe_carry() -> Obj {
+    carry::wipe();
+    Obj::const_none()
+}
+
+pub extern "C" fn upy_save_resume_point(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        resume::save(ResumePoint {
+            flow: kwargs.get(Qstr::MP_QSTR_flow)?.try_into()?,
+            step: kwargs.get(Qstr::MP_QSTR_step)?.try_into()?,
+            page: kwargs.get(Qstr::MP_QSTR_page)?.try_into()?,
+        })?;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+pub extern "C" fn upy_resume_point(flow: Obj) -> Obj {
+    let block = || match resume::load(flow.try_into()?) {
+        Some(point) => {
+            let (step, page): (Obj, Obj) = (point.step.into(), point.page.into());
+            Ok((step, page).try_into()?)
+        }
+        None => Ok(Obj::const_none()),
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_clear_resume_point() -> Obj {
+    resume::clear();
+    Obj::const_none()
+}
+
+pub extern "C" fn upy_secrets_equal(a: Obj, b: Obj) -> Obj {
+    let block = || {
+        let a: StrBuffer = a.try_into()?;
+        let b: StrBuffer = b.try_into()?;
+        Ok(ct_eq(a.as_ref().as_bytes(), b.as_ref().as_bytes()).into())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_wallet_badge(hidden: Obj) -> Obj {
+    let block = || {
+        let wallet = if hidden == Obj::const_none() {
+            None
+        } else if hidden.try_into()? {
+            Some(Wallet::Hidden)
+        } else {
+            Some(Wallet::Standard)
+        };
+        update_settings(|s| s.wallet = wallet);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_keyboard_layout(layout: Obj) -> Obj {
+    let block = || {
+        let layout = KeyboardLayout::from_u8(layout.try_into()?)
+            .ok_or(value_error!("Unknown keyboard layout."))?;
+        update_settings(|s| s.keyboard_layout = layout);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_density(density: Obj) -> Obj {
+    let block = || {
+        let density =
+            Density::from_u8(density.try_into()?).ok_or(value_error!("Unknown density."))?;
+        update_settings(|s| s.density = density);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_theme_inverted(inverted: Obj) -> Obj {
+    let block = || {
+        let variant = if inverted.try_into()? {
+            ThemeVariant::Inverted
+        } else {
+            ThemeVariant::Normal
+        };
+        update_settings(|s| s.theme_variant = variant);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_enable_profiler(enable: Obj) -> Obj {
+    let block = || {
+        profiler::set_enabled(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_battery_level(level: Obj) -> Obj {
+    let block = || {
+        let level: Option<u8> = level.try_into_option()?;
+        update_settings(|s| s.battery_level = level);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_translations_max_length() -> Obj {
+    let block = || installed::max_len().try_into();
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_erase_translations() -> Obj {
+    installed::erase();
+    Obj::const_none()
+}
+
+pub extern "C" fn upy_write_translations(data: Obj, offset: Obj) -> Obj {
+    let block = || {
+        // SAFETY: The data is only read for the duration of the call.
+        let data = unsafe { get_buffer(data) }?;
+        installed::write(data, offset.try_into()?)?;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_commit_translations(length: Obj) -> Obj {
+    let block = || {
+        installed::commit(length.try_into()?)?;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_load_translations() -> Obj {
+    let block = || {
+        installed::load()?;
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
+pub extern "C" fn upy_set_replay_seed(seed: Obj) -> Obj {
+    let block = || {
+        set_replay_seed(seed.try_into_option()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
+
 pub fn get_user_custom_image() -> Result<Gc<[u8]>, Error> {
     let len = get_avatar_len()?;
     let mut data = Gc::<[u8]>::new_slice(len)?;
@@ -0,0 +1,367 @@
+//! Message templates with positional placeholders and plural forms, a small
+//! subset of the ICU message format. Translated strings can then keep whole
+//! sentences together instead of being glued from pieces.
+//!
+//! - `{0}` is replaced with the first argument.
+//! - `{0, plural, one {# share} other {# shares}}` picks a form according to
+//!   the first argument, which has to be a number, and replaces `#` in it with
+//!   the number. Exact matches like `=0 {no shares}` take precedence over the
+//!   plural categories, `other` must always be present.
+//! - `{{` stands for a literal brace, and so does `}}` outside of plural forms.
+//!
+//! Translated strings are rendered with `format_translated`, according to the
+//! plural rules of the current language.
+
+use heapless::String;
+
+use crate::{
+    error::Error,
+    strutil::format_i64,
+    ui::translations::{self, TranslatedString},
+};
+
+/// Value substituted into a template.
+#[derive(Clone, Copy)]
+pub enum Arg<'a> {
+    Str(&'a str),
+    Int(i64),
+}
+
+impl<'a> From<&'a str> for Arg<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<i64> for Arg<'_> {
+    fn from(n: i64) -> Self {
+        Self::Int(n)
+    }
+}
+
+impl From<u32> for Arg<'_> {
+    fn from(n: u32) -> Self {
+        Self::Int(n.into())
+    }
+}
+
+impl From<usize> for Arg<'_> {
+    fn from(n: usize) -> Self {
+        Self::Int(n as i64)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    One,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "one" => Some(Self::One),
+            "few" => Some(Self::Few),
+            "many" => Some(Self::Many),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Plural rules of a language, grouped by how the languages pick the form.
+#[derive(Clone, Copy)]
+pub enum PluralRules {
+    /// Only 1 is singular, e.g. English, German or Spanish.
+    OneOther,
+    /// 0 and 1 are singular, e.g. French.
+    French,
+    /// 1, 2-4 and the rest, e.g. Czech or Slovak.
+    Czech,
+    /// 1, 2-4 except 12-14, and the rest, e.g. Polish.
+    Polish,
+    /// No plural forms, e.g. Japanese.
+    Other,
+}
+
+impl PluralRules {
+    /// Rules of the language with the tag `language`, e.g. `cs-CZ`.
+    pub fn for_language(language: &str) -> Self {
+        match language.split('-').next() {
+            Some("fr") => Self::French,
+            Some("cs" | "sk") => Self::Czech,
+            Some("pl") => Self::Polish,
+            Some("ja" | "ko" | "zh") => Self::Other,
+            _ => Self::OneOther,
+        }
+    }
+
+    pub fn category(self, n: i64) -> PluralCategory {
+        let n = n.unsigned_abs();
+        match self {
+            Self::OneOther if n == 1 => PluralCategory::One,
+            Self::French if n <= 1 => PluralCategory::One,
+            Self::Czech => match n {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            Self::Polish => match (n % 10, n % 100) {
+                _ if n == 1 => PluralCategory::One,
+                (2..=4, tens) if !(12..=14).contains(&tens) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+/// Render `template` with `args`, passing the resulting text to `sink` piece
+/// by piece. Fails in case the template is malformed or refers to a missing
+/// argument.
+pub fn render(
+    template: &str,
+    args: &[Arg],
+    rules: PluralRules,
+    sink: &mut dyn FnMut(&str),
+) -> Result<(), Error> {
+    let rest = render_until(template, args, rules, None, sink)?;
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(value_error!("Unmatched closing brace in template."))
+    }
+}
+
+/// Render `template` with `args` into a string of capacity `N`.
+pub fn format<const N: usize>(
+    template: &str,
+    args: &[Arg],
+    rules: PluralRules,
+) -> Result<String<N>, Error> {
+    let mut result = String::new();
+    let mut overflow = false;
+    render(template, args, rules, &mut |s| {
+        overflow |= result.push_str(s).is_err();
+    })?;
+    if overflow {
+        Err(Error::OutOfRange)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Render the translated `template` with `args` in the current language. A
+/// translation failing to render, e.g. a malformed one, is replaced with the
+/// English template.
+pub fn format_translated<const N: usize>(template: TranslatedString, args: &[Arg]) -> String<N> {
+    let rules = translations::language().map_or(PluralRules::OneOther, PluralRules::for_language);
+    template
+        .map_translated(|translated| format(translated, args, rules))
+        .or_else(|_| format(template.untranslated(), args, PluralRules::OneOther))
+        .unwrap_or_default()
+}
+
+/// Render `text` until an unmatched closing brace or the end, returns the
+/// part of `text` starting with that brace. `#` is replaced with `count`, if
+/// any.
+fn render_until<'t>(
+    mut text: &'t str,
+    args: &[Arg],
+    rules: PluralRules,
+    count: Option<i64>,
+    sink: &mut dyn FnMut(&str),
+) -> Result<&'t str, Error> {
+    loop {
+        let special = |c: char| c == '{' || c == '}' || (c == '#' && count.is_some());
+        let Some(pos) = text.find(special) else {
+            sink(text);
+            return Ok("");
+        };
+        sink(&text[..pos]);
+        text = &text[pos..];
+        if let Some(rest) = text.strip_prefix("{{") {
+            sink("{");
+            text = rest;
+        } else if let (None, Some(rest)) = (count, text.strip_prefix("}}")) {
+            // Inside a plural form this is the end of the form and the placeholder.
+            sink("}");
+            text = rest;
+        } else if text.starts_with('}') {
+            return Ok(text);
+        } else if let (Some(n), Some(rest)) = (count, text.strip_prefix('#')) {
+            sink_int(n, sink);
+            text = rest;
+        } else {
+            text = render_placeholder(&text[1..], args, rules, sink)?;
+        }
+    }
+}
+
+/// Render the placeholder `text` starts with, just after its opening brace.
+/// Returns the rest of `text` after the placeholder.
+fn render_placeholder<'t>(
+    text: &'t str,
+    args: &[Arg],
+    rules: PluralRules,
+    sink: &mut dyn FnMut(&str),
+) -> Result<&'t str, Error> {
+    let (index, text) = split_token(text);
+    let arg = index
+        .parse::<usize>()
+        .ok()
+        .and_then(|i| args.get(i).copied())
+        .ok_or(value_error!("Invalid argument index in template."))?;
+
+    if let Some(text) = text.strip_prefix('}') {
+        match arg {
+            Arg::Str(s) => sink(s),
+            Arg::Int(n) => sink_int(n, sink),
+        }
+        return Ok(text);
+    }
+
+    let text = text
+        .strip_prefix(',')
+        .map(split_token)
+        .filter(|(kind, _)| *kind == "plural")
+        .and_then(|(_, text)| text.strip_prefix(','))
+        .ok_or(value_error!("Unsupported placeholder in template."))?;
+    let Arg::Int(n) = arg else {
+        return Err(value_error!("Plural argument is not a number."));
+    };
+    let category = rules.category(n);
+
+    // Find the best matching form, then render it.
+    let mut text = text;
+    let mut exact_form = None;
+    let mut category_form = None;
+    let mut other_form = None;
+    loop {
+        let (selector, rest) = split_token(text);
+        if selector.is_empty() {
+            break;
+        }
+        let body_start = rest
+            .strip_prefix('{')
+            .ok_or(value_error!("Missing plural form in template."))?;
+        // Skim the form to find where it ends.
+        let after = render_until(body_start, args, rules, Some(n), &mut |_| {})?;
+        let after = after
+            .strip_prefix('}')
+            .ok_or(value_error!("Unterminated plural form in template."))?;
+        let body = &body_start[..body_start.len() - after.len() - 1];
+
+        if let Some(exact) = selector.strip_prefix('=') {
+            if exact.parse::<i64>() == Ok(n) {
+                exact_form = Some(body);
+            }
+        } else {
+            let selected = PluralCategory::from_keyword(selector)
+                .ok_or(value_error!("Unknown plural category in template."))?;
+            if selected == category {
+                category_form = Some(body);
+            }
+            if selected == PluralCategory::Other {
+                other_form = Some(body);
+            }
+        }
+        text = after;
+    }
+    let text = text
+        .strip_prefix('}')
+        .ok_or(value_error!("Unterminated placeholder in template."))?;
+    other_form.ok_or(value_error!("Missing other plural form in template."))?;
+    let body = unwrap!(exact_form.or(category_form).or(other_form));
+    render_until(body, args, rules, Some(n), sink)?;
+    Ok(text)
+}
+
+/// Split `text` into a token, delimited by whitespace or one of `,{}`, and
+/// the rest. Leading whitespace is skipped around the token.
+fn split_token(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    let end = text
+        .find(|c: char| c.is_whitespace() || c == ',' || c == '{' || c == '}')
+        .unwrap_or(text.len());
+    (&text[..end], text[end..].trim_start())
+}
+
+fn sink_int(n: i64, sink: &mut dyn FnMut(&str)) {
+    let mut buffer = [0; 20];
+    sink(unwrap!(format_i64(n, &mut buffer)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(template: &str, args: &[Arg], rules: PluralRules) -> Option<String<64>> {
+        format(template, args, rules).ok()
+    }
+
+    #[test]
+    fn placeholders() {
+        let args = [Arg::from(2u32), Arg::from(3u32)];
+        assert_eq!(
+            fmt("{0} of {1} shares", &args, PluralRules::OneOther).as_deref(),
+            Some("2 of 3 shares")
+        );
+        assert_eq!(
+            fmt("{1}{{}}{0}", &args, PluralRules::OneOther).as_deref(),
+            Some("3{}2")
+        );
+        assert!(fmt("{2}", &args, PluralRules::OneOther).is_none());
+        assert!(fmt("{0", &args, PluralRules::OneOther).is_none());
+        assert!(fmt("0}", &args, PluralRules::OneOther).is_none());
+    }
+
+    #[test]
+    fn plurals() {
+        let template = "{0, plural, =0 {no shares} one {# share} other {# shares}} left";
+        let english = |n: u32| fmt(template, &[n.into()], PluralRules::OneOther);
+        assert_eq!(english(0).as_deref(), Some("no shares left"));
+        assert_eq!(english(1).as_deref(), Some("1 share left"));
+        assert_eq!(english(5).as_deref(), Some("5 shares left"));
+
+        let template = "{0, plural, one {# slovo} few {# slova} other {# slov}}";
+        let czech = |n: u32| fmt(template, &[n.into()], PluralRules::Czech);
+        assert_eq!(czech(1).as_deref(), Some("1 slovo"));
+        assert_eq!(czech(3).as_deref(), Some("3 slova"));
+        assert_eq!(czech(12).as_deref(), Some("12 slov"));
+
+        assert_eq!(PluralRules::Polish.category(22), PluralCategory::Few);
+        assert_eq!(PluralRules::Polish.category(12), PluralCategory::Many);
+        assert_eq!(PluralRules::French.category(0), PluralCategory::One);
+        assert!(matches!(
+            PluralRules::for_language("cs-CZ"),
+            PluralRules::Czech
+        ));
+        assert!(matches!(
+            PluralRules::for_language("en"),
+            PluralRules::OneOther
+        ));
+
+        // Missing `other`, and a plural of a string.
+        assert!(fmt(
+            "{0, plural, one {#}}",
+            &[2u32.into()],
+            PluralRules::OneOther
+        )
+        .is_none());
+        assert!(fmt(
+            "{0, plural, other {#}}",
+            &["x".into()],
+            PluralRules::OneOther
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn overflow() {
+        let long = "x".repeat(100);
+        assert!(fmt("{0}", &[long.as_str().into()], PluralRules::OneOther).is_none());
+    }
+}
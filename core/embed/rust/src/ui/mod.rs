@@ -1,16 +1,43 @@
 #[macro_use]
 pub mod macros;
 
+pub mod accessibility;
 pub mod animation;
+pub mod autodim;
+pub mod budget;
+pub mod busy;
+#[cfg(feature = "button")]
+pub mod button_quirks;
+pub mod carry;
+pub mod coin_icons;
 pub mod component;
 pub mod constant;
+pub mod context;
+pub mod density;
 pub mod display;
+pub mod eta;
 pub mod event;
 pub mod geometry;
+pub mod help;
+pub mod keyboard;
 pub mod lerp;
+pub mod message_format;
+pub mod profiler;
+pub mod random;
+pub mod resume;
 pub mod screens;
+pub mod settings;
+#[cfg(test)]
+pub mod snapshot;
+pub mod sound;
+pub mod stats;
+#[cfg(test)]
+pub mod testing;
+pub mod theme;
+pub mod translations;
 #[macro_use]
 pub mod util;
+pub mod wallet_badge;
 
 #[cfg(feature = "micropython")]
 pub mod layout;
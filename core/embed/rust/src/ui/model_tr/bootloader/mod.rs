@@ -4,6 +4,7 @@ use crate::{
     ui::{
         component::{Component, Event, EventCtx, Label, LineBreaking::BreakWordsNoHyphen, Never},
         constant::SCREEN,
+        context::with_state,
         display::{self, Color, Font, Icon},
         event::ButtonEvent,
         geometry::{Alignment2D, Offset, Rect},
@@ -262,6 +263,14 @@ fn screen_progress(
         fg_color,
         bg_color,
     );
+    // The second line can change during the progress.
+    display::rect_fill(
+        Rect::new(
+            SCREEN.center() + Offset::new(-SCREEN.width() / 2, 20 - Font::BOLD.text_max_height()),
+            SCREEN.center() + Offset::new(SCREEN.width() / 2, 20 + Font::BOLD.text_baseline()),
+        ),
+        bg_color,
+    );
     display::text_center(
         SCREEN.center() + Offset::y(20),
         text2,
@@ -288,9 +297,22 @@ extern "C" fn screen_install_progress(progress: u16, initialize: bool, _initial_
 
 #[no_mangle]
 extern "C" fn screen_wipe_progress(progress: u16, initialize: bool) {
+    // SAFETY: the bootloader is single-threaded
+    // The remaining time replaces the second line once it is known.
+    let remaining = with_state(|s| {
+        if initialize {
+            s.wipe_eta.reset();
+        }
+        s.wipe_eta.update_text(progress)
+    });
+    let text2 = if remaining.is_empty() {
+        "Trezor"
+    } else {
+        remaining.as_str()
+    };
     screen_progress(
         "Resetting",
-        "Trezor",
+        text2,
         progress,
         initialize,
         BLD_FG,
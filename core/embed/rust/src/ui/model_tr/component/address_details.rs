@@ -6,7 +6,7 @@ use crate::{
     ui::{
         component::{
             text::paragraphs::{Paragraph, ParagraphSource, ParagraphVecShort, Paragraphs, VecExt},
-            Child, Component, Event, EventCtx, Pad, Paginate, Qr,
+            Child, Component, Event, EventCtx, Focus, FocusGesture, Pad, Paginate, Qr,
         },
         geometry::Rect,
     },
@@ -19,13 +19,19 @@ use super::{
 const MAX_XPUBS: usize = 16;
 const QR_BORDER: i16 = 3;
 
+type Pages<T> = (
+    Qr<T>,
+    Paragraphs<ParagraphVecShort<T>>,
+    Frame<Paragraphs<Paragraph<T>>, T>,
+);
+
 pub struct AddressDetails<T>
 where
     T: StringType,
 {
-    qr_code: Qr,
-    details_view: Paragraphs<ParagraphVecShort<T>>,
-    xpub_view: Frame<Paragraphs<Paragraph<T>>, T>,
+    /// The QR code, the details and the xpub pages, the shown one gets the
+    /// button events.
+    pages: Focus<Pages<T>, 3>,
     xpubs: Vec<(T, T), MAX_XPUBS>,
     current_page: usize,
     current_subpage: usize,
@@ -63,9 +69,7 @@ where
         );
 
         let result = Self {
-            qr_code,
-            details_view,
-            xpub_view,
+            pages: Focus::new((qr_code, details_view, xpub_view), FocusGesture::None),
             xpubs: Vec::new(),
             area: Rect::zero(),
             current_page: 0,
@@ -100,7 +104,7 @@ where
 
     fn subpages_in_current_page(&mut self) -> usize {
         if self.is_xpub_page() {
-            self.xpub_view.page_count()
+            self.pages.inner_mut().2.page_count()
         } else {
             1
         }
@@ -151,14 +155,16 @@ where
 
     fn fill_xpub_page(&mut self, ctx: &mut EventCtx) {
         let i = self.current_page - 2;
-        self.xpub_view.update_title(ctx, self.xpubs[i].0.clone());
-        self.xpub_view.update_content(ctx, |p| {
+        let xpub_view = &mut self.pages.inner_mut().2;
+        xpub_view.update_title(ctx, self.xpubs[i].0.clone());
+        xpub_view.update_content(ctx, |p| {
             p.inner_mut().update(self.xpubs[i].1.clone());
             p.change_page(0)
         });
     }
 
     fn change_page(&mut self, ctx: &mut EventCtx) {
+        self.pages.set_focused(ctx, self.current_page.min(2));
         if self.is_xpub_page() {
             self.fill_xpub_page(ctx);
         }
@@ -168,8 +174,11 @@ where
 
     fn change_subpage(&mut self, ctx: &mut EventCtx) {
         if self.is_xpub_page() {
-            self.xpub_view
-                .update_content(ctx, |p| p.change_page(self.current_subpage));
+            let subpage = self.current_subpage;
+            self.pages
+                .inner_mut()
+                .2
+                .update_content(ctx, |p| p.change_page(subpage));
             self.pad.clear();
         }
     }
@@ -185,11 +194,12 @@ where
         // QR code is being placed on the whole bounds, so it can be as big as possible
         // (it will not collide with the buttons, they are narrow and on the sides).
         // Therefore, also placing pad on the whole bounds.
-        self.qr_code.place(bounds);
+        let (qr_code, details_view, xpub_view) = self.pages.inner_mut();
+        qr_code.place(bounds);
         self.pad.place(bounds);
         let (content_area, button_area) = bounds.split_bottom(theme::BUTTON_HEIGHT);
-        self.details_view.place(content_area);
-        self.xpub_view.place(content_area);
+        details_view.place(content_area);
+        xpub_view.place(content_area);
         self.buttons.place(button_area);
         self.area = content_area;
         bounds
@@ -197,11 +207,7 @@ where
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         // Possibly update the components that have e.g. marquee
-        match self.current_page {
-            0 => self.qr_code.event(ctx, event),
-            1 => self.details_view.event(ctx, event),
-            _ => self.xpub_view.event(ctx, event),
-        };
+        self.pages.event(ctx, event);
 
         let button_event = self.buttons.event(ctx, event);
         if let Some(ButtonControllerMsg::Triggered(button, _)) = button_event {
@@ -251,10 +257,11 @@ where
     fn paint(&mut self) {
         self.pad.paint();
         self.buttons.paint();
+        let (qr_code, details_view, xpub_view) = self.pages.inner_mut();
         match self.current_page {
-            0 => self.qr_code.paint(),
-            1 => self.details_view.paint(),
-            _ => self.xpub_view.paint(),
+            0 => qr_code.paint(),
+            1 => details_view.paint(),
+            _ => xpub_view.paint(),
         }
     }
 
@@ -271,10 +278,11 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("AddressDetails");
+        let (qr_code, details_view, xpub_view) = self.pages.inner();
         match self.current_page {
-            0 => t.child("qr_code", &self.qr_code),
-            1 => t.child("details_view", &self.details_view),
-            _ => t.child("xpub_view", &self.xpub_view),
+            0 => t.child("qr_code", qr_code),
+            1 => t.child("details_view", details_view),
+            _ => t.child("xpub_view", xpub_view),
         }
     }
 }
@@ -7,12 +7,15 @@ use crate::{
         display::{self, Color, Font, Icon},
         event::PhysicalButton,
         geometry::{Alignment2D, Offset, Point, Rect},
+        translations::TranslatedString,
     },
 };
 
 use super::{loader::DEFAULT_DURATION_MS, theme};
 
 const HALF_SCREEN_BUTTON_WIDTH: i16 = constant::WIDTH / 2 - 1;
+/// Appended to a label cut short to fit its button.
+const ABBREVIATION_MARK: &str = ".";
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum ButtonPos {
@@ -21,6 +24,18 @@ pub enum ButtonPos {
     Right,
 }
 
+#[cfg(feature = "ui_debug")]
+impl ButtonPos {
+    /// Physical button(s) the position is mapped to, for debug tracing.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Middle => "middle",
+            Self::Right => "right",
+        }
+    }
+}
+
 impl From<PhysicalButton> for ButtonPos {
     fn from(btn: PhysicalButton) -> Self {
         match btn {
@@ -39,6 +54,10 @@ where
     content: ButtonContent<T>,
     styles: ButtonStyleSheet,
     state: State,
+    /// Shown instead of a text that does not fit the button.
+    fallback_icon: Option<Icon>,
+    /// How the text fits the button, found out in `place()`.
+    label_fit: LabelFit,
 }
 
 impl<T> Button<T>
@@ -52,16 +71,20 @@ where
             styles,
             bounds: Rect::zero(),
             state: State::Released,
+            fallback_icon: None,
+            label_fit: LabelFit::Whole,
         }
     }
 
     pub fn from_button_details(pos: ButtonPos, btn_details: ButtonDetails<T>) -> Self {
         // Deciding between text and icon
         let style = btn_details.style();
-        match btn_details.content {
+        let mut button = match btn_details.content {
             ButtonContent::Text(text) => Self::with_text(pos, text, style),
             ButtonContent::Icon(icon) => Self::with_icon(pos, icon, style),
-        }
+        };
+        button.fallback_icon = btn_details.fallback_icon;
+        button
     }
 
     pub fn with_text(pos: ButtonPos, text: T, styles: ButtonStyleSheet) -> Self {
@@ -86,16 +109,96 @@ where
     /// Changing the icon content of the button.
     pub fn set_icon(&mut self, image: Icon) {
         self.content = ButtonContent::Icon(image);
+        self.fit_label();
     }
 
     /// Changing the text content of the button.
     pub fn set_text(&mut self, text: T) {
         self.content = ButtonContent::Text(text);
+        self.fit_label();
     }
 
     /// Changing the style of the button.
     pub fn set_style(&mut self, styles: ButtonStyleSheet) {
         self.styles = styles;
+        self.fit_label();
+    }
+
+    /// Width the button can take without running into the others.
+    fn slot_width(&self) -> i16 {
+        match self.pos {
+            ButtonPos::Left | ButtonPos::Right => HALF_SCREEN_BUTTON_WIDTH,
+            ButtonPos::Middle => constant::WIDTH - 2 * theme::BUTTON_ICON_WIDTH,
+        }
+    }
+
+    /// Space the outline or the arms add to the width of the text.
+    fn text_margin(style: &ButtonStyle) -> i16 {
+        if style.with_outline {
+            2 * theme::BUTTON_OUTLINE
+        } else if style.with_arms {
+            2 * theme::ARMS_MARGIN
+        } else {
+            0
+        }
+    }
+
+    /// Find out whether the text fits the slot of the button. A text that does
+    /// not is replaced by the fallback icon, if there is one, or cut short.
+    fn fit_label(&mut self) {
+        self.label_fit = self.measure_label();
+    }
+
+    fn measure_label(&self) -> LabelFit {
+        let ButtonContent::Text(text) = &self.content else {
+            return LabelFit::Whole;
+        };
+        let style = self.style();
+        if style.fixed_width.is_some() {
+            return LabelFit::Whole;
+        }
+        let text = text.as_ref();
+        let available = self.slot_width() - Self::text_margin(style);
+        if style.font.visible_text_width(text) <= available {
+            return LabelFit::Whole;
+        }
+        if let Some(icon) = self.fallback_icon {
+            return LabelFit::Icon(icon);
+        }
+        let available = available - style.font.text_width(ABBREVIATION_MARK);
+        let mut len = 0;
+        for (i, ch) in text.char_indices() {
+            let end = i + ch.len_utf8();
+            if style.font.visible_text_width(&text[..end]) > available {
+                break;
+            }
+            len = end;
+        }
+        if len > 0 {
+            LabelFit::Abbreviated(len)
+        } else {
+            LabelFit::Whole
+        }
+    }
+
+    /// Content as it fits the button.
+    fn shown(&self) -> Shown<'_> {
+        match (&self.content, self.label_fit) {
+            (ButtonContent::Icon(icon), _) => Shown::Icon(*icon),
+            (ButtonContent::Text(_), LabelFit::Icon(icon)) => Shown::Icon(icon),
+            (ButtonContent::Text(text), LabelFit::Abbreviated(len)) => {
+                Shown::Text(&text.as_ref()[..len], true)
+            }
+            (ButtonContent::Text(text), LabelFit::Whole) => Shown::Text(text.as_ref(), false),
+        }
+    }
+
+    fn shown_text_width(style: &ButtonStyle, text: &str, abbreviated: bool) -> i16 {
+        let mut width = style.font.visible_text_width(text);
+        if abbreviated {
+            width += style.font.text_width(ABBREVIATION_MARK);
+        }
+        width
     }
 
     // Setting the visual state of the button.
@@ -125,18 +228,11 @@ where
         let button_width = if let Some(width) = style.fixed_width {
             width
         } else {
-            match &self.content {
-                ButtonContent::Text(text) => {
-                    let text_width = style.font.visible_text_width(text.as_ref());
-                    if style.with_outline {
-                        text_width + 2 * theme::BUTTON_OUTLINE
-                    } else if style.with_arms {
-                        text_width + 2 * theme::ARMS_MARGIN
-                    } else {
-                        text_width
-                    }
+            match self.shown() {
+                Shown::Text(text, abbreviated) => {
+                    Self::shown_text_width(style, text, abbreviated) + Self::text_margin(style)
                 }
-                ButtonContent::Icon(icon) => {
+                Shown::Icon(icon) => {
                     // When Icon does not have outline, hardcode its width
                     if style.with_outline {
                         icon.toif.width() + 2 * theme::BUTTON_OUTLINE
@@ -174,9 +270,9 @@ where
         };
 
         // Centering the text in case of fixed width.
-        if let ButtonContent::Text(text) = &self.content {
+        if let Shown::Text(text, abbreviated) = self.shown() {
             if let Some(fixed_width) = style.fixed_width {
-                let diff = fixed_width - style.font.visible_text_width(text.as_ref());
+                let diff = fixed_width - Self::shown_text_width(style, text, abbreviated);
                 offset_x = diff / 2;
             }
         }
@@ -193,6 +289,7 @@ where
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.bounds = bounds;
+        self.fit_label();
         self.get_current_area()
     }
 
@@ -236,18 +333,22 @@ where
         }
 
         // Painting the content
-        match &self.content {
-            ButtonContent::Text(text) => {
-                display::text_left(
-                    self.get_text_baseline(style)
-                        - Offset::x(style.font.start_x_bearing(text.as_ref())),
-                    text.as_ref(),
-                    style.font,
-                    fg_color,
-                    bg_color,
-                );
+        match self.shown() {
+            Shown::Text(text, abbreviated) => {
+                let baseline =
+                    self.get_text_baseline(style) - Offset::x(style.font.start_x_bearing(text));
+                display::text_left(baseline, text, style.font, fg_color, bg_color);
+                if abbreviated {
+                    display::text_left(
+                        baseline + Offset::x(style.font.text_width(text)),
+                        ABBREVIATION_MARK,
+                        style.font,
+                        fg_color,
+                        bg_color,
+                    );
+                }
             }
-            ButtonContent::Icon(icon) => {
+            Shown::Icon(icon) => {
                 // Allowing for possible offset of the area from current style
                 let icon_area = area.translate(style.offset);
                 if style.with_outline {
@@ -283,6 +384,21 @@ enum State {
     Pressed,
 }
 
+/// How the text of a button fits its slot.
+#[derive(Clone, Copy)]
+enum LabelFit {
+    Whole,
+    /// Only the first bytes of the text, followed by `ABBREVIATION_MARK`.
+    Abbreviated(usize),
+    Icon(Icon),
+}
+
+enum Shown<'a> {
+    /// Text, and whether it is cut short.
+    Text(&'a str, bool),
+    Icon(Icon),
+}
+
 #[derive(Clone)]
 pub enum ButtonContent<T> {
     Text(T),
@@ -348,17 +464,39 @@ impl ButtonStyleSheet {
             offset,
         )
     }
+
+    // Black text on white in normal mode, e.g. for a selected option.
+    pub fn inverse(
+        with_outline: bool,
+        with_arms: bool,
+        fixed_width: Option<i16>,
+        offset: Offset,
+    ) -> Self {
+        Self::new(
+            theme::BG,
+            theme::FG,
+            with_outline,
+            with_arms,
+            fixed_width,
+            offset,
+        )
+    }
 }
 
 /// Describing the button on the screen - only visuals.
 #[derive(Clone)]
 pub struct ButtonDetails<T> {
     pub content: ButtonContent<T>,
+    /// Translated string the text comes from, resolved again when the
+    /// language changes.
+    translated: Option<TranslatedString>,
     pub duration: Option<Duration>,
     with_outline: bool,
     with_arms: bool,
     fixed_width: Option<i16>,
     offset: Offset,
+    inverse: bool,
+    fallback_icon: Option<Icon>,
     pub send_long_press: bool,
 }
 
@@ -370,11 +508,14 @@ where
     pub fn text(text: T) -> Self {
         Self {
             content: ButtonContent::Text(text),
+            translated: None,
             duration: None,
             with_outline: true,
             with_arms: false,
             fixed_width: None,
             offset: Offset::zero(),
+            inverse: false,
+            fallback_icon: None,
             send_long_press: false,
         }
     }
@@ -383,15 +524,43 @@ where
     pub fn icon(icon: Icon) -> Self {
         Self {
             content: ButtonContent::Icon(icon),
+            translated: None,
             duration: None,
             with_outline: false,
             with_arms: false,
             fixed_width: None,
             offset: Offset::zero(),
+            inverse: false,
+            fallback_icon: None,
             send_long_press: false,
         }
     }
 
+    /// Text button labeled in the current language.
+    pub fn translated(text: TranslatedString) -> Self {
+        Self {
+            translated: Some(text),
+            ..Self::text(T::from(text))
+        }
+    }
+
+    /// Translated text with arms signalling double press.
+    pub fn armed_translated(text: TranslatedString) -> Self {
+        Self::translated(text).with_arms()
+    }
+
+    /// Whether the text has to be resolved again when the language changes.
+    pub fn is_translated(&self) -> bool {
+        self.translated.is_some()
+    }
+
+    /// Resolve the translated text in the current language.
+    pub fn retranslate(&mut self) {
+        if let Some(text) = self.translated {
+            self.content = ButtonContent::Text(T::from(text));
+        }
+    }
+
     /// Resolves text and finds possible icon names.
     pub fn from_text_possible_icon(text: T) -> Self {
         match text.as_ref() {
@@ -428,6 +597,12 @@ where
         Self::icon(theme::ICON_ARROW_UP).with_offset(Offset::new(3, -4))
     }
 
+    /// Down arrow to signal paginating forward. No outline. Offsetted little
+    /// left to not be on the boundary.
+    pub fn down_arrow_icon() -> Self {
+        Self::icon(theme::ICON_ARROW_DOWN).with_offset(Offset::new(-3, -4))
+    }
+
     /// Down arrow to signal paginating forward. Takes half the screen's width
     pub fn down_arrow_icon_wide() -> Self {
         Self::icon(theme::ICON_ARROW_DOWN)
@@ -464,6 +639,19 @@ where
         self
     }
 
+    /// Black on white, the colors swap while the button is pressed.
+    pub fn with_inverse(mut self) -> Self {
+        self.inverse = true;
+        self
+    }
+
+    /// Icon shown instead of the text when it does not fit the button, e.g. in
+    /// a language with long words.
+    pub fn with_fallback_icon(mut self, icon: Icon) -> Self {
+        self.fallback_icon = Some(icon);
+        self
+    }
+
     /// Default duration of the hold-to-confirm - 1 second.
     pub fn with_default_duration(mut self) -> Self {
         self.duration = Some(Duration::from_millis(DEFAULT_DURATION_MS));
@@ -484,12 +672,21 @@ where
 
     /// Button style that should be applied.
     pub fn style(&self) -> ButtonStyleSheet {
-        ButtonStyleSheet::default(
-            self.with_outline,
-            self.with_arms,
-            self.fixed_width,
-            self.offset,
-        )
+        if self.inverse {
+            ButtonStyleSheet::inverse(
+                self.with_outline,
+                self.with_arms,
+                self.fixed_width,
+                self.offset,
+            )
+        } else {
+            ButtonStyleSheet::default(
+                self.with_outline,
+                self.with_arms,
+                self.fixed_width,
+                self.offset,
+            )
+        }
     }
 }
 
@@ -523,9 +720,31 @@ where
         Self::new(None, None, None)
     }
 
+    /// Buttons for `actions`, with their default labels and icons, see
+    /// `ButtonAction::default_details`.
+    pub fn from_actions(actions: ButtonActions) -> Self {
+        Self::new(
+            actions
+                .left
+                .map(|action| action.default_details(ButtonPos::Left)),
+            actions
+                .middle
+                .map(|action| action.default_details(ButtonPos::Middle)),
+            actions
+                .right
+                .map(|action| action.default_details(ButtonPos::Right)),
+        )
+    }
+
     /// Default button layout for all three buttons - icons.
     pub fn default_three_icons() -> Self {
-        Self::arrow_armed_arrow("SELECT".into())
+        Self::new(
+            Some(ButtonDetails::left_arrow_icon()),
+            Some(ButtonDetails::armed_translated(
+                TranslatedString::ButtonSelect,
+            )),
+            Some(ButtonDetails::right_arrow_icon()),
+        )
     }
 
     /// Special middle text for default icon layout.
@@ -772,6 +991,41 @@ pub enum ButtonAction {
     Info,
 }
 
+impl ButtonAction {
+    /// Button at `pos` doing the action, labeled in the current language, for
+    /// screens that have no specific label for it. Buttons in the middle are
+    /// armed, as both buttons press them.
+    pub fn default_details<T: StringType>(self, pos: ButtonPos) -> ButtonDetails<T> {
+        let (label, icon) = match (self, pos) {
+            (Self::Cancel, ButtonPos::Left) => return ButtonDetails::cancel_icon(),
+            (Self::PrevPage | Self::FirstPage, ButtonPos::Left) => {
+                return ButtonDetails::left_arrow_icon()
+            }
+            (Self::NextPage | Self::LastPage, ButtonPos::Right) => {
+                return ButtonDetails::right_arrow_icon()
+            }
+            (Self::Cancel, _) => (TranslatedString::ButtonCancel, Some(theme::ICON_CANCEL)),
+            (Self::PrevPage | Self::FirstPage, _) => {
+                (TranslatedString::ButtonBack, Some(theme::ICON_ARROW_LEFT))
+            }
+            (Self::NextPage | Self::LastPage, _) => {
+                (TranslatedString::ButtonNext, Some(theme::ICON_ARROW_RIGHT))
+            }
+            (Self::Confirm, _) => (TranslatedString::ButtonConfirm, Some(theme::ICON_TICK)),
+            (Self::Info, _) => (TranslatedString::ButtonInfo, None),
+        };
+        let mut details = if pos == ButtonPos::Middle {
+            ButtonDetails::armed_translated(label)
+        } else {
+            ButtonDetails::translated(label)
+        };
+        if let Some(icon) = icon {
+            details = details.with_fallback_icon(icon);
+        }
+        details
+    }
+}
+
 /// Storing actions for all three possible buttons.
 #[derive(Clone, Copy)]
 pub struct ButtonActions {
@@ -951,6 +1205,8 @@ use crate::strutil::ShortString;
 impl<T: StringType> crate::trace::Trace for Button<T> {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Button");
+        t.string("pos", self.pos.name());
+        t.bounds(self.get_current_area());
         match &self.content {
             ButtonContent::Text(text) => t.string("text", text.as_ref()),
             ButtonContent::Icon(icon) => {
@@ -995,3 +1251,76 @@ impl ButtonAction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    fn button(
+        pos: ButtonPos,
+        details: ButtonDetails<&'static str>,
+    ) -> Harness<Button<&'static str>> {
+        Harness::new(Button::from_button_details(pos, details))
+    }
+
+    #[test]
+    fn inverse_swaps_when_pressed() {
+        let mut h = button(ButtonPos::Left, ButtonDetails::text("OK").with_inverse());
+        assert!(h.inner().style().text_color == theme::BG);
+
+        h.mutate(|ctx, b| b.set_pressed(ctx, true));
+        assert!(h.inner().style().text_color == theme::FG);
+        assert!(h.paint_requested());
+    }
+
+    #[test]
+    fn labels_from_the_actions() {
+        let is_icon = |details: &Option<ButtonDetails<&'static str>>| {
+            matches!(
+                details.as_ref().map(|d| &d.content),
+                Some(ButtonContent::Icon(_))
+            )
+        };
+        let layout =
+            ButtonLayout::<&'static str>::from_actions(ButtonActions::cancel_confirm_next());
+        assert!(is_icon(&layout.btn_left));
+        assert!(is_icon(&layout.btn_right));
+
+        // In the middle it is a text, armed as both buttons press it.
+        let middle = unwrap!(layout.btn_middle);
+        assert!(matches!(middle.content, ButtonContent::Text("CONFIRM")));
+        assert!(middle.with_arms);
+
+        let confirm = ButtonAction::Confirm.default_details::<&'static str>(ButtonPos::Right);
+        assert!(matches!(confirm.content, ButtonContent::Text("CONFIRM")));
+        assert!(!confirm.with_arms);
+    }
+
+    const LONG: &str = "ZRUSENI TRANSAKCE";
+
+    #[test]
+    fn long_labels_are_cut_short() {
+        let h = button(ButtonPos::Left, ButtonDetails::text(LONG));
+        let LabelFit::Abbreviated(len) = h.inner().label_fit else {
+            panic!("not abbreviated");
+        };
+        assert!(len > 0 && len < LONG.len());
+        assert!(h.inner().get_current_area().width() <= HALF_SCREEN_BUTTON_WIDTH);
+
+        let h = button(ButtonPos::Left, ButtonDetails::text("NO"));
+        assert!(matches!(h.inner().label_fit, LabelFit::Whole));
+    }
+
+    #[test]
+    fn icon_instead_of_a_long_label() {
+        let details = ButtonDetails::text(LONG).with_fallback_icon(theme::ICON_CANCEL);
+        let mut h = button(ButtonPos::Left, details);
+        assert!(matches!(h.inner().shown(), Shown::Icon(_)));
+
+        // Measured again for a new text.
+        h.mutate(|_, b| b.set_text("NO"));
+        assert!(matches!(h.inner().shown(), Shown::Text("NO", false)));
+    }
+}
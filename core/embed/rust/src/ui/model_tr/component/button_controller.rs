@@ -123,6 +123,9 @@ where
     /// Whether it should even send `ButtonControllerMsg::LongPressed` events
     /// (optional)
     send_long_press: bool,
+    /// Details of a button with a translated text, to set it up again when the
+    /// language changes.
+    translated: Option<ButtonDetails<T>>,
 }
 
 impl<T> ButtonContainer<T>
@@ -136,6 +139,10 @@ where
         let send_long_press = btn_details
             .as_ref()
             .map_or(false, |btn| btn.send_long_press);
+        let translated = btn_details
+            .as_ref()
+            .filter(|btn| btn.is_translated())
+            .cloned();
         Self {
             pos,
             button_type: ButtonType::from_button_details(pos, btn_details),
@@ -143,6 +150,7 @@ where
             long_press_ms: DEFAULT_LONG_PRESS_MS,
             long_pressed_timer: None,
             send_long_press,
+            translated,
         }
     }
 
@@ -153,10 +161,22 @@ where
         self.send_long_press = btn_details
             .as_ref()
             .map_or(false, |btn| btn.send_long_press);
+        self.translated = btn_details
+            .as_ref()
+            .filter(|btn| btn.is_translated())
+            .cloned();
         self.button_type = ButtonType::from_button_details(self.pos, btn_details);
         self.button_type.place(button_area);
     }
 
+    /// Set the button up again with its text in the current language.
+    pub fn language_changed(&mut self, button_area: Rect) {
+        if let Some(mut btn_details) = self.translated.take() {
+            btn_details.retranslate();
+            self.set(Some(btn_details), button_area);
+        }
+    }
+
     /// Placing the possible component.
     pub fn place(&mut self, bounds: Rect) {
         self.button_type.place(bounds);
@@ -182,8 +202,9 @@ where
         match self.button_type {
             ButtonType::Button(_) => {
                 // Finding out whether the button was long-pressed
+                let long_press_ms = self.long_press_threshold_ms(ctx);
                 let long_press = self.pressed_since.map_or(false, |since| {
-                    Instant::now().saturating_duration_since(since).to_millis() > self.long_press_ms
+                    Instant::now().saturating_duration_since(since).to_millis() > long_press_ms
                 });
                 self.pressed_since = None;
                 self.long_pressed_timer = None;
@@ -212,11 +233,18 @@ where
     pub fn got_pressed(&mut self, ctx: &mut EventCtx) {
         self.pressed_since = Some(Instant::now());
         if self.send_long_press {
-            self.long_pressed_timer =
-                Some(ctx.request_timer(Duration::from_millis(self.long_press_ms)));
+            let long_press_ms = self.long_press_threshold_ms(ctx);
+            self.long_pressed_timer = Some(ctx.request_timer(Duration::from_millis(long_press_ms)));
         }
     }
 
+    /// Long-press duration adjusted to the accessibility preferences.
+    fn long_press_threshold_ms(&self, ctx: &EventCtx) -> u32 {
+        ctx.accessibility()
+            .hold(Duration::from_millis(self.long_press_ms))
+            .to_millis()
+    }
+
     /// Reset the pressed information.
     pub fn reset(&mut self) {
         self.pressed_since = None;
@@ -586,6 +614,19 @@ where
                 }
                 self.handle_htc_expiration(ctx, event)
             }
+            // Attached again, e.g. after the help, the buttons held before do not count.
+            Event::Attach => {
+                self.reset_state(ctx);
+                None
+            }
+            Event::LanguageChanged => {
+                self.pad.clear();
+                self.left_btn.language_changed(self.button_area);
+                self.middle_btn.language_changed(self.button_area);
+                self.right_btn.language_changed(self.button_area);
+                ctx.request_paint();
+                None
+            }
             _ => None,
         }
     }
@@ -1,8 +1,11 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx, Never, Pad},
-    display::Font,
-    geometry::{Alignment, Point, Rect},
-    util::long_line_content_with_ellipsis,
+use crate::{
+    strutil::SecretString,
+    ui::{
+        component::{Component, Event, EventCtx, Never, Pad},
+        display::Font,
+        geometry::{Alignment, Point, Rect},
+        util::long_line_content_with_ellipsis,
+    },
 };
 
 use super::{common, theme};
@@ -10,9 +13,12 @@ use super::{common, theme};
 /// Component that allows for "allocating" a standalone line of text anywhere
 /// on the screen and updating it arbitrarily - without affecting the rest
 /// and without being affected by other components.
-pub struct ChangingTextLine<T> {
+/// The text is kept in a buffer of `N` bytes, sized for what each use-site
+/// shows in the line. The buffer is a `SecretString`, as the line can show a
+/// PIN or a passphrase.
+pub struct ChangingTextLine<const N: usize> {
     pad: Pad,
-    text: T,
+    text: SecretString<N>,
     font: Font,
     /// Whether to show the text. Can be disabled.
     show_content: bool,
@@ -23,27 +29,26 @@ pub struct ChangingTextLine<T> {
     text_at_the_top: bool,
 }
 
-impl<T> ChangingTextLine<T>
-where
-    T: AsRef<str>,
-{
-    pub fn new(text: T, font: Font, alignment: Alignment) -> Self {
-        Self {
+impl<const N: usize> ChangingTextLine<N> {
+    pub fn new(text: &str, font: Font, alignment: Alignment) -> Self {
+        let mut line = Self {
             pad: Pad::with_background(theme::BG),
-            text,
+            text: SecretString::new(),
             font,
             show_content: true,
             ellipsis: "...",
             alignment,
             text_at_the_top: false,
-        }
+        };
+        line.update_text(text);
+        line
     }
 
-    pub fn center_mono(text: T) -> Self {
+    pub fn center_mono(text: &str) -> Self {
         Self::new(text, Font::MONO, Alignment::Center)
     }
 
-    pub fn center_bold(text: T) -> Self {
+    pub fn center_bold(text: &str) -> Self {
         Self::new(text, Font::BOLD, Alignment::Center)
     }
 
@@ -59,13 +64,19 @@ where
         self
     }
 
-    /// Update the text to be displayed in the line.
-    pub fn update_text(&mut self, text: T) {
-        self.text = text;
+    /// Update the text to be displayed in the line. Whatever does not fit into
+    /// the `N` bytes is cut off.
+    pub fn update_text(&mut self, text: &str) {
+        self.text.clear();
+        for ch in text.chars() {
+            if self.text.push(ch).is_err() {
+                break;
+            }
+        }
     }
 
     /// Get current text.
-    pub fn get_text(&self) -> &T {
+    pub fn get_text(&self) -> &str {
         &self.text
     }
 
@@ -101,7 +112,7 @@ where
 
     /// Whether the whole text can be painted in the available space
     fn text_fits_completely(&self) -> bool {
-        self.font.text_width(self.text.as_ref()) <= self.pad.area.width()
+        self.font.text_width(&self.text) <= self.pad.area.width()
     }
 
     fn paint_left(&self) {
@@ -121,7 +132,7 @@ where
 
     fn paint_long_content_with_ellipsis(&self) {
         let text_to_display = long_line_content_with_ellipsis(
-            self.text.as_ref(),
+            &self.text,
             self.ellipsis,
             self.font,
             self.pad.area.width(),
@@ -130,21 +141,14 @@ where
         // Creating the notion of motion by shifting the text left and right with
         // each new text character.
         // (So that it is apparent for the user that the text is changing.)
-        let x_offset = if self.text.as_ref().len() % 2 == 0 {
-            0
-        } else {
-            2
-        };
+        let x_offset = if self.text.len() % 2 == 0 { 0 } else { 2 };
 
         let baseline = Point::new(self.pad.area.x0 + x_offset, self.y_baseline());
         common::display_left(baseline, &text_to_display, self.font);
     }
 }
 
-impl<T> Component for ChangingTextLine<T>
-where
-    T: AsRef<str>,
-{
+impl<const N: usize> Component for ChangingTextLine<N> {
     type Msg = Never;
 
     fn place(&mut self, bounds: Rect) -> Rect {
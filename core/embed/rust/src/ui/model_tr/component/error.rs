@@ -1,5 +1,5 @@
 use crate::ui::{
-    component::{Child, Component, Event, EventCtx, Label, Never, Pad},
+    component::{Child, Component, Event, EventCtx, Label, Never, Pad, Qr},
     constant::{screen, WIDTH},
     display,
     geometry::{Alignment2D, Offset, Point, Rect},
@@ -12,6 +12,7 @@ use super::super::{
 
 const FOOTER_AREA_HEIGHT: i16 = 20;
 const DIVIDER_POSITION: i16 = 43;
+const QR_BORDER: i16 = 2;
 
 pub struct ErrorScreen<T> {
     bg: Pad,
@@ -19,6 +20,7 @@ pub struct ErrorScreen<T> {
     title: Child<Label<T>>,
     message: Child<Label<T>>,
     footer: Child<Label<T>>,
+    qr: Option<Child<Qr<T>>>,
     area: Rect,
 }
 
@@ -34,9 +36,30 @@ impl<T: AsRef<str>> ErrorScreen<T> {
             title: Child::new(title),
             message: Child::new(message),
             footer: Child::new(footer),
+            qr: None,
             area: Rect::zero(),
         }
     }
+
+    /// Show `diagnostics` as a QR code on the right half of the screen, so
+    /// that the error can be reported by scanning it. The footer makes room
+    /// for it. The code is left out if it does not fit.
+    pub fn with_diagnostics(mut self, diagnostics: T) -> Self {
+        self.qr = Qr::new(diagnostics, true)
+            .ok()
+            .map(|qr| Child::new(qr.with_border(QR_BORDER)));
+        self
+    }
+
+    fn place_with_qr(&mut self) {
+        let (text_area, qr_area) = screen().split_right(screen().height());
+        self.qr.place(qr_area);
+        self.show_icons = false;
+
+        let (title_area, message_area) = text_area.split_top(DIVIDER_POSITION / 2);
+        self.title.place(title_area);
+        self.message.place(message_area);
+    }
 }
 
 impl<T: AsRef<str>> Component for ErrorScreen<T> {
@@ -44,6 +67,12 @@ impl<T: AsRef<str>> Component for ErrorScreen<T> {
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.bg.place(screen());
+        self.area = bounds;
+
+        if self.qr.is_some() {
+            self.place_with_qr();
+            return screen();
+        }
 
         let title_area = Rect::new(screen().top_left(), screen().top_right() + Offset::y(11));
         self.title.place(title_area);
@@ -72,7 +101,6 @@ impl<T: AsRef<str>> Component for ErrorScreen<T> {
         );
         self.footer.place(footer_area);
 
-        self.area = bounds;
         screen()
     }
 
@@ -90,9 +118,43 @@ impl<T: AsRef<str>> Component for ErrorScreen<T> {
         self.title.paint();
         self.message.paint();
 
+        if self.qr.is_some() {
+            self.qr.paint();
+            return;
+        }
+
         // // divider line
         display::dotted_line(Point::new(0, DIVIDER_POSITION), WIDTH, FG, 3);
 
         self.footer.paint();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    #[test]
+    fn qr_takes_the_right_half() {
+        let h = Harness::new(
+            ErrorScreen::new("Failure", "Please reset", "Reboot")
+                .with_diagnostics("rsod/1/firmware/2.6.0"),
+        );
+        let error = h.inner();
+        assert!(!error.show_icons);
+        assert!(error.message.inner().area().x1 == WIDTH - screen().height());
+    }
+
+    #[test]
+    fn without_diagnostics_the_footer_stays() {
+        let long = Box::leak("X".repeat(400).into_boxed_str());
+        let h = Harness::new(
+            ErrorScreen::new("Failure", "Please reset", "Reboot").with_diagnostics(long),
+        );
+        let error = h.inner();
+        assert!(error.qr.is_none());
+        assert!(error.footer.inner().area().y0 >= screen().y1 - FOOTER_AREA_HEIGHT);
+    }
+}
@@ -1,14 +1,19 @@
 use crate::{
     strutil::StringType,
     ui::{
-        component::{Child, Component, ComponentExt, Event, EventCtx, Pad, Paginate},
-        geometry::Rect,
+        coin_icons::CoinIcon,
+        component::{
+            AttrUpdate, Child, CoinLabel, Component, ComponentExt, Event, EventCtx, Pad, Paginate,
+            PAGE_SELECTOR_MIN_PAGES,
+        },
+        geometry::{Insets, Rect},
     },
 };
 
 use super::{
     scrollbar::SCROLLBAR_SPACE, theme, title::Title, ButtonAction, ButtonController,
-    ButtonControllerMsg, ButtonLayout, ButtonPos, CancelInfoConfirmMsg, FlowPages, Page, ScrollBar,
+    ButtonControllerMsg, ButtonLayout, ButtonPos, CancelInfoConfirmMsg, FlowPages, NumberInput,
+    Page, ScrollBar,
 };
 
 pub struct Flow<F, T>
@@ -22,6 +27,8 @@ where
     current_page: Page<T>,
     /// Title being shown at the top in bold
     title: Option<Title<T>>,
+    /// Icon and name of a coin in front of the title.
+    coin: Option<Child<CoinLabel>>,
     scrollbar: Child<ScrollBar>,
     content_area: Rect,
     title_area: Rect,
@@ -33,6 +40,9 @@ where
     /// Possibly enforcing the second button to be ignored after some time after
     /// pressing the first button
     ignore_second_button_ms: Option<u32>,
+    /// Choosing the page number to jump to, shown over the whole flow.
+    page_selector: Option<Child<NumberInput<T>>>,
+    area: Rect,
 }
 
 impl<F, T> Flow<F, T>
@@ -47,6 +57,7 @@ where
             pages,
             current_page,
             title,
+            coin: None,
             content_area: Rect::zero(),
             title_area: Rect::zero(),
             scrollbar: Child::new(ScrollBar::to_be_filled_later()),
@@ -59,6 +70,8 @@ where
             return_confirmed_index: false,
             show_scrollbar: true,
             ignore_second_button_ms: None,
+            page_selector: None,
+            area: Rect::zero(),
         }
     }
 
@@ -69,6 +82,13 @@ where
         self
     }
 
+    /// Showing the icon and name of a coin in front of the titles, on the pages
+    /// that have one.
+    pub fn with_coin(mut self, icon: &'static CoinIcon) -> Self {
+        self.coin = Some(Child::new(CoinLabel::new(icon, theme::TEXT_NORMAL, 1)));
+        self
+    }
+
     /// Causing the Flow to return the index of the page that was confirmed.
     pub fn with_return_confirmed_index(mut self) -> Self {
         self.return_confirmed_index = true;
@@ -95,6 +115,7 @@ where
     /// Also updating the possible title and moving the scrollbar to correct
     /// position.
     fn change_current_page(&mut self, ctx: &mut EventCtx) {
+        ctx.set_active_page(self.page_counter);
         self.current_page = self.pages.get(self.page_counter);
         if let Some(title) = self.current_page.title() {
             self.title = Some(Title::new(title));
@@ -151,6 +172,30 @@ where
         self.update(ctx, true);
     }
 
+    /// Letting the user choose the page to go to directly, instead of pressing
+    /// the buttons many times.
+    fn open_page_selector(&mut self, ctx: &mut EventCtx) {
+        let count = self.pages.count() as u32;
+        let current = self.page_counter as u32 + 1;
+        let mut selector = Child::new(NumberInput::new(1, count, current));
+        selector.place(self.area);
+        self.page_selector = Some(selector);
+        // The selector gets the release of the long press.
+        self.buttons
+            .mutate(ctx, |ctx, buttons| buttons.reset_state(ctx));
+        ctx.request_repaint_root();
+    }
+
+    /// Going to the page chosen in the page selector, `page` being numbered from
+    /// 1.
+    fn close_page_selector(&mut self, ctx: &mut EventCtx, page: u32) {
+        self.page_selector = None;
+        self.page_counter = (page as usize).saturating_sub(1);
+        self.update(ctx, true);
+        // The selector covered everything.
+        ctx.request_repaint_root();
+    }
+
     /// Jumping to another page relative to the current one.
     fn go_to_page_relative(&mut self, jump: i16, ctx: &mut EventCtx) {
         self.page_counter += jump as usize;
@@ -162,12 +207,29 @@ where
     /// If defined in the current choice, setting their text,
     /// whether they are long-pressed, and painting them.
     fn set_buttons(&mut self, ctx: &mut EventCtx) {
-        let btn_layout = self.current_page.btn_layout();
+        let btn_layout = self.btn_layout();
         self.buttons.mutate(ctx, |_ctx, buttons| {
             buttons.set(btn_layout);
         });
     }
 
+    /// Buttons of the current page. In long flows, holding the right button
+    /// going further opens the page selector, see `open_page_selector`.
+    fn btn_layout(&self) -> ButtonLayout<T> {
+        let mut btn_layout = self.current_page.btn_layout();
+        let goes_further = self.current_page.has_next_page()
+            || matches!(
+                self.current_page.btn_actions().get_action(ButtonPos::Right),
+                Some(ButtonAction::NextPage)
+            );
+        if self.pages.count() >= PAGE_SELECTOR_MIN_PAGES && goes_further {
+            if let Some(btn_right) = &mut btn_layout.btn_right {
+                btn_right.send_long_press = btn_right.duration.is_none();
+            }
+        }
+        btn_layout
+    }
+
     /// Current choice is still the same, only its inner state has changed
     /// (its sub-page changed).
     fn update_after_current_choice_inner_change(&mut self, ctx: &mut EventCtx) {
@@ -227,6 +289,14 @@ where
             } else {
                 (title_area, Rect::zero())
             };
+            let title_area = match &mut self.coin {
+                Some(coin) => {
+                    const COIN_SPACE: i16 = 4;
+                    let coin_area = coin.place(title_area);
+                    title_area.inset(Insets::left(coin_area.width() + COIN_SPACE))
+                }
+                None => title_area,
+            };
 
             self.title.place(title_area);
             self.title_area = title_area;
@@ -237,23 +307,36 @@ where
         self.current_page.place(content_area);
         if let Some(ignore_ms) = self.ignore_second_button_ms {
             self.buttons = Child::new(
-                ButtonController::new(self.current_page.btn_layout())
-                    .with_ignore_btn_delay(ignore_ms),
+                ButtonController::new(self.btn_layout()).with_ignore_btn_delay(ignore_ms),
             );
         } else {
-            self.buttons = Child::new(ButtonController::new(self.current_page.btn_layout()));
+            self.buttons = Child::new(ButtonController::new(self.btn_layout()));
         }
 
         self.pad.place(title_content_area);
         self.buttons.place(button_area);
+        self.page_selector.place(bounds);
+        self.area = bounds;
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         ctx.set_page_count(self.pages.scrollbar_page_count(self.content_area));
+        if let Some(selector) = &mut self.page_selector {
+            if let Some(page) = selector.event(ctx, event) {
+                self.close_page_selector(ctx, page);
+            }
+            return None;
+        }
         self.title.event(ctx, event);
         let button_event = self.buttons.event(ctx, event);
 
+        // Only sent by the right button of long flows, see `btn_layout`.
+        if let Some(ButtonControllerMsg::LongPressed(ButtonPos::Right)) = button_event {
+            self.open_page_selector(ctx);
+            return None;
+        }
+
         // Do something when a button was triggered
         // and we have some action connected with it
         if let Some(ButtonControllerMsg::Triggered(pos, _)) = button_event {
@@ -293,12 +376,17 @@ where
     }
 
     fn paint(&mut self) {
+        if let Some(selector) = &mut self.page_selector {
+            selector.paint();
+            return;
+        }
         self.pad.paint();
         // Scrollbars are painted only with a title and when requested
         if self.title.is_some() {
             if self.show_scrollbar {
                 self.scrollbar.paint();
             }
+            self.coin.paint();
             self.title.paint();
         }
         self.buttons.paint();
@@ -308,6 +396,17 @@ where
         // (and painting buttons last would cover the lower part).
         self.current_page.paint();
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Page(page) => {
+                self.page_counter = (page as usize).min(self.pages.count() - 1);
+                self.update(ctx, true);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
@@ -326,8 +425,14 @@ where
         if let Some(title) = &self.title {
             t.child("title", title);
         }
+        if let Some(coin) = &self.coin {
+            t.child("coin", coin);
+        }
         t.child("scrollbar", &self.scrollbar);
         t.child("buttons", &self.buttons);
         t.child("flow_page", &self.current_page);
+        if let Some(selector) = &self.page_selector {
+            t.child("page_selector", selector);
+        }
     }
 }
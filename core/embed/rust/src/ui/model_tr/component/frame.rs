@@ -1,8 +1,9 @@
 use crate::{
     strutil::StringType,
     ui::{
-        component::{Child, Component, ComponentExt, Event, EventCtx, Paginate},
+        component::{AttrUpdate, Child, Component, ComponentExt, Event, EventCtx, Paginate},
         geometry::{Insets, Rect},
+        translations::TranslatedString,
     },
 };
 
@@ -83,6 +84,13 @@ where
         self.title.paint();
         self.content.paint();
     }
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.content.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.content.help()
+    }
 }
 
 impl<T, U> Paginate for Frame<T, U>
@@ -137,6 +145,15 @@ where
         self.title = Some(Child::new(Title::new(title)));
         self
     }
+
+    /// Following the page of the content.
+    fn update_scrollbar(&mut self, ctx: &mut EventCtx) {
+        let content_active_page = self.content.inner().active_page();
+        if self.scrollbar.active_page != content_active_page {
+            self.scrollbar.change_page(content_active_page);
+            self.scrollbar.request_complete_repaint(ctx);
+        }
+    }
 }
 
 impl<T, U> Component for ScrollableFrame<T, U>
@@ -190,11 +207,7 @@ where
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         let msg = self.content.event(ctx, event);
-        let content_active_page = self.content.inner().active_page();
-        if self.scrollbar.active_page != content_active_page {
-            self.scrollbar.change_page(content_active_page);
-            self.scrollbar.request_complete_repaint(ctx);
-        }
+        self.update_scrollbar(ctx);
         self.title.event(ctx, event);
         msg
     }
@@ -204,6 +217,15 @@ where
         self.scrollbar.paint();
         self.content.paint();
     }
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        let updated = self.content.update_attr(ctx, attr);
+        self.update_scrollbar(ctx);
+        updated
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.content.help()
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
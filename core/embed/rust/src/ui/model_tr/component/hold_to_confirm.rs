@@ -135,6 +135,7 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("HoldToConfirm");
+        t.string("pos", self.pos.name());
         t.child("loader", &self.loader);
     }
 }
@@ -69,14 +69,21 @@ where
     current_screen: CurrentScreen,
 }
 
+pub enum HomescreenMsg {
+    /// Held long enough to lock the device.
+    Dismissed,
+    /// Both buttons pressed, the user wants to adjust the settings.
+    Settings,
+}
+
 impl<T> Homescreen<T>
 where
     T: StringType + Clone,
 {
     pub fn new(label: T, notification: Option<(T, u8)>, loader_description: Option<T>) -> Self {
-        // Buttons will not be visible, we only need both left and right to be existing
-        // so we can get the events from them.
-        let invisible_btn_layout = ButtonLayout::text_none_text("".into(), "".into());
+        // Buttons will not be visible, we only need all three of them to be present,
+        // so that we can get the events from them.
+        let invisible_btn_layout = ButtonLayout::arrow_armed_arrow("".into());
         let loader =
             loader_description.map(|desc| Child::new(ProgressLoader::new(desc, HOLD_TO_LOCK_MS)));
         Self {
@@ -169,7 +176,7 @@ impl<T> Component for Homescreen<T>
 where
     T: StringType + Clone,
 {
-    type Msg = ();
+    type Msg = HomescreenMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.label.place(LABEL_AREA);
@@ -180,17 +187,19 @@ where
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         Self::event_usb(self, ctx, event);
 
-        // Only care about button and loader events when there is a possibility of
-        // locking the device
+        // When loader has completely grown, we can lock the device
         if let Some(self_loader) = &mut self.loader {
-            // When loader has completely grown, we can lock the device
             if let Some(LoaderMsg::GrownCompletely) = self_loader.event(ctx, event) {
-                return Some(());
+                return Some(HomescreenMsg::Dismissed);
             }
+        }
+
+        let button_event = self.invisible_buttons.event(ctx, event);
 
-            // Longer hold of any button will lock the device.
-            // Normal/quick presses and releases will show/hide the loader.
-            let button_event = self.invisible_buttons.event(ctx, event);
+        // Only care about the loader when there is a possibility of locking the
+        // device. Longer hold of any button will lock the device.
+        // Normal/quick presses and releases will show/hide the loader.
+        if let Some(self_loader) = &mut self.loader {
             if let Some(ButtonControllerMsg::Pressed(..)) = button_event {
                 if !self.show_loader {
                     self.show_loader = true;
@@ -209,6 +218,11 @@ where
             }
         }
 
+        // Both buttons released before the device got locked open the settings.
+        if let Some(ButtonControllerMsg::Triggered(ButtonPos::Middle, _)) = button_event {
+            return Some(HomescreenMsg::Settings);
+        }
+
         None
     }
 
@@ -0,0 +1,175 @@
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::{
+        component::{Component, Event, EventCtx},
+        display::Font,
+        geometry::Rect,
+    },
+};
+
+use super::super::{theme, ButtonLayout, ChoiceFactory, ChoiceItem, ChoicePage};
+use heapless::Vec;
+
+/// Most accounts the host can offer at once.
+pub const MAX_ACCOUNTS: usize = 16;
+/// Longest label in bytes, leaving room for the icon.
+const MAX_LABEL_LENGTH: usize = 16;
+/// Longest balance in bytes, including the unit.
+const MAX_BALANCE_LENGTH: usize = 20;
+
+/// Account as sent by the host, its label and formatted balance.
+pub struct Account<T> {
+    pub label: T,
+    pub balance: T,
+}
+
+struct ChoiceFactoryAccount<T: StringType> {
+    accounts: Vec<Account<T>, MAX_ACCOUNTS>,
+}
+
+impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryAccount<T> {
+    type Action = usize;
+    type Item = ChoiceItem<T>;
+
+    fn count(&self) -> usize {
+        self.accounts.len()
+    }
+
+    fn get(&self, choice_index: usize) -> (Self::Item, Self::Action) {
+        let account = &self.accounts[choice_index];
+        let choice_item = ChoiceItem::new(&account.label, ButtonLayout::default_three_icons())
+            .with_icon(theme::ICON_WALLET)
+            .with_font(Font::NORMAL)
+            .with_subtext(&account.balance);
+        (choice_item, choice_index)
+    }
+}
+
+/// Choice of one of the accounts of the host, the label of each of them next
+/// to the wallet icon and its balance below, returning the index of the
+/// chosen one.
+pub struct AccountChoice<T>
+where
+    T: StringType + Clone,
+{
+    choice_page: ChoicePage<ChoiceFactoryAccount<T>, T, usize>,
+}
+
+impl<T> AccountChoice<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(accounts: impl IntoIterator<Item = Account<T>>) -> Result<Self, Error> {
+        let mut checked = Vec::new();
+        for account in accounts {
+            if account.label.as_ref().len() > MAX_LABEL_LENGTH
+                || account.balance.as_ref().len() > MAX_BALANCE_LENGTH
+            {
+                return Err(value_error!("Account too long"));
+            }
+            checked
+                .push(account)
+                .map_err(|_| value_error!("Too many accounts"))?;
+        }
+        if checked.is_empty() {
+            return Err(value_error!("No accounts"));
+        }
+        let choices = ChoiceFactoryAccount { accounts: checked };
+        Ok(Self {
+            choice_page: ChoicePage::new(choices).with_carousel(true),
+        })
+    }
+}
+
+impl<T> Component for AccountChoice<T>
+where
+    T: StringType + Clone,
+{
+    type Msg = usize;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.choice_page.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.choice_page.event(ctx, event).map(|evt| evt.0)
+    }
+
+    fn paint(&mut self) {
+        self.choice_page.paint();
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for AccountChoice<T>
+where
+    T: StringType + Clone,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("AccountChoice");
+        t.child("choice_page", &self.choice_page);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{event::PhysicalButton, testing::Harness};
+
+    use super::*;
+
+    const ACCOUNTS: [(&str, &str); 3] = [
+        ("Spending", "0.021 BTC"),
+        ("Savings", "1.5 BTC"),
+        ("Cold storage", "12.00031 BTC"),
+    ];
+
+    fn accounts(
+        list: &[(&'static str, &'static str)],
+    ) -> impl Iterator<Item = Account<&'static str>> + '_ {
+        list.iter()
+            .map(|&(label, balance)| Account { label, balance })
+    }
+
+    #[test]
+    fn accounts_that_do_not_fit_are_refused() {
+        assert!(AccountChoice::new(accounts(&[])).is_err());
+        assert!(AccountChoice::new(accounts(&[("Everyday spending", "1 BTC")])).is_err());
+        assert!(AccountChoice::new(accounts(&[("Savings", "0.00000000001 BTC/LTC")])).is_err());
+        let many = [("Savings", "1 BTC"); MAX_ACCOUNTS + 1];
+        assert!(AccountChoice::new(accounts(&many)).is_err());
+        assert!(AccountChoice::new(accounts(&many[1..])).is_ok());
+    }
+
+    #[test]
+    fn returns_the_index() {
+        let mut h = Harness::new(AccountChoice::new(accounts(&ACCOUNTS)).unwrap());
+        h.click(PhysicalButton::Right);
+        h.click(PhysicalButton::Right);
+        assert_eq!(h.click_both(), Some(2));
+
+        // The last account is followed by the first one again.
+        let mut h = Harness::new(AccountChoice::new(accounts(&ACCOUNTS)).unwrap());
+        for _ in 0..ACCOUNTS.len() {
+            h.click(PhysicalButton::Right);
+        }
+        assert_eq!(h.click_both(), Some(0));
+    }
+
+    #[cfg(feature = "ui_debug")]
+    #[test]
+    fn balance_below_the_label() {
+        use crate::trace::tests::trace;
+
+        let factory = ChoiceFactoryAccount {
+            accounts: accounts(&ACCOUNTS).collect(),
+        };
+        let (item, index) = factory.get(1);
+        assert_eq!(index, 1);
+        let traced = trace(&item);
+        assert_eq!(traced["content"], "Savings");
+        assert_eq!(traced["subtext"], "1.5 BTC");
+    }
+}
@@ -1,8 +1,12 @@
 use crate::{
     strutil::StringType,
+    time::{Duration, Instant},
     ui::{
-        component::{Child, Component, Event, EventCtx, Pad},
-        geometry::{Insets, Offset, Rect},
+        animation::Animation,
+        component::{Child, Component, Event, EventCtx, Pad, TimerToken},
+        display,
+        geometry::{Insets, LinearSplit, Offset, Point, Rect, Slot},
+        sound::Feedback,
         util::animation_disabled,
     },
 };
@@ -12,6 +16,12 @@ use super::super::{
 };
 
 const DEFAULT_ITEMS_DISTANCE: i16 = 10;
+/// How long the items slide when moving to the neighbouring one.
+const SLIDE_DURATION: Duration = Duration::from_millis(120);
+/// How long the position marker stays after a move.
+const TICK_DURATION: Duration = Duration::from_millis(300);
+const TICK_HEIGHT: i16 = 2;
+const TICK_MIN_WIDTH: i16 = 4;
 
 pub trait Choice<T: StringType> {
     // Only `paint_center` is required, the rest is optional
@@ -26,6 +36,13 @@ pub trait Choice<T: StringType> {
         0
     }
 
+    /// Faded beginning of the item, painted at the edge of the row in place of
+    /// the item when it does not fit there. Zero width for no preview.
+    fn paint_preview(&self, _area: Rect) {}
+    fn width_preview(&self) -> i16 {
+        0
+    }
+
     fn btn_layout(&self) -> ButtonLayout<T> {
         ButtonLayout::default_three_icons()
     }
@@ -83,6 +100,9 @@ where
     /// Whether we should show items on left/right even when they cannot
     /// be painted entirely (they would be cut off).
     show_incomplete: bool,
+    /// Whether to show the preview of the first item that does not fit on
+    /// left/right, so that it is clear what comes next in either direction.
+    show_preview: bool,
     /// Whether to show only the currently selected item, nothing left/right.
     show_only_one_item: bool,
     /// Whether the middle selected item should be painted with
@@ -95,6 +115,15 @@ where
     /// How many animated steps we should still do (positive for right, negative
     /// for left).
     animated_steps_to_do: i16,
+    /// Horizontal shift of the items, sliding the newly chosen one into the
+    /// middle.
+    slide: Option<Animation<i16>>,
+    /// Whether the user moving to another item briefly shows the position in
+    /// the list at the bottom, and possibly clicks.
+    ticks: bool,
+    click: bool,
+    /// Hides the position marker, `None` when it is not shown.
+    tick_timer: Option<TimerToken>,
 }
 
 impl<F, T, A> ChoicePage<F, T, A>
@@ -124,11 +153,16 @@ where
             items_distance: DEFAULT_ITEMS_DISTANCE,
             is_carousel: false,
             show_incomplete: false,
+            show_preview: false,
             show_only_one_item: false,
             inverse_selected_item: false,
             holding_mover: AutomaticMover::new(),
             animation_mover: AutomaticMover::new().with_duration_func(animation_duration_func),
             animated_steps_to_do: 0,
+            slide: None,
+            ticks: false,
+            click: false,
+            tick_timer: None,
         }
     }
 
@@ -156,12 +190,26 @@ where
         self
     }
 
+    /// Show the preview of the items that do not fit on left/right.
+    pub fn with_preview(mut self, show_preview: bool) -> Self {
+        self.show_preview = show_preview;
+        self
+    }
+
     /// Show only the currently selected item, nothing left/right.
     pub fn with_only_one_item(mut self, only_one_item: bool) -> Self {
         self.show_only_one_item = only_one_item;
         self
     }
 
+    /// Briefly mark the position in the list whenever the user moves to
+    /// another item, playing a click too if `click` and audio feedback is on.
+    pub fn with_ticks(mut self, click: bool) -> Self {
+        self.ticks = true;
+        self.click = click;
+        self
+    }
+
     /// Adjust the distance between the items.
     pub fn with_items_distance(mut self, items_distance: i16) -> Self {
         self.items_distance = items_distance;
@@ -196,7 +244,7 @@ where
         do_animation: bool,
     ) {
         // Either moving with animation or just jumping to the final position directly.
-        if do_animation && !animation_disabled() {
+        if do_animation && !animation_disabled() && !ctx.accessibility().reduce_motion {
             let diff = page_counter as i16 - self.page_counter as i16;
             // When there would be a small number of animation frames (3 or less),
             // animating in the opposite direction to make the animation longer.
@@ -213,14 +261,53 @@ where
             };
             self.animation_mover.start_moving(ctx, pos);
         } else {
+            // Jumping in the middle of an animation ends it right away.
+            self.animated_steps_to_do = 0;
+            self.animation_mover.stop_moving();
             self.page_counter = page_counter;
             self.update(ctx);
         }
     }
 
+    /// Slide the items from where they were before moving by one, `from_right`
+    /// when the new item was on the right. Skipped when the user asked for
+    /// reduced motion.
+    fn start_slide(&mut self, ctx: &mut EventCtx, previous_width: i16, from_right: bool) {
+        if animation_disabled() || ctx.accessibility().reduce_motion {
+            return;
+        }
+        let distance =
+            (previous_width + self.get_current_item().width_center()) / 2 + self.items_distance;
+        let from = if from_right { distance } else { -distance };
+        self.slide = Some(Animation::new(from, 0, SLIDE_DURATION, Instant::now()));
+        ctx.request_anim_frame();
+    }
+
+    /// Advance the slide on an animation frame, ending it once it is over.
+    fn slide_event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if let (Some(slide), Event::Timer(EventCtx::ANIM_FRAME_TIMER)) = (&self.slide, event) {
+            if slide.elapsed(Instant::now()) >= slide.duration {
+                self.slide = None;
+            } else {
+                ctx.request_anim_frame();
+            }
+            self.clear_and_repaint(ctx);
+        }
+    }
+
     /// Display current, previous and next choices according to
-    /// the current ChoiceItem.
+    /// the current ChoiceItem, shifted while sliding.
     fn paint_choices(&mut self) {
+        let shift = self
+            .slide
+            .as_ref()
+            .map_or(0, |slide| slide.value(Instant::now()));
+        display::set_offset(Offset::x(shift));
+        self.paint_choices_in_place();
+        display::set_offset(Offset::zero());
+    }
+
+    fn paint_choices_in_place(&mut self) {
         // Getting the row area for the choices - so that displaying
         // items in the used font will show them in the middle vertically.
         let area_height_half = self.pad.area.height() / 2;
@@ -330,7 +417,15 @@ where
             let choice_width = choice.width_side();
 
             if current_area.width() <= choice_width && !self.show_incomplete {
-                // early break for an item that will not fit the remaining space
+                // early break for an item that will not fit the remaining space,
+                // possibly previewing it at the very left
+                let preview_width = choice.width_preview();
+                if self.show_preview && preview_width > 0 && current_area.width() > preview_width {
+                    choice.paint_preview(Rect::from_top_left_and_size(
+                        current_area.top_left(),
+                        Offset::new(preview_width, current_area.height()),
+                    ));
+                }
                 break;
             }
 
@@ -371,7 +466,15 @@ where
             let choice_width = choice.width_side();
 
             if current_area.width() <= choice_width && !self.show_incomplete {
-                // early break for an item that will not fit the remaining space
+                // early break for an item that will not fit the remaining space,
+                // possibly previewing it at the very right
+                let preview_width = choice.width_preview();
+                if self.show_preview && preview_width > 0 && current_area.width() > preview_width {
+                    choice.paint_preview(Rect::from_top_right_and_size(
+                        current_area.top_right(),
+                        Offset::new(preview_width, current_area.height()),
+                    ));
+                }
                 break;
             }
 
@@ -458,6 +561,50 @@ where
         }
     }
 
+    /// The user moved to another item.
+    fn tick(&mut self, ctx: &mut EventCtx) {
+        if !self.ticks {
+            return;
+        }
+        self.tick_timer = Some(ctx.request_timer(TICK_DURATION));
+        if self.click {
+            Feedback::Tick.play(ctx);
+        }
+        ctx.request_paint();
+    }
+
+    fn tick_event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if let Event::Timer(token) = event {
+            if self.tick_timer == Some(token) {
+                self.tick_timer = None;
+                self.clear_and_repaint(ctx);
+            }
+        }
+    }
+
+    /// Marker of the current position in the list, along the bottom of the
+    /// choices.
+    fn paint_tick(&self) {
+        if self.tick_timer.is_none() || self.choices.count() < 2 {
+            return;
+        }
+        let area = self.pad.area;
+        let width = (area.width() / self.choices.count() as i16).max(TICK_MIN_WIDTH);
+        let x = area.x0
+            + (area.width() - width) * self.page_counter as i16 / self.last_page_index() as i16;
+        display::rect_fill(
+            Rect::from_top_left_and_size(
+                Point::new(x, area.y1 - TICK_HEIGHT),
+                Offset::new(width, TICK_HEIGHT),
+            ),
+            theme::FG,
+        );
+    }
+
+    fn current_page_and_width(&self) -> (usize, i16) {
+        (self.page_counter, self.get_current_item().width_center())
+    }
+
     /// Possibly doing an animation movement with the choice - either left or
     /// right.
     fn animation_event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<ButtonPos> {
@@ -492,13 +639,18 @@ where
     type Msg = (A, bool);
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let (content_area, button_area) = bounds.split_bottom(theme::BUTTON_HEIGHT);
+        let [content_area, button_area] =
+            LinearSplit::vertical([Slot::weighted(1), Slot::fixed(theme::BUTTON_HEIGHT)])
+                .split(bounds);
         self.pad.place(content_area);
         self.buttons.place(button_area);
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.slide_event(ctx, event);
+        self.tick_event(ctx, event);
+
         // Possible animation movement when setting (randomizing) the page counter.
         if let Some(animation_direction) = self.animation_event(ctx, event) {
             match animation_direction {
@@ -516,11 +668,15 @@ where
 
         // Possible automatic movement when user is holding left or right button.
         if let Some(auto_move_direction) = self.holding_mover.event(ctx, event) {
+            let previous = self.page_counter;
             match auto_move_direction {
                 ButtonPos::Left => self.move_left(ctx),
                 ButtonPos::Right => self.move_right(ctx),
                 _ => {}
             }
+            if self.page_counter != previous {
+                self.tick(ctx);
+            }
             return None;
         }
 
@@ -554,12 +710,22 @@ where
                 ButtonPos::Left => {
                     // Clicked BACK. Decrease the page counter.
                     // In case of carousel going to the right end.
+                    let (previous, previous_width) = self.current_page_and_width();
                     self.move_left(ctx);
+                    if self.page_counter != previous {
+                        self.start_slide(ctx, previous_width, false);
+                        self.tick(ctx);
+                    }
                 }
                 ButtonPos::Right => {
                     // Clicked NEXT. Increase the page counter.
                     // In case of carousel going to the left end.
+                    let (previous, previous_width) = self.current_page_and_width();
                     self.move_right(ctx);
+                    if self.page_counter != previous {
+                        self.start_slide(ctx, previous_width, true);
+                        self.tick(ctx);
+                    }
                 }
                 ButtonPos::Middle => {
                     // Clicked SELECT. Send current choice index with information about long-press
@@ -593,6 +759,7 @@ where
         self.pad.paint();
         self.buttons.paint();
         self.paint_choices();
+        self.paint_tick();
     }
 }
 
@@ -630,3 +797,184 @@ where
         t.child("buttons", &self.buttons);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::ui::{
+        event::PhysicalButton,
+        testing::{press, release, Harness},
+    };
+
+    use super::{super::choice_item::ChoiceItem, *};
+
+    struct Items {
+        count: usize,
+        /// Selecting by holding the middle button, without releasing it.
+        hold: bool,
+    }
+
+    impl ChoiceFactory<&'static str> for Items {
+        type Action = usize;
+        type Item = ChoiceItem<&'static str>;
+
+        fn count(&self) -> usize {
+            self.count
+        }
+
+        fn get(&self, index: usize) -> (Self::Item, usize) {
+            let item = ChoiceItem::new("ITEM", ButtonLayout::default_three_icons());
+            if self.hold {
+                (item.with_middle_action_without_release(), index)
+            } else {
+                (item, index)
+            }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    enum Step {
+        /// Press or release of the left (true) or right button.
+        Press(bool),
+        Release(bool),
+        /// Firing one of the timers requested so far.
+        Timer(usize),
+        /// Setting the page counter with or without animation.
+        Jump(usize, bool),
+    }
+
+    fn step() -> impl Strategy<Value = Step> {
+        prop_oneof![
+            any::<bool>().prop_map(Step::Press),
+            any::<bool>().prop_map(Step::Release),
+            any::<usize>().prop_map(Step::Timer),
+            (any::<usize>(), any::<bool>()).prop_map(|(i, a)| Step::Jump(i, a)),
+        ]
+    }
+
+    type Page = ChoicePage<Items, &'static str, usize>;
+
+    fn harness(count: usize, carousel: bool, hold: bool) -> Harness<Page> {
+        Harness::new(ChoicePage::new(Items { count, hold }).with_carousel(carousel))
+    }
+
+    fn button(h: &mut Harness<Page>, left: bool, pressed: bool) -> Option<(usize, bool)> {
+        let button = if left {
+            PhysicalButton::Left
+        } else {
+            PhysicalButton::Right
+        };
+        if pressed {
+            h.event(press(button))
+        } else {
+            h.event(release(button))
+        }
+    }
+
+    /// Fire the pending timer at `index`, wrapping around.
+    fn fire(h: &mut Harness<Page>, index: usize) -> Option<(usize, bool)> {
+        match h.timers().len() {
+            0 => None,
+            len => h.fire_timer(index % len),
+        }
+    }
+
+    fn is_animating(h: &Harness<Page>) -> bool {
+        h.inner().animation_mover.is_moving()
+    }
+
+    proptest! {
+        #[test]
+        fn choice_page_state_machine(
+            count in 1usize..8,
+            carousel in any::<bool>(),
+            hold in any::<bool>(),
+            steps in prop::collection::vec(step(), 0..64),
+        ) {
+            let mut h = harness(count, carousel, hold);
+            // Page the running animation is heading to.
+            let mut target = None;
+            for step in steps {
+                let before = h.inner().page_index();
+                let msg = match step {
+                    Step::Press(left) => button(&mut h, left, true),
+                    Step::Release(left) => button(&mut h, left, false),
+                    Step::Timer(i) => fire(&mut h, i),
+                    Step::Jump(i, animate) => {
+                        // Only carousels animate the jumps, going around in one
+                        // direction.
+                        let animate = animate && carousel;
+                        h.mutate(|ctx, page| page.set_page_counter(ctx, i % count, animate));
+                        target = animate.then_some(i % count);
+                        None
+                    }
+                };
+                let after = h.inner().page_index();
+                prop_assert!(after < count);
+                if !matches!(step, Step::Jump(..)) {
+                    // One item at a time, wrapping around only in carousels.
+                    let diff = before.abs_diff(after);
+                    prop_assert!(diff <= 1 || (carousel && diff == count - 1));
+                }
+                if let Some((action, _)) = msg {
+                    prop_assert_eq!(action, after);
+                }
+                if !is_animating(&h) {
+                    if let Some(target) = target.take() {
+                        prop_assert_eq!(after, target);
+                    }
+                }
+            }
+            // Let a running animation finish.
+            for _ in 0..4 * count + 8 {
+                if !is_animating(&h) {
+                    break;
+                }
+                fire(&mut h, 0);
+            }
+            prop_assert!(!is_animating(&h));
+            if let Some(target) = target {
+                prop_assert_eq!(h.inner().page_index(), target);
+            }
+        }
+    }
+
+    #[test]
+    fn long_press_selects_without_release() {
+        let mut h = harness(5, false, true);
+        assert_eq!(h.click(PhysicalButton::Right), None);
+        assert_eq!(h.inner().page_index(), 1);
+
+        assert_eq!(h.event(press(PhysicalButton::Left)), None);
+        assert_eq!(h.event(press(PhysicalButton::Right)), None);
+        // Selected once the long-press timer of the middle button fires.
+        let msg = (0..h.timers().len()).find_map(|_| fire(&mut h, 0));
+        assert_eq!(msg, Some((1, true)));
+    }
+
+    #[test]
+    fn moving_marks_the_position() {
+        let mut h = Harness::new(
+            ChoicePage::new(Items {
+                count: 5,
+                hold: false,
+            })
+            .with_ticks(false),
+        );
+        assert_eq!(h.click(PhysicalButton::Right), None);
+        let token = unwrap!(h.inner().tick_timer);
+        let index = unwrap!(h.timers().iter().position(|(t, _)| *t == token));
+        assert_eq!(h.timers()[index].1, TICK_DURATION);
+
+        // Gone once the timer fires.
+        h.fire_timer(index);
+        assert!(h.inner().tick_timer.is_none());
+
+        // Not at all unless asked for.
+        let mut h = harness(5, false, false);
+        assert_eq!(h.click(PhysicalButton::Right), None);
+        assert_eq!(h.inner().page_index(), 1);
+        assert!(h.inner().tick_timer.is_none());
+    }
+}
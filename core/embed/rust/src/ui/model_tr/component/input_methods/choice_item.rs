@@ -2,7 +2,7 @@ use crate::{
     strutil::{ShortString, StringType},
     ui::{
         display::{self, rect_fill, rect_fill_corners, rect_outline_rounded, Font, Icon},
-        geometry::{Alignment2D, Offset, Rect},
+        geometry::{Alignment2D, Offset, Point, Rect},
     },
 };
 
@@ -11,11 +11,16 @@ use heapless::String;
 use super::super::{theme, ButtonDetails, ButtonLayout, Choice};
 
 const ICON_RIGHT_PADDING: i16 = 2;
+/// How many first characters of the item its preview shows.
+const PREVIEW_LENGTH: usize = 2;
+const SUBTEXT_FONT: Font = Font::NORMAL;
 
 /// Simple string component used as a choice item.
 #[derive(Clone)]
 pub struct ChoiceItem<T: StringType> {
     text: ShortString,
+    /// Second line under the highlighted item, only shown in the center.
+    subtext: Option<ShortString>,
     icon: Option<Icon>,
     btn_layout: ButtonLayout<T>,
     font: Font,
@@ -26,6 +31,7 @@ impl<T: StringType> ChoiceItem<T> {
     pub fn new<U: AsRef<str>>(text: U, btn_layout: ButtonLayout<T>) -> Self {
         Self {
             text: String::from(text.as_ref()),
+            subtext: None,
             icon: None,
             btn_layout,
             font: theme::FONT_CHOICE_ITEMS,
@@ -39,6 +45,12 @@ impl<T: StringType> ChoiceItem<T> {
         self
     }
 
+    /// Allows to add the second line, e.g. the balance of an account.
+    pub fn with_subtext<U: AsRef<str>>(mut self, subtext: U) -> Self {
+        self.subtext = Some(String::from(subtext.as_ref()));
+        self
+    }
+
     /// Allows to change the font.
     pub fn with_font(mut self, font: Font) -> Self {
         self.font = font;
@@ -82,6 +94,19 @@ impl<T: StringType> ChoiceItem<T> {
         }
     }
 
+    /// Items with an icon are not previewed.
+    fn preview_text(&self) -> Option<&str> {
+        if self.icon.is_some() {
+            return None;
+        }
+        let end = self
+            .text
+            .char_indices()
+            .nth(PREVIEW_LENGTH)
+            .map_or(self.text.len(), |(i, _)| i);
+        Some(&self.text[..end])
+    }
+
     pub fn content(&self) -> &str {
         self.text.as_ref()
     }
@@ -104,6 +129,17 @@ where
             self.font,
             inverse,
         );
+        if let Some(subtext) = &self.subtext {
+            let baseline = area.bottom_center()
+                + Offset::y(theme::BUTTON_OUTLINE + 1 + SUBTEXT_FONT.line_height());
+            display::text_center(
+                baseline,
+                subtext.as_ref(),
+                SUBTEXT_FONT,
+                theme::FG,
+                theme::BG,
+            );
+        }
     }
 
     /// Getting the overall width in pixels when displayed in center.
@@ -124,6 +160,23 @@ where
         paint_text_icon(area, width, self.side_text(), self.icon, self.font, false);
     }
 
+    /// Getting the width of the first letters shown at the edge.
+    fn width_preview(&self) -> i16 {
+        text_icon_width(self.preview_text(), None, self.font)
+    }
+
+    /// Painting the first letters of the item, faded.
+    fn paint_preview(&self, area: Rect) {
+        let width = self.width_preview();
+        paint_text_icon(area, width, self.preview_text(), None, self.font, false);
+        // Covering the descenders below the baseline as well.
+        let top_left = area.bottom_center() - Offset::new(width / 2, self.font.text_height());
+        fade(Rect::from_top_left_and_size(
+            top_left,
+            Offset::new(width, self.font.text_max_height()),
+        ));
+    }
+
     /// Getting current button layout.
     fn btn_layout(&self) -> ButtonLayout<T> {
         self.btn_layout.clone()
@@ -150,6 +203,19 @@ fn paint_rounded_highlight(area: Rect, size: Offset, inverse: bool) {
     }
 }
 
+/// Dimming what is painted in `area` by clearing every other pixel in a
+/// checkerboard pattern, the display has no shades.
+fn fade(area: Rect) {
+    for y in area.y0..area.y1 {
+        for x in (area.x0 + (y & 1)..area.x1).step_by(2) {
+            rect_fill(
+                Rect::from_top_left_and_size(Point::new(x, y), Offset::new(1, 1)),
+                theme::BG,
+            );
+        }
+    }
+}
+
 fn text_icon_width(text: Option<&str>, icon: Option<Icon>, font: Font) -> i16 {
     match (text, icon) {
         (Some(text), Some(icon)) => {
@@ -200,5 +266,35 @@ impl<T: StringType> crate::trace::Trace for ChoiceItem<T> {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("ChoiceItem");
         t.string("content", self.text.as_ref());
+        if let Some(subtext) = &self.subtext {
+            t.string("subtext", subtext.as_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str) -> ChoiceItem<&'static str> {
+        ChoiceItem::new(text, ButtonLayout::default_three_icons())
+    }
+
+    #[test]
+    fn preview_shows_the_first_letters() {
+        assert_eq!(item("ABANDON").preview_text(), Some("AB"));
+        assert_eq!(item("ŽÁBA").preview_text(), Some("ŽÁ"));
+        assert_eq!(item("A").preview_text(), Some("A"));
+
+        let word = item("ABANDON");
+        assert!(word.width_preview() > 0);
+        assert!(word.width_preview() < word.width_side());
+    }
+
+    #[test]
+    fn icons_are_not_previewed() {
+        let delete = item("DELETE").with_icon(theme::ICON_DELETE);
+        assert_eq!(delete.preview_text(), None);
+        assert_eq!(delete.width_preview(), 0);
     }
 }
@@ -0,0 +1,132 @@
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::{
+        component::{Component, Event, EventCtx},
+        display::Font,
+        geometry::Rect,
+    },
+};
+
+use super::super::{ButtonLayout, ChoiceFactory, ChoiceItem, ChoicePage};
+use heapless::Vec;
+
+/// Most items of a list from the host.
+pub const MAX_HOST_CHOICES: usize = 32;
+/// Longest item in bytes, so that it fits the screen in `Font::NORMAL`.
+pub const MAX_HOST_CHOICE_LENGTH: usize = 20;
+
+struct ChoiceFactoryHost<T: StringType> {
+    items: Vec<T, MAX_HOST_CHOICES>,
+}
+
+impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryHost<T> {
+    type Action = usize;
+    type Item = ChoiceItem<T>;
+
+    fn count(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get(&self, choice_index: usize) -> (Self::Item, Self::Action) {
+        let choice_item = ChoiceItem::new(
+            &self.items[choice_index],
+            ButtonLayout::default_three_icons(),
+        )
+        .with_font(Font::NORMAL);
+        (choice_item, choice_index)
+    }
+}
+
+/// Choice of one of the items the host sent, e.g. account names or tokens,
+/// returning its index. Lists the host could use to make the screen overflow
+/// are refused.
+pub struct HostChoice<T>
+where
+    T: StringType + Clone,
+{
+    choice_page: ChoicePage<ChoiceFactoryHost<T>, T, usize>,
+}
+
+impl<T> HostChoice<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(items: impl IntoIterator<Item = T>) -> Result<Self, Error> {
+        let mut checked = Vec::new();
+        for item in items {
+            if item.as_ref().len() > MAX_HOST_CHOICE_LENGTH {
+                return Err(value_error!("Item too long"));
+            }
+            checked
+                .push(item)
+                .map_err(|_| value_error!("Too many items"))?;
+        }
+        if checked.is_empty() {
+            return Err(value_error!("No items"));
+        }
+        let choices = ChoiceFactoryHost { items: checked };
+        Ok(Self {
+            choice_page: ChoicePage::new(choices).with_carousel(true),
+        })
+    }
+}
+
+impl<T> Component for HostChoice<T>
+where
+    T: StringType + Clone,
+{
+    type Msg = usize;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.choice_page.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.choice_page.event(ctx, event).map(|evt| evt.0)
+    }
+
+    fn paint(&mut self) {
+        self.choice_page.paint();
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for HostChoice<T>
+where
+    T: StringType + Clone,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("HostChoice");
+        t.child("choice_page", &self.choice_page);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{event::PhysicalButton, testing::Harness};
+
+    use super::*;
+
+    #[test]
+    fn lists_that_do_not_fit_are_refused() {
+        assert!(HostChoice::<&'static str>::new([]).is_err());
+        assert!(HostChoice::new(["Savings", "Account with a long name"]).is_err());
+        assert!(HostChoice::new(["Token"; MAX_HOST_CHOICES + 1]).is_err());
+        assert!(HostChoice::new(["Token"; MAX_HOST_CHOICES]).is_ok());
+    }
+
+    #[test]
+    fn returns_the_index() {
+        let mut h = Harness::new(HostChoice::new(["Alice", "Bob", "Carol"]).unwrap());
+        assert_eq!(h.click(PhysicalButton::Right), None);
+        assert_eq!(h.click_both(), Some(1));
+
+        // Going round from the first item to the last one.
+        let mut h = Harness::new(HostChoice::new(["Alice", "Bob", "Carol"]).unwrap());
+        assert_eq!(h.click(PhysicalButton::Left), None);
+        assert_eq!(h.click_both(), Some(2));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod choice;
 pub mod choice_item;
 
+pub mod account_choice;
+pub mod host_choice;
 pub mod number_input;
 pub mod passphrase;
 pub mod pin;
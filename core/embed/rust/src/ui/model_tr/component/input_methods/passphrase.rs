@@ -1,19 +1,23 @@
 use crate::{
-    strutil::StringType,
-    trezorhal::random,
+    strutil::{SecretString, StringType},
     ui::{
-        component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
+        carry,
+        component::{
+            text::common::{InputMethod, TextBox, TextBoxFull, MAX_PASSPHRASE_LENGTH},
+            Child, Component, ComponentExt, Event, EventCtx,
+        },
+        context::settings,
         display::Icon,
-        geometry::Rect,
-        util::char_to_string,
+        geometry::{LinearSplit, Rect, Slot},
+        random,
+        translations::TranslatedString,
+        util::{char_to_string, ResultExt},
     },
 };
 
-use heapless::String;
-
 use super::super::{
-    theme, ButtonDetails, ButtonLayout, CancelConfirmMsg, ChangingTextLine, ChoiceFactory,
-    ChoiceItem, ChoicePage,
+    theme, ButtonAction, ButtonLayout, ButtonPos, CancelConfirmMsg, ChangingTextLine,
+    ChoiceFactory, ChoiceItem, ChoicePage,
 };
 
 /// Defines the choices currently available on the screen
@@ -26,11 +30,8 @@ enum ChoiceCategory {
     SpecialSymbol,
 }
 
-const MAX_PASSPHRASE_LENGTH: usize = 50;
-
 const DIGITS: &str = "0123456789";
-const LOWERCASE_LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
-const UPPERCASE_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LETTER_COUNT: usize = 26;
 const SPECIAL_SYMBOLS: &str = "_<>.:@/|\\!()+%&-[]?{},\'`;\"~$^=*#";
 
 const MENU_LENGTH: usize = 8;
@@ -116,8 +117,9 @@ enum PassphraseAction {
 /// Get a character at a specified index for a specified category.
 fn get_char(current_category: &ChoiceCategory, index: usize) -> char {
     let group = match current_category {
-        ChoiceCategory::LowercaseLetter => LOWERCASE_LETTERS,
-        ChoiceCategory::UppercaseLetter => UPPERCASE_LETTERS,
+        // Letters in the order of the keyboard layout selected by the user.
+        ChoiceCategory::LowercaseLetter => settings().keyboard_layout.letters(false),
+        ChoiceCategory::UppercaseLetter => settings().keyboard_layout.letters(true),
         ChoiceCategory::Digit => DIGITS,
         ChoiceCategory::SpecialSymbol => SPECIAL_SYMBOLS,
         ChoiceCategory::Menu => unreachable!(),
@@ -129,8 +131,8 @@ fn get_char(current_category: &ChoiceCategory, index: usize) -> char {
 /// (does not count the extra MENU choice for characters)
 fn get_category_length(current_category: &ChoiceCategory) -> usize {
     match current_category {
-        ChoiceCategory::LowercaseLetter => LOWERCASE_LETTERS.len(),
-        ChoiceCategory::UppercaseLetter => UPPERCASE_LETTERS.len(),
+        ChoiceCategory::LowercaseLetter => LETTER_COUNT,
+        ChoiceCategory::UppercaseLetter => LETTER_COUNT,
         ChoiceCategory::Digit => DIGITS.len(),
         ChoiceCategory::SpecialSymbol => SPECIAL_SYMBOLS.len(),
         ChoiceCategory::Menu => MENU.len(),
@@ -188,11 +190,16 @@ impl ChoiceFactoryPassphrase {
             }
         }
 
-        let mut menu_item = ChoiceItem::new(text, ButtonLayout::default_three_icons());
+        let mut menu_item = if matches!(action, PassphraseAction::Enter) {
+            TranslatedString::ButtonEnter
+                .map_translated(|text| ChoiceItem::new(text, ButtonLayout::default_three_icons()))
+        } else {
+            ChoiceItem::new(text, ButtonLayout::default_three_icons())
+        };
 
         // Action buttons have different middle button text
         if show_confirm {
-            let confirm_btn = ButtonDetails::armed_text("CONFIRM".into());
+            let confirm_btn = ButtonAction::Confirm.default_details(ButtonPos::Middle);
             menu_item.set_middle_btn(Some(confirm_btn));
         }
 
@@ -252,11 +259,15 @@ impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryPassphrase {
 /// Component for entering a passphrase.
 pub struct PassphraseEntry<T: StringType + Clone> {
     choice_page: ChoicePage<ChoiceFactoryPassphrase, T, PassphraseAction>,
-    passphrase_dots: Child<ChangingTextLine<String<MAX_PASSPHRASE_LENGTH>>>,
+    passphrase_dots: Child<ChangingTextLine<MAX_PASSPHRASE_LENGTH>>,
     show_plain_passphrase: bool,
+    /// Never hide the characters, for text that is not secret.
+    always_plain: bool,
     show_last_digit: bool,
     textbox: TextBox<MAX_PASSPHRASE_LENGTH>,
     current_category: ChoiceCategory,
+    /// Put the confirmed passphrase into `carry` for the next screen.
+    carry_out: bool,
 }
 
 impl<T> PassphraseEntry<T>
@@ -264,45 +275,86 @@ where
     T: StringType + Clone,
 {
     pub fn new() -> Self {
+        Self::with_prefill(SecretString::new())
+    }
+
+    /// Entry with `passphrase` already entered, e.g. the one taken from
+    /// `carry`.
+    pub fn with_prefill(passphrase: SecretString<MAX_PASSPHRASE_LENGTH>) -> Self {
+        let is_empty = passphrase.is_empty();
+        let dots = Self::dots(&passphrase, false);
         Self {
-            choice_page: ChoicePage::new(ChoiceFactoryPassphrase::new(ChoiceCategory::Menu, true))
-                .with_carousel(true)
-                .with_initial_page_counter(random_menu_position()),
-            passphrase_dots: Child::new(ChangingTextLine::center_mono(String::new())),
+            choice_page: ChoicePage::new(ChoiceFactoryPassphrase::new(
+                ChoiceCategory::Menu,
+                is_empty,
+            ))
+            .with_ticks(true)
+            .with_carousel(true)
+            .with_initial_page_counter(random_menu_position()),
+            passphrase_dots: Child::new(ChangingTextLine::center_mono(&dots)),
             show_plain_passphrase: false,
+            always_plain: false,
             show_last_digit: false,
-            textbox: TextBox::empty(),
+            textbox: TextBox::new(passphrase),
             current_category: ChoiceCategory::Menu,
+            carry_out: false,
+        }
+    }
+
+    /// Keep the confirmed passphrase in `carry`, so that the next screen of
+    /// the flow can be prefilled with it.
+    pub fn with_carry_out(mut self) -> Self {
+        self.carry_out = true;
+        self
+    }
+
+    /// Accept at most `max_len` bytes, e.g. for values shorter than a
+    /// passphrase entered with the same keyboard.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.textbox = self.textbox.with_max_len(max_len);
+        self
+    }
+
+    /// Show the entered text instead of the dots, e.g. for a name.
+    pub fn with_plain_text(mut self) -> Self {
+        self.always_plain = true;
+        self.passphrase_dots = Child::new(ChangingTextLine::center_mono(self.passphrase()));
+        self
+    }
+
+    /// Asterisks in place of the characters of `passphrase`, except possibly
+    /// the last one.
+    fn dots(passphrase: &str, show_last_digit: bool) -> SecretString<MAX_PASSPHRASE_LENGTH> {
+        let mut dots = SecretString::new();
+        let Some(last) = passphrase.chars().last() else {
+            return dots;
+        };
+        for _ in 0..passphrase.len() - 1 {
+            unwrap!(dots.push('*'));
         }
+        let last_char = if show_last_digit { last } else { '*' };
+        unwrap!(dots.push(last_char));
+        dots
     }
 
     fn update_passphrase_dots(&mut self, ctx: &mut EventCtx) {
-        let text_to_show = if self.show_plain_passphrase {
-            String::from(self.passphrase())
-        } else if self.is_empty() {
-            String::from("")
+        let text_to_show = if self.always_plain || self.show_plain_passphrase {
+            let mut plain = SecretString::new();
+            unwrap!(plain.push_str(self.passphrase()));
+            plain
         } else {
-            // Showing asterisks and possibly the last digit.
-            let mut dots: String<MAX_PASSPHRASE_LENGTH> = String::new();
-            for _ in 0..self.textbox.len() - 1 {
-                unwrap!(dots.push('*'));
-            }
-            let last_char = if self.show_last_digit {
-                unwrap!(self.textbox.content().chars().last())
-            } else {
-                '*'
-            };
-            unwrap!(dots.push(last_char));
-            dots
+            Self::dots(self.passphrase(), self.show_last_digit)
         };
         self.passphrase_dots.mutate(ctx, |ctx, passphrase_dots| {
-            passphrase_dots.update_text(text_to_show);
+            passphrase_dots.update_text(&text_to_show);
             passphrase_dots.request_complete_repaint(ctx);
         });
     }
 
     fn append_char(&mut self, ctx: &mut EventCtx, ch: char) {
-        self.textbox.append(ctx, ch);
+        self.textbox
+            .append(ctx, ch)
+            .assert_if_debugging_ui("TextBox is full");
     }
 
     fn delete_last_digit(&mut self, ctx: &mut EventCtx) {
@@ -353,6 +405,33 @@ where
     }
 }
 
+impl<T> InputMethod for PassphraseEntry<T>
+where
+    T: StringType + Clone,
+{
+    fn content(&self) -> &str {
+        self.passphrase()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        self.textbox.append(ctx, ch)?;
+        self.show_last_digit = true;
+        self.update_passphrase_dots(ctx);
+        ctx.request_paint();
+        Ok(())
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.delete_last_digit(ctx);
+        self.update_passphrase_dots(ctx);
+        if self.is_empty() {
+            // Allowing for DELETE/CANCEL change
+            self.show_menu_page(ctx);
+        }
+        ctx.request_paint();
+    }
+}
+
 impl<T> Component for PassphraseEntry<T>
 where
     T: StringType + Clone,
@@ -360,8 +439,11 @@ where
     type Msg = CancelConfirmMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let passphrase_area_height = self.passphrase_dots.inner().needed_height();
-        let (passphrase_area, choice_area) = bounds.split_top(passphrase_area_height);
+        let [passphrase_area, choice_area] = LinearSplit::vertical([
+            Slot::fixed(self.passphrase_dots.inner().needed_height()),
+            Slot::weighted(1),
+        ])
+        .split(bounds);
         self.passphrase_dots.place(passphrase_area);
         self.choice_page.place(choice_area);
         bounds
@@ -402,6 +484,9 @@ where
                     }
                 }
                 PassphraseAction::Enter => {
+                    if self.carry_out {
+                        carry::put(self.passphrase());
+                    }
                     return Some(CancelConfirmMsg::Confirmed);
                 }
                 PassphraseAction::Show => {
@@ -437,6 +522,10 @@ where
         self.passphrase_dots.paint();
         self.choice_page.paint();
     }
+
+    fn help(&self) -> Option<TranslatedString> {
+        Some(TranslatedString::HelpPassphrase)
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
@@ -462,3 +551,28 @@ where
         t.child("choice_page", &self.choice_page);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    #[test]
+    fn plain_text_up_to_the_limit() {
+        let mut h = Harness::new(
+            PassphraseEntry::<&'static str>::new()
+                .with_plain_text()
+                .with_max_len(3),
+        );
+        for ch in "abc".chars() {
+            assert_eq!(h.mutate(|ctx, entry| entry.append(ctx, ch)), Ok(()));
+        }
+        assert_eq!(
+            h.mutate(|ctx, entry| entry.append(ctx, 'd')),
+            Err(TextBoxFull)
+        );
+        // Shown as it is, not as dots.
+        assert_eq!(h.inner().passphrase_dots.inner().get_text(), "abc");
+    }
+}
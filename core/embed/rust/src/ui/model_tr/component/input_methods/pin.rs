@@ -1,18 +1,26 @@
+use heapless::String;
+
 use crate::{
-    strutil::StringType,
-    trezorhal::random,
+    strutil::{SecretString, StringType},
+    time::Duration,
     ui::{
-        component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
+        component::{
+            text::common::{InputMethod, TextBox, TextBoxFull, MAX_PIN_LENGTH},
+            AttrUpdate, Child, Component, ComponentExt, Event, EventCtx, TimerKind,
+        },
         display::{Font, Icon},
-        geometry::Rect,
+        geometry::{LinearSplit, Rect, Slot},
+        message_format::format_translated,
+        random::{self, PinShuffle},
+        translations::TranslatedString,
+        util::ResultExt,
     },
 };
 
 use super::super::{
-    theme, ButtonDetails, ButtonLayout, CancelConfirmMsg, ChangingTextLine, ChoiceFactory,
-    ChoiceItem, ChoicePage,
+    theme, ButtonAction, ButtonLayout, ButtonPos, CancelConfirmMsg, ChangingTextLine,
+    ChoiceFactory, ChoiceItem, ChoicePage,
 };
-use heapless::String;
 
 #[derive(Clone, Copy)]
 enum PinAction {
@@ -22,8 +30,18 @@ enum PinAction {
     Digit(char),
 }
 
-const MAX_PIN_LENGTH: usize = 50;
 const EMPTY_PIN_STR: &str = "_";
+const WRONG_PIN_STR: &str = "WRONG PIN";
+/// The prompt is cut off to one line anyway.
+const HEADER_LENGTH: usize = 32;
+const _: () = assert!(WRONG_PIN_STR.len() <= HEADER_LENGTH);
+
+/// How long the last digit entered stays visible.
+const LAST_DIGIT_TIMEOUT: Duration = Duration::from_millis(1000);
+/// How long the whole PIN stays visible after SHOW.
+const REAL_PIN_TIMEOUT: Duration = Duration::from_secs(3);
+/// How long WRONG PIN is shown before the prompt, unless a button is pressed.
+const WARNING_TIMEOUT: Duration = Duration::from_secs(2);
 
 const CHOICE_LENGTH: usize = 13;
 const NUMBER_START_INDEX: usize = 3;
@@ -57,12 +75,16 @@ impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryPIN {
 
     fn get(&self, choice_index: usize) -> (Self::Item, Self::Action) {
         let (choice_str, action, icon, without_release) = CHOICES[choice_index];
-
-        let mut choice_item = ChoiceItem::new(choice_str, ButtonLayout::default_three_icons());
+        let mut choice_item = if matches!(action, PinAction::Enter) {
+            TranslatedString::ButtonEnter
+                .map_translated(|text| ChoiceItem::new(text, ButtonLayout::default_three_icons()))
+        } else {
+            ChoiceItem::new(choice_str, ButtonLayout::default_three_icons())
+        };
 
         // Action buttons have different middle button text
         if !matches!(action, PinAction::Digit(_)) {
-            let confirm_btn = ButtonDetails::armed_text("CONFIRM".into());
+            let confirm_btn = ButtonAction::Confirm.default_details(ButtonPos::Middle);
             choice_item.set_middle_btn(Some(confirm_btn));
         }
 
@@ -87,15 +109,20 @@ impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryPIN {
 /// Component for entering a PIN.
 pub struct PinEntry<T: StringType + Clone> {
     choice_page: ChoicePage<ChoiceFactoryPIN, T, PinAction>,
-    header_line: Child<ChangingTextLine<String<MAX_PIN_LENGTH>>>,
-    pin_line: Child<ChangingTextLine<String<MAX_PIN_LENGTH>>>,
+    header_line: Child<ChangingTextLine<HEADER_LENGTH>>,
+    /// Dots for each of the digits, or the subprompt.
+    pin_line: Child<ChangingTextLine<MAX_PIN_LENGTH>>,
     prompt: T,
     subprompt: T,
+    /// Remaining attempts, shown instead of the subprompt once the firmware
+    /// updates them.
+    attempts: Option<String<MAX_PIN_LENGTH>>,
     /// Whether we already show the "real" prompt (not the warning).
     showing_real_prompt: bool,
     show_real_pin: bool,
     show_last_digit: bool,
     textbox: TextBox<MAX_PIN_LENGTH>,
+    shuffle: PinShuffle,
 }
 
 impl<T> PinEntry<T>
@@ -109,17 +136,9 @@ where
         // any button click.)
         let show_subprompt = !subprompt.as_ref().is_empty();
         let (showing_real_prompt, header_line_content, pin_line_content) = if show_subprompt {
-            (
-                false,
-                String::from("WRONG PIN"),
-                String::from(subprompt.as_ref()),
-            )
+            (false, WRONG_PIN_STR, subprompt.as_ref())
         } else {
-            (
-                true,
-                String::from(prompt.as_ref()),
-                String::from(EMPTY_PIN_STR),
-            )
+            (true, prompt.as_ref(), EMPTY_PIN_STR)
         };
 
         let mut pin_line = ChangingTextLine::center_bold(pin_line_content).without_ellipsis();
@@ -139,14 +158,34 @@ where
             ),
             pin_line: Child::new(pin_line),
             subprompt,
+            attempts: None,
             prompt,
             showing_real_prompt,
             show_real_pin: false,
             show_last_digit: false,
-            textbox: TextBox::empty(),
+            textbox: TextBox::for_input::<MAX_PIN_LENGTH>(),
+            shuffle: PinShuffle::PerKeypress,
         }
     }
 
+    /// Randomize the initial digit according to `shuffle`, by default a
+    /// random digit is offered after every keypress.
+    pub fn with_shuffle(mut self, shuffle: PinShuffle) -> Self {
+        self.shuffle = shuffle;
+        if !shuffle.on_start() {
+            self.choice_page = self
+                .choice_page
+                .with_initial_page_counter(NUMBER_START_INDEX);
+        }
+        self
+    }
+
+    fn subprompt(&self) -> &str {
+        self.attempts
+            .as_deref()
+            .unwrap_or_else(|| self.subprompt.as_ref())
+    }
+
     /// Performs overall update of the screen.
     fn update(&mut self, ctx: &mut EventCtx) {
         self.update_pin_line(ctx);
@@ -157,17 +196,17 @@ where
     /// Many possibilities, according to the PIN state.
     fn update_pin_line(&mut self, ctx: &mut EventCtx) {
         let mut used_font = Font::BOLD;
-        let pin_line_text = if self.is_empty() && !self.subprompt.as_ref().is_empty() {
+        let mut dots: SecretString<MAX_PIN_LENGTH> = SecretString::new();
+        let pin_line_text = if self.is_empty() && !self.subprompt().is_empty() {
             // Showing the subprompt in NORMAL font
             used_font = Font::NORMAL;
-            String::from(self.subprompt.as_ref())
+            self.subprompt()
         } else if self.is_empty() {
-            String::from(EMPTY_PIN_STR)
+            EMPTY_PIN_STR
         } else if self.show_real_pin {
-            String::from(self.pin())
+            self.textbox.content()
         } else {
             // Showing asterisks and possibly the last digit.
-            let mut dots: String<MAX_PIN_LENGTH> = String::new();
             for _ in 0..self.textbox.len() - 1 {
                 unwrap!(dots.push('*'));
             }
@@ -177,7 +216,7 @@ where
                 '*'
             };
             unwrap!(dots.push(last_char));
-            dots
+            dots.as_str()
         };
 
         self.pin_line.mutate(ctx, |ctx, pin_line| {
@@ -190,7 +229,7 @@ where
     /// Showing the real prompt instead of WRONG PIN
     fn show_prompt(&mut self, ctx: &mut EventCtx) {
         self.header_line.mutate(ctx, |ctx, header_line| {
-            header_line.update_text(String::from(self.prompt.as_ref()));
+            header_line.update_text(self.prompt.as_ref());
             header_line.request_complete_repaint(ctx);
         });
     }
@@ -206,6 +245,40 @@ where
     fn is_empty(&self) -> bool {
         self.textbox.is_empty()
     }
+
+    fn append_digit(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        self.textbox.append(ctx, ch)?;
+        // Choosing random digit to be shown next
+        if self.shuffle.on_keypress() {
+            self.choice_page
+                .set_page_counter(ctx, get_random_digit_position(), true);
+        }
+        self.show_last_digit = true;
+        ctx.start_timer(TimerKind::AutoHidePin, LAST_DIGIT_TIMEOUT);
+        self.update(ctx);
+        Ok(())
+    }
+}
+
+impl<T> InputMethod for PinEntry<T>
+where
+    T: StringType + Clone,
+{
+    fn content(&self) -> &str {
+        self.pin()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        if !ch.is_ascii_digit() {
+            return Err(TextBoxFull);
+        }
+        self.append_digit(ctx, ch)
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.textbox.delete_last(ctx);
+        self.update(ctx);
+    }
 }
 
 impl<T> Component for PinEntry<T>
@@ -215,10 +288,12 @@ where
     type Msg = CancelConfirmMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let header_height = self.header_line.inner().needed_height();
-        let (header_area, rest) = bounds.split_top(header_height);
-        let pin_height = self.pin_line.inner().needed_height();
-        let (pin_area, choice_area) = rest.split_top(pin_height);
+        let [header_area, pin_area, choice_area] = LinearSplit::vertical([
+            Slot::fixed(self.header_line.inner().needed_height()),
+            Slot::fixed(self.pin_line.inner().needed_height()),
+            Slot::weighted(1),
+        ])
+        .split(bounds);
         self.header_line.place(header_area);
         self.pin_line.place(pin_area);
         self.choice_page.place(choice_area);
@@ -226,9 +301,20 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if matches!(event, Event::Attach) && !self.showing_real_prompt {
+            let timeout = ctx.accessibility().timeout(WARNING_TIMEOUT);
+            ctx.start_timer(TimerKind::PinWarning, timeout);
+        }
+        if ctx.timer_fired(TimerKind::PinWarning, event) && !self.showing_real_prompt {
+            self.show_prompt(ctx);
+            self.showing_real_prompt = true;
+        }
+
         // Any non-timer event when showing real PIN should hide it
-        // Same with showing last digit
-        if !matches!(event, Event::Timer(_)) {
+        // Same with showing last digit, and both hide on their own after a while
+        let auto_hide = ctx.timer_fired(TimerKind::AutoHidePin, event);
+        if auto_hide || !matches!(event, Event::Timer(_)) {
+            ctx.cancel(TimerKind::AutoHidePin);
             if self.show_real_pin {
                 self.show_real_pin = false;
                 self.update(ctx)
@@ -242,6 +328,7 @@ where
         // Any button event will show the "real" prompt
         if !self.showing_real_prompt {
             if let Event::Button(_) = event {
+                ctx.cancel(TimerKind::PinWarning);
                 self.show_prompt(ctx);
                 self.showing_real_prompt = true;
             }
@@ -260,6 +347,7 @@ where
                 }
                 PinAction::Show => {
                     self.show_real_pin = true;
+                    ctx.start_timer(TimerKind::AutoHidePin, REAL_PIN_TIMEOUT);
                     self.update(ctx);
                 }
                 PinAction::Enter if !self.is_empty() => {
@@ -267,12 +355,8 @@ where
                     return Some(CancelConfirmMsg::Confirmed);
                 }
                 PinAction::Digit(ch) if !self.is_full() => {
-                    self.textbox.append(ctx, ch);
-                    // Choosing random digit to be shown next
-                    self.choice_page
-                        .set_page_counter(ctx, get_random_digit_position(), true);
-                    self.show_last_digit = true;
-                    self.update(ctx);
+                    self.append_digit(ctx, ch)
+                        .assert_if_debugging_ui("TextBox is full");
                 }
                 _ => {}
             }
@@ -285,6 +369,24 @@ where
         self.pin_line.paint();
         self.choice_page.paint();
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Attempts(attempts) => {
+                self.attempts = Some(format_translated(
+                    TranslatedString::TextAttemptsLeft,
+                    &[i64::from(attempts).into()],
+                ));
+                self.update(ctx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        Some(TranslatedString::HelpPin)
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
@@ -296,8 +398,26 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("PinKeyboard");
-        t.string("subprompt", self.subprompt.as_ref());
+        t.string("subprompt", self.subprompt());
         t.string("pin", self.textbox.content());
         t.child("choice_page", &self.choice_page);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::testing::Harness;
+
+    #[test]
+    fn attempts_replace_subprompt() {
+        let mut pin = Harness::new(PinEntry::new("ENTER PIN", "5 tries left"));
+        assert_eq!(pin.inner().subprompt(), "5 tries left");
+        assert!(pin.update_attr(AttrUpdate::Attempts(4)));
+        assert_eq!(pin.inner().subprompt(), "4 tries left");
+        assert!(pin.paint_requested());
+        assert!(pin.update_attr(AttrUpdate::Attempts(1)));
+        assert_eq!(pin.inner().subprompt(), "Last attempt");
+        assert!(!pin.update_attr(AttrUpdate::Text("ENTER PIN")));
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
     strutil::StringType,
-    trezorhal::{random, wordlist::Wordlist},
+    trezorhal::wordlist::Wordlist,
     ui::{
-        component::{text::common::TextBox, Child, Component, ComponentExt, Event, EventCtx},
-        geometry::Rect,
-        util::char_to_string,
+        component::{
+            text::common::{InputMethod, TextBox, TextBoxFull},
+            Child, Component, ComponentExt, Event, EventCtx,
+        },
+        display::Font,
+        geometry::{Alignment, LinearSplit, Rect, Slot},
+        random,
+        util::{char_to_string, ResultExt},
     },
 };
 
@@ -29,19 +34,37 @@ const DELETE_INDEX: usize = 0;
 const INITIAL_PAGE_COUNTER: usize = DELETE_INDEX + 1;
 
 const PROMPT: &str = "_";
+/// Chosen letters followed by the prompt.
+const LETTERS_LENGTH: usize = MAX_WORD_LENGTH + PROMPT.len();
+
+/// Long enough for the number of words in a wordlist.
+const CANDIDATES_LENGTH: usize = 4;
+/// Width of the number of candidate words right of the chosen letters, the
+/// letters stay centered.
+const CANDIDATES_WIDTH: i16 = 22;
 
 /// Choosing random choice index, disregarding DELETE option
 fn get_random_position(num_choices: usize) -> usize {
     random::uniform_between(INITIAL_PAGE_COUNTER as u32, (num_choices - 1) as u32) as usize
 }
 
-/// Type of the wordlist, deciding the list of words to be used
+/// Type of the wordlist, deciding the list of words to be used, for the lists
+/// compiled into the firmware
 #[derive(Clone, Copy)]
 pub enum WordlistType {
     Bip39,
     Slip39,
 }
 
+impl WordlistType {
+    fn wordlist(self) -> Wordlist {
+        match self {
+            Self::Bip39 => Wordlist::bip39(),
+            Self::Slip39 => Wordlist::slip39(),
+        }
+    }
+}
+
 struct ChoiceFactoryWordlist {
     wordlist: Wordlist,
     offer_words: bool,
@@ -50,12 +73,8 @@ struct ChoiceFactoryWordlist {
 }
 
 impl ChoiceFactoryWordlist {
-    pub fn new(wordlist_type: WordlistType, prefix: &str) -> Self {
-        let wordlist = match wordlist_type {
-            WordlistType::Bip39 => Wordlist::bip39(),
-            WordlistType::Slip39 => Wordlist::slip39(),
-        }
-        .filter_prefix(prefix);
+    pub fn new(wordlist: &Wordlist, prefix: &str) -> Self {
+        let wordlist = wordlist.filter_prefix(prefix);
         let offer_words = wordlist.len() < OFFER_WORDS_THRESHOLD;
         let word_random_order: Vec<usize, OFFER_WORDS_THRESHOLD> = if offer_words {
             // Filling slice with numbers 0..wordlist.len() and shuffling them
@@ -122,13 +141,16 @@ impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryWordlist {
     }
 }
 
-/// Component for entering a mnemonic from a wordlist - BIP39 or SLIP39.
+/// Component for entering a mnemonic from a wordlist - BIP39, SLIP39 or any
+/// other `WordList`.
 pub struct WordlistEntry<T: StringType + Clone> {
     choice_page: ChoicePage<ChoiceFactoryWordlist, T, WordlistAction>,
-    chosen_letters: Child<ChangingTextLine<String<{ MAX_WORD_LENGTH + 1 }>>>,
+    chosen_letters: Child<ChangingTextLine<LETTERS_LENGTH>>,
+    /// How many words start with the chosen letters.
+    candidates: Child<ChangingTextLine<CANDIDATES_LENGTH>>,
     textbox: TextBox<MAX_WORD_LENGTH>,
     offer_words: bool,
-    wordlist_type: WordlistType,
+    wordlist: Wordlist,
 }
 
 impl<T> WordlistEntry<T>
@@ -136,25 +158,35 @@ where
     T: StringType + Clone,
 {
     pub fn new(wordlist_type: WordlistType) -> Self {
-        let choices = ChoiceFactoryWordlist::new(wordlist_type, "");
+        Self::with_wordlist(wordlist_type.wordlist())
+    }
+
+    pub fn with_wordlist(wordlist: Wordlist) -> Self {
+        let choices = ChoiceFactoryWordlist::new(&wordlist, "");
         let choices_count = <ChoiceFactoryWordlist as ChoiceFactory<T>>::count(&choices);
         Self {
             // Starting at random letter position
+            // Items cut off at the edges are only previewed, so that the next
+            // letters or words are still hinted at in both directions.
             choice_page: ChoicePage::new(choices)
-                .with_incomplete(true)
+                .with_preview(true)
+                .with_ticks(true)
                 .with_carousel(true)
                 .with_initial_page_counter(get_random_position(choices_count)),
-            chosen_letters: Child::new(ChangingTextLine::center_mono(String::from(PROMPT))),
+            chosen_letters: Child::new(ChangingTextLine::center_mono(PROMPT)),
+            candidates: Child::new(
+                ChangingTextLine::new("", Font::NORMAL, Alignment::End).without_ellipsis(),
+            ),
             textbox: TextBox::empty(),
             offer_words: false,
-            wordlist_type,
+            wordlist,
         }
     }
 
     /// Gets up-to-date choices for letters or words.
     fn get_current_choices(&mut self) -> ChoiceFactoryWordlist {
         // Narrowing the word list
-        ChoiceFactoryWordlist::new(self.wordlist_type, self.textbox.content())
+        ChoiceFactoryWordlist::new(&self.wordlist, self.textbox.content())
     }
 
     fn get_last_textbox_letter(&self) -> Option<char> {
@@ -198,6 +230,7 @@ where
     fn update(&mut self, ctx: &mut EventCtx) {
         self.update_chosen_letters(ctx);
         let new_choices = self.get_current_choices();
+        self.update_candidates(ctx, new_choices.wordlist.len());
         self.offer_words = new_choices.offer_words;
         let new_page_counter = self.get_new_page_counter(&new_choices);
         // Not using carousel in case of words, as that looks weird in case
@@ -209,12 +242,50 @@ where
 
     /// Reflects currently chosen letters in the textbox.
     fn update_chosen_letters(&mut self, ctx: &mut EventCtx) {
-        let text = build_string!({ MAX_WORD_LENGTH + 1 }, self.textbox.content(), PROMPT);
+        let text = build_string!(LETTERS_LENGTH, self.textbox.content(), PROMPT);
         self.chosen_letters.mutate(ctx, |ctx, chosen_letters| {
-            chosen_letters.update_text(text);
+            chosen_letters.update_text(&text);
             chosen_letters.request_complete_repaint(ctx);
         });
     }
+
+    /// Shows how many words the chosen letters still leave, nothing until
+    /// there are some letters.
+    fn update_candidates(&mut self, ctx: &mut EventCtx, count: usize) {
+        let show = !self.textbox.is_empty();
+        self.candidates.mutate(ctx, |ctx, candidates| {
+            candidates.update_text(inttostr!(count as u16));
+            candidates.show_or_not(show);
+            candidates.request_complete_repaint(ctx);
+        });
+    }
+}
+
+impl<T> InputMethod for WordlistEntry<T>
+where
+    T: StringType + Clone,
+{
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        self.textbox.append(ctx, ch)?;
+        self.update(ctx);
+        Ok(())
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.textbox.delete_last(ctx);
+        self.update(ctx);
+    }
+
+    fn completions(&self, sink: &mut dyn FnMut(&'static str)) {
+        self.wordlist
+            .filter_prefix(self.textbox.content())
+            .iter()
+            .for_each(sink);
+    }
 }
 
 impl<T> Component for WordlistEntry<T>
@@ -224,9 +295,20 @@ where
     type Msg = &'static str;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        let letters_area_height = self.chosen_letters.inner().needed_height();
-        let (letters_area, choice_area) = bounds.split_top(letters_area_height);
-        self.chosen_letters.place(letters_area);
+        let [letters_area, choice_area] = LinearSplit::vertical([
+            Slot::fixed(self.chosen_letters.inner().needed_height()),
+            Slot::weighted(1),
+        ])
+        .split(bounds);
+        // The letters stay centered, with the candidates on the right.
+        let [_, chosen_area, candidates_area] = LinearSplit::horizontal([
+            Slot::fixed(CANDIDATES_WIDTH),
+            Slot::weighted(1),
+            Slot::fixed(CANDIDATES_WIDTH),
+        ])
+        .split(letters_area);
+        self.chosen_letters.place(chosen_area);
+        self.candidates.place(candidates_area);
         self.choice_page.place(choice_area);
         bounds
     }
@@ -244,7 +326,9 @@ where
                     self.update(ctx);
                 }
                 WordlistAction::Letter(letter) => {
-                    self.textbox.append(ctx, letter);
+                    self.textbox
+                        .append(ctx, letter)
+                        .assert_if_debugging_ui("TextBox is full");
                     self.update(ctx);
                 }
                 WordlistAction::Word(word) => {
@@ -257,6 +341,7 @@ where
 
     fn paint(&mut self) {
         self.chosen_letters.paint();
+        self.candidates.paint();
         self.choice_page.paint();
     }
 }
@@ -281,3 +366,30 @@ where
         t.child("choice_page", &self.choice_page);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    fn candidates(h: &Harness<WordlistEntry<&'static str>>) -> &str {
+        h.inner().candidates.inner().get_text()
+    }
+
+    #[test]
+    fn candidates_follow_the_letters() {
+        let mut h = Harness::new(WordlistEntry::with_wordlist(Wordlist::bip39()));
+
+        h.mutate(|ctx, w| w.append(ctx, 'a').unwrap());
+        let count = Wordlist::bip39().filter_prefix("a").len() as u16;
+        assert_eq!(candidates(&h), inttostr!(count));
+        assert!(!h.inner().offer_words);
+
+        h.mutate(|ctx, w| w.append(ctx, 'b').unwrap());
+        h.mutate(|ctx, w| w.append(ctx, 's').unwrap());
+        // "absent", "absorb", "abstract" and "absurd" are offered as words.
+        assert_eq!(candidates(&h), "4");
+        assert!(h.inner().offer_words);
+    }
+}
@@ -0,0 +1,331 @@
+use heapless::Vec;
+
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::{
+        component::{Component, Event, EventCtx},
+        display::Font,
+        geometry::Rect,
+    },
+};
+
+use super::{ButtonLayout, ChoiceFactory, ChoiceItem, ChoicePage};
+
+/// Most items the list and the items to add can have together.
+pub const MAX_LIST_ITEMS: usize = 16;
+/// Longest item in bytes, so that it fits the screen in `Font::NORMAL`.
+const MAX_LIST_ITEM_LENGTH: usize = 20;
+
+const ADD: &str = "ADD";
+const DONE: &str = "DONE";
+const MOVE_UP: &str = "MOVE UP";
+const MOVE_DOWN: &str = "MOVE DOWN";
+const DELETE: &str = "DELETE";
+const KEEP: &str = "KEEP";
+const BACK: &str = "BACK";
+
+pub enum ListEditorMsg {
+    Done,
+}
+
+#[derive(Clone, Copy)]
+enum EditAction {
+    Open(usize),
+    MoveUp,
+    MoveDown,
+    Delete,
+    ConfirmDelete,
+    Add,
+    Insert(usize),
+    Back,
+    Done,
+}
+
+/// Either the list itself, the actions on one of its items, the confirmation
+/// of deleting one, or the items that can be added.
+enum ChoiceFactoryList<T> {
+    Main {
+        items: Vec<T, MAX_LIST_ITEMS>,
+        can_add: bool,
+    },
+    Actions {
+        first: bool,
+        last: bool,
+    },
+    ConfirmDelete(T),
+    Add(Vec<T, MAX_LIST_ITEMS>),
+}
+
+impl<T> ChoiceFactoryList<T> {
+    fn actions(first: bool, last: bool) -> Vec<(&'static str, EditAction), 4> {
+        let mut actions = Vec::new();
+        if !first {
+            unwrap!(actions.push((MOVE_UP, EditAction::MoveUp)));
+        }
+        if !last {
+            unwrap!(actions.push((MOVE_DOWN, EditAction::MoveDown)));
+        }
+        unwrap!(actions.push((DELETE, EditAction::Delete)));
+        unwrap!(actions.push((BACK, EditAction::Back)));
+        actions
+    }
+}
+
+impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactoryList<T> {
+    type Action = EditAction;
+    type Item = ChoiceItem<T>;
+
+    fn count(&self) -> usize {
+        match self {
+            // Followed by adding an item, if there are any, and leaving.
+            Self::Main { items, can_add } => items.len() + *can_add as usize + 1,
+            Self::Actions { first, last } => Self::actions(*first, *last).len(),
+            Self::ConfirmDelete(_) => 2,
+            // Followed by going back.
+            Self::Add(available) => available.len() + 1,
+        }
+    }
+
+    fn get(&self, choice_index: usize) -> (Self::Item, Self::Action) {
+        let layout = ButtonLayout::default_three_icons();
+        match self {
+            Self::Main { items, can_add } => match items.get(choice_index) {
+                Some(item) => (
+                    ChoiceItem::new(item, layout).with_font(Font::NORMAL),
+                    EditAction::Open(choice_index),
+                ),
+                None if *can_add && choice_index == items.len() => {
+                    (ChoiceItem::new(ADD, layout), EditAction::Add)
+                }
+                None => (ChoiceItem::new(DONE, layout), EditAction::Done),
+            },
+            Self::Actions { first, last } => {
+                let (text, action) = Self::actions(*first, *last)[choice_index];
+                (ChoiceItem::new(text, layout), action)
+            }
+            Self::ConfirmDelete(item) => {
+                let (text, action) = match choice_index {
+                    0 => (KEEP, EditAction::Back),
+                    _ => (DELETE, EditAction::ConfirmDelete),
+                };
+                (ChoiceItem::new(text, layout).with_subtext(item), action)
+            }
+            Self::Add(available) => match available.get(choice_index) {
+                Some(item) => (
+                    ChoiceItem::new(item, layout).with_font(Font::NORMAL),
+                    EditAction::Insert(choice_index),
+                ),
+                None => (ChoiceItem::new(BACK, layout), EditAction::Back),
+            },
+        }
+    }
+}
+
+/// Remove the item at `index`, keeping the order of the rest.
+fn remove<T, const N: usize>(items: &mut Vec<T, N>, index: usize) -> T {
+    items[index..].rotate_left(1);
+    unwrap!(items.pop())
+}
+
+/// Editor of a list, e.g. of labels or enabled coins. Selecting an item offers
+/// moving it up or down and deleting it, which has to be confirmed. New items
+/// are picked from the given ones and appended. The firmware gets the final
+/// list once the editor is left.
+pub struct ListEditor<T>
+where
+    T: StringType + Clone,
+{
+    choice_page: ChoicePage<ChoiceFactoryList<T>, T, EditAction>,
+    items: Vec<T, MAX_LIST_ITEMS>,
+    /// Items not in the list that can be added, the deleted ones included.
+    available: Vec<T, MAX_LIST_ITEMS>,
+    /// Position of the item whose actions are listed.
+    open: Option<usize>,
+}
+
+impl<T> ListEditor<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(
+        items: impl IntoIterator<Item = T>,
+        available: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Error> {
+        let items = Self::checked(items)?;
+        let available = Self::checked(available)?;
+        if items.len() + available.len() > MAX_LIST_ITEMS {
+            return Err(value_error!("Too many items"));
+        }
+        let choices = Self::main_choices(&items, &available);
+        Ok(Self {
+            choice_page: ChoicePage::new(choices).with_carousel(true),
+            items,
+            available,
+            open: None,
+        })
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    fn checked(items: impl IntoIterator<Item = T>) -> Result<Vec<T, MAX_LIST_ITEMS>, Error> {
+        let mut checked = Vec::new();
+        for item in items {
+            if item.as_ref().len() > MAX_LIST_ITEM_LENGTH {
+                return Err(value_error!("Item too long"));
+            }
+            checked
+                .push(item)
+                .map_err(|_| value_error!("Too many items"))?;
+        }
+        Ok(checked)
+    }
+
+    fn main_choices(items: &[T], available: &[T]) -> ChoiceFactoryList<T> {
+        ChoiceFactoryList::Main {
+            items: items.iter().cloned().collect(),
+            can_add: !available.is_empty(),
+        }
+    }
+
+    /// Back to the list, showing the item at `page`.
+    fn show_list(&mut self, ctx: &mut EventCtx, page: usize) {
+        self.open = None;
+        let choices = Self::main_choices(&self.items, &self.available);
+        self.choice_page.reset(ctx, choices, Some(page), true);
+    }
+
+    fn open(&mut self, ctx: &mut EventCtx, index: usize) {
+        self.open = Some(index);
+        let choices = ChoiceFactoryList::Actions {
+            first: index == 0,
+            last: index == self.items.len() - 1,
+        };
+        self.choice_page.reset(ctx, choices, Some(0), false);
+    }
+
+    fn move_open(&mut self, ctx: &mut EventCtx, up: bool) {
+        if let Some(index) = self.open {
+            let other = if up { index - 1 } else { index + 1 };
+            self.items.swap(index, other);
+            self.show_list(ctx, other);
+        }
+    }
+
+    fn confirm_delete(&mut self, ctx: &mut EventCtx) {
+        if let Some(index) = self.open {
+            let choices = ChoiceFactoryList::ConfirmDelete(self.items[index].clone());
+            self.choice_page.reset(ctx, choices, Some(0), false);
+        }
+    }
+
+    fn delete_open(&mut self, ctx: &mut EventCtx) {
+        if let Some(index) = self.open {
+            let item = remove(&mut self.items, index);
+            // Cannot overflow, both fit `MAX_LIST_ITEMS` together.
+            unwrap!(self.available.push(item));
+            self.show_list(ctx, index.min(self.items.len()));
+        }
+    }
+
+    fn insert(&mut self, ctx: &mut EventCtx, index: usize) {
+        let item = remove(&mut self.available, index);
+        unwrap!(self.items.push(item));
+        self.show_list(ctx, self.items.len() - 1);
+    }
+}
+
+impl<T> Component for ListEditor<T>
+where
+    T: StringType + Clone,
+{
+    type Msg = ListEditorMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.choice_page.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match self.choice_page.event(ctx, event) {
+            Some((EditAction::Open(index), _)) => self.open(ctx, index),
+            Some((EditAction::MoveUp, _)) => self.move_open(ctx, true),
+            Some((EditAction::MoveDown, _)) => self.move_open(ctx, false),
+            Some((EditAction::Delete, _)) => self.confirm_delete(ctx),
+            Some((EditAction::ConfirmDelete, _)) => self.delete_open(ctx),
+            Some((EditAction::Add, _)) => {
+                let choices = ChoiceFactoryList::Add(self.available.clone());
+                self.choice_page.reset(ctx, choices, Some(0), false);
+            }
+            Some((EditAction::Insert(index), _)) => self.insert(ctx, index),
+            Some((EditAction::Back, _)) => {
+                // Back to the item, or to adding one.
+                let page = self.open.unwrap_or(self.items.len());
+                self.show_list(ctx, page);
+            }
+            Some((EditAction::Done, _)) => return Some(ListEditorMsg::Done),
+            None => {}
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.choice_page.paint();
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for ListEditor<T>
+where
+    T: StringType + Clone,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("ListEditor");
+        if let Some(index) = self.open {
+            t.string("open", self.items[index].as_ref());
+        }
+        t.child("choice_page", &self.choice_page);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{event::PhysicalButton, testing::Harness};
+
+    #[test]
+    fn edit() {
+        let editor = ListEditor::new(["BTC", "LTC", "ETH"], ["DOGE"]).unwrap();
+        let mut editor = Harness::new(editor);
+        let right = |editor: &mut Harness<_>, times| {
+            for _ in 0..times {
+                editor.click(PhysicalButton::Right);
+            }
+        };
+
+        // The first item can only move down.
+        editor.click_both();
+        editor.click_both();
+        assert_eq!(editor.inner().items(), ["LTC", "BTC", "ETH"]);
+
+        // Deleting BTC, the list stays on its position.
+        editor.click_both();
+        right(&mut editor, 2);
+        editor.click_both();
+        right(&mut editor, 1);
+        editor.click_both();
+        assert_eq!(editor.inner().items(), ["LTC", "ETH"]);
+
+        // Adding DOGE, BTC is available again.
+        right(&mut editor, 1);
+        editor.click_both();
+        editor.click_both();
+        assert_eq!(editor.inner().items(), ["LTC", "ETH", "DOGE"]);
+
+        right(&mut editor, 2);
+        assert!(matches!(editor.click_both(), Some(ListEditorMsg::Done)));
+    }
+}
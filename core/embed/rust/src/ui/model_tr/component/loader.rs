@@ -28,6 +28,8 @@ enum State {
     Grown,
 }
 
+/// Fills the button of a hold-to-confirm while it is held, an answer to the
+/// user rather than the progress of an operation.
 pub struct Loader<T>
 where
     T: StringType,
@@ -95,7 +97,7 @@ where
         let mut anim = Animation::new(
             display::LOADER_MIN,
             display::LOADER_MAX,
-            self.growing_duration,
+            ctx.accessibility().hold(self.growing_duration),
             now,
         );
         if let State::Shrinking(shrinking) = &self.state {
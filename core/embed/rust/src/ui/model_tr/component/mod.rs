@@ -34,13 +34,17 @@ mod flow_pages;
 mod frame;
 #[cfg(feature = "micropython")]
 mod homescreen;
+mod list_editor;
 mod page;
+mod pin_lockout;
 mod progress;
 mod result_anim;
 mod result_popup;
 mod scrollbar;
+mod settings_menu;
 mod share_words;
 mod show_more;
+mod terms_page;
 mod title;
 
 pub use address_details::AddressDetails;
@@ -51,18 +55,26 @@ pub use flow::Flow;
 pub use flow_pages::{FlowPages, Page};
 pub use frame::{Frame, ScrollableContent, ScrollableFrame};
 #[cfg(feature = "micropython")]
-pub use homescreen::{check_homescreen_format, ConfirmHomescreen, Homescreen, Lockscreen};
+pub use homescreen::{
+    check_homescreen_format, ConfirmHomescreen, Homescreen, HomescreenMsg, Lockscreen,
+};
 pub use input_methods::{
+    account_choice::{Account, AccountChoice, MAX_ACCOUNTS},
+    host_choice::{HostChoice, MAX_HOST_CHOICES},
     number_input::NumberInput,
     passphrase::PassphraseEntry,
     pin::PinEntry,
     simple_choice::SimpleChoice,
     wordlist::{WordlistEntry, WordlistType},
 };
+pub use list_editor::{ListEditor, ListEditorMsg, MAX_LIST_ITEMS};
 pub use page::ButtonPage;
+pub use pin_lockout::PinLockout;
 pub use progress::Progress;
 pub use result_anim::{ResultAnim, ResultAnimMsg};
 pub use result_popup::{ResultPopup, ResultPopupMsg};
 pub use scrollbar::ScrollBar;
+pub use settings_menu::{SettingsMenu, SettingsMenuMsg};
 pub use share_words::ShareWords;
 pub use show_more::{CancelInfoConfirmMsg, ShowMore};
+pub use terms_page::TermsPage;
@@ -1,25 +1,28 @@
 use crate::{
     strutil::StringType,
     ui::{
-        component::{Child, Component, ComponentExt, Event, EventCtx, Pad, PageMsg, Paginate},
+        component::{
+            AttrUpdate, Child, Component, ComponentExt, Event, EventCtx, Pad, PageMsg, Paginate,
+            Transition, PAGE_SELECTOR_MIN_PAGES,
+        },
         display::Color,
         geometry::{Insets, Rect},
     },
 };
 
 use super::{
-    constant, frame::ScrollableContent, theme, ButtonController, ButtonControllerMsg,
-    ButtonDetails, ButtonLayout, ButtonPos,
+    constant, frame::ScrollableContent, theme, ButtonAction, ButtonController, ButtonControllerMsg,
+    ButtonDetails, ButtonLayout, ButtonPos, NumberInput,
 };
 
 pub struct ButtonPage<T, U>
 where
     T: Component + Paginate,
-    U: StringType,
+    U: StringType + Clone,
 {
     page_count: usize,
     active_page: usize,
-    content: Child<T>,
+    content: Child<Transition<T>>,
     pad: Pad,
     /// Left button of the first screen
     cancel_btn_details: Option<ButtonDetails<U>>,
@@ -30,6 +33,9 @@ where
     /// Right button of every screen apart the last one
     next_btn_details: Option<ButtonDetails<U>>,
     buttons: Child<ButtonController<U>>,
+    /// Choosing the page number to jump to, shown over the whole page.
+    page_selector: Option<Child<NumberInput<U>>>,
+    area: Rect,
 }
 
 impl<T, U> ButtonPage<T, U>
@@ -41,16 +47,18 @@ where
         Self {
             page_count: 0, // will be set in place()
             active_page: 0,
-            content: Child::new(content),
+            content: Child::new(Transition::new(content, background)),
             pad: Pad::with_background(background).with_clear(),
             cancel_btn_details: Some(ButtonDetails::cancel_icon()),
-            confirm_btn_details: Some(ButtonDetails::text("CONFIRM".into())),
+            confirm_btn_details: Some(ButtonAction::Confirm.default_details(ButtonPos::Right)),
             back_btn_details: Some(ButtonDetails::up_arrow_icon()),
             next_btn_details: Some(ButtonDetails::down_arrow_icon_wide()),
             // Setting empty layout for now, we do not yet know the page count.
             // Initial button layout will be set in `place()` after we can call
             // `content.page_count()`.
             buttons: Child::new(ButtonController::new(ButtonLayout::empty())),
+            page_selector: None,
+            area: Rect::zero(),
         }
     }
 
@@ -105,11 +113,37 @@ where
         self.content.mutate(ctx, |ctx, content| {
             content.change_page(self.active_page);
             content.request_complete_repaint(ctx);
+            content.play(ctx);
         });
+        ctx.set_active_page(self.active_page);
         self.update_buttons(ctx);
         self.pad.clear();
     }
 
+    /// Letting the user choose the page to go to directly, instead of pressing
+    /// the buttons many times.
+    fn open_page_selector(&mut self, ctx: &mut EventCtx) {
+        let count = self.page_count as u32;
+        let current = self.active_page as u32 + 1;
+        let mut selector = Child::new(NumberInput::new(1, count, current));
+        selector.place(self.area);
+        self.page_selector = Some(selector);
+        // The selector gets the release of the long press.
+        self.buttons
+            .mutate(ctx, |ctx, buttons| buttons.reset_state(ctx));
+        ctx.request_repaint_root();
+    }
+
+    /// Going to the page chosen in the page selector, `page` being numbered from
+    /// 1.
+    fn close_page_selector(&mut self, ctx: &mut EventCtx, page: u32) {
+        self.page_selector = None;
+        self.active_page = (page as usize).saturating_sub(1).min(self.page_count - 1);
+        self.change_page(ctx);
+        // The selector covered everything.
+        ctx.request_repaint_root();
+    }
+
     /// Reflecting the current page in the buttons.
     fn update_buttons(&mut self, ctx: &mut EventCtx) {
         let btn_layout = self.get_button_layout(self.has_previous_page(), self.has_next_page());
@@ -134,10 +168,16 @@ where
     }
 
     /// Get the right button details, depending on whether there is a next
-    /// page.
+    /// page. With many pages, holding it opens the page selector.
     fn get_right_button_details(&self, has_next_page: bool) -> Option<ButtonDetails<U>> {
         if has_next_page {
-            self.next_btn_details.clone()
+            let mut details = self.next_btn_details.clone();
+            if self.page_count >= PAGE_SELECTOR_MIN_PAGES {
+                if let Some(details) = &mut details {
+                    details.send_long_press = details.duration.is_none();
+                }
+            }
+            details
         } else {
             self.confirm_btn_details.clone()
         }
@@ -147,7 +187,7 @@ where
 impl<T, U> ScrollableContent for ButtonPage<T, U>
 where
     T: Component + Paginate,
-    U: StringType,
+    U: StringType + Clone,
 {
     fn page_count(&self) -> usize {
         self.page_count
@@ -177,12 +217,27 @@ where
         self.page_count = self.content.page_count();
         self.set_buttons_for_initial_page(self.page_count);
         self.buttons.place(button_area);
+        self.page_selector.place(bounds);
+        self.area = bounds;
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         ctx.set_page_count(self.page_count());
-        if let Some(ButtonControllerMsg::Triggered(pos, _)) = self.buttons.event(ctx, event) {
+        if let Some(selector) = &mut self.page_selector {
+            if let Some(page) = selector.event(ctx, event) {
+                self.close_page_selector(ctx, page);
+            }
+            return None;
+        }
+        let button_event = self.buttons.event(ctx, event);
+        // Only sent by the next button of long content, see
+        // `get_right_button_details`.
+        if let Some(ButtonControllerMsg::LongPressed(ButtonPos::Right)) = button_event {
+            self.open_page_selector(ctx);
+            return None;
+        }
+        if let Some(ButtonControllerMsg::Triggered(pos, _)) = button_event {
             match pos {
                 ButtonPos::Left => {
                     if self.has_previous_page() {
@@ -215,10 +270,25 @@ where
     }
 
     fn paint(&mut self) {
+        if let Some(selector) = &mut self.page_selector {
+            selector.paint();
+            return;
+        }
         self.pad.paint();
         self.content.paint();
         self.buttons.paint();
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Page(page) => {
+                self.active_page = (page as usize).min(self.page_count - 1);
+                self.change_page(ctx);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
@@ -235,5 +305,44 @@ where
         t.int("page_count", self.page_count as i64);
         t.child("buttons", &self.buttons);
         t.child("content", &self.content);
+        if let Some(selector) = &self.page_selector {
+            t.child("page_selector", selector);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        component::text::paragraphs::{Paragraph, Paragraphs},
+        event::PhysicalButton,
+        testing::{press, release, Harness},
+    };
+
+    #[test]
+    fn page_selector() {
+        let paragraphs: [Paragraph<&'static str>; PAGE_SELECTOR_MIN_PAGES] =
+            core::array::from_fn(|_| Paragraph::new(&theme::TEXT_NORMAL, "Page.").break_after());
+        let page = ButtonPage::<_, &'static str>::new(Paragraphs::new(paragraphs), theme::BG);
+        let mut page = Harness::new(page);
+
+        // A click goes to the next page.
+        page.click(PhysicalButton::Right);
+        assert_eq!(page.inner().active_page, 1);
+
+        // Holding opens the selector, its release does not choose anything.
+        page.event(press(PhysicalButton::Right));
+        page.fire_timers();
+        page.event(release(PhysicalButton::Right));
+        assert!(page.inner().page_selector.is_some());
+        assert_eq!(page.inner().active_page, 1);
+
+        // From page 2 to page 4.
+        page.click(PhysicalButton::Right);
+        page.click(PhysicalButton::Right);
+        page.click_both();
+        assert!(page.inner().page_selector.is_none());
+        assert_eq!(page.inner().active_page, 3);
     }
 }
@@ -0,0 +1,140 @@
+use heapless::String;
+
+use crate::{
+    strutil::StringType,
+    ui::{
+        component::{
+            text::paragraphs::{Paragraph, Paragraphs},
+            Child, Component, Event, EventCtx, Never, Pad,
+        },
+        constant,
+        display::{self, Font, LOADER_MAX},
+        eta::format_countdown,
+        geometry::Rect,
+    },
+};
+
+use super::super::theme;
+
+const TIMER_FONT: Font = Font::BIG;
+const DESCRIPTION_LINES: i16 = 2;
+
+/// Countdown of the wait the storage imposes after wrong PINs, a large timer
+/// over a loader that fills up as the time passes and an explanation below.
+/// Driven by the progress reports of the storage, repainted once the timer
+/// changes, i.e. every second. There is no room for a title.
+pub struct PinLockout<T> {
+    description: Child<Paragraphs<Paragraph<T>>>,
+    timer_pad: Pad,
+    /// Length of the whole wait, at progress zero.
+    seconds: u32,
+    value: u16,
+    timer: String<12>,
+}
+
+impl<T> PinLockout<T>
+where
+    T: StringType,
+{
+    const AREA: Rect = constant::screen();
+
+    pub fn new(description: T, seconds: u32) -> Self {
+        Self {
+            description: Child::new(Paragraphs::new(
+                Paragraph::new(&theme::TEXT_NORMAL, description).centered(),
+            )),
+            timer_pad: Pad::with_background(theme::BG),
+            seconds,
+            value: 0,
+            timer: format_countdown(seconds),
+        }
+    }
+
+    /// Whole seconds left, rounded up so that zero is only shown at the end.
+    fn remaining(&self) -> u32 {
+        let left = LOADER_MAX.saturating_sub(self.value) as u32;
+        (self.seconds * left + LOADER_MAX as u32 - 1) / LOADER_MAX as u32
+    }
+}
+
+impl<T> Component for PinLockout<T>
+where
+    T: StringType,
+{
+    type Msg = Never;
+
+    fn place(&mut self, _bounds: Rect) -> Rect {
+        let (timer, rest) = Self::AREA.split_top(TIMER_FONT.line_height());
+        let (_loader, description) =
+            rest.split_bottom(Font::NORMAL.line_height() * DESCRIPTION_LINES);
+        self.timer_pad.place(timer);
+        self.description.place(description);
+        Self::AREA
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Progress(new_value, _) = event {
+            self.value = new_value;
+            let timer = format_countdown(self.remaining());
+            if timer != self.timer {
+                self.timer = timer;
+                self.timer_pad.clear();
+                ctx.request_paint();
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.timer_pad.paint();
+        display::text_center(
+            self.timer_pad.area.bottom_center(),
+            self.timer.as_str(),
+            TIMER_FONT,
+            theme::FG,
+            theme::BG,
+        );
+        display::loader(self.value, 0, theme::FG, theme::BG, None);
+        self.description.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(Self::AREA);
+        self.description.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for PinLockout<T>
+where
+    T: StringType,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("PinLockout");
+        t.string("timer", self.timer.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    #[test]
+    fn zero_only_at_the_end() {
+        let mut h = Harness::new(PinLockout::new("Please wait", 3600 * 2));
+        assert_eq!(h.inner().timer.as_str(), "2:00:00");
+
+        h.event(Event::Progress(LOADER_MAX - 1, ""));
+        assert_eq!(h.inner().timer.as_str(), "0:08");
+        assert!(h.paint_requested());
+
+        h.clear_paint();
+        h.event(Event::Progress(LOADER_MAX - 1, ""));
+        assert!(!h.paint_requested());
+        h.event(Event::Progress(LOADER_MAX, ""));
+        assert_eq!(h.inner().timer.as_str(), "0:00");
+    }
+}
@@ -1,4 +1,5 @@
 use core::mem;
+use heapless::String;
 
 use crate::{
     error::Error,
@@ -7,11 +8,12 @@ use crate::{
         component::{
             paginated::Paginate,
             text::paragraphs::{Paragraph, Paragraphs},
-            Child, Component, Event, EventCtx, Label, Never, Pad,
+            AttrUpdate, Child, Component, Event, EventCtx, Label, Never, Pad,
         },
         constant,
         display::{self, Font, Icon, LOADER_MAX},
-        geometry::Rect,
+        eta::RateTracker,
+        geometry::{Offset, Rect},
         util::animation_disabled,
     },
 };
@@ -25,6 +27,9 @@ const LOADER_Y_OFFSET_NO_TITLE: i16 = -20;
 // Clippy was complaining about `very complex type used`
 type UpdateDescriptionFn<T, Error> = fn(&str) -> Result<T, Error>;
 
+/// Screen of an operation reporting its progress, determinate or spinning,
+/// optionally with the remaining time. The description under it follows the
+/// reports.
 pub struct Progress<T>
 where
     T: StringType,
@@ -37,6 +42,11 @@ where
     description_pad: Pad,
     update_description: Option<UpdateDescriptionFn<T, Error>>,
     icon: Icon,
+    /// Estimate of the remaining time, shown above the description when
+    /// `Some`.
+    eta: Option<RateTracker>,
+    eta_text: String<24>,
+    eta_pad: Pad,
 }
 
 impl<T> Progress<T>
@@ -57,9 +67,20 @@ where
             description_pad: Pad::with_background(theme::BG),
             update_description: None,
             icon: theme::ICON_TICK_FAT,
+            eta: None,
+            eta_text: String::new(),
+            eta_pad: Pad::with_background(theme::BG),
         }
     }
 
+    /// Show the estimated remaining time. Has no effect in indeterminate mode.
+    pub fn with_eta(mut self) -> Self {
+        if !self.indeterminate {
+            self.eta = Some(RateTracker::new(LOADER_MAX));
+        }
+        self
+    }
+
     pub fn with_title(mut self, title: T) -> Self {
         self.title = Some(Child::new(Label::centered(title, theme::TEXT_BOLD)));
         self
@@ -84,6 +105,45 @@ where
         }
     }
 
+    fn set_value(&mut self, ctx: &mut EventCtx, new_value: u16) {
+        if mem::replace(&mut self.value, new_value) != new_value {
+            self.request_paint(ctx);
+            self.update_eta(ctx);
+        }
+    }
+
+    fn update_eta(&mut self, ctx: &mut EventCtx) {
+        let Some(eta) = &mut self.eta else {
+            return;
+        };
+        let text = eta.update_text(self.value);
+        if text != self.eta_text {
+            self.eta_text = text;
+            self.eta_pad.clear();
+            ctx.request_paint();
+        }
+    }
+
+    /// Returns `false` if the description cannot be changed.
+    fn set_description(&mut self, ctx: &mut EventCtx, new_description: &str) -> bool {
+        let Some(update_description) = self.update_description else {
+            return false;
+        };
+        self.description.mutate(ctx, |ctx, para| {
+            // NOTE: not doing any change for empty new descriptions
+            // (currently, there is no use-case for deleting the description)
+            if !new_description.is_empty() && para.inner_mut().content().as_ref() != new_description
+            {
+                let new_description = unwrap!((update_description)(new_description));
+                para.inner_mut().update(new_description);
+                para.change_page(0); // Recompute bounding box.
+                ctx.request_paint();
+                self.description_pad.clear();
+            }
+        });
+        true
+    }
+
     pub fn value(&self) -> u16 {
         self.value
     }
@@ -125,6 +185,19 @@ where
         let (_loader, description) = rest.split_bottom(
             BOTTOM_DESCRIPTION_MARGIN + Font::NORMAL.line_height() * description_lines,
         );
+        let loader_y_offset = if self.eta.is_some() {
+            // One more line above the description, the loader moves up to
+            // make room for it.
+            let line_height = Font::NORMAL.line_height();
+            self.eta_pad.place(
+                description
+                    .translate(Offset::y(-line_height))
+                    .with_height(line_height),
+            );
+            loader_y_offset - line_height / 2
+        } else {
+            loader_y_offset
+        };
         self.title.place(title);
         self.loader_y_offset = loader_y_offset;
         self.description.place(description);
@@ -137,24 +210,8 @@ where
         self.description.event(ctx, event);
 
         if let Event::Progress(new_value, new_description) = event {
-            if mem::replace(&mut self.value, new_value) != new_value {
-                self.request_paint(ctx);
-            }
-            if let Some(update_description) = self.update_description {
-                self.description.mutate(ctx, |ctx, para| {
-                    // NOTE: not doing any change for empty new descriptions
-                    // (currently, there is no use-case for deleting the description)
-                    if !new_description.is_empty()
-                        && para.inner_mut().content().as_ref() != new_description
-                    {
-                        let new_description = unwrap!((update_description)(new_description));
-                        para.inner_mut().update(new_description);
-                        para.change_page(0); // Recompute bounding box.
-                        ctx.request_paint();
-                        self.description_pad.clear();
-                    }
-                });
-            }
+            self.set_value(ctx, new_value);
+            self.set_description(ctx, new_description);
         }
         None
     }
@@ -178,6 +235,16 @@ where
                 Some((self.icon, theme::FG)),
             );
         }
+        if self.eta.is_some() {
+            self.eta_pad.paint();
+            display::text_center(
+                self.eta_pad.area.bottom_center(),
+                self.eta_text.as_str(),
+                Font::NORMAL,
+                theme::FG,
+                theme::BG,
+            );
+        }
         self.description_pad.paint();
         self.description.paint();
     }
@@ -188,6 +255,17 @@ where
         self.title.bounds(sink);
         self.description.bounds(sink);
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Progress(value) => {
+                self.set_value(ctx, value);
+                true
+            }
+            AttrUpdate::Text(description) => self.set_description(ctx, description),
+            _ => false,
+        }
+    }
 }
 
 // DEBUG-ONLY SECTION BELOW
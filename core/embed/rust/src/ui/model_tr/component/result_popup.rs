@@ -9,6 +9,7 @@ use crate::{
         constant::screen,
         display::toif::Icon,
         geometry::{Insets, LinearPlacement, Rect},
+        sound::Feedback,
     },
 };
 
@@ -28,10 +29,11 @@ where
     area: Rect,
     pad: Pad,
     result_anim: Child<ResultAnim>,
-    headline: Option<Label<&'static str>>,
+    headline: Option<Label<T>>,
     text: Child<Paragraphs<Paragraph<T>>>,
     buttons: Option<Child<ButtonController<T>>>,
     autoclose: bool,
+    feedback: Option<Feedback>,
 }
 
 const ANIM_SIZE: i16 = 18;
@@ -44,17 +46,12 @@ impl<T> ResultPopup<T>
 where
     T: StringType + Clone,
 {
-    pub fn new(
-        icon: Icon,
-        text: T,
-        headline: Option<&'static str>,
-        button_text: Option<&'static str>,
-    ) -> Self {
+    pub fn new(icon: Icon, text: T, headline: Option<T>, button_text: Option<T>) -> Self {
         let p1 = Paragraphs::new(Paragraph::new(&theme::TEXT_BIG, text))
             .with_placement(LinearPlacement::vertical().align_at_center());
 
         let buttons = button_text.map(|text| {
-            let btn_layout = ButtonLayout::none_none_text(text.into());
+            let btn_layout = ButtonLayout::none_none_text(text);
             Child::new(ButtonController::new(btn_layout))
         });
 
@@ -69,9 +66,16 @@ where
             text: Child::new(p1),
             buttons,
             autoclose: false,
+            feedback: None,
         }
     }
 
+    /// Sound played when the popup appears.
+    pub fn with_feedback(mut self, feedback: Feedback) -> Self {
+        self.feedback = Some(feedback);
+        self
+    }
+
     // autoclose even if button is used
     pub fn autoclose(&mut self) {
         self.autoclose = true;
@@ -129,6 +133,12 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Attach = event {
+            self.start(ctx);
+            if let Some(feedback) = self.feedback {
+                feedback.play(ctx);
+            }
+        }
         let mut button_confirmed = false;
 
         self.text.event(ctx, event);
@@ -1,7 +1,7 @@
 use crate::ui::{
-    component::{Component, Event, EventCtx, Never, Pad, Paginate},
+    component::{Component, Event, EventCtx, Never, Pad, Paginate, ScrollBarLayout, ScrollMarks},
     display,
-    geometry::{Offset, Point, Rect},
+    geometry::{Axis, Insets, Offset, Point, Rect},
 };
 
 use super::super::theme;
@@ -29,6 +29,10 @@ pub const SCROLLBAR_SPACE: i16 = 5;
 /// How many dots at most will there be
 const MAX_DOTS: usize = 5;
 
+/// With more pages than this, a line with a thicker thumb is shown instead of
+/// the dots.
+const LAYOUT: ScrollBarLayout = ScrollBarLayout::new(Axis::Horizontal, 9, 3);
+
 impl ScrollBar {
     /// Maximum size (width/height) of a dot
     pub const MAX_DOT_SIZE: i16 = 5;
@@ -60,6 +64,19 @@ impl ScrollBar {
         Self::dots_width(dots_shown)
     }
 
+    /// Drawing a line across the whole scrollbar with the thumb on top of it.
+    fn paint_bar(&self) {
+        let area = self.pad.area;
+        let track = area.inset(Insets::new(2, 0, 2, 0));
+        let thumb = LAYOUT.thumb(
+            area.inset(Insets::new(1, 0, 1, 0)),
+            self.page_count,
+            self.active_page,
+        );
+        display::rect_fill(track, theme::FG);
+        display::rect_fill(thumb, theme::FG);
+    }
+
     pub fn set_page_count(&mut self, page_count: usize) {
         self.page_count = page_count;
     }
@@ -231,7 +248,11 @@ impl Component for ScrollBar {
 
         self.pad.clear();
         self.pad.paint();
-        self.paint_horizontal();
+        if LAYOUT.marks(self.page_count) == ScrollMarks::Bar {
+            self.paint_bar();
+        } else {
+            self.paint_horizontal();
+        }
     }
 }
 
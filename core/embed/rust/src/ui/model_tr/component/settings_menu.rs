@@ -0,0 +1,168 @@
+use heapless::Vec;
+
+use crate::{
+    strutil::StringType,
+    ui::{
+        component::{Component, Event, EventCtx},
+        geometry::Rect,
+        settings::{Setting, SettingKind, MAX_SETTINGS},
+    },
+};
+
+use super::{ButtonLayout, ChoiceFactory, ChoiceItem, ChoicePage};
+
+const RENAME: &str = "DEVICE NAME";
+const DONE: &str = "DONE";
+
+pub enum SettingsMenuMsg {
+    Done,
+    /// Left to rename the device.
+    Rename,
+}
+
+#[derive(Clone, Copy)]
+enum MenuAction {
+    Open(usize),
+    Pick(u32),
+    Rename,
+    Done,
+}
+
+/// Either the list of the settings, or the options of one of them.
+enum ChoiceFactorySettings {
+    Main(Vec<SettingKind, MAX_SETTINGS>),
+    Options(SettingKind),
+}
+
+impl<T: StringType + Clone> ChoiceFactory<T> for ChoiceFactorySettings {
+    type Action = MenuAction;
+    type Item = ChoiceItem<T>;
+
+    fn count(&self) -> usize {
+        match self {
+            // The last two items leave the menu.
+            Self::Main(kinds) => kinds.len() + 2,
+            Self::Options(kind) => kind.options().len(),
+        }
+    }
+
+    fn get(&self, choice_index: usize) -> (Self::Item, Self::Action) {
+        let layout = ButtonLayout::default_three_icons();
+        match self {
+            Self::Main(kinds) => match kinds.get(choice_index) {
+                Some(kind) => (
+                    ChoiceItem::new(kind.title(), layout),
+                    MenuAction::Open(choice_index),
+                ),
+                None if choice_index == kinds.len() => {
+                    (ChoiceItem::new(RENAME, layout), MenuAction::Rename)
+                }
+                None => (ChoiceItem::new(DONE, layout), MenuAction::Done),
+            },
+            Self::Options(kind) => {
+                let option = &kind.options()[choice_index];
+                (
+                    ChoiceItem::new(option.label, layout),
+                    MenuAction::Pick(option.value),
+                )
+            }
+        }
+    }
+}
+
+/// Settings menu handled entirely in Rust. Selecting a setting lists its
+/// options, starting at the current value, selecting an option goes back to
+/// the list. The firmware gets all the values at once when the menu is left.
+pub struct SettingsMenu<T>
+where
+    T: StringType + Clone,
+{
+    choice_page: ChoicePage<ChoiceFactorySettings, T, MenuAction>,
+    settings: Vec<Setting, MAX_SETTINGS>,
+    /// Index of the setting whose options are listed.
+    open: Option<usize>,
+}
+
+impl<T> SettingsMenu<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(settings: Vec<Setting, MAX_SETTINGS>) -> Self {
+        let choices = Self::main_choices(&settings);
+        Self {
+            choice_page: ChoicePage::new(choices).with_carousel(true),
+            settings,
+            open: None,
+        }
+    }
+
+    pub fn settings(&self) -> &[Setting] {
+        &self.settings
+    }
+
+    fn main_choices(settings: &[Setting]) -> ChoiceFactorySettings {
+        ChoiceFactorySettings::Main(settings.iter().map(|s| s.kind).collect())
+    }
+
+    fn open(&mut self, ctx: &mut EventCtx, index: usize) {
+        let setting = self.settings[index];
+        let page = setting.kind.option_index(setting.value);
+        self.open = Some(index);
+        self.choice_page.reset(
+            ctx,
+            ChoiceFactorySettings::Options(setting.kind),
+            Some(page),
+            true,
+        );
+    }
+
+    fn close(&mut self, ctx: &mut EventCtx, value: u32) {
+        if let Some(index) = self.open.take() {
+            self.settings[index].value = value;
+            let choices = Self::main_choices(&self.settings);
+            self.choice_page.reset(ctx, choices, Some(index), true);
+        }
+    }
+}
+
+impl<T> Component for SettingsMenu<T>
+where
+    T: StringType + Clone,
+{
+    type Msg = SettingsMenuMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.choice_page.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match self.choice_page.event(ctx, event) {
+            Some((MenuAction::Open(index), _)) => self.open(ctx, index),
+            Some((MenuAction::Pick(value), _)) => self.close(ctx, value),
+            Some((MenuAction::Rename, _)) => return Some(SettingsMenuMsg::Rename),
+            Some((MenuAction::Done, _)) => return Some(SettingsMenuMsg::Done),
+            None => {}
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.choice_page.paint();
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for SettingsMenu<T>
+where
+    T: StringType + Clone,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("SettingsMenu");
+        if let Some(index) = self.open {
+            t.string("open", self.settings[index].kind.title());
+        }
+        t.child("choice_page", &self.choice_page);
+    }
+}
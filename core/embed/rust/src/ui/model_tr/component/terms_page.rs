@@ -0,0 +1,221 @@
+use crate::{
+    strutil::StringType,
+    ui::{
+        component::{Child, Component, ComponentExt, Event, EventCtx, Pad, PageMsg, Paginate},
+        geometry::{Insets, Rect},
+    },
+};
+
+use super::{
+    constant, frame::ScrollableContent, theme, ButtonController, ButtonControllerMsg,
+    ButtonDetails, ButtonLayout, ButtonPos,
+};
+
+/// Long informational text, e.g. a regulatory notice or a disclaimer of an
+/// experimental feature. The middle confirm button only appears once the last
+/// page has been shown, afterwards it stays there on every page.
+pub struct TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: StringType,
+{
+    page_count: usize,
+    active_page: usize,
+    content: Child<T>,
+    pad: Pad,
+    verb: U,
+    /// Whether the last page has been shown.
+    seen_last_page: bool,
+    buttons: Child<ButtonController<U>>,
+}
+
+impl<T, U> TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: StringType + Clone,
+{
+    pub fn new(content: T, verb: U) -> Self {
+        Self {
+            page_count: 0, // will be set in place()
+            active_page: 0,
+            content: Child::new(content),
+            pad: Pad::with_background(theme::BG).with_clear(),
+            verb,
+            seen_last_page: false,
+            buttons: Child::new(ButtonController::new(ButtonLayout::empty())),
+        }
+    }
+
+    fn has_next_page(&self) -> bool {
+        self.active_page < self.page_count - 1
+    }
+
+    fn has_previous_page(&self) -> bool {
+        self.active_page > 0
+    }
+
+    fn button_layout(&self) -> ButtonLayout<U> {
+        let btn_left = if self.has_previous_page() {
+            ButtonDetails::up_arrow_icon()
+        } else {
+            ButtonDetails::cancel_icon()
+        };
+        let btn_middle = self
+            .seen_last_page
+            .then(|| ButtonDetails::armed_text(self.verb.clone()));
+        let btn_right = self.has_next_page().then(ButtonDetails::down_arrow_icon);
+        ButtonLayout::new(Some(btn_left), btn_middle, btn_right)
+    }
+
+    /// Showing `active_page`, remembering the last page once shown.
+    fn change_page(&mut self, ctx: &mut EventCtx) {
+        self.content.mutate(ctx, |ctx, content| {
+            content.change_page(self.active_page);
+            content.request_complete_repaint(ctx);
+        });
+        if !self.has_next_page() {
+            self.seen_last_page = true;
+        }
+        let btn_layout = self.button_layout();
+        self.buttons.mutate(ctx, |_ctx, buttons| {
+            buttons.set(btn_layout);
+        });
+        self.pad.clear();
+    }
+}
+
+impl<T, U> ScrollableContent for TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: StringType,
+{
+    fn page_count(&self) -> usize {
+        self.page_count
+    }
+    fn active_page(&self) -> usize {
+        self.active_page
+    }
+}
+
+impl<T, U> Component for TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: StringType + Clone,
+{
+    type Msg = PageMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (content_area, button_area) = bounds.split_bottom(theme::BUTTON_HEIGHT);
+        self.pad.place(content_area);
+        self.content
+            .place(content_area.inset(Insets::top(constant::LINE_SPACE)));
+        self.page_count = self.content.page_count();
+        if self.page_count == 1 {
+            self.seen_last_page = true;
+        }
+        self.buttons = Child::new(ButtonController::new(self.button_layout()));
+        self.buttons.place(button_area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        ctx.set_page_count(self.page_count);
+        if let Some(ButtonControllerMsg::Triggered(pos, _)) = self.buttons.event(ctx, event) {
+            match pos {
+                ButtonPos::Left if self.has_previous_page() => {
+                    self.active_page -= 1;
+                    self.change_page(ctx);
+                }
+                ButtonPos::Left => return Some(PageMsg::Cancelled),
+                ButtonPos::Middle if self.seen_last_page => return Some(PageMsg::Confirmed),
+                ButtonPos::Right if self.has_next_page() => {
+                    self.active_page += 1;
+                    self.change_page(ctx);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(msg) = self.content.event(ctx, event) {
+            return Some(PageMsg::Content(msg));
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.content.paint();
+        self.buttons.paint();
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl<T, U> crate::trace::Trace for TermsPage<T, U>
+where
+    T: crate::trace::Trace + Paginate + Component,
+    U: StringType + Clone,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("TermsPage");
+        t.int("active_page", self.active_page as i64);
+        t.int("page_count", self.page_count as i64);
+        t.bool("seen_last_page", self.seen_last_page);
+        t.child("buttons", &self.buttons);
+        t.child("content", &self.content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{component::Never, event::PhysicalButton, testing::Harness};
+
+    use super::*;
+
+    /// Text of three pages.
+    struct Pages;
+
+    impl Component for Pages {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            None
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    impl Paginate for Pages {
+        fn page_count(&mut self) -> usize {
+            3
+        }
+
+        fn change_page(&mut self, _active_page: usize) {}
+    }
+
+    #[test]
+    fn middle_button_once_all_is_read() {
+        let mut h = Harness::new(TermsPage::new(Pages, "I AGREE"));
+        assert!(h.click_both().is_none());
+
+        h.click(PhysicalButton::Right);
+        h.click(PhysicalButton::Right);
+        assert!(h.inner().seen_last_page);
+        assert!(h.click(PhysicalButton::Right).is_none());
+
+        // Back to the first page, where the left button cancels.
+        h.click(PhysicalButton::Left);
+        h.click(PhysicalButton::Left);
+        assert!(h.inner().active_page == 0);
+        assert!(matches!(h.click_both(), Some(PageMsg::Confirmed)));
+        assert!(matches!(
+            h.click(PhysicalButton::Left),
+            Some(PageMsg::Cancelled)
+        ));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::ui::geometry::{Offset, Point, Rect};
+use crate::ui::{
+    display,
+    geometry::{Offset, Point, Rect},
+};
 
 use crate::trezorhal::display::{DISPLAY_RESX, DISPLAY_RESY};
 
@@ -21,4 +24,11 @@ pub const fn screen() -> Rect {
 }
 pub const SCREEN: Rect = screen();
 
+/// Area the root component is placed into, i.e. the screen in the current
+/// rotation of the display.
+pub fn root() -> Rect {
+    let rotation = display::rotation();
+    Rect::from_top_left_and_size(Point::zero(), rotation.rotate_size(SIZE))
+}
+
 pub const IGNORE_OTHER_BTN_MS: u32 = 200;
@@ -6,7 +6,8 @@ use crate::{
     error::Error,
     maybe_trace::MaybeTrace,
     micropython::{
-        buffer::{get_buffer, StrBuffer},
+        buffer::{get_buffer, BorrowedStr, StrBuffer},
+        ffi,
         gc::Gc,
         iter::IterBuf,
         list::List,
@@ -16,9 +17,12 @@ use crate::{
         qstr::Qstr,
         util,
     },
-    strutil::StringType,
+    strutil::{SecretString, StringType},
+    time::Duration,
     trezorhal::model,
     ui::{
+        carry,
+        coin_icons::{self, CoinIcon},
         component::{
             base::Component,
             paginated::{PageMsg, Paginate},
@@ -30,25 +34,44 @@ use crate::{
                 },
                 TextStyle,
             },
-            ComponentExt, FormattedText, Label, LineBreaking, Timeout,
+            BlobFormat, BlobView, Border, ComponentExt, Countdown, CountdownMsg, EntropyMixer,
+            EntropyMixerMsg, FormattedText, Label, LineBreaking, Qr, SettingsDiff, Timeout,
         },
-        display, geometry,
+        context::settings,
+        display,
+        geometry::{self, Insets},
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
-            result::{CANCELLED, CONFIRMED, INFO},
-            util::{iter_into_array, iter_into_vec, upy_disable_animation, ConfirmBlob},
+            result::{self, CANCELLED, CONFIRMED, INFO, MORE, SELECTED},
+            util::{
+                get_payment_request, get_setting_changes, get_template, iter_into_array,
+                iter_into_vec, upy_clear_resume_point, upy_commit_translations,
+                upy_disable_animation, upy_disable_transitions, upy_enable_profiler,
+                upy_erase_translations, upy_load_translations, upy_resume_point,
+                upy_save_resume_point, upy_secrets_equal, upy_set_accessibility, upy_set_autodim,
+                upy_set_battery_level, upy_set_button_quirks, upy_set_density,
+                upy_set_keyboard_layout, upy_set_replay_seed, upy_set_theme_inverted,
+                upy_set_wallet_badge, upy_trace_paint_counters, upy_translations_max_length,
+                upy_wipe_carry, upy_write_translations, ConfirmBlob, ObjBytes,
+            },
         },
         model_tr::component::check_homescreen_format,
+        random::PinShuffle,
+        settings::{Setting, SettingKind, MAX_SETTINGS},
+        sound::Feedback,
+        translations::{self, TranslatedString},
     },
 };
 
 use super::{
     component::{
-        AddressDetails, ButtonActions, ButtonDetails, ButtonLayout, ButtonPage, CancelConfirmMsg,
-        CancelInfoConfirmMsg, CoinJoinProgress, ConfirmHomescreen, Flow, FlowPages, Frame,
-        Homescreen, Lockscreen, NumberInput, Page, PassphraseEntry, PinEntry, Progress,
-        ScrollableContent, ScrollableFrame, ShareWords, ShowMore, SimpleChoice, WordlistEntry,
-        WordlistType,
+        Account, AccountChoice, AddressDetails, ButtonActions, ButtonDetails, ButtonLayout,
+        ButtonPage, CancelConfirmMsg, CancelInfoConfirmMsg, CoinJoinProgress, ConfirmHomescreen,
+        Flow, FlowPages, Frame, Homescreen, HomescreenMsg, HostChoice, ListEditor, ListEditorMsg,
+        Lockscreen, NumberInput, Page, PassphraseEntry, PinEntry, PinLockout, Progress,
+        ResultPopup, ResultPopupMsg, ScrollableContent, ScrollableFrame, SettingsMenu,
+        SettingsMenuMsg, ShareWords, ShowMore, SimpleChoice, TermsPage, WordlistEntry,
+        WordlistType, MAX_ACCOUNTS, MAX_HOST_CHOICES, MAX_LIST_ITEMS,
     },
     constant, theme,
 };
@@ -99,6 +122,20 @@ where
     }
 }
 
+impl<T, U> ComponentMsgObj for TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            PageMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+            PageMsg::Cancelled => Ok(CANCELLED.as_obj()),
+            _ => Err(Error::TypeError),
+        }
+    }
+}
+
 impl<F, T> ComponentMsgObj for Flow<F, T>
 where
     F: Fn(usize) -> Page<T>,
@@ -108,7 +145,7 @@ where
         match msg {
             CancelInfoConfirmMsg::Confirmed => {
                 if let Some(index) = self.confirmed_index() {
-                    index.try_into()
+                    result::with_value(&SELECTED, Obj::try_from(index)?)
                 } else {
                     Ok(CONFIRMED.as_obj())
                 }
@@ -131,6 +168,18 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for Countdown<T>
+where
+    T: ComponentMsgObj,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            CountdownMsg::Inner(msg) => self.inner().msg_try_into_obj(msg),
+            CountdownMsg::TimedOut => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
 impl<T> ComponentMsgObj for (Timeout, T)
 where
     T: Component<Msg = ()>,
@@ -140,6 +189,17 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for EntropyMixer<T>
+where
+    T: AsRef<str>,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            EntropyMixerMsg::Done => self.input().try_into(),
+        }
+    }
+}
+
 impl<T> ComponentMsgObj for AddressDetails<T>
 where
     T: StringType + Clone,
@@ -173,7 +233,7 @@ where
 {
     fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
         if self.return_index {
-            msg.try_into()
+            result::with_value(&SELECTED, Obj::try_from(msg)?)
         } else {
             let text = self.result_by_index(msg);
             text.try_into()
@@ -181,6 +241,41 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for HostChoice<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        result::with_value(&SELECTED, Obj::try_from(msg)?)
+    }
+}
+
+impl<T> ComponentMsgObj for ListEditor<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            ListEditorMsg::Done => {
+                let mut items = Vec::<Obj, MAX_LIST_ITEMS>::new();
+                for item in self.items() {
+                    unwrap!(items.push(item.as_ref().try_into()?));
+                }
+                Ok(List::from_iter(items)?.into())
+            }
+        }
+    }
+}
+
+impl<T> ComponentMsgObj for AccountChoice<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        result::with_value(&SELECTED, Obj::try_from(msg)?)
+    }
+}
+
 impl<T> ComponentMsgObj for WordlistEntry<T>
 where
     T: StringType + Clone,
@@ -222,6 +317,15 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for PinLockout<T>
+where
+    T: StringType,
+{
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
 impl<T> ComponentMsgObj for Progress<T>
 where
     T: StringType,
@@ -231,12 +335,52 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for Border<T>
+where
+    T: ComponentMsgObj,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        self.inner().msg_try_into_obj(msg)
+    }
+}
+
+impl<T> ComponentMsgObj for ResultPopup<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            ResultPopupMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+        }
+    }
+}
+
+impl<T> ComponentMsgObj for SettingsMenu<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        let mut changed = Vec::<Obj, MAX_SETTINGS>::new();
+        for setting in self.settings().iter().filter(|s| s.is_changed()) {
+            let name: Obj = setting.kind.name().try_into()?;
+            let value: Obj = setting.value.try_into()?;
+            unwrap!(changed.push((name, value).try_into()?));
+        }
+        let changed: Obj = List::from_iter(changed)?.into();
+        let rename: Obj = matches!(msg, SettingsMenuMsg::Rename).into();
+        (changed, rename).try_into()
+    }
+}
+
 impl<T> ComponentMsgObj for Homescreen<T>
 where
     T: StringType + Clone,
 {
-    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
-        Ok(CANCELLED.as_obj())
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            HomescreenMsg::Dismissed => Ok(CANCELLED.as_obj()),
+            HomescreenMsg::Settings => Ok(INFO.as_obj()),
+        }
     }
 }
 
@@ -313,12 +457,97 @@ fn content_in_button_page<T: Component + Paginate + MaybeTrace + 'static>(
     Ok(obj.into())
 }
 
+extern "C" fn new_confirm_terms(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let text: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_text)?;
+        let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "ACCEPT".into())?;
+
+        let paragraphs = Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, text));
+        let obj = LayoutObj::new(
+            ScrollableFrame::new(TermsPage::new(paragraphs, verb)).with_title(title),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_blind_signing(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs
+            .get_or(Qstr::MP_QSTR_title, Obj::const_none())?
+            .try_into_option()?
+            .unwrap_or_else(|| TranslatedString::TitleBlindSigning.into());
+        let description: StrBuffer =
+            kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
+
+        let warning: StrBuffer = TranslatedString::WarningBlindSigning.into();
+        let mut paragraphs = ParagraphVecShort::new();
+        paragraphs
+            .add(Paragraph::new(&theme::TEXT_BOLD, warning))
+            .add(Paragraph::new(&theme::TEXT_NORMAL, description));
+        let confirm_btn =
+            ButtonDetails::text(TranslatedString::ButtonConfirm.into()).with_default_duration();
+        let content = ButtonPage::new(paragraphs.into_paragraphs(), theme::BG)
+            .with_cancel_btn(Some(ButtonDetails::cancel_icon()))
+            .with_confirm_btn(Some(confirm_btn));
+        let frame = ScrollableFrame::new(content).with_title(title);
+        let obj = LayoutObj::new(
+            Border::new(Insets::top(theme::BLIND_SIGNING_STRIPES), frame)
+                .with_stripes(theme::FG, theme::BG),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_language(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let version: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_version)?;
+        let signed: bool = kwargs.get_field(Qstr::MP_QSTR_signed)?;
+        let language =
+            translations::language().ok_or_else(|| value_error!("No translations in use."))?;
+
+        // All the strings come from the translations being confirmed, so that
+        // the screen is a preview of them.
+        let title: StrBuffer = TranslatedString::TitleChangeLanguage.into();
+        let signature = if signed {
+            Paragraph::new(&theme::TEXT_NORMAL, TranslatedString::TextSigned.into())
+        } else {
+            Paragraph::new(&theme::TEXT_BOLD, TranslatedString::WarningNotSigned.into())
+        };
+        let mut paragraphs = ParagraphVecShort::new();
+        paragraphs
+            .add(Paragraph::new(
+                &theme::TEXT_NORMAL,
+                TranslatedString::TextLanguage.into(),
+            ))
+            .add(Paragraph::new(
+                &theme::TEXT_MONO,
+                StrBuffer::alloc(language)?,
+            ))
+            .add(Paragraph::new(
+                &theme::TEXT_NORMAL,
+                TranslatedString::TextVersion.into(),
+            ))
+            .add(Paragraph::new(&theme::TEXT_MONO, version))
+            .add(signature);
+        let confirm_btn = ButtonDetails::text(TranslatedString::ButtonConfirm.into());
+        let page = ButtonPage::new(paragraphs.into_paragraphs(), theme::BG)
+            .with_cancel_btn(Some(ButtonDetails::cancel_icon()))
+            .with_confirm_btn(Some(confirm_btn));
+        let obj = LayoutObj::new(ScrollableFrame::new(page).with_title(title))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let action: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_action)?.try_into_option()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let action: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_action)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
         let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "CONFIRM".into())?;
         let verb_cancel: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb_cancel)
@@ -350,11 +579,11 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_confirm_blob(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let data: Obj = kwargs.get(Qstr::MP_QSTR_data)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
-        let extra: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_extra)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
+        let extra: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_extra)?;
         let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "CONFIRM".into())?;
         let verb_cancel: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb_cancel)
@@ -387,7 +616,7 @@ extern "C" fn new_confirm_blob(n_args: usize, args: *const Obj, kwargs: *mut Map
 
 extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
@@ -413,12 +642,16 @@ extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *m
                 }
             }
             if let Some(value) = value {
+                // Texts in the proportional font take less room in the compact
+                // density.
                 let style = if is_data {
                     &theme::TEXT_MONO_DATA
                 } else {
-                    &theme::TEXT_MONO
+                    settings()
+                        .density
+                        .choose(&theme::TEXT_MONO, &theme::TEXT_NORMAL)
                 };
-                paragraphs.add(Paragraph::new(style, value));
+                paragraphs.add(Paragraph::new(style, value).keep_whole());
             }
         }
 
@@ -433,9 +666,47 @@ extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *m
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_confirm_template(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let template = get_template(kwargs)?;
+        let rows = template
+            .rows(
+                &theme::TEXT_BOLD,
+                settings()
+                    .density
+                    .choose(&theme::TEXT_MONO, &theme::TEXT_NORMAL),
+            )
+            .into_paragraphs();
+        // Risky actions have to be held.
+        content_in_button_page(
+            template.title(),
+            rows,
+            template.verb(),
+            Some(template.verb_cancel()),
+            template.warning(),
+        )
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_payment_uri(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_title, "PAYMENT REQUEST".into())?;
+        let request = get_payment_request(kwargs, &theme::TEXT_BOLD, &theme::TEXT_MONO)?;
+        content_in_button_page(
+            title,
+            request.into_paragraphs(),
+            "CONFIRM".into(),
+            Some("".into()),
+            false,
+        )
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_homescreen(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let data: Obj = kwargs.get(Qstr::MP_QSTR_image)?;
 
         // Layout needs to hold the Obj to play nice with GC. Obj is resolved to &[u8]
@@ -453,8 +724,8 @@ extern "C" fn new_confirm_homescreen(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_confirm_reset_device(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
 
         let ops = OpTextLayout::<StrBuffer>::new(theme::TEXT_NORMAL)
             .text_normal("By continuing you agree to Trezor Company's terms and conditions.".into())
@@ -505,17 +776,19 @@ extern "C" fn new_confirm_backup(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_show_address_details(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let address: StrBuffer = kwargs.get(Qstr::MP_QSTR_address)?.try_into()?;
-        let case_sensitive: bool = kwargs.get(Qstr::MP_QSTR_case_sensitive)?.try_into()?;
-        let account: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_account)?.try_into_option()?;
-        let path: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_path)?.try_into_option()?;
+        // Strings are borrowed from the Python objects, the address is not
+        // copied into the QR code component.
+        let address: BorrowedStr = kwargs.get_field(Qstr::MP_QSTR_address)?;
+        let case_sensitive: bool = kwargs.get_field(Qstr::MP_QSTR_case_sensitive)?;
+        let account: Option<BorrowedStr> = kwargs.get_optional_field(Qstr::MP_QSTR_account)?;
+        let path: Option<BorrowedStr> = kwargs.get_optional_field(Qstr::MP_QSTR_path)?;
 
         let xpubs: Obj = kwargs.get(Qstr::MP_QSTR_xpubs)?;
 
         let mut ad = AddressDetails::new(address, case_sensitive, account, path)?;
 
         for i in IterBuf::new().try_iterate(xpubs)? {
-            let [xtitle, text]: [StrBuffer; 2] = iter_into_array(i)?;
+            let [xtitle, text]: [BorrowedStr; 2] = iter_into_array(i)?;
             ad.add_xpub(xtitle, text)?;
         }
 
@@ -527,9 +800,9 @@ extern "C" fn new_show_address_details(n_args: usize, args: *const Obj, kwargs:
 
 extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let value: StrBuffer = kwargs.get(Qstr::MP_QSTR_value)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let value: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_value)?;
 
         let verb: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb)
@@ -539,7 +812,7 @@ extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_BOLD, description),
-            Paragraph::new(&theme::TEXT_MONO, value),
+            Paragraph::new(theme::value_style(&theme::TEXT_MONO), value).keep_whole(),
         ]);
 
         content_in_button_page(
@@ -555,14 +828,14 @@ extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_confirm_joint_total(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let spending_amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_spending_amount)?.try_into()?;
-        let total_amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_amount)?.try_into()?;
+        let spending_amount: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_spending_amount)?;
+        let total_amount: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_total_amount)?;
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_BOLD, "You are contributing:".into()),
-            Paragraph::new(&theme::TEXT_MONO, spending_amount),
+            Paragraph::new(theme::value_style(&theme::TEXT_MONO), spending_amount).keep_whole(),
             Paragraph::new(&theme::TEXT_BOLD, "To the total amount:".into()),
-            Paragraph::new(&theme::TEXT_MONO, total_amount),
+            Paragraph::new(theme::value_style(&theme::TEXT_MONO), total_amount).keep_whole(),
         ]);
 
         content_in_button_page(
@@ -578,9 +851,9 @@ extern "C" fn new_confirm_joint_total(n_args: usize, args: *const Obj, kwargs: *
 
 extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let sign: i32 = kwargs.get(Qstr::MP_QSTR_sign)?.try_into()?;
-        let amount_change: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_change)?.try_into()?;
-        let amount_new: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_new)?.try_into()?;
+        let sign: i32 = kwargs.get_field(Qstr::MP_QSTR_sign)?;
+        let amount_change: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_change)?;
+        let amount_new: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_new)?;
 
         let description = if sign < 0 {
             "Decrease amount by:"
@@ -590,9 +863,11 @@ extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs:
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_NORMAL, description.into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_change).break_after(),
+            Paragraph::new(theme::value_style(&theme::TEXT_MONO), amount_change)
+                .keep_whole()
+                .break_after(),
             Paragraph::new(&theme::TEXT_BOLD, "New amount:".into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_new),
+            Paragraph::new(theme::value_style(&theme::TEXT_MONO), amount_new).keep_whole(),
         ]);
 
         content_in_button_page(
@@ -606,12 +881,35 @@ extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs:
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+/// Icon of the coin given in the optional `coin` argument of a layout.
+fn get_coin_icon(kwargs: &Map) -> Result<Option<&'static CoinIcon>, Error> {
+    let coin: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_coin)?;
+    Ok(coin.and_then(|coin| coin_icons::find(coin.as_ref())))
+}
+
+/// Flow of the output pages, with the `coin` in front of the titles.
+fn output_flow<F>(
+    pages: FlowPages<F, StrBuffer>,
+    coin: Option<&'static CoinIcon>,
+) -> Result<Obj, Error>
+where
+    F: Fn(usize) -> Page<StrBuffer> + 'static,
+{
+    let mut flow = Flow::new(pages);
+    if let Some(coin) = coin {
+        flow = flow.with_coin(coin);
+    }
+    let obj = LayoutObj::new(flow)?;
+    Ok(obj.into())
+}
+
 extern "C" fn new_confirm_output_address(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let address: StrBuffer = kwargs.get(Qstr::MP_QSTR_address)?.try_into()?;
-        let address_label: StrBuffer = kwargs.get(Qstr::MP_QSTR_address_label)?.try_into()?;
-        let address_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_address_title)?.try_into()?;
+        let address: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_address)?;
+        let address_label: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_address_label)?;
+        let address_title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_address_title)?;
         let chunkify: bool = kwargs.get_or(Qstr::MP_QSTR_chunkify, false)?;
+        let coin = get_coin_icon(kwargs)?;
 
         let get_page = move |page_index| {
             assert!(page_index == 0);
@@ -634,51 +932,51 @@ extern "C" fn new_confirm_output_address(n_args: usize, args: *const Obj, kwargs
                 // Chunkifying the address into smaller pieces when requested
                 ops = ops.chunkify_text(Some((theme::MONO_CHUNKS, 2)));
             }
-            ops = ops.text_mono(address.clone());
+            ops = ops.font(theme::value_font()).text(address.clone());
             let formatted = FormattedText::new(ops).vertically_centered();
             Page::new(btn_layout, btn_actions, formatted).with_title(address_title.clone())
         };
         let pages = FlowPages::new(get_page, 1);
 
-        let obj = LayoutObj::new(Flow::new(pages))?;
-        Ok(obj.into())
+        output_flow(pages, coin)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_output_amount(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount)?.try_into()?;
-        let amount_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_title)?.try_into()?;
+        let amount: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount)?;
+        let amount_title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_title)?;
+        let coin = get_coin_icon(kwargs)?;
 
         let get_page = move |page_index| {
             assert!(page_index == 0);
             // AMOUNT + amount
             let btn_layout = ButtonLayout::up_arrow_none_text("CONFIRM".into());
             let btn_actions = ButtonActions::cancel_none_confirm();
-            let ops = OpTextLayout::new(theme::TEXT_MONO).text_mono(amount.clone());
+            let ops = OpTextLayout::new(theme::TEXT_MONO)
+                .font(theme::value_font())
+                .text(amount.clone());
             let formatted = FormattedText::new(ops).vertically_centered();
             Page::new(btn_layout, btn_actions, formatted).with_title(amount_title.clone())
         };
         let pages = FlowPages::new(get_page, 1);
 
-        let obj = LayoutObj::new(Flow::new(pages))?;
-        Ok(obj.into())
+        output_flow(pages, coin)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let total_amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_amount)?.try_into()?;
-        let fee_amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_fee_amount)?.try_into()?;
-        let fee_rate_amount: Option<StrBuffer> = kwargs
-            .get(Qstr::MP_QSTR_fee_rate_amount)?
-            .try_into_option()?;
+        let total_amount: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_total_amount)?;
+        let fee_amount: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fee_amount)?;
+        let fee_rate_amount: Option<StrBuffer> =
+            kwargs.get_optional_field(Qstr::MP_QSTR_fee_rate_amount)?;
         let account_label: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_account_label)?.try_into_option()?;
-        let total_label: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_label)?.try_into()?;
-        let fee_label: StrBuffer = kwargs.get(Qstr::MP_QSTR_fee_label)?.try_into()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_account_label)?;
+        let total_label: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_total_label)?;
+        let fee_label: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fee_label)?;
 
         let get_page = move |page_index| {
             match page_index {
@@ -690,12 +988,14 @@ extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Ma
                     let ops = OpTextLayout::new(theme::TEXT_MONO)
                         .text_bold(total_label.clone())
                         .newline()
-                        .text_mono(total_amount.clone())
+                        .font(theme::value_font())
+                        .text(total_amount.clone())
                         .newline()
                         .newline()
                         .text_bold(fee_label.clone())
                         .newline()
-                        .text_mono(fee_amount.clone());
+                        .font(theme::value_font())
+                        .text(fee_amount.clone());
 
                     let formatted = FormattedText::new(ops);
                     Page::new(btn_layout, btn_actions, formatted)
@@ -753,10 +1053,10 @@ extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_altcoin_tx_summary(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let amount_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_title)?.try_into()?;
-        let amount_value: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_value)?.try_into()?;
-        let fee_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_fee_title)?.try_into()?;
-        let fee_value: StrBuffer = kwargs.get(Qstr::MP_QSTR_fee_value)?.try_into()?;
+        let amount_title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_title)?;
+        let amount_value: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_value)?;
+        let fee_title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fee_title)?;
+        let fee_value: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fee_value)?;
         let cancel_cross: bool = kwargs.get_or(Qstr::MP_QSTR_cancel_cross, false)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
@@ -772,12 +1072,14 @@ extern "C" fn new_altcoin_tx_summary(n_args: usize, args: *const Obj, kwargs: *m
                     let btn_actions = ButtonActions::cancel_confirm_next();
 
                     let ops = OpTextLayout::new(theme::TEXT_MONO)
-                        .text_mono(amount_value.clone())
+                        .font(theme::value_font())
+                        .text(amount_value.clone())
                         .newline()
                         .newline_half()
                         .text_bold(fee_title.clone())
                         .newline()
-                        .text_mono(fee_value.clone());
+                        .font(theme::value_font())
+                        .text(fee_value.clone());
 
                     let formatted = FormattedText::new(ops);
                     Page::new(btn_layout, btn_actions, formatted).with_title(amount_title.clone())
@@ -819,8 +1121,8 @@ extern "C" fn new_altcoin_tx_summary(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let address: StrBuffer = kwargs.get(Qstr::MP_QSTR_data)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let address: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_data)?;
         let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "CONFIRM".into())?;
         let chunkify: bool = kwargs.get_or(Qstr::MP_QSTR_chunkify, false)?;
 
@@ -835,7 +1137,9 @@ extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut
             } else {
                 theme::TEXT_MONO_DATA
             };
-            let ops = OpTextLayout::new(style).text_mono(address.clone());
+            let ops = OpTextLayout::new(style)
+                .font(theme::value_font())
+                .text(address.clone());
             let formatted = FormattedText::new(ops).vertically_centered();
             Page::new(btn_layout, btn_actions, formatted).with_title(title.clone())
         };
@@ -949,12 +1253,11 @@ extern "C" fn tutorial(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj
 
 extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let sign: i32 = kwargs.get(Qstr::MP_QSTR_sign)?.try_into()?;
-        let user_fee_change: StrBuffer = kwargs.get(Qstr::MP_QSTR_user_fee_change)?.try_into()?;
-        let total_fee_new: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_fee_new)?.try_into()?;
-        let fee_rate_amount: Option<StrBuffer> = kwargs
-            .get(Qstr::MP_QSTR_fee_rate_amount)?
-            .try_into_option()?;
+        let sign: i32 = kwargs.get_field(Qstr::MP_QSTR_sign)?;
+        let user_fee_change: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_user_fee_change)?;
+        let total_fee_new: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_total_fee_new)?;
+        let fee_rate_amount: Option<StrBuffer> =
+            kwargs.get_optional_field(Qstr::MP_QSTR_fee_rate_amount)?;
 
         let (description, change) = match sign {
             s if s < 0 => ("Decrease fee by:", user_fee_change),
@@ -965,14 +1268,14 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
         let mut paragraphs_vec = ParagraphVecShort::new();
         paragraphs_vec
             .add(Paragraph::new(&theme::TEXT_BOLD, description.into()))
-            .add(Paragraph::new(&theme::TEXT_MONO, change))
+            .add(Paragraph::new(theme::value_style(&theme::TEXT_MONO), change).keep_whole())
             .add(Paragraph::new(&theme::TEXT_BOLD, "Transaction fee:".into()).no_break())
-            .add(Paragraph::new(&theme::TEXT_MONO, total_fee_new));
+            .add(Paragraph::new(theme::value_style(&theme::TEXT_MONO), total_fee_new).keep_whole());
 
         if let Some(fee_rate_amount) = fee_rate_amount {
             paragraphs_vec
                 .add(Paragraph::new(&theme::TEXT_BOLD, "Fee rate:".into()).no_break())
-                .add(Paragraph::new(&theme::TEXT_MONO, fee_rate_amount));
+                .add(Paragraph::new(&theme::TEXT_MONO, fee_rate_amount).keep_whole());
         }
 
         content_in_button_page(
@@ -988,9 +1291,9 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_multiple_pages_texts(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let verb: StrBuffer = kwargs.get(Qstr::MP_QSTR_verb)?.try_into()?;
-        let items: Gc<List> = kwargs.get(Qstr::MP_QSTR_items)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let verb: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_verb)?;
+        let items: Gc<List> = kwargs.get_field(Qstr::MP_QSTR_items)?;
 
         // Cache the page count so that we can move `items` into the closure.
         let page_count = items.len();
@@ -1043,9 +1346,10 @@ extern "C" fn new_multiple_pages_texts(n_args: usize, args: *const Obj, kwargs:
 
 extern "C" fn new_confirm_fido(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let app_name: StrBuffer = kwargs.get(Qstr::MP_QSTR_app_name)?.try_into()?;
-        let accounts: Gc<List> = kwargs.get(Qstr::MP_QSTR_accounts)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let app_name: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_app_name)?;
+        let accounts: Gc<List> = kwargs.get_field(Qstr::MP_QSTR_accounts)?;
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
 
         // Cache the page count so that we can move `accounts` into the closure.
         let page_count = accounts.len();
@@ -1095,21 +1399,25 @@ extern "C" fn new_confirm_fido(n_args: usize, args: *const Obj, kwargs: *mut Map
 
         let pages = FlowPages::new(get_page, page_count);
         // Returning the page index in case of confirmation.
-        let obj = LayoutObj::new(
-            Flow::new(pages)
-                .with_common_title(title)
-                .with_return_confirmed_index(),
-        )?;
-        Ok(obj.into())
+        let flow = Flow::new(pages)
+            .with_common_title(title)
+            .with_return_confirmed_index();
+        let obj = if time_ms > 0 {
+            let duration = Duration::from_millis(time_ms);
+            LayoutObj::new(Countdown::new(flow, duration, theme::TEXT_NORMAL))?.into()
+        } else {
+            LayoutObj::new(flow)?.into()
+        };
+        Ok(obj)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let warning: StrBuffer = kwargs.get(Qstr::MP_QSTR_warning)?.try_into()?;
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let warning: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_warning)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
 
         let get_page = move |page_index| {
             assert!(page_index == 0);
@@ -1134,9 +1442,25 @@ extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_entropy_mixing(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let seed: Obj = kwargs.get(Qstr::MP_QSTR_seed)?;
+        let time_ms: u32 = kwargs.get_field(Qstr::MP_QSTR_time_ms)?;
+
+        let seed = unsafe { get_buffer(seed) }?;
+        let duration = Duration::from_millis(time_ms);
+        let mixer = EntropyMixer::new(seed, description, duration, theme::TEXT_NORMAL);
+        let obj = LayoutObj::new(Frame::new(title, mixer))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let description: StrBuffer =
             kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
         let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
@@ -1160,6 +1484,66 @@ extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_result(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let success: bool = kwargs.get_field(Qstr::MP_QSTR_success)?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let detail: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_detail, StrBuffer::empty())?;
+        let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, StrBuffer::empty())?;
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
+        let rejected: bool = kwargs.get_or(Qstr::MP_QSTR_rejected, false)?;
+
+        let icon = if success {
+            theme::ICON_TICK_FAT
+        } else {
+            theme::ICON_CANCEL
+        };
+        // Without a detail, the title is shown in the big font on its own.
+        let (headline, text) = if detail.as_ref().is_empty() {
+            (None, title)
+        } else {
+            (Some(title), detail)
+        };
+        let has_button = !button.as_ref().is_empty();
+        let feedback = if success {
+            Feedback::Confirm
+        } else if rejected {
+            Feedback::Reject
+        } else {
+            Feedback::Error
+        };
+        let mut content = ResultPopup::new(icon, text, headline, has_button.then_some(button))
+            .with_feedback(feedback);
+        if has_button && time_ms > 0 {
+            content.autoclose();
+        }
+        let obj = LayoutObj::new(content)?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_settings_menu(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let items: Obj = kwargs.get_field(Qstr::MP_QSTR_settings)?;
+
+        let mut settings = Vec::new();
+        for item in IterBuf::new().try_iterate(items)? {
+            let [name, value]: [Obj; 2] = iter_into_array(item)?;
+            let name: StrBuffer = name.try_into()?;
+            let kind =
+                SettingKind::from_name(name.as_ref()).ok_or(value_error!("Unknown setting."))?;
+            settings
+                .push(Setting::new(kind, value.try_into()?))
+                .map_err(|_| value_error!("Too many settings."))?;
+        }
+
+        let obj = LayoutObj::new(SettingsMenu::new(settings))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_passphrase() -> Obj {
     let block = move || {
         let text: StrBuffer = "Please enter your passphrase.".into();
@@ -1173,7 +1557,7 @@ extern "C" fn new_show_passphrase() -> Obj {
 
 extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let get_page = move |page_index| {
             assert!(page_index == 0);
 
@@ -1199,8 +1583,8 @@ extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_confirm_with_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
         let verb_cancel: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb_cancel)
             .unwrap_or_else(|_| Obj::const_none())
@@ -1234,8 +1618,8 @@ extern "C" fn new_confirm_with_info(n_args: usize, args: *const Obj, kwargs: *mu
 
 extern "C" fn new_confirm_more(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecLong::new();
@@ -1258,10 +1642,92 @@ extern "C" fn new_confirm_more(n_args: usize, args: *const Obj, kwargs: *mut Map
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_blob(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let data: ObjBytes = kwargs.get_field(Qstr::MP_QSTR_data)?;
+        let base64: bool = kwargs.get_or(Qstr::MP_QSTR_base64, false)?;
+        let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, StrBuffer::empty())?;
+        let total: usize = kwargs.get_or(Qstr::MP_QSTR_total, 0)?;
+
+        let (format, other) = if base64 {
+            (BlobFormat::Base64, "HEX")
+        } else {
+            (BlobFormat::Hex, "B64")
+        };
+        let view = BlobView::new(data, format, theme::TEXT_MONO).with_total(total);
+        if verb.as_ref().is_empty() {
+            // The left button switches to the other format.
+            content_in_button_page(title, view, "CLOSE".into(), Some(other.into()), false)
+        } else {
+            // The left button rejects, there is no room for the format switch.
+            content_in_button_page(title, view, verb, Some(StrBuffer::empty()), false)
+        }
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_receipt(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let fingerprint: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fingerprint)?;
+        let qr_data: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_qr_data)?;
+        let qr: bool = kwargs.get_or(Qstr::MP_QSTR_qr, false)?;
+
+        // The left button switches between the fingerprint and the QR code,
+        // which has no room for the title.
+        if qr {
+            content_in_button_page(
+                StrBuffer::empty(),
+                Qr::new(qr_data, true)?,
+                "DONE".into(),
+                Some("<".into()),
+                false,
+            )
+        } else {
+            content_in_button_page(
+                title,
+                Paragraphs::new([
+                    Paragraph::new(&theme::TEXT_NORMAL, description),
+                    Paragraph::new(&theme::TEXT_MONO, fingerprint),
+                ]),
+                "DONE".into(),
+                Some("QR".into()),
+                false,
+            )
+        }
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_settings_change(
+    n_args: usize,
+    args: *const Obj,
+    kwargs: *mut Map,
+) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let changes: Obj = kwargs.get(Qstr::MP_QSTR_changes)?;
+        let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
+
+        let diff = SettingsDiff::new(
+            get_setting_changes(changes)?,
+            theme::TEXT_NORMAL,
+            theme::TEXT_BOLD,
+            theme::TEXT_BOLD_INVERSE,
+            theme::ICON_ARROW_RIGHT,
+        );
+        let verb = if hold { "HOLD TO CONFIRM" } else { "CONFIRM" };
+        content_in_button_page(title, diff, verb.into(), Some("".into()), hold)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let max_rounds: StrBuffer = kwargs.get(Qstr::MP_QSTR_max_rounds)?.try_into()?;
-        let max_feerate: StrBuffer = kwargs.get(Qstr::MP_QSTR_max_feerate)?.try_into()?;
+        let max_rounds: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_max_rounds)?;
+        let max_feerate: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_max_feerate)?;
 
         // Decreasing bottom padding between paragraphs to fit one screen
         let paragraphs = Paragraphs::new([
@@ -1286,10 +1752,15 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
 
 extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let subprompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_subprompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let subprompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_subprompt)?;
+        let shuffle: bool = kwargs.get_or(Qstr::MP_QSTR_shuffle, true)?;
+        let shuffle_on_keypress: bool = kwargs.get_or(Qstr::MP_QSTR_shuffle_on_keypress, true)?;
 
-        let obj = LayoutObj::new(PinEntry::new(prompt, subprompt))?;
+        let obj = LayoutObj::new(
+            PinEntry::new(prompt, subprompt)
+                .with_shuffle(PinShuffle::new(shuffle, shuffle_on_keypress)),
+        )?;
 
         Ok(obj.into())
     };
@@ -1298,11 +1769,41 @@ extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map)
 
 extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let max_len: u32 = kwargs.get_field(Qstr::MP_QSTR_max_len)?;
+        let carry_in: bool = kwargs.get_or(Qstr::MP_QSTR_carry_in, false)?;
+        let carry_out: bool = kwargs.get_or(Qstr::MP_QSTR_carry_out, false)?;
 
-        let obj = LayoutObj::new(
-            Frame::new(prompt, PassphraseEntry::<StrBuffer>::new()).with_title_centered(),
-        )?;
+        let prefill = if carry_in {
+            carry::take().unwrap_or_else(SecretString::new)
+        } else {
+            SecretString::new()
+        };
+        let mut entry =
+            PassphraseEntry::<StrBuffer>::with_prefill(prefill).with_max_len(max_len as usize);
+        if carry_out {
+            entry = entry.with_carry_out();
+        }
+        let obj = LayoutObj::new(Frame::new(prompt, entry).with_title_centered())?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_request_label(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let label: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_label, StrBuffer::empty())?;
+        let max_len: u32 = kwargs.get_field(Qstr::MP_QSTR_max_len)?;
+
+        let mut prefill = SecretString::new();
+        prefill
+            .push_str(label.as_ref())
+            .map_err(|_| Error::OutOfRange)?;
+        let entry = PassphraseEntry::<StrBuffer>::with_prefill(prefill)
+            .with_max_len(max_len as usize)
+            .with_plain_text();
+        let obj = LayoutObj::new(Frame::new(prompt, entry).with_title_centered())?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1310,7 +1811,7 @@ extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
 
         let obj = LayoutObj::new(
             Frame::new(prompt, WordlistEntry::<StrBuffer>::new(WordlistType::Bip39))
@@ -1323,7 +1824,7 @@ extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
 
         let obj = LayoutObj::new(
             Frame::new(
@@ -1340,7 +1841,7 @@ extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut M
 extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
         // we ignore passed in `title` and use `description` in its place
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
         let words_iterable: Obj = kwargs.get(Qstr::MP_QSTR_words)?;
         // There are only 3 words, but SimpleChoice requires 5 elements
         let words: Vec<StrBuffer, 5> = iter_into_vec(words_iterable)?;
@@ -1360,6 +1861,57 @@ extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map)
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_choose_from_list(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
+        let items: Vec<StrBuffer, MAX_HOST_CHOICES> = iter_into_vec(items)?;
+
+        let obj = LayoutObj::new(Frame::new(title, HostChoice::new(items)?).with_title_centered())?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_edit_list(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
+        let items: Vec<StrBuffer, MAX_LIST_ITEMS> = iter_into_vec(items)?;
+        let available: Obj = kwargs.get_or(Qstr::MP_QSTR_available, Obj::const_none())?;
+        let available: Vec<StrBuffer, MAX_LIST_ITEMS> = if available == Obj::const_none() {
+            Vec::new()
+        } else {
+            iter_into_vec(available)?
+        };
+
+        let editor = ListEditor::new(items, available)?;
+        let obj = LayoutObj::new(Frame::new(title, editor).with_title_centered())?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_choose_account(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let accounts_obj: Obj = kwargs.get(Qstr::MP_QSTR_accounts)?;
+
+        let mut accounts: Vec<Account<StrBuffer>, MAX_ACCOUNTS> = Vec::new();
+        for item in IterBuf::new().try_iterate(accounts_obj)? {
+            let [label, balance]: [StrBuffer; 2] = iter_into_array(item)?;
+            accounts
+                .push(Account { label, balance })
+                .map_err(|_| value_error!("Too many accounts"))?;
+        }
+
+        let obj =
+            LayoutObj::new(Frame::new(title, AccountChoice::new(accounts)?).with_title_centered())?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = |_args: &[Obj], kwargs: &Map| {
         let share_words_obj: Obj = kwargs.get(Qstr::MP_QSTR_share_words)?;
@@ -1382,10 +1934,10 @@ extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut
 
 extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let min_count: u32 = kwargs.get(Qstr::MP_QSTR_min_count)?.try_into()?;
-        let max_count: u32 = kwargs.get(Qstr::MP_QSTR_max_count)?.try_into()?;
-        let count: u32 = kwargs.get(Qstr::MP_QSTR_count)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let min_count: u32 = kwargs.get_field(Qstr::MP_QSTR_min_count)?;
+        let max_count: u32 = kwargs.get_field(Qstr::MP_QSTR_max_count)?;
+        let count: u32 = kwargs.get_field(Qstr::MP_QSTR_count)?;
 
         let obj = LayoutObj::new(
             Frame::new(
@@ -1401,8 +1953,8 @@ extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let active: usize = kwargs.get(Qstr::MP_QSTR_active)?.try_into()?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let active: usize = kwargs.get_field(Qstr::MP_QSTR_active)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecLong::new();
@@ -1441,10 +1993,10 @@ extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let dry_run: bool = kwargs.get(Qstr::MP_QSTR_dry_run)?.try_into()?;
-        let show_info: bool = kwargs.get(Qstr::MP_QSTR_show_info)?.try_into()?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let dry_run: bool = kwargs.get_field(Qstr::MP_QSTR_dry_run)?;
+        let show_info: bool = kwargs.get_field(Qstr::MP_QSTR_show_info)?;
 
         let mut paragraphs = ParagraphVecShort::new();
         paragraphs.add(Paragraph::new(&theme::TEXT_NORMAL, description));
@@ -1516,18 +2068,33 @@ extern "C" fn new_show_group_share_success(
 
 extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let indeterminate: bool = kwargs.get_or(Qstr::MP_QSTR_indeterminate, false)?;
         let description: StrBuffer =
             kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
+        let show_eta: bool = kwargs.get_or(Qstr::MP_QSTR_show_eta, false)?;
 
         // Description updates are received as &str and we need to provide a way to
         // convert them to StrBuffer.
-        let obj = LayoutObj::new(
-            Progress::new(indeterminate, description)
-                .with_title(title)
-                .with_update_description(StrBuffer::alloc),
-        )?;
+        let mut progress = Progress::new(indeterminate, description)
+            .with_title(title)
+            .with_update_description(StrBuffer::alloc);
+        if show_eta {
+            progress = progress.with_eta();
+        }
+        let obj = LayoutObj::new(progress)?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_pin_lockout(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let seconds: u32 = kwargs.get_field(Qstr::MP_QSTR_seconds)?;
+
+        // The title does not fit next to the timer.
+        let obj = LayoutObj::new(PinLockout::new(description, seconds))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1535,7 +2102,7 @@ extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_show_progress_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let indeterminate: bool = kwargs.get_or(Qstr::MP_QSTR_indeterminate, false)?;
         let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
         let skip_first_paint: bool = kwargs.get_or(Qstr::MP_QSTR_skip_first_paint, false)?;
@@ -1563,10 +2130,10 @@ extern "C" fn new_show_homescreen(n_args: usize, args: *const Obj, kwargs: *mut
             .try_into_option()?
             .unwrap_or_else(|| model::FULL_NAME.into());
         let notification: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_notification)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_notification)?;
         let notification_level: u8 = kwargs.get_or(Qstr::MP_QSTR_notification_level, 0)?;
-        let skip_first_paint: bool = kwargs.get(Qstr::MP_QSTR_skip_first_paint)?.try_into()?;
-        let hold: bool = kwargs.get(Qstr::MP_QSTR_hold)?.try_into()?;
+        let skip_first_paint: bool = kwargs.get_field(Qstr::MP_QSTR_skip_first_paint)?;
+        let hold: bool = kwargs.get_field(Qstr::MP_QSTR_hold)?;
 
         let notification = notification.map(|w| (w, notification_level));
         let loader_description = hold.then_some("Locking the device...".into());
@@ -1585,9 +2152,9 @@ extern "C" fn new_show_lockscreen(n_args: usize, args: *const Obj, kwargs: *mut
             .get(Qstr::MP_QSTR_label)?
             .try_into_option()?
             .unwrap_or_else(|| model::FULL_NAME.into());
-        let bootscreen: bool = kwargs.get(Qstr::MP_QSTR_bootscreen)?.try_into()?;
+        let bootscreen: bool = kwargs.get_field(Qstr::MP_QSTR_bootscreen)?;
         let coinjoin_authorized: bool = kwargs.get_or(Qstr::MP_QSTR_coinjoin_authorized, false)?;
-        let skip_first_paint: bool = kwargs.get(Qstr::MP_QSTR_skip_first_paint)?.try_into()?;
+        let skip_first_paint: bool = kwargs.get_field(Qstr::MP_QSTR_skip_first_paint)?;
 
         let obj = LayoutObj::new(Lockscreen::new(label, bootscreen, coinjoin_authorized))?;
         if skip_first_paint {
@@ -1605,8 +2172,8 @@ extern "C" fn new_confirm_firmware_update(
 ) -> Obj {
     use super::component::bl_confirm::Confirm;
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let fingerprint: StrBuffer = kwargs.get(Qstr::MP_QSTR_fingerprint)?.try_into()?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let fingerprint: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fingerprint)?;
 
         let title = "UPDATE FIRMWARE";
         let message = Label::left_aligned(description, theme::TEXT_NORMAL).vertically_centered();
@@ -1652,10 +2219,176 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// INFO: object
     Qstr::MP_QSTR_INFO => INFO.as_obj(),
 
+    /// SELECTED: object
+    Qstr::MP_QSTR_SELECTED => SELECTED.as_obj(),
+
+    /// MORE: object
+    Qstr::MP_QSTR_MORE => MORE.as_obj(),
+
+    /// class ValueResult:
+    ///     """Result of a layout carrying a value, e.g. the selected index."""
+    ///     kind: object
+    ///     name: str
+    ///     value: int
+    /// mock:global
+
+    /// class ValidationError(ValueError):
+    ///     """Layout argument failed validation. The first argument of the
+    ///     exception is name of the field, the second one the reason."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_ValidationError => unsafe { ffi::mp_type_ValidationError.as_obj() },
+
+    /// class MissingFieldError(ValidationError):
+    ///     """Required layout argument was not passed."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_MissingFieldError => unsafe { ffi::mp_type_MissingFieldError.as_obj() },
+
+    /// class InvalidFieldError(ValidationError):
+    ///     """Layout argument has an unexpected type or value."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_InvalidFieldError => unsafe { ffi::mp_type_InvalidFieldError.as_obj() },
+
     /// def disable_animation(disable: bool) -> None:
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def disable_transitions(disable: bool) -> None:
+    ///     """Disable animated screen transitions."""
+    Qstr::MP_QSTR_disable_transitions => obj_fn_1!(upy_disable_transitions).as_obj(),
+
+    /// def set_accessibility(
+    ///     *,
+    ///     reduce_motion: bool = False,
+    ///     longer_timeouts: bool = False,
+    ///     larger_fonts: bool = False,
+    ///     high_contrast: bool = False,
+    ///     audio_feedback: bool = False,
+    /// ) -> None:
+    ///     """Set the accessibility preferences of the user. Reduced motion skips
+    ///     decorative movement, e.g. transitions and sliding items. Longer
+    ///     timeouts give more time to read and to hold buttons. High contrast
+    ///     paints grey text in white or black. Audio feedback plays the outcome
+    ///     of a flow on the buzzer, if there is one."""
+    Qstr::MP_QSTR_set_accessibility => obj_fn_kw!(0, upy_set_accessibility).as_obj(),
+
+    /// def set_autodim(
+    ///     *,
+    ///     delay_ms: int | None = None,
+    ///     level: int = 0,
+    /// ) -> None:
+    ///     """Dim the display to backlight `level` after `delay_ms` without input, or
+    ///     never with `None`. The input waking the display up is not passed on to the
+    ///     layout."""
+    Qstr::MP_QSTR_set_autodim => obj_fn_kw!(0, upy_set_autodim).as_obj(),
+
+    /// def set_wallet_badge(hidden: bool | None) -> None:
+    ///     """Show on every screen whether the current session uses a hidden
+    ///     wallet, i.e. one derived with a non-empty passphrase, or the
+    ///     standard one. `None` hides the badge."""
+    Qstr::MP_QSTR_set_wallet_badge => obj_fn_1!(upy_set_wallet_badge).as_obj(),
+
+    /// def set_button_quirks(
+    ///     *,
+    ///     seed: int | None,
+    ///     bounce: int = 0,
+    ///     missed_release: int = 0,
+    ///     dropped: int = 0,
+    /// ) -> None:
+    ///     """Simulate bouncing buttons, lost releases and lost events, each in the
+    ///     given percentage of the button events, drawn from a PRNG seeded with
+    ///     `seed`. `None` turns the simulation off. Debug builds only."""
+    Qstr::MP_QSTR_set_button_quirks => obj_fn_kw!(0, upy_set_button_quirks).as_obj(),
+
+    /// def trace_paint_counters(enabled: bool) -> None:
+    ///     """Include in the traces of layouts how many times each component has
+    ///     been painted and has requested a paint. Debug builds only."""
+    Qstr::MP_QSTR_trace_paint_counters => obj_fn_1!(upy_trace_paint_counters).as_obj(),
+
+    /// def wipe_carry() -> None:
+    ///     """Zeroize the passphrase kept by `request_passphrase` with
+    ///     `carry_out` in case no other entry has taken it."""
+    Qstr::MP_QSTR_wipe_carry => obj_fn_0!(upy_wipe_carry).as_obj(),
+
+    /// def secrets_equal(a: str, b: str) -> bool:
+    ///     """Compare two secrets, e.g. a PIN and its re-entry, in time that does
+    ///     not depend on where they differ."""
+    Qstr::MP_QSTR_secrets_equal => obj_fn_2!(upy_secrets_equal).as_obj(),
+
+    /// def enable_profiler(enable: bool) -> None:
+    ///     """Show paint and event handling times of layouts in the corner of
+    ///     the screen, debug builds only."""
+    Qstr::MP_QSTR_enable_profiler => obj_fn_1!(upy_enable_profiler).as_obj(),
+
+    /// def set_replay_seed(seed: int | None) -> None:
+    ///     """Draw UI randomness from a PRNG seeded with `seed`, or from the RNG
+    ///     again if `None`. Emulator debug builds only."""
+    Qstr::MP_QSTR_set_replay_seed => obj_fn_1!(upy_set_replay_seed).as_obj(),
+
+    /// def translations_max_length() -> int:
+    ///     """Longest translations blob fitting the flash, zero on models
+    ///     without room for one."""
+    Qstr::MP_QSTR_translations_max_length => obj_fn_0!(upy_translations_max_length).as_obj(),
+
+    /// def erase_translations() -> None:
+    ///     """Switch to English and erase the translations installed in the
+    ///     flash."""
+    Qstr::MP_QSTR_erase_translations => obj_fn_0!(upy_erase_translations).as_obj(),
+
+    /// def write_translations(data: bytes, offset: int) -> None:
+    ///     """Write `data` at `offset` of the translations blob being installed,
+    ///     after `erase_translations()`."""
+    Qstr::MP_QSTR_write_translations => obj_fn_2!(upy_write_translations).as_obj(),
+
+    /// def commit_translations(length: int) -> None:
+    ///     """Install the first `length` bytes written as the translations blob,
+    ///     without switching to it. Until then nothing is installed."""
+    Qstr::MP_QSTR_commit_translations => obj_fn_1!(upy_commit_translations).as_obj(),
+
+    /// def load_translations() -> None:
+    ///     """Use the strings of the installed translations. Strings missing in
+    ///     them are shown in English. Raises `ValueError` if there are none or
+    ///     they are invalid."""
+    Qstr::MP_QSTR_load_translations => obj_fn_0!(upy_load_translations).as_obj(),
+
+    /// def set_battery_level(level: int | None) -> None:
+    ///     """Charge of the battery in percent, or `None` on external power. Below
+    ///     20 percent animations run with fewer frames and decorative effects, e.g.
+    ///     marquees and screen transitions, are skipped."""
+    Qstr::MP_QSTR_set_battery_level => obj_fn_1!(upy_set_battery_level).as_obj(),
+
+    /// def set_density(density: int) -> None:
+    ///     """Spacing of confirm screens, a value of `ScreenDensity`. Compact
+    ///     has tighter lines and narrower fonts fitting more on each page."""
+    Qstr::MP_QSTR_set_density => obj_fn_1!(upy_set_density).as_obj(),
+
+    /// def set_theme_inverted(inverted: bool) -> None:
+    ///     """Paint the screens dark on light, the shades of grey swapped. Takes
+    ///     effect from the next paint."""
+    Qstr::MP_QSTR_set_theme_inverted => obj_fn_1!(upy_set_theme_inverted).as_obj(),
+
+    /// def save_resume_point(*, flow: int, step: int, page: int) -> None:
+    ///     """Remember that `page` of `step` was shown in the flow identified by
+    ///     `flow`, so that it can be resumed after a reset. Layouts keep the
+    ///     page up to date as it changes. Needs the storage unlocked."""
+    Qstr::MP_QSTR_save_resume_point => obj_fn_kw!(0, upy_save_resume_point).as_obj(),
+
+    /// def resume_point(flow: int) -> tuple[int, int] | None:
+    ///     """Step and page saved for `flow`, or `None` when there is nothing to
+    ///     resume."""
+    Qstr::MP_QSTR_resume_point => obj_fn_1!(upy_resume_point).as_obj(),
+
+    /// def clear_resume_point() -> None:
+    ///     """Forget the saved point, e.g. once the flow is finished."""
+    Qstr::MP_QSTR_clear_resume_point => obj_fn_0!(upy_clear_resume_point).as_obj(),
+
+    /// def set_keyboard_layout(layout: int) -> None:
+    ///     """Order the letters of keyboards created from now on by `layout`, a
+    ///     value of `KeyboardLayout`."""
+    Qstr::MP_QSTR_set_keyboard_layout => obj_fn_1!(upy_set_keyboard_layout).as_obj(),
+
     /// def check_homescreen_format(data: bytes) -> bool:
     ///     """Check homescreen format and dimensions."""
     Qstr::MP_QSTR_check_homescreen_format => obj_fn_1!(upy_check_homescreen_format).as_obj(),
@@ -1674,6 +2407,35 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Confirm action."""
     Qstr::MP_QSTR_confirm_action => obj_fn_kw!(0, new_confirm_action).as_obj(),
 
+    /// def confirm_terms(
+    ///     *,
+    ///     title: str,
+    ///     text: str,
+    ///     verb: str = "ACCEPT",
+    /// ) -> object:
+    ///     """Long notice or disclaimer. Confirming is only possible once the last page
+    ///     has been shown."""
+    Qstr::MP_QSTR_confirm_terms => obj_fn_kw!(0, new_confirm_terms).as_obj(),
+
+    /// def confirm_blind_signing(
+    ///     *,
+    ///     title: str | None = None,
+    ///     description: str = "",
+    /// ) -> object:
+    ///     """Mandatory warning before signing something the device cannot decode,
+    ///     e.g. a call of an unknown contract or raw data. Accepted by holding."""
+    Qstr::MP_QSTR_confirm_blind_signing => obj_fn_kw!(0, new_confirm_blind_signing).as_obj(),
+
+    /// def confirm_language(
+    ///     *,
+    ///     version: str,
+    ///     signed: bool,
+    /// ) -> object:
+    ///     """Confirm switching to the translations in use, showing their language,
+    ///     `version` and whether they are signed. The screen itself is in the new
+    ///     language."""
+    Qstr::MP_QSTR_confirm_language => obj_fn_kw!(0, new_confirm_language).as_obj(),
+
     /// def confirm_homescreen(
     ///     *,
     ///     title: str,
@@ -1720,6 +2482,30 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     This only concerns the text style, you need to decode the value to UTF-8 in python."""
     Qstr::MP_QSTR_confirm_properties => obj_fn_kw!(0, new_confirm_properties).as_obj(),
 
+    /// def confirm_template(
+    ///     *,
+    ///     title: str,
+    ///     rows: Iterable[tuple[str, str]],
+    ///     warning: bool = False,
+    ///     verb: str = "CONFIRM",
+    ///     verb_cancel: str = "CANCEL",
+    /// ) -> object:
+    ///     """Confirm an application-specific action described by the host. Every
+    ///     text must be trimmed printable ASCII and short enough, there can be 1 to
+    ///     6 rows. Raises ValueError otherwise."""
+    Qstr::MP_QSTR_confirm_template => obj_fn_kw!(0, new_confirm_template).as_obj(),
+
+    /// def confirm_payment_uri(
+    ///     *,
+    ///     uri: str,
+    ///     scheme: str = "bitcoin",
+    ///     title: str = "PAYMENT REQUEST",
+    /// ) -> object:
+    ///     """Confirm a BIP-21 payment URI, its address, amount, label and message,
+    ///     with the whole URI on the last page. Raises ValueError when the URI is
+    ///     malformed."""
+    Qstr::MP_QSTR_confirm_payment_uri => obj_fn_kw!(0, new_confirm_payment_uri).as_obj(),
+
     /// def confirm_reset_device(
     ///     *,
     ///     title: str,
@@ -1777,16 +2563,20 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     address_label: str,
     ///     address_title: str,
     ///     chunkify: bool = False,
+    ///     coin: str | None = None,
     /// ) -> object:
-    ///     """Confirm output address."""
+    ///     """Confirm output address. The icon and name of the `coin` with the
+    ///     given shortcut are shown in front of the title, if there is an icon
+    ///     for it."""
     Qstr::MP_QSTR_confirm_output_address => obj_fn_kw!(0, new_confirm_output_address).as_obj(),
 
     /// def confirm_output_amount(
     ///     *,
     ///     amount: str,
     ///     amount_title: str,
+    ///     coin: str | None = None,
     /// ) -> object:
-    ///     """Confirm output amount."""
+    ///     """Confirm output amount, with the `coin` like confirm_output_address."""
     Qstr::MP_QSTR_confirm_output_amount => obj_fn_kw!(0, new_confirm_output_amount).as_obj(),
 
     /// def confirm_total(
@@ -1834,10 +2624,12 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     app_name: str,
     ///     icon_name: str | None,  # unused on TR
     ///     accounts: list[str | None],
-    /// ) -> int | object:
+    ///     time_ms: int = 0,
+    /// ) -> ValueResult | object:
     ///     """FIDO confirmation.
     ///
-    ///     Returns page index in case of confirmation and CANCELLED otherwise.
+    ///     Returns SELECTED with the page index in case of confirmation and CANCELLED
+    ///     otherwise, also when `time_ms` is set and the countdown runs out.
     ///     """
     Qstr::MP_QSTR_confirm_fido => obj_fn_kw!(0, new_confirm_fido).as_obj(),
 
@@ -1859,6 +2651,20 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Warning modal with middle button and centered text."""
     Qstr::MP_QSTR_show_warning => obj_fn_kw!(0, new_show_warning).as_obj(),
 
+    /// def show_entropy_mixing(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     seed: bytes,
+    ///     time_ms: int,
+    /// ) -> bytes:
+    ///     """Abstract pattern stirred while the entropy of a new wallet is
+    ///     mixed, starting from `seed`, a hash of the entropy and not the
+    ///     entropy itself. Closes after `time_ms` unless the user presses the
+    ///     buttons or taps the screen to add entropy, then once they stop.
+    ///     Returns the timing of the presses, empty if there were none."""
+    Qstr::MP_QSTR_show_entropy_mixing => obj_fn_kw!(0, new_show_entropy_mixing).as_obj(),
+
     /// def show_info(
     ///     *,
     ///     title: str,
@@ -1868,6 +2674,32 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Info modal."""
     Qstr::MP_QSTR_show_info => obj_fn_kw!(0, new_show_info).as_obj(),
 
+    /// def show_settings_menu(
+    ///     *,
+    ///     settings: Iterable[tuple[str, int]],
+    /// ) -> tuple[list[tuple[str, int]], bool]:
+    ///     """Settings menu. `settings` are names and current values of the
+    ///     settings to offer, one of `auto_lock`, `rotation`, `safety_checks`,
+    ///     `large_text`, `remember_destinations`, `spending_threshold` and
+    ///     `signing_receipt`. Returns the names and new values of the changed
+    ///     ones, and whether the user wants to rename the device."""
+    Qstr::MP_QSTR_show_settings_menu => obj_fn_kw!(0, new_show_settings_menu).as_obj(),
+
+    /// def show_result(
+    ///     *,
+    ///     success: bool,
+    ///     title: str,
+    ///     detail: str = "",
+    ///     button: str = "",
+    ///     time_ms: int = 0,
+    ///     rejected: bool = False,
+    /// ) -> object:
+    ///     """Result of a flow, dismissed by the button. Closes on its own once
+    ///     the icon animation finishes when there is no button or `time_ms` is
+    ///     set. A failure the user has `rejected` plays the reject sound
+    ///     instead of the error one."""
+    Qstr::MP_QSTR_show_result => obj_fn_kw!(0, new_show_result).as_obj(),
+
     /// def show_passphrase() -> object:
     ///     """Show passphrase on host dialog."""
     Qstr::MP_QSTR_show_passphrase => obj_fn_0!(new_show_passphrase).as_obj(),
@@ -1898,6 +2730,44 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     Meant to be used with confirm_with_info."""
     Qstr::MP_QSTR_confirm_more => obj_fn_kw!(0, new_confirm_more).as_obj(),
 
+    /// def show_blob(
+    ///     *,
+    ///     title: str,
+    ///     data: bytes,
+    ///     base64: bool = False,
+    ///     verb: str = "",
+    ///     total: int = 0,
+    /// ) -> object:
+    ///     """All of `data` in hex or base64, with offsets and the total length,
+    ///     on as many pages as needed. Returns CANCELLED to switch the format.
+    ///     With `verb`, the data is to be confirmed with that button, the left
+    ///     one rejects it instead. `total` is the length of the whole blob if
+    ///     `data` is only its start."""
+    Qstr::MP_QSTR_show_blob => obj_fn_kw!(0, new_show_blob).as_obj(),
+
+    /// def show_receipt(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     fingerprint: str,
+    ///     qr_data: str,
+    ///     qr: bool = False,
+    /// ) -> object:
+    ///     """Short fingerprint of what was signed, to be compared with the host.
+    ///     `qr` shows `qr_data` as a QR code instead."""
+    Qstr::MP_QSTR_show_receipt => obj_fn_kw!(0, new_show_receipt).as_obj(),
+
+    /// def confirm_settings_change(
+    ///     *,
+    ///     title: str,
+    ///     changes: Iterable[tuple[str, str, str, bool]],
+    ///     hold: bool = False,
+    /// ) -> object:
+    ///     """Confirm changes of settings, each of them `(name, old value, new
+    ///     value, reduces security)`. The new values reducing security stand
+    ///     out, `hold` for changes like that."""
+    Qstr::MP_QSTR_confirm_settings_change => obj_fn_kw!(0, new_confirm_settings_change).as_obj(),
+
     /// def confirm_coinjoin(
     ///     *,
     ///     max_rounds: str,
@@ -1912,18 +2782,36 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     subprompt: str,
     ///     allow_cancel: bool = True,  # unused on TR
     ///     wrong_pin: bool = False,  # unused on TR
+    ///     shuffle: bool = True,
+    ///     shuffle_on_keypress: bool = True,
     /// ) -> str | object:
-    ///     """Request pin on device."""
+    ///     """Request pin on device. A random digit is offered first or after
+    ///     every digit entered, according to `shuffle` and `shuffle_on_keypress`."""
     Qstr::MP_QSTR_request_pin => obj_fn_kw!(0, new_request_pin).as_obj(),
 
     /// def request_passphrase(
     ///     *,
     ///     prompt: str,
-    ///     max_len: int,  # unused on TR
+    ///     max_len: int,
+    ///     carry_in: bool = False,
+    ///     carry_out: bool = False,
     /// ) -> str | object:
-    ///     """Get passphrase."""
+    ///     """Get passphrase. With `carry_out` the confirmed passphrase is kept
+    ///     for the next entry of the flow, which takes it with `carry_in` and
+    ///     starts with it entered."""
     Qstr::MP_QSTR_request_passphrase => obj_fn_kw!(0, new_request_passphrase).as_obj(),
 
+    /// def request_label(
+    ///     *,
+    ///     prompt: str,
+    ///     label: str = "",
+    ///     max_len: int,
+    /// ) -> str | object:
+    ///     """Entry of a name of up to `max_len` bytes, e.g. the device label,
+    ///     starting with `label` entered. Unlike a passphrase, the characters
+    ///     are shown as they are entered."""
+    Qstr::MP_QSTR_request_label => obj_fn_kw!(0, new_request_label).as_obj(),
+
     /// def request_bip39(
     ///     *,
     ///     prompt: str,
@@ -1943,11 +2831,40 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     title: str,  # unused on TR
     ///     description: str,
     ///     words: Iterable[str],
-    /// ) -> int:
+    /// ) -> ValueResult:
     ///    """Select mnemonic word from three possibilities - seed check after backup. The
-    ///    iterable must be of exact size. Returns index in range `0..3`."""
+    ///    iterable must be of exact size. Returns SELECTED with index in range `0..3`."""
     Qstr::MP_QSTR_select_word => obj_fn_kw!(0, new_select_word).as_obj(),
 
+    /// def choose_from_list(
+    ///     *,
+    ///     title: str,
+    ///     items: Iterable[str],
+    /// ) -> ValueResult:
+    ///    """Select one of the items provided by the host, e.g. an account name. At most
+    ///    32 items of at most 20 bytes each. Returns SELECTED with the index of the item."""
+    Qstr::MP_QSTR_choose_from_list => obj_fn_kw!(0, new_choose_from_list).as_obj(),
+
+    /// def edit_list(
+    ///     *,
+    ///     title: str,
+    ///     items: Iterable[str],
+    ///     available: Iterable[str] | None = None,
+    /// ) -> list[str] | object:
+    ///    """Reorder and delete `items`, e.g. labels or enabled coins, and add the
+    ///    `available` ones. At most 16 items together, of at most 20 bytes each.
+    ///    Returns the final list."""
+    Qstr::MP_QSTR_edit_list => obj_fn_kw!(0, new_edit_list).as_obj(),
+
+    /// def choose_account(
+    ///     *,
+    ///     title: str,
+    ///     accounts: Iterable[tuple[str, str]],
+    /// ) -> ValueResult:
+    ///    """Select one of the accounts provided by the host, `(label, balance)` pairs. At
+    ///    most 16 accounts. Returns SELECTED with the index of the account."""
+    Qstr::MP_QSTR_choose_account => obj_fn_kw!(0, new_choose_account).as_obj(),
+
     /// def show_share_words(
     ///     *,
     ///     share_words: Iterable[str],
@@ -1992,7 +2909,7 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// def select_word_count(
     ///     *,
     ///     dry_run: bool,  # unused on TR
-    /// ) -> int | str:  # TR returns str
+    /// ) -> ValueResult | str:  # TR returns str
     ///    """Select mnemonic word count from (12, 18, 20, 24, 33)."""
     Qstr::MP_QSTR_select_word_count => obj_fn_kw!(0, new_select_word_count).as_obj(),
 
@@ -2008,12 +2925,24 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     title: str,
     ///     indeterminate: bool = False,
     ///     description: str = "",
+    ///     show_eta: bool = False,
     /// ) -> object:
     ///    """Show progress loader. Please note that the number of lines reserved on screen for
     ///    description is determined at construction time. If you want multiline descriptions
-    ///    make sure the initial description has at least that amount of lines."""
+    ///    make sure the initial description has at least that amount of lines. With `show_eta`,
+    ///    estimated remaining time is shown above the description (determinate mode only)."""
     Qstr::MP_QSTR_show_progress => obj_fn_kw!(0, new_show_progress).as_obj(),
 
+    /// def show_pin_lockout(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     seconds: int,
+    /// ) -> object:
+    ///     """Countdown of the `seconds` long wait after wrong PINs, driven by
+    ///     progress events like `show_progress`. The title is not shown."""
+    Qstr::MP_QSTR_show_pin_lockout => obj_fn_kw!(0, new_show_pin_lockout).as_obj(),
+
     /// def show_progress_coinjoin(
     ///     *,
     ///     title: str,
@@ -2032,8 +2961,8 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     notification: str | None,
     ///     notification_level: int = 0,
     ///     skip_first_paint: bool,
-    /// ) -> CANCELLED:
-    ///     """Idle homescreen."""
+    /// ) -> CANCELLED | INFO:
+    ///     """Idle homescreen. Returns INFO when the user opens the settings."""
     Qstr::MP_QSTR_show_homescreen => obj_fn_kw!(0, new_show_homescreen).as_obj(),
 
     /// def show_lockscreen(
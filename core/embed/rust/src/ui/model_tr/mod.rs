@@ -6,4 +6,6 @@ pub mod constant;
 #[cfg(feature = "micropython")]
 pub mod layout;
 pub mod screens;
+#[cfg(test)]
+mod snapshots;
 pub mod theme;
@@ -0,0 +1,56 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::text::paragraphs::{Paragraph, Paragraphs},
+    snapshot::assert_snapshot,
+};
+
+use super::{
+    component::{ButtonDetails, ButtonPage, PinEntry, ScrollableFrame, SimpleChoice},
+    theme,
+};
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn pin_entry() {
+    assert_snapshot("pin_entry", || {
+        PinEntry::<&'static str>::new("ENTER PIN", "")
+    });
+}
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn pin_entry_wrong_pin() {
+    assert_snapshot("pin_entry_wrong_pin", || {
+        PinEntry::<&'static str>::new("ENTER PIN", "2 tries left")
+    });
+}
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn choice_page() {
+    assert_snapshot("choice_page", || {
+        let choices: Vec<&'static str, 5> =
+            Vec::from_slice(&["12", "18", "20", "24", "33"]).unwrap();
+        SimpleChoice::new(choices, false)
+    });
+}
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn confirm_action() {
+    assert_snapshot("confirm_action", || {
+        ScrollableFrame::new(
+            ButtonPage::new(
+                Paragraphs::new([Paragraph::new(
+                    &theme::TEXT_NORMAL,
+                    "Do you really want to wipe the device?",
+                )]),
+                theme::BG,
+            )
+            .with_cancel_btn(Some(ButtonDetails::cancel_icon()))
+            .with_confirm_btn(Some(ButtonDetails::text("WIPE"))),
+        )
+        .with_title("WIPE DEVICE")
+    });
+}
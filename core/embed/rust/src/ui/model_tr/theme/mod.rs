@@ -3,8 +3,10 @@ use crate::ui::{
         text::{layout::Chunks, TextStyle},
         LineBreaking, PageBreaking,
     },
+    context::settings,
     display::{toif::Icon, Color, Font},
     geometry::Offset,
+    theme::{Theme, ThemeVariant},
 };
 
 use num_traits::FromPrimitive;
@@ -45,9 +47,36 @@ pub const TEXT_MONO_ADDRESS_CHUNKS: TextStyle = TEXT_MONO_DATA
     .with_line_spacing(2)
     .with_ellipsis_icon(ICON_NEXT_PAGE, -2);
 
+/// Black on white, for selected items and warnings.
+pub const TEXT_NORMAL_INVERSE: TextStyle = TEXT_NORMAL.inverse();
+pub const TEXT_BOLD_INVERSE: TextStyle = TEXT_BOLD.inverse();
+
+/// Amounts and addresses in the large-text mode, see `value_style`.
+pub const TEXT_BIG_VALUE: TextStyle = TextStyle::new(Font::BIG, FG, BG, FG, FG)
+    .with_line_breaking(LineBreaking::BreakWordsNoHyphen)
+    .with_page_breaking(PageBreaking::CutAndInsertEllipsisBoth)
+    .with_ellipsis_icon(ICON_NEXT_PAGE, ELLIPSIS_ICON_MARGIN)
+    .with_prev_page_icon(ICON_PREV_PAGE, PREV_PAGE_ICON_MARGIN);
+
 // Chunks for this model
 pub const MONO_CHUNKS: Chunks = Chunks::new(4, 4);
 
+/// Style of amounts and addresses on confirm screens, `style` unless the user
+/// turned on the large-text mode. Then they are in the big font, spread over
+/// more pages.
+pub fn value_style(style: &'static TextStyle) -> &'static TextStyle {
+    if settings().accessibility.larger_fonts {
+        &TEXT_BIG_VALUE
+    } else {
+        style
+    }
+}
+
+/// Font of amounts and addresses in formatted text, see `value_style`.
+pub fn value_font() -> Font {
+    value_style(&TEXT_MONO).text_font
+}
+
 /// Convert Python-side numeric id to a `TextStyle`.
 pub fn textstyle_number(num: i32) -> &'static TextStyle {
     let font = Font::from_i32(-num);
@@ -103,6 +132,7 @@ include_icon!(ICON_PREV_PAGE, "model_tr/res/prev_page.toif"); // 8*10
 include_icon!(ICON_SPACE, "model_tr/res/space.toif"); // 12*3
 include_icon!(ICON_TICK, "model_tr/res/tick.toif"); // 8*6
 include_icon!(ICON_TICK_FAT, "model_tr/res/tick_fat.toif"); // 8*6
+include_icon!(ICON_WALLET, "model_tr/res/wallet.toif"); // 10*10
 include_icon!(
     ICON_WARNING,
     "model_tr/res/warning.toif",
@@ -125,6 +155,65 @@ pub const BUTTON_HEIGHT: i16 = BUTTON_CONTENT_HEIGHT + 2 * BUTTON_OUTLINE;
 pub const BUTTON_ICON_WIDTH: i16 = BUTTON_HEIGHT;
 pub const TITLE_AREA_HEIGHT: i16 = 12;
 pub const ARMS_MARGIN: i16 = 2;
+/// Height of the striped band above the blind signing warning.
+pub const BLIND_SIGNING_STRIPES: i16 = 3;
+
+/// Theme of the model, for the code shared between the models.
+pub fn current() -> ModelTheme {
+    ModelTheme
+}
+
+pub struct ModelTheme;
+
+impl Theme for ModelTheme {
+    fn variant(&self) -> ThemeVariant {
+        settings().theme_variant
+    }
+
+    fn fg(&self) -> Color {
+        FG
+    }
+
+    fn bg(&self) -> Color {
+        BG
+    }
+
+    fn fg_secondary(&self) -> Color {
+        FG
+    }
+
+    fn font_normal(&self) -> Font {
+        Font::NORMAL
+    }
+
+    fn font_bold(&self) -> Font {
+        Font::BOLD
+    }
+
+    fn font_mono(&self) -> Font {
+        Font::MONO
+    }
+
+    fn text_normal(&self) -> &'static TextStyle {
+        &TEXT_NORMAL
+    }
+
+    fn text_bold(&self) -> &'static TextStyle {
+        &TEXT_BOLD
+    }
+
+    fn content_padding(&self) -> i16 {
+        0
+    }
+
+    fn icon_confirm(&self) -> Icon {
+        ICON_TICK
+    }
+
+    fn icon_cancel(&self) -> Icon {
+        ICON_CANCEL
+    }
+}
 
 // How many pixels should be between text and icons.
 pub const ELLIPSIS_ICON_MARGIN: i16 = 4;
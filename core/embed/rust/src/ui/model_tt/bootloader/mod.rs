@@ -4,9 +4,10 @@ use crate::{
     ui::{
         component::{Component, Event, EventCtx, Label, Never},
         constant::{screen, HEIGHT},
+        context::with_state,
         display::{self, Color, Font, Icon},
         event::TouchEvent,
-        geometry::Point,
+        geometry::{Point, Rect},
         model_tt::{
             bootloader::{connect::Connect, welcome::Welcome},
             component::{
@@ -243,6 +244,7 @@ extern "C" fn screen_intro(
 
 fn screen_progress(
     text: &str,
+    detail: &str,
     progress: u16,
     initialize: bool,
     fg_color: Color,
@@ -261,6 +263,17 @@ fn screen_progress(
         fg_color,
         bg_color,
     );
+    if !detail.is_empty() {
+        let baseline = Point::new(constant::WIDTH / 2, HEIGHT - 20);
+        display::rect_fill(
+            Rect::new(
+                Point::new(0, baseline.y - Font::NORMAL.text_max_height()),
+                Point::new(constant::WIDTH, baseline.y + Font::NORMAL.text_baseline()),
+            ),
+            bg_color,
+        );
+        display::text_center(baseline, detail, Font::NORMAL, fg_color, bg_color);
+    }
     display::loader(progress, -20, fg_color, bg_color, icon);
     if initialize {
         fadein();
@@ -276,6 +289,7 @@ extern "C" fn screen_install_progress(progress: u16, initialize: bool, initial_s
 
     screen_progress(
         "Installing firmware",
+        "",
         progress,
         initialize,
         fg_color,
@@ -286,8 +300,16 @@ extern "C" fn screen_install_progress(progress: u16, initialize: bool, initial_s
 
 #[no_mangle]
 extern "C" fn screen_wipe_progress(progress: u16, initialize: bool) {
+    // SAFETY: the bootloader is single-threaded
+    let remaining = with_state(|s| {
+        if initialize {
+            s.wipe_eta.reset();
+        }
+        s.wipe_eta.update_text(progress)
+    });
     screen_progress(
         "Resetting Trezor",
+        remaining.as_str(),
         progress,
         initialize,
         BLD_FG,
@@ -17,7 +17,7 @@ use super::{theme, Frame, FrameMsg};
 const MAX_XPUBS: usize = 16;
 
 pub struct AddressDetails<T> {
-    qr_code: Frame<Qr, T>,
+    qr_code: Frame<Qr<T>, T>,
     details: Frame<Paragraphs<ParagraphVecShort<T>>, T>,
     xpub_view: Frame<Paragraphs<Paragraph<T>>, T>,
     xpubs: Vec<(T, T), MAX_XPUBS>,
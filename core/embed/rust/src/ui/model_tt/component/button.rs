@@ -7,6 +7,8 @@ use crate::{
         display::{self, toif::Icon, Color, Font},
         event::TouchEvent,
         geometry::{Alignment2D, Insets, Offset, Point, Rect},
+        theme::ButtonIntent,
+        translations::TranslatedString,
     },
 };
 
@@ -50,6 +52,11 @@ impl<T> Button<T> {
         Self::new(ButtonContent::Text(text))
     }
 
+    /// Button labeled with `text` in the current language.
+    pub const fn with_translated(text: TranslatedString) -> Self {
+        Self::new(ButtonContent::Translated(text))
+    }
+
     pub const fn with_icon(icon: Icon) -> Self {
         Self::new(ButtonContent::Icon(icon))
     }
@@ -71,6 +78,11 @@ impl<T> Button<T> {
         self
     }
 
+    /// Style the button according to its meaning.
+    pub const fn with_intent(self, intent: ButtonIntent) -> Self {
+        self.styled(theme::button_for(intent))
+    }
+
     pub const fn with_expanded_touch_area(mut self, expand: Insets) -> Self {
         self.touch_expand = Some(expand);
         self
@@ -193,20 +205,9 @@ impl<T> Button<T> {
     {
         match &self.content {
             ButtonContent::Empty => {}
-            ButtonContent::Text(text) => {
-                let text = text.as_ref();
-                let width = style.font.text_width(text);
-                let height = style.font.text_height();
-                let start_of_baseline = self.area.center()
-                    + Offset::new(-width / 2, height / 2)
-                    + Offset::y(Self::BASELINE_OFFSET);
-                display::text_left(
-                    start_of_baseline,
-                    text,
-                    style.font,
-                    style.text_color,
-                    style.button_color,
-                );
+            ButtonContent::Text(text) => self.paint_text(text.as_ref(), style),
+            ButtonContent::Translated(text) => {
+                text.map_translated(|text| self.paint_text(text, style))
             }
             ButtonContent::Icon(icon) => {
                 icon.draw(
@@ -227,6 +228,21 @@ impl<T> Button<T> {
             ),
         }
     }
+
+    fn paint_text(&self, text: &str, style: &ButtonStyle) {
+        let width = style.font.text_width(text);
+        let height = style.font.text_height();
+        let start_of_baseline = self.area.center()
+            + Offset::new(-width / 2, height / 2)
+            + Offset::y(Self::BASELINE_OFFSET);
+        display::text_left(
+            start_of_baseline,
+            text,
+            style.font,
+            style.text_color,
+            style.button_color,
+        );
+    }
 }
 
 impl<T> Component for Button<T>
@@ -258,6 +274,7 @@ where
                         if touch_area.contains(pos) {
                             self.set(ctx, State::Pressed);
                             if let Some(duration) = self.long_press {
+                                let duration = ctx.accessibility().hold(duration);
                                 self.long_timer = Some(ctx.request_timer(duration));
                             }
                             return Some(ButtonMsg::Pressed);
@@ -303,6 +320,10 @@ where
                     }
                 }
             }
+            // Resolved in the new language when painted.
+            Event::LanguageChanged if matches!(self.content, ButtonContent::Translated(_)) => {
+                ctx.request_paint();
+            }
             _ => {}
         };
         None
@@ -327,9 +348,11 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("Button");
+        t.bounds(self.area);
         match &self.content {
             ButtonContent::Empty => {}
             ButtonContent::Text(text) => t.string("text", text.as_ref()),
+            ButtonContent::Translated(text) => text.map_translated(|text| t.string("text", text)),
             ButtonContent::Icon(_) => t.bool("icon", true),
             ButtonContent::IconAndText(content) => {
                 t.string("text", content.text);
@@ -352,6 +375,8 @@ enum State {
 pub enum ButtonContent<T> {
     Empty,
     Text(T),
+    /// Text in the current language, resolved again whenever painted.
+    Translated(TranslatedString),
     Icon(Icon),
     IconAndText(IconText),
     IconBlend(Icon, Icon, Offset),
@@ -430,10 +455,11 @@ impl<T> Button<T> {
             Button::with_icon(theme::ICON_CANCEL)
         };
         let right = if let Some(verb) = right {
-            Button::with_text(verb).styled(theme::button_confirm())
+            Button::with_text(verb)
         } else {
-            Button::with_icon(theme::ICON_CONFIRM).styled(theme::button_confirm())
-        };
+            Button::with_icon(theme::ICON_CONFIRM)
+        }
+        .with_intent(ButtonIntent::Confirm);
         Self::cancel_confirm(left, right, left_is_small)
     }
 
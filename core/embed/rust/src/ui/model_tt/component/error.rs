@@ -1,7 +1,7 @@
 use crate::ui::{
-    component::{Child, Component, Event, EventCtx, Label, Never, Pad},
+    component::{Child, Component, Event, EventCtx, Label, Never, Pad, Qr},
     constant::screen,
-    geometry::{Alignment2D, Point, Rect},
+    geometry::{Alignment2D, Insets, Point, Rect},
 };
 
 use crate::ui::model_tt::{
@@ -14,6 +14,12 @@ const ICON_TOP: i16 = 23;
 const TITLE_AREA_START: i16 = 70;
 const MESSAGE_AREA_START: i16 = 116;
 
+// With the diagnostics code the icon makes room for it.
+const QR_TITLE_AREA_START: i16 = 8;
+const QR_MESSAGE_AREA_START: i16 = 38;
+const QR_AREA_START: i16 = 82;
+const QR_BORDER: i16 = 4;
+
 #[cfg(feature = "bootloader")]
 const STYLE: &ResultStyle = &crate::ui::model_tt::theme::bootloader::RESULT_WIPE;
 #[cfg(not(feature = "bootloader"))]
@@ -24,6 +30,7 @@ pub struct ErrorScreen<'a, T> {
     title: Child<Label<T>>,
     message: Child<Label<T>>,
     footer: Child<ResultFooter<'a, T>>,
+    qr: Option<Child<Qr<T>>>,
 }
 
 impl<T: AsRef<str>> ErrorScreen<'_, T> {
@@ -40,8 +47,18 @@ impl<T: AsRef<str>> ErrorScreen<'_, T> {
             title: Child::new(title),
             message: Child::new(message),
             footer: Child::new(footer),
+            qr: None,
         }
     }
+
+    /// Show `diagnostics` as a QR code, so that the error can be reported by
+    /// scanning it. The code is left out if it does not fit.
+    pub fn with_diagnostics(mut self, diagnostics: T) -> Self {
+        self.qr = Qr::new(diagnostics, true)
+            .ok()
+            .map(|qr| Child::new(qr.with_border(QR_BORDER)));
+        self
+    }
 }
 
 impl<T: AsRef<str>> Component for ErrorScreen<'_, T> {
@@ -50,18 +67,31 @@ impl<T: AsRef<str>> Component for ErrorScreen<'_, T> {
     fn place(&mut self, _bounds: Rect) -> Rect {
         self.bg.place(screen());
 
+        let (title_start, message_start, message_end) = if self.qr.is_some() {
+            (QR_TITLE_AREA_START, QR_MESSAGE_AREA_START, QR_AREA_START)
+        } else {
+            (TITLE_AREA_START, MESSAGE_AREA_START, RESULT_FOOTER_START)
+        };
+
         let title_area = Rect::new(
-            Point::new(RESULT_PADDING, TITLE_AREA_START),
-            Point::new(WIDTH - RESULT_PADDING, MESSAGE_AREA_START),
+            Point::new(RESULT_PADDING, title_start),
+            Point::new(WIDTH - RESULT_PADDING, message_start),
         );
         self.title.place(title_area);
 
         let message_area = Rect::new(
-            Point::new(RESULT_PADDING, MESSAGE_AREA_START),
-            Point::new(WIDTH - RESULT_PADDING, RESULT_FOOTER_START),
+            Point::new(RESULT_PADDING, message_start),
+            Point::new(WIDTH - RESULT_PADDING, message_end),
         );
         self.message.place(message_area);
 
+        let qr_area = Rect::new(
+            Point::new(RESULT_PADDING, QR_AREA_START),
+            Point::new(WIDTH - RESULT_PADDING, RESULT_FOOTER_START),
+        )
+        .inset(Insets::bottom(QR_BORDER));
+        self.qr.place(qr_area);
+
         let (_, bottom_area) = ResultFooter::<T>::split_bounds();
         self.footer.place(bottom_area);
 
@@ -75,15 +105,51 @@ impl<T: AsRef<str>> Component for ErrorScreen<'_, T> {
     fn paint(&mut self) {
         self.bg.paint();
 
-        let icon = ICON_WARNING40;
-        icon.draw(
-            Point::new(screen().center().x, ICON_TOP),
-            Alignment2D::TOP_CENTER,
-            WHITE,
-            FATAL_ERROR_COLOR,
-        );
+        if self.qr.is_none() {
+            let icon = ICON_WARNING40;
+            icon.draw(
+                Point::new(screen().center().x, ICON_TOP),
+                Alignment2D::TOP_CENTER,
+                WHITE,
+                FATAL_ERROR_COLOR,
+            );
+        }
         self.title.paint();
         self.message.paint();
+        self.qr.paint();
         self.footer.paint();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    const DIAGNOSTICS: &str = "rsod/1/firmware/2.6.0/src/apps/base.py:123";
+
+    #[test]
+    fn diagnostics_push_the_text_up() {
+        let h = Harness::new(
+            ErrorScreen::new("Failure", "Please reset", "Reboot").with_diagnostics(DIAGNOSTICS),
+        );
+        let error = h.inner();
+        assert!(error.qr.is_some());
+        assert!(error.title.inner().area().y0 == QR_TITLE_AREA_START);
+        assert!(error.message.inner().area().y0 == QR_MESSAGE_AREA_START);
+        assert!(error.message.inner().area().y1 <= QR_AREA_START);
+    }
+
+    #[test]
+    fn too_long_diagnostics_are_left_out() {
+        let long = Box::leak("X".repeat(400).into_boxed_str());
+        let h = Harness::new(
+            ErrorScreen::new("Failure", "Please reset", "Reboot").with_diagnostics(long),
+        );
+        let error = h.inner();
+        assert!(error.qr.is_none());
+        assert!(error.title.inner().area().y0 == TITLE_AREA_START);
+        assert!(error.message.inner().area().y0 == MESSAGE_AREA_START);
+    }
+}
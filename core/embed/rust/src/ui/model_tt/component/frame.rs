@@ -1,17 +1,21 @@
 use super::theme;
 use crate::ui::{
+    coin_icons::CoinIcon,
     component::{
-        base::ComponentExt, label::Label, text::TextStyle, Child, Component, Event, EventCtx,
+        base::ComponentExt, label::Label, text::TextStyle, AttrUpdate, Child, CoinLabel, Component,
+        Event, EventCtx,
     },
     display::Icon,
     geometry::{Alignment, Insets, Offset, Rect},
     model_tt::component::{Button, ButtonMsg, CancelInfoConfirmMsg},
+    translations::TranslatedString,
 };
 
 pub struct Frame<T, U> {
     border: Insets,
     title: Child<Label<U>>,
     subtitle: Option<Child<Label<U>>>,
+    coin: Option<Child<CoinLabel>>,
     button: Option<Child<Button<&'static str>>>,
     button_msg: CancelInfoConfirmMsg,
     content: Child<T>,
@@ -31,6 +35,7 @@ where
         Self {
             title: Child::new(Label::new(title, alignment, style)),
             subtitle: None,
+            coin: None,
             border: theme::borders(),
             button: None,
             button_msg: CancelInfoConfirmMsg::Cancelled,
@@ -64,6 +69,27 @@ where
         self
     }
 
+    /// Icon and name of the coin in front of the title.
+    pub fn with_coin(mut self, icon: &'static CoinIcon) -> Self {
+        self.coin = Some(Child::new(CoinLabel::new(icon, theme::label_subtitle(), 2)));
+        self
+    }
+
+    /// Place the coin label at the left of `header_area`, returning what is
+    /// left for the title and the height of the label.
+    fn place_coin(&mut self, header_area: Rect) -> (Rect, i16) {
+        const COIN_SPACE: i16 = 8;
+
+        match &mut self.coin {
+            Some(coin) => {
+                let coin_area = coin.place(header_area);
+                let title_area = header_area.inset(Insets::left(coin_area.width() + COIN_SPACE));
+                (title_area, coin_area.height())
+            }
+            None => (header_area, 0),
+        }
+    }
+
     fn with_button(mut self, icon: Icon, msg: CancelInfoConfirmMsg) -> Self {
         let touch_area = Insets {
             left: self.border.left * 4,
@@ -128,11 +154,12 @@ where
             let (header_area, button_area) = bounds.split_right(button_side);
             let (button_area, _) = button_area.split_top(button_side);
             b.place(button_area);
+            let (header_area, coin_height) = self.place_coin(header_area);
             let title_area = self.title.place(header_area.outset(TITLE_EXTRA_SPACE));
             let remaining = header_area.inset(Insets::top(title_area.height()));
             let subtitle_area = self.subtitle.place(remaining);
 
-            let title_height = title_area.height() + subtitle_area.height();
+            let title_height = (title_area.height() + subtitle_area.height()).max(coin_height);
             let header_height = title_height.max(button_side);
             if title_height < button_side {
                 self.title
@@ -143,8 +170,9 @@ where
             let content_area = bounds.inset(Insets::top(header_height + TITLE_SPACE));
             self.content.place(content_area);
         } else {
-            let title_area = self.title.place(bounds.outset(TITLE_EXTRA_SPACE));
-            let remaining = bounds.inset(Insets::top(title_area.height()));
+            let (header_area, coin_height) = self.place_coin(bounds);
+            let title_area = self.title.place(header_area.outset(TITLE_EXTRA_SPACE));
+            let remaining = bounds.inset(Insets::top(title_area.height().max(coin_height)));
             let subtitle_area = self.subtitle.place(remaining);
             let remaining = remaining.inset(Insets::top(subtitle_area.height()));
             let content_area = remaining.inset(Insets::top(TITLE_SPACE));
@@ -156,6 +184,7 @@ where
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         self.title.event(ctx, event);
         self.subtitle.event(ctx, event);
+        self.coin.event(ctx, event);
         if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
             return Some(FrameMsg::Button(self.button_msg));
         }
@@ -165,6 +194,7 @@ where
     fn paint(&mut self) {
         self.title.paint();
         self.subtitle.paint();
+        self.coin.paint();
         self.button.paint();
         self.content.paint();
     }
@@ -173,9 +203,17 @@ where
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         self.title.bounds(sink);
         self.subtitle.bounds(sink);
+        self.coin.bounds(sink);
         self.button.bounds(sink);
         self.content.bounds(sink);
     }
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.content.update_attr(ctx, attr)
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        self.content.help()
+    }
 }
 
 #[cfg(feature = "ui_debug")]
@@ -191,8 +229,35 @@ where
         if let Some(subtitle) = &self.subtitle {
             t.child("subtitle", subtitle);
         }
+        if let Some(coin) = &self.coin {
+            t.child("coin", coin);
+        }
         if let Some(button) = &self.button {
             t.child("button", button);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{coin_icons, testing::Harness};
+
+    use super::*;
+
+    fn frame() -> Frame<Label<&'static str>, &'static str> {
+        let content = Label::left_aligned("1 BTC", theme::TEXT_NORMAL);
+        Frame::left_aligned(theme::label_title(), "SEND", content)
+    }
+
+    #[test]
+    fn coin_comes_before_the_title() {
+        let plain = Harness::new(frame());
+        let h = Harness::new(frame().with_coin(coin_icons::find("BTC").unwrap()));
+        let coin = h.inner().coin.as_ref().unwrap().inner().area;
+        let title = h.inner().title.inner().area();
+
+        assert!(coin.x0 == plain.inner().title.inner().area().x0);
+        assert!(title.x0 >= coin.x1);
+        assert!(h.inner().content.inner().area().y0 >= coin.y1);
+    }
+}
@@ -55,7 +55,10 @@ pub struct Homescreen<T> {
 }
 
 pub enum HomescreenMsg {
+    /// Held long enough to lock the device.
     Dismissed,
+    /// Tapped, the user wants to adjust the settings.
+    Settings,
 }
 
 impl<T> Homescreen<T>
@@ -168,6 +171,20 @@ where
 
         false
     }
+
+    /// Whether the screen was tapped, when there is no loader. The delay of the
+    /// loader tells a tap from a longer touch.
+    fn event_tap(&mut self, ctx: &mut EventCtx, event: Event) -> bool {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(_)) => {
+                self.delay = Some(ctx.request_timer(LOADER_DELAY));
+            }
+            Event::Touch(TouchEvent::TouchEnd(_)) => return self.delay.take().is_some(),
+            Event::Timer(token) if Some(token) == self.delay => self.delay = None,
+            _ => {}
+        }
+        false
+    }
 }
 
 impl<T> Component for Homescreen<T>
@@ -185,9 +202,16 @@ where
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         Self::event_usb(self, ctx, event);
         if self.hold_to_lock {
-            Self::event_hold(self, ctx, event).then_some(HomescreenMsg::Dismissed)
+            // A touch ending before the loader appears is a tap, checked before
+            // `event_hold` forgets the delay.
+            let tapped =
+                matches!(event, Event::Touch(TouchEvent::TouchEnd(_))) && self.delay.is_some();
+            if Self::event_hold(self, ctx, event) {
+                return Some(HomescreenMsg::Dismissed);
+            }
+            tapped.then_some(HomescreenMsg::Settings)
         } else {
-            None
+            Self::event_tap(self, ctx, event).then_some(HomescreenMsg::Settings)
         }
     }
 
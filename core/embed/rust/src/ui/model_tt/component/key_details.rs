@@ -0,0 +1,276 @@
+use crate::{
+    error::Error,
+    strutil::StringType,
+    ui::{
+        component::{
+            text::paragraphs::{Paragraph, ParagraphSource, ParagraphVecShort, Paragraphs, VecExt},
+            Component, Event, EventCtx, Paginate, PathText, Qr, Split,
+        },
+        geometry::Rect,
+        theme::ButtonIntent,
+    },
+};
+
+use super::{theme, Button, ButtonMsg, Dialog, DialogMsg, Frame, FrameMsg};
+
+type Summary<T> = Split<Paragraphs<ParagraphVecShort<T>>, Option<PathText<T>>>;
+
+pub enum KeyDetailsMsg {
+    Confirmed,
+    Cancelled,
+}
+
+/// Extended public key or output descriptor, too long to be read at once.
+/// The key is split into monospace chunks over as many pages as needed,
+/// followed by a QR code page and a summary page with the fingerprint, the
+/// account and the path, which is all the user needs to share to identify
+/// the key without sharing the key itself. The summary is confirmed with a
+/// button, any page can be cancelled. Paths the firmware does not know are
+/// marked as unusual.
+pub struct KeyDetails<T> {
+    key_view: Frame<Paragraphs<Paragraph<T>>, T>,
+    qr_code: Frame<Qr<T>, T>,
+    summary: Frame<Dialog<Summary<T>, Button<T>>, T>,
+    key_page_count: usize,
+    current_page: usize,
+}
+
+impl<T> KeyDetails<T>
+where
+    T: StringType + Clone,
+{
+    pub fn new(
+        title: T,
+        key: T,
+        fingerprint: Option<T>,
+        account: Option<T>,
+        path: Option<T>,
+        path_unusual: bool,
+    ) -> Result<Self, Error>
+    where
+        T: From<&'static str>,
+    {
+        let mut para = ParagraphVecShort::new();
+        if let Some(f) = fingerprint {
+            para.add(Paragraph::new(&theme::TEXT_NORMAL, "Fingerprint:".into()));
+            para.add(Paragraph::new(&theme::TEXT_MONO, f));
+        }
+        if let Some(a) = account {
+            para.add(Paragraph::new(&theme::TEXT_NORMAL, "Account:".into()));
+            para.add(Paragraph::new(&theme::TEXT_MONO, a));
+        }
+        let path = path.map(|p| {
+            para.add(Paragraph::new(
+                &theme::TEXT_NORMAL,
+                "Derivation path:".into(),
+            ));
+            let path = PathText::new(p, theme::path_style());
+            if path_unusual {
+                path.with_warning("UNUSUAL".into())
+            } else {
+                path
+            }
+        });
+        // Room for two lines of the path at the bottom of the summary.
+        let path_height = 2 * theme::TEXT_MONO.text_font.line_height();
+        let key_style = theme::get_chunkified_text_style(key.as_ref().len());
+        let result = Self {
+            key_view: Frame::left_aligned(
+                theme::label_title(),
+                title.clone(),
+                Paragraph::new(key_style, key.clone()).into_paragraphs(),
+            )
+            .with_cancel_button()
+            .with_border(theme::borders_horizontal_scroll()),
+            qr_code: Frame::left_aligned(
+                theme::label_title(),
+                title.clone(),
+                Qr::new(key, true)?.with_border(7),
+            )
+            .with_cancel_button()
+            .with_border(theme::borders_horizontal_scroll()),
+            summary: Frame::left_aligned(
+                theme::label_title(),
+                title,
+                Dialog::new(
+                    Split::bottom(path_height, 0, para.into_paragraphs(), path),
+                    Button::with_text("CONFIRM".into()).with_intent(ButtonIntent::Confirm),
+                ),
+            )
+            .with_cancel_button()
+            .with_border(theme::borders_horizontal_scroll()),
+            key_page_count: 1,
+            current_page: 0,
+        };
+        Ok(result)
+    }
+
+    fn switch_key_page(&mut self, page: usize) -> usize {
+        // The parent component that handles paging always requests complete
+        // repaint after page change so we can use a dummy context here.
+        let mut dummy_ctx = EventCtx::new();
+        self.key_view.update_content(&mut dummy_ctx, |p| {
+            let npages = p.page_count();
+            p.change_page(page);
+            npages
+        })
+    }
+}
+
+impl<T> Paginate for KeyDetails<T>
+where
+    T: StringType + Clone,
+{
+    fn page_count(&mut self) -> usize {
+        self.key_page_count.saturating_add(2)
+    }
+
+    fn change_page(&mut self, to_page: usize) {
+        self.current_page = to_page;
+        if to_page < self.key_page_count {
+            self.switch_key_page(to_page);
+        }
+    }
+}
+
+impl<T> Component for KeyDetails<T>
+where
+    T: StringType + Clone,
+{
+    type Msg = KeyDetailsMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.key_view.place(bounds);
+        self.qr_code.place(bounds);
+        self.summary.place(bounds);
+        self.key_page_count = self.switch_key_page(0).max(1);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if self.current_page < self.key_page_count {
+            self.key_view
+                .event(ctx, event)
+                .map(|_| KeyDetailsMsg::Cancelled)
+        } else if self.current_page == self.key_page_count {
+            self.qr_code
+                .event(ctx, event)
+                .map(|_| KeyDetailsMsg::Cancelled)
+        } else {
+            match self.summary.event(ctx, event) {
+                Some(FrameMsg::Content(DialogMsg::Controls(ButtonMsg::Clicked))) => {
+                    Some(KeyDetailsMsg::Confirmed)
+                }
+                Some(FrameMsg::Button(_)) => Some(KeyDetailsMsg::Cancelled),
+                _ => None,
+            }
+        }
+    }
+
+    fn paint(&mut self) {
+        if self.current_page < self.key_page_count {
+            self.key_view.paint()
+        } else if self.current_page == self.key_page_count {
+            self.qr_code.paint()
+        } else {
+            self.summary.paint()
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        if self.current_page < self.key_page_count {
+            self.key_view.bounds(sink)
+        } else if self.current_page == self.key_page_count {
+            self.qr_code.bounds(sink)
+        } else {
+            self.summary.bounds(sink)
+        }
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for KeyDetails<T>
+where
+    T: StringType,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("KeyDetails");
+        if self.current_page < self.key_page_count {
+            t.child("key_view", &self.key_view)
+        } else if self.current_page == self.key_page_count {
+            t.child("qr_code", &self.qr_code)
+        } else {
+            t.child("summary", &self.summary)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        constant::screen,
+        geometry::{Offset, Point},
+        testing::Harness,
+    };
+
+    const XPUB: &str = "xpub6BiVtCpG9fQPxnPmHXG8PhtzQdWC2Su4qWu6XW9tpWFYhxydCLJGrWBJZ5H6qTAHdPQ7pQhtpjiYZVZARo14qHiay2fvrX996oEP42u8wZy";
+
+    fn details(key: &'static str) -> Harness<KeyDetails<&'static str>> {
+        Harness::new(
+            KeyDetails::new(
+                "XPUB",
+                key,
+                Some("5c9e228d"),
+                Some("#1"),
+                Some("m/84h/0h/0h"),
+                false,
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Corner button of the frame.
+    fn cancel_button() -> Point {
+        Point::new(screen().x1 - 28, 28)
+    }
+
+    #[test]
+    fn key_pages_qr_and_summary() {
+        let mut short = details(XPUB);
+        let key_pages = short.inner().key_page_count;
+        assert_eq!(short.mutate(|_, d| d.page_count()), key_pages + 2);
+
+        // A descriptor keeps the QR and the summary after more pages of key.
+        let long: &'static str = Box::leak(XPUB.repeat(4).into_boxed_str());
+        let mut long = details(long);
+        assert!(long.inner().key_page_count > key_pages);
+        assert_eq!(
+            long.mutate(|_, d| d.page_count()),
+            long.inner().key_page_count + 2
+        );
+    }
+
+    #[test]
+    fn summary_is_confirmed() {
+        let mut h = details(XPUB);
+        let summary = h.inner().key_page_count + 1;
+        h.mutate(|_, d| d.change_page(summary));
+        let bottom = screen().bottom_center() - Offset::y(20);
+        assert!(matches!(h.tap(bottom), Some(KeyDetailsMsg::Confirmed)));
+    }
+
+    #[test]
+    fn every_page_is_cancelled() {
+        let mut h = details(XPUB);
+        let pages = h.mutate(|_, d| d.page_count());
+        for page in 0..pages {
+            h.mutate(|_, d| d.change_page(page));
+            assert!(matches!(
+                h.tap(cancel_button()),
+                Some(KeyDetailsMsg::Cancelled)
+            ));
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    trezorhal::bip39,
+    trezorhal::wordlist::Wordlist,
     ui::{
-        component::{text::common::TextBox, Component, Event, EventCtx},
+        component::{
+            text::common::{InputMethod, TextBox, TextBoxFull},
+            Component, Event, EventCtx,
+        },
         display,
         geometry::{Alignment2D, Offset, Rect},
         model_tt::{
@@ -14,12 +17,16 @@ use crate::{
             },
             theme,
         },
+        util::ResultExt,
     },
 };
 
 const MAX_LENGTH: usize = 8;
 
+/// Input of a word of a mnemonic by letters, offering the words of `wordlist`
+/// starting with them. BIP39 unless given another wordlist.
 pub struct Bip39Input {
+    wordlist: Wordlist,
     button: Button<&'static str>,
     // used only to keep track of suggestion text color
     button_suggestion: Button<&'static str>,
@@ -42,8 +49,12 @@ impl MnemonicInput for Bip39Input {
         // Currently pending key is always enabled.
         let key_is_pending = self.multi_tap.pending_key() == Some(key);
         // Keys that contain letters from the completion mask are enabled as well.
-        let key_matches_mask =
-            bip39::word_completion_mask(self.textbox.content()) & Self::key_mask(key) != 0;
+        let key_matches_mask = self
+            .wordlist
+            .filter_prefix(self.textbox.content())
+            .letter_mask()
+            & Self::key_mask(key)
+            != 0;
         key_is_pending || key_matches_mask
     }
 
@@ -52,7 +63,9 @@ impl MnemonicInput for Bip39Input {
     /// character.
     fn on_key_click(&mut self, ctx: &mut EventCtx, key: usize) {
         let edit = self.multi_tap.click_key(ctx, key, Self::keys()[key]);
-        self.textbox.apply(ctx, edit);
+        self.textbox
+            .apply(ctx, edit)
+            .assert_if_debugging_ui("TextBox is full");
         self.complete_word_from_dictionary(ctx);
     }
 
@@ -81,6 +94,30 @@ impl MnemonicInput for Bip39Input {
     }
 }
 
+impl InputMethod for Bip39Input {
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        self.multi_tap.clear_pending_state(ctx);
+        let result = self.textbox.append(ctx, ch);
+        self.complete_word_from_dictionary(ctx);
+        result
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.on_backspace_click(ctx);
+    }
+
+    fn completions(&self, sink: &mut dyn FnMut(&'static str)) {
+        self.wordlist
+            .filter_prefix(self.textbox.content())
+            .iter()
+            .for_each(sink);
+    }
+}
+
 impl Component for Bip39Input {
     type Msg = MnemonicInputMsg;
 
@@ -161,7 +198,12 @@ impl Component for Bip39Input {
 
 impl Bip39Input {
     pub fn new() -> Self {
+        Self::with_wordlist(Wordlist::bip39())
+    }
+
+    pub fn with_wordlist(wordlist: Wordlist) -> Self {
         Self {
+            wordlist,
             button: Button::empty(),
             textbox: TextBox::empty(),
             multi_tap: MultiTapKeyboard::new(),
@@ -195,7 +237,9 @@ impl Bip39Input {
                 Some(MnemonicInputMsg::Confirmed)
             } else {
                 // Auto-complete button.
-                self.textbox.replace(ctx, word);
+                self.textbox
+                    .replace(ctx, word)
+                    .assert_if_debugging_ui("TextBox is full");
                 self.complete_word_from_dictionary(ctx);
                 Some(MnemonicInputMsg::Completed)
             };
@@ -216,8 +260,15 @@ impl Bip39Input {
     }
 
     fn complete_word_from_dictionary(&mut self, ctx: &mut EventCtx) {
-        self.options_num = bip39::options_num(self.textbox.content());
-        self.suggested_word = bip39::complete_word(self.textbox.content());
+        let prefix = self.textbox.content();
+        if prefix.is_empty() {
+            self.options_num = None;
+            self.suggested_word = None;
+        } else {
+            let words = self.wordlist.filter_prefix(prefix);
+            self.options_num = Some(words.len());
+            self.suggested_word = words.get(0);
+        }
 
         // Change the style of the button depending on the completed word.
         if let (Some(word), Some(num)) = (self.suggested_word, self.options_num) {
@@ -259,3 +310,45 @@ impl crate::trace::Trace for Bip39Input {
         t.child("textbox", &self.textbox);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    fn typed(input: Bip39Input, prefix: &str) -> Harness<Bip39Input> {
+        let mut h = Harness::new(input);
+        h.mutate(|ctx, input| {
+            for ch in prefix.chars() {
+                input.append(ctx, ch).unwrap();
+            }
+        });
+        h
+    }
+
+    #[test]
+    fn keys_lead_to_the_words() {
+        let h = typed(Bip39Input::new(), "str");
+        assert_eq!(h.inner().options_num, Some(5));
+        assert_eq!(h.inner().mnemonic(), Some("strategy"));
+        // "abc" for strategy, "stu" for struggle, but no word goes on with "j".
+        assert!(h.inner().can_key_press_lead_to_a_valid_word(0));
+        assert!(h.inner().can_key_press_lead_to_a_valid_word(6));
+        assert!(!h.inner().can_key_press_lead_to_a_valid_word(3));
+        assert!(!h.inner().can_key_press_lead_to_a_valid_word(8));
+    }
+
+    #[test]
+    fn words_of_another_list() {
+        let h = typed(Bip39Input::with_wordlist(Wordlist::slip39()), "aca");
+        assert_eq!(h.inner().mnemonic(), Some("academic"));
+        let h = typed(Bip39Input::new(), "aca");
+        assert_eq!(h.inner().mnemonic(), Some("academy"));
+
+        let mut h = typed(Bip39Input::new(), "q");
+        h.mutate(|ctx, input| input.delete_last(ctx));
+        assert!(h.inner().mnemonic().is_none());
+        assert!(h.inner().options_num.is_none());
+    }
+}
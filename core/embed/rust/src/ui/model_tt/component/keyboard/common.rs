@@ -1,8 +1,9 @@
 use crate::{
-    time::Duration,
+    time::{Duration, Instant},
     ui::{
         component::{text::common::TextEdit, Event, EventCtx, TimerToken},
         display::{self, Color, Font},
+        event::TouchEvent,
         geometry::{Offset, Point, Rect},
     },
 };
@@ -127,3 +128,92 @@ pub fn paint_pending_marker(text_baseline: Point, text: &str, font: Font, color:
         display::rect_fill(marker_rect, color);
     }
 }
+
+pub enum DeleteGesture {
+    /// Short swipe to the left, delete the last character.
+    DeleteLast,
+    /// Long swipe to the left, delete everything.
+    ClearAll,
+}
+
+/// Recognizes fast swipes to the left over the input line of a keyboard.
+/// Slow drags are ignored, so that just touching the input line to reveal it
+/// does not edit it.
+pub struct SwipeToDelete {
+    area: Rect,
+    origin: Option<(Point, Instant)>,
+}
+
+impl SwipeToDelete {
+    /// Horizontal distance of a swipe deleting one character.
+    const MIN_DISTANCE: i16 = 40;
+    /// Horizontal distance of a swipe deleting everything.
+    const CLEAR_DISTANCE: i16 = 140;
+    /// Slowest swipe, in pixels per second.
+    const MIN_VELOCITY: i32 = 300;
+
+    pub fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            origin: None,
+        }
+    }
+
+    pub fn place(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    pub fn event(&mut self, event: Event) -> Option<DeleteGesture> {
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) => {
+                self.origin = self.area.contains(pos).then(|| (pos, Instant::now()));
+                None
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) => {
+                let (origin, start) = self.origin.take()?;
+                let millis = Instant::now().saturating_duration_since(start).to_millis();
+                Self::classify(pos - origin, millis)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gesture of a touch moving by `ofs` in `millis` milliseconds.
+    fn classify(ofs: Offset, millis: u32) -> Option<DeleteGesture> {
+        let distance = -ofs.x;
+        if distance < Self::MIN_DISTANCE || distance <= ofs.y.abs() {
+            return None;
+        }
+        let velocity = distance as i32 * 1000 / (millis.max(1) as i32);
+        if velocity < Self::MIN_VELOCITY {
+            None
+        } else if distance >= Self::CLEAR_DISTANCE {
+            Some(DeleteGesture::ClearAll)
+        } else {
+            Some(DeleteGesture::DeleteLast)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swipe_to_delete() {
+        let gesture = |x, y, millis| SwipeToDelete::classify(Offset::new(x, y), millis);
+        assert!(matches!(
+            gesture(-60, 5, 100),
+            Some(DeleteGesture::DeleteLast)
+        ));
+        assert!(matches!(
+            gesture(-160, 0, 200),
+            Some(DeleteGesture::ClearAll)
+        ));
+        // Too short, too slow, to the right, mostly vertical.
+        assert!(gesture(-20, 0, 50).is_none());
+        assert!(gesture(-60, 0, 1000).is_none());
+        assert!(gesture(60, 0, 100).is_none());
+        assert!(gesture(-50, 70, 100).is_none());
+    }
+}
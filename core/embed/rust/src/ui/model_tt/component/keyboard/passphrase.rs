@@ -1,16 +1,26 @@
-use crate::ui::{
-    component::{
-        base::ComponentExt, text::common::TextBox, Child, Component, Event, EventCtx, Never,
+use crate::{
+    strutil::SecretString,
+    ui::{
+        carry,
+        component::{
+            base::ComponentExt,
+            text::common::{InputMethod, TextBox, TextBoxFull, MAX_PASSPHRASE_LENGTH},
+            Child, Component, Event, EventCtx, Never,
+        },
+        context::settings,
+        display,
+        geometry::{Grid, Insets, Offset, Rect},
+        model_tt::component::{
+            button::{Button, ButtonContent, ButtonMsg},
+            keyboard::common::{
+                paint_pending_marker, DeleteGesture, MultiTapKeyboard, SwipeToDelete,
+            },
+            swipe::{Swipe, SwipeDirection},
+            theme, ScrollBar,
+        },
+        translations::TranslatedString,
+        util::{long_line_content_with_ellipsis, ResultExt},
     },
-    display,
-    geometry::{Grid, Offset, Rect},
-    model_tt::component::{
-        button::{Button, ButtonContent, ButtonMsg},
-        keyboard::common::{paint_pending_marker, MultiTapKeyboard},
-        swipe::{Swipe, SwipeDirection},
-        theme, ScrollBar,
-    },
-    util::long_line_content_with_ellipsis,
 };
 
 pub enum PassphraseKeyboardMsg {
@@ -20,12 +30,15 @@ pub enum PassphraseKeyboardMsg {
 
 pub struct PassphraseKeyboard {
     page_swipe: Swipe,
+    swipe_delete: SwipeToDelete,
     input: Child<Input>,
     back: Child<Button<&'static str>>,
     confirm: Child<Button<&'static str>>,
     keys: [Child<Button<&'static str>>; KEY_COUNT],
     scrollbar: ScrollBar,
     fade: bool,
+    /// Put the confirmed passphrase into `carry` for the next screen.
+    carry_out: bool,
 }
 
 const STARTING_PAGE: usize = 1;
@@ -39,14 +52,44 @@ const KEYBOARD: [[&str; KEY_COUNT]; PAGE_COUNT] = [
     ["_<>", ".:@", "/|\\", "!()", "+%&", "-[]", "?{}", ",'`", ";\"~", "$^="],
     ];
 
-const MAX_LENGTH: usize = 50;
+/// Number of letters on each of the letter keys.
+const LETTER_KEY_SIZES: [usize; 8] = [3, 3, 3, 3, 3, 4, 3, 4];
+
+/// Texts of the keys on `page`, with the letters in the order of the keyboard
+/// layout selected by the user.
+fn key_texts(page: usize) -> [&'static str; KEY_COUNT] {
+    let letters = match page {
+        1 => settings().keyboard_layout.letters(false),
+        2 => settings().keyboard_layout.letters(true),
+        _ => return KEYBOARD[page],
+    };
+    let mut texts = KEYBOARD[page];
+    let mut start = 0;
+    for (text, len) in texts[1..=LETTER_KEY_SIZES.len()]
+        .iter_mut()
+        .zip(LETTER_KEY_SIZES)
+    {
+        *text = &letters[start..start + len];
+        start += len;
+    }
+    texts
+}
+
 const INPUT_AREA_HEIGHT: i16 = ScrollBar::DOT_SIZE + 9;
 
 impl PassphraseKeyboard {
     pub fn new() -> Self {
+        Self::with_prefill(SecretString::new())
+    }
+
+    /// Keyboard with `passphrase` already entered, e.g. the one taken from
+    /// `carry`.
+    pub fn with_prefill(passphrase: SecretString<MAX_PASSPHRASE_LENGTH>) -> Self {
+        let is_empty = passphrase.is_empty();
         Self {
             page_swipe: Swipe::horizontal(),
-            input: Input::new().into_child(),
+            swipe_delete: SwipeToDelete::new(),
+            input: Input::new(passphrase).into_child(),
             confirm: Button::with_icon(theme::ICON_CONFIRM)
                 .styled(theme::button_confirm())
                 .into_child(),
@@ -56,23 +99,41 @@ impl PassphraseKeyboard {
                 Offset::new(30, 12),
             )
             .styled(theme::button_reset())
-            .initially_enabled(false)
+            .initially_enabled(!is_empty)
             .with_long_press(theme::ERASE_HOLD_DURATION)
             .into_child(),
-            keys: KEYBOARD[STARTING_PAGE].map(|text| {
+            keys: key_texts(STARTING_PAGE).map(|text| {
                 Child::new(Button::new(Self::key_content(text)).styled(theme::button_pin()))
             }),
             scrollbar: ScrollBar::horizontal(),
             fade: false,
+            carry_out: false,
         }
     }
 
+    /// Keep the confirmed passphrase in `carry`, so that the next screen of
+    /// the flow can be prefilled with it.
+    pub fn with_carry_out(mut self) -> Self {
+        self.carry_out = true;
+        self
+    }
+
+    /// Accept at most `max_len` bytes, e.g. for values shorter than a
+    /// passphrase entered with the same keyboard.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        let mut input = self.input.into_inner();
+        input.textbox = input.textbox.with_max_len(max_len);
+        self.input = input.into_child();
+        self
+    }
+
     fn key_text(content: &ButtonContent<&'static str>) -> &'static str {
         match content {
             ButtonContent::Text(text) => text,
             ButtonContent::Icon(_) => " ",
             ButtonContent::IconAndText(_) => " ",
             ButtonContent::Empty => "",
+            ButtonContent::Translated(_) => "",
             ButtonContent::IconBlend(_, _, _) => "",
         }
     }
@@ -106,8 +167,7 @@ impl PassphraseKeyboard {
     }
 
     fn replace_button_content(&mut self, ctx: &mut EventCtx, page: usize) {
-        for (i, btn) in self.keys.iter_mut().enumerate() {
-            let text = KEYBOARD[page][i];
+        for (btn, text) in self.keys.iter_mut().zip(key_texts(page)) {
             let content = Self::key_content(text);
             btn.mutate(ctx, |ctx, b| b.set_content(ctx, content));
             btn.request_complete_repaint(ctx);
@@ -172,6 +232,29 @@ impl PassphraseKeyboard {
     }
 }
 
+impl InputMethod for PassphraseKeyboard {
+    fn content(&self) -> &str {
+        self.passphrase()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        let result = self.input.mutate(ctx, |ctx, i| {
+            i.multi_tap.clear_pending_state(ctx);
+            i.textbox.append(ctx, ch)
+        });
+        self.after_edit(ctx);
+        result
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.input.mutate(ctx, |ctx, i| {
+            i.multi_tap.clear_pending_state(ctx);
+            i.textbox.delete_last(ctx);
+        });
+        self.after_edit(ctx);
+    }
+}
+
 impl Component for PassphraseKeyboard {
     type Msg = PassphraseKeyboardMsg;
 
@@ -188,7 +271,10 @@ impl Component for PassphraseKeyboard {
         let confirm_btn_area = key_grid.cell(11);
         let back_btn_area = key_grid.cell(9);
 
-        self.page_swipe.place(bounds);
+        // Swipes over the input line edit it instead of changing the page.
+        self.page_swipe
+            .place(bounds.inset(Insets::top(input_area.height())));
+        self.swipe_delete.place(input_area);
         self.input.place(input_area);
         self.confirm.place(confirm_btn_area);
         self.back.place(back_btn_area);
@@ -219,6 +305,17 @@ impl Component for PassphraseKeyboard {
                 .mutate(ctx, |ctx, i| i.multi_tap.clear_pending_state(ctx));
             return None;
         }
+        if let Some(gesture) = self.swipe_delete.event(event) {
+            self.input.mutate(ctx, |ctx, i| {
+                i.multi_tap.clear_pending_state(ctx);
+                match gesture {
+                    DeleteGesture::DeleteLast => i.textbox.delete_last(ctx),
+                    DeleteGesture::ClearAll => i.textbox.clear(ctx),
+                }
+            });
+            self.after_edit(ctx);
+            return None;
+        }
         if let Some(swipe) = self.page_swipe.event(ctx, event) {
             // We have detected a horizontal swipe. Change the keyboard page.
             self.on_page_swipe(ctx, swipe);
@@ -226,6 +323,9 @@ impl Component for PassphraseKeyboard {
         }
         if let Some(ButtonMsg::Clicked) = self.confirm.event(ctx, event) {
             // Confirm button was clicked, we're done.
+            if self.carry_out {
+                carry::put(self.passphrase());
+            }
             return Some(PassphraseKeyboardMsg::Confirmed);
         }
 
@@ -271,7 +371,9 @@ impl Component for PassphraseKeyboard {
                 let text = Self::key_text(btn.inner().content());
                 self.input.mutate(ctx, |ctx, i| {
                     let edit = i.multi_tap.click_key(ctx, key, text);
-                    i.textbox.apply(ctx, edit);
+                    i.textbox
+                        .apply(ctx, edit)
+                        .assert_if_debugging_ui("TextBox is full");
                 });
                 self.after_edit(ctx);
                 return None;
@@ -305,19 +407,23 @@ impl Component for PassphraseKeyboard {
             btn.bounds(sink)
         }
     }
+
+    fn help(&self) -> Option<TranslatedString> {
+        Some(TranslatedString::HelpPassphrase)
+    }
 }
 
 struct Input {
     area: Rect,
-    textbox: TextBox<MAX_LENGTH>,
+    textbox: TextBox<MAX_PASSPHRASE_LENGTH>,
     multi_tap: MultiTapKeyboard,
 }
 
 impl Input {
-    fn new() -> Self {
+    fn new(passphrase: SecretString<MAX_PASSPHRASE_LENGTH>) -> Self {
         Self {
             area: Rect::zero(),
-            textbox: TextBox::empty(),
+            textbox: TextBox::new(passphrase),
             multi_tap: MultiTapKeyboard::new(),
         }
     }
@@ -388,3 +494,29 @@ impl crate::trace::Trace for PassphraseKeyboard {
         t.string("passphrase", self.passphrase());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    fn tap_key(h: &mut Harness<PassphraseKeyboard>, key: usize) {
+        let center = h.inner().keys[key].inner().area().center();
+        assert!(h.tap(center).is_none());
+    }
+
+    #[test]
+    fn keys_stop_at_the_limit() {
+        let mut h = Harness::new(PassphraseKeyboard::new().with_max_len(2));
+        tap_key(&mut h, 1);
+        tap_key(&mut h, 2);
+        assert_eq!(h.inner().passphrase(), "ad");
+
+        // Only the pending key still cycles through its letters.
+        tap_key(&mut h, 3);
+        assert_eq!(h.inner().passphrase(), "ad");
+        tap_key(&mut h, 2);
+        assert_eq!(h.inner().passphrase(), "ae");
+    }
+}
@@ -2,20 +2,28 @@ use core::mem;
 use heapless::String;
 
 use crate::{
+    strutil::SecretString,
     time::Duration,
-    trezorhal::random,
     ui::{
         component::{
-            base::ComponentExt, text::TextStyle, Child, Component, Event, EventCtx, Label, Maybe,
-            Never, Pad, TimerToken,
+            base::ComponentExt,
+            text::{
+                common::{Holds, InputMethod, TextBoxFull, MAX_PIN_LENGTH},
+                TextStyle,
+            },
+            AttrUpdate, Child, Component, Event, EventCtx, Label, Maybe, Never, Pad, TimerKind,
         },
         display::{self, Font},
         event::TouchEvent,
-        geometry::{Alignment2D, Grid, Insets, Offset, Rect},
+        geometry::{Alignment2D, Grid, Insets, LinearSplit, Offset, Rect, Slot},
+        message_format::format_translated,
         model_tt::component::{
             button::{Button, ButtonContent, ButtonMsg, ButtonMsg::Clicked},
+            keyboard::common::{DeleteGesture, SwipeToDelete},
             theme,
         },
+        random::{self, PinShuffle},
+        translations::TranslatedString,
     },
 };
 
@@ -24,10 +32,13 @@ pub enum PinKeyboardMsg {
     Cancelled,
 }
 
-const MAX_LENGTH: usize = 50;
 const MAX_VISIBLE_DOTS: usize = 14;
 const MAX_VISIBLE_DIGITS: usize = 16;
 const DIGIT_COUNT: usize = 10; // 0..10
+const DIGITS: [&str; DIGIT_COUNT] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// Longest text of the remaining attempts.
+const ATTEMPTS_LEN: usize = 24;
 
 const HEADER_PADDING_SIDE: i16 = 5;
 const HEADER_PADDING_BOTTOM: i16 = 12;
@@ -43,14 +54,18 @@ pub struct PinKeyboard<T> {
     allow_cancel: bool,
     major_prompt: Child<Label<T>>,
     minor_prompt: Child<Label<T>>,
+    /// Remaining attempts, shown instead of the minor prompt once the firmware
+    /// updates them.
+    attempts: Option<Child<Label<String<ATTEMPTS_LEN>>>>,
     major_warning: Option<Child<Label<T>>>,
     textbox: Child<PinDots>,
     textbox_pad: Pad,
+    swipe_delete: SwipeToDelete,
     erase_btn: Child<Maybe<Button<&'static str>>>,
     cancel_btn: Child<Maybe<Button<&'static str>>>,
     confirm_btn: Child<Button<&'static str>>,
     digit_btns: [Child<Button<&'static str>>; DIGIT_COUNT],
-    warning_timer: Option<TimerToken>,
+    shuffle: PinShuffle,
 }
 
 impl<T> PinKeyboard<T>
@@ -87,32 +102,54 @@ where
             major_prompt: Label::left_aligned(major_prompt, theme::label_keyboard()).into_child(),
             minor_prompt: Label::right_aligned(minor_prompt, theme::label_keyboard_minor())
                 .into_child(),
+            attempts: None,
             major_warning: major_warning.map(|text| {
                 Label::left_aligned(text, theme::label_keyboard_warning()).into_child()
             }),
             textbox: PinDots::new(theme::label_default()).into_child(),
             textbox_pad: Pad::with_background(theme::label_default().background_color),
+            swipe_delete: SwipeToDelete::new(),
             erase_btn,
             cancel_btn,
             confirm_btn: Button::with_icon(theme::ICON_CONFIRM)
                 .styled(theme::button_confirm())
                 .initially_enabled(false)
                 .into_child(),
-            digit_btns: Self::generate_digit_buttons(),
-            warning_timer: None,
+            digit_btns: Self::generate_digit_buttons(PinShuffle::PerSession),
+            shuffle: PinShuffle::PerSession,
         }
     }
 
-    fn generate_digit_buttons() -> [Child<Button<&'static str>>; DIGIT_COUNT] {
-        // Generate a random sequence of digits from 0 to 9.
-        let mut digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
-        random::shuffle(&mut digits);
+    /// Randomize the keypad according to `shuffle`, by default it is shuffled
+    /// once per session.
+    pub fn with_shuffle(mut self, shuffle: PinShuffle) -> Self {
+        self.shuffle = shuffle;
+        self.digit_btns = Self::generate_digit_buttons(shuffle);
+        self
+    }
+
+    fn generate_digit_buttons(shuffle: PinShuffle) -> [Child<Button<&'static str>>; DIGIT_COUNT] {
+        // Generate a sequence of digits from 0 to 9, possibly random.
+        let mut digits = DIGITS;
+        if shuffle.on_start() {
+            random::shuffle(&mut digits);
+        }
         digits
             .map(Button::with_text)
             .map(|b| b.styled(theme::button_pin()))
             .map(Child::new)
     }
 
+    fn reshuffle_digit_buttons(&mut self, ctx: &mut EventCtx) {
+        let mut digits = DIGITS;
+        random::shuffle(&mut digits);
+        for (btn, digit) in self.digit_btns.iter_mut().zip(digits) {
+            btn.mutate(ctx, |ctx, btn| {
+                btn.set_content(ctx, ButtonContent::Text(digit));
+            });
+        }
+    }
+
     fn pin_modified(&mut self, ctx: &mut EventCtx) {
         let is_full = self.textbox.inner().is_full();
         let is_empty = self.textbox.inner().is_empty();
@@ -123,6 +160,7 @@ where
         if is_empty {
             self.major_prompt.request_complete_repaint(ctx);
             self.minor_prompt.request_complete_repaint(ctx);
+            self.attempts.request_complete_repaint(ctx);
             self.major_warning.request_complete_repaint(ctx);
         }
 
@@ -142,11 +180,41 @@ where
             .mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_empty));
     }
 
+    fn push_digit(&mut self, ctx: &mut EventCtx, text: &str) {
+        self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
+        if self.shuffle.on_keypress() {
+            self.reshuffle_digit_buttons(ctx);
+        }
+        self.pin_modified(ctx);
+    }
+
     pub fn pin(&self) -> &str {
         self.textbox.inner().pin()
     }
 }
 
+impl<T> InputMethod for PinKeyboard<T>
+where
+    T: AsRef<str>,
+{
+    fn content(&self) -> &str {
+        self.pin()
+    }
+
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        if self.textbox.inner().is_full() {
+            return Err(TextBoxFull);
+        }
+        self.push_digit(ctx, ch.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.textbox.mutate(ctx, |ctx, t| t.pop(ctx));
+        self.pin_modified(ctx);
+    }
+}
+
 impl<T> Component for PinKeyboard<T>
 where
     T: AsRef<str>,
@@ -161,9 +229,11 @@ where
             ..theme::borders()
         };
         // Prompts and PIN dots display.
-        let (header, keypad) = bounds
-            .inset(borders_no_top)
-            .split_bottom(4 * theme::PIN_BUTTON_HEIGHT + 3 * theme::BUTTON_SPACING);
+        let [header, keypad] = LinearSplit::vertical([
+            Slot::weighted(1),
+            Slot::fixed(4 * theme::PIN_BUTTON_HEIGHT + 3 * theme::BUTTON_SPACING),
+        ])
+        .split(bounds.inset(borders_no_top));
         let prompt = header.inset(HEADER_PADDING);
         // the inset -3 is a workaround for long text in "re-enter wipe code"
         let major_area = prompt.translate(Self::MAJOR_OFF).inset(Insets::right(-3));
@@ -175,8 +245,10 @@ where
         // Prompts and PIN dots display.
         self.textbox_pad.place(header);
         self.textbox.place(header);
+        self.swipe_delete.place(header);
         self.major_prompt.place(major_area);
         self.minor_prompt.place(minor_area);
+        self.attempts.as_mut().map(|c| c.place(minor_area));
         self.major_warning.as_mut().map(|c| c.place(major_area));
 
         // Control buttons.
@@ -201,22 +273,30 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        match event {
-            // Set up timer to switch off warning prompt.
-            Event::Attach if self.major_warning.is_some() => {
-                self.warning_timer = Some(ctx.request_timer(Duration::from_secs(2)));
-            }
-            // Hide warning, show major prompt.
-            Event::Timer(token) if Some(token) == self.warning_timer => {
-                self.major_warning = None;
-                self.textbox_pad.clear();
-                self.minor_prompt.request_complete_repaint(ctx);
-                ctx.request_paint();
-            }
-            _ => {}
+        // Set up timer to switch off warning prompt.
+        if matches!(event, Event::Attach) && self.major_warning.is_some() {
+            let timeout = ctx.accessibility().timeout(Duration::from_secs(2));
+            ctx.start_timer(TimerKind::PinWarning, timeout);
+        }
+        // Hide warning, show major prompt.
+        if ctx.timer_fired(TimerKind::PinWarning, event) {
+            self.major_warning = None;
+            self.textbox_pad.clear();
+            self.minor_prompt.request_complete_repaint(ctx);
+            ctx.request_paint();
         }
 
         self.textbox.event(ctx, event);
+        if let Some(gesture) = self.swipe_delete.event(event) {
+            if !self.textbox.inner().is_empty() {
+                match gesture {
+                    DeleteGesture::DeleteLast => self.textbox.mutate(ctx, |ctx, t| t.pop(ctx)),
+                    DeleteGesture::ClearAll => self.textbox.mutate(ctx, |ctx, t| t.clear(ctx)),
+                }
+                self.pin_modified(ctx);
+                return None;
+            }
+        }
         if let Some(Clicked) = self.confirm_btn.event(ctx, event) {
             return Some(PinKeyboardMsg::Confirmed);
         }
@@ -236,15 +316,18 @@ where
             }
             _ => {}
         }
+        let mut pressed = None;
         for btn in &mut self.digit_btns {
             if let Some(Clicked) = btn.event(ctx, event) {
                 if let ButtonContent::Text(text) = btn.inner().content() {
-                    self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
-                    self.pin_modified(ctx);
-                    return None;
+                    pressed = Some(*text);
+                    break;
                 }
             }
         }
+        if let Some(text) = pressed {
+            self.push_digit(ctx, text);
+        }
         None
     }
 
@@ -257,7 +340,11 @@ where
             } else {
                 self.major_prompt.paint();
             }
-            self.minor_prompt.paint();
+            if let Some(ref mut a) = self.attempts {
+                a.paint();
+            } else {
+                self.minor_prompt.paint();
+            }
             self.cancel_btn.paint();
         } else {
             self.textbox.paint();
@@ -280,13 +367,35 @@ where
             b.bounds(sink)
         }
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Attempts(attempts) => {
+                let text = format_translated(
+                    TranslatedString::TextAttemptsLeft,
+                    &[i64::from(attempts).into()],
+                );
+                self.attempts =
+                    Some(Label::right_aligned(text, theme::label_keyboard_minor()).into_child());
+                self.textbox_pad.clear();
+                ctx.request_place();
+                ctx.request_paint();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn help(&self) -> Option<TranslatedString> {
+        Some(TranslatedString::HelpPin)
+    }
 }
 
 struct PinDots {
     area: Rect,
     pad: Pad,
     style: TextStyle,
-    digits: String<MAX_LENGTH>,
+    digits: SecretString<MAX_PIN_LENGTH>,
     display_digits: bool,
 }
 
@@ -296,11 +405,13 @@ impl PinDots {
     const TWITCH: i16 = 4;
 
     fn new(style: TextStyle) -> Self {
+        // Visible digits are the last ones of the PIN kept in the buffer.
+        let () = Holds::<MAX_PIN_LENGTH, MAX_VISIBLE_DIGITS>::OK;
         Self {
             area: Rect::zero(),
             pad: Pad::with_background(style.background_color),
             style,
-            digits: String::new(),
+            digits: SecretString::new(),
             display_digits: false,
         }
     }
@@ -468,7 +579,7 @@ where
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.component("PinKeyboard");
         // So that debuglink knows the locations of the buttons
-        let mut digits_order: String<10> = String::new();
+        let mut digits_order: heapless::String<10> = heapless::String::new();
         for btn in self.digit_btns.iter() {
             let btn_content = btn.inner().content();
             if let ButtonContent::Text(text) = btn_content {
@@ -480,3 +591,28 @@ where
         t.bool("display_digits", self.textbox.inner().display_digits);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::testing::Harness;
+
+    fn attempts(keyboard: &PinKeyboard<&str>) -> Option<&str> {
+        keyboard
+            .attempts
+            .as_ref()
+            .map(|a| a.inner().text().as_str())
+    }
+
+    #[test]
+    fn attempts_replace_minor_prompt() {
+        let mut keyboard = Harness::new(PinKeyboard::new("Enter PIN", "", None, true));
+        assert_eq!(attempts(keyboard.inner()), None);
+        assert!(keyboard.update_attr(AttrUpdate::Attempts(3)));
+        assert_eq!(attempts(keyboard.inner()), Some("3 tries left"));
+        assert!(keyboard.paint_requested());
+        assert!(keyboard.update_attr(AttrUpdate::Attempts(1)));
+        assert_eq!(attempts(keyboard.inner()), Some("Last attempt"));
+        assert!(!keyboard.update_attr(AttrUpdate::Progress(500)));
+    }
+}
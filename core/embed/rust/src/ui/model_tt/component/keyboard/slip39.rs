@@ -6,7 +6,7 @@ use crate::{
     trezorhal::slip39,
     ui::{
         component::{
-            text::common::{TextBox, TextEdit},
+            text::common::{InputMethod, TextBox, TextBoxFull, TextEdit},
             Component, Event, EventCtx,
         },
         display,
@@ -62,7 +62,9 @@ impl MnemonicInput for Slip39Input {
         if let TextEdit::Append(_) = edit {
             // This key press wasn't just a pending key rotation, so let's push the key
             // digit to the buffer.
-            self.textbox.append(ctx, Self::key_digit(key));
+            self.textbox
+                .append(ctx, Self::key_digit(key))
+                .assert_if_debugging_ui("TextBox is full");
         } else {
             // Ignore the pending char rotation. We use the pending key to paint
             // the last character, but the mnemonic word computation depends
@@ -98,6 +100,35 @@ impl MnemonicInput for Slip39Input {
     }
 }
 
+/// The content is the sequence of the pressed keys, e.g. `"46"` for `"ho"`.
+impl InputMethod for Slip39Input {
+    fn content(&self) -> &str {
+        self.textbox.content()
+    }
+
+    /// Append the digit of the key containing `ch`.
+    fn append(&mut self, ctx: &mut EventCtx, ch: char) -> Result<(), TextBoxFull> {
+        let key = Self::keys()
+            .iter()
+            .position(|key| key.contains(ch))
+            .ok_or(TextBoxFull)?;
+        self.multi_tap.clear_pending_state(ctx);
+        let result = self.textbox.append(ctx, Self::key_digit(key));
+        self.complete_word_from_dictionary(ctx);
+        result
+    }
+
+    fn delete_last(&mut self, ctx: &mut EventCtx) {
+        self.on_backspace_click(ctx);
+    }
+
+    fn completions(&self, sink: &mut dyn FnMut(&'static str)) {
+        if let Some(word) = self.final_word {
+            sink(word);
+        }
+    }
+}
+
 impl Component for Slip39Input {
     type Msg = MnemonicInputMsg;
 
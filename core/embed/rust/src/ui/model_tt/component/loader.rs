@@ -26,6 +26,9 @@ enum State {
     Shrinking(Animation<u16>),
 }
 
+/// Feedback of a hold-to-confirm gesture, growing while the button is held and
+/// shrinking back when it is let go early. It shows what the user does, not
+/// the progress of an operation, see `Progress` for that.
 pub struct Loader {
     pub pad: Pad,
     state: State,
@@ -71,7 +74,7 @@ impl Loader {
         let mut anim = Animation::new(
             display::LOADER_MIN,
             display::LOADER_MAX,
-            self.growing_duration,
+            ctx.accessibility().hold(self.growing_duration),
             now,
         );
         if let State::Shrinking(shrinking) = &self.state {
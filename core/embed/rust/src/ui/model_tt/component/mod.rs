@@ -10,15 +10,22 @@ mod error;
 mod frame;
 #[cfg(feature = "micropython")]
 mod homescreen;
+mod key_details;
 mod keyboard;
 mod loader;
 mod number_input;
 mod page;
+mod pin_lockout;
 mod progress;
 mod result;
 mod scroll;
+mod settings_menu;
+mod share_groups;
 mod simple_page;
+mod status_screen;
+mod streamed_confirm;
 mod swipe;
+mod terms_page;
 mod welcome_screen;
 
 pub use address_details::AddressDetails;
@@ -33,6 +40,7 @@ pub use fido::{FidoConfirm, FidoMsg};
 pub use frame::{Frame, FrameMsg};
 #[cfg(feature = "micropython")]
 pub use homescreen::{check_homescreen_format, Homescreen, HomescreenMsg, Lockscreen};
+pub use key_details::{KeyDetails, KeyDetailsMsg};
 pub use keyboard::{
     bip39::Bip39Input,
     mnemonic::{MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg},
@@ -42,13 +50,19 @@ pub use keyboard::{
     word_count::{SelectWordCount, SelectWordCountMsg},
 };
 pub use loader::{Loader, LoaderMsg, LoaderStyle, LoaderStyleSheet};
-pub use number_input::{NumberInputDialog, NumberInputDialogMsg};
+pub use number_input::{NumberInputDialog, NumberInputDialogMsg, PageSelector};
 pub use page::ButtonPage;
-pub use progress::Progress;
+pub use pin_lockout::PinLockout;
+pub use progress::{CancellableProgress, Progress};
 pub use result::{ResultFooter, ResultScreen, ResultStyle};
 pub use scroll::ScrollBar;
+pub use settings_menu::{SettingsMenu, SettingsMenuMsg};
+pub use share_groups::{GroupProgress, ShareGroups, MAX_GROUPS};
 pub use simple_page::SimplePage;
+pub use status_screen::{StatusScreen, StatusScreenMsg};
+pub use streamed_confirm::{StreamedConfirm, StreamedConfirmMsg};
 pub use swipe::{Swipe, SwipeDirection};
+pub use terms_page::TermsPage;
 pub use welcome_screen::WelcomeScreen;
 
 use super::theme;
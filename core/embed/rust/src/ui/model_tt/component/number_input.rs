@@ -9,6 +9,7 @@ use crate::{
         },
         display::{self, Font},
         geometry::{Grid, Insets, Offset, Rect},
+        translations::TranslatedString,
     },
 };
 
@@ -45,8 +46,8 @@ where
             input: NumberInput::new(min, max, init_value).into_child(),
             paragraphs: Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, text)).into_child(),
             paragraphs_pad: Pad::with_background(theme::BG),
-            info_button: Button::with_text("INFO").into_child(),
-            confirm_button: Button::with_text("CONTINUE")
+            info_button: Button::with_translated(TranslatedString::ButtonInfo).into_child(),
+            confirm_button: Button::with_translated(TranslatedString::ButtonContinue)
                 .styled(theme::button_confirm())
                 .into_child(),
         }
@@ -145,6 +146,65 @@ where
     }
 }
 
+/// Choosing the page of long content to jump to, see `ButtonPage`. Sends the
+/// page chosen, numbered from 1.
+pub struct PageSelector {
+    input: Child<NumberInput>,
+    confirm_button: Child<Button<&'static str>>,
+}
+
+impl PageSelector {
+    pub fn new(page_count: u32, current: u32) -> Self {
+        Self {
+            input: NumberInput::new(1, page_count, current).into_child(),
+            confirm_button: Button::with_translated(TranslatedString::ButtonContinue)
+                .styled(theme::button_confirm())
+                .into_child(),
+        }
+    }
+}
+
+impl Component for PageSelector {
+    type Msg = u32;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let area = bounds.inset(theme::borders());
+        let (input_area, rest) = area.split_top(theme::BUTTON_HEIGHT);
+        let (_, button_area) = rest.split_bottom(theme::BUTTON_HEIGHT);
+        self.input.place(input_area);
+        self.confirm_button.place(button_area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.input.event(ctx, event);
+        if let Some(ButtonMsg::Clicked) = self.confirm_button.event(ctx, event) {
+            return Some(self.input.inner().value);
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.input.paint();
+        self.confirm_button.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.input.bounds(sink);
+        self.confirm_button.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for PageSelector {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("PageSelector");
+        t.child("input", &self.input);
+        t.child("confirm_button", &self.confirm_button);
+    }
+}
+
 pub enum NumberInputMsg {
     Changed(u32),
 }
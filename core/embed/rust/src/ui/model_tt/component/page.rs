@@ -1,17 +1,25 @@
+use heapless::String;
+
 use crate::{
-    time::Instant,
+    time::{Duration, Instant},
     ui::{
-        component::{paginated::PageMsg, Component, ComponentExt, Event, EventCtx, Pad, Paginate},
+        component::{
+            label::Label, paginated::PageMsg, AttrUpdate, Child, Component, ComponentExt, Event,
+            EventCtx, Momentum, Pad, Paginate, PAGE_SELECTOR_MIN_PAGES,
+        },
         constant,
-        display::{self, Color},
-        geometry::{Insets, Rect},
+        display::{self, Color, Icon},
+        event::TouchEvent,
+        geometry::{Alignment2D, Insets, Point, Rect},
+        theme::ButtonIntent,
+        translations::TranslatedString,
         util::animation_disabled,
     },
 };
 
 use super::{
-    theme, Button, ButtonContent, ButtonMsg, ButtonStyleSheet, Loader, LoaderMsg, ScrollBar, Swipe,
-    SwipeDirection,
+    theme, Button, ButtonContent, ButtonMsg, ButtonStyleSheet, Loader, LoaderMsg, PageSelector,
+    ScrollBar, Swipe, SwipeDirection,
 };
 
 /// Allows pagination of inner component. Shows scroll bar, confirm & cancel
@@ -19,7 +27,13 @@ use super::{
 pub struct ButtonPage<T, U> {
     /// Inner component.
     content: T,
-    /// Cleared when page changes.
+    /// Title pinned above the content, optionally with an icon. Stays in place
+    /// when the page changes.
+    header: Option<Child<Label<U>>>,
+    header_icon: Option<Icon>,
+    /// "2/5" counter pinned between the content and the buttons.
+    page_counter: Option<Child<Label<String<16>>>>,
+    /// Cleared when page changes, does not cover the header.
     pad: Pad,
     /// Swipe controller.
     swipe: Swipe,
@@ -38,6 +52,12 @@ pub struct ButtonPage<T, U> {
     swipe_right: bool,
     /// Fade to given backlight level on next paint().
     fade: Option<u16>,
+    /// Kinetic scrolling after a fast swipe, with the time of its last frame.
+    momentum: Momentum,
+    momentum_frame: Option<Instant>,
+    /// Choosing the page number to jump to, shown over the whole page.
+    page_selector: Option<Child<PageSelector>>,
+    area: Rect,
 }
 
 impl<T, U> ButtonPage<T, U>
@@ -49,18 +69,26 @@ where
     pub fn new(content: T, background: Color) -> Self {
         Self {
             content,
+            header: None,
+            header_icon: None,
+            page_counter: None,
             pad: Pad::with_background(background),
             swipe: Swipe::new(),
             scrollbar: ScrollBar::vertical(),
             loader: None,
             button_cancel: Some(Button::with_icon(theme::ICON_CANCEL)),
-            button_confirm: Button::with_icon(theme::ICON_CONFIRM).styled(theme::button_confirm()),
+            button_confirm: Button::with_icon(theme::ICON_CONFIRM)
+                .with_intent(ButtonIntent::Confirm),
             button_prev: Button::with_icon(theme::ICON_UP).initially_enabled(false),
-            button_next: Button::with_icon(theme::ICON_DOWN),
+            button_next: Button::with_icon(theme::ICON_DOWN).with_long_press(PAGE_SELECTOR_HOLD),
             cancel_from_any_page: false,
             swipe_left: false,
             swipe_right: false,
             fade: None,
+            momentum: Momentum::new(),
+            momentum_frame: None,
+            page_selector: None,
+            area: Rect::zero(),
         }
     }
 
@@ -79,8 +107,8 @@ where
             _ => Button::with_icon(theme::ICON_CANCEL),
         };
         let confirm = match right {
-            Some(verb) => Button::with_text(verb).styled(theme::button_confirm()),
-            _ => Button::with_icon(theme::ICON_CONFIRM).styled(theme::button_confirm()),
+            Some(verb) => Button::with_text(verb).with_intent(ButtonIntent::Confirm),
+            _ => Button::with_icon(theme::ICON_CONFIRM).with_intent(ButtonIntent::Confirm),
         };
         self.button_cancel = Some(cancel);
         self.button_confirm = confirm;
@@ -88,8 +116,8 @@ where
     }
 
     pub fn with_hold(mut self) -> Self {
-        self.button_confirm =
-            Button::with_text("HOLD TO CONFIRM".into()).styled(theme::button_confirm());
+        self.button_confirm = Button::with_translated(TranslatedString::ButtonHoldToConfirm)
+            .with_intent(ButtonIntent::Confirm);
         self.loader = Some(Loader::new());
         self
     }
@@ -111,6 +139,11 @@ where
         self
     }
 
+    /// Style the confirm button according to the meaning of the action.
+    pub fn with_confirm_intent(self, intent: ButtonIntent) -> Self {
+        self.with_confirm_style(theme::button_for(intent))
+    }
+
     pub fn with_swipe_left(mut self) -> Self {
         self.swipe_left = true;
         self
@@ -121,6 +154,29 @@ where
         self
     }
 
+    pub fn with_header(mut self, title: U, icon: Option<Icon>) -> Self {
+        self.header = Some(Child::new(Label::left_aligned(title, theme::label_title())));
+        self.header_icon = icon;
+        self
+    }
+
+    pub fn with_page_counter(mut self) -> Self {
+        self.page_counter = Some(Child::new(Label::centered(
+            String::new(),
+            theme::label_default(),
+        )));
+        self
+    }
+
+    fn page_counter_text(&self) -> String<16> {
+        build_string!(
+            16,
+            inttostr!(self.scrollbar.active_page as u32 + 1),
+            "/",
+            inttostr!(self.scrollbar.page_count as u32)
+        )
+    }
+
     fn setup_swipe(&mut self) {
         self.swipe.allow_up = self.scrollbar.has_next_page();
         self.swipe.allow_down = self.scrollbar.has_previous_page();
@@ -131,6 +187,7 @@ where
     fn change_page(&mut self, ctx: &mut EventCtx, step: isize) {
         // Advance scrollbar.
         self.scrollbar.go_to_relative(step);
+        ctx.set_active_page(self.scrollbar.active_page);
 
         // Adjust the swipe parameters according to the scrollbar.
         self.setup_swipe();
@@ -145,11 +202,85 @@ where
         self.content.request_complete_repaint(ctx);
         self.pad.clear();
 
+        let text = self.page_counter_text();
+        if let Some(counter) = &mut self.page_counter {
+            counter.mutate(ctx, |ctx, counter| {
+                counter.set_text(text);
+                ctx.request_paint();
+            });
+        }
+
         // Swipe has dimmed the screen, so fade back to normal backlight after the next
         // paint.
         self.fade = Some(theme::BACKLIGHT_NORMAL);
     }
 
+    /// Letting the user choose the page to go to directly, instead of swiping
+    /// many times.
+    fn open_page_selector(&mut self, ctx: &mut EventCtx) {
+        let count = self.scrollbar.page_count as u32;
+        let current = self.scrollbar.active_page as u32 + 1;
+        let mut selector = Child::new(PageSelector::new(count, current));
+        selector.place(self.area);
+        self.page_selector = Some(selector);
+        self.momentum.stop();
+        self.momentum_frame = None;
+        ctx.request_repaint_root();
+    }
+
+    /// Going to the page chosen in the page selector, `page` being numbered from
+    /// 1.
+    fn close_page_selector(&mut self, ctx: &mut EventCtx, page: u32) {
+        self.page_selector = None;
+        let step = page as isize - 1 - self.scrollbar.active_page as isize;
+        self.change_page(ctx, step);
+        // The selector covered everything.
+        ctx.request_repaint_root();
+    }
+
+    /// Keep scrolling in `direction` after a swipe fast enough.
+    fn fling(&mut self, ctx: &mut EventCtx, direction: isize) {
+        let velocity = self.swipe.velocity();
+        if velocity < FLING_MIN_VELOCITY || animation_disabled() {
+            return;
+        }
+        let pages_per_sec = (direction as i32 * velocity) as f32 / FLING_PIXELS_PER_PAGE as f32;
+        self.momentum.fling(pages_per_sec);
+        self.momentum_frame = Some(Instant::now());
+        ctx.request_anim_frame();
+    }
+
+    fn step_momentum(&mut self, ctx: &mut EventCtx) {
+        let Some(last_frame) = self.momentum_frame else {
+            return;
+        };
+        let now = Instant::now();
+        let millis = now.saturating_duration_since(last_frame).to_millis();
+        let pages = self.momentum.step(millis);
+        if pages != 0 {
+            let active = self.scrollbar.active_page as isize;
+            let last = self.scrollbar.page_count as isize - 1;
+            let target = (active + pages).clamp(0, last);
+            if target != active {
+                self.change_page(ctx, target - active);
+            }
+            if target != active + pages {
+                // Bounce off the edge of the content with a short flash.
+                self.momentum.stop();
+                display::set_backlight(theme::BACKLIGHT_DIM);
+                self.fade = Some(theme::BACKLIGHT_NORMAL);
+                ctx.request_paint();
+            }
+        }
+        if self.momentum.is_moving() {
+            self.momentum_frame = Some(now);
+            ctx.request_anim_frame();
+        } else {
+            self.momentum.stop();
+            self.momentum_frame = None;
+        }
+    }
+
     fn is_cancel_visible(&self) -> bool {
         self.cancel_from_any_page || !self.scrollbar.has_previous_page()
     }
@@ -198,8 +329,16 @@ where
         event: Event,
     ) -> HandleResult<(Option<<Self as Component>::Msg>, Option<ButtonMsg>)> {
         if self.scrollbar.has_next_page() {
-            if let Some(ButtonMsg::Clicked) = self.button_next.event(ctx, event) {
-                return HandleResult::NextPage;
+            match self.button_next.event(ctx, event) {
+                Some(ButtonMsg::LongPressed)
+                    if self.scrollbar.page_count >= PAGE_SELECTOR_MIN_PAGES =>
+                {
+                    return HandleResult::OpenPageSelector;
+                }
+                Some(ButtonMsg::Clicked | ButtonMsg::LongPressed) => {
+                    return HandleResult::NextPage;
+                }
+                _ => {}
             }
         } else {
             let result = self.button_confirm.event(ctx, event);
@@ -266,10 +405,23 @@ where
     }
 }
 
+/// Space between the header icon and the title.
+const HEADER_ICON_SPACE: i16 = 8;
+
+/// How long the next button has to be held to open the page selector.
+const PAGE_SELECTOR_HOLD: Duration = Duration::from_millis(1000);
+
+/// Slower swipes turn just one page, faster ones keep scrolling.
+const FLING_MIN_VELOCITY: i32 = 600;
+/// Swipe speed in pixels per second corresponding to one page per second of
+/// kinetic scrolling.
+const FLING_PIXELS_PER_PAGE: i32 = 120;
+
 enum HandleResult<T> {
     Return(T),
     PrevPage,
     NextPage,
+    OpenPageSelector,
     Continue,
 }
 
@@ -282,15 +434,27 @@ where
     type Msg = PageMsg<T::Msg>;
 
     fn place(&mut self, bounds: Rect) -> Rect {
+        let full_bounds = bounds;
+        let mut bounds = bounds;
+        if let Some(header) = &mut self.header {
+            let (header_area, rest) = bounds.split_top(header.inner().max_size().y);
+            let title_inset = self
+                .header_icon
+                .map_or(0, |icon| icon.toif.width() + HEADER_ICON_SPACE);
+            header.place(header_area.inset(Insets::left(title_inset)));
+            bounds = rest.inset(Insets::top(theme::BUTTON_SPACING));
+        }
+
         let small_left_button = match (&self.button_cancel, &self.button_confirm) {
             (None, _) => true,
             (Some(cancel), confirm) => match (cancel.content(), confirm.content()) {
                 (ButtonContent::Text(t), _) => t.as_ref().len() <= 4,
+                (ButtonContent::Translated(t), _) => t.map_translated(|t| t.len() <= 4),
                 (ButtonContent::Icon(_), ButtonContent::Icon(_)) => false,
                 _ => true,
             },
         };
-        let layout = PageLayout::new(bounds, small_left_button);
+        let mut layout = PageLayout::new(bounds, small_left_button);
         self.pad.place(bounds);
         self.swipe.place(bounds);
         self.button_cancel.place(layout.button_left);
@@ -305,6 +469,15 @@ where
         let page_count = {
             let count = self.content.page_count();
             if count > 1 {
+                // Only shown with multiple pages, a single page keeps the space.
+                if let Some(counter) = &mut self.page_counter {
+                    let height = counter.inner().font().text_max_height() + theme::BUTTON_SPACING;
+                    let (content, counter_area) = layout.content.split_bottom(height);
+                    counter.place(counter_area.inset(Insets::bottom(theme::BUTTON_SPACING)));
+                    layout.content = content;
+                    layout.scrollbar = layout.scrollbar.inset(Insets::bottom(height));
+                    self.scrollbar.place(layout.scrollbar);
+                }
                 self.content.place(layout.content);
                 self.content.page_count() // Make sure to re-count it with the
                                           // new size.
@@ -321,25 +494,53 @@ where
         // swiper.
         self.scrollbar.set_count_and_active_page(page_count, 0);
         self.setup_swipe();
+        let text = self.page_counter_text();
+        if let Some(counter) = &mut self.page_counter {
+            let area = counter.inner().area();
+            *counter = Child::new(Label::centered(text, theme::label_default()));
+            counter.place(area);
+        }
 
         self.loader.place(Self::loader_area());
-        bounds
+        self.page_selector.place(full_bounds);
+        self.area = full_bounds;
+        full_bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         ctx.set_page_count(self.scrollbar.page_count);
+        if let Some(selector) = &mut self.page_selector {
+            if let Some(page) = selector.event(ctx, event) {
+                self.close_page_selector(ctx, page);
+            }
+            return None;
+        }
+        self.header.event(ctx, event);
+        self.page_counter.event(ctx, event);
+
+        match event {
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => self.step_momentum(ctx),
+            // Touching the screen stops the kinetic scrolling.
+            Event::Touch(TouchEvent::TouchStart(_)) => {
+                self.momentum.stop();
+                self.momentum_frame = None;
+            }
+            _ => {}
+        }
 
         match self.handle_swipe(ctx, event) {
             HandleResult::Return(r) => return Some(r),
             HandleResult::PrevPage => {
                 self.change_page(ctx, -1);
+                self.fling(ctx, -1);
                 return None;
             }
             HandleResult::NextPage => {
                 self.change_page(ctx, 1);
+                self.fling(ctx, 1);
                 return None;
             }
-            HandleResult::Continue => {}
+            HandleResult::OpenPageSelector | HandleResult::Continue => {}
         }
 
         if let Some(msg) = self.content.event(ctx, event) {
@@ -363,6 +564,10 @@ where
                 self.change_page(ctx, 1);
                 return None;
             }
+            HandleResult::OpenPageSelector => {
+                self.open_page_selector(ctx);
+                return None;
+            }
             HandleResult::Continue => {}
         }
 
@@ -377,7 +582,29 @@ where
     }
 
     fn paint(&mut self) {
+        if let Some(selector) = &mut self.page_selector {
+            selector.paint();
+            return;
+        }
+        if let Some(header) = &mut self.header {
+            if header.will_paint() {
+                if let Some(icon) = self.header_icon {
+                    let area = header.inner().area();
+                    let left = Point::new(
+                        area.x0 - icon.toif.width() - HEADER_ICON_SPACE,
+                        area.center().y,
+                    );
+                    icon.draw(left, Alignment2D::CENTER_LEFT, theme::FG, theme::BG);
+                }
+            }
+            header.paint();
+        }
         self.pad.paint();
+        if let Some(counter) = &mut self.page_counter {
+            if self.scrollbar.has_pages() {
+                counter.paint();
+            }
+        }
         match &self.loader {
             Some(l) if l.is_animating() => self.loader.paint(),
             _ => {
@@ -406,6 +633,8 @@ where
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         sink(self.pad.area);
+        self.header.bounds(sink);
+        self.page_counter.bounds(sink);
         self.scrollbar.bounds(sink);
         self.content.bounds(sink);
         self.button_cancel.bounds(sink);
@@ -413,6 +642,17 @@ where
         self.button_prev.bounds(sink);
         self.button_next.bounds(sink);
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Page(page) => {
+                let step = page as isize - self.scrollbar.active_page as isize;
+                self.change_page(ctx, step);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(feature = "ui_debug")]
@@ -426,6 +666,9 @@ where
         t.int("page_count", self.scrollbar.page_count as i64);
         t.bool("hold", self.loader.is_some());
         t.child("content", &self.content);
+        if let Some(selector) = &self.page_selector {
+            t.child("page_selector", selector);
+        }
     }
 }
 
@@ -479,13 +722,14 @@ mod tests {
     use serde_json;
 
     use crate::{
-        strutil::SkipPrefix,
         trace::tests::trace,
         ui::{
             component::text::paragraphs::{Paragraph, Paragraphs},
             event::TouchEvent,
             geometry::Point,
             model_tt::{constant, theme},
+            testing::{touch_end, touch_start, Harness},
+            translations::{self, build_blob, Translations},
         },
     };
 
@@ -493,12 +737,6 @@ mod tests {
 
     const SCREEN: Rect = constant::screen().inset(theme::borders());
 
-    impl SkipPrefix for &str {
-        fn skip_prefix(&self, chars: usize) -> Self {
-            &self[chars..]
-        }
-    }
-
     fn swipe(component: &mut impl Component, points: &[(i16, i16)]) {
         let last = points.len().saturating_sub(1);
         let mut first = true;
@@ -811,4 +1049,89 @@ mod tests {
         swipe_up(&mut page);
         assert_eq!(trace(&page), third_page);
     }
+
+    #[test]
+    fn page_selector() {
+        let paragraphs: [Paragraph<&'static str>; PAGE_SELECTOR_MIN_PAGES] =
+            core::array::from_fn(|_| Paragraph::new(&theme::TEXT_NORMAL, "Page.").break_after());
+        let page = ButtonPage::<_, &'static str>::new(Paragraphs::new(paragraphs), theme::BG);
+        let mut page = Harness::new(page);
+        let area = constant::screen().inset(theme::borders());
+        let next = Point::new(area.x1 - 10, area.y1 - 10);
+        let inc = Point::new(area.x1 - 10, area.y0 + 10);
+        let confirm = Point::new(area.center().x, area.y1 - 10);
+
+        // A tap goes to the next page.
+        page.tap(next);
+        assert_eq!(page.inner().scrollbar.active_page, 1);
+
+        // Holding opens the selector, lifting the finger does not choose anything.
+        page.event(touch_start(next.x, next.y));
+        page.fire_timers();
+        page.event(touch_end(next.x, next.y));
+        assert!(page.inner().page_selector.is_some());
+        assert_eq!(page.inner().scrollbar.active_page, 1);
+
+        // From page 2 to page 4.
+        page.tap(inc);
+        page.tap(inc);
+        page.tap(confirm);
+        assert!(page.inner().page_selector.is_none());
+        assert_eq!(page.inner().scrollbar.active_page, 3);
+    }
+
+    #[test]
+    fn page_counter() {
+        let paragraphs = [
+            Paragraph::new(&theme::TEXT_NORMAL, "Page one.").break_after(),
+            Paragraph::new(&theme::TEXT_NORMAL, "Page two.").break_after(),
+            Paragraph::new(&theme::TEXT_NORMAL, "Page three."),
+        ];
+        let page = ButtonPage::<_, &'static str>::new(Paragraphs::new(paragraphs), theme::BG)
+            .with_page_counter();
+        let mut page = Harness::new(page);
+        let counter =
+            |page: &ButtonPage<_, _>| page.page_counter.as_ref().unwrap().inner().text().clone();
+        assert_eq!(counter(page.inner()), "1/3");
+        page.swipe(Point::new(20, 100), Point::new(20, 20));
+        assert_eq!(counter(page.inner()), "2/3");
+    }
+
+    #[test]
+    fn hold_button_follows_language() {
+        let page = ButtonPage::<_, &'static str>::new(
+            Paragraphs::<[Paragraph<&'static str>; 0]>::new([]),
+            theme::BG,
+        )
+        .with_hold();
+        let mut h = Harness::new(page);
+        assert_eq!(trace(&h.inner().button_confirm)["text"], "HOLD TO CONFIRM");
+
+        let strings = ["ZRUŠIT", "POTVRDIT", "POKRAČOVAT", "PODRŽET"];
+        let blob = Box::leak(build_blob("cs-CZ", &strings).into_boxed_slice());
+        translations::set_translations(Some(Translations::new(blob).unwrap()));
+        h.event(Event::LanguageChanged);
+        assert!(h.paint_requested());
+        assert_eq!(trace(&h.inner().button_confirm)["text"], "PODRŽET");
+
+        translations::set_translations(None);
+        h.event(Event::LanguageChanged);
+        assert_eq!(trace(&h.inner().button_confirm)["text"], "HOLD TO CONFIRM");
+    }
+
+    #[test]
+    fn hold_is_not_accepted_by_a_tap() {
+        let page = ButtonPage::<_, &'static str>::new(
+            Paragraphs::new([Paragraph::new(&theme::TEXT_NORMAL, "Blind signing.")]),
+            theme::BG,
+        )
+        .with_hold();
+        let mut h = Harness::new(
+            crate::ui::component::Border::new(theme::borders(), page)
+                .with_stripes(theme::WARN_COLOR, theme::BG),
+        );
+        let button = h.inner().inner().button_confirm.area().center();
+        // Lifting the finger before the loader has grown does not accept.
+        assert!(h.tap(button).is_none());
+    }
 }
@@ -0,0 +1,159 @@
+use heapless::String;
+
+use crate::{
+    strutil::StringType,
+    ui::{
+        component::{
+            base::ComponentExt,
+            text::paragraphs::{Paragraph, Paragraphs},
+            Child, Component, Event, EventCtx, Label, Never, Pad,
+        },
+        display::{self, Font, LOADER_MAX},
+        eta::format_countdown,
+        geometry::{Insets, Offset, Rect},
+        model_tt::constant,
+        theme::Theme,
+    },
+};
+
+use super::theme;
+
+/// Countdown of the wait the storage imposes after wrong PINs, a large timer
+/// inside a ring that fills up as the time passes and an explanation below.
+/// Driven by the progress reports of the storage, repainted once the timer
+/// changes, i.e. every second.
+pub struct PinLockout<T> {
+    area: Rect,
+    theme: theme::ModelTheme,
+    pad: Pad,
+    title: Child<Label<T>>,
+    description: Child<Paragraphs<Paragraph<T>>>,
+    /// Length of the whole wait, at progress zero.
+    seconds: u32,
+    value: u16,
+    timer: String<12>,
+    loader_y_offset: i16,
+}
+
+impl<T> PinLockout<T>
+where
+    T: StringType,
+{
+    const TIMER_FONT: Font = Font::DEMIBOLD;
+
+    pub fn new(title: T, description: T, seconds: u32) -> Self {
+        let theme = theme::current();
+        Self {
+            area: Rect::zero(),
+            pad: Pad::with_background(theme.bg()).with_clear(),
+            title: Label::centered(title, *theme.text_bold()).into_child(),
+            description: Paragraphs::new(
+                Paragraph::new(theme.text_normal(), description).centered(),
+            )
+            .into_child(),
+            seconds,
+            value: 0,
+            timer: format_countdown(seconds),
+            loader_y_offset: 0,
+            theme,
+        }
+    }
+
+    /// Whole seconds left, rounded up so that zero is only shown at the end.
+    fn remaining(&self) -> u32 {
+        let left = LOADER_MAX.saturating_sub(self.value) as u32;
+        (self.seconds * left + LOADER_MAX as u32 - 1) / LOADER_MAX as u32
+    }
+}
+
+impl<T> Component for PinLockout<T>
+where
+    T: StringType,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.pad.place(bounds);
+        self.area = bounds.inset(theme::borders());
+        let (title, rest) = self.area.split_top(self.title.inner().max_size().y);
+        let (loader, description) = rest.split_bottom(Font::NORMAL.line_height() * 3);
+        let loader = loader.inset(Insets::top(theme::CONTENT_BORDER));
+        self.title.place(title);
+        self.loader_y_offset = loader.center().y - constant::screen().center().y;
+        self.description.place(description);
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Progress(new_value, _) = event {
+            self.value = new_value;
+            let timer = format_countdown(self.remaining());
+            if timer != self.timer {
+                self.timer = timer;
+                ctx.request_paint();
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        let (fg, bg) = (self.theme.fg(), self.theme.bg());
+        self.pad.paint();
+        self.title.paint();
+        display::loader(self.value, self.loader_y_offset, fg, bg, None);
+        let center = constant::screen().center() + Offset::y(self.loader_y_offset);
+        display::text_center(
+            center + Offset::y(Self::TIMER_FONT.text_height() / 2),
+            self.timer.as_str(),
+            Self::TIMER_FONT,
+            fg,
+            bg,
+        );
+        self.description.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area);
+        self.title.bounds(sink);
+        self.description.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for PinLockout<T>
+where
+    T: StringType,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("PinLockout");
+        t.string("timer", self.timer.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::testing::Harness;
+
+    use super::*;
+
+    fn progress(h: &mut Harness<PinLockout<&'static str>>, value: u16) -> &str {
+        h.clear_paint();
+        h.event(Event::Progress(value, ""));
+        h.inner().timer.as_str()
+    }
+
+    #[test]
+    fn repainted_when_the_timer_changes() {
+        let mut h = Harness::new(PinLockout::new("Wrong PIN", "Please wait", 90));
+        assert_eq!(h.inner().timer.as_str(), "1:30");
+
+        // A bit of progress still leaves part of the first second.
+        assert_eq!(progress(&mut h, 1), "1:30");
+        assert!(!h.paint_requested());
+
+        assert_eq!(progress(&mut h, LOADER_MAX / 2), "0:45");
+        assert!(h.paint_requested());
+        assert_eq!(progress(&mut h, LOADER_MAX), "0:00");
+    }
+}
@@ -1,25 +1,35 @@
 use core::mem;
+use heapless::String;
 
 use crate::{
     error::Error,
     strutil::StringType,
+    time::Duration,
     ui::{
         component::{
             base::ComponentExt,
             paginated::Paginate,
             text::paragraphs::{Paragraph, Paragraphs},
-            Child, Component, Event, EventCtx, Label, Never, Pad,
+            AttrUpdate, Child, Component, Event, EventCtx, Label, Never, Pad,
         },
-        display::{self, Font},
+        display::{self, Font, LOADER_MAX},
+        eta::RateTracker,
+        event::USBEvent,
         geometry::{Insets, Rect},
         model_tt::constant,
         util::animation_disabled,
     },
 };
 
-use super::theme;
+use super::{theme, Button, ButtonMsg};
 
+/// Progress of a long operation reported by the firmware: the loader filled
+/// with the reported value, or in indeterminate mode a spinner moved by the
+/// reports, with an optional estimate of the remaining time and a description
+/// that can change mid-operation. This is the one progress screen, signing,
+/// wiping and firmware hashing all show it.
 pub struct Progress<T> {
+    area: Rect,
     title: Child<Label<T>>,
     value: u16,
     loader_y_offset: i16,
@@ -27,14 +37,16 @@ pub struct Progress<T> {
     description: Child<Paragraphs<Paragraph<T>>>,
     description_pad: Pad,
     update_description: fn(&str) -> Result<T, Error>,
+    /// Estimate of the remaining time, shown below the loader when `Some`.
+    eta: Option<RateTracker>,
+    eta_text: String<24>,
+    eta_pad: Pad,
 }
 
 impl<T> Progress<T>
 where
     T: StringType,
 {
-    const AREA: Rect = constant::screen().inset(theme::borders());
-
     pub fn new(
         title: T,
         indeterminate: bool,
@@ -42,6 +54,7 @@ where
         update_description: fn(&str) -> Result<T, Error>,
     ) -> Self {
         Self {
+            area: Rect::zero(),
             title: Label::centered(title, theme::label_progress()).into_child(),
             value: 0,
             loader_y_offset: 0,
@@ -52,6 +65,51 @@ where
             .into_child(),
             description_pad: Pad::with_background(theme::BG),
             update_description,
+            eta: None,
+            eta_text: String::new(),
+            eta_pad: Pad::with_background(theme::BG),
+        }
+    }
+
+    /// Show the estimated remaining time. Has no effect in indeterminate mode.
+    pub fn with_eta(mut self) -> Self {
+        if !self.indeterminate {
+            self.eta = Some(RateTracker::new(LOADER_MAX));
+        }
+        self
+    }
+
+    fn set_value(&mut self, ctx: &mut EventCtx, new_value: u16) {
+        if mem::replace(&mut self.value, new_value) != new_value {
+            if !animation_disabled() {
+                ctx.request_paint();
+            }
+            self.update_eta(ctx);
+        }
+    }
+
+    fn set_description(&mut self, ctx: &mut EventCtx, new_description: &str) {
+        self.description.mutate(ctx, |ctx, para| {
+            if !new_description.is_empty() && para.inner_mut().content().as_ref() != new_description
+            {
+                let new_description = unwrap!((self.update_description)(new_description));
+                para.inner_mut().update(new_description);
+                para.change_page(0); // Recompute bounding box.
+                ctx.request_paint();
+                self.description_pad.clear();
+            }
+        });
+    }
+
+    fn update_eta(&mut self, ctx: &mut EventCtx) {
+        let Some(eta) = &mut self.eta else {
+            return;
+        };
+        let text = eta.update_text(self.value);
+        if text != self.eta_text {
+            self.eta_text = text;
+            self.eta_pad.clear();
+            ctx.request_paint();
         }
     }
 }
@@ -62,7 +120,8 @@ where
 {
     type Msg = Never;
 
-    fn place(&mut self, _bounds: Rect) -> Rect {
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds.inset(theme::borders());
         let description_lines = 1 + self
             .description
             .inner()
@@ -72,33 +131,29 @@ where
             .chars()
             .filter(|c| *c == '\n')
             .count() as i16;
-        let (title, rest) = Self::AREA.split_top(self.title.inner().max_size().y);
+        let (title, rest) = self.area.split_top(self.title.inner().max_size().y);
         let (loader, description) =
             rest.split_bottom(Font::NORMAL.line_height() * description_lines);
         let loader = loader.inset(Insets::top(theme::CONTENT_BORDER));
+        let loader = if self.eta.is_some() {
+            let (loader, eta) = loader.split_bottom(Font::NORMAL.line_height());
+            self.eta_pad.place(eta);
+            loader
+        } else {
+            loader
+        };
         self.title.place(title);
         self.loader_y_offset = loader.center().y - constant::screen().center().y;
         self.description.place(description);
         self.description_pad.place(description);
-        Self::AREA
+        self.area
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         if let Event::Progress(new_value, new_description) = event {
-            if mem::replace(&mut self.value, new_value) != new_value {
-                if !animation_disabled() {
-                    ctx.request_paint();
-                }
-                self.description.mutate(ctx, |ctx, para| {
-                    if para.inner_mut().content().as_ref() != new_description {
-                        let new_description = unwrap!((self.update_description)(new_description));
-                        para.inner_mut().update(new_description);
-                        para.change_page(0); // Recompute bounding box.
-                        ctx.request_paint();
-                        self.description_pad.clear();
-                    }
-                });
-            }
+            self.set_value(ctx, new_value);
+            // The description can change mid-operation even without the value moving.
+            self.set_description(ctx, new_description);
         }
         None
     }
@@ -116,16 +171,35 @@ where
         } else {
             display::loader(self.value, self.loader_y_offset, theme::FG, theme::BG, None);
         }
+        if self.eta.is_some() {
+            self.eta_pad.paint();
+            display::text_center(
+                self.eta_pad.area.bottom_center(),
+                self.eta_text.as_str(),
+                Font::NORMAL,
+                theme::GREY_LIGHT,
+                theme::BG,
+            );
+        }
         self.description_pad.paint();
         self.description.paint();
     }
 
     #[cfg(feature = "ui_bounds")]
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
-        sink(Self::AREA);
+        sink(self.area);
         self.title.bounds(sink);
         self.description.bounds(sink);
     }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Progress(value) => self.set_value(ctx, value),
+            AttrUpdate::Text(description) => self.set_description(ctx, description),
+            _ => return false,
+        }
+        true
+    }
 }
 
 #[cfg(feature = "ui_debug")]
@@ -137,3 +211,129 @@ where
         t.component("Progress");
     }
 }
+
+/// Progress of a long operation that the user can abort by holding the cancel
+/// button. Also aborts when the host disconnects in the meantime.
+pub struct CancellableProgress<T> {
+    progress: Progress<T>,
+    cancel: Child<Button<&'static str>>,
+}
+
+impl<T> CancellableProgress<T>
+where
+    T: StringType,
+{
+    const CANCEL_HOLD_MS: u32 = 1000;
+
+    pub fn new(progress: Progress<T>) -> Self {
+        Self {
+            progress,
+            cancel: Button::with_text("HOLD TO CANCEL")
+                .styled(theme::button_cancel())
+                .with_long_press(Duration::from_millis(Self::CANCEL_HOLD_MS))
+                .into_child(),
+        }
+    }
+}
+
+impl<T> Component for CancellableProgress<T>
+where
+    T: StringType,
+{
+    type Msg = ();
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (content, button) = bounds.split_bottom(theme::BUTTON_HEIGHT + theme::BUTTON_SPACING);
+        self.progress.place(content);
+        self.cancel
+            .place(button.inset(Insets::new(theme::BUTTON_SPACING, 6, 6, 6)));
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::USB(USBEvent::Connected(false)) = event {
+            return Some(());
+        }
+        if let Some(ButtonMsg::LongPressed) = self.cancel.event(ctx, event) {
+            return Some(());
+        }
+        self.progress.event(ctx, event);
+        None
+    }
+
+    fn paint(&mut self) {
+        self.progress.paint();
+        self.cancel.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.progress.bounds(sink);
+        self.cancel.bounds(sink);
+    }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        self.progress.update_attr(ctx, attr)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for CancellableProgress<T>
+where
+    T: StringType,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("CancellableProgress");
+        t.child("progress", &self.progress);
+        t.child("cancel", &self.cancel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        geometry::Point,
+        testing::{touch_end, touch_start, Harness},
+    };
+
+    use super::*;
+
+    fn progress() -> Harness<CancellableProgress<&'static str>> {
+        let progress = Progress::new("SIGNING", false, "", |_| Ok(""));
+        Harness::new(CancellableProgress::new(progress))
+    }
+
+    fn cancel_button(h: &Harness<CancellableProgress<&'static str>>) -> Point {
+        h.inner().cancel.inner().area().center()
+    }
+
+    #[test]
+    fn hold_cancels_mid_operation() {
+        let mut h = progress();
+        h.expect_no_msg(Event::Progress(300, ""));
+
+        let button = cancel_button(&h);
+        h.expect_no_msg(touch_start(button.x, button.y));
+        // The operation goes on while the button is held.
+        h.expect_no_msg(Event::Progress(400, ""));
+        assert!(h.fire_timers().is_some());
+    }
+
+    #[test]
+    fn short_press_does_not_cancel() {
+        let mut h = progress();
+        let button = cancel_button(&h);
+        h.expect_no_msg(touch_start(button.x, button.y));
+        h.expect_no_msg(touch_end(button.x, button.y));
+        assert!(h.fire_timers().is_none());
+        h.expect_no_msg(Event::Progress(500, ""));
+    }
+
+    #[test]
+    fn host_disconnect_cancels() {
+        let mut h = progress();
+        h.expect_no_msg(Event::Progress(300, ""));
+        h.expect_no_msg(Event::USB(USBEvent::Connected(true)));
+        assert!(h.event(Event::USB(USBEvent::Connected(false))).is_some());
+    }
+}
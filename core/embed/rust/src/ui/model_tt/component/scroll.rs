@@ -1,14 +1,14 @@
 use crate::ui::{
-    component::{Component, Event, EventCtx, Never},
-    display::toif::Icon,
-    geometry::{Alignment2D, Axis, LinearPlacement, Offset, Rect},
+    component::{Component, Event, EventCtx, Never, ScrollBarLayout, ScrollMarks},
+    display::{self, toif::Icon},
+    geometry::{Alignment2D, Axis, Offset, Rect},
 };
 
 use super::theme;
 
 pub struct ScrollBar {
     area: Rect,
-    layout: LinearPlacement,
+    layout: ScrollBarLayout,
     pub page_count: usize,
     pub active_page: usize,
 }
@@ -20,12 +20,17 @@ impl ScrollBar {
     const MAX_DOTS: usize = 7;
     /// Center to center.
     const DOT_INTERVAL: i16 = 18;
+    /// If there's more pages than this value then a bar with a thumb is shown
+    /// instead of the dots.
+    const MAX_DOTTED_PAGES: usize = 16;
+    /// Thickness of the bar, shortest length of its thumb.
+    const BAR_WIDTH: i16 = 4;
+    const MIN_THUMB: i16 = 12;
 
     pub fn new(axis: Axis) -> Self {
-        let layout = LinearPlacement::new(axis);
         Self {
             area: Rect::zero(),
-            layout: layout.align_at_center().with_spacing(Self::DOT_INTERVAL),
+            layout: ScrollBarLayout::new(axis, Self::MAX_DOTTED_PAGES, Self::MIN_THUMB),
             page_count: 0,
             active_page: 0,
         }
@@ -73,6 +78,23 @@ impl ScrollBar {
     pub fn go_to(&mut self, active_page: usize) {
         self.active_page = active_page;
     }
+
+    /// Track of the bar, as long as the row of all the dots.
+    fn track(&self) -> Rect {
+        let len = Self::DOT_INTERVAL * (Self::MAX_DOTS as i16 - 1) + Self::DOT_SIZE;
+        let size = match self.layout.axis {
+            Axis::Horizontal => Offset::new(len, Self::BAR_WIDTH),
+            Axis::Vertical => Offset::new(Self::BAR_WIDTH, len),
+        };
+        Rect::from_center_and_size(self.area.center(), size)
+    }
+
+    fn paint_bar(&self) {
+        let track = self.track();
+        let thumb = self.layout.thumb(track, self.page_count, self.active_page);
+        display::rect_fill_rounded(track, theme::GREY_DARK, theme::BG, 2);
+        display::rect_fill_rounded(thumb, theme::FG, theme::BG, 2);
+    }
 }
 
 impl Component for ScrollBar {
@@ -83,6 +105,11 @@ impl Component for ScrollBar {
     }
 
     fn paint(&mut self) {
+        if self.layout.marks(self.page_count) == ScrollMarks::Bar {
+            self.paint_bar();
+            return;
+        }
+
         fn dotsize(distance: usize, nhidden: usize) -> Icon {
             match (nhidden.saturating_sub(distance)).min(2 - distance) {
                 0 => theme::DOT_INACTIVE,
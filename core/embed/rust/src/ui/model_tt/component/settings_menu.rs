@@ -0,0 +1,139 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::{base::ComponentExt, Child, Component, Event, EventCtx, Pad},
+    display::{self, Font},
+    geometry::{Grid, Offset, Rect},
+    settings::{Setting, MAX_SETTINGS},
+};
+
+use super::{theme, Button, ButtonContent, ButtonMsg};
+
+const RENAME: &str = "RENAME";
+const DONE: &str = "DONE";
+
+pub enum SettingsMenuMsg {
+    Done,
+    /// Left to rename the device.
+    Rename,
+}
+
+/// Settings menu handled entirely in Rust. Every setting has a row with its
+/// title and a button showing its value, tapping the button moves to the next
+/// option. The firmware gets all the values at once when the menu is left.
+pub struct SettingsMenu {
+    pad: Pad,
+    settings: Vec<Setting, MAX_SETTINGS>,
+    /// Where the titles of the settings are painted.
+    titles: Vec<Rect, MAX_SETTINGS>,
+    buttons: Vec<Child<Button<&'static str>>, MAX_SETTINGS>,
+    rename: Child<Button<&'static str>>,
+    done: Child<Button<&'static str>>,
+}
+
+impl SettingsMenu {
+    pub fn new(settings: Vec<Setting, MAX_SETTINGS>) -> Self {
+        let buttons = settings
+            .iter()
+            .map(|setting| Button::with_text(setting.label()).into_child())
+            .collect();
+        Self {
+            pad: Pad::with_background(theme::BG),
+            settings,
+            titles: Vec::new(),
+            buttons,
+            rename: Button::with_text(RENAME).into_child(),
+            done: Button::with_text(DONE)
+                .styled(theme::button_confirm())
+                .into_child(),
+        }
+    }
+
+    pub fn settings(&self) -> &[Setting] {
+        &self.settings
+    }
+}
+
+impl Component for SettingsMenu {
+    type Msg = SettingsMenuMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.pad.place(bounds);
+        let area = bounds.inset(theme::borders());
+        // One more row for the buttons leaving the menu.
+        let grid = Grid::new(area, self.settings.len() + 1, 2).with_spacing(theme::BUTTON_SPACING);
+        self.titles.clear();
+        for (row, button) in self.buttons.iter_mut().enumerate() {
+            unwrap!(self.titles.push(grid.row_col(row, 0)));
+            button.place(grid.row_col(row, 1));
+        }
+        self.rename.place(grid.row_col(self.settings.len(), 0));
+        self.done.place(grid.row_col(self.settings.len(), 1));
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        for (setting, button) in self.settings.iter_mut().zip(self.buttons.iter_mut()) {
+            if let Some(ButtonMsg::Clicked) = button.event(ctx, event) {
+                setting.next();
+                let label = setting.label();
+                button.mutate(ctx, |ctx, button| {
+                    button.set_content(ctx, ButtonContent::Text(label))
+                });
+            }
+        }
+        if let Some(ButtonMsg::Clicked) = self.rename.event(ctx, event) {
+            return Some(SettingsMenuMsg::Rename);
+        }
+        if let Some(ButtonMsg::Clicked) = self.done.event(ctx, event) {
+            return Some(SettingsMenuMsg::Done);
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        let font = Font::NORMAL;
+        for (setting, area) in self.settings.iter().zip(self.titles.iter()) {
+            display::text_left(
+                area.left_center() + Offset::y(font.text_height() / 2),
+                setting.kind.title(),
+                font,
+                theme::FG,
+                theme::BG,
+            );
+        }
+        for button in self.buttons.iter_mut() {
+            button.paint();
+        }
+        self.rename.paint();
+        self.done.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        for area in self.titles.iter() {
+            sink(*area);
+        }
+        for button in self.buttons.iter() {
+            button.bounds(sink);
+        }
+        self.rename.bounds(sink);
+        self.done.bounds(sink);
+    }
+}
+
+// DEBUG-ONLY SECTION BELOW
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for SettingsMenu {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("SettingsMenu");
+        t.in_list("settings", &|list| {
+            for setting in self.settings.iter() {
+                list.string(setting.kind.title());
+                list.string(setting.label());
+            }
+        });
+    }
+}
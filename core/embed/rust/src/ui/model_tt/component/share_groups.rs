@@ -0,0 +1,188 @@
+use heapless::{String, Vec};
+
+use crate::ui::{
+    component::{Child, Component, Event, EventCtx},
+    display::{self, Font},
+    geometry::{Alignment2D, Grid, Offset, Point, Rect},
+};
+
+use super::theme;
+
+/// Most groups a SLIP-39 backup can have.
+pub const MAX_GROUPS: usize = 16;
+const COLUMNS: usize = 4;
+const CELL_HEIGHT: i16 = 22;
+const ICON_WIDTH: i16 = 20;
+const ICON_OFFSET: Offset = Offset::new(0, 3);
+
+/// Shares of one group of a backup that the user has entered so far.
+#[derive(Copy, Clone)]
+pub struct GroupProgress {
+    pub collected: u8,
+    /// Member threshold of the group, zero while no share of the group has been
+    /// entered and it is not known yet.
+    pub required: u8,
+}
+
+impl GroupProgress {
+    pub fn is_complete(self) -> bool {
+        self.required > 0 && self.collected >= self.required
+    }
+}
+
+/// `content`, e.g. the text of the recovery homescreen, above a matrix of the
+/// groups of a Super Shamir backup with the collected and required share
+/// counts of each. Complete groups have a check mark, groups that have been
+/// started an arrow.
+pub struct ShareGroups<T> {
+    content: Child<T>,
+    groups: Vec<GroupProgress, MAX_GROUPS>,
+    grid: Grid,
+}
+
+impl<T> ShareGroups<T>
+where
+    T: Component,
+{
+    /// Groups over `MAX_GROUPS` are left out.
+    pub fn new(content: T, groups: impl IntoIterator<Item = GroupProgress>) -> Self {
+        Self {
+            content: Child::new(content),
+            groups: groups.into_iter().take(MAX_GROUPS).collect(),
+            grid: Grid::new(Rect::zero(), 1, COLUMNS),
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        self.content.inner()
+    }
+
+    fn rows(&self) -> usize {
+        (self.groups.len() + COLUMNS - 1) / COLUMNS
+    }
+
+    fn paint_group(&self, area: Rect, group: GroupProgress) {
+        let (icon, color) = if group.is_complete() {
+            (Some(theme::ICON_LIST_CHECK), theme::GREEN)
+        } else if group.collected > 0 {
+            (Some(theme::ICON_LIST_CURRENT), theme::FG)
+        } else {
+            (None, theme::GREY_LIGHT)
+        };
+        display::rect_fill(area, theme::BG);
+        if let Some(icon) = icon {
+            icon.draw(
+                area.top_left() + ICON_OFFSET,
+                Alignment2D::TOP_LEFT,
+                color,
+                theme::BG,
+            );
+        }
+        let count: String<8> = if group.required > 0 {
+            build_string!(
+                8,
+                inttostr!(group.collected),
+                "/",
+                inttostr!(group.required)
+            )
+        } else {
+            build_string!(8, "-")
+        };
+        let font = Font::NORMAL;
+        let baseline = Point::new(
+            area.x0 + ICON_WIDTH,
+            area.center().y + font.text_height() / 2,
+        );
+        display::text_left(baseline, &count, font, color, theme::BG);
+    }
+}
+
+impl<T> Component for ShareGroups<T>
+where
+    T: Component,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let rows = self.rows();
+        let (content_area, groups_area) = bounds.split_bottom(rows as i16 * CELL_HEIGHT);
+        self.content.place(content_area);
+        self.grid = Grid::new(groups_area, rows.max(1), COLUMNS);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.content.event(ctx, event)
+    }
+
+    fn paint(&mut self) {
+        self.content.paint();
+        for (i, group) in self.groups.iter().enumerate() {
+            self.paint_group(self.grid.cell(i), *group);
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.grid.area);
+        self.content.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for ShareGroups<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("ShareGroups");
+        t.int(
+            "complete",
+            self.groups.iter().filter(|g| g.is_complete()).count() as i64,
+        );
+        t.child("content", &self.content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{component::Timeout, constant::screen, testing::Harness};
+
+    use super::*;
+
+    fn group(collected: u8, required: u8) -> GroupProgress {
+        GroupProgress {
+            collected,
+            required,
+        }
+    }
+
+    #[test]
+    fn groups_take_rows_below_the_content() {
+        let groups = [
+            group(2, 2),
+            group(1, 3),
+            group(0, 0),
+            group(0, 0),
+            group(0, 0),
+        ];
+        let mut h = Harness::new(ShareGroups::new(Timeout::new(100), groups));
+
+        assert!(h.inner().grid.rows == 2);
+        assert!(h.inner().grid.area.y0 == screen().y1 - 2 * CELL_HEIGHT);
+        // The content still gets the events.
+        assert!(h.fire_timers() == Some(()));
+    }
+
+    #[test]
+    fn complete_groups() {
+        assert!(group(3, 3).is_complete());
+        assert!(!group(2, 3).is_complete());
+        // Not known yet, however many shares there are.
+        assert!(!group(0, 0).is_complete());
+
+        let h = Harness::new(ShareGroups::new(Timeout::new(100), [group(0, 0); 20]));
+        assert!(h.inner().groups.len() == MAX_GROUPS);
+        assert!(h.inner().grid.rows == MAX_GROUPS / COLUMNS);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::ui::{
-    component::{base::ComponentExt, Component, Event, EventCtx, Pad, PageMsg, Paginate},
+    component::{
+        base::ComponentExt, Component, Event, EventCtx, Pad, PageMsg, Paginate, Transition,
+        TransitionKind,
+    },
     display::{self, Color},
     geometry::{Axis, Insets, Rect},
 };
@@ -10,7 +13,8 @@ const SCROLLBAR_HEIGHT: i16 = 18;
 const SCROLLBAR_BORDER: i16 = 4;
 
 pub struct SimplePage<T> {
-    content: T,
+    /// Slides between horizontal pages, fades between vertical ones.
+    content: Transition<T>,
     pad: Pad,
     swipe: Swipe,
     scrollbar: ScrollBar,
@@ -26,7 +30,7 @@ where
 {
     pub fn new(content: T, axis: Axis, background: Color) -> Self {
         Self {
-            content,
+            content: Transition::new(content, background).with_backlight(theme::BACKLIGHT_NORMAL),
             swipe: Swipe::new(),
             pad: Pad::with_background(background),
             scrollbar: ScrollBar::new(axis),
@@ -50,7 +54,7 @@ where
     }
 
     pub fn inner(&self) -> &T {
-        &self.content
+        self.content.inner()
     }
 
     fn setup_swipe(&mut self) {
@@ -74,12 +78,18 @@ where
         // Change the page in the content, make sure it gets completely repainted and
         // clear the background under it.
         self.content.change_page(self.scrollbar.active_page);
+        if !self.is_horizontal() {
+            self.content.start(TransitionKind::Fade);
+        }
+        self.content.play(ctx);
         self.content.request_complete_repaint(ctx);
         self.pad.clear();
 
         // Swipe has dimmed the screen, so fade back to normal backlight after the next
-        // paint.
-        self.fade = Some(theme::BACKLIGHT_NORMAL);
+        // paint, unless the fade transition brings it up.
+        if self.is_horizontal() || !self.content.is_running() {
+            self.fade = Some(theme::BACKLIGHT_NORMAL);
+        }
     }
 
     fn is_horizontal(&self) -> bool {
@@ -0,0 +1,227 @@
+use crate::{
+    strutil::StringType,
+    ui::{
+        component::{image::BlendedImage, Child, Component, Event, EventCtx, Label, Timeout},
+        geometry::{Insets, Rect},
+        sound::Feedback,
+        theme::ButtonIntent,
+    },
+};
+
+use super::{theme, Button, ButtonMsg};
+
+pub enum StatusScreenMsg {
+    Dismissed,
+}
+
+/// Outcome shown at the end of a flow, e.g. after signing or a backup: a big
+/// success or failure icon, a one-line title and an optional detail. Dismissed
+/// by the button, the timeout, or whichever comes first if there are both.
+pub struct StatusScreen<T> {
+    image: Child<BlendedImage>,
+    title: Child<Label<T>>,
+    detail: Option<Child<Label<T>>>,
+    button: Option<Child<Button<T>>>,
+    timeout: Option<Child<Timeout>>,
+    feedback: Feedback,
+}
+
+impl<T> StatusScreen<T>
+where
+    T: StringType,
+{
+    pub const ICON_AREA_PADDING: i16 = 2;
+    pub const ICON_AREA_HEIGHT: i16 = 60;
+    pub const DETAIL_SPACE: i16 = 5;
+
+    pub fn new(success: bool, title: T) -> Self {
+        let (fg, color) = if success {
+            (theme::IMAGE_FG_SUCCESS, theme::SUCCESS_COLOR)
+        } else {
+            (theme::IMAGE_FG_ERROR, theme::ERROR_COLOR)
+        };
+        Self {
+            image: Child::new(BlendedImage::new(
+                theme::IMAGE_BG_CIRCLE,
+                fg,
+                color,
+                theme::FG,
+                theme::BG,
+            )),
+            title: Child::new(Label::centered(title, theme::TEXT_DEMIBOLD)),
+            detail: None,
+            button: None,
+            timeout: None,
+            feedback: if success {
+                Feedback::Confirm
+            } else {
+                Feedback::Error
+            },
+        }
+    }
+
+    /// Sound played when the screen appears, by default the one for success
+    /// or error.
+    pub fn with_feedback(mut self, feedback: Feedback) -> Self {
+        self.feedback = feedback;
+        self
+    }
+
+    pub fn with_detail(mut self, detail: T) -> Self {
+        if !detail.as_ref().is_empty() {
+            self.detail = Some(Child::new(Label::centered(
+                detail,
+                theme::TEXT_NORMAL_OFF_WHITE,
+            )));
+        }
+        self
+    }
+
+    pub fn with_button(mut self, text: T) -> Self {
+        if !text.as_ref().is_empty() {
+            self.button = Some(Child::new(
+                Button::with_text(text).with_intent(ButtonIntent::Confirm),
+            ));
+        }
+        self
+    }
+
+    /// Dismiss the screen on its own after `time_ms` milliseconds, 0 to wait
+    /// for the button.
+    pub fn with_timeout(mut self, time_ms: u32) -> Self {
+        self.timeout = (time_ms > 0).then(|| Child::new(Timeout::new(time_ms)));
+        self
+    }
+}
+
+impl<T> Component for StatusScreen<T>
+where
+    T: StringType,
+{
+    type Msg = StatusScreenMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let bounds = bounds
+            .inset(theme::borders())
+            .inset(Insets::top(Self::ICON_AREA_PADDING));
+
+        let mut content_area = bounds;
+        if let Some(button) = &mut self.button {
+            let (rest, button_area) = bounds.split_bottom(theme::BUTTON_HEIGHT);
+            button.place(button_area);
+            content_area = rest;
+        }
+        let (image_area, content_area) = content_area.split_top(Self::ICON_AREA_HEIGHT);
+        self.image.place(image_area);
+
+        let title_height = self.title.inner().font().text_max_height();
+        let (title_area, detail_area) = content_area.split_top(title_height);
+        self.title.place(title_area);
+        if let Some(detail) = &mut self.detail {
+            detail.place(detail_area.inset(Insets::top(Self::DETAIL_SPACE)));
+        }
+        self.timeout.place(Rect::zero());
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Attach = event {
+            self.feedback.play(ctx);
+        }
+        if let Some(ButtonMsg::Clicked) = self.button.event(ctx, event) {
+            return Some(StatusScreenMsg::Dismissed);
+        }
+        self.timeout
+            .event(ctx, event)
+            .map(|_| StatusScreenMsg::Dismissed)
+    }
+
+    fn paint(&mut self) {
+        self.image.paint();
+        self.title.paint();
+        self.detail.paint();
+        self.button.paint();
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.image.bounds(sink);
+        self.title.bounds(sink);
+        self.detail.bounds(sink);
+        self.button.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for StatusScreen<T>
+where
+    T: StringType,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("StatusScreen");
+        t.child("title", &self.title);
+        if let Some(detail) = &self.detail {
+            t.child("detail", detail);
+        }
+        if let Some(button) = &self.button {
+            t.child("button", button);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{geometry::Point, testing::Harness};
+
+    fn button_center(screen: &Harness<StatusScreen<&'static str>>) -> Point {
+        screen
+            .inner()
+            .button
+            .as_ref()
+            .unwrap()
+            .inner()
+            .area()
+            .center()
+    }
+
+    #[test]
+    fn dismissed_by_the_button() {
+        let mut screen = Harness::new(StatusScreen::new(true, "Signed").with_button("Continue"));
+        assert!(screen.timers().is_empty());
+        let center = button_center(&screen);
+        assert!(matches!(
+            screen.tap(center),
+            Some(StatusScreenMsg::Dismissed)
+        ));
+    }
+
+    #[test]
+    fn dismissed_by_whichever_comes_first() {
+        let mut screen = Harness::new(
+            StatusScreen::new(false, "Backup failed")
+                .with_detail("Check the words")
+                .with_button("Close")
+                .with_timeout(2000),
+        );
+        assert_eq!(screen.timers().len(), 1);
+        assert!(matches!(
+            screen.fire_timers(),
+            Some(StatusScreenMsg::Dismissed)
+        ));
+    }
+
+    #[test]
+    fn empty_parts_are_left_out() {
+        let mut screen = Harness::new(
+            StatusScreen::new(true, "Done")
+                .with_detail("")
+                .with_button("")
+                .with_timeout(0),
+        );
+        assert!(screen.inner().detail.is_none());
+        assert!(screen.inner().button.is_none());
+        assert!(screen.timers().is_empty());
+        assert!(screen.fire_timers().is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use heapless::String;
+
+use crate::ui::{
+    component::{
+        text::TextLayout, AttrUpdate, Child, Component, Event, EventCtx, Pad, StreamWindow,
+    },
+    display,
+    geometry::{Insets, Offset, Rect},
+    message_format::format_translated,
+    theme::ButtonIntent,
+    translations::TranslatedString,
+};
+
+use super::{theme, Button, ButtonContent, ButtonMsg};
+
+/// Items kept for going back, older ones can no longer be shown.
+const WINDOW: usize = 4;
+const VALUE_SPACE: i16 = 6;
+
+const NEXT: &str = "NEXT";
+const CONFIRM: &str = "CONFIRM";
+
+pub enum StreamedConfirmMsg {
+    Confirmed,
+    Cancelled,
+    /// The user wants to see the next item, which has not arrived yet.
+    More,
+}
+
+/// Confirmation of content that is too large to be held in memory at once,
+/// e.g. the outputs of a large transaction. The firmware pushes items into
+/// the live layout as they come from the host, the component shows one item
+/// per page and asks for more when the user gets past the last one received.
+/// Only the last few items are kept for going back. The content is confirmed
+/// on the last item, once the firmware has said there are no more.
+pub struct StreamedConfirm {
+    stream: StreamWindow<WINDOW>,
+    current: usize,
+    /// More items have been asked for and the user waits for them.
+    waiting: bool,
+    pad: Pad,
+    area: Rect,
+    left: Child<Button<&'static str>>,
+    right: Child<Button<&'static str>>,
+}
+
+impl StreamedConfirm {
+    pub fn new() -> Self {
+        Self {
+            stream: StreamWindow::new(),
+            current: 0,
+            waiting: false,
+            pad: Pad::with_background(theme::BG),
+            area: Rect::zero(),
+            left: Child::new(Button::with_icon(theme::ICON_CANCEL)),
+            right: Child::new(Button::with_text(NEXT).with_intent(ButtonIntent::Confirm)),
+        }
+    }
+
+    fn is_last(&self) -> bool {
+        self.current + 1 >= self.stream.received()
+    }
+
+    fn go_to(&mut self, ctx: &mut EventCtx, index: usize) {
+        self.current = index;
+        self.update_buttons(ctx);
+        self.pad.clear();
+        ctx.request_paint();
+    }
+
+    fn update_buttons(&mut self, ctx: &mut EventCtx) {
+        let back = self.current > self.stream.first();
+        let confirm = self.is_last() && self.stream.is_finished();
+        let waiting = self.waiting;
+        self.left.mutate(ctx, |ctx, b| {
+            let icon = if back {
+                theme::ICON_UP
+            } else {
+                theme::ICON_CANCEL
+            };
+            b.set_content(ctx, ButtonContent::Icon(icon));
+        });
+        self.right.mutate(ctx, |ctx, b| {
+            b.set_content(
+                ctx,
+                ButtonContent::Text(if confirm { CONFIRM } else { NEXT }),
+            );
+            b.enable_if(ctx, !waiting);
+        });
+    }
+
+    fn push(&mut self, ctx: &mut EventCtx, title: &str, value: &str) -> bool {
+        if !self.stream.push(title, value) {
+            return false;
+        }
+        if self.waiting || self.stream.received() == 1 {
+            self.waiting = false;
+            self.go_to(ctx, self.stream.received() - 1);
+        } else {
+            // The oldest item might have been dropped.
+            self.update_buttons(ctx);
+        }
+        true
+    }
+
+    fn finish(&mut self, ctx: &mut EventCtx) {
+        self.stream.finish();
+        self.waiting = false;
+        self.update_buttons(ctx);
+    }
+}
+
+impl Component for StreamedConfirm {
+    type Msg = StreamedConfirmMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (content, buttons) = bounds.split_bottom(theme::BUTTON_HEIGHT);
+        let (left, right) = buttons.split_left(theme::BUTTON_WIDTH);
+        self.left.place(left);
+        self.right
+            .place(right.inset(Insets::left(theme::BUTTON_SPACING)));
+        self.area = content.inset(Insets::bottom(theme::BUTTON_SPACING));
+        self.pad.place(self.area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Some(ButtonMsg::Clicked) = self.left.event(ctx, event) {
+            if self.current > self.stream.first() {
+                self.go_to(ctx, self.current - 1);
+                return None;
+            }
+            return Some(StreamedConfirmMsg::Cancelled);
+        }
+        if let Some(ButtonMsg::Clicked) = self.right.event(ctx, event) {
+            if !self.is_last() {
+                self.go_to(ctx, self.current + 1);
+            } else if self.stream.is_finished() {
+                return Some(StreamedConfirmMsg::Confirmed);
+            } else {
+                self.waiting = true;
+                self.update_buttons(ctx);
+                return Some(StreamedConfirmMsg::More);
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.left.paint();
+        self.right.paint();
+
+        let Some(item) = self.stream.get(self.current) else {
+            let baseline =
+                self.area.center() + Offset::y(theme::TEXT_NORMAL.text_font.text_height() / 2);
+            display::text_center(
+                baseline,
+                "Loading...",
+                theme::TEXT_NORMAL.text_font,
+                theme::FG,
+                theme::BG,
+            );
+            return;
+        };
+
+        let position = self.current as u32 + 1;
+        let counter: String<32> = if self.stream.is_finished() {
+            let total = self.stream.received() as u32;
+            format_translated(
+                TranslatedString::TextPositionOf,
+                &[position.into(), total.into()],
+            )
+        } else {
+            position.into()
+        };
+        let style = theme::TEXT_NORMAL_OFF_WHITE;
+        let fit = TextLayout::new(style)
+            .with_bounds(self.area)
+            .render_text(counter.as_str());
+        let area = self.area.inset(Insets::top(fit.height()));
+        let fit = TextLayout::new(theme::TEXT_DEMIBOLD)
+            .with_bounds(area)
+            .render_text(item.title.as_str());
+        let area = area.inset(Insets::top(fit.height() + VALUE_SPACE));
+        TextLayout::new(theme::TEXT_MONO)
+            .with_bounds(area)
+            .render_text(item.value.as_str());
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area);
+        self.left.bounds(sink);
+        self.right.bounds(sink);
+    }
+
+    fn update_attr(&mut self, ctx: &mut EventCtx, attr: AttrUpdate) -> bool {
+        match attr {
+            AttrUpdate::Item(title, value) => return self.push(ctx, title, value),
+            AttrUpdate::End => self.finish(ctx),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for StreamedConfirm {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("StreamedConfirm");
+        t.int("current", self.current as i64);
+        t.bool("finished", self.stream.is_finished());
+        if let Some(item) = self.stream.get(self.current) {
+            t.string("title", item.title.as_str());
+            t.string("value", item.value.as_str());
+        }
+        t.child("left", &self.left);
+        t.child("right", &self.right);
+    }
+}
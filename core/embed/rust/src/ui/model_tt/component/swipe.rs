@@ -1,8 +1,11 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx},
-    display,
-    event::TouchEvent,
-    geometry::{Point, Rect},
+use crate::{
+    time::Instant,
+    ui::{
+        component::{Component, Event, EventCtx},
+        display,
+        event::TouchEvent,
+        geometry::{Point, Rect},
+    },
 };
 
 use super::theme;
@@ -22,7 +25,9 @@ pub struct Swipe {
     pub allow_right: bool,
     backlight_start: u16,
     backlight_end: u16,
-    origin: Option<Point>,
+    origin: Option<(Point, Instant)>,
+    /// Speed of the last swipe in pixels per second.
+    velocity: i32,
 }
 
 impl Swipe {
@@ -39,6 +44,7 @@ impl Swipe {
             backlight_start: theme::BACKLIGHT_NORMAL,
             backlight_end: theme::BACKLIGHT_NONE,
             origin: None,
+            velocity: 0,
         }
     }
 
@@ -70,6 +76,12 @@ impl Swipe {
         self
     }
 
+    /// Speed of the last recognized swipe in pixels per second, used e.g. for
+    /// kinetic scrolling.
+    pub fn velocity(&self) -> i32 {
+        self.velocity
+    }
+
     fn is_active(&self) -> bool {
         self.allow_up || self.allow_down || self.allow_left || self.allow_right
     }
@@ -100,10 +112,10 @@ impl Component for Swipe {
         }
         match (event, self.origin) {
             (Event::Touch(TouchEvent::TouchStart(pos)), _) if self.area.contains(pos) => {
-                // Mark the starting position of this touch.
-                self.origin.replace(pos);
+                // Mark the starting position and time of this touch.
+                self.origin.replace((pos, Instant::now()));
             }
-            (Event::Touch(TouchEvent::TouchMove(pos)), Some(origin)) => {
+            (Event::Touch(TouchEvent::TouchMove(pos)), Some((origin, _))) => {
                 // Consider our allowed directions and the touch distance and modify the display
                 // backlight accordingly.
                 let ofs = pos - origin;
@@ -120,7 +132,7 @@ impl Component for Swipe {
                     }
                 };
             }
-            (Event::Touch(TouchEvent::TouchEnd(pos)), Some(origin)) => {
+            (Event::Touch(TouchEvent::TouchEnd(pos)), Some((origin, start))) => {
                 // Touch interaction is over, reset the position.
                 self.origin.take();
 
@@ -128,6 +140,8 @@ impl Component for Swipe {
                 // constitutes a valid swipe.
                 let ofs = pos - origin;
                 let abs = ofs.abs();
+                let millis = Instant::now().saturating_duration_since(start).to_millis();
+                self.velocity = abs.x.max(abs.y) as i32 * 1000 / millis.max(1) as i32;
                 if abs.x > abs.y && (self.allow_left || self.allow_right) {
                     // Horizontal direction.
                     if self.ratio(abs.x) >= Self::THRESHOLD {
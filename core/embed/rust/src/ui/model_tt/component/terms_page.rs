@@ -0,0 +1,228 @@
+use crate::ui::{
+    component::{paginated::PageMsg, Component, Event, EventCtx, Pad, Paginate},
+    display,
+    geometry::Rect,
+    theme::ButtonIntent,
+};
+
+use super::{page::PageLayout, theme, Button, ButtonMsg, ScrollBar, Swipe, SwipeDirection};
+
+/// Long informational text, e.g. a regulatory notice or a disclaimer of an
+/// experimental feature, paged by swiping. The confirm button stays disabled
+/// until the last page has been shown, afterwards it can be used from any page.
+pub struct TermsPage<T, U> {
+    content: T,
+    pad: Pad,
+    swipe: Swipe,
+    scrollbar: ScrollBar,
+    button_cancel: Button<U>,
+    button_confirm: Button<U>,
+    /// Whether the last page has been shown.
+    seen_last_page: bool,
+    /// Fade to given backlight level on next paint().
+    fade: Option<u16>,
+}
+
+impl<T, U> TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: AsRef<str> + From<&'static str>,
+{
+    pub fn new(content: T, verb: U) -> Self {
+        Self {
+            content,
+            pad: Pad::with_background(theme::BG),
+            swipe: Swipe::vertical(),
+            scrollbar: ScrollBar::vertical(),
+            button_cancel: Button::with_icon(theme::ICON_CANCEL),
+            button_confirm: Button::with_text(verb)
+                .with_intent(ButtonIntent::Confirm)
+                .initially_enabled(false),
+            seen_last_page: false,
+            fade: None,
+        }
+    }
+
+    fn change_page(&mut self, ctx: &mut EventCtx, step: isize) {
+        let before = self.scrollbar.active_page;
+        self.scrollbar.go_to_relative(step);
+        if self.scrollbar.active_page == before {
+            return;
+        }
+        self.content.change_page(self.scrollbar.active_page);
+        self.content.request_complete_repaint(ctx);
+        self.pad.clear();
+        self.update_confirm(ctx);
+        // Swipe has dimmed the screen, so fade back to normal backlight after the next
+        // paint.
+        self.fade = Some(theme::BACKLIGHT_NORMAL);
+    }
+
+    /// Remembering the last page once shown and enabling the confirm button.
+    fn update_confirm(&mut self, ctx: &mut EventCtx) {
+        if !self.scrollbar.has_next_page() && !self.seen_last_page {
+            self.seen_last_page = true;
+            self.button_confirm.enable(ctx);
+        }
+    }
+}
+
+impl<T, U> Component for TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: AsRef<str> + From<&'static str>,
+{
+    type Msg = PageMsg<T::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let layout = PageLayout::new(bounds, true);
+        self.pad.place(bounds);
+        self.swipe.place(bounds);
+        self.button_cancel.place(layout.button_left);
+        self.button_confirm.place(layout.button_right);
+        self.scrollbar.place(layout.scrollbar);
+
+        self.content.place(layout.content_single_page);
+        let mut page_count = self.content.page_count();
+        if page_count > 1 {
+            self.content.place(layout.content);
+            page_count = self.content.page_count();
+        }
+        self.scrollbar
+            .set_count_and_active_page(page_count, self.scrollbar.active_page);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        ctx.set_page_count(self.scrollbar.page_count);
+        // Covers the content fitting on a single page.
+        self.update_confirm(ctx);
+
+        match self.swipe.event(ctx, event) {
+            Some(SwipeDirection::Up) => {
+                self.change_page(ctx, 1);
+                return None;
+            }
+            Some(SwipeDirection::Down) => {
+                self.change_page(ctx, -1);
+                return None;
+            }
+            _ => {}
+        }
+        if let Some(msg) = self.content.event(ctx, event) {
+            return Some(PageMsg::Content(msg));
+        }
+        if let Some(ButtonMsg::Clicked) = self.button_cancel.event(ctx, event) {
+            return Some(PageMsg::Cancelled);
+        }
+        if let Some(ButtonMsg::Clicked) = self.button_confirm.event(ctx, event) {
+            return Some(PageMsg::Confirmed);
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.content.paint();
+        if self.scrollbar.has_pages() {
+            self.scrollbar.paint();
+        }
+        self.button_cancel.paint();
+        self.button_confirm.paint();
+        if let Some(val) = self.fade.take() {
+            // Note that this is blocking and takes some time.
+            display::fade_backlight(val);
+        }
+    }
+
+    #[cfg(feature = "ui_bounds")]
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area);
+        self.scrollbar.bounds(sink);
+        self.content.bounds(sink);
+        self.button_cancel.bounds(sink);
+        self.button_confirm.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, U> crate::trace::Trace for TermsPage<T, U>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.component("TermsPage");
+        t.int("active_page", self.scrollbar.active_page as i64);
+        t.int("page_count", self.scrollbar.page_count as i64);
+        t.bool("seen_last_page", self.seen_last_page);
+        t.child("content", &self.content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{component::Never, geometry::Point, testing::Harness};
+
+    use super::*;
+
+    /// Text of `count` pages.
+    struct Pages {
+        count: usize,
+    }
+
+    impl Component for Pages {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            None
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    impl Paginate for Pages {
+        fn page_count(&mut self) -> usize {
+            self.count
+        }
+
+        fn change_page(&mut self, _active_page: usize) {}
+    }
+
+    fn terms(count: usize) -> Harness<TermsPage<Pages, &'static str>> {
+        Harness::new(TermsPage::new(Pages { count }, "I AGREE"))
+    }
+
+    fn swipe_up(h: &mut Harness<TermsPage<Pages, &'static str>>) {
+        h.swipe(Point::new(120, 150), Point::new(120, 30));
+    }
+
+    #[test]
+    fn confirm_after_the_last_page() {
+        let mut h = terms(3);
+        let confirm = h.inner().button_confirm.area().center();
+        assert!(h.tap(confirm).is_none());
+
+        swipe_up(&mut h);
+        assert!(!h.inner().seen_last_page);
+        swipe_up(&mut h);
+        assert!(h.inner().seen_last_page);
+        // Still allowed after going back.
+        h.swipe(Point::new(120, 30), Point::new(120, 150));
+        assert!(h.inner().scrollbar.active_page == 1);
+        assert!(matches!(h.tap(confirm), Some(PageMsg::Confirmed)));
+    }
+
+    #[test]
+    fn single_page_can_be_confirmed_at_once() {
+        let mut h = terms(1);
+        let confirm = h.inner().button_confirm.area().center();
+        assert!(matches!(h.tap(confirm), Some(PageMsg::Confirmed)));
+
+        let cancel = h.inner().button_cancel.area().center();
+        assert!(matches!(h.tap(cancel), Some(PageMsg::Cancelled)));
+    }
+}
@@ -2,8 +2,10 @@ use core::{cmp::Ordering, convert::TryInto};
 
 use crate::{
     error::Error,
+    maybe_trace::MaybeTrace,
     micropython::{
-        buffer::{get_buffer, StrBuffer},
+        buffer::{get_buffer, BorrowedStr, StrBuffer},
+        ffi,
         gc::Gc,
         iter::IterBuf,
         list::List,
@@ -13,9 +15,12 @@ use crate::{
         qstr::Qstr,
         util,
     },
-    strutil::StringType,
+    strutil::{SecretString, StringType},
+    time::Duration,
     trezorhal::model,
     ui::{
+        carry,
+        coin_icons::{self, CoinIcon},
         component::{
             base::ComponentExt,
             image::BlendedImage,
@@ -25,32 +30,52 @@ use crate::{
             text::{
                 op::OpTextLayout,
                 paragraphs::{
-                    Checklist, Paragraph, ParagraphSource, ParagraphVecLong, ParagraphVecShort,
-                    Paragraphs, VecExt,
+                    Checklist, IconList, Paragraph, ParagraphSource, ParagraphVecLong,
+                    ParagraphVecShort, Paragraphs, VecExt,
                 },
                 TextStyle,
             },
-            Border, Component, Empty, FormattedText, Label, Never, Qr, Timeout,
+            wizard::WizardSteps,
+            BlobFormat, BlobView, Border, Component, Countdown, CountdownMsg, Empty, EntropyMixer,
+            EntropyMixerMsg, FormattedText, Label, Never, Qr, SettingsDiff, StepMsg, Timeout,
+            ToastQueue, Wizard, WizardMsg,
         },
+        context::settings,
         display::tjpgd::jpeg_info,
         geometry,
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
-            result::{CANCELLED, CONFIRMED, INFO},
-            util::{iter_into_array, upy_disable_animation, ConfirmBlob, PropsList},
+            result::{self, CANCELLED, CONFIRMED, INFO, MORE, SELECTED},
+            util::{
+                get_payment_request, get_setting_changes, get_template, iter_into_array,
+                upy_clear_resume_point, upy_commit_translations, upy_disable_animation,
+                upy_disable_transitions, upy_enable_profiler, upy_erase_translations,
+                upy_load_translations, upy_resume_point, upy_save_resume_point, upy_secrets_equal,
+                upy_set_accessibility, upy_set_autodim, upy_set_battery_level, upy_set_density,
+                upy_set_keyboard_layout, upy_set_replay_seed, upy_set_theme_inverted,
+                upy_set_wallet_badge, upy_trace_paint_counters, upy_translations_max_length,
+                upy_wipe_carry, upy_write_translations, ConfirmBlob, ObjBytes, PropsList,
+            },
         },
         model_tt::component::check_homescreen_format,
+        random::PinShuffle,
+        settings::{Setting, SettingKind, MAX_SETTINGS},
+        sound::Feedback,
+        theme::ButtonIntent,
+        translations::{self, TranslatedString},
     },
 };
 
 use super::{
     component::{
-        AddressDetails, Bip39Input, Button, ButtonMsg, ButtonPage, ButtonStyleSheet,
-        CancelConfirmMsg, CancelInfoConfirmMsg, CoinJoinProgress, Dialog, DialogMsg, FidoConfirm,
-        FidoMsg, Frame, FrameMsg, Homescreen, HomescreenMsg, IconDialog, Lockscreen, MnemonicInput,
-        MnemonicKeyboard, MnemonicKeyboardMsg, NumberInputDialog, NumberInputDialogMsg,
-        PassphraseKeyboard, PassphraseKeyboardMsg, PinKeyboard, PinKeyboardMsg, Progress,
-        SelectWordCount, SelectWordCountMsg, SelectWordMsg, SimplePage, Slip39Input,
+        AddressDetails, Bip39Input, Button, ButtonMsg, ButtonPage, CancelConfirmMsg,
+        CancelInfoConfirmMsg, CancellableProgress, CoinJoinProgress, Dialog, DialogMsg,
+        FidoConfirm, FidoMsg, Frame, FrameMsg, GroupProgress, Homescreen, HomescreenMsg,
+        IconDialog, KeyDetails, KeyDetailsMsg, Lockscreen, MnemonicInput, MnemonicKeyboard,
+        MnemonicKeyboardMsg, NumberInputDialog, NumberInputDialogMsg, PassphraseKeyboard,
+        PassphraseKeyboardMsg, PinKeyboard, PinKeyboardMsg, PinLockout, Progress, SelectWordCount,
+        SelectWordCountMsg, SelectWordMsg, SettingsMenu, SettingsMenuMsg, ShareGroups, SimplePage,
+        Slip39Input, StatusScreen, StatusScreenMsg, TermsPage, MAX_GROUPS,
     },
     theme,
 };
@@ -83,7 +108,7 @@ impl TryFrom<SelectWordMsg> for Obj {
 
     fn try_from(value: SelectWordMsg) -> Result<Self, Self::Error> {
         match value {
-            SelectWordMsg::Selected(i) => i.try_into(),
+            SelectWordMsg::Selected(i) => result::with_value(&SELECTED, Obj::try_from(i)?),
         }
     }
 }
@@ -93,7 +118,7 @@ impl TryFrom<SelectWordCountMsg> for Obj {
 
     fn try_from(value: SelectWordCountMsg) -> Result<Self, Self::Error> {
         match value {
-            SelectWordCountMsg::Selected(i) => i.try_into(),
+            SelectWordCountMsg::Selected(i) => result::with_value(&SELECTED, Obj::try_from(i)?),
         }
     }
 }
@@ -106,7 +131,7 @@ where
 {
     fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
         match msg {
-            FidoMsg::Confirmed(page) => Ok((page as u8).into()),
+            FidoMsg::Confirmed(page) => result::with_value(&SELECTED, page as u8),
             FidoMsg::Cancelled => Ok(CANCELLED.as_obj()),
         }
     }
@@ -140,6 +165,38 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for StatusScreen<T>
+where
+    T: StringType,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            StatusScreenMsg::Dismissed => Ok(CONFIRMED.as_obj()),
+        }
+    }
+}
+
+impl<T> ComponentMsgObj for ToastQueue<T>
+where
+    T: ComponentMsgObj,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        self.inner().msg_try_into_obj(msg)
+    }
+}
+
+impl<T> ComponentMsgObj for Countdown<T>
+where
+    T: ComponentMsgObj,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            CountdownMsg::Inner(msg) => self.inner().msg_try_into_obj(msg),
+            CountdownMsg::TimedOut => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
 impl<T> ComponentMsgObj for PinKeyboard<T>
 where
     T: AsRef<str>,
@@ -208,6 +265,20 @@ where
     }
 }
 
+impl<T, U> ComponentMsgObj for TermsPage<T, U>
+where
+    T: Component + Paginate,
+    U: AsRef<str> + From<&'static str>,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            PageMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+            PageMsg::Cancelled => Ok(CANCELLED.as_obj()),
+            _ => Err(Error::TypeError),
+        }
+    }
+}
+
 impl<F> ComponentMsgObj for painter::Painter<F>
 where
     F: FnMut(geometry::Rect),
@@ -247,6 +318,24 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for IconList<T>
+where
+    T: ParagraphSource,
+{
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
+impl<T> ComponentMsgObj for ShareGroups<T>
+where
+    T: ComponentMsgObj,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        self.inner().msg_try_into_obj(msg)
+    }
+}
+
 impl<T, F> ComponentMsgObj for NumberInputDialog<T, F>
 where
     T: StringType,
@@ -279,6 +368,24 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for PinLockout<T>
+where
+    T: StringType,
+{
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
+impl<T> ComponentMsgObj for CancellableProgress<T>
+where
+    T: StringType,
+{
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        Ok(CANCELLED.as_obj())
+    }
+}
+
 impl<T> ComponentMsgObj for Homescreen<T>
 where
     T: AsRef<str>,
@@ -286,10 +393,25 @@ where
     fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
         match msg {
             HomescreenMsg::Dismissed => Ok(CANCELLED.as_obj()),
+            HomescreenMsg::Settings => Ok(INFO.as_obj()),
         }
     }
 }
 
+impl ComponentMsgObj for SettingsMenu {
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        let mut changed = heapless::Vec::<Obj, MAX_SETTINGS>::new();
+        for setting in self.settings().iter().filter(|s| s.is_changed()) {
+            let name: Obj = setting.kind.name().try_into()?;
+            let value: Obj = setting.value.try_into()?;
+            unwrap!(changed.push((name, value).try_into()?));
+        }
+        let changed: Obj = List::from_iter(changed)?.into();
+        let rename: Obj = matches!(msg, SettingsMenuMsg::Rename).into();
+        (changed, rename).try_into()
+    }
+}
+
 impl<T> ComponentMsgObj for Lockscreen<T>
 where
     T: AsRef<str>,
@@ -297,6 +419,7 @@ where
     fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
         match msg {
             HomescreenMsg::Dismissed => Ok(CANCELLED.as_obj()),
+            HomescreenMsg::Settings => Ok(INFO.as_obj()),
         }
     }
 }
@@ -322,7 +445,39 @@ where
     }
 }
 
-impl ComponentMsgObj for Qr {
+impl<T> ComponentMsgObj for EntropyMixer<T>
+where
+    T: AsRef<str>,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            EntropyMixerMsg::Done => self.input().try_into(),
+        }
+    }
+}
+
+impl<S> ComponentMsgObj for Wizard<S, Obj>
+where
+    S: WizardSteps<Obj>,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            WizardMsg::Completed => {
+                let results = self
+                    .results()
+                    .iter()
+                    .map(|result| result.unwrap_or(Obj::const_none()));
+                Ok(List::from_iter(results)?.into())
+            }
+            WizardMsg::Cancelled => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
+impl<T> ComponentMsgObj for Qr<T>
+where
+    T: AsRef<str>,
+{
     fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
         unreachable!();
     }
@@ -350,6 +505,28 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for KeyDetails<T>
+where
+    T: StringType + Clone,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            KeyDetailsMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+            KeyDetailsMsg::Cancelled => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
+impl ComponentMsgObj for StreamedConfirm {
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            StreamedConfirmMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+            StreamedConfirmMsg::Cancelled => Ok(CANCELLED.as_obj()),
+            StreamedConfirmMsg::More => Ok(MORE.as_obj()),
+        }
+    }
+}
+
 impl<T, U> ComponentMsgObj for CoinJoinProgress<T, U>
 where
     T: AsRef<str>,
@@ -374,10 +551,10 @@ where
 
 extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let action: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_action)?.try_into_option()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let action: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_action)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
         let verb: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb)
             .unwrap_or_else(|_| Obj::const_none())
@@ -389,6 +566,7 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
         let reverse: bool = kwargs.get_or(Qstr::MP_QSTR_reverse, false)?;
         let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
         let hold_danger: bool = kwargs.get_or(Qstr::MP_QSTR_hold_danger, false)?;
+        let destructive: bool = kwargs.get_or(Qstr::MP_QSTR_destructive, false)?;
 
         let paragraphs = {
             let action = action.unwrap_or_default();
@@ -406,14 +584,85 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
             paragraphs.into_paragraphs()
         };
 
-        let mut page = if hold {
-            ButtonPage::new(paragraphs, theme::BG).with_hold()
+        let buttons = if hold {
+            ConfirmButtons::HoldToConfirm
         } else {
-            ButtonPage::new(paragraphs, theme::BG).with_cancel_confirm(verb_cancel, verb)
+            ConfirmButtons::CancelConfirm(verb_cancel, verb)
         };
-        if hold && hold_danger {
-            page = page.with_confirm_style(theme::button_danger())
+        let mut screen = SimpleConfirm::new(title, paragraphs, buttons);
+        if (hold && hold_danger) || destructive {
+            screen = screen.with_intent(ButtonIntent::Destructive)
         }
+        screen.into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_blind_signing(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs
+            .get_or(Qstr::MP_QSTR_title, Obj::const_none())?
+            .try_into_option()?
+            .unwrap_or_else(|| TranslatedString::TitleBlindSigning.into());
+        let description: StrBuffer =
+            kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
+
+        let warning: StrBuffer = TranslatedString::WarningBlindSigning.into();
+        let mut paragraphs = ParagraphVecShort::new();
+        paragraphs
+            .add(Paragraph::new(&theme::TEXT_DEMIBOLD, warning))
+            .add(Paragraph::new(&theme::TEXT_NORMAL, description));
+        let page = ButtonPage::new(paragraphs.into_paragraphs(), theme::BG)
+            .with_header(title, Some(theme::ICON_WARN))
+            .with_hold()
+            .with_confirm_intent(ButtonIntent::Destructive);
+        let obj = LayoutObj::new(
+            Border::new(theme::borders(), page).with_stripes(theme::WARN_COLOR, theme::BG),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_language(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = |_args: &[Obj], kwargs: &Map| {
+        let version: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_version)?;
+        let signed: bool = kwargs.get_field(Qstr::MP_QSTR_signed)?;
+        let language =
+            translations::language().ok_or_else(|| value_error!("No translations in use."))?;
+
+        // All the strings come from the translations being confirmed, so that
+        // the screen is a preview of them.
+        let title: StrBuffer = TranslatedString::TitleChangeLanguage.into();
+        let signature = if signed {
+            Paragraph::new(&theme::TEXT_NORMAL, TranslatedString::TextSigned.into())
+        } else {
+            Paragraph::new(
+                &theme::TEXT_DEMIBOLD,
+                TranslatedString::WarningNotSigned.into(),
+            )
+        };
+        let mut paragraphs = ParagraphVecShort::new();
+        paragraphs
+            .add(Paragraph::new(
+                &theme::TEXT_NORMAL,
+                TranslatedString::TextLanguage.into(),
+            ))
+            .add(Paragraph::new(
+                &theme::TEXT_MONO,
+                StrBuffer::alloc(language)?,
+            ))
+            .add(Paragraph::new(
+                &theme::TEXT_NORMAL,
+                TranslatedString::TextVersion.into(),
+            ))
+            .add(Paragraph::new(&theme::TEXT_MONO, version))
+            .add(signature);
+        let page: ButtonPage<_, StrBuffer> =
+            ButtonPage::new(paragraphs.into_paragraphs(), theme::BG).with_cancel_confirm(
+                Some(TranslatedString::ButtonCancel.into()),
+                Some(TranslatedString::ButtonConfirm.into()),
+            );
         let obj = LayoutObj::new(Frame::left_aligned(theme::label_title(), title, page))?;
         Ok(obj.into())
     };
@@ -422,7 +671,7 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_confirm_emphasized(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let verb: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb)
             .unwrap_or_else(|_| Obj::const_none())
@@ -444,13 +693,12 @@ extern "C" fn new_confirm_emphasized(n_args: usize, args: *const Obj, kwargs: *m
             }
         }
 
-        let obj = LayoutObj::new(Frame::left_aligned(
-            theme::label_title(),
+        SimpleConfirm::new(
             title,
-            ButtonPage::new(FormattedText::new(ops).vertically_centered(), theme::BG)
-                .with_cancel_confirm(None, verb),
-        ))?;
-        Ok(obj.into())
+            FormattedText::new(ops).vertically_centered(),
+            ConfirmButtons::CancelConfirm(None, verb),
+        )
+        .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -466,6 +714,7 @@ struct ConfirmBlobParams {
     info_button: bool,
     hold: bool,
     chunkify: bool,
+    coin: Option<&'static CoinIcon>,
 }
 
 impl ConfirmBlobParams {
@@ -488,6 +737,7 @@ impl ConfirmBlobParams {
             info_button: false,
             hold,
             chunkify: false,
+            coin: None,
         }
     }
 
@@ -511,6 +761,11 @@ impl ConfirmBlobParams {
         self
     }
 
+    fn with_coin(mut self, coin: Option<&'static CoinIcon>) -> Self {
+        self.coin = coin;
+        self
+    }
+
     fn into_layout(self) -> Result<Obj, Error> {
         let paragraphs = ConfirmBlob {
             description: self.description.unwrap_or_else(StrBuffer::empty),
@@ -527,32 +782,152 @@ impl ConfirmBlobParams {
         }
         .into_paragraphs();
 
-        let mut page = ButtonPage::new(paragraphs, theme::BG);
+        let mut page = ButtonPage::new(paragraphs, theme::BG).with_page_counter();
         if let Some(verb) = self.verb {
             page = page.with_cancel_confirm(self.verb_cancel, Some(verb))
         }
         if self.hold {
             page = page.with_hold()
         }
+        if self.subtitle.is_none() && self.coin.is_none() && !self.info_button {
+            // Nothing else for the frame to show, the title is pinned above the
+            // pages of the data instead.
+            let page = page.with_header(self.title, None);
+            let obj = LayoutObj::new(Border::new(theme::borders(), page))?;
+            return Ok(obj.into());
+        }
         let mut frame = Frame::left_aligned(theme::label_title(), self.title, page);
         if let Some(subtitle) = self.subtitle {
             frame = frame.with_subtitle(theme::label_subtitle(), subtitle);
         }
+        if let Some(coin) = self.coin {
+            frame = frame.with_coin(coin);
+        }
+        if self.info_button {
+            frame = frame.with_info_button();
+        }
+        frame
+    }
+
+    fn into_layout(self) -> Result<Obj, Error> {
+        let obj = LayoutObj::new(self.into_frame())?;
+        Ok(obj.into())
+    }
+}
+
+/// How a simple confirm screen is confirmed.
+enum ConfirmButtons {
+    /// Cancel and confirm buttons, icons unless there are texts for them.
+    CancelConfirm(Option<StrBuffer>, Option<StrBuffer>),
+    HoldToConfirm,
+}
+
+/// Title, body and buttons of a simple confirm screen, which is always
+/// composed the same way: a `ButtonPage` in a left-aligned `Frame`.
+struct SimpleConfirm<T> {
+    title: StrBuffer,
+    body: T,
+    buttons: ConfirmButtons,
+    intent: Option<ButtonIntent>,
+    back_button: bool,
+    cancel_arrow: bool,
+    info_button: bool,
+    coin: Option<&'static CoinIcon>,
+}
+
+impl<T> SimpleConfirm<T>
+where
+    T: Component + Paginate + MaybeTrace + 'static,
+{
+    fn new(title: StrBuffer, body: T, buttons: ConfirmButtons) -> Self {
+        Self {
+            title,
+            body,
+            buttons,
+            intent: None,
+            back_button: false,
+            cancel_arrow: false,
+            info_button: false,
+            coin: None,
+        }
+    }
+
+    fn with_intent(mut self, intent: ButtonIntent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+
+    /// Cancel from any page with the back button.
+    fn with_back_button(mut self) -> Self {
+        self.back_button = true;
+        self
+    }
+
+    fn with_cancel_arrow(mut self, cancel_arrow: bool) -> Self {
+        self.cancel_arrow = cancel_arrow;
+        self
+    }
+
+    /// Info button in the title, swiping left does the same.
+    fn with_info_button(mut self, info_button: bool) -> Self {
+        self.info_button = info_button;
+        self
+    }
+
+    fn with_coin(mut self, coin: Option<&'static CoinIcon>) -> Self {
+        self.coin = coin;
+        self
+    }
+
+    fn into_frame(self) -> Frame<ButtonPage<T, StrBuffer>, StrBuffer> {
+        let mut page = ButtonPage::new(self.body, theme::BG).with_page_counter();
+        page = match self.buttons {
+            ConfirmButtons::CancelConfirm(cancel, confirm) => {
+                page.with_cancel_confirm(cancel, confirm)
+            }
+            ConfirmButtons::HoldToConfirm => page.with_hold(),
+        };
+        if let Some(intent) = self.intent {
+            page = page.with_confirm_intent(intent);
+        }
+        if self.back_button {
+            page = page.with_back_button();
+        }
+        if self.cancel_arrow {
+            page = page.with_cancel_arrow();
+        }
+        if self.info_button {
+            page = page.with_swipe_left();
+        }
+        let mut frame = Frame::left_aligned(theme::label_title(), self.title, page);
+        if let Some(coin) = self.coin {
+            frame = frame.with_coin(coin);
+        }
         if self.info_button {
             frame = frame.with_info_button();
         }
-        let obj = LayoutObj::new(frame)?;
+        frame
+    }
+
+    fn into_layout(self) -> Result<Obj, Error> {
+        let obj = LayoutObj::new(self.into_frame())?;
         Ok(obj.into())
     }
 }
 
+/// Icon of the coin given in the optional `coin` argument of a layout.
+fn get_coin_icon(kwargs: &Map) -> Result<Option<&'static CoinIcon>, Error> {
+    let coin: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_coin)?;
+    Ok(coin.and_then(|coin| coin_icons::find(coin.as_ref())))
+}
+
 extern "C" fn new_confirm_blob(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let data: Obj = kwargs.get(Qstr::MP_QSTR_data)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
-        let extra: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_extra)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
+        let extra: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_extra)?;
         let verb: Option<StrBuffer> = kwargs
             .get(Qstr::MP_QSTR_verb)
             .unwrap_or_else(|_| Obj::const_none())
@@ -574,11 +949,11 @@ extern "C" fn new_confirm_blob(n_args: usize, args: *const Obj, kwargs: *mut Map
 
 extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
         let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "CONFIRM".into())?;
-        let extra: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_extra)?.try_into_option()?;
+        let extra: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_extra)?;
         let data: Obj = kwargs.get(Qstr::MP_QSTR_data)?;
         let chunkify: bool = kwargs.get_or(Qstr::MP_QSTR_chunkify, false)?;
 
@@ -614,16 +989,49 @@ extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_confirm_terms(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let text: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_text)?;
+        let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, "ACCEPT".into())?;
+
+        let paragraphs = Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, text));
+        let obj = LayoutObj::new(Frame::left_aligned(
+            theme::label_title(),
+            title,
+            TermsPage::new(paragraphs, verb),
+        ))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_streamed(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let obj = LayoutObj::new(Frame::left_aligned(
+            theme::label_title(),
+            title,
+            StreamedConfirm::new(),
+        ))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
+        // Texts in the proportional font take less room in the compact density.
         let paragraphs = PropsList::new(
             items,
             &theme::TEXT_NORMAL,
-            &theme::TEXT_MONO,
+            settings()
+                .density
+                .choose(&theme::TEXT_MONO, &theme::TEXT_NORMAL),
             &theme::TEXT_MONO,
         )?;
         let page: ButtonPage<_, StrBuffer> = if hold {
@@ -638,9 +1046,45 @@ extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *m
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_confirm_template(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let template = get_template(kwargs)?;
+        let rows = template
+            .rows(
+                &theme::TEXT_NORMAL,
+                settings()
+                    .density
+                    .choose(&theme::TEXT_MONO, &theme::TEXT_NORMAL),
+            )
+            .into_paragraphs();
+        let buttons =
+            ConfirmButtons::CancelConfirm(Some(template.verb_cancel()), Some(template.verb()));
+        let mut screen = SimpleConfirm::new(template.title(), rows, buttons);
+        if template.warning() {
+            screen = screen.with_intent(ButtonIntent::Destructive);
+        }
+        screen.into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_confirm_payment_uri(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_title, "PAYMENT REQUEST".into())?;
+        let request = get_payment_request(kwargs, &theme::TEXT_NORMAL, &theme::TEXT_MONO)?;
+        SimpleConfirm::new(
+            title,
+            request.into_paragraphs(),
+            ConfirmButtons::CancelConfirm(None, Some("CONFIRM".into())),
+        )
+        .into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_homescreen(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let data: Obj = kwargs.get(Qstr::MP_QSTR_image)?;
 
         // Layout needs to hold the Obj to play nice with GC. Obj is resolved to &[u8]
@@ -677,8 +1121,8 @@ extern "C" fn new_confirm_homescreen(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_confirm_reset_device(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(
@@ -692,7 +1136,7 @@ extern "C" fn new_confirm_reset_device(n_args: usize, args: *const Obj, kwargs:
         ]);
         let buttons = Button::cancel_confirm(
             Button::with_icon(theme::ICON_CANCEL),
-            Button::with_text(button).styled(theme::button_confirm()),
+            Button::with_text(button).with_intent(ButtonIntent::Confirm),
             true,
         );
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -707,12 +1151,14 @@ extern "C" fn new_confirm_reset_device(n_args: usize, args: *const Obj, kwargs:
 
 extern "C" fn new_show_address_details(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let qr_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_qr_title)?.try_into()?;
-        let details_title: StrBuffer = kwargs.get(Qstr::MP_QSTR_details_title)?.try_into()?;
-        let address: StrBuffer = kwargs.get(Qstr::MP_QSTR_address)?.try_into()?;
-        let case_sensitive: bool = kwargs.get(Qstr::MP_QSTR_case_sensitive)?.try_into()?;
-        let account: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_account)?.try_into_option()?;
-        let path: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_path)?.try_into_option()?;
+        // Strings are borrowed from the Python objects, the address is not
+        // copied into the QR code component.
+        let qr_title: BorrowedStr = kwargs.get_field(Qstr::MP_QSTR_qr_title)?;
+        let details_title: BorrowedStr = kwargs.get_field(Qstr::MP_QSTR_details_title)?;
+        let address: BorrowedStr = kwargs.get_field(Qstr::MP_QSTR_address)?;
+        let case_sensitive: bool = kwargs.get_field(Qstr::MP_QSTR_case_sensitive)?;
+        let account: Option<BorrowedStr> = kwargs.get_optional_field(Qstr::MP_QSTR_account)?;
+        let path: Option<BorrowedStr> = kwargs.get_optional_field(Qstr::MP_QSTR_path)?;
 
         let xpubs: Obj = kwargs.get(Qstr::MP_QSTR_xpubs)?;
 
@@ -726,7 +1172,7 @@ extern "C" fn new_show_address_details(n_args: usize, args: *const Obj, kwargs:
         )?;
 
         for i in IterBuf::new().try_iterate(xpubs)? {
-            let [xtitle, text]: [StrBuffer; 2] = iter_into_array(i)?;
+            let [xtitle, text]: [BorrowedStr; 2] = iter_into_array(i)?;
             ad.add_xpub(xtitle, text)?;
         }
 
@@ -737,9 +1183,31 @@ extern "C" fn new_show_address_details(n_args: usize, args: *const Obj, kwargs:
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_pubkey_details(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let pubkey: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_pubkey)?;
+        let fingerprint: Option<StrBuffer> = kwargs
+            .get_or(Qstr::MP_QSTR_fingerprint, Obj::const_none())?
+            .try_into_option()?;
+        let account: Option<StrBuffer> = kwargs
+            .get_or(Qstr::MP_QSTR_account, Obj::const_none())?
+            .try_into_option()?;
+        let path: Option<StrBuffer> = kwargs
+            .get_or(Qstr::MP_QSTR_path, Obj::const_none())?
+            .try_into_option()?;
+        let path_unusual: bool = kwargs.get_or(Qstr::MP_QSTR_path_unusual, false)?;
+
+        let details = KeyDetails::new(title, pubkey, fingerprint, account, path, path_unusual)?;
+        let obj = LayoutObj::new(SimplePage::horizontal(details, theme::BG))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_info_with_cancel(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
         let horizontal: bool = kwargs.get_or(Qstr::MP_QSTR_horizontal, false)?;
 
@@ -750,7 +1218,7 @@ extern "C" fn new_show_info_with_cancel(n_args: usize, args: *const Obj, kwargs:
             let key: StrBuffer = key.try_into()?;
             let value: StrBuffer = value.try_into()?;
             paragraphs.add(Paragraph::new(&theme::TEXT_NORMAL, key).no_break());
-            paragraphs.add(Paragraph::new(&theme::TEXT_MONO, value));
+            paragraphs.add(Paragraph::new(&theme::TEXT_MONO, value).keep_whole());
         }
 
         let axis = match horizontal {
@@ -774,10 +1242,10 @@ extern "C" fn new_show_info_with_cancel(n_args: usize, args: *const Obj, kwargs:
 
 extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let subtitle: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_subtitle)?.try_into_option()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let subtitle: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_subtitle)?;
         let description: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_description)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_description)?;
         let value: Obj = kwargs.get(Qstr::MP_QSTR_value)?;
         let info_button: bool = kwargs.get_or(Qstr::MP_QSTR_info_button, false)?;
 
@@ -791,11 +1259,13 @@ extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Ma
             .try_into_option()?;
         let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
         let chunkify: bool = kwargs.get_or(Qstr::MP_QSTR_chunkify, false)?;
+        let coin = get_coin_icon(kwargs)?;
 
         ConfirmBlobParams::new(title, value, description, verb, verb_cancel, hold)
             .with_subtitle(subtitle)
             .with_info_button(info_button)
             .with_chunkify(chunkify)
+            .with_coin(coin)
             .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -803,41 +1273,37 @@ extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_confirm_total(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
         let info_button: bool = kwargs.get_or(Qstr::MP_QSTR_info_button, false)?;
         let cancel_arrow: bool = kwargs.get_or(Qstr::MP_QSTR_cancel_arrow, false)?;
+        let coin = get_coin_icon(kwargs)?;
 
         let mut paragraphs = ParagraphVecShort::new();
 
         for pair in IterBuf::new().try_iterate(items)? {
             let [label, value]: [StrBuffer; 2] = iter_into_array(pair)?;
             paragraphs.add(Paragraph::new(&theme::TEXT_NORMAL, label).no_break());
-            paragraphs.add(Paragraph::new(&theme::TEXT_MONO, value));
-        }
-        let mut page: ButtonPage<_, StrBuffer> =
-            ButtonPage::new(paragraphs.into_paragraphs(), theme::BG).with_hold();
-        if cancel_arrow {
-            page = page.with_cancel_arrow()
-        }
-        if info_button {
-            page = page.with_swipe_left();
-        }
-        let mut frame = Frame::left_aligned(theme::label_title(), title, page);
-        if info_button {
-            frame = frame.with_info_button();
+            paragraphs.add(Paragraph::new(&theme::TEXT_MONO, value).keep_whole());
         }
-        let obj = LayoutObj::new(frame)?;
-        Ok(obj.into())
+        SimpleConfirm::new(
+            title,
+            paragraphs.into_paragraphs(),
+            ConfirmButtons::HoldToConfirm,
+        )
+        .with_cancel_arrow(cancel_arrow)
+        .with_info_button(info_button)
+        .with_coin(coin)
+        .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let sign: i32 = kwargs.get(Qstr::MP_QSTR_sign)?.try_into()?;
-        let amount_change: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_change)?.try_into()?;
-        let amount_new: StrBuffer = kwargs.get(Qstr::MP_QSTR_amount_new)?.try_into()?;
+        let sign: i32 = kwargs.get_field(Qstr::MP_QSTR_sign)?;
+        let amount_change: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_change)?;
+        let amount_new: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_amount_new)?;
 
         let description = if sign < 0 {
             "Decrease amount by:"
@@ -847,28 +1313,27 @@ extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs:
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_NORMAL, description.into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_change),
-            Paragraph::new(&theme::TEXT_NORMAL, "New amount:".into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_new),
-        ]);
-
-        let obj = LayoutObj::new(Frame::left_aligned(
-            theme::label_title(),
-            "MODIFY AMOUNT",
-            ButtonPage::<_, StrBuffer>::new(paragraphs, theme::BG)
-                .with_cancel_confirm(Some("^".into()), Some("CONTINUE".into())),
-        ))?;
-        Ok(obj.into())
+            Paragraph::new(&theme::TEXT_MONO, amount_change).keep_whole(),
+            Paragraph::new(&theme::TEXT_NORMAL, "New amount:".into()),
+            Paragraph::new(&theme::TEXT_MONO, amount_new).keep_whole(),
+        ]);
+
+        SimpleConfirm::new(
+            "MODIFY AMOUNT".into(),
+            paragraphs,
+            ConfirmButtons::CancelConfirm(Some("^".into()), Some("CONTINUE".into())),
+        )
+        .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let sign: i32 = kwargs.get(Qstr::MP_QSTR_sign)?.try_into()?;
-        let user_fee_change: StrBuffer = kwargs.get(Qstr::MP_QSTR_user_fee_change)?.try_into()?;
-        let total_fee_new: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_fee_new)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let sign: i32 = kwargs.get_field(Qstr::MP_QSTR_sign)?;
+        let user_fee_change: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_user_fee_change)?;
+        let total_fee_new: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_total_fee_new)?;
 
         let (description, change, total_label) = match sign {
             s if s < 0 => ("Decrease fee by:", user_fee_change, "New transaction fee:"),
@@ -882,22 +1347,14 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_NORMAL, description.into()),
-            Paragraph::new(&theme::TEXT_MONO, change),
+            Paragraph::new(&theme::TEXT_MONO, change).keep_whole(),
             Paragraph::new(&theme::TEXT_NORMAL, total_label.into()),
-            Paragraph::new(&theme::TEXT_MONO, total_fee_new),
+            Paragraph::new(&theme::TEXT_MONO, total_fee_new).keep_whole(),
         ]);
 
-        let obj = LayoutObj::new(
-            Frame::left_aligned(
-                theme::label_title(),
-                title,
-                ButtonPage::<_, StrBuffer>::new(paragraphs, theme::BG)
-                    .with_hold()
-                    .with_swipe_left(),
-            )
-            .with_info_button(),
-        )?;
-        Ok(obj.into())
+        SimpleConfirm::new(title, paragraphs, ConfirmButtons::HoldToConfirm)
+            .with_info_button(true)
+            .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -905,9 +1362,9 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
 fn new_show_modal(
     kwargs: &Map,
     icon: BlendedImage,
-    button_style: ButtonStyleSheet,
+    button_intent: ButtonIntent,
 ) -> Result<Obj, Error> {
-    let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+    let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
     let value: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_value, StrBuffer::empty())?;
     let description: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
     let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, "CONTINUE".into())?;
@@ -944,7 +1401,7 @@ fn new_show_modal(
                 title,
                 Button::cancel_confirm(
                     Button::with_icon(theme::ICON_CANCEL),
-                    Button::with_text(button).styled(button_style),
+                    Button::with_text(button).with_intent(button_intent),
                     false,
                 ),
             )
@@ -958,9 +1415,9 @@ fn new_show_modal(
             IconDialog::new(
                 icon,
                 title,
-                theme::button_bar(Button::with_text(button).styled(button_style).map(|msg| {
-                    (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
-                })),
+                theme::button_bar(Button::with_text(button).with_intent(button_intent).map(
+                    |msg| (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed),
+                )),
             )
             .with_value(value)
             .with_description(description),
@@ -980,17 +1437,18 @@ extern "C" fn new_show_error(n_args: usize, args: *const Obj, kwargs: *mut Map)
             theme::FG,
             theme::BG,
         );
-        new_show_modal(kwargs, icon, theme::button_default())
+        new_show_modal(kwargs, icon, ButtonIntent::Neutral)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_fido(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let app_name: StrBuffer = kwargs.get(Qstr::MP_QSTR_app_name)?.try_into()?;
-        let icon: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_icon_name)?.try_into_option()?;
-        let accounts: Gc<List> = kwargs.get(Qstr::MP_QSTR_accounts)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let app_name: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_app_name)?;
+        let icon: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_icon_name)?;
+        let accounts: Gc<List> = kwargs.get_field(Qstr::MP_QSTR_accounts)?;
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
 
         // Cache the page count so that we can move `accounts` into the closure.
         let page_count = accounts.len();
@@ -1004,14 +1462,20 @@ extern "C" fn new_confirm_fido(n_args: usize, args: *const Obj, kwargs: *mut Map
 
         let controls = Button::cancel_confirm(
             Button::with_icon(theme::ICON_CANCEL),
-            Button::with_text("CONFIRM").styled(theme::button_confirm()),
+            Button::with_text("CONFIRM").with_intent(ButtonIntent::Confirm),
             true,
         );
 
         let fido_page = FidoConfirm::new(app_name, get_page, page_count, icon, controls);
 
-        let obj = LayoutObj::new(Frame::centered(theme::label_title(), title, fido_page))?;
-        Ok(obj.into())
+        let frame = Frame::centered(theme::label_title(), title, fido_page);
+        let obj = if time_ms > 0 {
+            let duration = Duration::from_millis(time_ms);
+            LayoutObj::new(Countdown::new(frame, duration, theme::TEXT_NORMAL))?.into()
+        } else {
+            LayoutObj::new(frame)?.into()
+        };
+        Ok(obj)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -1025,7 +1489,7 @@ extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map
             theme::FG,
             theme::BG,
         );
-        new_show_modal(kwargs, icon, theme::button_reset())
+        new_show_modal(kwargs, icon, ButtonIntent::Caution)
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -1039,7 +1503,66 @@ extern "C" fn new_show_success(n_args: usize, args: *const Obj, kwargs: *mut Map
             theme::FG,
             theme::BG,
         );
-        new_show_modal(kwargs, icon, theme::button_confirm())
+        new_show_modal(kwargs, icon, ButtonIntent::Confirm)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_result(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let success: bool = kwargs.get_field(Qstr::MP_QSTR_success)?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let detail: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_detail, StrBuffer::empty())?;
+        let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, StrBuffer::empty())?;
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
+        let rejected: bool = kwargs.get_or(Qstr::MP_QSTR_rejected, false)?;
+
+        let mut screen = StatusScreen::new(success, title)
+            .with_detail(detail)
+            .with_button(button)
+            .with_timeout(time_ms);
+        if rejected {
+            screen = screen.with_feedback(Feedback::Reject);
+        }
+        let obj = LayoutObj::new(screen)?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_entropy_mixing(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let seed: Obj = kwargs.get(Qstr::MP_QSTR_seed)?;
+        let time_ms: u32 = kwargs.get_field(Qstr::MP_QSTR_time_ms)?;
+
+        let seed = unsafe { get_buffer(seed) }?;
+        let duration = Duration::from_millis(time_ms);
+        let mixer = EntropyMixer::new(seed, description, duration, theme::TEXT_NORMAL);
+        let obj = LayoutObj::new(Frame::left_aligned(theme::label_title(), title, mixer))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_settings_menu(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let items: Obj = kwargs.get_field(Qstr::MP_QSTR_settings)?;
+
+        let mut settings = heapless::Vec::new();
+        for item in IterBuf::new().try_iterate(items)? {
+            let [name, value]: [Obj; 2] = iter_into_array(item)?;
+            let name: StrBuffer = name.try_into()?;
+            let kind =
+                SettingKind::from_name(name.as_ref()).ok_or(value_error!("Unknown setting."))?;
+            settings
+                .push(Setting::new(kind, value.try_into()?))
+                .map_err(|_| value_error!("Too many settings."))?;
+        }
+
+        let obj = LayoutObj::new(SettingsMenu::new(settings))?;
+        Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
@@ -1060,7 +1583,7 @@ extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -
 
 extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let description: StrBuffer = "Please contact Trezor support at".into();
         let url: StrBuffer = "trezor.io/support".into();
         let button = "QUIT";
@@ -1078,7 +1601,7 @@ extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Ma
                 title,
                 Button::cancel_confirm(
                     Button::with_icon(theme::ICON_BACK),
-                    Button::with_text(button).styled(theme::button_reset()),
+                    Button::with_text(button).with_intent(ButtonIntent::Caution),
                     true,
                 ),
             )
@@ -1100,7 +1623,7 @@ extern "C" fn new_show_mismatch(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_show_simple(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: Option<StrBuffer> = kwargs.get(Qstr::MP_QSTR_title)?.try_into_option()?;
+        let title: Option<StrBuffer> = kwargs.get_optional_field(Qstr::MP_QSTR_title)?;
         let description: StrBuffer =
             kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
         let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, StrBuffer::empty())?;
@@ -1148,9 +1671,9 @@ extern "C" fn new_show_simple(n_args: usize, args: *const Obj, kwargs: *mut Map)
 
 extern "C" fn new_confirm_with_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let info_button: StrBuffer = kwargs.get(Qstr::MP_QSTR_info_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let info_button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_info_button)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecShort::new();
@@ -1179,8 +1702,8 @@ extern "C" fn new_confirm_with_info(n_args: usize, args: *const Obj, kwargs: *mu
 
 extern "C" fn new_confirm_more(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecLong::new();
@@ -1192,23 +1715,131 @@ extern "C" fn new_confirm_more(n_args: usize, args: *const Obj, kwargs: *mut Map
             paragraphs.add(Paragraph::new(style, text));
         }
 
-        let obj = LayoutObj::new(Frame::left_aligned(
-            theme::label_title(),
+        SimpleConfirm::new(
             title,
-            ButtonPage::new(paragraphs.into_paragraphs(), theme::BG)
-                .with_cancel_confirm(None, Some(button))
-                .with_confirm_style(theme::button_default())
-                .with_back_button(),
-        ))?;
-        Ok(obj.into())
+            paragraphs.into_paragraphs(),
+            ConfirmButtons::CancelConfirm(None, Some(button)),
+        )
+        .with_intent(ButtonIntent::Neutral)
+        .with_back_button()
+        .into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_blob(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let data: ObjBytes = kwargs.get_field(Qstr::MP_QSTR_data)?;
+        let base64: bool = kwargs.get_or(Qstr::MP_QSTR_base64, false)?;
+        let verb: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_verb, StrBuffer::empty())?;
+        let total: usize = kwargs.get_or(Qstr::MP_QSTR_total, 0)?;
+
+        let format = if base64 {
+            BlobFormat::Base64
+        } else {
+            BlobFormat::Hex
+        };
+        let view = BlobView::new(data, format, theme::TEXT_MONO).with_total(total);
+        // The info button switches between hex and base64.
+        let confirm = if verb.as_ref().is_empty() {
+            SimpleConfirm::new(
+                title,
+                view,
+                ConfirmButtons::CancelConfirm(None, Some("CLOSE".into())),
+            )
+            .with_intent(ButtonIntent::Neutral)
+        } else {
+            SimpleConfirm::new(title, view, ConfirmButtons::CancelConfirm(None, Some(verb)))
+        };
+        confirm
+            .with_back_button()
+            .with_info_button(true)
+            .into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_receipt(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let fingerprint: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fingerprint)?;
+        let qr_data: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_qr_data)?;
+        let qr: bool = kwargs.get_or(Qstr::MP_QSTR_qr, false)?;
+
+        if qr {
+            let obj = LayoutObj::new(
+                Frame::left_aligned(
+                    theme::label_title(),
+                    title,
+                    Qr::new(qr_data, true)?.with_border(7),
+                )
+                .with_cancel_button(),
+            )?;
+            return Ok(obj.into());
+        }
+        receipt(title, description, fingerprint).into_layout()
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+/// First page of `show_receipt`, the info button shows the whole of `qr_data`
+/// as a QR code.
+fn receipt(
+    title: StrBuffer,
+    description: StrBuffer,
+    fingerprint: StrBuffer,
+) -> SimpleConfirm<Paragraphs<[Paragraph<StrBuffer>; 2]>> {
+    SimpleConfirm::new(
+        title,
+        Paragraphs::new([
+            Paragraph::new(&theme::TEXT_NORMAL, description),
+            Paragraph::new(&theme::TEXT_MONO, fingerprint),
+        ]),
+        ConfirmButtons::CancelConfirm(None, Some("DONE".into())),
+    )
+    .with_intent(ButtonIntent::Neutral)
+    .with_info_button(true)
+}
+
+extern "C" fn new_confirm_settings_change(
+    n_args: usize,
+    args: *const Obj,
+    kwargs: *mut Map,
+) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let changes: Obj = kwargs.get(Qstr::MP_QSTR_changes)?;
+        let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
+
+        let diff = SettingsDiff::new(
+            get_setting_changes(changes)?,
+            theme::TEXT_NORMAL,
+            theme::TEXT_DEMIBOLD,
+            theme::TEXT_SETTING_RISKY,
+            theme::ICON_LIST_CURRENT,
+        );
+        if hold {
+            SimpleConfirm::new(title, diff, ConfirmButtons::HoldToConfirm)
+                .with_intent(ButtonIntent::Caution)
+                .into_layout()
+        } else {
+            SimpleConfirm::new(
+                title,
+                diff,
+                ConfirmButtons::CancelConfirm(None, Some("CONFIRM".into())),
+            )
+            .into_layout()
+        }
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let max_rounds: StrBuffer = kwargs.get(Qstr::MP_QSTR_max_rounds)?.try_into()?;
-        let max_feerate: StrBuffer = kwargs.get(Qstr::MP_QSTR_max_feerate)?.try_into()?;
+        let max_rounds: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_max_rounds)?;
+        let max_feerate: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_max_feerate)?;
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_NORMAL, "Max rounds".into()),
@@ -1217,20 +1848,20 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
             Paragraph::new(&theme::TEXT_MONO, max_feerate),
         ]);
 
-        let obj = LayoutObj::new(Frame::left_aligned(
-            theme::label_title(),
-            "AUTHORIZE COINJOIN",
-            ButtonPage::<_, StrBuffer>::new(paragraphs, theme::BG).with_hold(),
-        ))?;
-        Ok(obj.into())
+        SimpleConfirm::new(
+            "AUTHORIZE COINJOIN".into(),
+            paragraphs,
+            ConfirmButtons::HoldToConfirm,
+        )
+        .into_layout()
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
 extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let subprompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_subprompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let subprompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_subprompt)?;
         let allow_cancel: bool = kwargs.get_or(Qstr::MP_QSTR_allow_cancel, true)?;
         let warning: bool = kwargs.get_or(Qstr::MP_QSTR_wrong_pin, false)?;
         let warning = if warning {
@@ -1238,7 +1869,15 @@ extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map)
         } else {
             None
         };
-        let obj = LayoutObj::new(PinKeyboard::new(prompt, subprompt, warning, allow_cancel))?;
+        let shuffle: bool = kwargs.get_or(Qstr::MP_QSTR_shuffle, true)?;
+        let shuffle_on_keypress: bool = kwargs.get_or(Qstr::MP_QSTR_shuffle_on_keypress, false)?;
+        let keyboard = PinKeyboard::new(prompt, subprompt, warning, allow_cancel)
+            .with_shuffle(PinShuffle::new(shuffle, shuffle_on_keypress));
+        let obj = LayoutObj::new(ToastQueue::new(
+            keyboard,
+            theme::label_toast(),
+            theme::ICON_WARN,
+        ))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1246,9 +1885,38 @@ extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map)
 
 extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let _prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
-        let _max_len: u32 = kwargs.get(Qstr::MP_QSTR_max_len)?.try_into()?;
-        let obj = LayoutObj::new(PassphraseKeyboard::new())?;
+        let _prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let max_len: u32 = kwargs.get_field(Qstr::MP_QSTR_max_len)?;
+        let carry_in: bool = kwargs.get_or(Qstr::MP_QSTR_carry_in, false)?;
+        let carry_out: bool = kwargs.get_or(Qstr::MP_QSTR_carry_out, false)?;
+
+        let prefill = if carry_in {
+            carry::take().unwrap_or_else(SecretString::new)
+        } else {
+            SecretString::new()
+        };
+        let mut keyboard = PassphraseKeyboard::with_prefill(prefill).with_max_len(max_len as usize);
+        if carry_out {
+            keyboard = keyboard.with_carry_out();
+        }
+        let obj = LayoutObj::new(keyboard)?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_request_label(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let _prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
+        let label: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_label, StrBuffer::empty())?;
+        let max_len: u32 = kwargs.get_field(Qstr::MP_QSTR_max_len)?;
+
+        let mut prefill = SecretString::new();
+        prefill
+            .push_str(label.as_ref())
+            .map_err(|_| Error::OutOfRange)?;
+        let keyboard = PassphraseKeyboard::with_prefill(prefill).with_max_len(max_len as usize);
+        let obj = LayoutObj::new(keyboard)?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1256,7 +1924,7 @@ extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *m
 
 extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
         let obj = LayoutObj::new(MnemonicKeyboard::new(Bip39Input::new(), prompt))?;
         Ok(obj.into())
     };
@@ -1265,7 +1933,7 @@ extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
+        let prompt: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_prompt)?;
         let obj = LayoutObj::new(MnemonicKeyboard::new(Slip39Input::new(), prompt))?;
         Ok(obj.into())
     };
@@ -1274,8 +1942,8 @@ extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
         let words_iterable: Obj = kwargs.get(Qstr::MP_QSTR_words)?;
         let words: [StrBuffer; 3] = iter_into_array(words_iterable)?;
 
@@ -1290,9 +1958,51 @@ extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map)
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+/// One check of `check_share_words`. Picking the word at `correct` goes on to
+/// the next check, any other word ends them.
+fn word_check(
+    title: StrBuffer,
+    check: Obj,
+) -> Result<impl Component<Msg = StepMsg<Obj>> + MaybeTrace, Error> {
+    let [description, words, correct]: [Obj; 3] = iter_into_array(check)?;
+    let description: StrBuffer = description.try_into()?;
+    let words: [StrBuffer; 3] = iter_into_array(words)?;
+    let correct: usize = correct.try_into()?;
+
+    let paragraphs = Paragraphs::new([Paragraph::new(&theme::TEXT_DEMIBOLD, description)]);
+    let frame = Frame::left_aligned(
+        theme::label_title(),
+        title,
+        Dialog::new(paragraphs, Button::select_word(words)),
+    );
+    Ok(frame.map(move |msg| match msg {
+        FrameMsg::Content(DialogMsg::Controls(SelectWordMsg::Selected(i))) if i == correct => {
+            Some(StepMsg::Next(Obj::from(i as u8)))
+        }
+        FrameMsg::Content(DialogMsg::Controls(_)) => Some(StepMsg::Cancel),
+        _ => None,
+    }))
+}
+
+extern "C" fn new_check_share_words(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let checks: Obj = kwargs.get(Qstr::MP_QSTR_checks)?;
+        let [first, second, third]: [Obj; 3] = iter_into_array(checks)?;
+
+        let obj = LayoutObj::new(Wizard::new((
+            word_check(title.clone(), first)?,
+            word_check(title.clone(), second)?,
+            word_check(title, third)?,
+        )))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let pages: Obj = kwargs.get(Qstr::MP_QSTR_pages)?;
 
         let mut paragraphs = ParagraphVecLong::new();
@@ -1315,10 +2025,10 @@ extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut
 
 extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let min_count: u32 = kwargs.get(Qstr::MP_QSTR_min_count)?.try_into()?;
-        let max_count: u32 = kwargs.get(Qstr::MP_QSTR_max_count)?.try_into()?;
-        let count: u32 = kwargs.get(Qstr::MP_QSTR_count)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let min_count: u32 = kwargs.get_field(Qstr::MP_QSTR_min_count)?;
+        let max_count: u32 = kwargs.get_field(Qstr::MP_QSTR_max_count)?;
+        let count: u32 = kwargs.get_field(Qstr::MP_QSTR_count)?;
         let description_callback: Obj = kwargs.get(Qstr::MP_QSTR_description)?;
         assert!(description_callback != Obj::const_none());
 
@@ -1343,9 +2053,9 @@ extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let active: usize = kwargs.get(Qstr::MP_QSTR_active)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let active: usize = kwargs.get_field(Qstr::MP_QSTR_active)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut paragraphs = ParagraphVecLong::new();
@@ -1386,17 +2096,33 @@ extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut M
 
 extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
-        let dry_run: bool = kwargs.get(Qstr::MP_QSTR_dry_run)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let dry_run: bool = kwargs.get_field(Qstr::MP_QSTR_dry_run)?;
         let info_button: bool = kwargs.get_or(Qstr::MP_QSTR_info_button, false)?;
+        let share_groups: Obj = kwargs.get_or(Qstr::MP_QSTR_share_groups, Obj::const_none())?;
+
+        let mut groups = heapless::Vec::<_, MAX_GROUPS>::new();
+        if share_groups != Obj::const_none() {
+            for group in IterBuf::new().try_iterate(share_groups)? {
+                let [collected, required]: [u8; 2] = iter_into_array(group)?;
+                let progress = GroupProgress {
+                    collected,
+                    required,
+                };
+                if groups.push(progress).is_err() {
+                    break;
+                }
+            }
+        }
 
         let paragraphs = Paragraphs::new([
             Paragraph::new(&theme::TEXT_DEMIBOLD, title),
             Paragraph::new(&theme::TEXT_NORMAL, description),
         ])
         .with_spacing(theme::RECOVERY_SPACING);
+        let content = ShareGroups::new(paragraphs, groups);
 
         let notification = if dry_run {
             "BACKUP CHECK"
@@ -1409,7 +2135,7 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
                 theme::label_title(),
                 notification,
                 Dialog::new(
-                    paragraphs,
+                    content,
                     Button::cancel_info_confirm("CONTINUE", "MORE INFO"),
                 ),
             ))?
@@ -1417,7 +2143,7 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
             LayoutObj::new(Frame::left_aligned(
                 theme::label_title(),
                 notification,
-                Dialog::new(paragraphs, Button::cancel_confirm_text(None, Some(button))),
+                Dialog::new(content, Button::cancel_confirm_text(None, Some(button))),
             ))?
         };
         Ok(obj.into())
@@ -1427,7 +2153,7 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
 
 extern "C" fn new_select_word_count(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let dry_run: bool = kwargs.get(Qstr::MP_QSTR_dry_run)?.try_into()?;
+        let dry_run: bool = kwargs.get_field(Qstr::MP_QSTR_dry_run)?;
         let title = if dry_run {
             "BACKUP CHECK"
         } else {
@@ -1486,7 +2212,7 @@ extern "C" fn new_show_remaining_shares(n_args: usize, args: *const Obj, kwargs:
             "REMAINING SHARES",
             ButtonPage::<_, StrBuffer>::new(paragraphs.into_paragraphs(), theme::BG)
                 .with_cancel_confirm(None, Some("CONTINUE".into()))
-                .with_confirm_style(theme::button_default())
+                .with_confirm_intent(ButtonIntent::Neutral)
                 .without_cancel(),
         ))?;
         Ok(obj.into())
@@ -1494,21 +2220,86 @@ extern "C" fn new_show_remaining_shares(n_args: usize, args: *const Obj, kwargs:
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_check_result(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let button: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_button)?;
+        let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
+
+        let mut paragraphs = ParagraphVecShort::new();
+        let mut icons = heapless::Vec::<_, 8>::new();
+        for item in IterBuf::new().try_iterate(items)? {
+            let [passed, text]: [Obj; 2] = iter_into_array(item)?;
+            let (style, icon) = match passed.try_into_option()? {
+                Some(true) => (
+                    &theme::TEXT_NORMAL,
+                    Some((theme::ICON_LIST_CHECK, theme::GREEN)),
+                ),
+                Some(false) => (&theme::TEXT_DEMIBOLD, Some((theme::ICON_WARN, theme::RED))),
+                None => (&theme::TEXT_CHECKLIST_DEFAULT, None),
+            };
+            let text: StrBuffer = text.try_into()?;
+            if text.is_empty() {
+                continue;
+            }
+            paragraphs.add(Paragraph::new(style, text));
+            let _ = icons.push(icon);
+        }
+
+        let obj = LayoutObj::new(Frame::left_aligned(
+            theme::label_title(),
+            title,
+            Dialog::new(
+                IconList::from_paragraphs(
+                    icons,
+                    paragraphs
+                        .into_paragraphs()
+                        .with_spacing(theme::CHECKLIST_SPACING),
+                )
+                .with_icon_width(theme::CHECK_RESULT_ICON_WIDTH)
+                .with_icon_offset(theme::CHECK_RESULT_ICON_OFFSET),
+                theme::button_bar(Button::with_text(button).map(|msg| {
+                    (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
+                })),
+            ),
+        ))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let indeterminate: bool = kwargs.get_or(Qstr::MP_QSTR_indeterminate, false)?;
         let description: StrBuffer =
             kwargs.get_or(Qstr::MP_QSTR_description, StrBuffer::empty())?;
+        let show_eta: bool = kwargs.get_or(Qstr::MP_QSTR_show_eta, false)?;
+        let cancellable: bool = kwargs.get_or(Qstr::MP_QSTR_cancellable, false)?;
 
         // Description updates are received as &str and we need to provide a way to
         // convert them to StrBuffer.
-        let obj = LayoutObj::new(Progress::new(
-            title,
-            indeterminate,
-            description,
-            StrBuffer::alloc,
-        ))?;
+        let mut progress = Progress::new(title, indeterminate, description, StrBuffer::alloc);
+        if show_eta {
+            progress = progress.with_eta();
+        }
+        let obj = if cancellable {
+            LayoutObj::new(CancellableProgress::new(progress))?
+        } else {
+            LayoutObj::new(progress)?
+        };
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_pin_lockout(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let seconds: u32 = kwargs.get_field(Qstr::MP_QSTR_seconds)?;
+
+        let obj = LayoutObj::new(PinLockout::new(title, description, seconds))?;
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1516,7 +2307,7 @@ extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Ma
 
 extern "C" fn new_show_progress_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let title: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_title)?;
         let indeterminate: bool = kwargs.get_or(Qstr::MP_QSTR_indeterminate, false)?;
         let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
         let skip_first_paint: bool = kwargs.get_or(Qstr::MP_QSTR_skip_first_paint, false)?;
@@ -1545,10 +2336,10 @@ extern "C" fn new_show_homescreen(n_args: usize, args: *const Obj, kwargs: *mut
             .try_into_option()?
             .unwrap_or_else(|| model::FULL_NAME.into());
         let notification: Option<StrBuffer> =
-            kwargs.get(Qstr::MP_QSTR_notification)?.try_into_option()?;
+            kwargs.get_optional_field(Qstr::MP_QSTR_notification)?;
         let notification_level: u8 = kwargs.get_or(Qstr::MP_QSTR_notification_level, 0)?;
-        let hold: bool = kwargs.get(Qstr::MP_QSTR_hold)?.try_into()?;
-        let skip_first_paint: bool = kwargs.get(Qstr::MP_QSTR_skip_first_paint)?.try_into()?;
+        let hold: bool = kwargs.get_field(Qstr::MP_QSTR_hold)?;
+        let skip_first_paint: bool = kwargs.get_field(Qstr::MP_QSTR_skip_first_paint)?;
 
         let notification = notification.map(|w| (w, notification_level));
         let obj = LayoutObj::new(Homescreen::new(label, notification, hold))?;
@@ -1566,9 +2357,9 @@ extern "C" fn new_show_lockscreen(n_args: usize, args: *const Obj, kwargs: *mut
             .get(Qstr::MP_QSTR_label)?
             .try_into_option()?
             .unwrap_or_else(|| model::FULL_NAME.into());
-        let bootscreen: bool = kwargs.get(Qstr::MP_QSTR_bootscreen)?.try_into()?;
+        let bootscreen: bool = kwargs.get_field(Qstr::MP_QSTR_bootscreen)?;
         let coinjoin_authorized: bool = kwargs.get_or(Qstr::MP_QSTR_coinjoin_authorized, false)?;
-        let skip_first_paint: bool = kwargs.get(Qstr::MP_QSTR_skip_first_paint)?.try_into()?;
+        let skip_first_paint: bool = kwargs.get_field(Qstr::MP_QSTR_skip_first_paint)?;
 
         let obj = LayoutObj::new(Lockscreen::new(label, bootscreen, coinjoin_authorized))?;
         if skip_first_paint {
@@ -1597,15 +2388,15 @@ extern "C" fn new_confirm_firmware_update(
 ) -> Obj {
     use super::component::bl_confirm::{Confirm, ConfirmTitle};
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let description: StrBuffer = kwargs.get(Qstr::MP_QSTR_description)?.try_into()?;
-        let fingerprint: StrBuffer = kwargs.get(Qstr::MP_QSTR_fingerprint)?.try_into()?;
+        let description: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_description)?;
+        let fingerprint: StrBuffer = kwargs.get_field(Qstr::MP_QSTR_fingerprint)?;
 
         let title_str = StrBuffer::from("UPDATE FIRMWARE");
         let title = Label::left_aligned(title_str, theme::TEXT_BOLD).vertically_centered();
         let msg = Label::left_aligned(description, theme::TEXT_NORMAL);
 
-        let left = Button::with_text("CANCEL").styled(theme::button_default());
-        let right = Button::with_text("INSTALL").styled(theme::button_confirm());
+        let left = Button::with_text("CANCEL").with_intent(ButtonIntent::Neutral);
+        let right = Button::with_text("INSTALL").with_intent(ButtonIntent::Confirm);
 
         let obj = LayoutObj::new(
             Confirm::new(theme::BG, left, right, ConfirmTitle::Text(title), msg).with_info(
@@ -1632,10 +2423,164 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// INFO: object
     Qstr::MP_QSTR_INFO => INFO.as_obj(),
 
+    /// SELECTED: object
+    Qstr::MP_QSTR_SELECTED => SELECTED.as_obj(),
+
+    /// MORE: object
+    Qstr::MP_QSTR_MORE => MORE.as_obj(),
+
+    /// class ValueResult:
+    ///     """Result of a layout carrying a value, e.g. the selected index."""
+    ///     kind: object
+    ///     name: str
+    ///     value: int
+    /// mock:global
+
+    /// class ValidationError(ValueError):
+    ///     """Layout argument failed validation. The first argument of the
+    ///     exception is name of the field, the second one the reason."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_ValidationError => unsafe { ffi::mp_type_ValidationError.as_obj() },
+
+    /// class MissingFieldError(ValidationError):
+    ///     """Required layout argument was not passed."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_MissingFieldError => unsafe { ffi::mp_type_MissingFieldError.as_obj() },
+
+    /// class InvalidFieldError(ValidationError):
+    ///     """Layout argument has an unexpected type or value."""
+    ///
+    /// mock:global
+    Qstr::MP_QSTR_InvalidFieldError => unsafe { ffi::mp_type_InvalidFieldError.as_obj() },
+
     /// def disable_animation(disable: bool) -> None:
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def disable_transitions(disable: bool) -> None:
+    ///     """Disable animated screen transitions."""
+    Qstr::MP_QSTR_disable_transitions => obj_fn_1!(upy_disable_transitions).as_obj(),
+
+    /// def set_accessibility(
+    ///     *,
+    ///     reduce_motion: bool = False,
+    ///     longer_timeouts: bool = False,
+    ///     larger_fonts: bool = False,
+    ///     high_contrast: bool = False,
+    ///     audio_feedback: bool = False,
+    /// ) -> None:
+    ///     """Set the accessibility preferences of the user. Reduced motion skips
+    ///     decorative movement, e.g. transitions and sliding items. Longer
+    ///     timeouts give more time to read and to hold buttons. High contrast
+    ///     paints grey text in white or black. Audio feedback plays the outcome
+    ///     of a flow on the buzzer, if there is one."""
+    Qstr::MP_QSTR_set_accessibility => obj_fn_kw!(0, upy_set_accessibility).as_obj(),
+
+    /// def set_autodim(
+    ///     *,
+    ///     delay_ms: int | None = None,
+    ///     level: int = 0,
+    /// ) -> None:
+    ///     """Dim the display to backlight `level` after `delay_ms` without input, or
+    ///     never with `None`. The input waking the display up is not passed on to the
+    ///     layout."""
+    Qstr::MP_QSTR_set_autodim => obj_fn_kw!(0, upy_set_autodim).as_obj(),
+
+    /// def set_wallet_badge(hidden: bool | None) -> None:
+    ///     """Show on every screen whether the current session uses a hidden
+    ///     wallet, i.e. one derived with a non-empty passphrase, or the
+    ///     standard one. `None` hides the badge."""
+    Qstr::MP_QSTR_set_wallet_badge => obj_fn_1!(upy_set_wallet_badge).as_obj(),
+
+    /// def trace_paint_counters(enabled: bool) -> None:
+    ///     """Include in the traces of layouts how many times each component has
+    ///     been painted and has requested a paint. Debug builds only."""
+    Qstr::MP_QSTR_trace_paint_counters => obj_fn_1!(upy_trace_paint_counters).as_obj(),
+
+    /// def wipe_carry() -> None:
+    ///     """Zeroize the passphrase kept by `request_passphrase` with
+    ///     `carry_out` in case no other entry has taken it."""
+    Qstr::MP_QSTR_wipe_carry => obj_fn_0!(upy_wipe_carry).as_obj(),
+
+    /// def secrets_equal(a: str, b: str) -> bool:
+    ///     """Compare two secrets, e.g. a PIN and its re-entry, in time that does
+    ///     not depend on where they differ."""
+    Qstr::MP_QSTR_secrets_equal => obj_fn_2!(upy_secrets_equal).as_obj(),
+
+    /// def enable_profiler(enable: bool) -> None:
+    ///     """Show paint and event handling times of layouts in the corner of
+    ///     the screen, debug builds only."""
+    Qstr::MP_QSTR_enable_profiler => obj_fn_1!(upy_enable_profiler).as_obj(),
+
+    /// def set_replay_seed(seed: int | None) -> None:
+    ///     """Draw UI randomness from a PRNG seeded with `seed`, or from the RNG
+    ///     again if `None`. Emulator debug builds only."""
+    Qstr::MP_QSTR_set_replay_seed => obj_fn_1!(upy_set_replay_seed).as_obj(),
+
+    /// def translations_max_length() -> int:
+    ///     """Longest translations blob fitting the flash, zero on models
+    ///     without room for one."""
+    Qstr::MP_QSTR_translations_max_length => obj_fn_0!(upy_translations_max_length).as_obj(),
+
+    /// def erase_translations() -> None:
+    ///     """Switch to English and erase the translations installed in the
+    ///     flash."""
+    Qstr::MP_QSTR_erase_translations => obj_fn_0!(upy_erase_translations).as_obj(),
+
+    /// def write_translations(data: bytes, offset: int) -> None:
+    ///     """Write `data` at `offset` of the translations blob being installed,
+    ///     after `erase_translations()`."""
+    Qstr::MP_QSTR_write_translations => obj_fn_2!(upy_write_translations).as_obj(),
+
+    /// def commit_translations(length: int) -> None:
+    ///     """Install the first `length` bytes written as the translations blob,
+    ///     without switching to it. Until then nothing is installed."""
+    Qstr::MP_QSTR_commit_translations => obj_fn_1!(upy_commit_translations).as_obj(),
+
+    /// def load_translations() -> None:
+    ///     """Use the strings of the installed translations. Strings missing in
+    ///     them are shown in English. Raises `ValueError` if there are none or
+    ///     they are invalid."""
+    Qstr::MP_QSTR_load_translations => obj_fn_0!(upy_load_translations).as_obj(),
+
+    /// def set_battery_level(level: int | None) -> None:
+    ///     """Charge of the battery in percent, or `None` on external power. Below
+    ///     20 percent animations run with fewer frames and decorative effects, e.g.
+    ///     marquees and screen transitions, are skipped."""
+    Qstr::MP_QSTR_set_battery_level => obj_fn_1!(upy_set_battery_level).as_obj(),
+
+    /// def set_density(density: int) -> None:
+    ///     """Spacing of confirm screens, a value of `ScreenDensity`. Compact
+    ///     has tighter lines and narrower fonts fitting more on each page."""
+    Qstr::MP_QSTR_set_density => obj_fn_1!(upy_set_density).as_obj(),
+
+    /// def set_theme_inverted(inverted: bool) -> None:
+    ///     """Paint the screens dark on light, the shades of grey swapped. Takes
+    ///     effect from the next paint."""
+    Qstr::MP_QSTR_set_theme_inverted => obj_fn_1!(upy_set_theme_inverted).as_obj(),
+
+    /// def save_resume_point(*, flow: int, step: int, page: int) -> None:
+    ///     """Remember that `page` of `step` was shown in the flow identified by
+    ///     `flow`, so that it can be resumed after a reset. Layouts keep the
+    ///     page up to date as it changes. Needs the storage unlocked."""
+    Qstr::MP_QSTR_save_resume_point => obj_fn_kw!(0, upy_save_resume_point).as_obj(),
+
+    /// def resume_point(flow: int) -> tuple[int, int] | None:
+    ///     """Step and page saved for `flow`, or `None` when there is nothing to
+    ///     resume."""
+    Qstr::MP_QSTR_resume_point => obj_fn_1!(upy_resume_point).as_obj(),
+
+    /// def clear_resume_point() -> None:
+    ///     """Forget the saved point, e.g. once the flow is finished."""
+    Qstr::MP_QSTR_clear_resume_point => obj_fn_0!(upy_clear_resume_point).as_obj(),
+
+    /// def set_keyboard_layout(layout: int) -> None:
+    ///     """Order the letters of keyboards created from now on by `layout`, a
+    ///     value of `KeyboardLayout`."""
+    Qstr::MP_QSTR_set_keyboard_layout => obj_fn_1!(upy_set_keyboard_layout).as_obj(),
+
     /// def check_homescreen_format(data: bytes) -> bool:
     ///     """Check homescreen format and dimensions."""
     Qstr::MP_QSTR_check_homescreen_format => obj_fn_1!(upy_check_homescreen_format).as_obj(),
@@ -1649,11 +2594,33 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     verb_cancel: str | None = None,
     ///     hold: bool = False,
     ///     hold_danger: bool = False,
+    ///     destructive: bool = False,
     ///     reverse: bool = False,
     /// ) -> object:
-    ///     """Confirm action."""
+    ///     """Confirm action. Irreversible actions are confirmed with a button in
+    ///     warning colors, either held with `hold_danger` or clicked with
+    ///     `destructive`."""
     Qstr::MP_QSTR_confirm_action => obj_fn_kw!(0, new_confirm_action).as_obj(),
 
+    /// def confirm_blind_signing(
+    ///     *,
+    ///     title: str | None = None,
+    ///     description: str = "",
+    /// ) -> object:
+    ///     """Mandatory warning before signing something the device cannot decode,
+    ///     e.g. a call of an unknown contract or raw data. Accepted by holding."""
+    Qstr::MP_QSTR_confirm_blind_signing => obj_fn_kw!(0, new_confirm_blind_signing).as_obj(),
+
+    /// def confirm_language(
+    ///     *,
+    ///     version: str,
+    ///     signed: bool,
+    /// ) -> object:
+    ///     """Confirm switching to the translations in use, showing their language,
+    ///     `version` and whether they are signed. The screen itself is in the new
+    ///     language."""
+    Qstr::MP_QSTR_confirm_language => obj_fn_kw!(0, new_confirm_language).as_obj(),
+
     /// def confirm_emphasized(
     ///     *,
     ///     title: str,
@@ -1709,6 +2676,49 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     the value is to be rendered as binary with monospace font, False otherwise."""
     Qstr::MP_QSTR_confirm_properties => obj_fn_kw!(0, new_confirm_properties).as_obj(),
 
+    /// def confirm_template(
+    ///     *,
+    ///     title: str,
+    ///     rows: Iterable[tuple[str, str]],
+    ///     warning: bool = False,
+    ///     verb: str = "CONFIRM",
+    ///     verb_cancel: str = "CANCEL",
+    /// ) -> object:
+    ///     """Confirm an application-specific action described by the host. Every
+    ///     text must be trimmed printable ASCII and short enough, there can be 1 to
+    ///     6 rows. Raises ValueError otherwise."""
+    Qstr::MP_QSTR_confirm_template => obj_fn_kw!(0, new_confirm_template).as_obj(),
+
+    /// def confirm_payment_uri(
+    ///     *,
+    ///     uri: str,
+    ///     scheme: str = "bitcoin",
+    ///     title: str = "PAYMENT REQUEST",
+    /// ) -> object:
+    ///     """Confirm a BIP-21 payment URI, its address, amount, label and message,
+    ///     with the whole URI on the last page. Raises ValueError when the URI is
+    ///     malformed."""
+    Qstr::MP_QSTR_confirm_payment_uri => obj_fn_kw!(0, new_confirm_payment_uri).as_obj(),
+
+    /// def confirm_terms(
+    ///     *,
+    ///     title: str,
+    ///     text: str,
+    ///     verb: str = "ACCEPT",
+    /// ) -> object:
+    ///     """Long notice or disclaimer, paged by swiping. Confirming is only possible
+    ///     once the last page has been shown."""
+    Qstr::MP_QSTR_confirm_terms => obj_fn_kw!(0, new_confirm_terms).as_obj(),
+
+    /// def confirm_streamed(
+    ///     *,
+    ///     title: str,
+    /// ) -> object:
+    ///     """Confirm content pushed into the layout piece by piece with
+    ///     `update("item", (title, value))`, `update("item", None)` ends it. Returns
+    ///     `MORE` when the user is past the last item received."""
+    Qstr::MP_QSTR_confirm_streamed => obj_fn_kw!(0, new_confirm_streamed).as_obj(),
+
     /// def confirm_reset_device(
     ///     *,
     ///     title: str,
@@ -1730,6 +2740,21 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Show address details - QR code, account, path, cosigner xpubs."""
     Qstr::MP_QSTR_show_address_details => obj_fn_kw!(0, new_show_address_details).as_obj(),
 
+    /// def show_pubkey_details(
+    ///     *,
+    ///     title: str,
+    ///     pubkey: str,
+    ///     fingerprint: str | None = None,
+    ///     account: str | None = None,
+    ///     path: str | None = None,
+    ///     path_unusual: bool = False,
+    /// ) -> object:
+    ///     """Show an extended public key or descriptor in chunks over several pages,
+    ///     then as a QR code, then a summary with the fingerprint, account and path.
+    ///     The path is marked when `path_unusual`, i.e. it does not follow any known
+    ///     scheme."""
+    Qstr::MP_QSTR_show_pubkey_details => obj_fn_kw!(0, new_show_pubkey_details).as_obj(),
+
     /// def show_info_with_cancel(
     ///     *,
     ///     title: str,
@@ -1750,8 +2775,11 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     info_button: bool = False,
     ///     hold: bool = False,
     ///     chunkify: bool = False,
+    ///     coin: str | None = None,
     /// ) -> object:
-    ///     """Confirm value. Merge of confirm_total and confirm_output."""
+    ///     """Confirm value. Merge of confirm_total and confirm_output. The icon
+    ///     and name of the `coin` with the given shortcut are shown in the
+    ///     header, if there is an icon for it."""
     Qstr::MP_QSTR_confirm_value => obj_fn_kw!(0, new_confirm_value).as_obj(),
 
     /// def confirm_total(
@@ -1760,6 +2788,7 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     items: Iterable[tuple[str, str]],
     ///     info_button: bool = False,
     ///     cancel_arrow: bool = False,
+    ///     coin: str | None = None,
     /// ) -> object:
     ///     """Transaction summary. Always hold to confirm."""
     Qstr::MP_QSTR_confirm_total => obj_fn_kw!(0, new_confirm_total).as_obj(),
@@ -1790,10 +2819,12 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     app_name: str,
     ///     icon_name: str | None,
     ///     accounts: list[str | None],
-    /// ) -> int | object:
+    ///     time_ms: int = 0,
+    /// ) -> ValueResult | object:
     ///     """FIDO confirmation.
     ///
-    ///     Returns page index in case of confirmation and CANCELLED otherwise.
+    ///     Returns SELECTED with the page index in case of confirmation and CANCELLED
+    ///     otherwise, also when `time_ms` is set and the countdown runs out.
     ///     """
     Qstr::MP_QSTR_confirm_fido => obj_fn_kw!(0, new_confirm_fido).as_obj(),
 
@@ -1831,6 +2862,20 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Success modal. No buttons shown when `button` is empty string."""
     Qstr::MP_QSTR_show_success => obj_fn_kw!(0, new_show_success).as_obj(),
 
+    /// def show_entropy_mixing(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     seed: bytes,
+    ///     time_ms: int,
+    /// ) -> bytes:
+    ///     """Abstract pattern stirred while the entropy of a new wallet is
+    ///     mixed, starting from `seed`, a hash of the entropy and not the
+    ///     entropy itself. Closes after `time_ms` unless the user presses the
+    ///     buttons or taps the screen to add entropy, then once they stop.
+    ///     Returns the timing of the presses, empty if there were none."""
+    Qstr::MP_QSTR_show_entropy_mixing => obj_fn_kw!(0, new_show_entropy_mixing).as_obj(),
+
     /// def show_info(
     ///     *,
     ///     title: str,
@@ -1842,6 +2887,32 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Info modal. No buttons shown when `button` is empty string."""
     Qstr::MP_QSTR_show_info => obj_fn_kw!(0, new_show_info).as_obj(),
 
+    /// def show_settings_menu(
+    ///     *,
+    ///     settings: Iterable[tuple[str, int]],
+    /// ) -> tuple[list[tuple[str, int]], bool]:
+    ///     """Settings menu. `settings` are names and current values of the
+    ///     settings to offer, one of `auto_lock`, `rotation`, `safety_checks`,
+    ///     `large_text`, `remember_destinations`, `spending_threshold` and
+    ///     `signing_receipt`. Returns the names and new values of the changed
+    ///     ones, and whether the user wants to rename the device."""
+    Qstr::MP_QSTR_show_settings_menu => obj_fn_kw!(0, new_show_settings_menu).as_obj(),
+
+    /// def show_result(
+    ///     *,
+    ///     success: bool,
+    ///     title: str,
+    ///     detail: str = "",
+    ///     button: str = "",
+    ///     time_ms: int = 0,
+    ///     rejected: bool = False,
+    /// ) -> object:
+    ///     """Result of a flow with a big success or failure icon. Dismissed by
+    ///     the button or after `time_ms`, whichever comes first. Without both
+    ///     it is only drawn once. A failure the user has `rejected` plays the
+    ///     reject sound instead of the error one."""
+    Qstr::MP_QSTR_show_result => obj_fn_kw!(0, new_show_result).as_obj(),
+
     /// def show_mismatch(*, title: str) -> object:
     ///     """Warning modal, receiving address mismatch."""
     Qstr::MP_QSTR_show_mismatch => obj_fn_kw!(0, new_show_mismatch).as_obj(),
@@ -1876,6 +2947,44 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     Meant to be used with confirm_with_info."""
     Qstr::MP_QSTR_confirm_more => obj_fn_kw!(0, new_confirm_more).as_obj(),
 
+    /// def show_blob(
+    ///     *,
+    ///     title: str,
+    ///     data: bytes,
+    ///     base64: bool = False,
+    ///     verb: str = "",
+    ///     total: int = 0,
+    /// ) -> object:
+    ///     """All of `data` in hex or base64, with offsets and the total length,
+    ///     on as many pages as needed. Returns INFO to switch the format. With
+    ///     `verb`, the data is to be confirmed with that button rather than just
+    ///     closed. `total` is the length of the whole blob if `data` is only its
+    ///     start."""
+    Qstr::MP_QSTR_show_blob => obj_fn_kw!(0, new_show_blob).as_obj(),
+
+    /// def show_receipt(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     fingerprint: str,
+    ///     qr_data: str,
+    ///     qr: bool = False,
+    /// ) -> object:
+    ///     """Short fingerprint of what was signed, to be compared with the host.
+    ///     `qr` shows `qr_data` as a QR code instead."""
+    Qstr::MP_QSTR_show_receipt => obj_fn_kw!(0, new_show_receipt).as_obj(),
+
+    /// def confirm_settings_change(
+    ///     *,
+    ///     title: str,
+    ///     changes: Iterable[tuple[str, str, str, bool]],
+    ///     hold: bool = False,
+    /// ) -> object:
+    ///     """Confirm changes of settings, each of them `(name, old value, new
+    ///     value, reduces security)`. The new values reducing security stand
+    ///     out, `hold` for changes like that."""
+    Qstr::MP_QSTR_confirm_settings_change => obj_fn_kw!(0, new_confirm_settings_change).as_obj(),
+
     /// def confirm_coinjoin(
     ///     *,
     ///     max_rounds: str,
@@ -1890,18 +2999,37 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     subprompt: str,
     ///     allow_cancel: bool = True,
     ///     wrong_pin: bool = False,
+    ///     shuffle: bool = True,
+    ///     shuffle_on_keypress: bool = False,
     /// ) -> str | object:
-    ///     """Request pin on device."""
+    ///     """Request pin on device. The keypad is shuffled once or after every
+    ///     digit entered, according to `shuffle` and `shuffle_on_keypress`.
+    ///     `update("toast", text)` shows a notification without interrupting
+    ///     the entry."""
     Qstr::MP_QSTR_request_pin => obj_fn_kw!(0, new_request_pin).as_obj(),
 
     /// def request_passphrase(
     ///     *,
     ///     prompt: str,
     ///     max_len: int,
+    ///     carry_in: bool = False,
+    ///     carry_out: bool = False,
     /// ) -> str | object:
-    ///     """Passphrase input keyboard."""
+    ///     """Passphrase input keyboard. With `carry_out` the confirmed
+    ///     passphrase is kept for the next keyboard of the flow, which takes it
+    ///     with `carry_in` and starts with it entered."""
     Qstr::MP_QSTR_request_passphrase => obj_fn_kw!(0, new_request_passphrase).as_obj(),
 
+    /// def request_label(
+    ///     *,
+    ///     prompt: str,
+    ///     label: str = "",
+    ///     max_len: int,
+    /// ) -> str | object:
+    ///     """Keyboard for a name of up to `max_len` bytes, e.g. the device
+    ///     label, starting with `label` entered."""
+    Qstr::MP_QSTR_request_label => obj_fn_kw!(0, new_request_label).as_obj(),
+
     /// def request_bip39(
     ///     *,
     ///     prompt: str,
@@ -1921,11 +3049,21 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     title: str,
     ///     description: str,
     ///     words: Iterable[str],
-    /// ) -> int:
+    /// ) -> ValueResult:
     ///     """Select mnemonic word from three possibilities - seed check after backup. The
-    ///    iterable must be of exact size. Returns index in range `0..3`."""
+    ///    iterable must be of exact size. Returns SELECTED with index in range `0..3`."""
     Qstr::MP_QSTR_select_word => obj_fn_kw!(0, new_select_word).as_obj(),
 
+    /// def check_share_words(
+    ///     *,
+    ///     title: str,
+    ///     checks: Iterable[tuple[str, Iterable[str], int]],
+    /// ) -> list[int] | object:
+    ///     """Three word checks after the backup in a row, each a description, three
+    ///    words and the index of the correct one. Returns the picked indices, or
+    ///    CANCELLED as soon as a wrong word is picked."""
+    Qstr::MP_QSTR_check_share_words => obj_fn_kw!(0, new_check_share_words).as_obj(),
+
     /// def show_share_words(
     ///     *,
     ///     title: str,
@@ -1963,14 +3101,17 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     button: str,
     ///     dry_run: bool,
     ///     info_button: bool = False,
+    ///     share_groups: Iterable[tuple[int, int]] | None = None,
     /// ) -> object:
-    ///     """Device recovery homescreen."""
+    ///     """Device recovery homescreen. `share_groups` are the collected and required
+    ///     share counts of the groups of a multi-group backup, required is 0 for groups
+    ///     without any share yet."""
     Qstr::MP_QSTR_confirm_recovery => obj_fn_kw!(0, new_confirm_recovery).as_obj(),
 
     /// def select_word_count(
     ///     *,
     ///     dry_run: bool,
-    /// ) -> int | str:  # TT returns int
+    /// ) -> ValueResult | str:  # TT returns ValueResult
     ///     """Select mnemonic word count from (12, 18, 20, 24, 33)."""
     Qstr::MP_QSTR_select_word_count => obj_fn_kw!(0, new_select_word_count).as_obj(),
 
@@ -1988,17 +3129,42 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
     Qstr::MP_QSTR_show_remaining_shares => obj_fn_kw!(0, new_show_remaining_shares).as_obj(),
 
+    /// def show_check_result(
+    ///     *,
+    ///     title: str,
+    ///     items: Iterable[tuple[bool | None, str]],
+    ///     button: str,
+    /// ) -> object:
+    ///     """Outcome of the checks of a backup, one item each. Passed checks have a check
+    ///     mark next to them, failed ones a warning, `None` is a note without an icon."""
+    Qstr::MP_QSTR_show_check_result => obj_fn_kw!(0, new_show_check_result).as_obj(),
+
     /// def show_progress(
     ///     *,
     ///     title: str,
     ///     indeterminate: bool = False,
     ///     description: str = "",
+    ///     show_eta: bool = False,
+    ///     cancellable: bool = False,
     /// ) -> object:
     ///     """Show progress loader. Please note that the number of lines reserved on screen for
     ///    description is determined at construction time. If you want multiline descriptions
-    ///    make sure the initial description has at least that amount of lines."""
+    ///    make sure the initial description has at least that amount of lines. With `show_eta`,
+    ///    estimated remaining time is shown below the loader (determinate mode only). With
+    ///    `cancellable`, the operation can be aborted by holding a cancel button, or by the host
+    ///    disconnecting, in which case CANCELLED is returned from the event."""
     Qstr::MP_QSTR_show_progress => obj_fn_kw!(0, new_show_progress).as_obj(),
 
+    /// def show_pin_lockout(
+    ///     *,
+    ///     title: str,
+    ///     description: str,
+    ///     seconds: int,
+    /// ) -> object:
+    ///     """Countdown of the `seconds` long wait after wrong PINs, driven by
+    ///     progress events like `show_progress`."""
+    Qstr::MP_QSTR_show_pin_lockout => obj_fn_kw!(0, new_show_pin_lockout).as_obj(),
+
     /// def show_progress_coinjoin(
     ///     *,
     ///     title: str,
@@ -2017,8 +3183,8 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     notification: str | None,
     ///     notification_level: int = 0,
     ///     skip_first_paint: bool,
-    /// ) -> CANCELLED:
-    ///     """Idle homescreen."""
+    /// ) -> CANCELLED | INFO:
+    ///     """Idle homescreen. Returns INFO when the user opens the settings."""
     Qstr::MP_QSTR_show_homescreen => obj_fn_kw!(0, new_show_homescreen).as_obj(),
 
     /// def show_lockscreen(
@@ -2046,7 +3212,12 @@ mod tests {
 
     use crate::{
         trace::tests::trace,
-        ui::{component::text::op::OpTextLayout, geometry::Rect, model_tt::constant},
+        ui::{
+            component::text::op::OpTextLayout,
+            geometry::{Offset, Point, Rect},
+            model_tt::constant,
+            testing::Harness,
+        },
     };
 
     use super::*;
@@ -2091,4 +3262,26 @@ mod tests {
 
         assert_eq!(trace(&layout), expected);
     }
+
+    #[test]
+    fn receipt_offers_the_qr() {
+        let mut h = Harness::new(
+            receipt(
+                "MESSAGE SIGNED".into(),
+                "Compare with the host:".into(),
+                "3f2a 91c0 77e4 0b5d".into(),
+            )
+            .into_frame(),
+        );
+        let side = theme::CORNER_BUTTON_SIDE;
+        let info = SCREEN.top_right() + Offset::new(-side / 2, side / 2);
+        assert!(matches!(
+            h.tap(info),
+            Some(FrameMsg::Button(CancelInfoConfirmMsg::Info))
+        ));
+
+        // Swiping to the left does the same.
+        let msg = h.swipe(Point::new(200, 140), Point::new(20, 140));
+        assert!(matches!(msg, Some(FrameMsg::Content(PageMsg::SwipeLeft))));
+    }
 }
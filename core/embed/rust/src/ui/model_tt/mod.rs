@@ -7,3 +7,5 @@ pub mod theme;
 #[cfg(feature = "micropython")]
 pub mod layout;
 pub mod screens;
+#[cfg(test)]
+mod snapshots;
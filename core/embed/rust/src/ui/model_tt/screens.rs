@@ -22,7 +22,7 @@ unsafe fn get_str(text: &str) -> StrBuffer {
     unsafe { StrBuffer::from_ptr_and_len(text.as_ptr(), text.len()) }
 }
 
-pub fn screen_fatal_error(title: &str, msg: &str, footer: &str) {
+pub fn screen_fatal_error(title: &str, msg: &str, footer: &str, diagnostics: Option<&str>) {
     // SAFETY: these will get placed into `frame` which does not outlive this
     // function
     let title = unsafe { get_str(title) };
@@ -30,6 +30,9 @@ pub fn screen_fatal_error(title: &str, msg: &str, footer: &str) {
     let footer = unsafe { get_str(footer) };
 
     let mut frame = ErrorScreen::new(title, msg, footer);
+    if let Some(diagnostics) = diagnostics {
+        frame = frame.with_diagnostics(unsafe { get_str(diagnostics) });
+    }
     frame.place(constant::screen());
     frame.paint();
 }
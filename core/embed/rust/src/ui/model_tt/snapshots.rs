@@ -0,0 +1,44 @@
+use crate::ui::{
+    component::text::paragraphs::{Paragraph, Paragraphs},
+    snapshot::assert_snapshot,
+};
+
+use super::{
+    component::{ButtonPage, Frame, PinKeyboard},
+    theme,
+};
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn pin_keyboard() {
+    assert_snapshot("pin_keyboard", || {
+        PinKeyboard::new("Enter PIN", "", None, true)
+    });
+}
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn pin_keyboard_wrong_pin() {
+    assert_snapshot("pin_keyboard_wrong_pin", || {
+        PinKeyboard::new("Enter PIN", "2 tries left", Some("Wrong PIN"), true)
+    });
+}
+
+#[test]
+#[ignore = "golden images are not checked in yet, see golden/README.md"]
+fn confirm_action() {
+    assert_snapshot("confirm_action", || {
+        Frame::left_aligned(
+            theme::label_title(),
+            "WIPE DEVICE",
+            ButtonPage::<_, &'static str>::new(
+                Paragraphs::new([Paragraph::new(
+                    &theme::TEXT_NORMAL,
+                    "Do you really want to wipe the device?",
+                )]),
+                theme::BG,
+            )
+            .with_cancel_confirm(None, Some("WIPE")),
+        )
+    });
+}
@@ -1,14 +1,17 @@
 pub mod bootloader;
 
 use crate::{
+    strutil::HardenedMarker,
     time::Duration,
     ui::{
         component::{
             text::{layout::Chunks, LineBreaking, PageBreaking, TextStyle},
-            FixedHeightBar,
+            FixedHeightBar, PathStyle,
         },
+        context::settings,
         display::{Color, Font, Icon},
         geometry::{Insets, Offset},
+        theme::{ButtonIntent, Theme, ThemeVariant},
     },
 };
 
@@ -165,10 +168,34 @@ pub const fn label_subtitle() -> TextStyle {
     TextStyle::new(Font::MONO, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
+/// Derivation paths with bold hardened levels.
+pub const fn path_style() -> PathStyle {
+    PathStyle {
+        text: TEXT_MONO,
+        hardened_font: Font::BOLD,
+        marker: Some(HardenedMarker::Apostrophe),
+        warning_color: WARN_COLOR,
+    }
+}
+
+pub const fn label_toast() -> TextStyle {
+    TextStyle::new(Font::BOLD, FG, GREY_DARK, FG, FG)
+}
+
 pub const fn label_coinjoin_progress() -> TextStyle {
     TextStyle::new(Font::BOLD, FG, YELLOW, FG, FG)
 }
 
+/// Style of a button according to its meaning.
+pub const fn button_for(intent: ButtonIntent) -> ButtonStyleSheet {
+    match intent {
+        ButtonIntent::Neutral => button_default(),
+        ButtonIntent::Confirm => button_confirm(),
+        ButtonIntent::Caution => button_reset(),
+        ButtonIntent::Destructive => button_danger(),
+    }
+}
+
 pub const fn button_default() -> ButtonStyleSheet {
     ButtonStyleSheet {
         normal: &ButtonStyle {
@@ -643,6 +670,9 @@ pub const TEXT_CHECKLIST_SELECTED: TextStyle =
     TextStyle::new(Font::NORMAL, FG, BG, GREY_LIGHT, GREY_LIGHT);
 pub const TEXT_CHECKLIST_DONE: TextStyle =
     TextStyle::new(Font::NORMAL, GREEN_DARK, BG, GREY_LIGHT, GREY_LIGHT);
+/// New value of a setting that reduces security.
+pub const TEXT_SETTING_RISKY: TextStyle =
+    TextStyle::new(Font::DEMIBOLD, RED, BG, GREY_LIGHT, GREY_LIGHT);
 
 pub const CONTENT_BORDER: i16 = 0;
 pub const BUTTON_HEIGHT: i16 = 50;
@@ -665,6 +695,10 @@ pub const CHECKLIST_CHECK_WIDTH: i16 = 16;
 pub const CHECKLIST_DONE_OFFSET: Offset = Offset::new(-2, 6);
 pub const CHECKLIST_CURRENT_OFFSET: Offset = Offset::new(2, 3);
 
+// backup check result settings
+pub const CHECK_RESULT_ICON_WIDTH: i16 = 24;
+pub const CHECK_RESULT_ICON_OFFSET: Offset = Offset::new(0, 3);
+
 pub const fn button_bar<T>(inner: T) -> FixedHeightBar<T> {
     FixedHeightBar::bottom(inner, BUTTON_HEIGHT)
 }
@@ -688,5 +722,66 @@ pub const fn borders_notification() -> Insets {
     Insets::new(48, 6, 6, 6)
 }
 
+/// Theme of the model, for the code shared between the models.
+pub fn current() -> ModelTheme {
+    ModelTheme
+}
+
+pub struct ModelTheme;
+
+impl Theme for ModelTheme {
+    fn variant(&self) -> ThemeVariant {
+        settings().theme_variant
+    }
+
+    fn fg(&self) -> Color {
+        FG
+    }
+
+    fn bg(&self) -> Color {
+        BG
+    }
+
+    fn fg_secondary(&self) -> Color {
+        if settings().accessibility.high_contrast {
+            FG
+        } else {
+            GREY_LIGHT
+        }
+    }
+
+    fn font_normal(&self) -> Font {
+        Font::NORMAL
+    }
+
+    fn font_bold(&self) -> Font {
+        Font::BOLD
+    }
+
+    fn font_mono(&self) -> Font {
+        Font::MONO
+    }
+
+    fn text_normal(&self) -> &'static TextStyle {
+        &TEXT_NORMAL
+    }
+
+    fn text_bold(&self) -> &'static TextStyle {
+        &TEXT_BOLD
+    }
+
+    fn content_padding(&self) -> i16 {
+        borders().left
+    }
+
+    fn icon_confirm(&self) -> Icon {
+        ICON_CONFIRM
+    }
+
+    fn icon_cancel(&self) -> Icon {
+        ICON_CANCEL
+    }
+}
+
 pub const RESULT_ERROR: ResultStyle =
     ResultStyle::new(FG, FATAL_ERROR_COLOR, FATAL_ERROR_HIGHLIGHT_COLOR);
@@ -0,0 +1,169 @@
+//! Frame-time profiler of layouts, debug builds only. While enabled, the
+//! durations of event passes and paints are measured and their minimum,
+//! average and maximum in milliseconds are drawn over the top right corner of
+//! the screen after every paint.
+
+#[cfg(feature = "ui_debug")]
+use heapless::String;
+
+#[cfg(feature = "ui_debug")]
+use crate::{
+    time::Instant,
+    ui::{
+        constant::screen,
+        context::with_state,
+        display::{self, Color, Font},
+        geometry::{Offset, Point, Rect},
+    },
+};
+
+#[cfg(feature = "ui_debug")]
+const FONT: Font = Font::MONO;
+
+#[cfg(feature = "ui_debug")]
+#[derive(Clone, Copy)]
+struct Timing {
+    min: u32,
+    max: u32,
+    total: u32,
+    count: u32,
+}
+
+#[cfg(feature = "ui_debug")]
+impl Timing {
+    const fn new() -> Self {
+        Self {
+            min: u32::MAX,
+            max: 0,
+            total: 0,
+            count: 0,
+        }
+    }
+
+    fn record(&mut self, start: Instant) {
+        let millis = Instant::now().saturating_duration_since(start).to_millis();
+        self.min = self.min.min(millis);
+        self.max = self.max.max(millis);
+        self.total = self.total.saturating_add(millis);
+        self.count += 1;
+    }
+
+    /// E.g. "P 2/5/31" for `label` "P".
+    fn format(&self, label: &str) -> String<40> {
+        if self.count == 0 {
+            return build_string!(40, label, " -");
+        }
+        let avg = self.total / self.count;
+        build_string!(
+            40,
+            label,
+            " ",
+            String::<10>::from(self.min).as_str(),
+            "/",
+            String::<10>::from(avg).as_str(),
+            "/",
+            String::<10>::from(self.max).as_str()
+        )
+    }
+}
+
+/// Timings measured since the profiler was turned on or the last layout was
+/// created, kept in `UiState::profiler`.
+#[cfg(feature = "ui_debug")]
+#[derive(Clone, Copy)]
+pub(crate) struct Profiler {
+    event: Timing,
+    paint: Timing,
+}
+
+#[cfg(feature = "ui_debug")]
+impl Profiler {
+    const fn new() -> Self {
+        Self {
+            event: Timing::new(),
+            paint: Timing::new(),
+        }
+    }
+
+    fn paint_overlay(&self) {
+        let lines = [self.event.format("E"), self.paint.format("P")];
+        let width = lines.iter().map(|l| FONT.text_width(l)).max().unwrap_or(0);
+        let height = FONT.line_height() * lines.len() as i16;
+        let area = screen();
+        let area = Rect::from_top_right_and_size(area.top_right(), Offset::new(width, height));
+        display::rect_fill(area, Color::black());
+        let mut baseline = Point::new(area.x1, area.y0 + FONT.text_max_height());
+        for line in lines.iter() {
+            display::text_right(baseline, line, FONT, Color::white(), Color::black());
+            baseline.y += FONT.line_height();
+        }
+    }
+}
+
+/// Turn the profiler on or off. Turning it on starts over with empty
+/// statistics.
+#[cfg(feature = "ui_debug")]
+pub fn set_enabled(enabled: bool) {
+    with_state(|s| s.profiler = enabled.then(Profiler::new));
+}
+
+#[cfg(not(feature = "ui_debug"))]
+pub fn set_enabled(_enabled: bool) {}
+
+/// Forget the timings measured so far, called when a new layout is created.
+#[cfg(feature = "ui_debug")]
+pub fn reset() {
+    with_state(|s| {
+        if let Some(profiler) = s.profiler.as_mut() {
+            *profiler = Profiler::new();
+        }
+    });
+}
+
+#[cfg(not(feature = "ui_debug"))]
+pub fn reset() {}
+
+/// Measure an event pass.
+#[cfg(feature = "ui_debug")]
+pub fn measure_event<U>(func: impl FnOnce() -> U) -> U {
+    let start = Instant::now();
+    let result = func();
+    with_state(|s| {
+        if let Some(profiler) = s.profiler.as_mut() {
+            profiler.event.record(start);
+        }
+    });
+    result
+}
+
+#[cfg(not(feature = "ui_debug"))]
+#[inline(always)]
+pub fn measure_event<U>(func: impl FnOnce() -> U) -> U {
+    func()
+}
+
+/// Measure a paint pass, `func` returns whether anything was painted. The
+/// statistics are drawn over the freshly painted screen.
+#[cfg(feature = "ui_debug")]
+pub fn measure_paint(func: impl FnOnce() -> bool) -> bool {
+    let start = Instant::now();
+    let painted = func();
+    if painted {
+        let profiler = with_state(|s| {
+            let profiler = s.profiler.as_mut()?;
+            profiler.paint.record(start);
+            Some(*profiler)
+        });
+        // Painted from a copy, the text may need the state too.
+        if let Some(profiler) = profiler {
+            profiler.paint_overlay();
+        }
+    }
+    painted
+}
+
+#[cfg(not(feature = "ui_debug"))]
+#[inline(always)]
+pub fn measure_paint(func: impl FnOnce() -> bool) -> bool {
+    func()
+}
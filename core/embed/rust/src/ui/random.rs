@@ -0,0 +1,119 @@
+use crate::trezorhal::random;
+
+#[cfg(feature = "ui_replay")]
+use crate::ui::context::with_state;
+
+/// Draw all UI randomness (keyboard shuffling, initial positions of choice
+/// pages, ...) from a PRNG seeded with `seed`, so that the same inputs always
+/// produce the same screens. `None` goes back to the hardware RNG. The state
+/// of the PRNG is kept in `UiState::replay_state`.
+#[cfg(feature = "ui_replay")]
+pub fn set_replay_seed(seed: Option<u32>) {
+    // Xorshift would get stuck on zero.
+    let state = seed.map(|seed| if seed == 0 { 1 } else { seed });
+    with_state(|s| s.replay_state = state);
+}
+
+#[cfg(not(feature = "ui_replay"))]
+pub fn set_replay_seed(_seed: Option<u32>) {}
+
+/// Next number of the xorshift32 sequence, if the replay mode is on.
+#[cfg(feature = "ui_replay")]
+fn replay_next() -> Option<u32> {
+    with_state(|s| {
+        let mut x = s.replay_state?;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        s.replay_state = Some(x);
+        Some(x)
+    })
+}
+
+/// Returns a random number in the range [0, n).
+pub fn uniform(n: u32) -> u32 {
+    #[cfg(feature = "ui_replay")]
+    if let Some(x) = replay_next() {
+        return x % n;
+    }
+    random::uniform(n)
+}
+
+pub fn shuffle<T>(slice: &mut [T]) {
+    // Fisher-Yates shuffle.
+    for i in (1..slice.len()).rev() {
+        let j = uniform(i as u32 + 1) as usize;
+        slice.swap(i, j);
+    }
+}
+
+/// Returns a random number in the range [min, max].
+pub fn uniform_between(min: u32, max: u32) -> u32 {
+    assert!(max >= min);
+    uniform(max - min + 1) + min
+}
+
+/// When the digits of a PIN dialog get randomized. The policy is shared by
+/// the models, each of them applies it in its own way, e.g. by shuffling the
+/// keypad or by picking a random initial digit.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum PinShuffle {
+    /// Digits stay in their natural order.
+    Never,
+    /// Digits are randomized once, when the dialog is shown.
+    PerSession,
+    /// Digits are randomized again after every entered digit.
+    PerKeypress,
+}
+
+impl PinShuffle {
+    pub fn new(shuffle: bool, on_keypress: bool) -> Self {
+        match (shuffle, on_keypress) {
+            (false, _) => Self::Never,
+            (true, false) => Self::PerSession,
+            (true, true) => Self::PerKeypress,
+        }
+    }
+
+    /// Whether to randomize the digits when the dialog is shown.
+    pub fn on_start(self) -> bool {
+        self != Self::Never
+    }
+
+    /// Whether to randomize the digits after a digit was entered.
+    pub fn on_keypress(self) -> bool {
+        self == Self::PerKeypress
+    }
+}
+
+/// Held by tests that depend on the replay seed, the PRNG state is shared by
+/// all the threads running tests.
+#[cfg(test)]
+pub static REPLAY_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(all(test, feature = "ui_replay"))]
+mod tests {
+    use super::*;
+
+    fn shuffled(seed: u32) -> [u8; 10] {
+        let mut digits = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        set_replay_seed(Some(seed));
+        shuffle(&mut digits);
+        set_replay_seed(None);
+        digits
+    }
+
+    #[test]
+    fn replay_is_deterministic() {
+        let _lock = REPLAY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        assert!(shuffled(42) == shuffled(42));
+        assert!(shuffled(0) == shuffled(0));
+        assert!(shuffled(42) != shuffled(43));
+
+        set_replay_seed(Some(1));
+        for _ in 0..100 {
+            assert!((10..=12).contains(&uniform_between(10, 12)));
+        }
+        set_replay_seed(None);
+    }
+}
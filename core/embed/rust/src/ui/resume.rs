@@ -0,0 +1,136 @@
+//! Where the user got to in a long review flow, kept across resets so that the
+//! firmware can offer to resume there instead of starting from the first page.
+//!
+//! The boardloader wipes all of RAM on every boot, so the point is kept in the
+//! storage. It is private, so only written and read while the storage is
+//! unlocked, which it always is while a review flow is shown. To keep the
+//! flash writes few, the page is only rewritten when the shown one changes.
+
+use crate::{error::Error, storage};
+
+/// Length of the stored point.
+const LEN: usize = 8;
+
+/// Step of a flow and the page shown in it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ResumePoint {
+    /// Identifies the flow, e.g. a digest of the message being reviewed, so
+    /// that the point is never offered in a different one.
+    pub flow: u32,
+    /// Screen of the flow, as numbered by the firmware.
+    pub step: u16,
+    /// Page of the screen, see `EventCtx::set_active_page`.
+    pub page: u16,
+}
+
+impl ResumePoint {
+    fn to_bytes(self) -> [u8; LEN] {
+        let mut bytes = [0; LEN];
+        bytes[..4].copy_from_slice(&self.flow.to_le_bytes());
+        bytes[4..6].copy_from_slice(&self.step.to_le_bytes());
+        bytes[6..].copy_from_slice(&self.page.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != LEN {
+            return None;
+        }
+        Some(Self {
+            flow: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            step: u16::from_le_bytes([bytes[4], bytes[5]]),
+            page: u16::from_le_bytes([bytes[6], bytes[7]]),
+        })
+    }
+}
+
+/// The saved point of any flow.
+fn read() -> Option<ResumePoint> {
+    let mut buf = [0; LEN];
+    storage::load_resume_point(&mut buf)
+        .ok()
+        .and_then(ResumePoint::from_bytes)
+}
+
+/// Remember `point`, replacing the previous one.
+pub fn save(point: ResumePoint) -> Result<(), Error> {
+    storage::save_resume_point(&point.to_bytes())?;
+    Ok(())
+}
+
+/// The point reached in `flow`, if any.
+pub fn load(flow: u32) -> Option<ResumePoint> {
+    read().filter(|point| point.flow == flow)
+}
+
+/// Forget the point, e.g. once the flow is finished.
+pub fn clear() {
+    // Fails only when there is nothing saved.
+    storage::delete_resume_point().ok();
+}
+
+/// Note that `page` of the current step is shown. Called by the layouts when
+/// their page changes, does nothing when no flow has saved a point.
+pub fn update_page(page: u16) {
+    if let Some(point) = read() {
+        if point.page != page {
+            // Not worth failing the layout over, the point is only a help.
+            save(ResumePoint { page, ..point }).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trezorhal::storage::{init, init_storage, unlock};
+
+    use super::*;
+
+    const FLOW: u32 = 0xdead_beef;
+
+    /// What the firmware does on boot: read the storage from the flash again
+    /// and unlock it.
+    fn restart() {
+        init();
+        assert!(unlock("", None));
+    }
+
+    #[test]
+    fn survives_restart() {
+        init_storage(true);
+        assert!(load(FLOW).is_none());
+
+        save(ResumePoint {
+            flow: FLOW,
+            step: 3,
+            page: 0,
+        })
+        .unwrap();
+        update_page(2);
+        restart();
+
+        assert!(
+            load(FLOW)
+                == Some(ResumePoint {
+                    flow: FLOW,
+                    step: 3,
+                    page: 2,
+                })
+        );
+        // Never offered in a different flow.
+        assert!(load(1).is_none());
+
+        clear();
+        restart();
+        assert!(load(FLOW).is_none());
+    }
+
+    #[test]
+    fn pages_without_a_flow() {
+        init_storage(true);
+        update_page(4);
+        assert!(read().is_none());
+        // Clearing with nothing saved is fine too.
+        clear();
+    }
+}
@@ -7,6 +7,10 @@ pub use super::model_tr::screens::*;
 pub use super::model_tt::screens::*;
 use crate::ui::util::from_c_str;
 
+/// Longest diagnostics payload shown as a QR code. Binary data of this length
+/// fits the largest code `Qr` paints.
+const MAX_DIAGNOSTICS_LEN: usize = 200;
+
 #[no_mangle]
 extern "C" fn screen_fatal_error_rust(
     title: *const cty::c_char,
@@ -17,5 +21,21 @@ extern "C" fn screen_fatal_error_rust(
     let msg = unsafe { from_c_str(msg) }.unwrap_or("");
     let footer = unsafe { from_c_str(footer) }.unwrap_or("");
 
-    screen_fatal_error(title, msg, footer);
+    screen_fatal_error(title, msg, footer, None);
+}
+
+#[no_mangle]
+extern "C" fn screen_fatal_error_qr_rust(
+    title: *const cty::c_char,
+    msg: *const cty::c_char,
+    footer: *const cty::c_char,
+    diagnostics: *const cty::c_char,
+) {
+    let title = unsafe { from_c_str(title) }.unwrap_or("");
+    let msg = unsafe { from_c_str(msg) }.unwrap_or("");
+    let footer = unsafe { from_c_str(footer) }.unwrap_or("");
+    let diagnostics = unsafe { from_c_str(diagnostics) }
+        .filter(|d| !d.is_empty() && d.len() <= MAX_DIAGNOSTICS_LEN);
+
+    screen_fatal_error(title, msg, footer, diagnostics);
 }
@@ -0,0 +1,188 @@
+//! Typed descriptors of the device settings adjustable in the on-device
+//! settings menu. The values are the ones the firmware stores, the menu
+//! only maps them to labels and back.
+
+/// Most settings a menu can offer.
+pub const MAX_SETTINGS: usize = 7;
+
+/// One of the values a setting can take, with its label in the menu.
+pub struct SettingOption {
+    pub label: &'static str,
+    pub value: u32,
+}
+
+const fn option(label: &'static str, value: u32) -> SettingOption {
+    SettingOption { label, value }
+}
+
+const AUTO_LOCK_OPTIONS: [SettingOption; 5] = [
+    option("1 MINUTE", 60_000),
+    option("5 MINUTES", 300_000),
+    option("10 MINUTES", 600_000),
+    option("30 MINUTES", 1_800_000),
+    option("1 HOUR", 3_600_000),
+];
+
+const ROTATION_OPTIONS: [SettingOption; 4] = [
+    option("NORTH", 0),
+    option("EAST", 90),
+    option("SOUTH", 180),
+    option("WEST", 270),
+];
+
+const ON_OFF_OPTIONS: [SettingOption; 2] = [option("ON", 1), option("OFF", 0)];
+
+/// Values of `SafetyCheckLevel`.
+const SAFETY_CHECKS_OPTIONS: [SettingOption; 3] = [
+    option("STRICT", 0),
+    option("PROMPT", 1),
+    option("PROMPT ONCE", 2),
+];
+
+/// Amounts in satoshis, zero is no threshold.
+const SPENDING_THRESHOLD_OPTIONS: [SettingOption; 5] = [
+    option("OFF", 0),
+    option("0.01 BTC", 1_000_000),
+    option("0.1 BTC", 10_000_000),
+    option("1 BTC", 100_000_000),
+    option("10 BTC", 1_000_000_000),
+];
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SettingKind {
+    AutoLock,
+    Rotation,
+    SafetyChecks,
+    LargeText,
+    RememberDestinations,
+    SpendingThreshold,
+    SigningReceipt,
+}
+
+impl SettingKind {
+    /// Setting given its name as used by the Python side.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "auto_lock" => Some(Self::AutoLock),
+            "rotation" => Some(Self::Rotation),
+            "safety_checks" => Some(Self::SafetyChecks),
+            "large_text" => Some(Self::LargeText),
+            "remember_destinations" => Some(Self::RememberDestinations),
+            "spending_threshold" => Some(Self::SpendingThreshold),
+            "signing_receipt" => Some(Self::SigningReceipt),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AutoLock => "auto_lock",
+            Self::Rotation => "rotation",
+            Self::SafetyChecks => "safety_checks",
+            Self::LargeText => "large_text",
+            Self::RememberDestinations => "remember_destinations",
+            Self::SpendingThreshold => "spending_threshold",
+            Self::SigningReceipt => "signing_receipt",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::AutoLock => "AUTO-LOCK",
+            Self::Rotation => "ROTATION",
+            Self::SafetyChecks => "SAFETY CHECKS",
+            Self::LargeText => "LARGE TEXT",
+            Self::RememberDestinations => "RECENT ADDRESSES",
+            Self::SpendingThreshold => "PIN TO SEND OVER",
+            Self::SigningReceipt => "SIGNING RECEIPT",
+        }
+    }
+
+    pub fn options(self) -> &'static [SettingOption] {
+        match self {
+            Self::AutoLock => &AUTO_LOCK_OPTIONS,
+            Self::Rotation => &ROTATION_OPTIONS,
+            Self::SafetyChecks => &SAFETY_CHECKS_OPTIONS,
+            Self::LargeText => &ON_OFF_OPTIONS,
+            Self::RememberDestinations => &ON_OFF_OPTIONS,
+            Self::SpendingThreshold => &SPENDING_THRESHOLD_OPTIONS,
+            Self::SigningReceipt => &ON_OFF_OPTIONS,
+        }
+    }
+
+    /// Index of the option closest to `value`, so that values set from the
+    /// host that are not offered in the menu still select something sensible.
+    pub fn option_index(self, value: u32) -> usize {
+        let distance = |o: &SettingOption| o.value.abs_diff(value);
+        self.options()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, o)| distance(o))
+            .map_or(0, |(i, _)| i)
+    }
+}
+
+/// Setting shown in a menu together with its value.
+#[derive(Clone, Copy)]
+pub struct Setting {
+    pub kind: SettingKind,
+    pub value: u32,
+    original: u32,
+}
+
+impl Setting {
+    pub fn new(kind: SettingKind, value: u32) -> Self {
+        Self {
+            kind,
+            value,
+            original: value,
+        }
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.value != self.original
+    }
+
+    pub fn label(&self) -> &'static str {
+        let options = self.kind.options();
+        options[self.kind.option_index(self.value)].label
+    }
+
+    /// Move to the option after the current one, wrapping around.
+    pub fn next(&mut self) {
+        let options = self.kind.options();
+        let index = (self.kind.option_index(self.value) + 1) % options.len();
+        self.value = options[index].value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_index() {
+        assert_eq!(SettingKind::AutoLock.option_index(600_000), 2);
+        // Not offered, the nearest option is picked.
+        assert_eq!(SettingKind::AutoLock.option_index(10_000), 0);
+        assert_eq!(SettingKind::AutoLock.option_index(2_000_000), 3);
+        assert_eq!(SettingKind::LargeText.option_index(0), 1);
+
+        for kind in [SettingKind::Rotation, SettingKind::SafetyChecks] {
+            assert!(SettingKind::from_name(kind.name()) == Some(kind));
+        }
+    }
+
+    #[test]
+    fn next() {
+        let mut setting = Setting::new(SettingKind::Rotation, 180);
+        setting.next();
+        assert_eq!(setting.value, 270);
+        assert!(setting.is_changed());
+        setting.next();
+        assert_eq!(setting.label(), "NORTH");
+        setting.next();
+        setting.next();
+        assert!(!setting.is_changed());
+    }
+}
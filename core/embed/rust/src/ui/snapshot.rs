@@ -0,0 +1,300 @@
+//! Golden-image tests of components.
+//!
+//! `assert_snapshot` draws a component on the emulator screen, reads back the
+//! pixels and compares them with the image checked in under `golden/<model>/`.
+//! TT images are stored as PPM, TR ones as PBM. On a mismatch both the actual
+//! screen and a diff with the differing pixels marked in red are written to
+//! the temp directory. Run the tests with `UPDATE_GOLDEN=1` to accept the
+//! current rendering as the new golden image. The tests are `#[ignore]`d while
+//! no golden images are checked in, `--ignored` runs them.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::{
+    trezorhal::display::copy_rgb565,
+    ui::{
+        component::{Component, Event, EventCtx},
+        constant::{screen, HEIGHT, WIDTH},
+        display, random,
+    },
+};
+
+/// All the snapshot tests share the one emulator screen.
+static SCREEN_LOCK: Mutex<()> = Mutex::new(());
+
+/// Seed of the UI randomness, so that e.g. the shuffled PIN keyboard looks
+/// the same in every run.
+const REPLAY_SEED: u32 = 1;
+
+#[cfg(feature = "model_tt")]
+const MODEL_DIR: &str = "tt";
+#[cfg(not(feature = "model_tt"))]
+const MODEL_DIR: &str = "tr";
+
+/// Monochrome models are stored as bitmaps.
+#[cfg(feature = "model_tt")]
+const MONOCHROME: bool = false;
+#[cfg(not(feature = "model_tt"))]
+const MONOCHROME: bool = true;
+
+/// Screen contents in RGB888, row by row.
+#[derive(PartialEq, Eq)]
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    fn from_rgb565(width: usize, height: usize, data: &[u16]) -> Self {
+        let expand = |value: u16, bits: u32| {
+            let max = (1 << bits) - 1;
+            ((value as u32 * 255 + max / 2) / max) as u8
+        };
+        let pixels = data
+            .iter()
+            .map(|&c| {
+                [
+                    expand(c >> 11, 5),
+                    expand((c >> 5) & 0x3F, 6),
+                    expand(c & 0x1F, 5),
+                ]
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Encode as binary PBM (P4) when `monochrome`, PPM (P6) otherwise.
+    fn encode(&self, monochrome: bool) -> Vec<u8> {
+        let mut out = Vec::new();
+        if monochrome {
+            out.extend_from_slice(format!("P4\n{} {}\n", self.width, self.height).as_bytes());
+            for row in self.pixels.chunks(self.width) {
+                for byte in row.chunks(8) {
+                    let mut bits = 0u8;
+                    for (i, px) in byte.iter().enumerate() {
+                        // In PBM, 1 stands for black.
+                        if px[0] < 0x80 {
+                            bits |= 0x80 >> i;
+                        }
+                    }
+                    out.push(bits);
+                }
+            }
+        } else {
+            out.extend_from_slice(format!("P6\n{} {}\n255\n", self.width, self.height).as_bytes());
+            for px in &self.pixels {
+                out.extend_from_slice(px);
+            }
+        }
+        out
+    }
+
+    /// Decode binary PBM or PPM with 8-bit channels.
+    fn decode(data: &[u8]) -> Option<Self> {
+        let mut pos = 0;
+        let mut token = || {
+            loop {
+                match data.get(pos)? {
+                    b'#' => {
+                        while *data.get(pos)? != b'\n' {
+                            pos += 1;
+                        }
+                    }
+                    c if c.is_ascii_whitespace() => pos += 1,
+                    _ => break,
+                }
+            }
+            let start = pos;
+            while !data.get(pos)?.is_ascii_whitespace() {
+                pos += 1;
+            }
+            core::str::from_utf8(&data[start..pos]).ok()
+        };
+        let magic = token()?;
+        let width: usize = token()?.parse().ok()?;
+        let height: usize = token()?.parse().ok()?;
+        let monochrome = match magic {
+            "P4" => true,
+            "P6" if token()? == "255" => false,
+            _ => return None,
+        };
+        // Single whitespace character separates the header from the data.
+        let body = data.get(pos + 1..)?;
+
+        let pixels = if monochrome {
+            let stride = (width + 7) / 8;
+            if body.len() != stride * height {
+                return None;
+            }
+            (0..width * height)
+                .map(|i| {
+                    let (x, y) = (i % width, i / width);
+                    let black = body[y * stride + x / 8] & (0x80 >> (x % 8)) != 0;
+                    if black {
+                        [0x00; 3]
+                    } else {
+                        [0xFF; 3]
+                    }
+                })
+                .collect()
+        } else {
+            if body.len() != 3 * width * height {
+                return None;
+            }
+            body.chunks(3).map(|px| [px[0], px[1], px[2]]).collect()
+        };
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Copy of `self` with the pixels differing from `other` in red and the
+    /// rest dimmed, together with the number of the differing pixels.
+    fn diff(&self, other: &Self) -> (Self, usize) {
+        let mut count = 0;
+        let pixels = self
+            .pixels
+            .iter()
+            .zip(other.pixels.iter())
+            .map(|(a, b)| {
+                if a == b {
+                    a.map(|c| c / 4)
+                } else {
+                    count += 1;
+                    [0xFF, 0x00, 0x00]
+                }
+            })
+            .collect();
+        let image = Self {
+            width: self.width,
+            height: self.height,
+            pixels,
+        };
+        (image, count)
+    }
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    let ext = if MONOCHROME { "pbm" } else { "ppm" };
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("golden")
+        .join(MODEL_DIR)
+        .join(format!("{}.{}", name, ext))
+}
+
+/// Draw `component` over the whole screen and read the pixels back.
+fn render(component: &mut impl Component) -> Image {
+    display::clear();
+    component.place(screen());
+    let mut ctx = EventCtx::new();
+    component.event(&mut ctx, Event::Attach);
+    component.paint();
+
+    let (width, height) = (WIDTH as usize, HEIGHT as usize);
+    let mut data = vec![0; width * height];
+    copy_rgb565(&mut data, WIDTH as u16, HEIGHT as u16);
+    Image::from_rgb565(width, height, &data)
+}
+
+/// Render the component returned by `create` and compare the result with the
+/// golden image called `name`. The component is created only after the UI
+/// randomness is seeded.
+pub fn assert_snapshot<C: Component>(name: &str, create: impl FnOnce() -> C) {
+    let _lock = SCREEN_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _replay_lock = random::REPLAY_LOCK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    // Render off-screen when running headless.
+    if env::var_os("SDL_VIDEODRIVER").is_none() {
+        env::set_var("SDL_VIDEODRIVER", "dummy");
+    }
+
+    random::set_replay_seed(Some(REPLAY_SEED));
+    let mut component = create();
+    let actual = render(&mut component);
+    random::set_replay_seed(None);
+
+    let golden = golden_path(name);
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(golden.parent().unwrap()).unwrap();
+        fs::write(&golden, actual.encode(MONOCHROME)).unwrap();
+        return;
+    }
+
+    let expected = match fs::read(&golden) {
+        Ok(data) => Image::decode(&data)
+            .unwrap_or_else(|| panic!("{}: invalid golden image", golden.display())),
+        Err(_) => panic!(
+            "{}: golden image missing, run with UPDATE_GOLDEN=1 to create it",
+            golden.display()
+        ),
+    };
+    // Monochrome goldens only keep one bit per pixel, compare in that form.
+    let actual = if MONOCHROME {
+        Image::decode(&actual.encode(true)).unwrap()
+    } else {
+        actual
+    };
+    if actual == expected {
+        return;
+    }
+
+    let report_dir = env::temp_dir().join("trezor-snapshots").join(MODEL_DIR);
+    fs::create_dir_all(&report_dir).unwrap();
+    let actual_path = report_dir.join(format!("{}.actual.ppm", name));
+    let diff_path = report_dir.join(format!("{}.diff.ppm", name));
+    fs::write(&actual_path, actual.encode(false)).unwrap();
+    if (actual.width, actual.height) != (expected.width, expected.height) {
+        panic!(
+            "{}: size {}x{} differs from the golden {}x{}, actual screen in {}",
+            name,
+            actual.width,
+            actual.height,
+            expected.width,
+            expected.height,
+            actual_path.display()
+        );
+    }
+    let (diff, count) = actual.diff(&expected);
+    fs::write(&diff_path, diff.encode(false)).unwrap();
+    panic!(
+        "{}: {} pixels differ from {}, actual screen in {}, diff in {}",
+        name,
+        count,
+        golden.display(),
+        actual_path.display(),
+        diff_path.display()
+    );
+}
+
+#[test]
+fn image_roundtrip() {
+    let image = Image::from_rgb565(
+        9,
+        2,
+        &[
+            0xFFFF, 0x0000, 0xF800, 0, 0, 0, 0, 0, 0xFFFF, 0, 0, 0, 0, 0, 0, 0, 0, 0x07E0,
+        ],
+    );
+    assert!(Image::decode(&image.encode(false)) == Some(image));
+
+    let mono = Image::from_rgb565(
+        9,
+        2,
+        &[
+            0xFFFF, 0, 0, 0, 0, 0, 0, 0, 0xFFFF, 0, 0, 0, 0, 0, 0, 0, 0, 0xFFFF,
+        ],
+    );
+    assert!(Image::decode(&mono.encode(true)) == Some(mono));
+}
@@ -0,0 +1,48 @@
+//! Tone patterns telling the outcome of a flow without looking at the
+//! screen, and a click for moving through a list. Played on the piezo buzzer
+//! of boards that have one, and only when the user has turned audio feedback
+//! on.
+
+use crate::ui::component::EventCtx;
+
+/// Frequency in Hz, 0 for a pause, and duration in milliseconds.
+#[cfg(feature = "audio")]
+type Tone = (u16, u16);
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Feedback {
+    Confirm,
+    Reject,
+    Error,
+    /// Moved to the next item, short enough to repeat while scrolling fast.
+    Tick,
+}
+
+impl Feedback {
+    /// Confirmation rises, rejection falls and an error is a low tone
+    /// repeated, so that they can be told apart even in a noisy place.
+    #[cfg(feature = "audio")]
+    fn pattern(self) -> &'static [Tone] {
+        match self {
+            Self::Confirm => &[(1760, 60), (0, 30), (2637, 90)],
+            Self::Reject => &[(1319, 60), (0, 30), (880, 90)],
+            Self::Error => &[(440, 120), (0, 60), (440, 120), (0, 60), (440, 120)],
+            Self::Tick => &[(4000, 2)],
+        }
+    }
+
+    /// Play the pattern if audio feedback is on. Blocks until it has
+    /// finished, which is a few hundred milliseconds at most.
+    #[cfg(feature = "audio")]
+    pub fn play(self, ctx: &EventCtx) {
+        if !ctx.accessibility().audio_feedback {
+            return;
+        }
+        for &(freq_hz, duration_ms) in self.pattern() {
+            crate::trezorhal::audio::play_tone(freq_hz, duration_ms);
+        }
+    }
+
+    #[cfg(not(feature = "audio"))]
+    pub fn play(self, _ctx: &EventCtx) {}
+}
@@ -0,0 +1,175 @@
+//! Resource usage of layouts, to help with sizing the stack and the fixed-size
+//! buffers scattered through the components. Without the `ui_stats` feature
+//! nothing is recorded.
+//!
+//! Usage is recorded into `UiState::stats`. While a layout handles an event or
+//! paints, its own `Stats` are swapped in by `enter`. What is recorded outside
+//! of any layout, i.e. while the components are being constructed, is picked up
+//! by the next layout created with `take`.
+
+use core::ops::DerefMut;
+
+#[cfg(feature = "ui_stats")]
+use crate::ui::context::with_state;
+
+/// Peak utilization of one kind of fixed-size buffer.
+#[cfg(feature = "ui_stats")]
+struct BufferUsage {
+    name: &'static str,
+    capacity: usize,
+    peak: usize,
+}
+
+/// Number of distinct buffers tracked, the rest is ignored.
+#[cfg(feature = "ui_stats")]
+const MAX_BUFFERS: usize = 16;
+
+#[cfg(feature = "ui_stats")]
+#[derive(Default)]
+pub struct Stats {
+    /// Deepest stack usage sampled, in bytes.
+    stack_peak: usize,
+    buffers: heapless::Vec<BufferUsage, MAX_BUFFERS>,
+}
+
+#[cfg(not(feature = "ui_stats"))]
+#[derive(Default)]
+pub struct Stats;
+
+#[cfg(feature = "ui_stats")]
+impl Stats {
+    pub(crate) const fn new() -> Self {
+        Self {
+            stack_peak: 0,
+            buffers: heapless::Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "ui_stats")]
+#[inline(always)]
+fn stack_pointer() -> usize {
+    let marker = 0u8;
+    core::ptr::addr_of!(marker) as usize
+}
+
+/// Move out everything recorded outside of the layouts so far.
+#[cfg(feature = "ui_stats")]
+pub fn take() -> Stats {
+    with_state(|s| core::mem::take(&mut s.stats))
+}
+
+#[cfg(not(feature = "ui_stats"))]
+pub fn take() -> Stats {
+    Stats
+}
+
+/// Record into `stats` until the returned guard is dropped. The stack depth is
+/// measured from the outermost call.
+#[cfg(feature = "ui_stats")]
+pub fn enter<S: DerefMut<Target = Stats>>(mut stats: S) -> Recording<S> {
+    let outermost = with_state(|s| {
+        let outermost = s.stack_base == 0;
+        if outermost {
+            s.stack_base = stack_pointer();
+        }
+        core::mem::swap(&mut s.stats, &mut *stats);
+        outermost
+    });
+    Recording { stats, outermost }
+}
+
+#[cfg(not(feature = "ui_stats"))]
+pub fn enter<S: DerefMut<Target = Stats>>(stats: S) -> Recording<S> {
+    Recording { _stats: stats }
+}
+
+/// Guard returned by `enter`.
+#[cfg(feature = "ui_stats")]
+pub struct Recording<S: DerefMut<Target = Stats>> {
+    stats: S,
+    outermost: bool,
+}
+
+#[cfg(not(feature = "ui_stats"))]
+pub struct Recording<S: DerefMut<Target = Stats>> {
+    _stats: S,
+}
+
+#[cfg(feature = "ui_stats")]
+impl<S: DerefMut<Target = Stats>> Drop for Recording<S> {
+    fn drop(&mut self) {
+        with_state(|s| {
+            core::mem::swap(&mut s.stats, &mut *self.stats);
+            if self.outermost {
+                s.stack_base = 0;
+            }
+        });
+    }
+}
+
+/// Sample the current stack depth. The peak is only as accurate as the
+/// sampling points are close to the deepest calls.
+#[cfg(feature = "ui_stats")]
+#[inline(always)]
+pub fn sample_stack() {
+    let sp = stack_pointer();
+    with_state(|s| {
+        if s.stack_base != 0 {
+            // The stack grows downwards on all the supported platforms.
+            let depth = s.stack_base.saturating_sub(sp);
+            s.stats.stack_peak = s.stats.stack_peak.max(depth);
+        }
+    });
+}
+
+#[cfg(not(feature = "ui_stats"))]
+#[inline(always)]
+pub fn sample_stack() {}
+
+/// Record that `used` out of `capacity` items of the buffer called `name` are
+/// taken.
+#[cfg(feature = "ui_stats")]
+pub fn record_buffer(name: &'static str, used: usize, capacity: usize) {
+    with_state(|s| {
+        let buffers = &mut s.stats.buffers;
+        let existing = buffers
+            .iter_mut()
+            .find(|b| b.name == name && b.capacity == capacity);
+        match existing {
+            Some(buffer) => buffer.peak = buffer.peak.max(used),
+            None => {
+                // Too many kinds of buffers, drop the rest.
+                let _ = buffers.push(BufferUsage {
+                    name,
+                    capacity,
+                    peak: used,
+                });
+            }
+        }
+    });
+}
+
+#[cfg(not(feature = "ui_stats"))]
+#[inline(always)]
+pub fn record_buffer(_name: &'static str, _used: usize, _capacity: usize) {}
+
+#[cfg(feature = "ui_stats")]
+impl Stats {
+    /// Write the statistics under the `ui_stats` key: the peak stack depth in
+    /// bytes and the peak item count of each buffer.
+    pub fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.in_child("ui_stats", &|t| {
+            t.int("stack_peak", self.stack_peak as i64);
+            t.in_list("buffers", &|l| {
+                for buffer in self.buffers.iter() {
+                    l.in_child(&|t| {
+                        t.string("name", buffer.name);
+                        t.int("peak", buffer.peak as i64);
+                        t.int("capacity", buffer.capacity as i64);
+                    });
+                }
+            });
+        });
+    }
+}
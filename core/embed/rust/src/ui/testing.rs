@@ -0,0 +1,232 @@
+//! Helpers for unit tests of components.
+//!
+//! `Harness` places a component on the whole screen and feeds it events
+//! without the UI loop around it. It remembers whether the component asked to
+//! be painted and which timers it requested, so that the test can check them
+//! and deliver the timers when it wants. Nothing is drawn on the screen.
+//!
+//! ```ignore
+//! let mut pin = Harness::new(PinEntry::new("ENTER PIN", ""));
+//! pin.click(PhysicalButton::Right);
+//! assert!(pin.paint_requested());
+//! ```
+
+use crate::{
+    time::Duration,
+    ui::{
+        component::{AttrUpdate, Child, Component, Event, EventCtx, TimerToken},
+        constant::screen,
+    },
+};
+
+#[cfg(feature = "button")]
+use crate::ui::event::{ButtonEvent, PhysicalButton};
+#[cfg(feature = "touch")]
+use crate::ui::{event::TouchEvent, geometry::Point};
+
+/// How many intermediate points a swipe consists of.
+#[cfg(feature = "touch")]
+const SWIPE_STEPS: i16 = 4;
+
+#[cfg(feature = "button")]
+pub fn press(button: PhysicalButton) -> Event<'static> {
+    Event::Button(ButtonEvent::ButtonPressed(button))
+}
+
+#[cfg(feature = "button")]
+pub fn release(button: PhysicalButton) -> Event<'static> {
+    Event::Button(ButtonEvent::ButtonReleased(button))
+}
+
+#[cfg(feature = "touch")]
+pub fn touch_start(x: i16, y: i16) -> Event<'static> {
+    Event::Touch(TouchEvent::TouchStart(Point::new(x, y)))
+}
+
+#[cfg(feature = "touch")]
+pub fn touch_move(x: i16, y: i16) -> Event<'static> {
+    Event::Touch(TouchEvent::TouchMove(Point::new(x, y)))
+}
+
+#[cfg(feature = "touch")]
+pub fn touch_end(x: i16, y: i16) -> Event<'static> {
+    Event::Touch(TouchEvent::TouchEnd(Point::new(x, y)))
+}
+
+pub struct Harness<T> {
+    component: Child<T>,
+    ctx: EventCtx,
+    /// Requested timers not delivered yet, with their deadlines.
+    timers: Vec<(TimerToken, Duration)>,
+    /// Whether any of the events so far asked for a paint.
+    paint_requested: bool,
+}
+
+impl<T> Harness<T>
+where
+    T: Component,
+{
+    /// Place `component` on the whole screen and attach it.
+    pub fn new(component: T) -> Self {
+        let mut harness = Self {
+            component: Child::new(component),
+            ctx: EventCtx::new(),
+            timers: Vec::new(),
+            paint_requested: false,
+        };
+        harness.component.place(screen());
+        harness.event(Event::Attach);
+        harness.clear_paint();
+        harness
+    }
+
+    pub fn inner(&self) -> &T {
+        self.component.inner()
+    }
+
+    /// Deliver `event`, returning the message the component emitted.
+    pub fn event(&mut self, event: Event) -> Option<T::Msg> {
+        self.ctx.clear();
+        let msg = self.component.event(&mut self.ctx, event);
+        self.after_event();
+        msg
+    }
+
+    fn after_event(&mut self) {
+        if self.ctx.needs_place_before_next_event_or_paint() {
+            self.component.place(screen());
+        }
+        while let Some(timer) = self.ctx.pop_timer() {
+            self.timers.push(timer);
+        }
+        if self.component.will_paint() {
+            self.paint_requested = true;
+            self.component.skip_paint();
+        }
+    }
+
+    /// Run `func` on the component as if it was handling an event, e.g. to
+    /// call its methods that need the context.
+    pub fn mutate<R>(&mut self, func: impl FnOnce(&mut EventCtx, &mut T) -> R) -> R {
+        self.ctx.clear();
+        let result = self.component.mutate(&mut self.ctx, func);
+        self.after_event();
+        result
+    }
+
+    /// Change an attribute of the component, returning whether it supports
+    /// the attribute.
+    pub fn update_attr(&mut self, attr: AttrUpdate) -> bool {
+        self.ctx.clear();
+        let supported = self.component.update_attr(&mut self.ctx, attr);
+        self.after_event();
+        supported
+    }
+
+    /// Deliver `events` in order, returning the first message emitted. The
+    /// events after it are not delivered.
+    pub fn events<'a>(&mut self, events: impl IntoIterator<Item = Event<'a>>) -> Option<T::Msg> {
+        events.into_iter().find_map(|event| self.event(event))
+    }
+
+    /// Deliver `event`, panicking when the component emits nothing.
+    pub fn expect_msg(&mut self, event: Event) -> T::Msg {
+        match self.event(event) {
+            Some(msg) => msg,
+            None => panic!("component emitted no message"),
+        }
+    }
+
+    /// Deliver `event`, panicking when the component emits a message.
+    pub fn expect_no_msg(&mut self, event: Event) {
+        if self.event(event).is_some() {
+            panic!("component emitted an unexpected message");
+        }
+    }
+
+    /// Whether the component asked to be painted since the last
+    /// `clear_paint()`.
+    pub fn paint_requested(&self) -> bool {
+        self.paint_requested
+    }
+
+    pub fn clear_paint(&mut self) {
+        self.paint_requested = false;
+    }
+
+    /// Timers requested and not delivered yet, with their deadlines.
+    pub fn timers(&self) -> &[(TimerToken, Duration)] {
+        &self.timers
+    }
+
+    /// Deliver the pending timers, as if their deadlines passed, soonest
+    /// first. Returns the first message emitted, the timers requested while
+    /// delivering them stay pending.
+    pub fn fire_timers(&mut self) -> Option<T::Msg> {
+        let mut timers = core::mem::take(&mut self.timers);
+        timers.sort_by_key(|(_, deadline)| *deadline);
+        let mut result = None;
+        for (token, _) in timers {
+            let msg = self.event(Event::Timer(token));
+            result = result.or(msg);
+        }
+        result
+    }
+
+    /// Deliver the pending timer at `index` of `timers()`, the others stay
+    /// pending.
+    pub fn fire_timer(&mut self, index: usize) -> Option<T::Msg> {
+        let (token, _) = self.timers.remove(index);
+        self.event(Event::Timer(token))
+    }
+
+    /// Press and release `button`.
+    #[cfg(feature = "button")]
+    pub fn click(&mut self, button: PhysicalButton) -> Option<T::Msg> {
+        self.events([press(button), release(button)])
+    }
+
+    /// Press both buttons and release them.
+    #[cfg(feature = "button")]
+    pub fn click_both(&mut self) -> Option<T::Msg> {
+        self.events([
+            press(PhysicalButton::Left),
+            press(PhysicalButton::Right),
+            release(PhysicalButton::Left),
+            release(PhysicalButton::Right),
+        ])
+    }
+
+    /// Touch `point` and lift the finger at the same place.
+    #[cfg(feature = "touch")]
+    pub fn tap(&mut self, point: Point) -> Option<T::Msg> {
+        self.events([touch_start(point.x, point.y), touch_end(point.x, point.y)])
+    }
+
+    /// Move the finger from `from` to `to` in a few steps.
+    #[cfg(feature = "touch")]
+    pub fn swipe(&mut self, from: Point, to: Point) -> Option<T::Msg> {
+        let moves = (1..SWIPE_STEPS).map(|i| {
+            touch_move(
+                from.x + (to.x - from.x) * i / SWIPE_STEPS,
+                from.y + (to.y - from.y) * i / SWIPE_STEPS,
+            )
+        });
+        let start = core::iter::once(touch_start(from.x, from.y));
+        let end = core::iter::once(touch_end(to.x, to.y));
+        self.events(start.chain(moves).chain(end))
+    }
+}
+
+#[test]
+fn timeout_fires_once() {
+    use crate::ui::component::Timeout;
+
+    let mut timeout = Harness::new(Timeout::new(500));
+    assert_eq!(timeout.timers().len(), 1);
+    assert_eq!(timeout.timers()[0].1, Duration::from_millis(500));
+    assert!(timeout.fire_timers().is_some());
+    assert!(timeout.timers().is_empty());
+    assert!(timeout.fire_timers().is_none());
+    assert!(!timeout.paint_requested());
+}
@@ -0,0 +1,63 @@
+use crate::ui::{
+    component::text::TextStyle,
+    display::{Color, Font, Icon},
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ThemeVariant {
+    Normal,
+    /// Dark content on a light background. The shades of grey, including the
+    /// black background and the white text, are swapped, colors carrying a
+    /// meaning, e.g. the green of a confirm button, are kept.
+    Inverted,
+}
+
+impl ThemeVariant {
+    /// `color` of the normal variant as it is painted in this one.
+    pub fn paint(self, color: Color) -> Color {
+        match self {
+            Self::Normal => color,
+            Self::Inverted => color.with_inverted_greys(),
+        }
+    }
+}
+
+/// Meaning of a button. Layouts pick the meaning, models with color display
+/// then color the button consistently according to it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ButtonIntent {
+    /// Neither positive nor risky, e.g. "CONTINUE".
+    Neutral,
+    /// Positive action, e.g. confirming a transaction.
+    Confirm,
+    /// Going on despite a warning.
+    Caution,
+    /// Irreversible action, e.g. wiping or downgrading the device.
+    Destructive,
+}
+
+/// Visual properties of a model's UI. Each model exposes its implementation
+/// through `theme::current()`, so that the code shared between the models,
+/// e.g. the help or the wallet badge, can paint in the style of the model.
+///
+/// The colors are those of the normal variant. The variant selected in
+/// `UiSettings` is applied by the display primitives when they paint, see
+/// `display::themed`, so every component follows it without asking.
+pub trait Theme {
+    fn variant(&self) -> ThemeVariant;
+    /// Default foreground (text & icon) color.
+    fn fg(&self) -> Color;
+    /// Default background color.
+    fn bg(&self) -> Color;
+    /// Color of secondary text.
+    fn fg_secondary(&self) -> Color;
+    fn font_normal(&self) -> Font;
+    fn font_bold(&self) -> Font;
+    fn font_mono(&self) -> Font;
+    fn text_normal(&self) -> &'static TextStyle;
+    fn text_bold(&self) -> &'static TextStyle;
+    /// Padding between the screen edge and the content.
+    fn content_padding(&self) -> i16;
+    fn icon_confirm(&self) -> Icon;
+    fn icon_cancel(&self) -> Icon;
+}
@@ -0,0 +1,616 @@
+use core::{
+    cell::{Cell, UnsafeCell},
+    cmp::Ordering,
+    ops::Deref,
+    str,
+};
+
+use crate::{error::Error, ui::display::Glyph};
+
+use super::heatshrink;
+
+/// Magic number at the beginning of the blob.
+const MAGIC: &[u8; 4] = b"TRTR";
+/// Magic number of the blob with compressed strings.
+const MAGIC_COMPRESSED: &[u8; 4] = b"TRTZ";
+
+/// Length of the NUL-padded language tag, e.g. `cs-CZ`.
+const LANGUAGE_LEN: usize = 8;
+
+/// Size of the magic, the language tag and the number of strings.
+const HEADER_LEN: usize = MAGIC.len() + LANGUAGE_LEN + 2;
+
+/// Size of the compression parameters.
+const PARAMS_LEN: usize = 4;
+
+/// Most chunks of compressed strings in a blob.
+const MAX_CHUNKS: usize = 64;
+
+/// Longest chunk of compressed strings once decompressed.
+pub const MAX_CHUNK_LEN: usize = 1024;
+
+/// Number of chunks kept decompressed at a time.
+const CACHE_SLOTS: usize = 2;
+
+/// Chunk of an empty slot of the cache.
+const NO_CHUNK: u16 = u16::MAX;
+
+/// Strings of one language, borrowed from a blob in the following format,
+/// all numbers little-endian:
+///
+/// - magic `TRTR`
+/// - language tag, NUL-padded to 8 bytes
+/// - number of strings `n`, `u16`
+/// - `n + 1` offsets of the strings into the data, `u16`
+/// - data, UTF-8
+/// - optionally the font: number of glyphs `m`, `u16`, then `m` pairs of a
+///   code point and an offset into the glyph data, `u32` each, sorted by the
+///   code point, then the glyph data in the format of the built-in fonts
+///
+/// String `i` spans from offset `i` to offset `i + 1`. The blob does not need
+/// to cover all the strings of the firmware. Strings past `n` as well as the
+/// empty ones are considered missing. The font provides characters the
+/// built-in fonts lack, e.g. CJK ones.
+///
+/// The blob with the magic `TRTZ` has the data compressed, so that larger
+/// languages fit in the flash. The offsets then point into the decompressed
+/// data and are followed by:
+///
+/// - heatshrink window and lookahead sizes in bits, `u8` each
+/// - number of strings per chunk `s`, `u16`
+/// - `ceil(n / s) + 1` offsets of the chunks into the compressed data, `u32`
+/// - compressed data, the chunk `c` holding the strings `c * s` up to `(c + 1)
+///   * s`, at most `MAX_CHUNK_LEN` bytes of them
+///
+/// The blob is only ever read, a chunk is decompressed on the use of any of its
+/// strings into a small cache holding the last few chunks used.
+pub struct Translations<'a> {
+    language: &'a str,
+    offsets: &'a [u8],
+    data: Data<'a>,
+    glyph_table: &'a [u8],
+    glyphs: &'a [u8],
+}
+
+enum Data<'a> {
+    Plain(&'a str),
+    Compressed(Compressed<'a>),
+}
+
+struct Compressed<'a> {
+    window_bits: u8,
+    lookahead_bits: u8,
+    strings_per_chunk: usize,
+    chunk_offsets: &'a [u8],
+    data: &'a [u8],
+    /// Bit of each chunk that failed to decompress.
+    invalid: Cell<u64>,
+    /// Counts the uses of the cache, to tell the least recently used slot.
+    clock: Cell<u32>,
+    cache: [Slot; CACHE_SLOTS],
+}
+
+/// Slot of the cache holding one decompressed chunk.
+struct Slot {
+    chunk: Cell<u16>,
+    /// Number of `Text`s borrowing from the slot, it is only reused once
+    /// there are none.
+    pins: Cell<u8>,
+    last_used: Cell<u32>,
+    buf: UnsafeCell<[u8; MAX_CHUNK_LEN]>,
+}
+
+impl Slot {
+    fn new() -> Self {
+        Self {
+            chunk: Cell::new(NO_CHUNK),
+            pins: Cell::new(0),
+            last_used: Cell::new(0),
+            buf: UnsafeCell::new([0; MAX_CHUNK_LEN]),
+        }
+    }
+}
+
+/// String of the translations. A decompressed one keeps its chunk in the cache
+/// while alive.
+pub struct Text<'t> {
+    text: &'t str,
+    slot: Option<&'t Slot>,
+}
+
+impl Deref for Text<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+impl Drop for Text<'_> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot {
+            slot.pins.set(slot.pins.get() - 1);
+        }
+    }
+}
+
+impl<'a> Translations<'a> {
+    /// Parse and validate `blob`.
+    pub fn new(blob: &'a [u8]) -> Result<Self, Error> {
+        let magic = blob.get(..MAGIC.len());
+        let compressed = magic == Some(MAGIC_COMPRESSED);
+        if blob.len() < HEADER_LEN || (magic != Some(MAGIC) && !compressed) {
+            return Err(value_error!("Invalid translations header."));
+        }
+        let language = &blob[MAGIC.len()..MAGIC.len() + LANGUAGE_LEN];
+        let language_len = language
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(LANGUAGE_LEN);
+        let language = str::from_utf8(&language[..language_len])
+            .map_err(|_| value_error!("Invalid translations language."))?;
+
+        let count = read_u16(&blob[HEADER_LEN - 2..], 0) as usize;
+        let table_len = 2 * (count + 1);
+        if blob.len() < HEADER_LEN + table_len {
+            return Err(value_error!("Translations table truncated."));
+        }
+        let (offsets, rest) = blob[HEADER_LEN..].split_at(table_len);
+        let data_len = read_u16(offsets, count) as usize;
+        let (data, font) = if compressed {
+            split_compressed(rest, count)?
+        } else {
+            if rest.len() < data_len {
+                return Err(value_error!("Translations data truncated."));
+            }
+            let (data, font) = rest.split_at(data_len);
+            let data =
+                str::from_utf8(data).map_err(|_| value_error!("Invalid translations data."))?;
+            (Data::Plain(data), font)
+        };
+        let (glyph_table, glyphs) = split_font(font)?;
+
+        let result = Self {
+            language,
+            offsets,
+            data,
+            glyph_table,
+            glyphs,
+        };
+        let mut prev = 0;
+        for i in 0..=count {
+            let offset = result.offset(i);
+            // Character boundaries of compressed strings are only known after
+            // decompressing them.
+            let boundary = match &result.data {
+                Data::Plain(data) => data.is_char_boundary(offset),
+                Data::Compressed(_) => true,
+            };
+            if offset < prev || offset > data_len || !boundary {
+                return Err(value_error!("Invalid translations offset."));
+            }
+            prev = offset;
+        }
+        if let Data::Compressed(compressed) = &result.data {
+            let per_chunk = compressed.strings_per_chunk;
+            let fits = (0..count).step_by(per_chunk).all(|first| {
+                let last = (first + per_chunk).min(count);
+                result.offset(last) - result.offset(first) <= MAX_CHUNK_LEN
+            });
+            if !fits {
+                return Err(value_error!("Translations chunk too long."));
+            }
+        }
+        let mut prev = None;
+        for i in 0..result.glyph_count() {
+            let (code_point, offset) = result.glyph_entry(i);
+            let ordered = prev.map_or(true, |prev| prev < code_point);
+            let header = glyphs.get(offset..offset + Glyph::HEADER_LEN);
+            let fits = header.map_or(false, |h| {
+                let len = Glyph::data_len(h[0].into(), h[1].into());
+                offset + Glyph::HEADER_LEN + len <= glyphs.len()
+            });
+            if !ordered || !fits {
+                return Err(value_error!("Invalid translations font."));
+            }
+            prev = Some(code_point);
+        }
+        Ok(result)
+    }
+
+    /// Whether the strings have to be decompressed.
+    pub fn is_compressed(&self) -> bool {
+        matches!(self.data, Data::Compressed(_))
+    }
+
+    /// Language tag of the translations.
+    pub fn language(&self) -> &'a str {
+        self.language
+    }
+
+    /// String at `index`, `None` if it is missing. A compressed one is also
+    /// `None` when it fails to decompress or when the cache is full of the
+    /// chunks of strings still in use.
+    pub fn get(&self, index: usize) -> Option<Text<'_>> {
+        let count = self.offsets.len() / 2 - 1;
+        if index >= count {
+            return None;
+        }
+        let (start, end) = (self.offset(index), self.offset(index + 1));
+        if start == end {
+            return None;
+        }
+        match &self.data {
+            Data::Plain(data) => Some(Text {
+                text: &data[start..end],
+                slot: None,
+            }),
+            Data::Compressed(compressed) => {
+                let chunk = index / compressed.strings_per_chunk;
+                let chunk_start = self.offset(chunk * compressed.strings_per_chunk);
+                let (slot, text) = self.load_chunk(compressed, chunk)?;
+                slot.pins.set(slot.pins.get() + 1);
+                Some(Text {
+                    text: &text[start - chunk_start..end - chunk_start],
+                    slot: Some(slot),
+                })
+            }
+        }
+    }
+
+    /// Slot of the cache holding the decompressed strings of `chunk`,
+    /// decompressing them into the least recently used free slot if needed.
+    fn load_chunk<'t>(
+        &self,
+        compressed: &'t Compressed<'a>,
+        chunk: usize,
+    ) -> Option<(&'t Slot, &'t str)> {
+        let count = self.offsets.len() / 2 - 1;
+        let first = chunk * compressed.strings_per_chunk;
+        let last = (first + compressed.strings_per_chunk).min(count);
+        let (start, end) = (self.offset(first), self.offset(last));
+        let len = end - start;
+
+        let now = compressed.clock.get().wrapping_add(1);
+        compressed.clock.set(now);
+        if compressed.invalid.get() & (1 << chunk) != 0 {
+            return None;
+        }
+        let cached = compressed
+            .cache
+            .iter()
+            .find(|slot| slot.chunk.get() == chunk as u16);
+        let slot = match cached {
+            Some(slot) => slot,
+            None => {
+                let slot = compressed
+                    .cache
+                    .iter()
+                    .filter(|slot| slot.pins.get() == 0)
+                    .max_by_key(|slot| now.wrapping_sub(slot.last_used.get()))?;
+                slot.chunk.set(NO_CHUNK);
+                // SAFETY: No `Text` borrows from the slot, the buffer is only
+                // read through them and below.
+                let buf = unsafe { &mut (*slot.buf.get())[..len] };
+                if !self.decompress(compressed, chunk, first, last, buf) {
+                    compressed
+                        .invalid
+                        .set(compressed.invalid.get() | 1 << chunk);
+                    return None;
+                }
+                slot.chunk.set(chunk as u16);
+                slot
+            }
+        };
+        slot.last_used.set(now);
+        // SAFETY: Only written while there are no `Text`s borrowing from the
+        // slot, validated as UTF-8 when decompressed.
+        let text = unsafe { str::from_utf8_unchecked(&(*slot.buf.get())[..len]) };
+        Some((slot, text))
+    }
+
+    /// Decompress `chunk` holding the strings `first` up to `last` into `buf`,
+    /// `false` when the data is damaged.
+    fn decompress(
+        &self,
+        compressed: &Compressed<'a>,
+        chunk: usize,
+        first: usize,
+        last: usize,
+        buf: &mut [u8],
+    ) -> bool {
+        let start = self.offset(first);
+        let input = compressed
+            .data
+            .get(read_u32(compressed.chunk_offsets, chunk) as usize..)
+            .and_then(|rest| {
+                let chunk_len = (read_u32(compressed.chunk_offsets, chunk + 1)
+                    - read_u32(compressed.chunk_offsets, chunk))
+                    as usize;
+                rest.get(..chunk_len)
+            });
+        let valid = input.is_some_and(|input| {
+            heatshrink::decompress(
+                input,
+                compressed.window_bits,
+                compressed.lookahead_bits,
+                buf,
+            )
+            .is_ok()
+        });
+        match str::from_utf8(buf) {
+            Ok(text) if valid => {
+                (first..=last).all(|i| text.is_char_boundary(self.offset(i) - start))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the blob comes with a font.
+    pub fn has_glyphs(&self) -> bool {
+        self.glyph_count() > 0
+    }
+
+    fn offset(&self, i: usize) -> usize {
+        read_u16(self.offsets, i) as usize
+    }
+
+    fn glyph_count(&self) -> usize {
+        self.glyph_table.len() / 8
+    }
+
+    fn glyph_entry(&self, i: usize) -> (u32, usize) {
+        let code_point = read_u32(self.glyph_table, 2 * i);
+        let offset = read_u32(self.glyph_table, 2 * i + 1);
+        (code_point, offset as usize)
+    }
+}
+
+impl Translations<'static> {
+    /// Glyph of `ch` from the font of the blob.
+    pub fn glyph(&self, ch: char) -> Option<Glyph> {
+        let (mut low, mut high) = (0, self.glyph_count());
+        while low < high {
+            let mid = (low + high) / 2;
+            let (code_point, offset) = self.glyph_entry(mid);
+            match code_point.cmp(&(ch as u32)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Glyph::from_slice(&self.glyphs[offset..]),
+            }
+        }
+        None
+    }
+}
+
+/// Split the compressed strings, up to the font, from `rest` of the blob after
+/// the offsets of `count` strings.
+fn split_compressed(rest: &[u8], count: usize) -> Result<(Data<'_>, &[u8]), Error> {
+    let truncated = || value_error!("Translations data truncated.");
+    let params = rest.get(..PARAMS_LEN).ok_or_else(truncated)?;
+    let (window_bits, lookahead_bits) = (params[0], params[1]);
+    let strings_per_chunk = read_u16(params, 1) as usize;
+    if !heatshrink::WINDOW_BITS.contains(&window_bits)
+        || !(3..window_bits).contains(&lookahead_bits)
+        || strings_per_chunk == 0
+    {
+        return Err(value_error!("Invalid translations compression."));
+    }
+    let chunks = (count + strings_per_chunk - 1) / strings_per_chunk;
+    if chunks > MAX_CHUNKS {
+        return Err(value_error!("Too many translations chunks."));
+    }
+    let table_len = 4 * (chunks + 1);
+    let chunk_offsets = rest[PARAMS_LEN..].get(..table_len).ok_or_else(truncated)?;
+    let data_len = read_u32(chunk_offsets, chunks) as usize;
+    let (data, font) = rest[PARAMS_LEN + table_len..]
+        .get(data_len..)
+        .map(|font| (&rest[PARAMS_LEN + table_len..][..data_len], font))
+        .ok_or_else(truncated)?;
+    let ordered = (0..chunks).all(|c| read_u32(chunk_offsets, c) <= read_u32(chunk_offsets, c + 1));
+    if !ordered {
+        return Err(value_error!("Invalid translations chunk offset."));
+    }
+    let compressed = Compressed {
+        window_bits,
+        lookahead_bits,
+        strings_per_chunk,
+        chunk_offsets,
+        data,
+        invalid: Cell::new(0),
+        clock: Cell::new(0),
+        cache: core::array::from_fn(|_| Slot::new()),
+    };
+    Ok((Data::Compressed(compressed), font))
+}
+
+/// Split the optional font section into the glyph table and the glyph data.
+fn split_font(font: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    if font.is_empty() {
+        return Ok((&[], &[]));
+    }
+    let table_len = font
+        .get(..2)
+        .map(|count| 8 * read_u16(count, 0) as usize)
+        .filter(|table_len| font.len() >= 2 + table_len)
+        .ok_or(value_error!("Translations font truncated."))?;
+    Ok(font[2..].split_at(table_len))
+}
+
+/// `i`-th little-endian `u16` of `data`.
+fn read_u16(data: &[u8], i: usize) -> u16 {
+    u16::from_le_bytes([data[2 * i], data[2 * i + 1]])
+}
+
+/// `i`-th little-endian `u32` of `data`.
+fn read_u32(data: &[u8], i: usize) -> u32 {
+    let bytes = &data[4 * i..4 * i + 4];
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// Uncompressed blob of `strings` in `language`.
+    pub fn build(language: &str, strings: &[&str]) -> Vec<u8> {
+        build_with_font(language, strings, &[])
+    }
+
+    /// Blob with a font of 2x2 glyphs of the given code points.
+    fn build_with_font(language: &str, strings: &[&str], chars: &[char]) -> Vec<u8> {
+        let mut blob = MAGIC.to_vec();
+        let mut tag = [0; LANGUAGE_LEN];
+        tag[..language.len()].copy_from_slice(language.as_bytes());
+        blob.extend_from_slice(&tag);
+        blob.extend_from_slice(&(strings.len() as u16).to_le_bytes());
+        let mut offset = 0u16;
+        blob.extend_from_slice(&offset.to_le_bytes());
+        for s in strings {
+            offset += s.len() as u16;
+            blob.extend_from_slice(&offset.to_le_bytes());
+        }
+        for s in strings {
+            blob.extend_from_slice(s.as_bytes());
+        }
+        if !chars.is_empty() {
+            let glyph_len = Glyph::HEADER_LEN + Glyph::data_len(2, 2);
+            blob.extend_from_slice(&(chars.len() as u16).to_le_bytes());
+            for (i, ch) in chars.iter().enumerate() {
+                blob.extend_from_slice(&(*ch as u32).to_le_bytes());
+                blob.extend_from_slice(&((i * glyph_len) as u32).to_le_bytes());
+            }
+            for i in 0..chars.len() {
+                blob.extend_from_slice(&[2, 2, 3 + i as u8, 0, 2]);
+                blob.resize(blob.len() + Glyph::data_len(2, 2), 0);
+            }
+        }
+        blob
+    }
+
+    /// Compressed blob with `per_chunk` strings in a chunk.
+    fn build_compressed(language: &str, strings: &[&str], per_chunk: usize) -> Vec<u8> {
+        let plain = build(language, strings);
+        let (header, data) = plain.split_at(HEADER_LEN + 2 * (strings.len() + 1));
+        let mut blob = MAGIC_COMPRESSED.to_vec();
+        blob.extend_from_slice(&header[MAGIC.len()..]);
+        blob.extend_from_slice(&[8, 4]);
+        blob.extend_from_slice(&(per_chunk as u16).to_le_bytes());
+        let mut compressed = Vec::new();
+        let mut start = 0;
+        blob.extend_from_slice(&0u32.to_le_bytes());
+        for chunk in strings.chunks(per_chunk) {
+            let len: usize = chunk.iter().map(|s| s.len()).sum();
+            let input = &data[start..start + len];
+            compressed.extend(heatshrink::tests::compress(input, 8, 4));
+            blob.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+            start += len;
+        }
+        blob.extend(compressed);
+        blob
+    }
+
+    #[test]
+    fn lookup() {
+        let blob = build("cs-CZ", &["ZRUŠIT", "", "POKRAČOVAT"]);
+        let translations = Translations::new(&blob).unwrap();
+        assert_eq!(translations.language(), "cs-CZ");
+        assert_eq!(translations.get(0).as_deref(), Some("ZRUŠIT"));
+        assert!(translations.get(1).is_none());
+        assert_eq!(translations.get(2).as_deref(), Some("POKRAČOVAT"));
+        assert!(translations.get(3).is_none());
+    }
+
+    #[test]
+    fn font() {
+        let blob = build_with_font("ja", &["キャンセル"], &['ン', '日']);
+        let blob: &'static [u8] = Box::leak(blob.into_boxed_slice());
+        let translations = Translations::new(blob).unwrap();
+        assert!(translations.has_glyphs());
+        assert_eq!(translations.get(0).as_deref(), Some("キャンセル"));
+        assert_eq!(translations.glyph('ン').map(|g| g.adv), Some(3));
+        assert_eq!(translations.glyph('日').map(|g| g.adv), Some(4));
+        assert!(translations.glyph('本').is_none());
+
+        // Glyphs not sorted by the code point.
+        let blob = build_with_font("ja", &[], &['日', 'ン']);
+        assert!(Translations::new(&blob).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        let blob = build("de", &["ABBRECHEN", "WEITER"]);
+        assert!(Translations::new(&blob[1..]).is_err());
+        assert!(Translations::new(&blob[..HEADER_LEN + 4]).is_err());
+
+        // Offset pointing into the middle of a character.
+        let mut blob = build("cs", &["Š"]);
+        blob[HEADER_LEN + 2] = 1;
+        assert!(Translations::new(&blob).is_err());
+    }
+
+    #[test]
+    fn compressed() {
+        let strings = ["ZRUŠIT", "", "POKRAČOVAT", "POKRAČOVAT ZRUŠIT"];
+        let blob = build_compressed("cs-CZ", &strings, 2);
+        let translations = Translations::new(&blob).unwrap();
+        assert!(translations.is_compressed());
+        assert_eq!(translations.language(), "cs-CZ");
+        assert_eq!(translations.get(3).as_deref(), Some("POKRAČOVAT ZRUŠIT"));
+        assert_eq!(translations.get(2).as_deref(), Some("POKRAČOVAT"));
+        assert_eq!(translations.get(0).as_deref(), Some("ZRUŠIT"));
+        assert!(translations.get(1).is_none());
+        assert!(translations.get(4).is_none());
+
+        // Truncated data is caught right away.
+        assert!(Translations::new(&blob[..blob.len() - 1]).is_err());
+
+        // Damaged data only affects the strings of its chunk.
+        let mut damaged = blob.clone();
+        let first_chunk = HEADER_LEN + 2 * 5 + PARAMS_LEN + 4 * 3;
+        damaged[first_chunk] = 0;
+        let translations = Translations::new(&damaged).unwrap();
+        assert!(translations.get(0).is_none());
+        assert_eq!(translations.get(2).as_deref(), Some("POKRAČOVAT"));
+
+        // Chunks have to fit the cache.
+        let long = "A".repeat(MAX_CHUNK_LEN);
+        let blob = build_compressed("cs-CZ", &[&long, "B"], 2);
+        assert!(Translations::new(&blob).is_err());
+        let blob = build_compressed("cs-CZ", &[&long, "B"], 1);
+        assert!(Translations::new(&blob).is_ok());
+    }
+
+    #[test]
+    fn cache() {
+        let strings = ["ZRUŠIT", "POKRAČOVAT", "PODRŽET"];
+        let blob = build_compressed("cs-CZ", &strings, 1);
+        let translations = Translations::new(&blob).unwrap();
+
+        // Strings in use keep their chunks, nothing is left for the third one.
+        let cancel = translations.get(0).unwrap();
+        let next = translations.get(1).unwrap();
+        assert!(translations.get(2).is_none());
+        // Chunks already in the cache are still there.
+        assert_eq!(translations.get(1).as_deref(), Some("POKRAČOVAT"));
+        assert_eq!(&*cancel, "ZRUŠIT");
+        drop(next);
+
+        // The free slot is reused, the one still in use stays intact.
+        assert_eq!(translations.get(2).as_deref(), Some("PODRŽET"));
+        assert_eq!(&*cancel, "ZRUŠIT");
+        drop(cancel);
+
+        // The least recently used chunk goes first.
+        assert_eq!(translations.get(0).as_deref(), Some("ZRUŠIT"));
+        assert_eq!(translations.get(1).as_deref(), Some("POKRAČOVAT"));
+        let cached = |chunk| {
+            let cache = match &translations.data {
+                Data::Compressed(compressed) => &compressed.cache,
+                Data::Plain(_) => unreachable!(),
+            };
+            cache.iter().any(|slot| slot.chunk.get() == chunk)
+        };
+        assert!(cached(0) && cached(1) && !cached(2));
+    }
+}
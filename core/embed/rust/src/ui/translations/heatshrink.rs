@@ -0,0 +1,150 @@
+//! Decoder of the heatshrink compression, LZSS with a bitstream of, MSB
+//! first:
+//!
+//! - `1` followed by a literal byte, or
+//! - `0` followed by the distance minus one on `window_bits` bits and the
+//!   length minus one on `lookahead_bits` bits of a copy of earlier output.
+//!
+//! The whole output is kept, so it doubles as the window and the decoder
+//! needs no memory of its own.
+
+use crate::error::Error;
+
+/// Range of the window size the encoder supports, in bits.
+pub const WINDOW_BITS: core::ops::RangeInclusive<u8> = 4..=15;
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    /// Position in bits.
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read(&mut self, bits: u8) -> Option<u16> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.data.get(self.pos / 8)?;
+            let bit = (byte >> (7 - self.pos % 8)) & 1;
+            value = (value << 1) | bit as u16;
+            self.pos += 1;
+        }
+        Some(value)
+    }
+}
+
+/// Decompress `input` filling the whole `output`. Padding after the last
+/// complete item is ignored.
+pub fn decompress(
+    input: &[u8],
+    window_bits: u8,
+    lookahead_bits: u8,
+    output: &mut [u8],
+) -> Result<(), Error> {
+    if !WINDOW_BITS.contains(&window_bits) || !(3..window_bits).contains(&lookahead_bits) {
+        return Err(value_error!("Invalid compression parameters."));
+    }
+    let truncated = || value_error!("Compressed data truncated.");
+    let mut reader = BitReader::new(input);
+    let mut len = 0;
+    while len < output.len() {
+        if reader.read(1).ok_or_else(truncated)? == 1 {
+            output[len] = reader.read(8).ok_or_else(truncated)? as u8;
+            len += 1;
+        } else {
+            let distance = reader.read(window_bits).ok_or_else(truncated)? as usize + 1;
+            let count = reader.read(lookahead_bits).ok_or_else(truncated)? as usize + 1;
+            if distance > len || len + count > output.len() {
+                return Err(value_error!("Invalid compressed data."));
+            }
+            // Byte by byte, the copy can overlap its source.
+            for i in len..len + count {
+                output[i] = output[i - distance];
+            }
+            len += count;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    struct BitWriter {
+        data: Vec<u8>,
+        bits: usize,
+    }
+
+    impl BitWriter {
+        fn write(&mut self, value: usize, bits: u8) {
+            for i in (0..bits).rev() {
+                if self.bits % 8 == 0 {
+                    self.data.push(0);
+                }
+                let bit = ((value >> i) & 1) as u8;
+                *self.data.last_mut().unwrap() |= bit << (7 - self.bits % 8);
+                self.bits += 1;
+            }
+        }
+    }
+
+    /// Greedy encoder producing what `decompress` accepts.
+    pub fn compress(input: &[u8], window_bits: u8, lookahead_bits: u8) -> Vec<u8> {
+        let mut out = BitWriter {
+            data: Vec::new(),
+            bits: 0,
+        };
+        let (window, lookahead) = (1 << window_bits, 1 << lookahead_bits);
+        let mut pos = 0;
+        while pos < input.len() {
+            let (mut best_distance, mut best_len) = (0, 0);
+            for distance in 1..=window.min(pos) {
+                let len = (0..lookahead.min(input.len() - pos))
+                    .take_while(|&i| input[pos + i] == input[pos + i - distance])
+                    .count();
+                if len > best_len {
+                    (best_distance, best_len) = (distance, len);
+                }
+            }
+            if best_len >= 2 {
+                out.write(0, 1);
+                out.write(best_distance - 1, window_bits);
+                out.write(best_len - 1, lookahead_bits);
+                pos += best_len;
+            } else {
+                out.write(1, 1);
+                out.write(input[pos] as usize, 8);
+                pos += 1;
+            }
+        }
+        out.data
+    }
+
+    #[test]
+    fn roundtrip() {
+        let text = b"HOLD TO CONFIRM, HOLD TO CANCEL, CONFIRM, CANCEL, aaaaaaaaaaaaaaaa";
+        for (window_bits, lookahead_bits) in [(8, 4), (4, 3), (10, 5)] {
+            let compressed = compress(text, window_bits, lookahead_bits);
+            assert!(compressed.len() < text.len());
+            let mut output = [0; 66];
+            decompress(&compressed, window_bits, lookahead_bits, &mut output).unwrap();
+            assert_eq!(&output[..], &text[..]);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let compressed = compress(b"CONFIRM CONFIRM", 8, 4);
+        let mut output = [0; 15];
+        // Truncated input, and output longer than the data.
+        assert!(decompress(&compressed[..4], 8, 4, &mut output).is_err());
+        assert!(decompress(&compressed, 8, 4, &mut [0; 20]).is_err());
+        // Reference before the start of the output.
+        assert!(decompress(&[0x00, 0x00], 8, 4, &mut output).is_err());
+        assert!(decompress(&compressed, 3, 2, &mut output).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Translations installed in the flash, kept across restarts. The area starts
+//! with the length of the blob, `u32` little-endian, followed by the blob. The
+//! length is written last, so that an installation cut short leaves nothing
+//! installed.
+
+use crate::error::Error;
+
+use super::{set_translations, Translations};
+
+#[cfg(feature = "translations")]
+use crate::trezorhal::translations as flash;
+
+/// Length of an erased area.
+const NOT_INSTALLED: u32 = u32::MAX;
+
+const LEN_SIZE: usize = 4;
+
+/// Longest blob fitting the area.
+pub fn max_len() -> usize {
+    flash::area_len().saturating_sub(LEN_SIZE)
+}
+
+/// Switch to English and erase the installed translations.
+pub fn erase() {
+    set_translations(None);
+    flash::erase();
+}
+
+/// Write `data` at `offset` of the blob being installed, after `erase`.
+pub fn write(data: &[u8], offset: usize) -> Result<(), Error> {
+    let fits = offset <= max_len() && data.len() <= max_len() - offset;
+    if !fits || installed().is_some() || !flash::write(data, LEN_SIZE + offset) {
+        return Err(value_error!("Translations do not fit."));
+    }
+    Ok(())
+}
+
+/// Install the first `len` bytes written as the blob, without switching to it.
+pub fn commit(len: usize) -> Result<(), Error> {
+    if len > max_len() || installed().is_some() {
+        return Err(value_error!("Translations do not fit."));
+    }
+    if !flash::write(&(len as u32).to_le_bytes(), 0) {
+        return Err(value_error!("Translations do not fit."));
+    }
+    Ok(())
+}
+
+/// Switch to the installed translations.
+pub fn load() -> Result<(), Error> {
+    let blob = installed().ok_or(value_error!("No translations installed."))?;
+    set_translations(Some(Translations::new(blob)?));
+    Ok(())
+}
+
+/// The installed blob, `None` if there is none.
+fn installed() -> Option<&'static [u8]> {
+    let area = flash::read();
+    let len = area.get(..LEN_SIZE)?;
+    let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]);
+    if len == NOT_INSTALLED {
+        return None;
+    }
+    area.get(LEN_SIZE..LEN_SIZE + len as usize)
+}
+
+/// Models without the area have nothing installed, tests get an area in RAM.
+#[cfg(not(feature = "translations"))]
+mod flash {
+    #[cfg(test)]
+    std::thread_local! {
+        static AREA: core::cell::RefCell<Vec<u8>> =
+            core::cell::RefCell::new(vec![0xFF; super::tests::AREA_LEN]);
+    }
+
+    #[cfg(test)]
+    pub fn read() -> &'static [u8] {
+        AREA.with(|area| Box::leak(area.borrow().clone().into_boxed_slice()))
+    }
+
+    #[cfg(test)]
+    pub fn write(data: &[u8], offset: usize) -> bool {
+        AREA.with(|area| {
+            let mut area = area.borrow_mut();
+            match area.get_mut(offset..offset + data.len()) {
+                Some(dst) if dst.iter().all(|&b| b == 0xFF) => {
+                    dst.copy_from_slice(data);
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    #[cfg(test)]
+    pub fn erase() {
+        AREA.with(|area| area.borrow_mut().fill(0xFF));
+    }
+
+    #[cfg(test)]
+    pub fn area_len() -> usize {
+        super::tests::AREA_LEN
+    }
+
+    #[cfg(not(test))]
+    pub fn read() -> &'static [u8] {
+        &[]
+    }
+
+    #[cfg(not(test))]
+    pub fn write(_data: &[u8], _offset: usize) -> bool {
+        false
+    }
+
+    #[cfg(not(test))]
+    pub fn erase() {}
+
+    #[cfg(not(test))]
+    pub fn area_len() -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::build_blob, *};
+    use crate::ui::translations::{language, TranslatedString};
+
+    pub const AREA_LEN: usize = 256;
+
+    fn install(blob: &[u8]) {
+        erase();
+        for (i, chunk) in blob.chunks(16).enumerate() {
+            write(chunk, i * 16).unwrap();
+        }
+        commit(blob.len()).unwrap();
+    }
+
+    #[test]
+    fn install_and_load() {
+        assert!(load().is_err());
+        let blob = build_blob("cs-CZ", &["ZRUŠIT"]);
+        install(&blob);
+        // Installed, but still in English until loaded.
+        assert_eq!(language(), None);
+
+        // Loading again, e.g. after a restart, finds the same blob.
+        for _ in 0..2 {
+            load().unwrap();
+            assert_eq!(language(), Some("cs-CZ"));
+            TranslatedString::ButtonCancel.map_translated(|t| assert_eq!(t, "ZRUŠIT"));
+            set_translations(None);
+        }
+
+        erase();
+        assert!(load().is_err());
+        assert_eq!(language(), None);
+    }
+
+    #[test]
+    fn unfinished() {
+        erase();
+        let blob = build_blob("de", &["ABBRECHEN"]);
+        write(&blob, 0).unwrap();
+        // Nothing is installed until committed.
+        assert!(load().is_err());
+
+        // The blob has to fit.
+        erase();
+        assert!(write(&[0; 1], max_len()).is_err());
+        assert!(write(&[0; AREA_LEN], 0).is_err());
+        assert!(commit(max_len() + 1).is_err());
+
+        // Damaged blobs are not loaded.
+        erase();
+        install(&blob[1..]);
+        assert!(load().is_err());
+        assert_eq!(language(), None);
+    }
+}
@@ -0,0 +1,115 @@
+//! Translations of the strings used by the UI. English is built into the
+//! firmware, other languages are loaded from a blob, see `Translations`.
+//! Strings missing in the blob fall back to English, which is marked with a
+//! leading `~` in debug builds so that the gaps are easy to spot. The blob can
+//! also bring glyphs of the characters the built-in fonts lack.
+//!
+//! The blob stays in the flash, strings of a compressed one are decompressed
+//! on use into a cache of a few chunks. They are only ever lent out for the
+//! duration of a closure, see `TranslatedString::map_translated`.
+
+mod blob;
+mod heatshrink;
+pub mod installed;
+mod strings;
+
+#[cfg(test)]
+pub use blob::tests::build as build_blob;
+pub use blob::Translations;
+pub use strings::TranslatedString;
+
+use crate::ui::{context, display::Glyph};
+
+/// Switch the UI to the language of `translations`, or back to English.
+/// Layouts already shown keep their strings until `Event::LanguageChanged`
+/// is sent to them. Must not be called from within `map_translated`.
+pub fn set_translations(translations: Option<Translations<'static>>) {
+    context::set_translations(translations);
+}
+
+fn current() -> Option<&'static Translations<'static>> {
+    context::translations()
+}
+
+/// Language tag of the current translations, `None` for English.
+pub fn language() -> Option<&'static str> {
+    current().map(Translations::language)
+}
+
+/// Glyph of `ch` from the font of the current translations.
+pub fn wide_glyph(ch: char) -> Option<Glyph> {
+    current()?.glyph(ch)
+}
+
+// Strings stored by the components are copied, so that they do not depend on
+// the cache of the translations.
+#[cfg(feature = "micropython")]
+impl From<TranslatedString> for crate::micropython::buffer::StrBuffer {
+    fn from(text: TranslatedString) -> Self {
+        if current().is_none() {
+            return text.untranslated().into();
+        }
+        text.map_translated(|translated| {
+            Self::alloc(translated).unwrap_or_else(|_| text.untranslated().into())
+        })
+    }
+}
+
+#[cfg(feature = "micropython")]
+impl From<TranslatedString> for crate::micropython::buffer::BorrowedStr {
+    fn from(text: TranslatedString) -> Self {
+        use crate::micropython::obj::Obj;
+        if current().is_none() {
+            return text.untranslated().into();
+        }
+        text.map_translated(|translated| {
+            Obj::try_from(translated)
+                .and_then(Self::try_from)
+                .unwrap_or_else(|_| text.untranslated().into())
+        })
+    }
+}
+
+// Same as with `SkipPrefix`, `&str` is only a `StringType` in the bootloader
+// and tests. The bootloader has no translations, tests leak the strings.
+#[cfg(all(feature = "bootloader", not(test)))]
+impl From<TranslatedString> for &'static str {
+    fn from(text: TranslatedString) -> Self {
+        text.untranslated()
+    }
+}
+
+#[cfg(test)]
+impl From<TranslatedString> for &'static str {
+    fn from(text: TranslatedString) -> Self {
+        text.map_translated(|translated| Box::leak(Box::<str>::from(translated)))
+    }
+}
+
+impl TranslatedString {
+    /// Call `fun` with the string in the current language. The string is only
+    /// valid during the call, a string of the translations held for longer
+    /// would keep the cache from being reused.
+    pub fn map_translated<F, R>(self, fun: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        match current() {
+            None => fun(self.untranslated()),
+            Some(translations) => match translations.get(self as usize) {
+                Some(text) => fun(&text),
+                None => fun(self.fallback()),
+            },
+        }
+    }
+
+    #[cfg(feature = "ui_debug")]
+    fn fallback(self) -> &'static str {
+        self.untranslated_marked()
+    }
+
+    #[cfg(not(feature = "ui_debug"))]
+    fn fallback(self) -> &'static str {
+        self.untranslated()
+    }
+}
@@ -0,0 +1,61 @@
+/// Defines `TranslatedString` together with the English strings. Index of a
+/// string in the blob is the position of its variant, so new strings can only
+/// be appended at the end.
+macro_rules! translated_strings {
+    ($($key:ident => $english:literal,)*) => {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[repr(u16)]
+        pub enum TranslatedString {
+            $($key,)*
+        }
+
+        impl TranslatedString {
+            /// The string in English.
+            pub const fn untranslated(self) -> &'static str {
+                match self {
+                    $(Self::$key => $english,)*
+                }
+            }
+
+            /// The string in English, marked as missing in the translation.
+            #[cfg(feature = "ui_debug")]
+            pub const fn untranslated_marked(self) -> &'static str {
+                match self {
+                    $(Self::$key => concat!("~", $english),)*
+                }
+            }
+        }
+    };
+}
+
+translated_strings! {
+    ButtonCancel => "CANCEL",
+    ButtonConfirm => "CONFIRM",
+    ButtonContinue => "CONTINUE",
+    ButtonHoldToConfirm => "HOLD TO CONFIRM",
+    ButtonHoldToCancel => "HOLD TO CANCEL",
+    ButtonInfo => "INFO",
+    ButtonMoreInfo => "MORE INFO",
+    ButtonSelect => "SELECT",
+    ButtonSkip => "SKIP",
+    ButtonQuit => "QUIT",
+    TitleBlindSigning => "BLIND SIGNING",
+    WarningBlindSigning => "The device cannot show what this operation does. Only continue if you fully trust the source.",
+    ButtonEnter => "ENTER",
+    ButtonBack => "BACK",
+    ButtonNext => "NEXT",
+    TitleChangeLanguage => "CHANGE LANGUAGE",
+    TextLanguage => "Language",
+    TextVersion => "Version",
+    TextSigned => "Signed by the manufacturer.",
+    WarningNotSigned => "Not signed by the manufacturer!",
+    TitleHelp => "HELP",
+    HelpPin => "Enter the PIN you chose for this device. The digits are in random order each time.",
+    HelpPassphrase => "Each passphrase opens a different wallet. An empty one opens the standard wallet.",
+    WalletStandard => "STANDARD",
+    WalletHidden => "HIDDEN",
+    TextSecondsLeft => "About {0} s left",
+    TextMinutesLeft => "About {0} min left",
+    TextPositionOf => "{0} of {1}",
+    TextAttemptsLeft => "{0, plural, =1 {Last attempt} other {# tries left}}",
+}
@@ -1,5 +1,5 @@
 use crate::{
-    strutil::ShortString,
+    strutil::{count_graphemes, truncate_graphemes, ShortString},
     ui::{
         component::text::TextStyle,
         display,
@@ -138,25 +138,40 @@ pub fn char_to_string(ch: char) -> ShortString {
 /// When the text is too long to fit, it is truncated with ellipsis
 /// on the left side.
 /// Hardcoding 50 (via ShortString) as the length of the returned String -
-/// there should not be any lines as long as this.
+/// there should not be any lines as long as this, longer ones are truncated
+/// the same way as too wide ones.
 pub fn long_line_content_with_ellipsis(
     text: &str,
     ellipsis: &str,
     text_font: Font,
     available_width: i16,
 ) -> ShortString {
-    if text_font.text_width(text) <= available_width {
-        String::from(text) // whole text can fit
-    } else {
-        // Text is longer, showing its right end with ellipsis at the beginning.
-        // Finding out how many additional text characters will fit in,
-        // starting from the right end.
-        let ellipsis_width = text_font.text_width(ellipsis);
-        let remaining_available_width = available_width - ellipsis_width;
-        let chars_from_right = text_font.longest_suffix(remaining_available_width, text);
-
-        build_string!(50, ellipsis, &text[text.len() - chars_from_right..])
+    let mut result = ShortString::new();
+    let capacity = result.capacity();
+    if text.len() <= capacity && text_font.text_width(text) <= available_width {
+        unwrap!(result.push_str(text)); // whole text can fit
+        return result;
     }
+    // Text is longer, showing its right end with ellipsis at the beginning.
+    // Finding out how many additional text characters will fit in,
+    // starting from the right end.
+    let ellipsis_width = text_font.text_width(ellipsis);
+    let remaining_available_width = available_width - ellipsis_width;
+    let suffix_len = text_font.longest_suffix(remaining_available_width, text);
+    // Move the start forward to the end of the grapheme cluster it splits, if
+    // any.
+    let mut skipped = count_graphemes(&text[..text.len() - suffix_len]);
+    let mut start = truncate_graphemes(text, skipped).len();
+    // Lots of narrow characters could still overflow the buffer.
+    let max_len = capacity.saturating_sub(ellipsis.len());
+    while text.len() - start > max_len {
+        skipped += 1;
+        start = truncate_graphemes(text, skipped).len();
+    }
+    // Both fit because of the checks above, unless the ellipsis is too long.
+    let _ = result.push_str(ellipsis);
+    let _ = result.push_str(&text[start..]);
+    result
 }
 
 #[macro_export]
@@ -181,6 +196,8 @@ macro_rules! include_icon {
 mod tests {
     use crate::strutil;
 
+    use super::*;
+
     #[test]
     fn u32_to_str_valid() {
         let testcases = [0, 1, 9, 10, 11, 999, u32::MAX];
@@ -203,4 +220,12 @@ mod tests {
             assert_eq!(converted, None)
         }
     }
+
+    #[test]
+    fn long_line_multibyte() {
+        let text = "žluťoučký kůň úpěl ďábelské ódy".repeat(4);
+        let line = long_line_content_with_ellipsis(&text, "...", Font::NORMAL, 100);
+        assert!(line.starts_with("..."));
+        assert!(text.ends_with(&line[3..]));
+    }
 }
@@ -0,0 +1,130 @@
+//! Badge in the top right corner of every screen telling whether the current
+//! session uses the standard wallet or a hidden one, i.e. a wallet derived
+//! with a non-empty passphrase, so that nothing gets signed from the other
+//! wallet by mistake. The firmware sets it with `trezorui2.set_wallet_badge()`
+//! whenever the seed of the session is known, into `UiSettings::wallet`. The
+//! `Root` of a layout placed while a wallet is set keeps a strip above the
+//! header for the badge.
+
+use crate::ui::{
+    display::{self, Font},
+    geometry::{Offset, Rect},
+    theme::Theme,
+    translations::TranslatedString,
+};
+
+#[cfg(all(feature = "model_tr", not(feature = "model_tt")))]
+use super::model_tr::theme;
+#[cfg(feature = "model_tt")]
+use super::model_tt::theme;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Wallet {
+    Standard,
+    Hidden,
+}
+
+impl Wallet {
+    fn label(self) -> TranslatedString {
+        match self {
+            Self::Standard => TranslatedString::WalletStandard,
+            Self::Hidden => TranslatedString::WalletHidden,
+        }
+    }
+}
+
+/// Space between the label and the edge of the badge.
+const PADDING: i16 = 1;
+const FONT: Font = Font::NORMAL;
+
+/// Height of the strip above the header taken by the badge of `wallet`.
+pub fn height(wallet: Option<Wallet>) -> i16 {
+    match wallet {
+        Some(_) => FONT.text_height() + 2 * PADDING,
+        None => 0,
+    }
+}
+
+/// Paint the badge of `wallet` into the top right corner of `area`. The
+/// hidden wallet is inverted, so that the two can be told apart at a glance,
+/// without reading the label.
+pub fn paint(area: Rect, wallet: Option<Wallet>) {
+    let Some(wallet) = wallet else {
+        return;
+    };
+    let theme = theme::current();
+    let (fg, bg) = match wallet {
+        Wallet::Standard => (theme.fg_secondary(), theme.bg()),
+        Wallet::Hidden => (theme.bg(), theme.fg()),
+    };
+    wallet.label().map_translated(|label| {
+        let size =
+            Offset::new(FONT.text_width(label), FONT.text_height()) + Offset::uniform(2 * PADDING);
+        let badge = Rect::from_top_right_and_size(area.top_right(), size);
+        display::rect_fill(badge, bg);
+        display::text_left(
+            badge.bottom_left() + Offset::new(PADDING, -PADDING),
+            label,
+            FONT,
+            fg,
+            bg,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        component::{Component, Event, EventCtx, Never, Root},
+        constant::screen,
+        context::{update_settings, UiSettings},
+        geometry::Insets,
+        testing::Harness,
+    };
+
+    use super::*;
+
+    /// Remembers where it was placed.
+    struct Placed(Rect);
+
+    impl Component for Placed {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            self.0 = bounds;
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            None
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    fn placed(h: &Harness<Root<Placed>>) -> Rect {
+        h.inner().inner().inner().0
+    }
+
+    #[test]
+    fn strip_above_the_header() {
+        let mut h = Harness::new(Root::new(Placed(Rect::zero())));
+        assert!(placed(&h) == screen());
+
+        // Taken into account from the next place on.
+        update_settings(|s| s.wallet = Some(Wallet::Hidden));
+        assert!(placed(&h) == screen());
+        h.mutate(|_, root| root.place(screen()));
+        let strip = height(Some(Wallet::Hidden));
+        assert!(strip > 0);
+        assert!(placed(&h) == screen().inset(Insets::top(strip)));
+
+        update_settings(|s| *s = UiSettings::DEFAULT);
+    }
+
+    #[test]
+    fn both_wallets_take_the_same_strip() {
+        assert!(height(Some(Wallet::Standard)) == height(Some(Wallet::Hidden)));
+        assert!(height(None) == 0);
+    }
+}
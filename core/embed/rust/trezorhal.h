@@ -1,4 +1,5 @@
 #include TREZOR_BOARD
+#include "audio.h"
 #include "buffers.h"
 #include "button.h"
 #include "common.h"
@@ -12,6 +13,7 @@
 #include "secbool.h"
 #include "storage.h"
 #include "touch.h"
+#include "translations.h"
 #include "usb.h"
 
 #include "bip39.h"
@@ -0,0 +1,34 @@
+/*
+ * This file is part of the Trezor project, https://trezor.io/
+ *
+ * Copyright (c) SatoshiLabs
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#ifndef __TREZORHAL_AUDIO_H__
+#define __TREZORHAL_AUDIO_H__
+
+#include <stdint.h>
+
+// Piezo buzzer. Boards that have one implement these and make the `audio`
+// feature available.
+
+void audio_init(void);
+
+// Play a tone of `freq_hz` for `duration_ms`, returns once it has finished.
+// A frequency of 0 keeps silent for the duration.
+void audio_play_tone(uint16_t freq_hz, uint16_t duration_ms);
+
+#endif
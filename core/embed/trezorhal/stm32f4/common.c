@@ -28,6 +28,7 @@
 
 #ifdef FANCY_FATAL_ERROR
 #include "rust_ui.h"
+#include "version.h"
 #endif
 #include "flash.h"
 #include "platform.h"
@@ -49,6 +50,23 @@
 
 uint32_t systick_val_copy = 0;
 
+#ifdef FANCY_FATAL_ERROR
+// Report of the error for the QR code on the error screen: firmware version,
+// beginning of the build hash, message and location.
+static void fatal_error_diagnostics(char *buf, size_t len, const char *msg,
+                                    const char *file, int line) {
+  char rev[11] = "";
+#ifdef SCM_REVISION
+  const uint8_t *r = (const uint8_t *)SCM_REVISION;
+  mini_snprintf(rev, sizeof(rev), "%02x%02x%02x%02x%02x", r[0], r[1], r[2],
+                r[3], r[4]);
+#endif
+  mini_snprintf(buf, len, "%d.%d.%d %s\n%s\n%s:%d", VERSION_MAJOR,
+                VERSION_MINOR, VERSION_PATCH, rev, msg != NULL ? msg : "", file,
+                line);
+}
+#endif
+
 // from util.s
 extern void shutdown_privileged(void);
 
@@ -98,8 +116,10 @@ __fatal_error(const char *expr, const char *msg, const char *file, int line,
 #ifdef FANCY_FATAL_ERROR
   char buf[256] = {0};
   mini_snprintf(buf, sizeof(buf), "%s: %d", file, line);
-  screen_fatal_error_rust("INTERNAL ERROR", msg != NULL ? msg : buf,
-                          "PLEASE VISIT\nTREZOR.IO/RSOD");
+  char diagnostics[200] = {0};
+  fatal_error_diagnostics(diagnostics, sizeof(diagnostics), msg, file, line);
+  screen_fatal_error_qr_rust("INTERNAL ERROR", msg != NULL ? msg : buf,
+                             "PLEASE VISIT\nTREZOR.IO/RSOD", diagnostics);
   display_refresh();
 #else
   term_set_color(COLOR_WHITE, COLOR_FATAL_ERROR);
@@ -31,7 +31,7 @@
 
 #define TOUCH_ADDRESS \
   (0x38U << 1)  // the HAL requires the 7-bit address to be shifted by one bit
-#define TOUCH_PACKET_SIZE 7U
+#define TOUCH_PACKET_SIZE 9U
 #define EVENT_PRESS_DOWN 0x00U
 #define EVENT_CONTACT 0x80U
 #define EVENT_LIFT_UP 0x40U
@@ -41,10 +41,19 @@
 #define X_POS_LSB (touch_data[4])
 #define Y_POS_MSB (touch_data[5] & 0x0FU)
 #define Y_POS_LSB (touch_data[6])
+#define TOUCH_AREA (touch_data[8] >> 4)
 
 #define EVENT_OLD_TIMEOUT_MS 50
 #define EVENT_MISSING_TIMEOUT_MS 50
 
+// contacts shorter than this are ghost touches
+#define TOUCH_DEBOUNCE_MS 20
+// contacts with larger area (as reported by the controller) are palms
+#define PALM_AREA_THRESHOLD 8
+// position reported with the END event of a rejected touch, off the screen
+#define TOUCH_CANCEL_X 0xFFF
+#define TOUCH_CANCEL_Y 0xFFF
+
 static void touch_default_pin_state(void) {
   // set power off and other pins as per section 3.5 of FT6236 datasheet
   HAL_GPIO_WritePin(TOUCH_ON_PORT, TOUCH_ON_PIN,
@@ -189,24 +198,40 @@ uint32_t touch_read(void) {
   static uint32_t xy;
   static uint32_t last_check_time = 0;
   static uint32_t last_event_time = 0;
+  static uint32_t press_time = 0;
   static int touching = 0;
+  // press detected, START not sent until it lasts TOUCH_DEBOUNCE_MS
+  static int pending_start = 0;
+  // second contact or palm seen, ignore everything until all contacts lift
+  static int rejected = 0;
+
+  if (pending_start && check_timeout(press_time, TOUCH_DEBOUNCE_MS)) {
+    // the contact lasted long enough, it is not a ghost touch
+    pending_start = 0;
+    touching = 1;
+    return TOUCH_START | xy;
+  }
 
   uint32_t detected = touch_is_detected();
 
   if (detected == 0) {
     last_check_time = hal_ticks_ms();
 
-    if (touching && check_timeout(last_event_time, EVENT_MISSING_TIMEOUT_MS)) {
+    if ((touching || pending_start || rejected) &&
+        check_timeout(last_event_time, EVENT_MISSING_TIMEOUT_MS)) {
       // we didn't detect an event for a long time, but there was an active
       // touch: send END event, as we probably missed the END event
+      int was_touching = touching;
       touching = 0;
-      return TOUCH_END | xy;
+      pending_start = 0;
+      rejected = 0;
+      return was_touching ? TOUCH_END | xy : 0;
     }
 
     return 0;
   }
 
-  if ((touching == 0) &&
+  if ((touching == 0) && (pending_start == 0) &&
       (check_timeout(last_check_time, EVENT_OLD_TIMEOUT_MS))) {
     // we have detected an event, but it might be too old, rather drop it
     // (only dropping old events if there was no touch active)
@@ -241,17 +266,54 @@ uint32_t touch_read(void) {
       touch_data[2] & 0x0F;  // valid values are 0, 1, 2 (invalid 0xF before
                              // first touch) (tested with FT6206)
   const uint32_t event_flag = touch_data[3] & 0xC0;
+
+  if (rejected) {
+    if (number_of_touch_points == 0) {
+      rejected = 0;
+    }
+    return 0;
+  }
+
+  if (number_of_touch_points == 2 ||
+      (number_of_touch_points == 1 && TOUCH_AREA > PALM_AREA_THRESHOLD)) {
+    // simultaneous second contact or a palm, none of them is a deliberate tap
+    rejected = 1;
+    pending_start = 0;
+    if (touching) {
+      // end the interaction outside of the screen, so nothing is clicked
+      touching = 0;
+      return TOUCH_END | touch_pack_xy(TOUCH_CANCEL_X, TOUCH_CANCEL_Y);
+    }
+    return 0;
+  }
+
   if (touch_data[1] == GESTURE_NO_GESTURE) {
     xy = TRANSFORM_TOUCH_COORDS((X_POS_MSB << 8) | X_POS_LSB,
                                 (Y_POS_MSB << 8) | Y_POS_LSB);
     if ((number_of_touch_points == 1) && (event_flag == EVENT_PRESS_DOWN)) {
-      touching = 1;
-      return TOUCH_START | xy;
+      pending_start = 1;
+      press_time = hal_ticks_ms();
+      return 0;
     } else if ((number_of_touch_points == 1) && (event_flag == EVENT_CONTACT)) {
-      return TOUCH_MOVE | xy;
+      if (pending_start) {
+        if (!check_timeout(press_time, TOUCH_DEBOUNCE_MS)) {
+          return 0;
+        }
+        pending_start = 0;
+        touching = 1;
+        return TOUCH_START | xy;
+      }
+      return touching ? TOUCH_MOVE | xy : 0;
     } else if ((number_of_touch_points == 0) && (event_flag == EVENT_LIFT_UP)) {
-      touching = 0;
-      return TOUCH_END | xy;
+      if (pending_start) {
+        // lifted before TOUCH_DEBOUNCE_MS, a ghost touch
+        pending_start = 0;
+        return 0;
+      }
+      if (touching) {
+        touching = 0;
+        return TOUCH_END | xy;
+      }
     }
   }
 
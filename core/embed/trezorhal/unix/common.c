@@ -28,6 +28,7 @@
 #include "display.h"
 #ifdef FANCY_FATAL_ERROR
 #include "rust_ui.h"
+#include "version.h"
 #endif
 #include "memzero.h"
 
@@ -73,6 +74,22 @@ error_uni(const char *label, const char *msg, const char *footer) {
   trezor_shutdown();
 }
 
+#ifdef FANCY_FATAL_ERROR
+// Report of the error for the QR code on the error screen: firmware version,
+// beginning of the build hash, message and location.
+static void fatal_error_diagnostics(char *buf, size_t len, const char *msg,
+                                    const char *file, int line) {
+  char rev[11] = "";
+#ifdef SCM_REVISION
+  const uint8_t *r = (const uint8_t *)SCM_REVISION;
+  snprintf(rev, sizeof(rev), "%02x%02x%02x%02x%02x", r[0], r[1], r[2], r[3],
+           r[4]);
+#endif
+  snprintf(buf, len, "%d.%d.%d %s\n%s\n%s:%d", VERSION_MAJOR, VERSION_MINOR,
+           VERSION_PATCH, rev, msg != NULL ? msg : "", file, line);
+}
+#endif
+
 void __attribute__((noreturn))
 __fatal_error(const char *expr, const char *msg, const char *file, int line,
               const char *func) {
@@ -80,15 +97,17 @@ __fatal_error(const char *expr, const char *msg, const char *file, int line,
   display_backlight(255);
 
 #ifdef FANCY_FATAL_ERROR
+  char diagnostics[200] = {0};
+  fatal_error_diagnostics(diagnostics, sizeof(diagnostics), msg, file, line);
   if (msg == NULL) {
     msg = "Unknown error";
     char buf[256] = {0};
     snprintf(buf, sizeof(buf), "%s: %d", file, line);
-    screen_fatal_error_rust("INTERNAL ERROR", buf,
-                            "PLEASE VISIT\nTREZOR.IO/RSOD");
+    screen_fatal_error_qr_rust("INTERNAL ERROR", buf,
+                               "PLEASE VISIT\nTREZOR.IO/RSOD", diagnostics);
   } else {
-    screen_fatal_error_rust("INTERNAL ERROR", msg,
-                            "PLEASE VISIT\nTREZOR.IO/RSOD");
+    screen_fatal_error_qr_rust("INTERNAL ERROR", msg,
+                               "PLEASE VISIT\nTREZOR.IO/RSOD", diagnostics);
   }
 
   display_refresh();
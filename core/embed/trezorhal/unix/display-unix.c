@@ -344,4 +344,14 @@ void display_clear_save(void) {
   PREV_SAVED = NULL;
 }
 
+void display_copy_rgb565(uint16_t *dest, uint16_t width, uint16_t height) {
+  if (!RENDERER) {
+    display_init();
+  }
+  for (int y = 0; y < height; y++) {
+    memcpy(dest + y * width, (uint8_t *)BUFFER->pixels + y * BUFFER->pitch,
+           width * sizeof(pixel_color));
+  }
+}
+
 uint8_t *display_get_wr_addr(void) { return (uint8_t *)DISPLAY_DATA_ADDRESS; }
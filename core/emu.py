@@ -99,6 +99,7 @@ def _from_env(name: str) -> bool:
 )
 # fmt: off
 @click.option("-a", "--disable-animation/--enable-animation", default=_from_env("TREZOR_DISABLE_ANIMATION"), help="Disable animation")
+@click.option("-b", "--button-quirks", metavar="SEED,BOUNCE,MISSED,DROPPED", default=os.environ.get("TREZOR_BUTTON_QUIRKS"), help="Simulate bouncing and lost button events, rates in percent")
 @click.option("-c", "--command", "run_command", is_flag=True, help="Run command while emulator is running")
 @click.option("-d", "--production/--no-production", default=_from_env("PYOPT"), help="Production mode (debuglink disabled)")
 @click.option("-D", "--debugger", is_flag=True, help="Run emulator in debugger (gdb/lldb)")
@@ -125,6 +126,7 @@ def _from_env(name: str) -> bool:
 @click.argument("command", nargs=-1, type=click.UNPROCESSED)
 def cli(
     disable_animation: bool,
+    button_quirks: Optional[str],
     run_command: bool,
     production: bool,
     debugger: bool,
@@ -258,6 +260,9 @@ def cli(
     if log_memory:
         os.environ["TREZOR_LOG_MEMORY"] = "1"
 
+    if button_quirks:
+        os.environ["TREZOR_BUTTON_QUIRKS"] = button_quirks
+
     if alloc_profiling:
         os.environ["TREZOR_MEMPERF"] = "1"
 
@@ -2,6 +2,32 @@ from typing import *
 CONFIRMED: object
 CANCELLED: object
 INFO: object
+SELECTED: object
+MORE: object
+
+
+# rust/src/ui/model_tr/layout.rs
+class ValueResult:
+    """Result of a layout carrying a value, e.g. the selected index."""
+    kind: object
+    name: str
+    value: int
+
+
+# rust/src/ui/model_tr/layout.rs
+class ValidationError(ValueError):
+    """Layout argument failed validation. The first argument of the
+    exception is name of the field, the second one the reason."""
+
+
+# rust/src/ui/model_tr/layout.rs
+class MissingFieldError(ValidationError):
+    """Required layout argument was not passed."""
+
+
+# rust/src/ui/model_tr/layout.rs
+class InvalidFieldError(ValidationError):
+    """Layout argument has an unexpected type or value."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -9,6 +35,162 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def disable_transitions(disable: bool) -> None:
+    """Disable animated screen transitions."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_accessibility(
+    *,
+    reduce_motion: bool = False,
+    longer_timeouts: bool = False,
+    larger_fonts: bool = False,
+    high_contrast: bool = False,
+    audio_feedback: bool = False,
+) -> None:
+    """Set the accessibility preferences of the user. Reduced motion skips
+    decorative movement, e.g. transitions and sliding items. Longer
+    timeouts give more time to read and to hold buttons. High contrast
+    paints grey text in white or black. Audio feedback plays the outcome
+    of a flow on the buzzer, if there is one."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_autodim(
+    *,
+    delay_ms: int | None = None,
+    level: int = 0,
+) -> None:
+    """Dim the display to backlight `level` after `delay_ms` without input, or
+    never with `None`. The input waking the display up is not passed on to the
+    layout."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_wallet_badge(hidden: bool | None) -> None:
+    """Show on every screen whether the current session uses a hidden
+    wallet, i.e. one derived with a non-empty passphrase, or the
+    standard one. `None` hides the badge."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_button_quirks(
+    *,
+    seed: int | None,
+    bounce: int = 0,
+    missed_release: int = 0,
+    dropped: int = 0,
+) -> None:
+    """Simulate bouncing buttons, lost releases and lost events, each in the
+    given percentage of the button events, drawn from a PRNG seeded with
+    `seed`. `None` turns the simulation off. Debug builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def trace_paint_counters(enabled: bool) -> None:
+    """Include in the traces of layouts how many times each component has
+    been painted and has requested a paint. Debug builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def wipe_carry() -> None:
+    """Zeroize the passphrase kept by `request_passphrase` with
+    `carry_out` in case no other entry has taken it."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def secrets_equal(a: str, b: str) -> bool:
+    """Compare two secrets, e.g. a PIN and its re-entry, in time that does
+    not depend on where they differ."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def enable_profiler(enable: bool) -> None:
+    """Show paint and event handling times of layouts in the corner of
+    the screen, debug builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_replay_seed(seed: int | None) -> None:
+    """Draw UI randomness from a PRNG seeded with `seed`, or from the RNG
+    again if `None`. Emulator debug builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def translations_max_length() -> int:
+    """Longest translations blob fitting the flash, zero on models
+    without room for one."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def erase_translations() -> None:
+    """Switch to English and erase the translations installed in the
+    flash."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def write_translations(data: bytes, offset: int) -> None:
+    """Write `data` at `offset` of the translations blob being installed,
+    after `erase_translations()`."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def commit_translations(length: int) -> None:
+    """Install the first `length` bytes written as the translations blob,
+    without switching to it. Until then nothing is installed."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def load_translations() -> None:
+    """Use the strings of the installed translations. Strings missing in
+    them are shown in English. Raises `ValueError` if there are none or
+    they are invalid."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_battery_level(level: int | None) -> None:
+    """Charge of the battery in percent, or `None` on external power. Below
+    20 percent animations run with fewer frames and decorative effects, e.g.
+    marquees and screen transitions, are skipped."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_density(density: int) -> None:
+    """Spacing of confirm screens, a value of `ScreenDensity`. Compact
+    has tighter lines and narrower fonts fitting more on each page."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_theme_inverted(inverted: bool) -> None:
+    """Paint the screens dark on light, the shades of grey swapped. Takes
+    effect from the next paint."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def save_resume_point(*, flow: int, step: int, page: int) -> None:
+    """Remember that `page` of `step` was shown in the flow identified by
+    `flow`, so that it can be resumed after a reset. Layouts keep the
+    page up to date as it changes. Needs the storage unlocked."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def resume_point(flow: int) -> tuple[int, int] | None:
+    """Step and page saved for `flow`, or `None` when there is nothing to
+    resume."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def clear_resume_point() -> None:
+    """Forget the saved point, e.g. once the flow is finished."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_keyboard_layout(layout: int) -> None:
+    """Order the letters of keyboards created from now on by `layout`, a
+    value of `KeyboardLayout`."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def check_homescreen_format(data: bytes) -> bool:
     """Check homescreen format and dimensions."""
@@ -29,6 +211,38 @@ def confirm_action(
     """Confirm action."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def confirm_terms(
+    *,
+    title: str,
+    text: str,
+    verb: str = "ACCEPT",
+) -> object:
+    """Long notice or disclaimer. Confirming is only possible once the last page
+    has been shown."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def confirm_blind_signing(
+    *,
+    title: str | None = None,
+    description: str = "",
+) -> object:
+    """Mandatory warning before signing something the device cannot decode,
+    e.g. a call of an unknown contract or raw data. Accepted by holding."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def confirm_language(
+    *,
+    version: str,
+    signed: bool,
+) -> object:
+    """Confirm switching to the translations in use, showing their language,
+    `version` and whether they are signed. The screen itself is in the new
+    language."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def confirm_homescreen(
     *,
@@ -78,6 +292,32 @@ def confirm_properties(
     This only concerns the text style, you need to decode the value to UTF-8 in python."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def confirm_template(
+    *,
+    title: str,
+    rows: Iterable[tuple[str, str]],
+    warning: bool = False,
+    verb: str = "CONFIRM",
+    verb_cancel: str = "CANCEL",
+) -> object:
+    """Confirm an application-specific action described by the host. Every
+    text must be trimmed printable ASCII and short enough, there can be 1 to
+    6 rows. Raises ValueError otherwise."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def confirm_payment_uri(
+    *,
+    uri: str,
+    scheme: str = "bitcoin",
+    title: str = "PAYMENT REQUEST",
+) -> object:
+    """Confirm a BIP-21 payment URI, its address, amount, label and message,
+    with the whole URI on the last page. Raises ValueError when the URI is
+    malformed."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def confirm_reset_device(
     *,
@@ -142,8 +382,11 @@ def confirm_output_address(
     address_label: str,
     address_title: str,
     chunkify: bool = False,
+    coin: str | None = None,
 ) -> object:
-    """Confirm output address."""
+    """Confirm output address. The icon and name of the `coin` with the
+    given shortcut are shown in front of the title, if there is an icon
+    for it."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -151,8 +394,9 @@ def confirm_output_amount(
     *,
     amount: str,
     amount_title: str,
+    coin: str | None = None,
 ) -> object:
-    """Confirm output amount."""
+    """Confirm output amount, with the `coin` like confirm_output_address."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -205,9 +449,11 @@ def confirm_fido(
     app_name: str,
     icon_name: str | None,  # unused on TR
     accounts: list[str | None],
-) -> int | object:
+    time_ms: int = 0,
+) -> ValueResult | object:
     """FIDO confirmation.
-    Returns page index in case of confirmation and CANCELLED otherwise.
+    Returns SELECTED with the page index in case of confirmation and CANCELLED
+    otherwise, also when `time_ms` is set and the countdown runs out.
     """
 
 
@@ -231,6 +477,21 @@ def show_warning(
     """Warning modal with middle button and centered text."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def show_entropy_mixing(
+    *,
+    title: str,
+    description: str,
+    seed: bytes,
+    time_ms: int,
+) -> bytes:
+    """Abstract pattern stirred while the entropy of a new wallet is
+    mixed, starting from `seed`, a hash of the entropy and not the
+    entropy itself. Closes after `time_ms` unless the user presses the
+    buttons or taps the screen to add entropy, then once they stop.
+    Returns the timing of the presses, empty if there were none."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def show_info(
     *,
@@ -241,6 +502,34 @@ def show_info(
     """Info modal."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def show_settings_menu(
+    *,
+    settings: Iterable[tuple[str, int]],
+) -> tuple[list[tuple[str, int]], bool]:
+    """Settings menu. `settings` are names and current values of the
+    settings to offer, one of `auto_lock`, `rotation`, `safety_checks`,
+    `large_text`, `remember_destinations`, `spending_threshold` and
+    `signing_receipt`. Returns the names and new values of the changed
+    ones, and whether the user wants to rename the device."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def show_result(
+    *,
+    success: bool,
+    title: str,
+    detail: str = "",
+    button: str = "",
+    time_ms: int = 0,
+    rejected: bool = False,
+) -> object:
+    """Result of a flow, dismissed by the button. Closes on its own once
+    the icon animation finishes when there is no button or `time_ms` is
+    set. A failure the user has `rejected` plays the reject sound
+    instead of the error one."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def show_passphrase() -> object:
     """Show passphrase on host dialog."""
@@ -275,6 +564,47 @@ def confirm_more(
     Meant to be used with confirm_with_info."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def show_blob(
+    *,
+    title: str,
+    data: bytes,
+    base64: bool = False,
+    verb: str = "",
+    total: int = 0,
+) -> object:
+    """All of `data` in hex or base64, with offsets and the total length,
+    on as many pages as needed. Returns CANCELLED to switch the format.
+    With `verb`, the data is to be confirmed with that button, the left
+    one rejects it instead. `total` is the length of the whole blob if
+    `data` is only its start."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def show_receipt(
+    *,
+    title: str,
+    description: str,
+    fingerprint: str,
+    qr_data: str,
+    qr: bool = False,
+) -> object:
+    """Short fingerprint of what was signed, to be compared with the host.
+    `qr` shows `qr_data` as a QR code instead."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def confirm_settings_change(
+    *,
+    title: str,
+    changes: Iterable[tuple[str, str, str, bool]],
+    hold: bool = False,
+) -> object:
+    """Confirm changes of settings, each of them `(name, old value, new
+    value, reduces security)`. The new values reducing security stand
+    out, `hold` for changes like that."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def confirm_coinjoin(
     *,
@@ -291,17 +621,36 @@ def request_pin(
     subprompt: str,
     allow_cancel: bool = True,  # unused on TR
     wrong_pin: bool = False,  # unused on TR
+    shuffle: bool = True,
+    shuffle_on_keypress: bool = True,
 ) -> str | object:
-    """Request pin on device."""
+    """Request pin on device. A random digit is offered first or after
+    every digit entered, according to `shuffle` and `shuffle_on_keypress`."""
 
 
 # rust/src/ui/model_tr/layout.rs
 def request_passphrase(
     *,
     prompt: str,
-    max_len: int,  # unused on TR
+    max_len: int,
+    carry_in: bool = False,
+    carry_out: bool = False,
+) -> str | object:
+    """Get passphrase. With `carry_out` the confirmed passphrase is kept
+    for the next entry of the flow, which takes it with `carry_in` and
+    starts with it entered."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def request_label(
+    *,
+    prompt: str,
+    label: str = "",
+    max_len: int,
 ) -> str | object:
-    """Get passphrase."""
+    """Entry of a name of up to `max_len` bytes, e.g. the device label,
+    starting with `label` entered. Unlike a passphrase, the characters
+    are shown as they are entered."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -326,9 +675,41 @@ def select_word(
     title: str,  # unused on TR
     description: str,
     words: Iterable[str],
-) -> int:
+) -> ValueResult:
    """Select mnemonic word from three possibilities - seed check after backup. The
-   iterable must be of exact size. Returns index in range `0..3`."""
+   iterable must be of exact size. Returns SELECTED with index in range `0..3`."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def choose_from_list(
+    *,
+    title: str,
+    items: Iterable[str],
+) -> ValueResult:
+   """Select one of the items provided by the host, e.g. an account name. At most
+   32 items of at most 20 bytes each. Returns SELECTED with the index of the item."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def edit_list(
+    *,
+    title: str,
+    items: Iterable[str],
+    available: Iterable[str] | None = None,
+) -> list[str] | object:
+   """Reorder and delete `items`, e.g. labels or enabled coins, and add the
+   `available` ones. At most 16 items together, of at most 20 bytes each.
+   Returns the final list."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def choose_account(
+    *,
+    title: str,
+    accounts: Iterable[tuple[str, str]],
+) -> ValueResult:
+   """Select one of the accounts provided by the host, `(label, balance)` pairs. At
+   most 16 accounts. Returns SELECTED with the index of the account."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -380,7 +761,7 @@ def confirm_recovery(
 def select_word_count(
     *,
     dry_run: bool,  # unused on TR
-) -> int | str:  # TR returns str
+) -> ValueResult | str:  # TR returns str
    """Select mnemonic word count from (12, 18, 20, 24, 33)."""
 
 
@@ -398,10 +779,23 @@ def show_progress(
     title: str,
     indeterminate: bool = False,
     description: str = "",
+    show_eta: bool = False,
 ) -> object:
    """Show progress loader. Please note that the number of lines reserved on screen for
    description is determined at construction time. If you want multiline descriptions
-   make sure the initial description has at least that amount of lines."""
+   make sure the initial description has at least that amount of lines. With `show_eta`,
+   estimated remaining time is shown above the description (determinate mode only)."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def show_pin_lockout(
+    *,
+    title: str,
+    description: str,
+    seconds: int,
+) -> object:
+    """Countdown of the `seconds` long wait after wrong PINs, driven by
+    progress events like `show_progress`. The title is not shown."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -424,8 +818,8 @@ def show_homescreen(
     notification: str | None,
     notification_level: int = 0,
     skip_first_paint: bool,
-) -> CANCELLED:
-    """Idle homescreen."""
+) -> CANCELLED | INFO:
+    """Idle homescreen. Returns INFO when the user opens the settings."""
 
 
 # rust/src/ui/model_tr/layout.rs
@@ -449,6 +843,32 @@ def confirm_firmware_update(
 CONFIRMED: object
 CANCELLED: object
 INFO: object
+SELECTED: object
+MORE: object
+
+
+# rust/src/ui/model_tt/layout.rs
+class ValueResult:
+    """Result of a layout carrying a value, e.g. the selected index."""
+    kind: object
+    name: str
+    value: int
+
+
+# rust/src/ui/model_tt/layout.rs
+class ValidationError(ValueError):
+    """Layout argument failed validation. The first argument of the
+    exception is name of the field, the second one the reason."""
+
+
+# rust/src/ui/model_tt/layout.rs
+class MissingFieldError(ValidationError):
+    """Required layout argument was not passed."""
+
+
+# rust/src/ui/model_tt/layout.rs
+class InvalidFieldError(ValidationError):
+    """Layout argument has an unexpected type or value."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -456,6 +876,149 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def disable_transitions(disable: bool) -> None:
+    """Disable animated screen transitions."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_accessibility(
+    *,
+    reduce_motion: bool = False,
+    longer_timeouts: bool = False,
+    larger_fonts: bool = False,
+    high_contrast: bool = False,
+    audio_feedback: bool = False,
+) -> None:
+    """Set the accessibility preferences of the user. Reduced motion skips
+    decorative movement, e.g. transitions and sliding items. Longer
+    timeouts give more time to read and to hold buttons. High contrast
+    paints grey text in white or black. Audio feedback plays the outcome
+    of a flow on the buzzer, if there is one."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_autodim(
+    *,
+    delay_ms: int | None = None,
+    level: int = 0,
+) -> None:
+    """Dim the display to backlight `level` after `delay_ms` without input, or
+    never with `None`. The input waking the display up is not passed on to the
+    layout."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_wallet_badge(hidden: bool | None) -> None:
+    """Show on every screen whether the current session uses a hidden
+    wallet, i.e. one derived with a non-empty passphrase, or the
+    standard one. `None` hides the badge."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def trace_paint_counters(enabled: bool) -> None:
+    """Include in the traces of layouts how many times each component has
+    been painted and has requested a paint. Debug builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def wipe_carry() -> None:
+    """Zeroize the passphrase kept by `request_passphrase` with
+    `carry_out` in case no other entry has taken it."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def secrets_equal(a: str, b: str) -> bool:
+    """Compare two secrets, e.g. a PIN and its re-entry, in time that does
+    not depend on where they differ."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def enable_profiler(enable: bool) -> None:
+    """Show paint and event handling times of layouts in the corner of
+    the screen, debug builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_replay_seed(seed: int | None) -> None:
+    """Draw UI randomness from a PRNG seeded with `seed`, or from the RNG
+    again if `None`. Emulator debug builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def translations_max_length() -> int:
+    """Longest translations blob fitting the flash, zero on models
+    without room for one."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def erase_translations() -> None:
+    """Switch to English and erase the translations installed in the
+    flash."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def write_translations(data: bytes, offset: int) -> None:
+    """Write `data` at `offset` of the translations blob being installed,
+    after `erase_translations()`."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def commit_translations(length: int) -> None:
+    """Install the first `length` bytes written as the translations blob,
+    without switching to it. Until then nothing is installed."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def load_translations() -> None:
+    """Use the strings of the installed translations. Strings missing in
+    them are shown in English. Raises `ValueError` if there are none or
+    they are invalid."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_battery_level(level: int | None) -> None:
+    """Charge of the battery in percent, or `None` on external power. Below
+    20 percent animations run with fewer frames and decorative effects, e.g.
+    marquees and screen transitions, are skipped."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_density(density: int) -> None:
+    """Spacing of confirm screens, a value of `ScreenDensity`. Compact
+    has tighter lines and narrower fonts fitting more on each page."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_theme_inverted(inverted: bool) -> None:
+    """Paint the screens dark on light, the shades of grey swapped. Takes
+    effect from the next paint."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def save_resume_point(*, flow: int, step: int, page: int) -> None:
+    """Remember that `page` of `step` was shown in the flow identified by
+    `flow`, so that it can be resumed after a reset. Layouts keep the
+    page up to date as it changes. Needs the storage unlocked."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def resume_point(flow: int) -> tuple[int, int] | None:
+    """Step and page saved for `flow`, or `None` when there is nothing to
+    resume."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def clear_resume_point() -> None:
+    """Forget the saved point, e.g. once the flow is finished."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_keyboard_layout(layout: int) -> None:
+    """Order the letters of keyboards created from now on by `layout`, a
+    value of `KeyboardLayout`."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def check_homescreen_format(data: bytes) -> bool:
     """Check homescreen format and dimensions."""
@@ -471,9 +1034,33 @@ def confirm_action(
     verb_cancel: str | None = None,
     hold: bool = False,
     hold_danger: bool = False,
+    destructive: bool = False,
     reverse: bool = False,
 ) -> object:
-    """Confirm action."""
+    """Confirm action. Irreversible actions are confirmed with a button in
+    warning colors, either held with `hold_danger` or clicked with
+    `destructive`."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_blind_signing(
+    *,
+    title: str | None = None,
+    description: str = "",
+) -> object:
+    """Mandatory warning before signing something the device cannot decode,
+    e.g. a call of an unknown contract or raw data. Accepted by holding."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_language(
+    *,
+    version: str,
+    signed: bool,
+) -> object:
+    """Confirm switching to the translations in use, showing their language,
+    `version` and whether they are signed. The screen itself is in the new
+    language."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -536,6 +1123,53 @@ def confirm_properties(
     the value is to be rendered as binary with monospace font, False otherwise."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def confirm_template(
+    *,
+    title: str,
+    rows: Iterable[tuple[str, str]],
+    warning: bool = False,
+    verb: str = "CONFIRM",
+    verb_cancel: str = "CANCEL",
+) -> object:
+    """Confirm an application-specific action described by the host. Every
+    text must be trimmed printable ASCII and short enough, there can be 1 to
+    6 rows. Raises ValueError otherwise."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_payment_uri(
+    *,
+    uri: str,
+    scheme: str = "bitcoin",
+    title: str = "PAYMENT REQUEST",
+) -> object:
+    """Confirm a BIP-21 payment URI, its address, amount, label and message,
+    with the whole URI on the last page. Raises ValueError when the URI is
+    malformed."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_terms(
+    *,
+    title: str,
+    text: str,
+    verb: str = "ACCEPT",
+) -> object:
+    """Long notice or disclaimer, paged by swiping. Confirming is only possible
+    once the last page has been shown."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_streamed(
+    *,
+    title: str,
+) -> object:
+    """Confirm content pushed into the layout piece by piece with
+    `update("item", (title, value))`, `update("item", None)` ends it. Returns
+    `MORE` when the user is past the last item received."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def confirm_reset_device(
     *,
@@ -559,6 +1193,22 @@ def show_address_details(
     """Show address details - QR code, account, path, cosigner xpubs."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_pubkey_details(
+    *,
+    title: str,
+    pubkey: str,
+    fingerprint: str | None = None,
+    account: str | None = None,
+    path: str | None = None,
+    path_unusual: bool = False,
+) -> object:
+    """Show an extended public key or descriptor in chunks over several pages,
+    then as a QR code, then a summary with the fingerprint, account and path.
+    The path is marked when `path_unusual`, i.e. it does not follow any known
+    scheme."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def show_info_with_cancel(
     *,
@@ -581,8 +1231,11 @@ def confirm_value(
     info_button: bool = False,
     hold: bool = False,
     chunkify: bool = False,
+    coin: str | None = None,
 ) -> object:
-    """Confirm value. Merge of confirm_total and confirm_output."""
+    """Confirm value. Merge of confirm_total and confirm_output. The icon
+    and name of the `coin` with the given shortcut are shown in the
+    header, if there is an icon for it."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -592,6 +1245,7 @@ def confirm_total(
     items: Iterable[tuple[str, str]],
     info_button: bool = False,
     cancel_arrow: bool = False,
+    coin: str | None = None,
 ) -> object:
     """Transaction summary. Always hold to confirm."""
 
@@ -625,9 +1279,11 @@ def confirm_fido(
     app_name: str,
     icon_name: str | None,
     accounts: list[str | None],
-) -> int | object:
+    time_ms: int = 0,
+) -> ValueResult | object:
     """FIDO confirmation.
-    Returns page index in case of confirmation and CANCELLED otherwise.
+    Returns SELECTED with the page index in case of confirmation and CANCELLED
+    otherwise, also when `time_ms` is set and the countdown runs out.
     """
 
 
@@ -668,6 +1324,21 @@ def show_success(
     """Success modal. No buttons shown when `button` is empty string."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_entropy_mixing(
+    *,
+    title: str,
+    description: str,
+    seed: bytes,
+    time_ms: int,
+) -> bytes:
+    """Abstract pattern stirred while the entropy of a new wallet is
+    mixed, starting from `seed`, a hash of the entropy and not the
+    entropy itself. Closes after `time_ms` unless the user presses the
+    buttons or taps the screen to add entropy, then once they stop.
+    Returns the timing of the presses, empty if there were none."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def show_info(
     *,
@@ -680,6 +1351,34 @@ def show_info(
     """Info modal. No buttons shown when `button` is empty string."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_settings_menu(
+    *,
+    settings: Iterable[tuple[str, int]],
+) -> tuple[list[tuple[str, int]], bool]:
+    """Settings menu. `settings` are names and current values of the
+    settings to offer, one of `auto_lock`, `rotation`, `safety_checks`,
+    `large_text`, `remember_destinations`, `spending_threshold` and
+    `signing_receipt`. Returns the names and new values of the changed
+    ones, and whether the user wants to rename the device."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def show_result(
+    *,
+    success: bool,
+    title: str,
+    detail: str = "",
+    button: str = "",
+    time_ms: int = 0,
+    rejected: bool = False,
+) -> object:
+    """Result of a flow with a big success or failure icon. Dismissed by
+    the button or after `time_ms`, whichever comes first. Without both
+    it is only drawn once. A failure the user has `rejected` plays the
+    reject sound instead of the error one."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def show_mismatch(*, title: str) -> object:
     """Warning modal, receiving address mismatch."""
@@ -718,6 +1417,47 @@ def confirm_more(
     Meant to be used with confirm_with_info."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_blob(
+    *,
+    title: str,
+    data: bytes,
+    base64: bool = False,
+    verb: str = "",
+    total: int = 0,
+) -> object:
+    """All of `data` in hex or base64, with offsets and the total length,
+    on as many pages as needed. Returns INFO to switch the format. With
+    `verb`, the data is to be confirmed with that button rather than just
+    closed. `total` is the length of the whole blob if `data` is only its
+    start."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def show_receipt(
+    *,
+    title: str,
+    description: str,
+    fingerprint: str,
+    qr_data: str,
+    qr: bool = False,
+) -> object:
+    """Short fingerprint of what was signed, to be compared with the host.
+    `qr` shows `qr_data` as a QR code instead."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def confirm_settings_change(
+    *,
+    title: str,
+    changes: Iterable[tuple[str, str, str, bool]],
+    hold: bool = False,
+) -> object:
+    """Confirm changes of settings, each of them `(name, old value, new
+    value, reduces security)`. The new values reducing security stand
+    out, `hold` for changes like that."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def confirm_coinjoin(
     *,
@@ -734,8 +1474,13 @@ def request_pin(
     subprompt: str,
     allow_cancel: bool = True,
     wrong_pin: bool = False,
+    shuffle: bool = True,
+    shuffle_on_keypress: bool = False,
 ) -> str | object:
-    """Request pin on device."""
+    """Request pin on device. The keypad is shuffled once or after every
+    digit entered, according to `shuffle` and `shuffle_on_keypress`.
+    `update("toast", text)` shows a notification without interrupting
+    the entry."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -743,8 +1488,23 @@ def request_passphrase(
     *,
     prompt: str,
     max_len: int,
+    carry_in: bool = False,
+    carry_out: bool = False,
 ) -> str | object:
-    """Passphrase input keyboard."""
+    """Passphrase input keyboard. With `carry_out` the confirmed
+    passphrase is kept for the next keyboard of the flow, which takes it
+    with `carry_in` and starts with it entered."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def request_label(
+    *,
+    prompt: str,
+    label: str = "",
+    max_len: int,
+) -> str | object:
+    """Keyboard for a name of up to `max_len` bytes, e.g. the device
+    label, starting with `label` entered."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -769,9 +1529,20 @@ def select_word(
     title: str,
     description: str,
     words: Iterable[str],
-) -> int:
+) -> ValueResult:
     """Select mnemonic word from three possibilities - seed check after backup. The
-   iterable must be of exact size. Returns index in range `0..3`."""
+   iterable must be of exact size. Returns SELECTED with index in range `0..3`."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def check_share_words(
+    *,
+    title: str,
+    checks: Iterable[tuple[str, Iterable[str], int]],
+) -> list[int] | object:
+    """Three word checks after the backup in a row, each a description, three
+   words and the index of the correct one. Returns the picked indices, or
+   CANCELLED as soon as a wrong word is picked."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -815,15 +1586,18 @@ def confirm_recovery(
     button: str,
     dry_run: bool,
     info_button: bool = False,
+    share_groups: Iterable[tuple[int, int]] | None = None,
 ) -> object:
-    """Device recovery homescreen."""
+    """Device recovery homescreen. `share_groups` are the collected and required
+    share counts of the groups of a multi-group backup, required is 0 for groups
+    without any share yet."""
 
 
 # rust/src/ui/model_tt/layout.rs
 def select_word_count(
     *,
     dry_run: bool,
-) -> int | str:  # TT returns int
+) -> ValueResult | str:  # TT returns ValueResult
     """Select mnemonic word count from (12, 18, 20, 24, 33)."""
 
 
@@ -843,16 +1617,43 @@ def show_remaining_shares(
     """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_check_result(
+    *,
+    title: str,
+    items: Iterable[tuple[bool | None, str]],
+    button: str,
+) -> object:
+    """Outcome of the checks of a backup, one item each. Passed checks have a check
+    mark next to them, failed ones a warning, `None` is a note without an icon."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def show_progress(
     *,
     title: str,
     indeterminate: bool = False,
     description: str = "",
+    show_eta: bool = False,
+    cancellable: bool = False,
 ) -> object:
     """Show progress loader. Please note that the number of lines reserved on screen for
    description is determined at construction time. If you want multiline descriptions
-   make sure the initial description has at least that amount of lines."""
+   make sure the initial description has at least that amount of lines. With `show_eta`,
+   estimated remaining time is shown below the loader (determinate mode only). With
+   `cancellable`, the operation can be aborted by holding a cancel button, or by the host
+   disconnecting, in which case CANCELLED is returned from the event."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def show_pin_lockout(
+    *,
+    title: str,
+    description: str,
+    seconds: int,
+) -> object:
+    """Countdown of the `seconds` long wait after wrong PINs, driven by
+    progress events like `show_progress`."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -875,8 +1676,8 @@ def show_homescreen(
     notification: str | None,
     notification_level: int = 0,
     skip_first_paint: bool,
-) -> CANCELLED:
-    """Idle homescreen."""
+) -> CANCELLED | INFO:
+    """Idle homescreen. Returns INFO when the user opens the settings."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -113,6 +113,8 @@ trezor.enums.HomescreenFormat
 import trezor.enums.HomescreenFormat
 trezor.enums.InputScriptType
 import trezor.enums.InputScriptType
+trezor.enums.KeyboardLayout
+import trezor.enums.KeyboardLayout
 trezor.enums.MessageType
 import trezor.enums.MessageType
 trezor.enums.OutputScriptType
@@ -125,6 +127,8 @@ trezor.enums.RequestType
 import trezor.enums.RequestType
 trezor.enums.SafetyCheckLevel
 import trezor.enums.SafetyCheckLevel
+trezor.enums.ScreenDensity
+import trezor.enums.ScreenDensity
 trezor.enums.SdProtectOperationType
 import trezor.enums.SdProtectOperationType
 trezor.enums.WordRequestType
@@ -147,6 +151,8 @@ trezor.sdcard
 import trezor.sdcard
 trezor.strings
 import trezor.strings
+trezor.translations
+import trezor.translations
 trezor.ui
 import trezor.ui
 trezor.ui.layouts
@@ -275,6 +281,8 @@ apps.common
 import apps.common
 apps.common.address_mac
 import apps.common.address_mac
+apps.common.address_poisoning
+import apps.common.address_poisoning
 apps.common.address_type
 import apps.common.address_type
 apps.common.authorization
@@ -293,6 +301,8 @@ apps.common.paths
 import apps.common.paths
 apps.common.readers
 import apps.common.readers
+apps.common.recent_destinations
+import apps.common.recent_destinations
 apps.common.request_pin
 import apps.common.request_pin
 apps.common.safety_checks
@@ -301,8 +311,12 @@ apps.common.sdcard
 import apps.common.sdcard
 apps.common.seed
 import apps.common.seed
+apps.common.signing_receipt
+import apps.common.signing_receipt
 apps.common.signverify
 import apps.common.signverify
+apps.common.spending_policy
+import apps.common.spending_policy
 apps.common.writers
 import apps.common.writers
 apps.debug
@@ -321,6 +335,10 @@ apps.management.backup_device
 import apps.management.backup_device
 apps.management.backup_types
 import apps.management.backup_types
+apps.management.change_label
+import apps.management.change_label
+apps.management.change_language
+import apps.management.change_language
 apps.management.change_pin
 import apps.management.change_pin
 apps.management.change_wipe_code
@@ -349,6 +367,8 @@ apps.management.sd_protect
 import apps.management.sd_protect
 apps.management.set_u2f_counter
 import apps.management.set_u2f_counter
+apps.management.settings_menu
+import apps.management.settings_menu
 apps.management.show_tutorial
 import apps.management.show_tutorial
 apps.management.wipe_device
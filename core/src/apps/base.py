@@ -1,3 +1,4 @@
+from micropython import const
 from typing import TYPE_CHECKING
 
 import storage.cache as storage_cache
@@ -26,6 +27,9 @@ if TYPE_CHECKING:
 
 _SCREENSAVER_IS_ON = False
 
+# longest time without input before the display dims
+_AUTODIM_DELAY_MS = const(60_000)
+
 
 def busy_expiry_ms() -> int:
     """
@@ -48,7 +52,7 @@ def get_features() -> Features:
     from trezor.messages import Features
     from trezor.ui import HEIGHT, WIDTH
 
-    from apps.common import mnemonic, safety_checks
+    from apps.common import mnemonic, safety_checks, spending_policy
 
     f = Features(
         vendor="trezor.io",
@@ -149,6 +153,13 @@ def get_features() -> Features:
         f.display_rotation = storage_device.get_rotation()
         f.experimental_features = storage_device.get_experimental_features()
         f.hide_passphrase_from_host = storage_device.get_hide_passphrase_from_host()
+        f.keyboard_layout = storage_device.get_keyboard_layout()
+        f.density = storage_device.get_density()
+        f.remember_destinations = storage_device.get_remember_destinations()
+        f.spending_threshold = spending_policy.read_setting()
+        f.large_text = storage_device.get_large_text()
+        f.audio_feedback = storage_device.get_audio_feedback()
+        f.signing_receipt = storage_device.get_signing_receipt()
 
     return f
 
@@ -177,6 +188,10 @@ async def handle_Initialize(msg: Initialize) -> Features:
                 b"\x01" if msg.derive_cardano else b"",
             )
 
+    # the session may have been resumed with its seed already derived
+    from apps.common.seed import update_wallet_badge
+
+    update_wallet_badge()
     features = get_features()
     features.session_id = session_id
     return features
@@ -212,7 +227,10 @@ async def handle_SetBusy(msg: SetBusy) -> Success:
 
 
 async def handle_EndSession(msg: EndSession) -> Success:
+    import trezorui2
+
     storage_cache.end_current_session()
+    trezorui2.set_wallet_badge(None)
     return Success()
 
 
@@ -406,12 +424,27 @@ def get_pinlocked_handler(
 
 # this function is also called when handling ApplySettings
 def reload_settings_from_storage() -> None:
+    import trezorui2
     from trezor import ui
 
-    workflow.idle_timer.set(
-        storage_device.get_autolock_delay_ms(), lock_device_if_unlocked
+    autolock_delay_ms = storage_device.get_autolock_delay_ms()
+    workflow.idle_timer.set(autolock_delay_ms, lock_device_if_unlocked)
+    # dim the display well before the device locks itself
+    trezorui2.set_autodim(
+        delay_ms=min(autolock_delay_ms // 2, _AUTODIM_DELAY_MS),
+        level=ui.style.BACKLIGHT_LOW,
     )
     wire.EXPERIMENTAL_ENABLED = storage_device.get_experimental_features()
+    trezorui2.set_keyboard_layout(storage_device.get_keyboard_layout())
+    trezorui2.set_density(storage_device.get_density())
+    trezorui2.set_theme_inverted(storage_device.get_theme_inverted())
+    trezorui2.set_accessibility(
+        reduce_motion=storage_device.get_reduce_motion(),
+        larger_fonts=storage_device.get_large_text(),
+        longer_timeouts=storage_device.get_longer_timeouts(),
+        audio_feedback=storage_device.get_audio_feedback(),
+        high_contrast=storage_device.get_high_contrast(),
+    )
     if ui.display.orientation() != storage_device.get_rotation():
         ui.backlight_fade(ui.style.BACKLIGHT_DIM)
         ui.display.orientation(storage_device.get_rotation())
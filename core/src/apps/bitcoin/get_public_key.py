@@ -107,6 +107,8 @@ async def get_public_key(
             "XPUB",
             account=account,
             path=path,
+            path_unusual=account_name is None,
+            fingerprint=f"{keychain.root_fingerprint():08x}",
             mismatch_title="XPUB mismatch?",
             br_type="show_xpub",
         )
@@ -20,6 +20,7 @@ async def sign_message(
     from trezor.ui.layouts import confirm_signverify
 
     from apps.common.paths import address_n_to_str, validate_path
+    from apps.common.signing_receipt import show_signing_receipt
     from apps.common.signverify import decode_message, message_digest
 
     from .addresses import address_short, get_address
@@ -67,4 +68,6 @@ async def sign_message(
     if script_type_info != 0 and not msg.no_script_type:
         signature = bytes([signature[0] + script_type_info]) + signature[1:]
 
+    await show_signing_receipt("Message signed", "Message digest:", digest)
+
     return MessageSignature(address=address, signature=signature)
@@ -6,7 +6,7 @@ from trezor.crypto.hashlib import sha256
 from trezor.utils import HashWriter
 from trezor.wire import DataError, ProcessError
 
-from apps.common import safety_checks
+from apps.common import safety_checks, spending_policy
 
 from .. import writers
 from ..common import input_is_external_unverified
@@ -373,6 +373,8 @@ class BasicApprover(Approver):
             else:
                 await helpers.confirm_joint_total(spending, total, coin, amount_unit)
 
+        await spending_policy.check_spending(spending, coin)
+
 
 class CoinJoinApprover(Approver):
     # Minimum registrable output amount accepted by the coinjoin coordinator.
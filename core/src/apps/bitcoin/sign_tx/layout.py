@@ -66,6 +66,8 @@ async def confirm_output(
 ) -> None:
     from trezor.enums import OutputScriptType
 
+    from apps.common import address_poisoning, recent_destinations
+
     from . import omni
 
     if output.script_type == OutputScriptType.PAYTOOPRETURN:
@@ -82,14 +84,17 @@ async def confirm_output(
             )
         else:
             # generic OP_RETURN
-            layout = layouts.confirm_blob(
+            layout = layouts.show_blob(
                 "op_return",
                 "OP_RETURN",
                 data,
+                verb="Confirm",
                 br_code=ButtonRequestType.ConfirmOutput,
             )
     else:
         assert output.address is not None
+        if not output.address_n:
+            await address_poisoning.check_address(output.address)
         address_short = addresses.address_short(coin, output.address)
         if output.payment_req_index is not None:
             title = "Confirm details"
@@ -112,6 +117,8 @@ async def confirm_output(
                 )
                 or f"address path {address_n_to_str(output.address_n)}"
             )
+        elif not output.address_n:
+            address_label = recent_destinations.label(output.address)
 
         layout = layouts.confirm_output(
             address_short,
@@ -120,9 +127,13 @@ async def confirm_output(
             address_label=address_label,
             output_index=output_index,
             chunkify=chunkify,
+            coin=coin.coin_shortcut,
         )
 
     await layout
+    if output.address is not None and not output.address_n:
+        address_poisoning.remember(output.address)
+        await recent_destinations.confirmed(output.address)
 
 
 async def confirm_decred_sstx_submission(
@@ -247,6 +258,7 @@ async def confirm_total(
         format_coin_amount(fee, coin, amount_unit),
         fee_rate_amount=format_fee_rate(fee_rate, coin) if fee_rate >= 0 else None,
         account_label=account_label(coin, address_n),
+        coin=coin.coin_shortcut,
     )
 
 
@@ -0,0 +1,74 @@
+"""
+Defense against address poisoning. The attacker sends dust to the user from an
+address that starts and ends like one the user sends to, hoping that the user copies
+it from the transaction history next time. The addresses the user confirmed recently
+are kept in a small ring buffer in RAM, as their beginning, end and a hash of the
+whole. A destination starting and ending like one of them but differing in between
+gets a dedicated warning.
+"""
+
+from micropython import const
+
+from storage import cache as storage_cache
+
+_PREFIX_LEN = const(6)
+_SUFFIX_LEN = const(4)
+_AFFIX_LEN = const(10)  # _PREFIX_LEN + _SUFFIX_LEN
+_HASH_LEN = const(6)
+_ENTRY_LEN = const(16)  # _AFFIX_LEN + _HASH_LEN
+_CAPACITY = const(8)
+
+
+def _entry(address: str) -> bytes | None:
+    from trezor.crypto.hashlib import sha256
+
+    # Case only encodes checksums (EIP-55) or is not significant at all (bech32).
+    encoded = address.lower().encode()
+    if len(encoded) <= _AFFIX_LEN:
+        return None
+    return (
+        encoded[:_PREFIX_LEN]
+        + encoded[-_SUFFIX_LEN:]
+        + sha256(encoded).digest()[:_HASH_LEN]
+    )
+
+
+def _recent() -> list[bytes]:
+    data = storage_cache.get(storage_cache.APP_COMMON_RECENT_ADDRESSES, b"")
+    return [data[i : i + _ENTRY_LEN] for i in range(0, len(data), _ENTRY_LEN)]
+
+
+def lookalike(address: str) -> str | None:
+    """Beginning and end of a recently confirmed address which `address` looks like
+    without being the same, e.g. "bc1qar...5mdq". None if there is no such address."""
+    entry = _entry(address)
+    if entry is None:
+        return None
+    for recent in _recent():
+        if recent[:_AFFIX_LEN] == entry[:_AFFIX_LEN] and recent != entry:
+            prefix = recent[:_PREFIX_LEN].decode()
+            suffix = recent[_PREFIX_LEN:_AFFIX_LEN].decode()
+            return f"{prefix}...{suffix}"
+    return None
+
+
+def remember(address: str) -> None:
+    """Add `address` as the most recent one, dropping the oldest one if full."""
+    entry = _entry(address)
+    if entry is None:
+        return
+    entries = [entry] + [e for e in _recent() if e != entry]
+    storage_cache.set(
+        storage_cache.APP_COMMON_RECENT_ADDRESSES, b"".join(entries[:_CAPACITY])
+    )
+
+
+async def check_address(address: str) -> None:
+    """Warn the user if `address` looks like a recently confirmed one. Raises
+    ActionCancelled if the user rejects it. The caller remembers the address with
+    `remember()` once it is confirmed."""
+    recent = lookalike(address)
+    if recent is not None:
+        from trezor.ui.layouts import confirm_address_lookalike
+
+        await confirm_address_lookalike(recent)
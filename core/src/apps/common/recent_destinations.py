@@ -0,0 +1,79 @@
+"""
+Memory of the destination addresses the user confirmed recently, opt-in with the
+`remember_destinations` setting. Only a hash of each address is stored, together with
+a short name the user can give it, so that the next payout to the same address is
+recognized on the confirmation screen.
+"""
+
+from micropython import const
+
+import storage.device as storage_device
+
+_HASH_LEN = const(8)
+LABEL_MAXLENGTH = const(16)
+_ENTRY_LEN = const(24)  # _HASH_LEN + LABEL_MAXLENGTH
+_CAPACITY = const(10)
+
+PREVIOUSLY_USED = "Previously used"
+
+
+def _hash(address: str) -> bytes:
+    from trezor.crypto.hashlib import sha256
+
+    return sha256(b"recent destination" + address.encode()).digest()[:_HASH_LEN]
+
+
+def _entries() -> list[bytes]:
+    data = storage_device.get_recent_destinations()
+    return [data[i : i + _ENTRY_LEN] for i in range(0, len(data), _ENTRY_LEN)]
+
+
+def _find(address: str) -> bytes | None:
+    if not storage_device.get_remember_destinations():
+        return None
+    address_hash = _hash(address)
+    for entry in _entries():
+        if entry[:_HASH_LEN] == address_hash:
+            return entry
+    return None
+
+
+def label(address: str) -> str | None:
+    """What the confirmation screen says about `address`: the name the user gave it,
+    `PREVIOUSLY_USED` if it has none, or None if it has not been used recently."""
+    entry = _find(address)
+    if entry is None:
+        return None
+    name = entry[_HASH_LEN:].rstrip(b"\x00")
+    return name.decode() if name else PREVIOUSLY_USED
+
+
+def remember(address: str, name: str = "") -> None:
+    """Store `address` named `name` as the most recent destination, dropping the
+    oldest one if full. Does nothing unless the memory is turned on."""
+    if not storage_device.get_remember_destinations():
+        return
+    address_hash = _hash(address)
+    encoded = name.encode()
+    while len(encoded) > LABEL_MAXLENGTH:
+        name = name[:-1]
+        encoded = name.encode()
+    entry = address_hash + encoded + bytes(LABEL_MAXLENGTH - len(encoded))
+    entries = [entry] + [e for e in _entries() if e[:_HASH_LEN] != address_hash]
+    storage_device.set_recent_destinations(b"".join(entries[:_CAPACITY]))
+
+
+async def confirmed(address: str) -> None:
+    """Remember `address` after the user confirmed it, offering to name it when it
+    is new."""
+    if not storage_device.get_remember_destinations():
+        return
+    entry = _find(address)
+    if entry is not None:
+        # Keep the name, only make it the most recent one.
+        name = entry[_HASH_LEN:].rstrip(b"\x00").decode()
+    else:
+        from trezor.ui.layouts import request_destination_label
+
+        name = await request_destination_label(LABEL_MAXLENGTH) or ""
+    remember(address, name)
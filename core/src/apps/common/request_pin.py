@@ -50,13 +50,15 @@ async def request_pin(
 
 
 async def request_pin_confirm(*args: Any, **kwargs: Any) -> str:
+    from trezorui2 import secrets_equal
+
     from trezor.ui.layouts import confirm_reenter_pin, pin_mismatch_popup
 
     while True:
         pin1 = await request_pin("Enter new PIN", *args, **kwargs)
         await confirm_reenter_pin()
         pin2 = await request_pin("Re-enter new PIN", *args, **kwargs)
-        if pin1 == pin2:
+        if secrets_equal(pin1, pin2):
             return pin1
         await pin_mismatch_popup()
 
@@ -45,6 +45,27 @@ class Slip21Node:
         return Slip21Node(data=self.data)
 
 
+def _store_wallet(passphrase: str) -> None:
+    storage_cache.set(
+        storage_cache.APP_COMMON_HIDDEN_WALLET, b"\x01" if passphrase else b""
+    )
+    update_wallet_badge()
+
+
+def update_wallet_badge() -> None:
+    """
+    Shows on every screen whether the current session uses a hidden wallet, once its
+    passphrase is known. Nothing is shown if passphrase protection is off.
+    """
+    import trezorui2
+
+    hidden = storage_cache.get(storage_cache.APP_COMMON_HIDDEN_WALLET)
+    if hidden is None or not storage_device.is_passphrase_enabled():
+        trezorui2.set_wallet_badge(None)
+    else:
+        trezorui2.set_wallet_badge(bool(hidden))
+
+
 if not utils.BITCOIN_ONLY:
     # === Cardano variant ===
     # We want to derive both the normal seed and the Cardano seed together, AND
@@ -67,6 +88,7 @@ if not utils.BITCOIN_ONLY:
         passphrase = await get_passphrase()
 
         if need_seed:
+            _store_wallet(passphrase)
             common_seed = mnemonic.get_seed(passphrase)
             storage_cache.set(storage_cache.APP_COMMON_SEED, common_seed)
 
@@ -89,6 +111,7 @@ else:
     @storage_cache.stored_async(storage_cache.APP_COMMON_SEED)
     async def get_seed() -> bytes:
         passphrase = await get_passphrase()
+        _store_wallet(passphrase)
         return mnemonic.get_seed(passphrase)
 
 
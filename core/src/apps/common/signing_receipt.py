@@ -0,0 +1,35 @@
+"""
+Screen shown after signing with a short fingerprint of what was signed, opt-in with
+the `signing_receipt` setting. The user can compare the fingerprint with the one the
+host shows for what it broadcasts, or scan the whole digest from a QR code.
+"""
+
+from micropython import const
+
+import storage.device as storage_device
+
+_FINGERPRINT_BYTES = const(8)
+
+
+def fingerprint(digest: bytes) -> str:
+    """First bytes of `digest` in hex, in groups of four characters."""
+    from ubinascii import hexlify
+
+    h = hexlify(digest[:_FINGERPRINT_BYTES]).decode()
+    return " ".join(h[i : i + 4] for i in range(0, len(h), 4))
+
+
+async def show_signing_receipt(title: str, description: str, digest: bytes) -> None:
+    from ubinascii import hexlify
+
+    from trezor.ui.layouts import show_receipt
+
+    if not storage_device.get_signing_receipt():
+        return
+    await show_receipt(
+        "signing_receipt",
+        title,
+        description,
+        fingerprint(digest),
+        hexlify(digest).decode(),
+    )
@@ -0,0 +1,57 @@
+"""
+Amount above which signing a transaction needs the PIN once more after the user
+confirmed it. The threshold is either stored in the device, or set temporarily, until
+the device restarts, overriding the stored one. Only Bitcoin transactions are checked,
+the threshold is in satoshis of the amount leaving the wallet.
+"""
+
+from typing import TYPE_CHECKING
+
+import storage.cache as storage_cache
+import storage.device as storage_device
+from storage.cache import APP_COMMON_SPENDING_THRESHOLD_TEMPORARY
+
+if TYPE_CHECKING:
+    from apps.common.coininfo import CoinInfo
+
+_COINS = ("Bitcoin", "Testnet", "Regtest")
+
+
+def read_setting() -> int | None:
+    """
+    Returns the effective threshold, None if there is none.
+    """
+    temporary = storage_cache.get(APP_COMMON_SPENDING_THRESHOLD_TEMPORARY)
+    if temporary:
+        # Zero turns the stored threshold off until restart.
+        return int.from_bytes(temporary, "big") or None
+    return storage_device.get_spending_threshold()
+
+
+def apply_setting(threshold: int | None, temporary: bool = False) -> None:
+    """
+    Changes the threshold, None turns it off.
+    """
+    if temporary:
+        storage_cache.set_int(APP_COMMON_SPENDING_THRESHOLD_TEMPORARY, threshold or 0)
+    else:
+        storage_cache.delete(APP_COMMON_SPENDING_THRESHOLD_TEMPORARY)
+        storage_device.set_spending_threshold(threshold)
+
+
+def needs_pin(spending: int, coin: CoinInfo) -> bool:
+    threshold = read_setting()
+    return threshold is not None and coin.coin_name in _COINS and spending > threshold
+
+
+async def check_spending(spending: int, coin: CoinInfo) -> None:
+    """
+    Asks for the PIN if the confirmed `spending` is above the threshold. Raises if the
+    user cancels or the PIN is wrong, devices without a PIN have nothing to ask.
+    """
+    if not needs_pin(spending, coin):
+        return
+
+    from apps.common.request_pin import verify_user_pin
+
+    await verify_user_pin("PIN to send")
@@ -18,6 +18,8 @@ if __debug__:
     from apps import workflow_handlers
 
     if TYPE_CHECKING:
+        from typing import Any
+
         from trezor.messages import (
             DebugLinkDecision,
             DebugLinkEraseSdCard,
@@ -25,6 +27,7 @@ if __debug__:
             DebugLinkRecordScreen,
             DebugLinkReseedRandom,
             DebugLinkResetDebugEvents,
+            DebugLinkShowProfiler,
             DebugLinkState,
             DebugLinkWatchLayout,
         )
@@ -63,6 +66,8 @@ if __debug__:
 
     def notify_layout_change(layout: Layout, event_id: int | None = None) -> None:
         layout.read_content_into(storage.current_content_tokens)
+        storage.current_layout_tree = layout.debug_tree()
+        storage.current_layout_trace = layout.read_trace_binary()
         if storage.watch_layout_changes or layout_change_chan.takers:
             payload = (event_id, storage.current_content_tokens)
             layout_change_chan.publish(payload)
@@ -208,9 +213,31 @@ if __debug__:
             return None
         else:
             m.tokens = storage.current_content_tokens
+            if msg.layout_tree and storage.current_layout_tree is not None:
+                m.layout_tree = _to_json(storage.current_layout_tree)
+            if msg.layout_trace:
+                m.layout_trace = storage.current_layout_trace
 
         return m
 
+    def _to_json(value: Any) -> str:
+        """Serialize the layout tree, there is no JSON module in the firmware."""
+        if value is None:
+            return "null"
+        if value is True:
+            return "true"
+        if value is False:
+            return "false"
+        if isinstance(value, int):
+            return str(value)
+        if isinstance(value, str):
+            escaped = value.replace("\\", "\\\\").replace('"', '\\"')
+            return '"' + escaped.replace("\n", "\\n") + '"'
+        if isinstance(value, dict):
+            items = (_to_json(k) + ":" + _to_json(v) for k, v in value.items())
+            return "{" + ",".join(items) + "}"
+        return "[" + ",".join(_to_json(v) for v in value) + "]"
+
     async def dispatch_DebugLinkRecordScreen(msg: DebugLinkRecordScreen) -> Success:
         if msg.target_directory:
             # In case emulator is restarted but we still want to record screenshots
@@ -231,6 +258,14 @@ if __debug__:
             from trezor.crypto import random
 
             random.reseed(msg.value)
+            # Keyboard shuffling and other UI randomness, for reproducible
+            # screenshots.
+            trezorui2.set_replay_seed(msg.value)
+        return Success()
+
+    async def dispatch_DebugLinkShowProfiler(msg: DebugLinkShowProfiler) -> Success:
+        # Shown from the next paint on.
+        trezorui2.enable_profiler(bool(msg.show))
         return Success()
 
     async def dispatch_DebugLinkEraseSdCard(msg: DebugLinkEraseSdCard) -> Success:
@@ -267,6 +302,7 @@ if __debug__:
         register(
             MessageType.DebugLinkResetDebugEvents, dispatch_DebugLinkResetDebugEvents
         )
+        register(MessageType.DebugLinkShowProfiler, dispatch_DebugLinkShowProfiler)
 
         loop.schedule(debuglink_decision_dispatcher())
         if storage.layout_watcher is not LAYOUT_WATCHER_NONE:
@@ -10,6 +10,8 @@ from trezor.ui.layouts import (
     should_show_more,
 )
 
+from apps.common import address_poisoning
+
 from .helpers import address_from_bytes, decode_typed_data
 
 if TYPE_CHECKING:
@@ -34,6 +36,7 @@ async def require_confirm_tx(
 ) -> None:
     if to_bytes:
         to_str = address_from_bytes(to_bytes, network)
+        await address_poisoning.check_address(to_str)
     else:
         to_str = "new contract?"
         chunkify = False
@@ -53,6 +56,8 @@ async def require_confirm_tx(
     await confirm_ethereum_tx(
         to_str, total_amount, maximum_fee, items, chunkify=chunkify
     )
+    if to_bytes:
+        address_poisoning.remember(to_str)
 
 
 async def require_confirm_tx_eip1559(
@@ -67,6 +72,7 @@ async def require_confirm_tx_eip1559(
 ) -> None:
     if to_bytes:
         to_str = address_from_bytes(to_bytes, network)
+        await address_poisoning.check_address(to_str)
     else:
         to_str = "new contract?"
         chunkify = False
@@ -90,6 +96,8 @@ async def require_confirm_tx_eip1559(
     await confirm_ethereum_tx(
         to_str, total_amount, maximum_fee, items, chunkify=chunkify
     )
+    if to_bytes:
+        address_poisoning.remember(to_str)
 
 
 def require_confirm_unknown_token(address_bytes: bytes) -> Awaitable[None]:
@@ -33,6 +33,7 @@ async def sign_message(
     from trezor.ui.layouts import confirm_signverify
 
     from apps.common import paths
+    from apps.common.signing_receipt import show_signing_receipt
     from apps.common.signverify import decode_message
 
     from .helpers import address_from_bytes
@@ -46,12 +47,14 @@ async def sign_message(
         decode_message(msg.message), address, account="ETH", path=path, verify=False
     )
 
+    digest = message_digest(msg.message)
     signature = secp256k1.sign(
         node.private_key(),
-        message_digest(msg.message),
+        digest,
         False,
         secp256k1.CANONICAL_SIG_ETHEREUM,
     )
+    await show_signing_receipt("Message signed", "Message digest:", digest)
 
     return EthereumMessageSignature(
         address=address,
@@ -3,7 +3,8 @@ from typing import Coroutine
 import storage
 import storage.cache
 import storage.device
-from trezor import config, wire
+import trezorui2
+from trezor import config, wire, workflow
 from trezor.enums import MessageType
 from trezor.ui.layouts.homescreen import Busyscreen, Homescreen, Lockscreen
 
@@ -38,12 +39,18 @@ async def homescreen() -> None:
     elif storage.device.get_experimental_features():
         notification = "EXPERIMENTAL MODE"
 
-    await Homescreen(
+    result = await Homescreen(
         label=label,
         notification=notification,
         notification_is_error=notification_is_error,
         hold_to_lock=config.has_pin(),
     )
+    if result is trezorui2.INFO:
+        from apps.management.settings_menu import settings_menu
+
+        # Started as a workflow of its own, the homescreen comes back after it.
+        workflow.spawn(settings_menu())
+        return
     lock_device()
 
 
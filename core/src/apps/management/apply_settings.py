@@ -6,7 +6,7 @@ from trezor.ui.layouts import confirm_action
 from trezor.wire import DataError
 
 if TYPE_CHECKING:
-    from trezor.enums import SafetyCheckLevel
+    from trezor.enums import KeyboardLayout, SafetyCheckLevel, ScreenDensity
     from trezor.messages import ApplySettings, Success
 
 
@@ -33,7 +33,7 @@ async def apply_settings(msg: ApplySettings) -> Success:
     from trezor.wire import NotInitialized, ProcessError
 
     from apps.base import reload_settings_from_storage
-    from apps.common import safety_checks
+    from apps.common import safety_checks, spending_policy
 
     if not storage_device.is_initialized():
         raise NotInitialized("Device is not initialized")
@@ -48,6 +48,17 @@ async def apply_settings(msg: ApplySettings) -> Success:
     msg_safety_checks = msg.safety_checks  # local_cache_attribute
     experimental_features = msg.experimental_features  # local_cache_attribute
     hide_passphrase_from_host = msg.hide_passphrase_from_host  # local_cache_attribute
+    remember_destinations = msg.remember_destinations  # local_cache_attribute
+    spending_threshold = msg.spending_threshold  # local_cache_attribute
+    keyboard_layout = msg.keyboard_layout  # local_cache_attribute
+    large_text = msg.large_text  # local_cache_attribute
+    audio_feedback = msg.audio_feedback  # local_cache_attribute
+    signing_receipt = msg.signing_receipt  # local_cache_attribute
+    density = msg.density  # local_cache_attribute
+    longer_timeouts = msg.longer_timeouts  # local_cache_attribute
+    high_contrast = msg.high_contrast  # local_cache_attribute
+    reduce_motion = msg.reduce_motion  # local_cache_attribute
+    theme_inverted = msg.theme_inverted  # local_cache_attribute
 
     if (
         homescreen is None
@@ -59,6 +70,17 @@ async def apply_settings(msg: ApplySettings) -> Success:
         and msg_safety_checks is None
         and experimental_features is None
         and hide_passphrase_from_host is None
+        and remember_destinations is None
+        and spending_threshold is None
+        and keyboard_layout is None
+        and large_text is None
+        and audio_feedback is None
+        and signing_receipt is None
+        and density is None
+        and longer_timeouts is None
+        and high_contrast is None
+        and reduce_motion is None
+        and theme_inverted is None
     ):
         raise ProcessError("No setting provided")
 
@@ -112,6 +134,51 @@ async def apply_settings(msg: ApplySettings) -> Success:
         await _require_confirm_hide_passphrase_from_host(hide_passphrase_from_host)
         storage_device.set_hide_passphrase_from_host(hide_passphrase_from_host)
 
+    if remember_destinations is not None:
+        await _require_confirm_remember_destinations(remember_destinations)
+        storage_device.set_remember_destinations(remember_destinations)
+
+    if spending_threshold is not None:
+        temporary = bool(msg.spending_threshold_temporary)
+        await _require_confirm_spending_threshold(spending_threshold, temporary)
+        spending_policy.apply_setting(spending_threshold or None, temporary)
+
+    if keyboard_layout is not None:
+        await _require_confirm_keyboard_layout(keyboard_layout)
+        storage_device.set_keyboard_layout(keyboard_layout)
+
+    if large_text is not None:
+        await _require_confirm_large_text(large_text)
+        storage_device.set_large_text(large_text)
+
+    if audio_feedback is not None:
+        await _require_confirm_audio_feedback(audio_feedback)
+        storage_device.set_audio_feedback(audio_feedback)
+
+    if signing_receipt is not None:
+        await _require_confirm_signing_receipt(signing_receipt)
+        storage_device.set_signing_receipt(signing_receipt)
+
+    if density is not None:
+        await _require_confirm_density(density)
+        storage_device.set_density(density)
+
+    if longer_timeouts is not None:
+        await _require_confirm_longer_timeouts(longer_timeouts)
+        storage_device.set_longer_timeouts(longer_timeouts)
+
+    if high_contrast is not None:
+        await _require_confirm_high_contrast(high_contrast)
+        storage_device.set_high_contrast(high_contrast)
+
+    if reduce_motion is not None:
+        await _require_confirm_reduce_motion(reduce_motion)
+        storage_device.set_reduce_motion(reduce_motion)
+
+    if theme_inverted is not None:
+        await _require_confirm_theme_inverted(theme_inverted)
+        storage_device.set_theme_inverted(theme_inverted)
+
     reload_settings_from_storage()
 
     return Success(message="Settings applied")
@@ -186,14 +253,23 @@ async def _require_confirm_change_display_rotation(rotation: int) -> None:
 
 
 async def _require_confirm_change_autolock_delay(delay_ms: int) -> None:
+    import storage.device as storage_device
     from trezor.strings import format_duration_ms
+    from trezor.ui.layouts import confirm_settings_change
 
-    await confirm_action(
+    old_delay_ms = storage_device.get_autolock_delay_ms()
+    await confirm_settings_change(
         "set_autolock_delay",
         "Auto-lock delay",
-        description="Auto-lock your Trezor after {} of inactivity?",
-        description_param=format_duration_ms(delay_ms),
-        br_code=BRT_PROTECT_CALL,
+        [
+            (
+                "Auto-lock after",
+                format_duration_ms(old_delay_ms),
+                format_duration_ms(delay_ms),
+                delay_ms > old_delay_ms,
+            )
+        ],
+        BRT_PROTECT_CALL,
     )
 
 
@@ -250,3 +326,157 @@ async def _require_confirm_hide_passphrase_from_host(enable: bool) -> None:
             description="Hide passphrase coming from host?",
             br_code=BRT_PROTECT_CALL,
         )
+
+
+async def _require_confirm_remember_destinations(enable: bool) -> None:
+    description = (
+        "Remember the addresses you send to?"
+        if enable
+        else "Forget the remembered addresses and stop remembering them?"
+    )
+    await confirm_action(
+        "set_remember_destinations",
+        "Recent addresses",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_spending_threshold(threshold: int, temporary: bool) -> None:
+    from trezor.strings import format_amount
+
+    if threshold:
+        amount = format_amount(threshold, 8)
+        description = f"Ask for the PIN again when sending more than {amount} BTC?"
+    else:
+        description = "Stop asking for the PIN again for large amounts?"
+    if temporary:
+        description += " Until the device restarts."
+    await confirm_action(
+        "set_spending_threshold",
+        "Spending limit",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_keyboard_layout(layout: KeyboardLayout) -> None:
+    # indexed by the values of KeyboardLayout
+    layout_names = ("ABC", "QWERTY", "AZERTY", "QWERTZ")
+    await confirm_action(
+        "set_keyboard_layout",
+        "Keyboard layout",
+        description="Change keyboard layout to {}?",
+        description_param=layout_names[layout],
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_large_text(enable: bool) -> None:
+    description = (
+        "Show amounts and addresses in a larger font?"
+        if enable
+        else "Show amounts and addresses in the normal font?"
+    )
+    await confirm_action(
+        "set_large_text",
+        "Large text",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_audio_feedback(enable: bool) -> None:
+    description = (
+        "Play a sound at the end of each action?"
+        if enable
+        else "Stop playing sounds at the end of actions?"
+    )
+    await confirm_action(
+        "set_audio_feedback",
+        "Audio feedback",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_signing_receipt(enable: bool) -> None:
+    description = (
+        "Show a fingerprint of each signed message?"
+        if enable
+        else "Stop showing fingerprints of signed messages?"
+    )
+    await confirm_action(
+        "set_signing_receipt",
+        "Signing receipt",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_density(density: ScreenDensity) -> None:
+    # indexed by the values of ScreenDensity
+    density_names = ("comfortable", "compact")
+    await confirm_action(
+        "set_density",
+        "Screen density",
+        description="Change the spacing of the screens to {}?",
+        description_param=density_names[density],
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_longer_timeouts(enable: bool) -> None:
+    description = (
+        "Give more time to hold buttons and read messages?"
+        if enable
+        else "Use the usual time to hold buttons and read messages?"
+    )
+    await confirm_action(
+        "set_longer_timeouts",
+        "Longer timeouts",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_high_contrast(enable: bool) -> None:
+    description = (
+        "Paint all the text in full contrast?"
+        if enable
+        else "Paint secondary text dimmed again?"
+    )
+    await confirm_action(
+        "set_high_contrast",
+        "High contrast",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_reduce_motion(enable: bool) -> None:
+    description = (
+        "Show screens without sliding and transitions?"
+        if enable
+        else "Slide items and screens again?"
+    )
+    await confirm_action(
+        "set_reduce_motion",
+        "Reduce motion",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_theme_inverted(enable: bool) -> None:
+    description = (
+        "Paint the screens dark on light?"
+        if enable
+        else "Paint the screens light on dark?"
+    )
+    await confirm_action(
+        "set_theme_inverted",
+        "Inverted colors",
+        description=description,
+        br_code=BRT_PROTECT_CALL,
+    )
@@ -0,0 +1,17 @@
+async def change_label() -> None:
+    """
+    Renames the device on the device itself, without any host software. Not a wire
+    workflow, it is started from the settings menu.
+    """
+    import storage.device as storage_device
+    from trezor.ui.layouts import request_device_label
+
+    from apps.base import reload_settings_from_storage
+
+    if not storage_device.is_initialized():
+        return
+    label = await request_device_label(
+        storage_device.get_label() or "", storage_device.LABEL_MAXLENGTH
+    )
+    storage_device.set_label(label)
+    reload_settings_from_storage()
@@ -0,0 +1,61 @@
+from micropython import const
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from trezor.messages import ChangeLanguage, Success
+
+_CHUNK_SIZE = const(1024)
+
+
+async def change_language(msg: ChangeLanguage) -> Success:
+    import trezorui2
+    from trezor.messages import Success, TranslationDataAck, TranslationDataRequest
+    from trezor.translations import (
+        blob_hasher,
+        change_language,
+        install_language,
+        verify_signature,
+    )
+    from trezor.wire import DataError
+    from trezor.wire.context import call
+
+    data_length = msg.data_length  # local_cache_attribute
+
+    # The blob is written to the flash as it comes, it does not fit in RAM.
+    if data_length == 0 or data_length > trezorui2.translations_max_length():
+        raise DataError("Invalid translations length.")
+    if msg.signature is None and not __debug__:
+        raise DataError("Missing translations signature.")
+    if msg.signature is not None and msg.sigmask is None:
+        raise DataError("Missing signature mask.")
+
+    change_language(False)
+    hasher = blob_hasher(msg.version)
+    offset = 0
+    try:
+        while offset < data_length:
+            length = min(_CHUNK_SIZE, data_length - offset)
+            ack = await call(
+                TranslationDataRequest(data_length=length, data_offset=offset),
+                TranslationDataAck,
+            )
+            if len(ack.data_chunk) != length:
+                raise DataError("Invalid translations chunk.")
+            trezorui2.write_translations(ack.data_chunk, offset)
+            hasher.update(ack.data_chunk)
+            offset += length
+        signed = verify_signature(hasher.digest(), msg.signature, msg.sigmask or 0)
+        if not signed and not __debug__:
+            raise DataError("Invalid translations signature.")
+        trezorui2.commit_translations(data_length)
+    except BaseException:
+        # Nothing half-written is left behind.
+        change_language(False)
+        raise
+
+    try:
+        await install_language(msg.version, signed)
+    except ValueError as e:
+        # the blob is invalid
+        raise DataError(str(e))
+    return Success(message="Language changed")
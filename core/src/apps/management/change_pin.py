@@ -63,18 +63,21 @@ async def change_pin(msg: ChangePin) -> Success:
 
 
 def _require_confirm_change_pin(msg: ChangePin) -> Awaitable[None]:
-    from trezor.ui.layouts import confirm_action, confirm_set_new_pin
+    from trezor.ui.layouts import (
+        confirm_action,
+        confirm_set_new_pin,
+        confirm_settings_change,
+    )
 
     has_pin = config.has_pin()
 
     title = "PIN settings"
 
     if msg.remove and has_pin:  # removing pin
-        return confirm_action(
+        return confirm_settings_change(
             "disable_pin",
             title,
-            description="Are you sure you want to turn off PIN protection?",
-            verb="Turn off",
+            [("PIN protection", "On", "Off", True)],
         )
 
     if not msg.remove and has_pin:  # changing pin
@@ -91,6 +91,8 @@ def _require_confirm_action(
 
 
 async def _request_wipe_code_confirm(pin: str) -> str:
+    from trezorui2 import secrets_equal
+
     from trezor.ui.layouts import (
         confirm_reenter_pin,
         pin_mismatch_popup,
@@ -101,11 +103,11 @@ async def _request_wipe_code_confirm(pin: str) -> str:
 
     while True:
         code1 = await request_pin("Enter new wipe code")
-        if code1 == pin:
+        if secrets_equal(code1, pin):
             await wipe_code_same_as_pin_popup()
             continue
         await confirm_reenter_pin(is_wipe_code=True)
         code2 = await request_pin("Re-enter wipe code")
-        if code1 == code2:
+        if secrets_equal(code1, code2):
             return code1
         await pin_mismatch_popup(is_wipe_code=True)
@@ -113,9 +113,11 @@ async def _finish_recovery_dry_run(secret: bytes, backup_type: BackupType) -> Su
     digest_input = sha256(secret).digest()
     stored = mnemonic.get_secret()
     digest_stored = sha256(stored).digest()
-    result = utils.consteq(digest_stored, digest_input)
+    seed_matches = utils.consteq(digest_stored, digest_input)
+    result = seed_matches
 
     is_slip39 = backup_types.is_slip39_backup_type(backup_type)
+    groups_completed = None
     # Check that the identifier and iteration exponent match as well
     if is_slip39:
         result &= (
@@ -126,10 +128,13 @@ async def _finish_recovery_dry_run(secret: bytes, backup_type: BackupType) -> Su
             storage_device.get_slip39_iteration_exponent()
             == storage_recovery.get_slip39_iteration_exponent()
         )
+        if storage_recovery.get_slip39_group_count() > 1:
+            remaining = storage_recovery.fetch_slip39_remaining_shares() or []
+            groups_completed = (remaining.count(0), len(remaining))
 
     storage_recovery.end_progress()
 
-    await layout.show_dry_run_result(result, is_slip39)
+    await layout.show_dry_run_result(result, is_slip39, seed_matches, groups_completed)
 
     if result:
         return Success(message="The seed is valid and matches the one in the device")
@@ -217,6 +222,7 @@ async def _request_share_next_screen() -> None:
             "Enter",
             "More shares needed",
             info_func=_show_remaining_groups_and_shares,
+            share_groups=_share_groups_progress(remaining),
         )
     else:
         still_needed_shares = remaining[0]
@@ -231,6 +237,23 @@ async def _request_share_next_screen() -> None:
         await layout.homescreen_dialog("Enter share", entered, needed)
 
 
+def _share_groups_progress(remaining: list[int]) -> list[tuple[int, int]]:
+    """
+    Collected and required share counts of each group, (0, 0) for groups
+    without any share entered yet.
+    """
+    from trezor.crypto import slip39
+
+    groups = []
+    for index, shares_remaining in enumerate(remaining):
+        if shares_remaining == slip39.MAX_SHARE_COUNT:
+            groups.append((0, 0))
+        else:
+            collected = len(storage_recovery_shares.fetch_group(index))
+            groups.append((collected, collected + shares_remaining))
+    return groups
+
+
 async def _show_remaining_groups_and_shares() -> None:
     """
     Show info dialog for Slip39 Advanced - what shares are to be entered.
@@ -84,8 +84,13 @@ async def request_mnemonic(
     return " ".join(words)
 
 
-async def show_dry_run_result(result: bool, is_slip39: bool) -> None:
-    from trezor.ui.layouts import show_success
+async def show_dry_run_result(
+    result: bool,
+    is_slip39: bool,
+    seed_matches: bool,
+    groups_completed: tuple[int, int] | None = None,
+) -> None:
+    from trezor.ui.layouts.recovery import show_dry_run_result
 
     if result:
         if is_slip39:
@@ -94,13 +99,26 @@ async def show_dry_run_result(result: bool, is_slip39: bool) -> None:
             text = (
                 "The entered recovery seed is valid and matches the one in the device."
             )
-        await show_success("success_dry_recovery", text, button="Continue")
     else:
         if is_slip39:
             text = "The entered recovery shares are valid but do not match what is currently in the device."
         else:
             text = "The entered recovery seed is valid but does not match the one in the device."
-        await show_recovery_warning("warning_dry_recovery", "", text, button="Continue")
+
+    subject = "The shares lead" if is_slip39 else "The seed leads"
+    if seed_matches:
+        seed_check = f"{subject} to the wallet in the device."
+    else:
+        seed_check = f"{subject} to a different wallet."
+    checks: list[tuple[bool | None, str]] = [(seed_matches, seed_check)]
+    if is_slip39 and seed_matches and not result:
+        checks.append((False, "The shares are of a different backup of this seed."))
+    if groups_completed is not None:
+        done, count = groups_completed
+        checks.append((None, f"Groups completed: {done} of {count}."))
+    checks.append((None, "Passphrase wallets are not part of the check."))
+
+    await show_dry_run_result(result, text, checks)
 
 
 async def show_invalid_mnemonic(word_count: int) -> None:
@@ -124,6 +142,7 @@ async def homescreen_dialog(
     subtext: str | None = None,
     info_func: Callable | None = None,
     show_info: bool = False,
+    share_groups: list[tuple[int, int]] | None = None,
 ) -> None:
     import storage.recovery as storage_recovery
     from trezor.ui.layouts.recovery import continue_recovery
@@ -134,7 +153,7 @@ async def homescreen_dialog(
     while True:
         dry_run = storage_recovery.is_dry_run()
         if await continue_recovery(
-            button_label, text, subtext, info_func, dry_run, show_info
+            button_label, text, subtext, info_func, dry_run, show_info, share_groups
         ):
             # go forward in the recovery process
             break
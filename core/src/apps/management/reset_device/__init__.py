@@ -57,6 +57,8 @@ async def reset_device(msg: ResetDevice) -> Success:
 
     # generate and display internal entropy
     int_entropy = random.bytes(32, True)
+    if msg.mix_entropy:
+        int_entropy = await _mix_entropy(int_entropy)
     if __debug__:
         storage.debug.reset_internal_entropy = int_entropy
     if msg.display_random:
@@ -197,6 +199,20 @@ def _validate_reset_device(msg: ResetDevice) -> None:
         raise UnexpectedMessage("Already initialized")
 
 
+async def _mix_entropy(int_entropy: bytes) -> bytes:
+    """Let the user add the timing of their presses to `int_entropy` while a pattern
+    derived from it is stirred on the screen. Without any presses it is returned
+    unchanged."""
+    from trezor.crypto.hashlib import sha256
+    from trezor.ui.layouts.reset import show_entropy_mixing
+
+    seed = sha256(b"entropy pattern" + int_entropy).digest()
+    user_entropy = await show_entropy_mixing(seed)
+    if not user_entropy:
+        return int_entropy
+    return sha256(int_entropy + user_entropy).digest()
+
+
 def _compute_secret_from_entropy(
     int_entropy: bytes, ext_entropy: bytes, strength_in_bytes: int
 ) -> bytes:
@@ -26,15 +26,8 @@ async def show_internal_entropy(entropy: bytes) -> None:
     )
 
 
-async def _confirm_word(
-    share_index: int | None,
-    share_words: Sequence[str],
-    offset: int,
-    count: int,
-    group_index: int | None = None,
-) -> bool:
+def _word_check(share_words: Sequence[str], offset: int) -> tuple[list[str], str, int]:
     from trezor.crypto import random
-    from trezor.ui.layouts.reset import select_word
 
     # remove duplicates
     non_duplicates = list(set(share_words))
@@ -48,12 +41,7 @@ async def _confirm_word(
     checked_index = share_words.index(checked_word) + offset
     # shuffle again so the confirmed word is not always the first choice
     random.shuffle(choices)
-    # let the user pick a word
-    selected_word: str = await select_word(
-        choices, share_index, checked_index, count, group_index
-    )
-    # confirm it is the correct one
-    return selected_word == checked_word
+    return choices, checked_word, checked_index
 
 
 async def _share_words_confirmed(
@@ -89,19 +77,19 @@ async def _do_confirm_share_words(
     group_index: int | None = None,
 ) -> bool:
     from trezor import utils
+    from trezor.ui.layouts.reset import check_share_words
 
     # divide list into thirds, rounding up, so that chunking by `third` always yields
     # three parts (the last one might be shorter)
     third = (len(share_words) + 2) // 3
 
+    checks = []
     offset = 0
-    count = len(share_words)
     for part in utils.chunks(share_words, third):
-        if not await _confirm_word(share_index, part, offset, count, group_index):
-            return False
+        checks.append(_word_check(part, offset))
         offset += len(part)
 
-    return True
+    return await check_share_words(checks, share_index, len(share_words), group_index)
 
 
 async def _show_confirmation_success(
@@ -0,0 +1,55 @@
+async def settings_menu() -> None:
+    """
+    Adjusts the device settings on the device itself, without any host software. Not
+    a wire workflow, it is started from the homescreen.
+    """
+    import storage.device as storage_device
+    from trezor import utils
+    from trezor.ui.layouts import show_settings_menu
+
+    from apps.base import reload_settings_from_storage
+    from apps.common import safety_checks, spending_policy
+
+    if not storage_device.is_initialized():
+        return
+
+    settings = [("auto_lock", storage_device.get_autolock_delay_ms())]
+    if utils.UI_LAYOUT == "TT":
+        settings.append(("rotation", storage_device.get_rotation()))
+    # Menu values are 32-bit, larger thresholds show as the largest option.
+    threshold = min(storage_device.get_spending_threshold() or 0, 0xFFFF_FFFF)
+    settings += [
+        ("safety_checks", safety_checks.read_setting()),
+        ("large_text", int(storage_device.get_large_text())),
+        ("remember_destinations", int(storage_device.get_remember_destinations())),
+        ("spending_threshold", threshold),
+        ("signing_receipt", int(storage_device.get_signing_receipt())),
+    ]
+
+    changed, rename = await show_settings_menu(settings)
+    for name, value in changed:
+        if name == "auto_lock":
+            storage_device.set_autolock_delay_ms(value)
+        elif name == "rotation":
+            storage_device.set_rotation(value)
+        elif name == "safety_checks":
+            safety_checks.apply_setting(value)  # type: ignore [Argument of type "int" cannot be assigned to parameter "level" of type "SafetyCheckLevel"]
+        elif name == "large_text":
+            storage_device.set_large_text(bool(value))
+        elif name == "remember_destinations":
+            storage_device.set_remember_destinations(bool(value))
+        elif name == "spending_threshold":
+            spending_policy.apply_setting(value or None)
+        elif name == "signing_receipt":
+            storage_device.set_signing_receipt(bool(value))
+    reload_settings_from_storage()
+
+    if rename:
+        from trezor.wire import ActionCancelled
+
+        from apps.management.change_label import change_label
+
+        try:
+            await change_label()
+        except ActionCancelled:
+            pass
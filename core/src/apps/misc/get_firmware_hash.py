@@ -15,7 +15,7 @@ async def get_firmware_hash(msg: GetFirmwareHash) -> FirmwareHash:
 
     workflow.close_others()
     global _progress_obj
-    _progress_obj = progress()
+    _progress_obj = progress(show_eta=True)
 
     try:
         hash = firmware_hash(msg.challenge, _render_progress)
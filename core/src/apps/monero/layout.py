@@ -165,6 +165,7 @@ async def _require_confirm_output(
         _format_amount(dst.amount),
         br_code=BRT_SignTx,
         chunkify=chunkify,
+        coin="XMR",
     )
 
 
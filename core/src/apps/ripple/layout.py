@@ -9,6 +9,7 @@ async def require_confirm_total(total: int, fee: int) -> None:
     await confirm_total(
         format_amount(total, DECIMALS) + " XRP",
         format_amount(fee, DECIMALS) + " XRP",
+        coin="XRP",
     )
 
 
@@ -25,4 +26,6 @@ async def require_confirm_destination_tag(tag: int) -> None:
 async def require_confirm_tx(to: str, value: int, chunkify: bool = False) -> None:
     from trezor.ui.layouts import confirm_output
 
-    await confirm_output(to, format_amount(value, DECIMALS) + " XRP", chunkify=chunkify)
+    await confirm_output(
+        to, format_amount(value, DECIMALS) + " XRP", chunkify=chunkify, coin="XRP"
+    )
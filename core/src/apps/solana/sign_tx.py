@@ -21,7 +21,7 @@ async def sign_tx(
     from trezor.crypto.curve import ed25519
     from trezor.enums import ButtonRequestType
     from trezor.messages import SolanaTxSignature
-    from trezor.ui.layouts import confirm_metadata, show_warning
+    from trezor.ui.layouts import confirm_blind_signing, confirm_metadata
 
     from apps.common import seed
 
@@ -40,7 +40,7 @@ async def sign_tx(
         raise DataError("Invalid transaction")
 
     if transaction.blind_signing:
-        await show_warning(
+        await confirm_blind_signing(
             "warning_blind_signing", "Transaction contains unknown instructions."
         )
 
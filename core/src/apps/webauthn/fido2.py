@@ -584,7 +584,9 @@ async def verify_user(keepalive_callback: KeepaliveCallback) -> bool:
     return ret
 
 
-def _confirm_fido_choose(title: str, credentials: list[Credential]) -> Awaitable[int]:
+def _confirm_fido_choose(
+    title: str, credentials: list[Credential], time_ms: int = 0
+) -> Awaitable[int]:
     from trezor.ui.layouts.fido import confirm_fido
 
     from . import knownapps
@@ -600,13 +602,13 @@ def _confirm_fido_choose(title: str, credentials: list[Credential]) -> Awaitable
     app = knownapps.by_rp_id_hash(repr_credential.rp_id_hash)
     icon_name = None if app is None else app.icon_name
     return confirm_fido(
-        title, app_name, icon_name, [c.account_name() for c in credentials]
+        title, app_name, icon_name, [c.account_name() for c in credentials], time_ms
     )
 
 
-async def _confirm_fido(title: str, credential: Credential) -> bool:
+async def _confirm_fido(title: str, credential: Credential, time_ms: int = 0) -> bool:
     try:
-        await _confirm_fido_choose(title, [credential])
+        await _confirm_fido_choose(title, [credential], time_ms)
         return True
     except wire.ActionCancelled:
         return False
@@ -803,7 +805,9 @@ class Fido2ConfirmMakeCredential(Fido2State):
         if self._cred.rp_id == _BOGUS_RP_ID:
             await _confirm_bogus_app("FIDO2")
             return True
-        if not await _confirm_fido("FIDO2 Register", self._cred):
+        if not await _confirm_fido(
+            "FIDO2 Register", self._cred, _FIDO2_CONFIRM_TIMEOUT_MS
+        ):
             return False
         if self._user_verification:
             return await verify_user(KeepaliveCallback(self.cid, self.iface))
@@ -869,7 +873,9 @@ class Fido2ConfirmGetAssertion(Fido2State):
     async def confirm_dialog(self) -> bool:
         # There is a choice from more than one credential.
         try:
-            index = await _confirm_fido_choose("FIDO2 Authenticate", self._creds)
+            index = await _confirm_fido_choose(
+                "FIDO2 Authenticate", self._creds, _FIDO2_CONFIRM_TIMEOUT_MS
+            )
         except wire.ActionCancelled:
             return False
 
@@ -52,6 +52,8 @@ def _find_message_handler_module(msg_type: int) -> str:
         return "apps.management.get_nonce"
     if msg_type == MessageType.RebootToBootloader:
         return "apps.management.reboot_to_bootloader"
+    if msg_type == MessageType.ChangeLanguage:
+        return "apps.management.change_language"
 
     if utils.INTERNAL_MODEL in ("T2B1",) and msg_type == MessageType.ShowDeviceTutorial:
         return "apps.management.show_tutorial"
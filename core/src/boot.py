@@ -10,7 +10,7 @@ welcome_screen_start_ms = utime.ticks_ms()
 
 import storage
 import storage.device
-from trezor import config, log, loop, ui, utils, wire
+from trezor import config, log, loop, translations, ui, utils, wire
 from trezor.pin import (
     allow_all_loader_messages,
     ignore_nonpin_loader_messages,
@@ -78,6 +78,9 @@ async def bootscreen() -> None:
             utils.halt(e.__class__.__name__)
 
 
+# Show everything from the lockscreen on in the installed language.
+translations.init()
+
 # Ignoring all non-PIN messages in the boot-phase (turned off in `bootscreen()`).
 ignore_nonpin_loader_messages()
 
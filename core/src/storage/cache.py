@@ -20,11 +20,12 @@ APP_COMMON_SEED = const(0)
 APP_COMMON_AUTHORIZATION_TYPE = const(1)
 APP_COMMON_AUTHORIZATION_DATA = const(2)
 APP_COMMON_NONCE = const(3)
+APP_COMMON_HIDDEN_WALLET = const(4)
 if not utils.BITCOIN_ONLY:
-    APP_COMMON_DERIVE_CARDANO = const(4)
-    APP_CARDANO_ICARUS_SECRET = const(5)
-    APP_CARDANO_ICARUS_TREZOR_SECRET = const(6)
-    APP_MONERO_LIVE_REFRESH = const(7)
+    APP_COMMON_DERIVE_CARDANO = const(5)
+    APP_CARDANO_ICARUS_SECRET = const(6)
+    APP_CARDANO_ICARUS_TREZOR_SECRET = const(7)
+    APP_MONERO_LIVE_REFRESH = const(8)
 
 # Keys that are valid across sessions
 APP_COMMON_SEED_WITHOUT_PASSPHRASE = const(0 | _SESSIONLESS_FLAG)
@@ -34,6 +35,8 @@ APP_COMMON_REQUEST_PIN_LAST_UNLOCK = const(3 | _SESSIONLESS_FLAG)
 APP_COMMON_BUSY_DEADLINE_MS = const(4 | _SESSIONLESS_FLAG)
 APP_MISC_COSI_NONCE = const(5 | _SESSIONLESS_FLAG)
 APP_MISC_COSI_COMMITMENT = const(6 | _SESSIONLESS_FLAG)
+APP_COMMON_RECENT_ADDRESSES = const(7 | _SESSIONLESS_FLAG)
+APP_COMMON_SPENDING_THRESHOLD_TEMPORARY = const(8 | _SESSIONLESS_FLAG)
 
 
 # === Homescreen storage ===
@@ -106,6 +109,7 @@ class SessionCache(DataCache):
                 2,  # APP_COMMON_AUTHORIZATION_TYPE
                 128,  # APP_COMMON_AUTHORIZATION_DATA
                 32,  # APP_COMMON_NONCE
+                1,  # APP_COMMON_HIDDEN_WALLET
             )
         else:
             self.fields = (
@@ -113,6 +117,7 @@ class SessionCache(DataCache):
                 2,  # APP_COMMON_AUTHORIZATION_TYPE
                 128,  # APP_COMMON_AUTHORIZATION_DATA
                 32,  # APP_COMMON_NONCE
+                1,  # APP_COMMON_HIDDEN_WALLET
                 1,  # APP_COMMON_DERIVE_CARDANO
                 96,  # APP_CARDANO_ICARUS_SECRET
                 96,  # APP_CARDANO_ICARUS_TREZOR_SECRET
@@ -146,6 +151,8 @@ class SessionlessCache(DataCache):
             8,  # APP_COMMON_BUSY_DEADLINE_MS
             32,  # APP_MISC_COSI_NONCE
             32,  # APP_MISC_COSI_COMMITMENT
+            128,  # APP_COMMON_RECENT_ADDRESSES
+            8,  # APP_COMMON_SPENDING_THRESHOLD_TEMPORARY
         )
         super().__init__()
 
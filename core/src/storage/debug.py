@@ -9,6 +9,10 @@ if __debug__:
 
     current_content_tokens: list[str] = [""] * 60
     current_content_tokens.clear()
+    # Components of the current layout, see Layout.debug_tree.
+    current_layout_tree: dict | None = None
+    # The same in the binary trace encoding, see Layout.read_trace_binary.
+    current_layout_trace: bytes | None = None
 
     watch_layout_changes = False
     layout_watcher = 0
@@ -5,7 +5,7 @@ import storage.cache as storage_cache
 from storage import common
 
 if TYPE_CHECKING:
-    from trezor.enums import BackupType
+    from trezor.enums import BackupType, KeyboardLayout, ScreenDensity
     from typing_extensions import Literal
 
 # Namespace:
@@ -35,6 +35,19 @@ INITIALIZED                = const(0x13)  # bool (0x01 or empty)
 _SAFETY_CHECK_LEVEL        = const(0x14)  # int
 _EXPERIMENTAL_FEATURES     = const(0x15)  # bool (0x01 or empty)
 _HIDE_PASSPHRASE_FROM_HOST = const(0x16)  # bool (0x01 or empty)
+_KEYBOARD_LAYOUT           = const(0x17)  # int
+_LARGE_TEXT                = const(0x18)  # bool (0x01 or empty)
+_AUDIO_FEEDBACK            = const(0x19)  # bool (0x01 or empty)
+_REMEMBER_DESTINATIONS     = const(0x1A)  # bool (0x01 or empty)
+_RECENT_DESTINATIONS       = const(0x1B)  # bytes
+_SPENDING_THRESHOLD        = const(0x1C)  # int
+_SIGNING_RECEIPT           = const(0x1D)  # bool (0x01 or empty)
+_COMPACT_DENSITY           = const(0x1E)  # bool (0x01 or empty)
+# 0x1F holds the resume point, kept by trezorui2.save_resume_point.
+_LONGER_TIMEOUTS           = const(0x20)  # bool (0x01 or empty)
+_HIGH_CONTRAST             = const(0x21)  # bool (0x01 or empty)
+_REDUCE_MOTION             = const(0x22)  # bool (0x01 or empty)
+_THEME_INVERTED            = const(0x23)  # bool (0x01 or empty)
 
 SAFETY_CHECK_LEVEL_STRICT  : Literal[0] = const(0)
 SAFETY_CHECK_LEVEL_PROMPT  : Literal[1] = const(1)
@@ -58,7 +71,6 @@ AUTOLOCK_DELAY_MAXIMUM = const(0x2000_0000)  # ~6 days
 # Other SD-salt-related constants are in sd_salt.py
 SD_SALT_AUTH_KEY_LEN_BYTES = const(16)
 
-
 def is_version_stored() -> bool:
     return bool(common.get(_NAMESPACE, _VERSION))
 
@@ -344,3 +356,153 @@ def get_hide_passphrase_from_host() -> bool:
     Whether we should hide the passphrase from the host.
     """
     return common.get_bool(_NAMESPACE, _HIDE_PASSPHRASE_FROM_HOST)
+
+
+def get_keyboard_layout() -> KeyboardLayout:
+    """
+    Order of the letters on the passphrase keyboards.
+    """
+    from trezor.enums import KeyboardLayout
+
+    layout = common.get(_NAMESPACE, _KEYBOARD_LAYOUT, public=True)
+    if not layout or layout[0] > KeyboardLayout.Qwertz:
+        return KeyboardLayout.Abc
+    return layout[0]  # type: ignore [int-into-enum]
+
+
+def set_keyboard_layout(layout: KeyboardLayout) -> None:
+    common.set(_NAMESPACE, _KEYBOARD_LAYOUT, layout.to_bytes(1, "big"), True)  # public
+
+
+def get_large_text() -> bool:
+    """
+    Whether amounts and addresses are shown in a larger font.
+    """
+    return common.get_bool(_NAMESPACE, _LARGE_TEXT, public=True)
+
+
+def set_large_text(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _LARGE_TEXT, enable, public=True)
+
+
+def get_audio_feedback() -> bool:
+    """
+    Whether the outcome of a flow is played on the buzzer.
+    """
+    return common.get_bool(_NAMESPACE, _AUDIO_FEEDBACK, public=True)
+
+
+def set_audio_feedback(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _AUDIO_FEEDBACK, enable, public=True)
+
+
+def get_longer_timeouts() -> bool:
+    """
+    Whether holds and messages that go away on their own take longer.
+    """
+    return common.get_bool(_NAMESPACE, _LONGER_TIMEOUTS, public=True)
+
+
+def set_longer_timeouts(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _LONGER_TIMEOUTS, enable, public=True)
+
+
+def get_high_contrast() -> bool:
+    """
+    Whether secondary text is painted in the main color instead of dimmed.
+    """
+    return common.get_bool(_NAMESPACE, _HIGH_CONTRAST, public=True)
+
+
+def set_high_contrast(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _HIGH_CONTRAST, enable, public=True)
+
+
+def get_reduce_motion() -> bool:
+    """
+    Whether decorative movement, e.g. sliding items, is left out.
+    """
+    return common.get_bool(_NAMESPACE, _REDUCE_MOTION, public=True)
+
+
+def set_reduce_motion(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _REDUCE_MOTION, enable, public=True)
+
+
+def get_theme_inverted() -> bool:
+    """
+    Whether the screens are painted dark on light.
+    """
+    return common.get_bool(_NAMESPACE, _THEME_INVERTED, public=True)
+
+
+def set_theme_inverted(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _THEME_INVERTED, enable, public=True)
+
+
+def get_density() -> ScreenDensity:
+    """
+    Spacing of the confirm screens.
+    """
+    from trezor.enums import ScreenDensity
+
+    if common.get_bool(_NAMESPACE, _COMPACT_DENSITY, public=True):
+        return ScreenDensity.Compact
+    return ScreenDensity.Comfortable
+
+
+def set_density(density: ScreenDensity) -> None:
+    from trezor.enums import ScreenDensity
+
+    compact = density == ScreenDensity.Compact
+    common.set_bool(_NAMESPACE, _COMPACT_DENSITY, compact, public=True)
+
+
+def get_remember_destinations() -> bool:
+    """
+    Whether the confirmed destination addresses are remembered, see
+    `apps.common.recent_destinations`.
+    """
+    return common.get_bool(_NAMESPACE, _REMEMBER_DESTINATIONS)
+
+
+def set_remember_destinations(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _REMEMBER_DESTINATIONS, enable)
+    if not enable:
+        common.delete(_NAMESPACE, _RECENT_DESTINATIONS)
+
+
+def get_recent_destinations() -> bytes:
+    return common.get(_NAMESPACE, _RECENT_DESTINATIONS) or b""
+
+
+def set_recent_destinations(data: bytes) -> None:
+    common.set(_NAMESPACE, _RECENT_DESTINATIONS, data)
+
+
+# do not use this function directly, see apps.common.spending_policy instead
+def get_spending_threshold() -> int | None:
+    threshold = common.get(_NAMESPACE, _SPENDING_THRESHOLD)
+    if not threshold:
+        return None
+    return int.from_bytes(threshold, "big")
+
+
+# do not use this function directly, see apps.common.spending_policy instead
+def set_spending_threshold(threshold: int | None) -> None:
+    if threshold is None:
+        common.delete(_NAMESPACE, _SPENDING_THRESHOLD)
+    else:
+        common.set(_NAMESPACE, _SPENDING_THRESHOLD, threshold.to_bytes(8, "big"))
+
+
+def get_signing_receipt() -> bool:
+    """
+    Whether a fingerprint of what was signed is shown after signing, see
+    `apps.common.signing_receipt`.
+    """
+    return common.get_bool(_NAMESPACE, _SIGNING_RECEIPT)
+
+
+def set_signing_receipt(enable: bool) -> None:
+    common.set_bool(_NAMESPACE, _SIGNING_RECEIPT, enable)
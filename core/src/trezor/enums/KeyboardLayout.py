@@ -0,0 +1,8 @@
+# Automatically generated by pb2py
+# fmt: off
+# isort:skip_file
+
+Abc = 0
+Qwerty = 1
+Azerty = 2
+Qwertz = 3
@@ -50,6 +50,9 @@ ShowDeviceTutorial = 95
 UnlockBootloader = 96
 AuthenticateDevice = 97
 AuthenticityProof = 98
+ChangeLanguage = 990
+TranslationDataRequest = 991
+TranslationDataAck = 992
 FirmwareErase = 6
 FirmwareUpload = 7
 FirmwareRequest = 8
@@ -94,6 +97,7 @@ DebugLinkRecordScreen = 9003
 DebugLinkEraseSdCard = 9005
 DebugLinkWatchLayout = 9006
 DebugLinkResetDebugEvents = 9007
+DebugLinkShowProfiler = 9008
 if not utils.BITCOIN_ONLY:
     SetU2FCounter = 63
     GetNextU2FCounter = 80
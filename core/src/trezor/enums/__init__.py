@@ -67,6 +67,9 @@ if TYPE_CHECKING:
         UnlockBootloader = 96
         AuthenticateDevice = 97
         AuthenticityProof = 98
+        ChangeLanguage = 990
+        TranslationDataRequest = 991
+        TranslationDataAck = 992
         SetU2FCounter = 63
         GetNextU2FCounter = 80
         NextU2FCounter = 81
@@ -117,6 +120,7 @@ if TYPE_CHECKING:
         DebugLinkEraseSdCard = 9005
         DebugLinkWatchLayout = 9006
         DebugLinkResetDebugEvents = 9007
+        DebugLinkShowProfiler = 9008
         EthereumGetPublicKey = 450
         EthereumPublicKey = 451
         EthereumGetAddress = 56
@@ -424,6 +428,16 @@ if TYPE_CHECKING:
         Jpeg = 2
         ToiG = 3
 
+    class KeyboardLayout(IntEnum):
+        Abc = 0
+        Qwerty = 1
+        Azerty = 2
+        Qwertz = 3
+
+    class ScreenDensity(IntEnum):
+        Comfortable = 0
+        Compact = 1
+
     class Capability(IntEnum):
         Bitcoin = 1
         Bitcoin_like = 2
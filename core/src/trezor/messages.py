@@ -44,6 +44,7 @@ if TYPE_CHECKING:
     from trezor.enums import FailureType  # noqa: F401
     from trezor.enums import HomescreenFormat  # noqa: F401
     from trezor.enums import InputScriptType  # noqa: F401
+    from trezor.enums import KeyboardLayout  # noqa: F401
     from trezor.enums import MessageType  # noqa: F401
     from trezor.enums import MoneroNetworkType  # noqa: F401
     from trezor.enums import NEMImportanceTransferMode  # noqa: F401
@@ -55,6 +56,7 @@ if TYPE_CHECKING:
     from trezor.enums import RecoveryDeviceType  # noqa: F401
     from trezor.enums import RequestType  # noqa: F401
     from trezor.enums import SafetyCheckLevel  # noqa: F401
+    from trezor.enums import ScreenDensity  # noqa: F401
     from trezor.enums import SdProtectOperationType  # noqa: F401
     from trezor.enums import StellarAssetType  # noqa: F401
     from trezor.enums import StellarMemoType  # noqa: F401
@@ -2134,6 +2136,13 @@ if TYPE_CHECKING:
         homescreen_width: "int | None"
         homescreen_height: "int | None"
         bootloader_locked: "bool | None"
+        keyboard_layout: "KeyboardLayout | None"
+        density: "ScreenDensity | None"
+        remember_destinations: "bool | None"
+        spending_threshold: "int | None"
+        large_text: "bool | None"
+        audio_feedback: "bool | None"
+        signing_receipt: "bool | None"
 
         def __init__(
             self,
@@ -2184,6 +2193,13 @@ if TYPE_CHECKING:
             homescreen_width: "int | None" = None,
             homescreen_height: "int | None" = None,
             bootloader_locked: "bool | None" = None,
+            keyboard_layout: "KeyboardLayout | None" = None,
+            density: "ScreenDensity | None" = None,
+            remember_destinations: "bool | None" = None,
+            spending_threshold: "int | None" = None,
+            large_text: "bool | None" = None,
+            audio_feedback: "bool | None" = None,
+            signing_receipt: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2228,6 +2244,18 @@ if TYPE_CHECKING:
         safety_checks: "SafetyCheckLevel | None"
         experimental_features: "bool | None"
         hide_passphrase_from_host: "bool | None"
+        remember_destinations: "bool | None"
+        spending_threshold: "int | None"
+        spending_threshold_temporary: "bool | None"
+        keyboard_layout: "KeyboardLayout | None"
+        large_text: "bool | None"
+        audio_feedback: "bool | None"
+        signing_receipt: "bool | None"
+        density: "ScreenDensity | None"
+        longer_timeouts: "bool | None"
+        high_contrast: "bool | None"
+        reduce_motion: "bool | None"
+        theme_inverted: "bool | None"
 
         def __init__(
             self,
@@ -2242,6 +2270,18 @@ if TYPE_CHECKING:
             safety_checks: "SafetyCheckLevel | None" = None,
             experimental_features: "bool | None" = None,
             hide_passphrase_from_host: "bool | None" = None,
+            remember_destinations: "bool | None" = None,
+            spending_threshold: "int | None" = None,
+            spending_threshold_temporary: "bool | None" = None,
+            keyboard_layout: "KeyboardLayout | None" = None,
+            large_text: "bool | None" = None,
+            audio_feedback: "bool | None" = None,
+            signing_receipt: "bool | None" = None,
+            density: "ScreenDensity | None" = None,
+            longer_timeouts: "bool | None" = None,
+            high_contrast: "bool | None" = None,
+            reduce_motion: "bool | None" = None,
+            theme_inverted: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2460,6 +2500,7 @@ if TYPE_CHECKING:
         skip_backup: "bool | None"
         no_backup: "bool | None"
         backup_type: "BackupType"
+        mix_entropy: "bool | None"
 
         def __init__(
             self,
@@ -2474,6 +2515,7 @@ if TYPE_CHECKING:
             skip_backup: "bool | None" = None,
             no_backup: "bool | None" = None,
             backup_type: "BackupType | None" = None,
+            mix_entropy: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2695,6 +2737,56 @@ if TYPE_CHECKING:
         def is_type_of(cls, msg: Any) -> TypeGuard["UnlockBootloader"]:
             return isinstance(msg, cls)
 
+    class ChangeLanguage(protobuf.MessageType):
+        data_length: "int"
+        version: "str"
+        signature: "bytes | None"
+        sigmask: "int | None"
+
+        def __init__(
+            self,
+            *,
+            data_length: "int",
+            version: "str",
+            signature: "bytes | None" = None,
+            sigmask: "int | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["ChangeLanguage"]:
+            return isinstance(msg, cls)
+
+    class TranslationDataRequest(protobuf.MessageType):
+        data_length: "int"
+        data_offset: "int"
+
+        def __init__(
+            self,
+            *,
+            data_length: "int",
+            data_offset: "int",
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["TranslationDataRequest"]:
+            return isinstance(msg, cls)
+
+    class TranslationDataAck(protobuf.MessageType):
+        data_chunk: "bytes"
+
+        def __init__(
+            self,
+            *,
+            data_chunk: "bytes",
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["TranslationDataAck"]:
+            return isinstance(msg, cls)
+
     class DebugLinkDecision(protobuf.MessageType):
         button: "DebugButton | None"
         swipe: "DebugSwipeDirection | None"
@@ -2771,6 +2863,8 @@ if TYPE_CHECKING:
         wait_word_list: "bool | None"
         wait_word_pos: "bool | None"
         wait_layout: "bool | None"
+        layout_tree: "bool | None"
+        layout_trace: "bool | None"
 
         def __init__(
             self,
@@ -2778,6 +2872,8 @@ if TYPE_CHECKING:
             wait_word_list: "bool | None" = None,
             wait_word_pos: "bool | None" = None,
             wait_layout: "bool | None" = None,
+            layout_tree: "bool | None" = None,
+            layout_trace: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2799,6 +2895,8 @@ if TYPE_CHECKING:
         reset_word_pos: "int | None"
         mnemonic_type: "BackupType | None"
         tokens: "list[str]"
+        layout_tree: "str | None"
+        layout_trace: "bytes | None"
 
         def __init__(
             self,
@@ -2816,6 +2914,8 @@ if TYPE_CHECKING:
             recovery_word_pos: "int | None" = None,
             reset_word_pos: "int | None" = None,
             mnemonic_type: "BackupType | None" = None,
+            layout_tree: "str | None" = None,
+            layout_trace: "bytes | None" = None,
         ) -> None:
             pass
 
@@ -2943,6 +3043,20 @@ if TYPE_CHECKING:
         def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkResetDebugEvents"]:
             return isinstance(msg, cls)
 
+    class DebugLinkShowProfiler(protobuf.MessageType):
+        show: "bool | None"
+
+        def __init__(
+            self,
+            *,
+            show: "bool | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkShowProfiler"]:
+            return isinstance(msg, cls)
+
     class EosGetPublicKey(protobuf.MessageType):
         address_n: "list[int]"
         show_display: "bool | None"
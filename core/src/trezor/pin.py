@@ -1,5 +1,7 @@
 from typing import TYPE_CHECKING
 
+from micropython import const
+
 if TYPE_CHECKING:
     from typing import Any
 
@@ -13,6 +15,11 @@ keepalive_callback: Any = None
 
 _ignore_loader_messages: tuple[str, ...] = ()
 
+# Waits at least this long while verifying the PIN come from the delay after wrong PINs
+# rather than from the key derivation, they get the lockout countdown.
+_LOCKOUT_MIN_SECONDS = const(5)
+_lockout_shown = False
+
 
 def ignore_nonpin_loader_messages() -> None:
     global _ignore_loader_messages
@@ -38,7 +45,7 @@ def render_empty_loader(message: str, description: str) -> None:
 
 
 def show_pin_timeout(seconds: int, progress: int, message: str) -> bool:
-    from trezor.ui.layouts.progress import pin_progress
+    from trezor.ui.layouts.progress import pin_lockout, pin_progress
 
     # Possibility to ignore certain messages - not showing loader for them
     if message in _ignore_loader_messages:
@@ -48,10 +55,21 @@ def show_pin_timeout(seconds: int, progress: int, message: str) -> bool:
     global _previous_remaining
     global _progress_layout
     global _started_with_empty_loader
+    global _lockout_shown
 
     if callable(keepalive_callback):
         keepalive_callback()
 
+    if (
+        not _lockout_shown
+        and message == "Verifying PIN"
+        and seconds >= _LOCKOUT_MIN_SECONDS
+    ):
+        # counts down to the end of the whole wait, the message does not matter then
+        _progress_layout = pin_lockout(message, seconds)
+        _lockout_shown = True
+        _started_with_empty_loader = True
+
     if progress == 0 or _progress_layout is None:
         _previous_seconds = None
 
@@ -68,7 +86,9 @@ def show_pin_timeout(seconds: int, progress: int, message: str) -> bool:
         remaining = _previous_remaining
 
     # create the layout if it doesn't exist yet or should be started again
-    if _progress_layout is None or (progress == 0 and not _started_with_empty_loader):
+    if _progress_layout is None or (
+        progress == 0 and not _started_with_empty_loader and not _lockout_shown
+    ):
         _progress_layout = pin_progress(message, description=remaining or "")
 
     # reset the flag - the render_empty_loader() has the effect only in the first call
@@ -82,5 +102,6 @@ def show_pin_timeout(seconds: int, progress: int, message: str) -> bool:
     # drop the layout when done so trezor.ui doesn't have to remain in memory
     if progress >= 1000:
         _progress_layout = None
+        _lockout_shown = False
 
     return False
@@ -0,0 +1,89 @@
+from micropython import const
+from typing import TYPE_CHECKING
+
+import trezorui2
+
+if TYPE_CHECKING:
+    from trezor.utils import HashContext
+
+# Signing the translations, with keys of their own. There are no production
+# keys yet, so only debug builds can change the language for now.
+_THRESHOLD = const(2)
+_PUBLIC_KEYS: tuple[bytes, ...] = ()
+if __debug__:
+    # private keys 0xe0, 0xe1 and 0xe2 repeated 32 times
+    _DEV_PUBLIC_KEYS = (
+        b"\x9f\xf6\x20\x4d\x61\xb5\x9a\x9e\x61\xaf\xdd\x64\xfd\xf2\x94\xbf\xe8\xa1\x66\x87\xba\x05\x38\x82\x3b\xa5\x9d\xb6\xcb\x7b\x21\xff",
+        b"\x25\xfc\x32\xc4\x78\xa5\xa4\xf8\x4e\x45\x57\xa0\x34\x2e\x08\x27\x8c\x88\x36\x41\xb6\x14\x9b\xdb\x0b\x4a\x98\x6a\xb6\xc2\xcb\x78",
+        b"\xc9\x1c\xb3\xce\x2b\x84\xe4\xba\x85\xf5\x62\xec\xe4\x1e\xdf\xe4\xe2\x7a\xfc\x52\xd8\x8d\x50\x7f\x66\xa1\x86\x38\xdf\x82\x3e\x9f",
+    )
+
+# Prefix of the signed data, so that no signature made for anything else is
+# valid for translations.
+_SIGNING_TAG = b"trezor-translations\x00"
+
+
+def init() -> None:
+    """Use the language installed in the flash, if any. Called on boot."""
+    try:
+        trezorui2.load_translations()
+    except ValueError:
+        # none installed, or damaged ones, English is used until the next install
+        pass
+
+
+def change_language(installed: bool) -> None:
+    """Switch the UI to the language of the translations installed in the flash,
+    or back to English if not `installed`, erasing them. Raises in case the
+    installed ones are invalid."""
+    from trezor import ui
+
+    if installed:
+        trezorui2.load_translations()
+    else:
+        trezorui2.erase_translations()
+    if ui.CURRENT_LAYOUT is not None:
+        ui.CURRENT_LAYOUT.language_changed()
+
+
+async def install_language(version: str, signed: bool) -> None:
+    """Switch to the language of the blob just installed for the user to confirm
+    it on a screen in that language, which also tells whether it is `signed`.
+    English is restored unless confirmed, the flash only holds one language."""
+    from trezor.ui.layouts import confirm_language
+
+    try:
+        change_language(True)
+        await confirm_language("change_language", version, signed)
+    except BaseException:
+        change_language(False)
+        raise
+
+
+def blob_hasher(version: str) -> HashContext:
+    """Hasher of the blob signed along with its `version`, which is shown on the
+    screen."""
+    from trezor.crypto.hashlib import sha256
+
+    hasher = sha256(_SIGNING_TAG)
+    hasher.update(version.encode())
+    hasher.update(b"\x00")
+    return hasher
+
+
+def verify_signature(digest: bytes, signature: bytes | None, sigmask: int) -> bool:
+    """Whether `signature` of the blob `digest` is valid. There is nothing to
+    install without one outside debug builds."""
+    from trezor.crypto.cosi import verify as cosi_verify
+
+    if signature is None:
+        return False
+    try:
+        if cosi_verify(signature, digest, _THRESHOLD, _PUBLIC_KEYS, sigmask):
+            return True
+        if __debug__:
+            return cosi_verify(signature, digest, _THRESHOLD, _DEV_PUBLIC_KEYS, sigmask)
+    except ValueError:
+        # sigmask naming more keys than there are
+        pass
+    return False
@@ -22,6 +22,9 @@ layout_chan = loop.chan()
 # allow only one alert at a time to avoid alerts overlapping
 _alert_in_progress = False
 
+# layout running on the screen, if any
+CURRENT_LAYOUT: "Layout | None" = None
+
 # in debug mode, display an indicator in top right corner
 if __debug__:
 
@@ -134,6 +137,8 @@ class Layout:
             # are being awaited, and in handle_rendering() we send the appropriate event
             self.should_notify_layout_change = True
 
+        global CURRENT_LAYOUT
+
         value = None
         try:
             # If any other layout is running (waiting on the layout channel),
@@ -141,6 +146,7 @@ class Layout:
             # closed, just to be sure.
             if layout_chan.takers:
                 await layout_chan.put(Cancelled())
+            CURRENT_LAYOUT = self
             # Now, no other layout should be running.  In a loop, we create new
             # layout tasks and execute them in parallel, while waiting on the
             # layout channel.  This allows other layouts to cancel us, and the
@@ -151,6 +157,9 @@ class Layout:
         except Result as result:
             # Result exception was raised, this means this layout is complete.
             value = result.value
+        finally:
+            if CURRENT_LAYOUT is self:
+                CURRENT_LAYOUT = None
         return value
 
     if TYPE_CHECKING:
@@ -170,12 +179,25 @@ class Layout:
         Usually overridden to add another tasks to the list."""
         raise NotImplementedError
 
+    def language_changed(self) -> None:
+        """Called when the language of the UI changes while the layout is
+        running, so that it can show its strings in the new language."""
+
     if __debug__:
 
         def read_content_into(self, content_store: list[str]) -> None:
             content_store.clear()
             content_store.append(self.__class__.__name__)
 
+        def debug_tree(self) -> dict:
+            """Components of the layout as nested dicts and lists."""
+            return {"component": self.__class__.__name__}
+
+        def read_trace_binary(self) -> bytes | None:
+            """Content in the compact binary trace encoding, only Rust layouts
+            have it."""
+            return None
+
 
 def wait_until_layout_is_running() -> Awaitable[None]:  # type: ignore [awaitable-is-generator]
     while not layout_chan.takers:
@@ -39,3 +39,12 @@ async def interact(
         pages = layout.page_count()  # type: ignore [Cannot access member "page_count" for type "LayoutType"]
     await button_request(br_type, br_code, pages)
     return await context.wait(layout)
+
+
+def resume_flow(*parts: str) -> int:
+    """Identifies the review of `parts` for `trezorui2.save_resume_point`, so
+    that a point saved while reviewing other data is never offered."""
+    from trezor.crypto.hashlib import sha256
+
+    digest = sha256("\0".join(parts).encode()).digest()
+    return int.from_bytes(digest[:4], "little")
@@ -6,10 +6,10 @@ from trezor.enums import ButtonRequestType
 from trezor.wire import ActionCancelled
 from trezor.wire.context import wait as ctx_wait
 
-from ..common import button_request, interact
+from ..common import button_request, interact, resume_flow
 
 if TYPE_CHECKING:
-    from typing import Any, Awaitable, Iterable, NoReturn, Sequence, TypeVar
+    from typing import Any, Awaitable, Callable, Iterable, NoReturn, Sequence, TypeVar
 
     from ..common import ExceptionType, PropertyType
 
@@ -24,9 +24,26 @@ BR_TYPE_OTHER = ButtonRequestType.Other  # global_import_cache
 
 
 if __debug__:
-    from trezor.utils import DISABLE_ANIMATION
+    from trezor.utils import DISABLE_ANIMATION, EMULATOR
 
     trezorui2.disable_animation(bool(DISABLE_ANIMATION))
+    trezorui2.disable_transitions(bool(DISABLE_ANIMATION))
+
+    if EMULATOR:
+        import uos
+
+        # "seed,bounce,missed_release,dropped", rates in percent
+        quirks = uos.getenv("TREZOR_BUTTON_QUIRKS")
+        if quirks:
+            seed, bounce, missed_release, dropped = (
+                int(q) for q in quirks.split(",")
+            )
+            trezorui2.set_button_quirks(
+                seed=seed,
+                bounce=bounce,
+                missed_release=missed_release,
+                dropped=dropped,
+            )
 
 
 class RustLayout(ui.Layout):
@@ -43,6 +60,10 @@ class RustLayout(ui.Layout):
         msg = self.layout.request_complete_repaint()
         assert msg is None
 
+    def language_changed(self) -> None:
+        self.layout.language_changed()
+        self._paint()
+
     def _paint(self) -> None:
         import storage.cache as storage_cache
 
@@ -89,6 +110,18 @@ class RustLayout(ui.Layout):
             content_store.clear()
             self.layout.trace(callback)
 
+        def debug_tree(self) -> dict:
+            """Components of the layout as nested dicts and lists, with their
+            bounds."""
+            return self.layout.debug_tree()
+
+        def read_trace_binary(self) -> bytes:
+            """The content of `read_content_into` in the compact binary trace
+            encoding."""
+            chunks: list[bytes] = []
+            self.layout.trace_binary(chunks.append)
+            return b"".join(chunks)
+
         async def _press_left(self, hold_ms: int | None) -> Any:
             """Triggers left button press."""
             self.layout.button_event(io.BUTTON_PRESSED, io.BUTTON_LEFT)
@@ -244,6 +277,14 @@ class RustLayout(ui.Layout):
         """How many paginated pages current screen has."""
         return self.layout.page_count()
 
+    def active_page(self) -> int:
+        """Page of the screen being shown."""
+        return self.layout.active_page()
+
+    def show_page(self, page: int) -> None:
+        """Continue on `page`, e.g. one shown before the layout was replaced."""
+        self.layout.update("page", page)
+
 
 def draw_simple(layout: Any) -> None:
     # Simple drawing not supported for layouts that set timers.
@@ -544,9 +585,14 @@ def show_pubkey(
     *,
     account: str | None = None,
     path: str | None = None,
+    path_unusual: bool = False,
+    fingerprint: str | None = None,
     mismatch_title: str = "KEY MISMATCH?",
     br_type="show_pubkey",
 ) -> Awaitable[None]:
+    # The fingerprint summary needs more room than there is on this model,
+    # the key is shown the same way as an address. An unusual path was
+    # already confirmed with `confirm_path_warning`.
     return show_address(
         address=pubkey,
         title=title.upper(),
@@ -598,6 +644,63 @@ async def show_error_and_raise(
     raise exc
 
 
+async def confirm_blind_signing(
+    br_type: str,
+    description: str = "",
+    title: str | None = None,
+    br_code: ButtonRequestType = ButtonRequestType.Warning,
+) -> None:
+    """Warning shown before signing something the device cannot decode, the same
+    for all coins."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_blind_signing(title=title, description=description)
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
+async def confirm_language(
+    br_type: str,
+    version: str,
+    signed: bool,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm the translations in use, shown in their language."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(trezorui2.confirm_language(version=version, signed=signed)),
+            br_type,
+            br_code,
+        )
+    )
+
+
+async def confirm_terms(
+    br_type: str,
+    title: str,
+    text: str,
+    verb: str = "ACCEPT",
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Long notice or disclaimer, e.g. of an experimental feature. It can only be
+    accepted after the user has been shown its last page."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_terms(
+                    title=title.upper(), text=text, verb=verb.upper()
+                )
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
 async def show_warning(
     br_type: str,
     content: str,
@@ -618,6 +721,125 @@ async def show_warning(
     )
 
 
+async def confirm_address_lookalike(recent: str) -> None:
+    """Warn that the destination starts and ends like the recently confirmed address
+    `recent` but differs in between, as the addresses of address poisoning do."""
+    await confirm_action(
+        "address_lookalike",
+        "Address looks familiar",
+        action=recent,
+        description=(
+            "The address starts and ends like one you sent to recently, but differs"
+            " in between. Check all of it."
+        ),
+        verb="CONTINUE",
+        br_code=ButtonRequestType.Warning,
+    )
+
+
+async def show_result(
+    br_type: str,
+    success: bool,
+    title: str,
+    detail: str | None = None,
+    button: str | None = "CONTINUE",
+    time_ms: int = 0,
+    rejected: bool = False,
+) -> None:
+    """Standard screen at the end of a flow. Without a button it is dismissed
+    after `time_ms`. `rejected` marks a failure caused by the user cancelling,
+    which sounds different from an error."""
+    await interact(
+        RustLayout(
+            trezorui2.show_result(
+                success=success,
+                title=title,
+                detail=detail or "",
+                button=(button or "").upper(),
+                time_ms=time_ms,
+                rejected=rejected,
+            )
+        ),
+        br_type,
+        ButtonRequestType.Success if success else BR_TYPE_OTHER,
+    )
+
+
+async def show_settings_menu(
+    settings: Iterable[tuple[str, int]]
+) -> tuple[list[tuple[str, int]], bool]:
+    """Let the user adjust `settings`, pairs of a setting name and its current
+    value. The whole menu runs in Rust, only the changed settings are returned,
+    together with whether the user asked to rename the device."""
+    result = await interact(
+        RustLayout(trezorui2.show_settings_menu(settings=settings)),
+        "settings_menu",
+        BR_TYPE_OTHER,
+    )
+    assert isinstance(result, tuple)
+    return result
+
+
+async def choose_from_list(
+    br_type: str,
+    title: str,
+    items: Sequence[str],
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> int:
+    """Let the user pick one of `items` sent by the host, returning its index.
+    Raises DataError when the list does not fit the screen."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.choose_from_list(title=title.upper(), items=items)
+    except ValueError as e:
+        raise DataError(str(e))
+    result = await interact(RustLayout(layout), br_type, br_code)
+    assert result.kind is trezorui2.SELECTED and 0 <= result.value < len(items)
+    return result.value
+
+
+async def choose_account(
+    br_type: str,
+    title: str,
+    accounts: Sequence[tuple[str, str]],
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> int:
+    """Let the user pick one of `accounts`, `(label, balance)` pairs sent by the
+    host, returning its index. Raises DataError when they do not fit the screen."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.choose_account(title=title.upper(), accounts=accounts)
+    except ValueError as e:
+        raise DataError(str(e))
+    result = await interact(RustLayout(layout), br_type, br_code)
+    assert result.kind is trezorui2.SELECTED and 0 <= result.value < len(accounts)
+    return result.value
+
+
+async def edit_list(
+    br_type: str,
+    title: str,
+    items: Sequence[str],
+    available: Sequence[str] = (),
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> list[str]:
+    """Let the user reorder and delete `items` and add the `available` ones,
+    returning the final list. Raises DataError when they do not fit the screen."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.edit_list(
+            title=title.upper(), items=items, available=available
+        )
+    except ValueError as e:
+        raise DataError(str(e))
+    result = await interact(RustLayout(layout), br_type, br_code)
+    assert isinstance(result, list)
+    return result
+
+
 def show_success(
     br_type: str,
     content: str,
@@ -659,6 +881,7 @@ async def confirm_output(
     address_label: str | None = None,
     output_index: int | None = None,
     chunkify: bool = False,
+    coin: str | None = None,
 ) -> None:
     address_title = (
         "RECIPIENT" if output_index is None else f"RECIPIENT #{output_index + 1}"
@@ -673,6 +896,7 @@ async def confirm_output(
                     address_label=address_label or "",
                     address_title=address_title,
                     chunkify=chunkify,
+                    coin=coin,
                 )
             ),
             "confirm_output",
@@ -686,6 +910,7 @@ async def confirm_output(
                 trezorui2.confirm_output_amount(
                     amount_title=amount_title,
                     amount=amount,
+                    coin=coin,
                 )
             ),
             "confirm_output",
@@ -804,6 +1029,93 @@ async def confirm_blob(
         )
 
 
+async def show_blob(
+    br_type: str,
+    title: str,
+    data: bytes,
+    verb: str | None = None,
+    total: int | None = None,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Show all of `data` with offsets, the left button switches between hex and
+    base64. With `verb`, the data has to be confirmed, the left button rejects it
+    instead, raising ActionCancelled. `total` is the length of the whole blob if
+    `data` is only its start."""
+    base64 = False
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.show_blob(
+                    title=title.upper(),
+                    data=data,
+                    base64=base64,
+                    verb=(verb or "").upper(),
+                    total=total or 0,
+                )
+            ),
+            br_type,
+            br_code,
+        )
+        if result is not CANCELLED:
+            return
+        if verb:
+            raise ActionCancelled
+        base64 = not base64
+
+
+async def show_receipt(
+    br_type: str,
+    title: str,
+    description: str,
+    fingerprint: str,
+    qr_data: str,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Show `fingerprint` of what was signed, the left button switches to `qr_data`
+    as a QR code and back."""
+    qr = False
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.show_receipt(
+                    title=title.upper(),
+                    description=description,
+                    fingerprint=fingerprint,
+                    qr_data=qr_data,
+                    qr=qr,
+                )
+            ),
+            br_type,
+            br_code,
+        )
+        if result is not CANCELLED:
+            return
+        qr = not qr
+
+
+async def confirm_settings_change(
+    br_type: str,
+    title: str,
+    changes: Sequence[tuple[str, str, str, bool]],
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm `(name, old value, new value, reduces security)` changes, holding
+    to confirm if any of them reduces security."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_settings_change(
+                    title=title.upper(),
+                    changes=changes,
+                    hold=any(risky for _, _, _, risky in changes),
+                )
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
 async def _confirm_ask_pagination(
     br_type: str,
     title: str,
@@ -813,6 +1125,7 @@ async def _confirm_ask_pagination(
     br_code: ButtonRequestType,
 ) -> None:
     paginated: ui.Layout | None = None
+    blob = data if isinstance(data, bytes) else None
     # TODO: make should_show_more/confirm_more accept bytes directly
     if isinstance(data, bytes):
         from ubinascii import hexlify
@@ -828,6 +1141,10 @@ async def _confirm_ask_pagination(
         ):
             return
 
+        if blob is not None:
+            await show_blob(br_type, title, blob, br_code=br_code)
+            continue
+
         if paginated is None:
             paginated = RustLayout(
                 trezorui2.confirm_more(
@@ -925,6 +1242,69 @@ async def confirm_properties(
         )
     )
 
+async def confirm_streamed(
+    br_type: str,
+    title: str,
+    fetch: Callable[[int], Awaitable[tuple[str, str] | None]],
+    br_code: ButtonRequestType = ButtonRequestType.ConfirmOutput,
+) -> None:
+    """Confirm content too large to be held in memory at once, e.g. the outputs of a
+    large transaction. `fetch(i)` returns the title and value of the i-th item, or
+    None after the last one. Each item is confirmed on its own here."""
+    index = 0
+    while True:
+        item = await fetch(index)
+        if item is None:
+            return
+        item_title, value = item
+        await confirm_blob(
+            br_type, title, value, item_title, verb="CONTINUE", br_code=br_code
+        )
+        index += 1
+
+async def confirm_template(
+    br_type: str,
+    title: str,
+    rows: Sequence[tuple[str, str]],
+    warning: bool = False,
+    verb: str = "CONFIRM",
+    verb_cancel: str = "CANCEL",
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm an application-specific action described by the host, in place of
+    free-form text. Raises DataError when the template does not pass validation."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.confirm_template(
+            title=title.upper(),
+            rows=rows,
+            warning=warning,
+            verb=verb,
+            verb_cancel=verb_cancel,
+        )
+    except ValueError as e:
+        raise DataError(str(e))
+    await raise_if_not_confirmed(interact(RustLayout(layout), br_type, br_code))
+
+async def confirm_payment_uri(
+    br_type: str,
+    uri: str,
+    scheme: str = "bitcoin",
+    br_code: ButtonRequestType = ButtonRequestType.ConfirmOutput,
+) -> None:
+    """Confirm the fields of a BIP-21 payment URI. Raises DataError when the URI is
+    malformed."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.confirm_payment_uri(uri=uri, scheme=scheme)
+    except ValueError as e:
+        raise DataError(str(e))
+    await raise_if_not_confirmed(interact(RustLayout(layout), br_type, br_code))
+
+
+
 
 async def confirm_value(
     title: str,
@@ -1019,6 +1399,7 @@ async def confirm_total(
     account_label: str | None = None,
     br_type: str = "confirm_total",
     br_code: ButtonRequestType = ButtonRequestType.SignTx,
+    coin: str | None = None,  # no room for the coin next to the summary on TR
 ) -> None:
     await raise_if_not_confirmed(
         interact(
@@ -1267,29 +1648,59 @@ async def confirm_signverify(
 ) -> None:
     br_type = "verify_message" if verify else "sign_message"
 
-    # Allowing to go back from the second screen
-    while True:
-        await confirm_blob(
+    # The message is shown from its start again, with the prompt to show all of
+    # it, so only the step is resumed.
+    flow = resume_flow(br_type, address, message)
+    step, _ = await _resume_point(flow, br_type)
+    try:
+        # Allowing to go back from the second screen
+        while True:
+            if step == 0:
+                trezorui2.save_resume_point(flow=flow, step=0, page=0)
+                await confirm_blob(
+                    br_type,
+                    "SIGNING ADDRESS",
+                    address,
+                    verb="CONTINUE",
+                    br_code=BR_TYPE_OTHER,
+                )
+
+            trezorui2.save_resume_point(flow=flow, step=1, page=0)
+            try:
+                await confirm_blob(
+                    br_type,
+                    "CONFIRM MESSAGE",
+                    message,
+                    verb_cancel="^",
+                    br_code=BR_TYPE_OTHER,
+                    ask_pagination=True,
+                )
+            except ActionCancelled:
+                step = 0
+                continue
+            else:
+                break
+    finally:
+        trezorui2.clear_resume_point()
+
+
+async def _resume_point(flow: int, br_type: str) -> tuple[int, int]:
+    """Step and page to start `flow` on. When a reset interrupted its review,
+    offer to continue where it was left."""
+    point = trezorui2.resume_point(flow)
+    if point is None:
+        return 0, 0
+    try:
+        await confirm_action(
             br_type,
-            "SIGNING ADDRESS",
-            address,
-            verb="CONTINUE",
-            br_code=BR_TYPE_OTHER,
+            "RESUME REVIEW",
+            "Continue where you left off?",
+            verb="RESUME",
+            verb_cancel="RESTART",
         )
-
-        try:
-            await confirm_blob(
-                br_type,
-                "CONFIRM MESSAGE",
-                message,
-                verb_cancel="^",
-                br_code=BR_TYPE_OTHER,
-                ask_pagination=True,
-            )
-        except ActionCancelled:
-            continue
-        else:
-            break
+    except ActionCancelled:
+        return 0, 0
+    return point
 
 
 async def show_error_popup(
@@ -1319,12 +1730,16 @@ def request_passphrase_on_host() -> None:
     draw_simple(trezorui2.show_passphrase())
 
 
-async def request_passphrase_on_device(max_len: int) -> str:
+async def request_passphrase_on_device(
+    max_len: int, carry_in: bool = False, carry_out: bool = False
+) -> str:
     result = await interact(
         RustLayout(
             trezorui2.request_passphrase(
                 prompt="ENTER PASSPHRASE",
                 max_len=max_len,
+                carry_in=carry_in,
+                carry_out=carry_out,
             )
         ),
         "passphrase_device",
@@ -1337,6 +1752,68 @@ async def request_passphrase_on_device(max_len: int) -> str:
     return result
 
 
+async def request_destination_label(max_len: int) -> str | None:
+    """Offer the user to name a newly confirmed destination address, so that it is
+    recognized next time. None if they skip it."""
+    try:
+        await confirm_action(
+            "destination_label",
+            "Recent address",
+            description="Name this address to recognize it next time?",
+            verb="NAME",
+            verb_cancel="SKIP",
+        )
+    except ActionCancelled:
+        return None
+    result = await interact(
+        RustLayout(
+            trezorui2.request_label(
+                prompt="Address name",
+                max_len=max_len,
+            )
+        ),
+        "destination_label",
+        BR_TYPE_OTHER,
+    )
+    if result is CANCELLED:
+        return None
+    assert isinstance(result, str)
+    return result or None
+
+
+async def request_device_label(label: str, max_len: int) -> str:
+    """Edit the device label on the device, starting from `label`. The new one is
+    previewed before the user confirms it, going back to the keyboard to edit it."""
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.request_label(
+                    prompt="Device name",
+                    label=label,
+                    max_len=max_len,
+                )
+            ),
+            "set_label",
+            BR_TYPE_OTHER,
+        )
+        if result is CANCELLED:
+            raise ActionCancelled
+        assert isinstance(result, str)
+        label = result
+        try:
+            await confirm_action(
+                "set_label",
+                "Device name",
+                action=label,
+                description="Change device name to this?",
+                verb="CHANGE",
+                verb_cancel="EDIT",
+            )
+            return label
+        except ActionCancelled:
+            pass
+
+
 async def request_pin_on_device(
     prompt: str,
     attempts_remaining: int | None,
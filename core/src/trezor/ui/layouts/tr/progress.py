@@ -32,18 +32,20 @@ def progress(
     message: str = "PLEASE WAIT",
     description: str | None = None,
     indeterminate: bool = False,
+    show_eta: bool = False,
 ) -> ProgressLayout:
     return RustProgress(
         layout=trezorui2.show_progress(
             title=message.upper(),
             indeterminate=indeterminate,
             description=description or "",
+            show_eta=show_eta,
         )
     )
 
 
 def bitcoin_progress(description: str) -> ProgressLayout:
-    return progress("", description)
+    return progress("", description, show_eta=True)
 
 
 def coinjoin_progress(message: str) -> ProgressLayout:
@@ -56,6 +58,16 @@ def pin_progress(message: str, description: str) -> ProgressLayout:
     return progress(message, description)
 
 
+def pin_lockout(message: str, seconds: int) -> ProgressLayout:
+    return RustProgress(
+        layout=trezorui2.show_pin_lockout(
+            title=message.upper(),
+            description="Too many wrong PIN attempts, please wait.",
+            seconds=seconds,
+        )
+    )
+
+
 def monero_keyimage_sync_progress() -> ProgressLayout:
     return progress("", "Syncing...")
 
@@ -65,4 +77,4 @@ def monero_live_refresh_progress() -> ProgressLayout:
 
 
 def monero_transaction_progress_inner() -> ProgressLayout:
-    return progress("", "Signing transaction...")
+    return progress("", "Signing transaction...", show_eta=True)
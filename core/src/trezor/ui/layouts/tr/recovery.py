@@ -65,6 +65,7 @@ async def continue_recovery(
     info_func: Callable | None,
     dry_run: bool,
     show_info: bool = False,
+    share_groups: list[tuple[int, int]] | None = None,  # unused on TR
 ) -> bool:
     # TODO: implement info_func?
     # There is very limited space on the screen
@@ -103,3 +104,14 @@ async def show_recovery_warning(
     br_code: ButtonRequestType = ButtonRequestType.Warning,
 ) -> None:
     await show_warning(br_type, content, subheader, button, br_code)
+
+
+async def show_dry_run_result(
+    result: bool, text: str, checks: Iterable[tuple[bool | None, str]]
+) -> None:
+    from . import show_success
+
+    if result:
+        await show_success("success_dry_recovery", text, button="Continue")
+    else:
+        await show_recovery_warning("warning_dry_recovery", "", text, button="Continue")
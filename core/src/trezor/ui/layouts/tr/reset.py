@@ -96,8 +96,25 @@ async def select_word(
     )
     if __debug__ and isinstance(result, str):
         return result
-    assert isinstance(result, int) and 0 <= result <= 2
-    return words[result]
+    assert result.kind is trezorui2.SELECTED and 0 <= result.value <= 2
+    return words[result.value]
+
+
+async def check_share_words(
+    checks: Sequence[tuple[Sequence[str], str, int]],
+    share_index: int | None,
+    count: int,
+    group_index: int | None = None,
+) -> bool:
+    """Lets the user pick the checked word out of the choices of each check,
+    one screen after another. False as soon as a wrong word is picked."""
+    for words, checked_word, checked_index in checks:
+        selected_word = await select_word(
+            words, share_index, checked_index, count, group_index
+        )
+        if selected_word != checked_word:
+            return False
+    return True
 
 
 async def slip39_show_checklist(step: int, backup_type: BackupType) -> None:
@@ -281,3 +298,21 @@ async def show_reset_warning(
         button.upper(),
         br_code=br_code,
     )
+
+
+async def show_entropy_mixing(seed: bytes) -> bytes:
+    """Pattern stirred by the user pressing the buttons, returns the timing of the
+    presses to mix into the entropy, empty if there were none."""
+    result = await interact(
+        RustLayout(
+            trezorui2.show_entropy_mixing(
+                title="MIXING ENTROPY",
+                description="Press to add more",
+                seed=seed,
+                time_ms=3000,
+            )
+        ),
+        "entropy_mixing",
+        ButtonRequestType.ResetDevice,
+    )
+    return result if isinstance(result, bytes) else b""
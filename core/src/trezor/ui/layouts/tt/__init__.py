@@ -1,15 +1,16 @@
 from typing import TYPE_CHECKING
 
 import trezorui2
+from micropython import const
 from trezor import io, loop, ui
 from trezor.enums import ButtonRequestType
 from trezor.wire import ActionCancelled
 from trezor.wire.context import wait as ctx_wait
 
-from ..common import button_request, interact
+from ..common import button_request, interact, resume_flow
 
 if TYPE_CHECKING:
-    from typing import Any, Awaitable, Iterable, NoReturn, Sequence, TypeVar
+    from typing import Any, Awaitable, Callable, Iterable, NoReturn, Sequence, TypeVar
 
     from ..common import ExceptionType, PropertyType
 
@@ -27,6 +28,7 @@ if __debug__:
     from trezor.utils import DISABLE_ANIMATION
 
     trezorui2.disable_animation(bool(DISABLE_ANIMATION))
+    trezorui2.disable_transitions(bool(DISABLE_ANIMATION))
 
 
 class RustLayout(ui.Layout):
@@ -45,6 +47,10 @@ class RustLayout(ui.Layout):
         msg = self.layout.request_complete_repaint()
         assert msg is None
 
+    def language_changed(self) -> None:
+        self.layout.language_changed()
+        self._paint()
+
     def _paint(self) -> None:
         import storage.cache as storage_cache
 
@@ -89,6 +95,18 @@ class RustLayout(ui.Layout):
             content_store.clear()
             self.layout.trace(callback)
 
+        def debug_tree(self) -> dict:
+            """Components of the layout as nested dicts and lists, with their
+            bounds."""
+            return self.layout.debug_tree()
+
+        def read_trace_binary(self) -> bytes:
+            """The content of `read_content_into` in the compact binary trace
+            encoding."""
+            chunks: list[bytes] = []
+            self.layout.trace_binary(chunks.append)
+            return b"".join(chunks)
+
         async def handle_swipe(self):
             from trezor.enums import DebugSwipeDirection
 
@@ -215,6 +233,14 @@ class RustLayout(ui.Layout):
     def page_count(self) -> int:
         return self.layout.page_count()
 
+    def active_page(self) -> int:
+        """Page of the screen being shown."""
+        return self.layout.active_page()
+
+    def show_page(self, page: int) -> None:
+        """Continue on `page`, e.g. one shown before the layout was replaced."""
+        self.layout.update("page", page)
+
 
 def draw_simple(layout: Any) -> None:
     # Simple drawing not supported for layouts that set timers.
@@ -488,26 +514,45 @@ async def show_address(
                 raise ActionCancelled
 
 
-def show_pubkey(
+async def show_pubkey(
     pubkey: str,
     title: str = "Public key",
     *,
     account: str | None = None,
     path: str | None = None,
+    path_unusual: bool = False,
+    fingerprint: str | None = None,
     mismatch_title: str = "Key mismatch?",
     br_type="show_pubkey",
-) -> Awaitable[None]:
-    return show_address(
-        address=pubkey,
-        title=title.upper(),
-        account=account,
-        path=path,
-        br_type=br_type,
-        br_code=ButtonRequestType.PublicKey,
-        mismatch_title=mismatch_title,
-        chunkify=False,
+) -> None:
+    layout = RustLayout(
+        trezorui2.show_pubkey_details(
+            title=title.upper(),
+            pubkey=pubkey,
+            fingerprint=fingerprint,
+            account=account,
+            path=path,
+            path_unusual=path_unusual,
+        )
+    )
+    await button_request(
+        br_type, ButtonRequestType.PublicKey, pages=layout.page_count()
     )
 
+    while True:
+        layout.request_complete_repaint()
+        result = await ctx_wait(layout)
+        if result is CONFIRMED:
+            break
+
+        result = await ctx_wait(
+            RustLayout(trezorui2.show_mismatch(title=mismatch_title))
+        )
+        assert result in (CONFIRMED, CANCELLED)
+        # Right button aborts action, left goes back to showing the key.
+        if result is CONFIRMED:
+            raise ActionCancelled
+
 
 async def show_error_and_raise(
     br_type: str,
@@ -531,6 +576,63 @@ async def show_error_and_raise(
     raise exc
 
 
+async def confirm_blind_signing(
+    br_type: str,
+    description: str = "",
+    title: str | None = None,
+    br_code: ButtonRequestType = ButtonRequestType.Warning,
+) -> None:
+    """Warning shown before signing something the device cannot decode, the same
+    for all coins."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_blind_signing(title=title, description=description)
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
+async def confirm_language(
+    br_type: str,
+    version: str,
+    signed: bool,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm the translations in use, shown in their language."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(trezorui2.confirm_language(version=version, signed=signed)),
+            br_type,
+            br_code,
+        )
+    )
+
+
+async def confirm_terms(
+    br_type: str,
+    title: str,
+    text: str,
+    verb: str = "ACCEPT",
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Long notice or disclaimer, e.g. of an experimental feature. It can only be
+    accepted after the user has been shown its last page."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_terms(
+                    title=title.upper(), text=text, verb=verb.upper()
+                )
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
 async def show_warning(
     br_type: str,
     content: str,
@@ -553,6 +655,72 @@ async def show_warning(
     )
 
 
+async def confirm_address_lookalike(recent: str) -> None:
+    """Warn that the destination starts and ends like the recently confirmed address
+    `recent` but differs in between, as the addresses of address poisoning do."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.show_warning(
+                    title="ADDRESS LOOKS FAMILIAR",
+                    value=recent,
+                    description=(
+                        "The address starts and ends like one you sent to recently,"
+                        " but differs in between. Check all of it."
+                    ),
+                    button="CONTINUE",
+                    allow_cancel=True,
+                )
+            ),
+            "address_lookalike",
+            ButtonRequestType.Warning,
+        )
+    )
+
+
+async def show_result(
+    br_type: str,
+    success: bool,
+    title: str,
+    detail: str | None = None,
+    button: str | None = "CONTINUE",
+    time_ms: int = 0,
+    rejected: bool = False,
+) -> None:
+    """Standard screen at the end of a flow. Without a button it is dismissed
+    after `time_ms`. `rejected` marks a failure caused by the user cancelling,
+    which sounds different from an error."""
+    await interact(
+        RustLayout(
+            trezorui2.show_result(
+                success=success,
+                title=title,
+                detail=detail or "",
+                button=(button or "").upper(),
+                time_ms=time_ms,
+                rejected=rejected,
+            )
+        ),
+        br_type,
+        ButtonRequestType.Success if success else BR_TYPE_OTHER,
+    )
+
+
+async def show_settings_menu(
+    settings: Iterable[tuple[str, int]]
+) -> tuple[list[tuple[str, int]], bool]:
+    """Let the user adjust `settings`, pairs of a setting name and its current
+    value. The whole menu runs in Rust, only the changed settings are returned,
+    together with whether the user asked to rename the device."""
+    result = await interact(
+        RustLayout(trezorui2.show_settings_menu(settings=settings)),
+        "settings_menu",
+        BR_TYPE_OTHER,
+    )
+    assert isinstance(result, tuple)
+    return result
+
+
 async def show_success(
     br_type: str,
     content: str,
@@ -584,6 +752,7 @@ async def confirm_output(
     address_label: str | None = None,
     output_index: int | None = None,
     chunkify: bool = False,
+    coin: str | None = None,
 ) -> None:
     if title is not None:
         if title.upper().startswith("CONFIRM "):
@@ -609,6 +778,7 @@ async def confirm_output(
                     hold=False,
                     info_button=False,
                     chunkify=chunkify,
+                    coin=coin,
                 )
             ),
             "confirm_output",
@@ -628,6 +798,7 @@ async def confirm_output(
                     verb_cancel="^",
                     hold=hold,
                     info_button=False,
+                    coin=coin,
                 )
             ),
             "confirm_output",
@@ -704,6 +875,97 @@ async def should_show_more(
         raise ActionCancelled
 
 
+async def show_blob(
+    br_type: str,
+    title: str,
+    data: bytes,
+    verb: str | None = None,
+    total: int | None = None,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Show all of `data` with offsets, the info button switches between hex and
+    base64. With `verb`, the data has to be confirmed, rejecting it raises
+    ActionCancelled. `total` is the length of the whole blob if `data` is only
+    its start."""
+    base64 = False
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.show_blob(
+                    title=title.upper(),
+                    data=data,
+                    base64=base64,
+                    verb=(verb or "").upper(),
+                    total=total or 0,
+                )
+            ),
+            br_type,
+            br_code,
+        )
+        if result is INFO:
+            base64 = not base64
+        elif result is CANCELLED and verb:
+            raise ActionCancelled
+        else:
+            return
+
+
+async def show_receipt(
+    br_type: str,
+    title: str,
+    description: str,
+    fingerprint: str,
+    qr_data: str,
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Show `fingerprint` of what was signed, the info button shows `qr_data` as a
+    QR code."""
+    qr = False
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.show_receipt(
+                    title=title.upper(),
+                    description=description,
+                    fingerprint=fingerprint,
+                    qr_data=qr_data,
+                    qr=qr,
+                )
+            ),
+            br_type,
+            br_code,
+        )
+        if qr:
+            qr = False
+        elif result is INFO:
+            qr = True
+        else:
+            return
+
+
+async def confirm_settings_change(
+    br_type: str,
+    title: str,
+    changes: Sequence[tuple[str, str, str, bool]],
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm `(name, old value, new value, reduces security)` changes, holding
+    to confirm if any of them reduces security."""
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.confirm_settings_change(
+                    title=title.upper(),
+                    changes=changes,
+                    hold=any(risky for _, _, _, risky in changes),
+                )
+            ),
+            br_type,
+            br_code,
+        )
+    )
+
+
 async def _confirm_ask_pagination(
     br_type: str,
     title: str,
@@ -712,6 +974,7 @@ async def _confirm_ask_pagination(
     br_code: ButtonRequestType,
 ) -> None:
     paginated: ui.Layout | None = None
+    blob = data if isinstance(data, bytes) else None
     # TODO: make should_show_more/confirm_more accept bytes directly
     if isinstance(data, bytes):
         from ubinascii import hexlify
@@ -726,6 +989,10 @@ async def _confirm_ask_pagination(
         ):
             return
 
+        if blob is not None:
+            await show_blob(br_type, title, blob, br_code=br_code)
+            continue
+
         if paginated is None:
             paginated = RustLayout(
                 trezorui2.confirm_more(
@@ -906,6 +1173,122 @@ async def confirm_properties(
         )
     )
 
+# Longest title and value of a streamed item in bytes of UTF-8, longer values are
+# split.
+_STREAM_TITLE_LEN = const(32)
+_STREAM_VALUE_LEN = const(160)
+
+
+def _split_utf8(text: str, limit: int) -> list[str]:
+    """Split `text` into parts of at most `limit` bytes of UTF-8, keeping each
+    character whole."""
+    data = text.encode()
+    parts: list[str] = []
+    start = 0
+    while start < len(data):
+        end = min(start + limit, len(data))
+        # Continuation bytes of a character are 0b10xxxxxx.
+        while end < len(data) and data[end] & 0xC0 == 0x80:
+            end -= 1
+        parts.append(data[start:end].decode())
+        start = end
+    return parts or [""]
+
+
+async def confirm_template(
+    br_type: str,
+    title: str,
+    rows: Sequence[tuple[str, str]],
+    warning: bool = False,
+    verb: str = "CONFIRM",
+    verb_cancel: str = "CANCEL",
+    br_code: ButtonRequestType = BR_TYPE_OTHER,
+) -> None:
+    """Confirm an application-specific action described by the host, in place of
+    free-form text. Raises DataError when the template does not pass validation."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.confirm_template(
+            title=title.upper(),
+            rows=rows,
+            warning=warning,
+            verb=verb,
+            verb_cancel=verb_cancel,
+        )
+    except ValueError as e:
+        raise DataError(str(e))
+    await raise_if_not_confirmed(interact(RustLayout(layout), br_type, br_code))
+
+async def confirm_payment_uri(
+    br_type: str,
+    uri: str,
+    scheme: str = "bitcoin",
+    br_code: ButtonRequestType = ButtonRequestType.ConfirmOutput,
+) -> None:
+    """Confirm the fields of a BIP-21 payment URI. Raises DataError when the URI is
+    malformed."""
+    from trezor.wire import DataError
+
+    try:
+        layout = trezorui2.confirm_payment_uri(uri=uri, scheme=scheme)
+    except ValueError as e:
+        raise DataError(str(e))
+    await raise_if_not_confirmed(interact(RustLayout(layout), br_type, br_code))
+
+
+
+
+async def confirm_streamed(
+    br_type: str,
+    title: str,
+    fetch: Callable[[int], Awaitable[tuple[str, str] | None]],
+    br_code: ButtonRequestType = ButtonRequestType.ConfirmOutput,
+) -> None:
+    """Confirm content too large to be held in memory at once, e.g. the outputs of a
+    large transaction. `fetch(i)` returns the title and value of the i-th item, or
+    None after the last one. Items are fetched only when the user gets to them and
+    only the last few are kept for going back."""
+    from trezor.wire import DataError
+
+    layout = RustLayout(trezorui2.confirm_streamed(title=title.upper()))
+    index = 0
+    pending: list[tuple[str, str]] = []
+
+    def update(item: tuple[str, str] | None) -> None:
+        # The layout refuses items that do not fit, it would wait for them forever.
+        try:
+            layout.layout.update("item", item)
+        except ValueError:
+            raise DataError("Cannot show streamed item")
+
+    async def push_next() -> None:
+        nonlocal index
+        if not pending:
+            item = await fetch(index)
+            index += 1
+            if item is None:
+                update(None)
+                return
+            item_title, value = item
+            if len(item_title.encode()) > _STREAM_TITLE_LEN:
+                raise DataError("Streamed item title too long")
+            for part in _split_utf8(value, _STREAM_VALUE_LEN):
+                pending.append((item_title, part))
+        update(pending.pop(0))
+
+    await push_next()
+    await button_request(br_type, br_code)
+    while True:
+        layout.request_complete_repaint()
+        result = await ctx_wait(layout)
+        if result is CONFIRMED:
+            return
+        if result is CANCELLED:
+            raise ActionCancelled
+        assert result is trezorui2.MORE
+        await push_next()
+
 
 async def confirm_total(
     total_amount: str,
@@ -917,6 +1300,7 @@ async def confirm_total(
     fee_rate_amount: str | None = None,
     br_type: str = "confirm_total",
     br_code: ButtonRequestType = ButtonRequestType.SignTx,
+    coin: str | None = None,
 ) -> None:
     items = [
         (total_label, total_amount),
@@ -929,7 +1313,7 @@ async def confirm_total(
         info_items.append(("Fee rate:", fee_rate_amount))
 
     await confirm_summary(
-        items, "SUMMARY", info_items, br_type=br_type, br_code=br_code
+        items, "SUMMARY", info_items, br_type=br_type, br_code=br_code, coin=coin
     )
 
 
@@ -939,12 +1323,14 @@ async def confirm_summary(
     info_items: Iterable[tuple[str, str]] | None = None,
     br_type: str = "confirm_total",
     br_code: ButtonRequestType = ButtonRequestType.SignTx,
+    coin: str | None = None,
 ) -> None:
     total_layout = RustLayout(
         trezorui2.confirm_total(
             title=title.upper(),
             items=items,
             info_button=bool(info_items),
+            coin=coin,
         )
     )
     info_items = info_items or []
@@ -1251,28 +1637,59 @@ async def confirm_signverify(
         )
     )
 
-    while True:
-        result = await with_info(
-            address_layout, info_layout, br_type, br_code=BR_TYPE_OTHER
-        )
-        if result is not CONFIRMED:
-            result = await ctx_wait(
-                RustLayout(trezorui2.show_mismatch(title="Address mismatch?"))
-            )
-            assert result in (CONFIRMED, CANCELLED)
-            # Right button aborts action, left goes back to showing address.
+    flow = resume_flow(br_type, address, message)
+    step, page = await _resume_point(flow, br_type)
+    try:
+        while True:
+            if step == 0:
+                trezorui2.save_resume_point(flow=flow, step=0, page=0)
+                result = await with_info(
+                    address_layout, info_layout, br_type, br_code=BR_TYPE_OTHER
+                )
+                if result is not CONFIRMED:
+                    result = await ctx_wait(
+                        RustLayout(trezorui2.show_mismatch(title="Address mismatch?"))
+                    )
+                    assert result in (CONFIRMED, CANCELLED)
+                    # Right button aborts action, left goes back to showing address.
+                    if result is CONFIRMED:
+                        raise ActionCancelled
+                    else:
+                        address_layout.request_complete_repaint()
+                        continue
+
+            # The layout keeps the saved page up to date from here on.
+            page = page or message_layout.active_page()
+            trezorui2.save_resume_point(flow=flow, step=1, page=page)
+            message_layout.show_page(page)
+            message_layout.request_complete_repaint()
+            result = await interact(message_layout, br_type, BR_TYPE_OTHER)
             if result is CONFIRMED:
-                raise ActionCancelled
-            else:
-                address_layout.request_complete_repaint()
-                continue
-
-        message_layout.request_complete_repaint()
-        result = await interact(message_layout, br_type, BR_TYPE_OTHER)
-        if result is CONFIRMED:
-            break
-
-        address_layout.request_complete_repaint()
+                break
+
+            step = page = 0
+            address_layout.request_complete_repaint()
+    finally:
+        trezorui2.clear_resume_point()
+
+
+async def _resume_point(flow: int, br_type: str) -> tuple[int, int]:
+    """Step and page to start `flow` on. When a reset interrupted its review,
+    offer to continue where it was left."""
+    point = trezorui2.resume_point(flow)
+    if point is None:
+        return 0, 0
+    try:
+        await confirm_action(
+            br_type,
+            "RESUME REVIEW",
+            "Continue where you left off?",
+            verb="RESUME",
+            verb_cancel="START OVER",
+        )
+    except ActionCancelled:
+        return 0, 0
+    return point
 
 
 async def show_error_popup(
@@ -1309,10 +1726,17 @@ def request_passphrase_on_host() -> None:
     )
 
 
-async def request_passphrase_on_device(max_len: int) -> str:
+async def request_passphrase_on_device(
+    max_len: int, carry_in: bool = False, carry_out: bool = False
+) -> str:
     result = await interact(
         RustLayout(
-            trezorui2.request_passphrase(prompt="Enter passphrase", max_len=max_len)
+            trezorui2.request_passphrase(
+                prompt="Enter passphrase",
+                max_len=max_len,
+                carry_in=carry_in,
+                carry_out=carry_out,
+            )
         ),
         "passphrase_device",
         ButtonRequestType.PassphraseEntry,
@@ -1324,6 +1748,84 @@ async def request_passphrase_on_device(max_len: int) -> str:
     return result
 
 
+async def request_destination_label(max_len: int) -> str | None:
+    """Offer the user to name a newly confirmed destination address, so that it is
+    recognized next time. None if they skip it."""
+    try:
+        await confirm_action(
+            "destination_label",
+            "Recent address",
+            description="Name this address to recognize it next time?",
+            verb="NAME",
+            verb_cancel="SKIP",
+        )
+    except ActionCancelled:
+        return None
+    result = await interact(
+        RustLayout(
+            trezorui2.request_label(
+                prompt="Address name",
+                max_len=max_len,
+            )
+        ),
+        "destination_label",
+        BR_TYPE_OTHER,
+    )
+    if result is CANCELLED:
+        return None
+    assert isinstance(result, str)
+    return result or None
+
+
+async def request_device_label(label: str, max_len: int) -> str:
+    """Edit the device label on the device, starting from `label`. The new one is
+    previewed before the user confirms it, going back to the keyboard to edit it."""
+    while True:
+        result = await interact(
+            RustLayout(
+                trezorui2.request_label(
+                    prompt="Device name",
+                    label=label,
+                    max_len=max_len,
+                )
+            ),
+            "set_label",
+            BR_TYPE_OTHER,
+        )
+        if result is CANCELLED:
+            raise ActionCancelled
+        assert isinstance(result, str)
+        label = result
+        try:
+            await confirm_action(
+                "set_label",
+                "Device name",
+                action=label,
+                description="Change device name to this?",
+                verb="CHANGE",
+                verb_cancel="EDIT",
+            )
+            return label
+        except ActionCancelled:
+            pass
+
+
+class _PinLayout(RustLayout):
+    """Keeps the PIN entry on screen when USB is disconnected, only shows a
+    toast about it."""
+
+    async def usb_checker_task(self) -> None:
+        usbcheck = loop.wait(io.USB_CHECK)
+        while True:
+            is_connected = await usbcheck
+            if not is_connected:
+                self.layout.update("toast", "USB disconnected")
+                self._paint()
+
+    def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
+        return super().create_tasks() + (self.usb_checker_task(),)
+
+
 async def request_pin_on_device(
     prompt: str,
     attempts_remaining: int | None,
@@ -1340,7 +1842,7 @@ async def request_pin_on_device(
         subprompt = f"{attempts_remaining} tries left"
 
     result = await interact(
-        RustLayout(
+        _PinLayout(
             trezorui2.request_pin(
                 prompt=prompt,
                 subprompt=subprompt,
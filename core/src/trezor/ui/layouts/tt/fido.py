@@ -52,22 +52,25 @@ async def confirm_fido(
     app_name: str,
     icon_name: str | None,
     accounts: list[str | None],
+    time_ms: int = 0,
 ) -> int:
-    """Webauthn confirmation for one or more credentials."""
+    """Webauthn confirmation for one or more credentials. With `time_ms`, the
+    time left to confirm is shown and the dialog is cancelled once it runs out."""
     confirm = _RustFidoLayout(
         trezorui2.confirm_fido(
             title=header.upper(),
             app_name=app_name,
             icon_name=icon_name,
             accounts=accounts,
+            time_ms=time_ms,
         )
     )
     result = await interact(confirm, "confirm_fido", ButtonRequestType.Other)
 
-    # The Rust side returns either an int or `CANCELLED`. We detect the int situation
-    # and assume cancellation otherwise.
-    if isinstance(result, int):
-        return result
+    # The Rust side returns either `SELECTED` with the index or `CANCELLED`. We detect
+    # the selection and assume cancellation otherwise.
+    if getattr(result, "kind", None) is trezorui2.SELECTED:
+        return result.value
 
     # Late import won't get executed on the happy path.
     from trezor.wire import ActionCancelled
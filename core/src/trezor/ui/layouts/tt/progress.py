@@ -1,7 +1,9 @@
 from typing import TYPE_CHECKING
 
 import trezorui2
-from trezor import ui
+import utime
+from trezor import io, ui
+from trezor.wire import ActionCancelled
 
 if TYPE_CHECKING:
     from typing import Any
@@ -13,39 +15,76 @@ class RustProgress:
     def __init__(
         self,
         layout: Any,
+        cancellable: bool = False,
     ):
         self.layout = layout
+        self.cancellable = cancellable
+        # Deadlines of the timers of the cancel button, by token.
+        self.timers: dict[int, int] = {}
+        self.poll_ref = [0, None]
         ui.backlight_fade(ui.style.BACKLIGHT_DIM)
         self.layout.attach_timer_fn(self.set_timer)
         self.layout.paint()
         ui.backlight_fade(ui.style.BACKLIGHT_NORMAL)
 
     def set_timer(self, token: int, deadline: int) -> None:
-        raise RuntimeError  # progress layouts should not set timers
+        if not self.cancellable:
+            raise RuntimeError  # only the cancel button sets timers
+        self.timers[token] = utime.ticks_add(utime.ticks_ms(), deadline)
 
     def report(self, value: int, description: str | None = None):
         msg = self.layout.progress_event(value, description or "")
         assert msg is None
+        if self.cancellable:
+            self._handle_input()
         self.layout.paint()
         ui.refresh()
 
+    def _handle_input(self) -> None:
+        """The operation does not give way to the event loop until it is over, so
+        the touch and USB events and the due timers are delivered here, between
+        its steps. Raises ActionCancelled once the layout asks for it."""
+        while io.poll((io.TOUCH, io.USB_CHECK), self.poll_ref, 0):
+            iface, data = self.poll_ref
+            if iface == io.TOUCH:
+                event, x, y = data
+                self._check(self.layout.touch_event(event, x, y))
+            else:
+                self._check(self.layout.usb_event(data))
+
+        now = utime.ticks_ms()
+        for token, deadline in list(self.timers.items()):
+            if utime.ticks_diff(now, deadline) >= 0:
+                del self.timers[token]
+                self._check(self.layout.timer(token))
+
+    def _check(self, msg: Any) -> None:
+        if msg is trezorui2.CANCELLED:
+            raise ActionCancelled
+        assert msg is None
+
 
 def progress(
     message: str = "PLEASE WAIT",
     description: str | None = None,
     indeterminate: bool = False,
+    show_eta: bool = False,
+    cancellable: bool = False,
 ) -> ProgressLayout:
     return RustProgress(
         layout=trezorui2.show_progress(
             title=message.upper(),
             indeterminate=indeterminate,
             description=description or "",
-        )
+            show_eta=show_eta,
+            cancellable=cancellable,
+        ),
+        cancellable=cancellable,
     )
 
 
 def bitcoin_progress(message: str) -> ProgressLayout:
-    return progress(message)
+    return progress(message, show_eta=True, cancellable=True)
 
 
 def coinjoin_progress(message: str) -> ProgressLayout:
@@ -58,6 +97,16 @@ def pin_progress(message: str, description: str) -> ProgressLayout:
     return progress(message, description=description)
 
 
+def pin_lockout(message: str, seconds: int) -> ProgressLayout:
+    return RustProgress(
+        layout=trezorui2.show_pin_lockout(
+            title=message.upper(),
+            description="Too many wrong PIN attempts, please wait.",
+            seconds=seconds,
+        )
+    )
+
+
 def monero_keyimage_sync_progress() -> ProgressLayout:
     return progress("SYNCING")
 
@@ -67,4 +116,4 @@ def monero_live_refresh_progress() -> ProgressLayout:
 
 
 def monero_transaction_progress_inner() -> ProgressLayout:
-    return progress("SIGNING TRANSACTION")
+    return progress("SIGNING TRANSACTION", show_eta=True)
@@ -28,7 +28,11 @@ async def _is_confirmed_info(
 async def request_word_count(dry_run: bool) -> int:
     selector = RustLayout(trezorui2.select_word_count(dry_run=dry_run))
     count = await interact(selector, "word_count", ButtonRequestType.MnemonicWordCount)
-    return int(count)
+    # It can be returning a string (for example for __debug__ in tests)
+    if __debug__ and isinstance(count, str):
+        return int(count)
+    assert count.kind is trezorui2.SELECTED
+    return count.value
 
 
 async def request_word(word_index: int, word_count: int, is_slip39: bool) -> str:
@@ -103,6 +107,7 @@ async def continue_recovery(
     info_func: Callable | None,
     dry_run: bool,
     show_info: bool = False,  # unused on TT
+    share_groups: list[tuple[int, int]] | None = None,
 ) -> bool:
     from ..common import button_request
 
@@ -119,6 +124,7 @@ async def continue_recovery(
             button=button_label.upper(),
             info_button=info_func is not None,
             dry_run=dry_run,
+            share_groups=share_groups,
         )
     )
 
@@ -152,3 +158,21 @@ async def show_recovery_warning(
             br_code,
         )
     )
+
+
+async def show_dry_run_result(
+    result: bool, text: str, checks: Iterable[tuple[bool | None, str]]
+) -> None:
+    await raise_if_not_confirmed(
+        interact(
+            RustLayout(
+                trezorui2.show_check_result(
+                    title="BACKUP CHECK",
+                    items=checks,
+                    button="CONTINUE",
+                )
+            ),
+            "success_dry_recovery" if result else "warning_dry_recovery",
+            ButtonRequestType.Success if result else ButtonRequestType.Warning,
+        )
+    )
@@ -69,13 +69,14 @@ async def show_share_words(
         raise ActionCancelled
 
 
-async def select_word(
-    words: Sequence[str],
+async def check_share_words(
+    checks: Sequence[tuple[Sequence[str], str, int]],
     share_index: int | None,
-    checked_index: int,
     count: int,
     group_index: int | None = None,
-) -> str:
+) -> bool:
+    """Lets the user pick the checked word out of the choices of each of the
+    three checks, all in one layout. False as soon as a wrong word is picked."""
     if share_index is None:
         title: str = "CHECK SEED"
     elif group_index is None:
@@ -83,26 +84,26 @@ async def select_word(
     else:
         title = f"CHECK G{group_index + 1} - SHARE {share_index + 1}"
 
-    # It may happen (with a very low probability)
-    # that there will be less than three unique words to choose from.
-    # In that case, duplicating the last word to make it three.
-    words = list(words)
-    while len(words) < 3:
-        words.append(words[-1])
-
-    result = await ctx_wait(
-        RustLayout(
-            trezorui2.select_word(
-                title=title,
-                description=f"Select word {checked_index + 1} of {count}:",
-                words=(words[0], words[1], words[2]),
+    items = []
+    for words, checked_word, checked_index in checks:
+        # It may happen (with a very low probability)
+        # that there will be less than three unique words to choose from.
+        # In that case, duplicating the last word to make it three.
+        words = list(words)
+        while len(words) < 3:
+            words.append(words[-1])
+        items.append(
+            (
+                f"Select word {checked_index + 1} of {count}:",
+                (words[0], words[1], words[2]),
+                words.index(checked_word),
             )
         )
+
+    result = await ctx_wait(
+        RustLayout(trezorui2.check_share_words(title=title, checks=items))
     )
-    if __debug__ and isinstance(result, str):
-        return result
-    assert isinstance(result, int) and 0 <= result <= 2
-    return words[result]
+    return isinstance(result, list)
 
 
 async def slip39_show_checklist(step: int, backup_type: BackupType) -> None:
@@ -358,3 +359,21 @@ async def show_reset_warning(
             br_code,
         )
     )
+
+
+async def show_entropy_mixing(seed: bytes) -> bytes:
+    """Pattern stirred by the user tapping the screen, returns the timing of the
+    taps to mix into the entropy, empty if there were none."""
+    result = await interact(
+        RustLayout(
+            trezorui2.show_entropy_mixing(
+                title="MIXING ENTROPY",
+                description="Tap to add more",
+                seed=seed,
+                time_ms=3000,
+            )
+        ),
+        "entropy_mixing",
+        ButtonRequestType.ResetDevice,
+    )
+    return result if isinstance(result, bytes) else b""
@@ -44,6 +44,7 @@ from trezor.enums import FailureType
 from trezor.messages import Failure
 from trezor.wire import codec_v1, context
 from trezor.wire.errors import ActionCancelled, DataError, Error
+from trezorui2 import ValidationError
 
 # Import all errors into namespace, so that `wire.Error` is available from
 # other packages.
@@ -287,6 +288,12 @@ def failure(exc: BaseException) -> Failure:
         return Failure(code=FailureType.ActionCancelled, message="Cancelled")
     elif isinstance(exc, InvalidSessionError):
         return Failure(code=FailureType.InvalidSession, message="Invalid session")
+    elif isinstance(exc, ValidationError):
+        # Arguments of a layout failed validation, most likely because the request
+        # contained malformed data. Report the field that is to blame.
+        field = exc.args[0] if exc.args else "field"
+        reason = exc.args[1] if len(exc.args) > 1 else "Invalid value."
+        return Failure(code=FailureType.DataError, message=f"{field}: {reason}")
     else:
         # NOTE: when receiving generic `FirmwareError` on non-debug build,
         # change the `if __debug__` to `if True` to get the full error message.
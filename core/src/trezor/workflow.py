@@ -2,6 +2,7 @@ import utime
 from typing import TYPE_CHECKING
 
 import storage.cache
+import trezorui2
 from trezor import log, loop
 from trezor.enums import MessageType
 
@@ -60,6 +61,8 @@ def _on_close(workflow: loop.spawn) -> None:
     if __debug__:
         log.debug(__name__, "close: %s", workflow.task)
     tasks.remove(workflow)
+    # Text carried from one input screen to the next must not outlive the flow.
+    trezorui2.wipe_carry()
     if not tasks and default_constructor:
         # If no workflows are running, we should create a new default workflow
         # and run it.
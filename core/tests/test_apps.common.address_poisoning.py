@@ -0,0 +1,42 @@
+from common import *
+
+from storage import cache
+
+from apps.common import address_poisoning
+
+ADDRESS = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+LOOKALIKE = "bc1qarqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq5mdq"
+OTHER = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
+
+
+class TestAddressPoisoning(unittest.TestCase):
+    def setUp(self):
+        cache.clear_all()
+
+    def test_lookalike(self):
+        self.assertIsNone(address_poisoning.lookalike(LOOKALIKE))
+
+        address_poisoning.remember(ADDRESS)
+        self.assertEqual(address_poisoning.lookalike(LOOKALIKE), "bc1qar...5mdq")
+        self.assertEqual(
+            address_poisoning.lookalike(LOOKALIKE.upper()), "bc1qar...5mdq"
+        )
+        # The same address again, or one starting or ending differently, is fine.
+        self.assertIsNone(address_poisoning.lookalike(ADDRESS))
+        self.assertIsNone(address_poisoning.lookalike(OTHER))
+        self.assertIsNone(address_poisoning.lookalike(LOOKALIKE[:-1] + "x"))
+
+    def test_ring_buffer(self):
+        address_poisoning.remember(ADDRESS)
+        for i in range(7):
+            address_poisoning.remember(OTHER[:-1] + str(i))
+        # Remembering an address again does not push out anything.
+        address_poisoning.remember(OTHER[:-1] + "0")
+        self.assertIsNotNone(address_poisoning.lookalike(LOOKALIKE))
+
+        address_poisoning.remember(OTHER)
+        self.assertIsNone(address_poisoning.lookalike(LOOKALIKE))
+
+
+if __name__ == "__main__":
+    unittest.main()
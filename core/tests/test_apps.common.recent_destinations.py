@@ -0,0 +1,53 @@
+from common import *
+from mock_storage import mock_storage
+
+import storage.device
+
+from apps.common import recent_destinations
+
+ADDRESS = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+OTHER = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
+
+
+class TestRecentDestinations(unittest.TestCase):
+    @mock_storage
+    def test_opt_in(self):
+        recent_destinations.remember(ADDRESS)
+        self.assertIsNone(recent_destinations.label(ADDRESS))
+
+        storage.device.set_remember_destinations(True)
+        recent_destinations.remember(ADDRESS)
+        self.assertEqual(
+            recent_destinations.label(ADDRESS), recent_destinations.PREVIOUSLY_USED
+        )
+        self.assertIsNone(recent_destinations.label(OTHER))
+
+        # Turning the memory off forgets everything.
+        storage.device.set_remember_destinations(False)
+        storage.device.set_remember_destinations(True)
+        self.assertIsNone(recent_destinations.label(ADDRESS))
+
+    @mock_storage
+    def test_labels(self):
+        storage.device.set_remember_destinations(True)
+        recent_destinations.remember(ADDRESS, "exchange")
+        recent_destinations.remember(OTHER, "a name much too long")
+        self.assertEqual(recent_destinations.label(ADDRESS), "exchange")
+        self.assertEqual(recent_destinations.label(OTHER), "a name much too ")
+
+        # Only the hash of the address is stored.
+        self.assertNotIn(ADDRESS.encode(), storage.device.get_recent_destinations())
+
+    @mock_storage
+    def test_capacity(self):
+        storage.device.set_remember_destinations(True)
+        recent_destinations.remember(ADDRESS)
+        for i in range(9):
+            recent_destinations.remember(OTHER + str(i))
+        self.assertIsNotNone(recent_destinations.label(ADDRESS))
+        recent_destinations.remember(OTHER)
+        self.assertIsNone(recent_destinations.label(ADDRESS))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,16 @@
+from common import *
+
+from apps.common.signing_receipt import fingerprint
+
+
+class TestSigningReceipt(unittest.TestCase):
+    def test_fingerprint(self):
+        digest = unhexlify(
+            "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+        )
+        self.assertEqual(fingerprint(digest), "0123 4567 89ab cdef")
+        self.assertEqual(fingerprint(b"\xff\x00\x01"), "ff00 01")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,52 @@
+from common import *
+from mock_storage import mock_storage
+
+import storage.cache
+
+from apps.common import coins, spending_policy
+
+BITCOIN = coins.by_name("Bitcoin")
+
+
+class TestSpendingPolicy(unittest.TestCase):
+    def setUp(self):
+        storage.cache.delete(storage.cache.APP_COMMON_SPENDING_THRESHOLD_TEMPORARY)
+
+    @mock_storage
+    def test_threshold(self):
+        self.assertIsNone(spending_policy.read_setting())
+        self.assertFalse(spending_policy.needs_pin(10**8, BITCOIN))
+
+        spending_policy.apply_setting(1000)
+        self.assertFalse(spending_policy.needs_pin(1000, BITCOIN))
+        self.assertTrue(spending_policy.needs_pin(1001, BITCOIN))
+
+        spending_policy.apply_setting(None)
+        self.assertIsNone(spending_policy.read_setting())
+
+    @mock_storage
+    def test_temporary(self):
+        spending_policy.apply_setting(1000)
+        spending_policy.apply_setting(5000, temporary=True)
+        self.assertEqual(spending_policy.read_setting(), 5000)
+
+        # Turned off until restart, the stored one comes back after.
+        spending_policy.apply_setting(None, temporary=True)
+        self.assertIsNone(spending_policy.read_setting())
+        storage.cache.delete(storage.cache.APP_COMMON_SPENDING_THRESHOLD_TEMPORARY)
+        self.assertEqual(spending_policy.read_setting(), 1000)
+
+        # Storing a threshold ends the temporary one.
+        spending_policy.apply_setting(5000, temporary=True)
+        spending_policy.apply_setting(2000)
+        self.assertEqual(spending_policy.read_setting(), 2000)
+
+    @unittest.skipUnless(not utils.BITCOIN_ONLY, "altcoin")
+    @mock_storage
+    def test_other_coins(self):
+        spending_policy.apply_setting(1000)
+        self.assertFalse(spending_policy.needs_pin(2000, coins.by_name("Litecoin")))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -205,6 +205,7 @@ def recover(
 @click.option("-s", "--skip-backup", is_flag=True)
 @click.option("-n", "--no-backup", is_flag=True)
 @click.option("-b", "--backup-type", type=ChoiceType(BACKUP_TYPE), default="single")
+@click.option("-m", "--mix-entropy", is_flag=True)
 @with_client
 def setup(
     client: "TrezorClient",
@@ -217,6 +218,7 @@ def setup(
     skip_backup: bool,
     no_backup: bool,
     backup_type: messages.BackupType,
+    mix_entropy: bool,
 ) -> str:
     """Perform device setup and generate new seed."""
     if strength:
@@ -246,6 +248,7 @@ def setup(
         skip_backup=skip_backup,
         no_backup=no_backup,
         backup_type=backup_type,
+        mix_entropy=mix_entropy,
     )
 
 
@@ -38,6 +38,16 @@ SAFETY_LEVELS = {
     "strict": messages.SafetyCheckLevel.Strict,
     "prompt": messages.SafetyCheckLevel.PromptTemporarily,
 }
+KEYBOARD_LAYOUTS = {
+    "abc": messages.KeyboardLayout.Abc,
+    "qwerty": messages.KeyboardLayout.Qwerty,
+    "azerty": messages.KeyboardLayout.Azerty,
+    "qwertz": messages.KeyboardLayout.Qwertz,
+}
+DENSITIES = {
+    "comfortable": messages.ScreenDensity.Comfortable,
+    "compact": messages.ScreenDensity.Compact,
+}
 
 T1_TR_IMAGE_SIZE = (128, 64)
 
@@ -203,6 +213,32 @@ def label(client: "TrezorClient", label: str) -> str:
     return device.apply_settings(client, label=label)
 
 
+@cli.command()
+@click.argument("filename", type=click.Path(exists=True, dir_okay=False))
+@click.option("-v", "--version", required=True, help="Version of the translations")
+@click.option(
+    "-s", "--signature", help="Hex-encoded CoSi signature of the translations"
+)
+@click.option("-m", "--sigmask", type=int, help="Signers of the signature")
+@with_client
+def language(
+    client: "TrezorClient",
+    filename: str,
+    version: str,
+    signature: Optional[str],
+    sigmask: Optional[int],
+) -> str:
+    """Install translations from a blob file."""
+    blob = Path(filename).read_bytes()
+    return device.change_language(
+        client,
+        blob,
+        version,
+        signature=bytes.fromhex(signature) if signature else None,
+        sigmask=sigmask,
+    )
+
+
 @cli.command()
 @click.argument("rotation", type=ChoiceType(ROTATION))
 @with_client
@@ -344,6 +380,105 @@ def experimental_features(client: "TrezorClient", enable: bool) -> str:
     return device.apply_settings(client, experimental_features=enable)
 
 
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def remember_destinations(client: "TrezorClient", enable: bool) -> str:
+    """Remember the confirmed destination addresses.
+
+    Turning it off also forgets the addresses remembered so far.
+    """
+    return device.apply_settings(client, remember_destinations=enable)
+
+
+@cli.command()
+@click.option("-t", "--temporary", is_flag=True, help="Only until the device restarts")
+@click.argument("satoshis", type=int)
+@with_client
+def spending_threshold(client: "TrezorClient", temporary: bool, satoshis: int) -> str:
+    """Ask for the PIN again when sending more than SATOSHIS.
+
+    Only Bitcoin transactions are checked. Set to 0 to turn it off.
+    """
+    return device.apply_settings(
+        client,
+        spending_threshold=satoshis,
+        spending_threshold_temporary=temporary or None,
+    )
+
+
+@cli.command()
+@click.argument("layout", type=ChoiceType(KEYBOARD_LAYOUTS))
+@with_client
+def keyboard_layout(client: "TrezorClient", layout: messages.KeyboardLayout) -> str:
+    """Set the layout of the passphrase keyboard."""
+    return device.apply_settings(client, keyboard_layout=layout)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def large_text(client: "TrezorClient", enable: bool) -> str:
+    """Show amounts and addresses in a larger font."""
+    return device.apply_settings(client, large_text=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def audio_feedback(client: "TrezorClient", enable: bool) -> str:
+    """Play the outcome of each action on the buzzer."""
+    return device.apply_settings(client, audio_feedback=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def signing_receipt(client: "TrezorClient", enable: bool) -> str:
+    """Show a fingerprint of each signed message."""
+    return device.apply_settings(client, signing_receipt=enable)
+
+
+@cli.command()
+@click.argument("density", type=ChoiceType(DENSITIES))
+@with_client
+def density(client: "TrezorClient", density: messages.ScreenDensity) -> str:
+    """Set the spacing of the confirm screens."""
+    return device.apply_settings(client, density=density)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def longer_timeouts(client: "TrezorClient", enable: bool) -> str:
+    """Give more time to hold buttons and read messages."""
+    return device.apply_settings(client, longer_timeouts=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def high_contrast(client: "TrezorClient", enable: bool) -> str:
+    """Paint all the text in full contrast."""
+    return device.apply_settings(client, high_contrast=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def reduce_motion(client: "TrezorClient", enable: bool) -> str:
+    """Show screens without sliding and transitions."""
+    return device.apply_settings(client, reduce_motion=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def theme_inverted(client: "TrezorClient", enable: bool) -> str:
+    """Paint the screens dark on light."""
+    return device.apply_settings(client, theme_inverted=enable)
+
+
 #
 # passphrase operations
 #
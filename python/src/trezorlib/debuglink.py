@@ -297,6 +297,27 @@ class LayoutContent(UnstructuredJSONReader):
         button_keys = ("left_btn", "middle_btn", "right_btn")
         return [get_button_content(btn_key) for btn_key in button_keys]
 
+    def buttons(self) -> List["AnyDict"]:
+        """Getting all buttons of the layout, with their `bounds` and, on models
+        with physical buttons, the button (`pos`) they are mapped to."""
+        return self.find_objects_with_key_and_value("component", "Button")
+
+    def ui_stats(self) -> Optional["AnyDict"]:
+        """Peak stack depth (`stack_peak`, in bytes) and peak usage of the
+        fixed-size buffers (`buffers`) of the layout. Only available when the
+        firmware is built with `TREZOR_UI_STATS=1`."""
+        return self.top_level_value("ui_stats")
+
+    def paint_counters(self, component: str) -> List[Tuple[int, int]]:
+        """Paints and paint requests of all the `component`s of the layout,
+        counted since the layout was created. Only traced after
+        `trezorui2.trace_paint_counters(True)`."""
+        return [
+            (obj["paints"], obj["paint_requests"])
+            for obj in self.find_objects_with_key_and_value("component", component)
+            if "paints" in obj
+        ]
+
     def seed_words(self) -> List[str]:
         """Get all the seed words on the screen in order.
 
@@ -361,6 +382,90 @@ def multipage_content(layouts: List[LayoutContent]) -> str:
     return "".join(layout.text_content() for layout in layouts)
 
 
+# Tag bytes of the compact binary trace encoding, see `BinaryWriter` in
+# core/embed/rust/src/trace.rs.
+TRACE_OBJECT_START = 0x01
+TRACE_OBJECT_END = 0x02
+TRACE_LIST_START = 0x03
+TRACE_LIST_END = 0x04
+TRACE_KEY = 0x05
+TRACE_INT = 0x06
+TRACE_STRING = 0x07
+TRACE_FALSE = 0x08
+TRACE_TRUE = 0x09
+TRACE_NULL = 0x0A
+
+
+def decode_trace(data: bytes) -> Any:
+    """Decode a layout trace in the compact binary encoding.
+
+    The result is the same as `json.loads` gives for the JSON trace of the layout.
+    Raises ValueError on malformed data.
+    """
+    pos = 0
+
+    def byte() -> int:
+        nonlocal pos
+        if pos >= len(data):
+            raise ValueError("Trace ends unexpectedly")
+        pos += 1
+        return data[pos - 1]
+
+    def varint() -> int:
+        n = shift = 0
+        while True:
+            b = byte()
+            n |= (b & 0x7F) << shift
+            shift += 7
+            if not b & 0x80:
+                return n
+
+    def text() -> str:
+        nonlocal pos
+        length = varint()
+        if pos + length > len(data):
+            raise ValueError("Trace ends unexpectedly")
+        pos += length
+        return data[pos - length : pos].decode()
+
+    def value(tag: int) -> Any:
+        if tag == TRACE_OBJECT_START:
+            obj: "AnyDict" = {}
+            tag = byte()
+            while tag != TRACE_OBJECT_END:
+                if tag != TRACE_KEY:
+                    raise ValueError(f"Expected a key in the trace, got tag {tag}")
+                key = text()
+                obj[key] = value(byte())
+                tag = byte()
+            return obj
+        if tag == TRACE_LIST_START:
+            items: List[Any] = []
+            tag = byte()
+            while tag != TRACE_LIST_END:
+                items.append(value(tag))
+                tag = byte()
+            return items
+        if tag == TRACE_INT:
+            n = varint()
+            # zigzag
+            return (n >> 1) ^ -(n & 1)
+        if tag == TRACE_STRING:
+            return text()
+        if tag == TRACE_FALSE:
+            return False
+        if tag == TRACE_TRUE:
+            return True
+        if tag == TRACE_NULL:
+            return None
+        raise ValueError(f"Unknown tag {tag} in the trace")
+
+    result = value(byte())
+    if pos != len(data):
+        raise ValueError("Unexpected data after the trace")
+    return result
+
+
 class DebugLink:
     def __init__(self, transport: "Transport", auto_interact: bool = True) -> None:
         self.transport = transport
@@ -436,6 +541,19 @@ class DebugLink:
     def read_layout(self) -> LayoutContent:
         return LayoutContent(self.state().tokens or [])
 
+    def read_layout_tree(self) -> "AnyDict":
+        """Get the components of the current layout as a tree, with their bounds."""
+        state = self._call(messages.DebugLinkGetState(layout_tree=True))
+        return json.loads(state.layout_tree or "{}")
+
+    def read_layout_trace(self) -> Any:
+        """Get the trace of the current layout, sent in the compact binary
+        encoding."""
+        state = self._call(messages.DebugLinkGetState(layout_trace=True))
+        if not state.layout_trace:
+            raise RuntimeError("The current layout has no binary trace")
+        return decode_trace(state.layout_trace)
+
     def wait_layout(self, wait_for_external_change: bool = False) -> LayoutContent:
         # Next layout change will be caused by external event
         # (e.g. device being auto-locked or as a result of device_handler.run(xxx))
@@ -698,6 +816,11 @@ class DebugLink:
     def reseed(self, value: int) -> protobuf.MessageType:
         return self._call(messages.DebugLinkReseedRandom(value=value))
 
+    def show_profiler(self, show: bool = True) -> protobuf.MessageType:
+        """Show or hide the paint and event handling times (min/avg/max in ms)
+        in the top right corner of the screen. Core debug builds only."""
+        return self._call(messages.DebugLinkShowProfiler(show=show))
+
     def start_recording(
         self, directory: str, refresh_index: Optional[int] = None
     ) -> None:
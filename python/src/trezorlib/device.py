@@ -44,6 +44,18 @@ def apply_settings(
     safety_checks: Optional[messages.SafetyCheckLevel] = None,
     experimental_features: Optional[bool] = None,
     hide_passphrase_from_host: Optional[bool] = None,
+    remember_destinations: Optional[bool] = None,
+    spending_threshold: Optional[int] = None,
+    spending_threshold_temporary: Optional[bool] = None,
+    keyboard_layout: Optional[messages.KeyboardLayout] = None,
+    large_text: Optional[bool] = None,
+    audio_feedback: Optional[bool] = None,
+    signing_receipt: Optional[bool] = None,
+    density: Optional[messages.ScreenDensity] = None,
+    longer_timeouts: Optional[bool] = None,
+    high_contrast: Optional[bool] = None,
+    reduce_motion: Optional[bool] = None,
+    theme_inverted: Optional[bool] = None,
 ) -> "MessageType":
     settings = messages.ApplySettings(
         label=label,
@@ -56,6 +68,18 @@ def apply_settings(
         safety_checks=safety_checks,
         experimental_features=experimental_features,
         hide_passphrase_from_host=hide_passphrase_from_host,
+        remember_destinations=remember_destinations,
+        spending_threshold=spending_threshold,
+        spending_threshold_temporary=spending_threshold_temporary,
+        keyboard_layout=keyboard_layout,
+        large_text=large_text,
+        audio_feedback=audio_feedback,
+        signing_receipt=signing_receipt,
+        density=density,
+        longer_timeouts=longer_timeouts,
+        high_contrast=high_contrast,
+        reduce_motion=reduce_motion,
+        theme_inverted=theme_inverted,
     )
 
     out = client.call(settings)
@@ -173,6 +197,7 @@ def reset(
     skip_backup: bool = False,
     no_backup: bool = False,
     backup_type: messages.BackupType = messages.BackupType.Bip39,
+    mix_entropy: bool = False,
 ) -> "MessageType":
     if client.features.initialized:
         raise RuntimeError(
@@ -197,6 +222,7 @@ def reset(
         skip_backup=bool(skip_backup),
         no_backup=bool(no_backup),
         backup_type=backup_type,
+        mix_entropy=bool(mix_entropy),
     )
 
     resp = client.call(msg)
@@ -262,6 +288,34 @@ def unlock_bootloader(client: "TrezorClient") -> "MessageType":
     return client.call(messages.UnlockBootloader())
 
 
+@session
+@expect(messages.Success, field="message", ret_type=str)
+def change_language(
+    client: "TrezorClient",
+    blob: bytes,
+    version: str,
+    signature: Optional[bytes] = None,
+    sigmask: Optional[int] = None,
+) -> "MessageType":
+    """Install the translations `blob` after the user confirms them on the device.
+
+    The device tells the user whether `signature` of the blob and its `version`
+    is valid.
+    """
+    resp = client.call(
+        messages.ChangeLanguage(
+            data_length=len(blob),
+            version=version,
+            signature=signature,
+            sigmask=sigmask,
+        )
+    )
+    while isinstance(resp, messages.TranslationDataRequest):
+        chunk = blob[resp.data_offset : resp.data_offset + resp.data_length]
+        resp = client.call(messages.TranslationDataAck(data_chunk=chunk))
+    return resp
+
+
 @expect(messages.Success, field="message", ret_type=str)
 @session
 def set_busy(client: "TrezorClient", expiry_ms: Optional[int]) -> "MessageType":
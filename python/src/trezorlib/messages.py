@@ -75,6 +75,9 @@ class MessageType(IntEnum):
     UnlockBootloader = 96
     AuthenticateDevice = 97
     AuthenticityProof = 98
+    ChangeLanguage = 990
+    TranslationDataRequest = 991
+    TranslationDataAck = 992
     SetU2FCounter = 63
     GetNextU2FCounter = 80
     NextU2FCounter = 81
@@ -125,6 +128,7 @@ class MessageType(IntEnum):
     DebugLinkEraseSdCard = 9005
     DebugLinkWatchLayout = 9006
     DebugLinkResetDebugEvents = 9007
+    DebugLinkShowProfiler = 9008
     EthereumGetPublicKey = 450
     EthereumPublicKey = 451
     EthereumGetAddress = 56
@@ -455,6 +459,18 @@ class HomescreenFormat(IntEnum):
     ToiG = 3
 
 
+class KeyboardLayout(IntEnum):
+    Abc = 0
+    Qwerty = 1
+    Azerty = 2
+    Qwertz = 3
+
+
+class ScreenDensity(IntEnum):
+    Comfortable = 0
+    Compact = 1
+
+
 class Capability(IntEnum):
     Bitcoin = 1
     Bitcoin_like = 2
@@ -3210,6 +3226,13 @@ class Features(protobuf.MessageType):
         47: protobuf.Field("homescreen_width", "uint32", repeated=False, required=False, default=None),
         48: protobuf.Field("homescreen_height", "uint32", repeated=False, required=False, default=None),
         49: protobuf.Field("bootloader_locked", "bool", repeated=False, required=False, default=None),
+        50: protobuf.Field("keyboard_layout", "KeyboardLayout", repeated=False, required=False, default=None),
+        51: protobuf.Field("density", "ScreenDensity", repeated=False, required=False, default=None),
+        52: protobuf.Field("remember_destinations", "bool", repeated=False, required=False, default=None),
+        53: protobuf.Field("spending_threshold", "uint64", repeated=False, required=False, default=None),
+        54: protobuf.Field("large_text", "bool", repeated=False, required=False, default=None),
+        55: protobuf.Field("audio_feedback", "bool", repeated=False, required=False, default=None),
+        56: protobuf.Field("signing_receipt", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3262,6 +3285,13 @@ class Features(protobuf.MessageType):
         homescreen_width: Optional["int"] = None,
         homescreen_height: Optional["int"] = None,
         bootloader_locked: Optional["bool"] = None,
+        keyboard_layout: Optional["KeyboardLayout"] = None,
+        density: Optional["ScreenDensity"] = None,
+        remember_destinations: Optional["bool"] = None,
+        spending_threshold: Optional["int"] = None,
+        large_text: Optional["bool"] = None,
+        audio_feedback: Optional["bool"] = None,
+        signing_receipt: Optional["bool"] = None,
     ) -> None:
         self.capabilities: Sequence["Capability"] = capabilities if capabilities is not None else []
         self.major_version = major_version
@@ -3310,6 +3340,13 @@ class Features(protobuf.MessageType):
         self.homescreen_width = homescreen_width
         self.homescreen_height = homescreen_height
         self.bootloader_locked = bootloader_locked
+        self.keyboard_layout = keyboard_layout
+        self.density = density
+        self.remember_destinations = remember_destinations
+        self.spending_threshold = spending_threshold
+        self.large_text = large_text
+        self.audio_feedback = audio_feedback
+        self.signing_receipt = signing_receipt
 
 
 class LockDevice(protobuf.MessageType):
@@ -3348,6 +3385,18 @@ class ApplySettings(protobuf.MessageType):
         9: protobuf.Field("safety_checks", "SafetyCheckLevel", repeated=False, required=False, default=None),
         10: protobuf.Field("experimental_features", "bool", repeated=False, required=False, default=None),
         11: protobuf.Field("hide_passphrase_from_host", "bool", repeated=False, required=False, default=None),
+        12: protobuf.Field("remember_destinations", "bool", repeated=False, required=False, default=None),
+        13: protobuf.Field("spending_threshold", "uint64", repeated=False, required=False, default=None),
+        14: protobuf.Field("spending_threshold_temporary", "bool", repeated=False, required=False, default=None),
+        15: protobuf.Field("keyboard_layout", "KeyboardLayout", repeated=False, required=False, default=None),
+        16: protobuf.Field("large_text", "bool", repeated=False, required=False, default=None),
+        17: protobuf.Field("audio_feedback", "bool", repeated=False, required=False, default=None),
+        18: protobuf.Field("signing_receipt", "bool", repeated=False, required=False, default=None),
+        19: protobuf.Field("density", "ScreenDensity", repeated=False, required=False, default=None),
+        20: protobuf.Field("longer_timeouts", "bool", repeated=False, required=False, default=None),
+        21: protobuf.Field("high_contrast", "bool", repeated=False, required=False, default=None),
+        22: protobuf.Field("reduce_motion", "bool", repeated=False, required=False, default=None),
+        23: protobuf.Field("theme_inverted", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3364,6 +3413,18 @@ class ApplySettings(protobuf.MessageType):
         safety_checks: Optional["SafetyCheckLevel"] = None,
         experimental_features: Optional["bool"] = None,
         hide_passphrase_from_host: Optional["bool"] = None,
+        remember_destinations: Optional["bool"] = None,
+        spending_threshold: Optional["int"] = None,
+        spending_threshold_temporary: Optional["bool"] = None,
+        keyboard_layout: Optional["KeyboardLayout"] = None,
+        large_text: Optional["bool"] = None,
+        audio_feedback: Optional["bool"] = None,
+        signing_receipt: Optional["bool"] = None,
+        density: Optional["ScreenDensity"] = None,
+        longer_timeouts: Optional["bool"] = None,
+        high_contrast: Optional["bool"] = None,
+        reduce_motion: Optional["bool"] = None,
+        theme_inverted: Optional["bool"] = None,
     ) -> None:
         self.language = language
         self.label = label
@@ -3376,6 +3437,18 @@ class ApplySettings(protobuf.MessageType):
         self.safety_checks = safety_checks
         self.experimental_features = experimental_features
         self.hide_passphrase_from_host = hide_passphrase_from_host
+        self.remember_destinations = remember_destinations
+        self.spending_threshold = spending_threshold
+        self.spending_threshold_temporary = spending_threshold_temporary
+        self.keyboard_layout = keyboard_layout
+        self.large_text = large_text
+        self.audio_feedback = audio_feedback
+        self.signing_receipt = signing_receipt
+        self.density = density
+        self.longer_timeouts = longer_timeouts
+        self.high_contrast = high_contrast
+        self.reduce_motion = reduce_motion
+        self.theme_inverted = theme_inverted
 
 
 class ApplyFlags(protobuf.MessageType):
@@ -3597,6 +3670,7 @@ class ResetDevice(protobuf.MessageType):
         8: protobuf.Field("skip_backup", "bool", repeated=False, required=False, default=None),
         9: protobuf.Field("no_backup", "bool", repeated=False, required=False, default=None),
         10: protobuf.Field("backup_type", "BackupType", repeated=False, required=False, default=BackupType.Bip39),
+        11: protobuf.Field("mix_entropy", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3612,6 +3686,7 @@ class ResetDevice(protobuf.MessageType):
         skip_backup: Optional["bool"] = None,
         no_backup: Optional["bool"] = None,
         backup_type: Optional["BackupType"] = BackupType.Bip39,
+        mix_entropy: Optional["bool"] = None,
     ) -> None:
         self.display_random = display_random
         self.strength = strength
@@ -3623,6 +3698,7 @@ class ResetDevice(protobuf.MessageType):
         self.skip_backup = skip_backup
         self.no_backup = no_backup
         self.backup_type = backup_type
+        self.mix_entropy = mix_entropy
 
 
 class BackupDevice(protobuf.MessageType):
@@ -3831,6 +3907,60 @@ class UnlockBootloader(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 96
 
 
+class ChangeLanguage(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 990
+    FIELDS = {
+        1: protobuf.Field("data_length", "uint32", repeated=False, required=True),
+        2: protobuf.Field("version", "string", repeated=False, required=True),
+        3: protobuf.Field("signature", "bytes", repeated=False, required=False, default=None),
+        4: protobuf.Field("sigmask", "uint32", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        data_length: "int",
+        version: "str",
+        signature: Optional["bytes"] = None,
+        sigmask: Optional["int"] = None,
+    ) -> None:
+        self.data_length = data_length
+        self.version = version
+        self.signature = signature
+        self.sigmask = sigmask
+
+
+class TranslationDataRequest(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 991
+    FIELDS = {
+        1: protobuf.Field("data_length", "uint32", repeated=False, required=True),
+        2: protobuf.Field("data_offset", "uint32", repeated=False, required=True),
+    }
+
+    def __init__(
+        self,
+        *,
+        data_length: "int",
+        data_offset: "int",
+    ) -> None:
+        self.data_length = data_length
+        self.data_offset = data_offset
+
+
+class TranslationDataAck(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 992
+    FIELDS = {
+        1: protobuf.Field("data_chunk", "bytes", repeated=False, required=True),
+    }
+
+    def __init__(
+        self,
+        *,
+        data_chunk: "bytes",
+    ) -> None:
+        self.data_chunk = data_chunk
+
+
 class DebugLinkDecision(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 100
     FIELDS = {
@@ -3917,6 +4047,8 @@ class DebugLinkGetState(protobuf.MessageType):
         1: protobuf.Field("wait_word_list", "bool", repeated=False, required=False, default=None),
         2: protobuf.Field("wait_word_pos", "bool", repeated=False, required=False, default=None),
         3: protobuf.Field("wait_layout", "bool", repeated=False, required=False, default=None),
+        4: protobuf.Field("layout_tree", "bool", repeated=False, required=False, default=None),
+        5: protobuf.Field("layout_trace", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3925,10 +4057,14 @@ class DebugLinkGetState(protobuf.MessageType):
         wait_word_list: Optional["bool"] = None,
         wait_word_pos: Optional["bool"] = None,
         wait_layout: Optional["bool"] = None,
+        layout_tree: Optional["bool"] = None,
+        layout_trace: Optional["bool"] = None,
     ) -> None:
         self.wait_word_list = wait_word_list
         self.wait_word_pos = wait_word_pos
         self.wait_layout = wait_layout
+        self.layout_tree = layout_tree
+        self.layout_trace = layout_trace
 
 
 class DebugLinkState(protobuf.MessageType):
@@ -3947,6 +4083,8 @@ class DebugLinkState(protobuf.MessageType):
         11: protobuf.Field("reset_word_pos", "uint32", repeated=False, required=False, default=None),
         12: protobuf.Field("mnemonic_type", "BackupType", repeated=False, required=False, default=None),
         13: protobuf.Field("tokens", "string", repeated=True, required=False, default=None),
+        14: protobuf.Field("layout_tree", "string", repeated=False, required=False, default=None),
+        15: protobuf.Field("layout_trace", "bytes", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3965,6 +4103,8 @@ class DebugLinkState(protobuf.MessageType):
         recovery_word_pos: Optional["int"] = None,
         reset_word_pos: Optional["int"] = None,
         mnemonic_type: Optional["BackupType"] = None,
+        layout_tree: Optional["str"] = None,
+        layout_trace: Optional["bytes"] = None,
     ) -> None:
         self.tokens: Sequence["str"] = tokens if tokens is not None else []
         self.layout = layout
@@ -3979,6 +4119,8 @@ class DebugLinkState(protobuf.MessageType):
         self.recovery_word_pos = recovery_word_pos
         self.reset_word_pos = reset_word_pos
         self.mnemonic_type = mnemonic_type
+        self.layout_tree = layout_tree
+        self.layout_trace = layout_trace
 
 
 class DebugLinkStop(protobuf.MessageType):
@@ -4102,6 +4244,20 @@ class DebugLinkResetDebugEvents(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 9007
 
 
+class DebugLinkShowProfiler(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9008
+    FIELDS = {
+        1: protobuf.Field("show", "bool", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        show: Optional["bool"] = None,
+    ) -> None:
+        self.show = show
+
+
 class EosGetPublicKey(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 600
     FIELDS = {
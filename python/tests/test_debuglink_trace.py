@@ -0,0 +1,96 @@
+import json
+
+import pytest
+
+from trezorlib import debuglink
+from trezorlib.debuglink import decode_trace
+
+# The trace of `Sample` in the tests of core/embed/rust/src/trace.rs, as the
+# firmware encodes it.
+SAMPLE_JSON = '{"component": "Sample", "count": -2, "items": [true, {"none": null}]}'
+SAMPLE_BINARY = (
+    b"\x01\x05\x09component\x07\x06Sample\x05\x05count\x06\x03"
+    b"\x05\x05items\x03\x09\x01\x05\x04none\x0a\x02\x04\x02"
+)
+
+
+def _varint(n: int) -> bytes:
+    out = bytearray()
+    while True:
+        byte = n & 0x7F
+        n >>= 7
+        if not n:
+            out.append(byte)
+            return bytes(out)
+        out.append(byte | 0x80)
+
+
+def _text(tag: int, s: str) -> bytes:
+    data = s.encode()
+    return bytes([tag]) + _varint(len(data)) + data
+
+
+def encode(value) -> bytes:
+    """Encode `value` like the firmware does."""
+    if value is None:
+        return bytes([debuglink.TRACE_NULL])
+    if value is True:
+        return bytes([debuglink.TRACE_TRUE])
+    if value is False:
+        return bytes([debuglink.TRACE_FALSE])
+    if isinstance(value, int):
+        return bytes([debuglink.TRACE_INT]) + _varint((value << 1) ^ (value >> 63))
+    if isinstance(value, str):
+        return _text(debuglink.TRACE_STRING, value)
+    if isinstance(value, dict):
+        items = b"".join(
+            _text(debuglink.TRACE_KEY, k) + encode(v) for k, v in value.items()
+        )
+        start, end = debuglink.TRACE_OBJECT_START, debuglink.TRACE_OBJECT_END
+    else:
+        items = b"".join(encode(v) for v in value)
+        start, end = debuglink.TRACE_LIST_START, debuglink.TRACE_LIST_END
+    return bytes([start]) + items + bytes([end])
+
+
+VALUES = (
+    0,
+    300,
+    -1,
+    2**63 - 1,
+    -(2**63),
+    "",
+    "Příliš žluťoučký kůň",
+    [],
+    {},
+    {"component": "Frame", "title": {"component": "Label", "text": "QR"}},
+    [[], [None, False], {"bounds": [0, 0, 240, 240]}],
+)
+
+
+def test_firmware_encoding():
+    assert decode_trace(SAMPLE_BINARY) == json.loads(SAMPLE_JSON)
+    assert encode(json.loads(SAMPLE_JSON)) == SAMPLE_BINARY
+    assert decode_trace(b"\x06\xd8\x04") == 300
+
+
+@pytest.mark.parametrize("value", VALUES)
+def test_round_trip(value):
+    assert decode_trace(encode(value)) == value
+
+
+@pytest.mark.parametrize(
+    "data",
+    (
+        b"",
+        b"\x01\x05\x09comp",  # key cut short
+        b"\x03\x09",  # list not closed
+        b"\x06\x80",  # varint not finished
+        b"\x01\x07\x01a\x02",  # string instead of a key
+        b"\x0b",  # unknown tag
+        b"\x0a\x0a",  # data after the value
+    ),
+)
+def test_malformed(data: bytes):
+    with pytest.raises(ValueError):
+        decode_trace(data)
@@ -26,6 +26,8 @@ import pytest
 from trezorlib import btc, messages, tools
 from trezorlib.messages import ButtonRequestType
 
+from . import buttons
+
 if TYPE_CHECKING:
     from _pytest.mark.structures import MarkDecorator
 
@@ -212,15 +214,23 @@ def read_and_confirm_mnemonic_tt(
 
     debug.press_yes()
 
-    # check share
-    for _ in range(3):
-        word_pos = int(debug.wait_layout().text_content().split()[2])
-        index = word_pos - 1
+    # check share, all three checks are steps of one layout
+    layout = debug.wait_layout()
+    for i in range(3):
+        # "Select word 3 of 20"
+        #              ^
+        word_pos = int(layout.text_content().split()[2])
+        wanted_word = mnemonic[word_pos - 1].lower()
+        btn_texts = [text.lower() for text in layout.tt_check_seed_button_contents()]
+        button_pos = btn_texts.index(wanted_word)
         if choose_wrong:
-            debug.input(mnemonic[(index + 1) % len(mnemonic)])
+            debug.click(buttons.RESET_WORD_CHECK[(button_pos + 1) % 3])
             return None
+        if i == 2:
+            # The last pick ends the layout, the next one comes with a ButtonRequest.
+            debug.click(buttons.RESET_WORD_CHECK[button_pos])
         else:
-            debug.input(mnemonic[index])
+            layout = debug.click(buttons.RESET_WORD_CHECK[button_pos], wait=True)
 
     return " ".join(mnemonic)
 
@@ -24,6 +24,7 @@ from trezorlib.tools import parse_path, unharden
 
 from ...common import parametrize_using_common_fixtures
 from ...input_flows import (
+    InputFlowEthereumSignTxDataBase64,
     InputFlowEthereumSignTxDataGoBack,
     InputFlowEthereumSignTxDataScrollDown,
     InputFlowEthereumSignTxDataSkip,
@@ -415,6 +416,10 @@ def input_flow_data_skip(client: Client, cancel: bool = False):
     return InputFlowEthereumSignTxDataSkip(client, cancel).get()
 
 
+def input_flow_data_base64(client: Client, cancel: bool = False):
+    return InputFlowEthereumSignTxDataBase64(client, cancel).get()
+
+
 def input_flow_data_scroll_down(client: Client, cancel: bool = False):
     return InputFlowEthereumSignTxDataScrollDown(client, cancel).get()
 
@@ -427,7 +432,13 @@ HEXDATA = "0123456789abcd000023456789abcd010003456789abcd020000456789abcd0300000
 
 
 @pytest.mark.parametrize(
-    "flow", (input_flow_data_skip, input_flow_data_scroll_down, input_flow_data_go_back)
+    "flow",
+    (
+        input_flow_data_skip,
+        input_flow_data_base64,
+        input_flow_data_scroll_down,
+        input_flow_data_go_back,
+    ),
 )
 @pytest.mark.skip_t1
 def test_signtx_data_pagination(client: Client, flow):
@@ -25,6 +25,7 @@ from ...common import EXTERNAL_ENTROPY, MNEMONIC12, WITH_MOCK_URANDOM, generate_
 from ...input_flows import (
     InputFlowBip39ResetBackup,
     InputFlowBip39ResetFailedCheck,
+    InputFlowBip39ResetMixEntropy,
     InputFlowBip39ResetPIN,
 )
 
@@ -78,6 +79,32 @@ def test_reset_device_192(client: Client):
     reset_device(client, 192)  # 18 words
 
 
+@pytest.mark.setup_client(uninitialized=True)
+def test_reset_device_mix_entropy(client: Client):
+    strength = 128
+    with WITH_MOCK_URANDOM, client:
+        IF = InputFlowBip39ResetMixEntropy(client)
+        client.set_input_flow(IF.get())
+
+        device.reset(
+            client,
+            strength=strength,
+            pin_protection=False,
+            label="test",
+            mix_entropy=True,
+        )
+
+    # the device reports the internal entropy after the presses were mixed in
+    internal_entropy = client.debug.state().reset_entropy
+    entropy = generate_entropy(strength, internal_entropy, EXTERNAL_ENTROPY)
+    expected_mnemonic = Mnemonic("english").to_mnemonic(entropy)
+    assert IF.mnemonic == expected_mnemonic
+
+    resp = client.call_raw(messages.Initialize())
+    assert resp.initialized is True
+    assert resp.needs_backup is False
+
+
 @pytest.mark.setup_client(uninitialized=True)
 def test_reset_device_pin(client: Client):
     strength = 256  # 24 words
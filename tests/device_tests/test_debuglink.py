@@ -14,6 +14,8 @@
 # You should have received a copy of the License along with this library.
 # If not, see <https://www.gnu.org/licenses/lgpl-3.0.html>.
 
+import json
+
 import pytest
 
 from trezorlib import debuglink, device, messages, misc
@@ -95,3 +97,58 @@ def test_softlock_instability(client: Client):
     # If the device actually called config.unlock(), it would use additional randomness.
     # That is undesirable. Assert that the returned entropy is still the same.
     assert misc.get_entropy(client, 16) == entropy_after_wipe
+
+
+def _strings(tree: object) -> list[str]:
+    """All strings in the values of a layout tree."""
+    if isinstance(tree, str):
+        return [tree]
+    if isinstance(tree, dict):
+        tree = list(tree.values())
+    if isinstance(tree, list):
+        return [s for item in tree for s in _strings(item)]
+    return []
+
+
+@pytest.mark.skip_t1
+def test_layout_tree(client: Client):
+    def input_flow():
+        yield  # confirm ping
+        client.debug.wait_layout()
+        tree = client.debug.read_layout_tree()
+
+        # confirm_action is a titled frame around a paginated body
+        assert tree["component"] in ("Frame", "ScrollableFrame")
+        assert tree["title"]["component"] in ("Label", "Title")
+        assert tree["title"]["text"] == "CONFIRM"
+
+        page = tree["content"]
+        assert page["component"] == "ButtonPage"
+        assert page["active_page"] == 0
+        assert page["page_count"] == 1
+        assert "ping" in _strings(page["content"])
+
+        client.debug.press_yes()
+
+    with client:
+        client.set_input_flow(input_flow)
+        assert client.ping("tree", button_protection=True) == "tree"
+
+
+@pytest.mark.skip_t1
+def test_layout_trace(client: Client):
+    def input_flow():
+        yield  # confirm ping
+        client.debug.wait_layout()
+        tokens = client.debug.state().tokens
+
+        # The binary encoding carries the same trace as the JSON one.
+        trace = client.debug.read_layout_trace()
+        assert trace == json.loads("".join(tokens))
+        assert trace["component"] in ("Frame", "ScrollableFrame")
+
+        client.debug.press_yes()
+
+    with client:
+        client.set_input_flow(input_flow)
+        assert client.ping("trace", button_protection=True) == "trace"
@@ -437,3 +437,142 @@ def test_label_too_long(client: Client):
     with pytest.raises(exceptions.TrezorFailure), client:
         client.set_expected_responses([messages.Failure])
         device.apply_settings(client, label="A" * 33)
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_remember_destinations(client: Client):
+    assert client.features.remember_destinations is False
+
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, remember_destinations=enable)
+
+        assert client.features.remember_destinations is enable
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_spending_threshold(client: Client):
+    assert client.features.spending_threshold is None
+
+    # the temporary threshold overrides the stored one, zero turns it off
+    for threshold, temporary, effective in (
+        (100_000, None, 100_000),
+        (50_000, True, 50_000),
+        (0, None, None),
+    ):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(
+                client,
+                spending_threshold=threshold,
+                spending_threshold_temporary=temporary,
+            )
+
+        assert client.features.spending_threshold == effective
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_keyboard_layout(client: Client):
+    assert client.features.keyboard_layout == messages.KeyboardLayout.Abc
+
+    for layout in (messages.KeyboardLayout.Qwertz, messages.KeyboardLayout.Abc):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, keyboard_layout=layout)
+
+        assert client.features.keyboard_layout == layout
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_large_text(client: Client):
+    assert client.features.large_text is False
+
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, large_text=enable)
+
+        assert client.features.large_text is enable
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_audio_feedback(client: Client):
+    assert client.features.audio_feedback is False
+
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, audio_feedback=enable)
+
+        assert client.features.audio_feedback is enable
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_signing_receipt(client: Client):
+    assert client.features.signing_receipt is False
+
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, signing_receipt=enable)
+
+        assert client.features.signing_receipt is enable
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_density(client: Client):
+    assert client.features.density == messages.ScreenDensity.Comfortable
+
+    for density in (
+        messages.ScreenDensity.Compact,
+        messages.ScreenDensity.Comfortable,
+    ):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, density=density)
+
+        assert client.features.density == density
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_longer_timeouts(client: Client):
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, longer_timeouts=enable)
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_high_contrast(client: Client):
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, high_contrast=enable)
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_reduce_motion(client: Client):
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, reduce_motion=enable)
+
+
+@pytest.mark.skip_t1
+@pytest.mark.setup_client(pin=None)
+def test_theme_inverted(client: Client):
+    for enable in (True, False):
+        with client:
+            client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+            device.apply_settings(client, theme_inverted=enable)
@@ -860,6 +860,19 @@ class InputFlowEthereumSignTxDataSkip(InputFlowBase):
         yield from self.ETH.confirm_tx(cancel=self.cancel)
 
 
+class InputFlowEthereumSignTxDataBase64(InputFlowBase):
+    def __init__(self, client: Client, cancel: bool = False):
+        super().__init__(client)
+        self.cancel = cancel
+
+    def input_flow_common(self) -> BRGeneratorType:
+        yield from self.ETH.confirm_data(info=True)
+        yield from self.ETH.switch_data_format()
+        yield from self.ETH.paginate_data()
+        yield from self.ETH.confirm_data()
+        yield from self.ETH.confirm_tx(cancel=self.cancel)
+
+
 class InputFlowEthereumSignTxDataScrollDown(InputFlowBase):
     def __init__(self, client: Client, cancel: bool = False):
         super().__init__(client)
@@ -937,6 +950,29 @@ class InputFlowBip39ResetBackup(InputFlowBase):
         self.mnemonic = yield from get_mnemonic_and_confirm_success(self.debug)
 
 
+class InputFlowBip39ResetMixEntropy(InputFlowBase):
+    def __init__(self, client: Client):
+        super().__init__(client)
+        self.mnemonic = None
+
+    def input_flow_common(self) -> BRGeneratorType:
+        br = yield  # Confirm Reset
+        assert br.code == B.ResetDevice
+        self.debug.press_yes()
+
+        br = yield  # Mixing entropy, closes by itself once the presses stop
+        assert br.code == B.ResetDevice
+        for _ in range(3):
+            self.debug.press_yes()
+
+        # 1. Backup your seed
+        # 2. Confirm warning
+        yield from click_through(self.debug, screens=2, code=B.ResetDevice)
+
+        # mnemonic phrases and rest
+        self.mnemonic = yield from get_mnemonic_and_confirm_success(self.debug)
+
+
 class InputFlowBip39ResetPIN(InputFlowBase):
     def __init__(self, client: Client):
         super().__init__(client)
@@ -250,8 +250,6 @@ class RecoveryFlow:
 
 
 class EthereumFlow:
-    GO_BACK = (16, 220)
-
     def __init__(self, client: Client):
         self.client = client
         self.debug = self.client.debug
@@ -267,15 +265,22 @@ class EthereumFlow:
         else:
             self.debug.press_yes()
 
+    def switch_data_format(self) -> BRGeneratorType:
+        yield
+        assert self.debug.wait_layout().title() == "CONFIRM DATA"
+        assert "bytes, hex" in self.debug.wait_layout().text_content()
+        if self.debug.model == "T":
+            self.debug.press_info()
+        else:
+            # the left button switches the format on the first page
+            self.debug.press_no()
+
     def paginate_data(self) -> BRGeneratorType:
         br = yield
         assert self.debug.wait_layout().title() == "CONFIRM DATA"
+        assert "bytes" in self.debug.wait_layout().text_content()
         assert br.pages is not None
-        for i in range(br.pages):
-            self.debug.wait_layout()
-            if i < br.pages - 1:
-                self.debug.swipe_up()
-        self.debug.press_yes()
+        self._close_data_pages(br.pages)
 
     def paginate_data_go_back(self) -> BRGeneratorType:
         br = yield
@@ -285,13 +290,21 @@ class EthereumFlow:
         if self.debug.model == "T":
             self.debug.swipe_up(wait=True)
             self.debug.swipe_up(wait=True)
-            self.debug.click(self.GO_BACK)
+            self.debug.swipe_down(wait=True)
+            self.debug.swipe_down(wait=True)
         else:
-            self.debug.press_right()
-            self.debug.press_right()
-            self.debug.press_left()
-            self.debug.press_left()
-            self.debug.press_left()
+            self.debug.press_right(wait=True)
+            self.debug.press_right(wait=True)
+            self.debug.press_left(wait=True)
+            self.debug.press_left(wait=True)
+        self._close_data_pages(br.pages)
+
+    def _close_data_pages(self, pages: int) -> None:
+        """Page through the whole viewer and close it on the last page, which goes
+        back to the data summary."""
+        for _ in range(pages - 1):
+            self.debug.swipe_up(wait=True)
+        self.debug.press_yes()
 
     def confirm_tx(
         self,